@@ -2,7 +2,7 @@ use super::control_builder::ControlBuilder;
 use crate::parameter_parser::*;
 use proc_macro::*;
 
-static FLAGS: FlagsSignature = FlagsSignature::new(&["ScrollBars", "SearchBar", "MultiSelect"]);
+static FLAGS: FlagsSignature = FlagsSignature::new(&["ScrollBars", "SearchBar", "MultiSelect", "Minimap"]);
 
 static POSILITIONAL_PARAMETERS: &[PositionalParameter] = &[];
 static NAMED_PARAMETERS: &[NamedParameter] = &[
@@ -30,7 +30,9 @@ static NAMED_PARAMETERS: &[NamedParameter] = &[
     NamedParameter::new("highlight-incoming-edges", "hie", ParamType::Bool),
     NamedParameter::new("hie", "hie", ParamType::Bool),
     NamedParameter::new("highlight-outgoing-edges", "hoe", ParamType::Bool),
-    NamedParameter::new("hoe", "hoe", ParamType::Bool)
+    NamedParameter::new("hoe", "hoe", ParamType::Bool),
+    // zoom
+    NamedParameter::new("zoom", "zoom", ParamType::Integer),
 ];
 
 static LINE_TYPE: FlagsSignature = FlagsSignature::new(&[
@@ -107,6 +109,10 @@ pub(crate) fn create(input: TokenStream) -> TokenStream {
         let hoe = cb.get_bool("hoe").unwrap_or(false);
         cb.add_line(format!("control.enable_edge_highlighting({hie}, {hoe});").as_str());
     }
+    // zoom
+    if let Some(zoom) = cb.get_i32("zoom") {
+        cb.add_line(format!("control.set_zoom({});", zoom.max(1)).as_str());
+    }
 
 
     cb.add_scroll_margin_setup("lsm", "tsm");
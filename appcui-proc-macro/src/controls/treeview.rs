@@ -12,6 +12,7 @@ static FLAGS: FlagsSignature = FlagsSignature::new(&[
     "NoSelection",
     "HideHeader",
     "MergeBorders",
+    "ShowFooter",
 ]);
 
 static POSILITIONAL_PARAMETERS: &[PositionalParameter] = &[PositionalParameter::new("type", ParamType::String)];
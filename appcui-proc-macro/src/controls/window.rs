@@ -24,6 +24,7 @@ static NAMED_PARAMETERS: &[NamedParameter] = &[
     NamedParameter::new("back", "background", ParamType::String),
     NamedParameter::new("bg", "background", ParamType::String),
     NamedParameter::new("tag", "tag", ParamType::String),
+    NamedParameter::new("id", "id", ParamType::String),
     NamedParameter::new("hot-key", "hotkey", ParamType::String),
     NamedParameter::new("hotkey", "hotkey", ParamType::String),
     NamedParameter::new("key", "hotkey", ParamType::String),
@@ -43,6 +44,11 @@ pub(crate) fn create(input: TokenStream) -> TokenStream {
         cb.add_string_parameter("tag", None);
         cb.add_line(");\n");  
     }
+    if cb.has_parameter("id") {
+        cb.add("\n\tcontrol.set_id(");
+        cb.add_string_parameter("id", None);
+        cb.add_line(");\n");  
+    }
     if cb.has_parameter("hotkey") {
         let s = cb.get_value("hotkey").unwrap();
         if utils::equal_ignore_case(s, "auto") {
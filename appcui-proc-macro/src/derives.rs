@@ -2,4 +2,5 @@ pub(crate) mod structure;
 pub(crate) mod listitem;
 pub(crate) mod enumeration;
 pub(crate) mod enumselector;
-pub(crate) mod dropdownlisttype;
\ No newline at end of file
+pub(crate) mod dropdownlisttype;
+pub(crate) mod form;
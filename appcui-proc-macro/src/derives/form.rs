@@ -0,0 +1,261 @@
+use super::structure::Structure;
+use super::structure::StructureField;
+use proc_macro::*;
+use std::str::FromStr;
+
+static TEMPLATE: &str = r#"
+impl dialogs::FormData for $(STRUCT_NAME) {
+    fn form(&self, title: &str) -> dialogs::Form {
+        dialogs::Form::new(title)
+            $(FIELDS)
+    }
+    fn update(&mut self, values: &dialogs::FormValues) {
+        $(UPDATES)
+    }
+}
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldType {
+    Text,
+    Password,
+    Number,
+    Bool,
+    Date,
+    Time,
+    Enum,
+    OptionalEnum,
+}
+
+impl FieldType {
+    fn from_type(field: &StructureField) -> (Self, String) {
+        let ty: String = field.ty.chars().filter(|c| !c.is_whitespace()).collect();
+        let short = ty.rsplit("::").next().unwrap_or(&ty);
+        let kind = match short {
+            "String" => FieldType::Text,
+            "bool" => FieldType::Bool,
+            "i8" | "i16" | "i32" | "i64" | "isize" | "u8" | "u16" | "u32" | "u64" | "usize" => return (FieldType::Number, short.to_string()),
+            "NaiveDate" => FieldType::Date,
+            "NaiveTime" => FieldType::Time,
+            _ => {
+                if let Some(inner) = ty.strip_prefix("Option<").and_then(|t| t.strip_suffix('>')) {
+                    if !Self::is_enum_type(inner) {
+                        Self::unsupported(field);
+                    }
+                    return (FieldType::OptionalEnum, inner.to_string());
+                }
+                if !Self::is_enum_type(&ty) {
+                    Self::unsupported(field);
+                }
+                FieldType::Enum
+            }
+        };
+        (kind, ty)
+    }
+    // an enum is a simple path (no generics, references, tuples or arrays) that is not a primitive type
+    fn is_enum_type(ty: &str) -> bool {
+        let short = ty.rsplit("::").next().unwrap_or(ty);
+        if short.is_empty() || !ty.chars().all(|c| c.is_alphanumeric() || c == '_' || c == ':') {
+            return false;
+        }
+        !matches!(
+            short,
+            "String" | "str" | "bool" | "char" | "f32" | "f64" | "i128" | "u128" | "NaiveDate" | "NaiveTime"
+        ) && !short.starts_with(|c: char| c.is_ascii_digit())
+    }
+    fn unsupported(field: &StructureField) -> ! {
+        panic!(
+            "Field '{}' has an unsupported type: '{}'. Supported types are: String, bool, integers (i8..i64, u8..u64, isize, usize), NaiveDate, NaiveTime, enums that implement EnumSelector and Option<...> of such an enum. Use #[Field(skip)] to exclude the field from the form !",
+            field.name, field.ty
+        );
+    }
+    // the limits of a numeric type (for types whose size depends on the platform the limits are computed in the generated code)
+    fn number_limits(ty: &str) -> Option<(i64, i64)> {
+        match ty {
+            "i8" => Some((i8::MIN as i64, i8::MAX as i64)),
+            "i16" => Some((i16::MIN as i64, i16::MAX as i64)),
+            "i32" => Some((i32::MIN as i64, i32::MAX as i64)),
+            "i64" => Some((i64::MIN, i64::MAX)),
+            "u8" => Some((0, u8::MAX as i64)),
+            "u16" => Some((0, u16::MAX as i64)),
+            "u32" => Some((0, u32::MAX as i64)),
+            "u64" => Some((0, i64::MAX)),
+            _ => None,
+        }
+    }
+}
+
+struct Field {
+    name: String,
+    label: String,
+    kind: FieldType,
+    ty: String,
+    required: bool,
+    min: Option<i64>,
+    max: Option<i64>,
+    validator: Option<String>,
+}
+
+impl Field {
+    fn parse_number(value: &str, attr: &str, field: &StructureField) -> i64 {
+        match value.trim().parse::<i64>() {
+            Ok(v) => v,
+            Err(_) => panic!("Invalid numerical value: '{}' for attribute '{}' of field '{}'", value, attr, field.name),
+        }
+    }
+    fn try_from(field: &StructureField) -> Option<Self> {
+        let mut label = None;
+        let mut required = false;
+        let mut password = false;
+        let mut min = None;
+        let mut max = None;
+        let mut validator = None;
+        for (attr_name, value) in field.attributes.iter() {
+            if !attr_name.starts_with("Field.") {
+                continue;
+            }
+            match attr_name[6..].trim() {
+                "label" | "name" | "caption" => label = Some(value.to_string()),
+                "required" => required = true,
+                "password" => password = true,
+                "min" => min = Some(Self::parse_number(value, "min", field)),
+                "max" => max = Some(Self::parse_number(value, "max", field)),
+                "validator" | "validate" => validator = Some(value.to_string()),
+                "skip" => return None,
+                _ => {
+                    panic!(
+                        "Unknown attribute: '{}' for field '{}'. Available attributes are: 'label', 'required', 'password', 'min', 'max', 'validator' and 'skip' !",
+                        &attr_name[6..],
+                        field.name
+                    );
+                }
+            }
+        }
+        let (mut kind, ty) = FieldType::from_type(field);
+        if password {
+            if kind != FieldType::Text {
+                panic!(
+                    "Attribute 'password' can only be used with fields of type 'String' (field '{}' is of type '{}')",
+                    field.name, field.ty
+                );
+            }
+            kind = FieldType::Password;
+        }
+        if (min.is_some() || max.is_some()) && !matches!(kind, FieldType::Number | FieldType::Text | FieldType::Password) {
+            panic!(
+                "Attributes 'min' and 'max' can only be used with numeric or text fields (field '{}' is of type '{}')",
+                field.name, field.ty
+            );
+        }
+        if let (Some(a), Some(b)) = (min, max) {
+            if a > b {
+                panic!(
+                    "Invalid range for field '{}': min ({}) must be smaller or equal to max ({})",
+                    field.name, a, b
+                );
+            }
+        }
+        if let Some((type_min, type_max)) = FieldType::number_limits(&ty) {
+            for value in [min, max].into_iter().flatten() {
+                if value < type_min || value > type_max {
+                    panic!(
+                        "Invalid range for field '{}': {} is outside the limits of type '{}' ({} to {})",
+                        field.name, value, ty, type_min, type_max
+                    );
+                }
+            }
+        }
+        Some(Self {
+            name: field.name.clone(),
+            label: label.unwrap_or_else(|| field.name.clone()),
+            kind,
+            ty,
+            required,
+            min,
+            max,
+            validator,
+        })
+    }
+    fn to_field_code(&self) -> String {
+        let mut s = match self.kind {
+            FieldType::Text => format!(".text({:?}, {:?}, &self.{})", self.name, self.label, self.name),
+            FieldType::Password => format!(".password({:?}, {:?}, &self.{})", self.name, self.label, self.name),
+            FieldType::Number => format!(
+                ".number({:?}, {:?}, i64::try_from(self.{}).unwrap_or(i64::MAX))",
+                self.name, self.label, self.name
+            ),
+            FieldType::Bool => format!(".bool({:?}, {:?}, self.{})", self.name, self.label, self.name),
+            FieldType::Date => format!(".date({:?}, {:?}, self.{})", self.name, self.label, self.name),
+            FieldType::Time => format!(".time({:?}, {:?}, self.{})", self.name, self.label, self.name),
+            // a non-optional enum must always have a value
+            FieldType::Enum => format!(
+                ".enumeration::<{}>({:?}, {:?}, Some(self.{})).required()",
+                self.ty, self.name, self.label, self.name
+            ),
+            FieldType::OptionalEnum => format!(".enumeration::<{}>({:?}, {:?}, self.{})", self.ty, self.name, self.label, self.name),
+        };
+        if self.required && self.kind != FieldType::Enum {
+            s.push_str(".required()");
+        }
+        if self.kind == FieldType::Number {
+            // the range is always set so that the value entered by the user fits in the type of the field
+            let min = match self.min {
+                Some(v) => format!("{v}i64"),
+                None => format!("i64::try_from({}::MIN).unwrap_or(i64::MIN)", self.ty),
+            };
+            let max = match self.max {
+                Some(v) => format!("{v}i64"),
+                None => format!("i64::try_from({}::MAX).unwrap_or(i64::MAX)", self.ty),
+            };
+            s.push_str(&format!(".range({min}, {max})"));
+        } else if self.min.is_some() || self.max.is_some() {
+            s.push_str(&format!(
+                ".range({}i64, {}i64)",
+                self.min.unwrap_or(i64::MIN),
+                self.max.unwrap_or(i64::MAX)
+            ));
+        }
+        if let Some(validator) = &self.validator {
+            s.push_str(&format!(".validator({validator})"));
+        }
+        s.push('\n');
+        s
+    }
+    fn to_update_code(&self) -> String {
+        let (n, v) = (&self.name, format!("{:?}", self.name));
+        match self.kind {
+            FieldType::Text | FieldType::Password => format!("if let Some(v) = values.text({v}) {{ self.{n} = v.to_string(); }}\n"),
+            FieldType::Number => format!("if let Some(Ok(v)) = values.number({v}).map({}::try_from) {{ self.{n} = v; }}\n", self.ty),
+            FieldType::Bool => format!("if let Some(v) = values.bool({v}) {{ self.{n} = v; }}\n"),
+            FieldType::Date => format!("if let Some(v) = values.date({v}) {{ self.{n} = v; }}\n"),
+            FieldType::Time => format!("if let Some(v) = values.time({v}) {{ self.{n} = v; }}\n"),
+            FieldType::Enum => format!("if let Some(v) = values.enumeration::<{}>({v}) {{ self.{n} = v; }}\n", self.ty),
+            FieldType::OptionalEnum => format!("self.{n} = values.enumeration::<{}>({v});\n", self.ty),
+        }
+    }
+}
+
+fn build_derive_code(s: &Structure) -> TokenStream {
+    let fields: Vec<Field> = s.fields.iter().filter_map(Field::try_from).collect();
+    if fields.is_empty() {
+        panic!("Structure '{}' has no fields that can be edited in a form !", s.name);
+    }
+    let mut fields_code = String::new();
+    let mut updates_code = String::new();
+    for field in fields.iter() {
+        fields_code.push_str(&field.to_field_code());
+        updates_code.push_str(&field.to_update_code());
+    }
+    let output = TEMPLATE
+        .replace("$(STRUCT_NAME)", &s.name)
+        .replace("$(FIELDS)", &fields_code)
+        .replace("$(UPDATES)", &updates_code);
+    TokenStream::from_str(&output).expect("Fail to convert structure derived definition to token stream")
+}
+
+pub(crate) fn derive(input: TokenStream) -> TokenStream {
+    match Structure::from(input) {
+        Ok(s) => build_derive_code(&s),
+        Err(desc) => panic!("Fail to derive Form for structure: {}", desc),
+    }
+}
@@ -314,10 +314,8 @@ impl Structure {
             if let TokenTree::Punct(punct) = token {
                 let value = punct.as_char();
                 match value {
-                    ',' => {
-                        if depth == 0 {
-                            return Ok(index + start);
-                        }
+                    ',' if depth == 0 => {
+                        return Ok(index + start);
                     }
                     '<' => {
                         depth += 1;
@@ -9,6 +9,8 @@ struct Key {
     alt: bool,
     ctrl: bool,
     shift: bool,
+    super_key: bool,
+    meta: bool,
     code: KeyCode,
 }
 impl Key {
@@ -17,6 +19,8 @@ impl Key {
             alt: false,
             ctrl: false,
             shift: false,
+            super_key: false,
+            meta: false,
             code: KeyCode::None,
         };
         for text in string.split('+') {
@@ -28,6 +32,8 @@ impl Key {
                         alt: false,
                         ctrl: false,
                         shift: false,
+                        super_key: false,
+                        meta: false,
                         code: KeyCode::None,
                     };
                 }
@@ -63,6 +69,18 @@ impl Key {
                         }
                         k.ctrl = true;
                     }
+                    KeyModifier::Super => {
+                        if k.super_key {
+                            panic!("You have already added `Super` as a modifier !");
+                        }
+                        k.super_key = true;
+                    }
+                    KeyModifier::Meta => {
+                        if k.meta {
+                            panic!("You have already added `Meta` as a modifier !");
+                        }
+                        k.meta = true;
+                    }
                 }
             } else {
                 panic!("Unknwon key or modifiert: '{text}'");
@@ -71,7 +89,7 @@ impl Key {
         k
     }
     fn has_modifier(&self) -> bool {
-        self.alt | self.shift | self.ctrl
+        self.alt | self.shift | self.ctrl | self.super_key | self.meta
     }
 }
 
@@ -90,6 +108,12 @@ pub(crate) fn create_string(string: &str) -> String {
         if key.ctrl {
             s.push_str("KeyModifier::Ctrl|");
         }
+        if key.super_key {
+            s.push_str("KeyModifier::Super|");
+        }
+        if key.meta {
+            s.push_str("KeyModifier::Meta|");
+        }
         // remove last character
         s.pop();
         s.push(')');
@@ -110,6 +134,12 @@ pub(crate) fn create_u16(string: &str) -> u16 {
     if key.shift {
         value |= 0x400;
     }
+    if key.super_key {
+        value |= 0x800;
+    }
+    if key.meta {
+        value |= 0x1000;
+    }
     value |= (key.code as u8) as u16;
     value
 }
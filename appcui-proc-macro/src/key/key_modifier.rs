@@ -5,37 +5,57 @@ pub(crate) enum KeyModifier {
     Alt = 1,
     Shift = 2,
     Ctrl = 3,
+    Super = 4,
+    Meta = 5,
 }
 
-static HASH_TO_ALIGNAMENT: [Option<KeyModifier>; 10] = [
+static HASH_TO_ALIGNAMENT: [Option<KeyModifier>; 19] = [
     Some(KeyModifier::Alt),
     None,
-    Some(KeyModifier::Ctrl),
+    None,
+    Some(KeyModifier::Meta),
+    None,
+    None,
+    Some(KeyModifier::None),
+    None,
     Some(KeyModifier::Shift),
+    None,
+    Some(KeyModifier::Ctrl),
+    None,
     Some(KeyModifier::None),
     None,
     None,
     None,
+    Some(KeyModifier::Super),
+    None,
     None,
-    Some(KeyModifier::None),
 ];
 
-static HASH_COLISION_VALIDATOR: [u64; 10] = [
+static HASH_COLISION_VALIDATOR: [u64; 19] = [
     0xE6F0A3190519E83C,
     0x0,
-    0x45253F90A9043CC4,
-    0x297A5749140AC887,
-    0x904FEFB3D01CB2AE,
+    0x0,
+    0x4320E9A2E32EAC38,
     0x0,
     0x0,
+    0x904FEFB3D01CB2AE,
+    0x0,
+    0x297A5749140AC887,
     0x0,
+    0x45253F90A9043CC4,
     0x0,
     0x3C0D17BAD169557B,
+    0x0,
+    0x0,
+    0x0,
+    0xD0D708B40E957634,
+    0x0,
+    0x0,
 ];
 
 impl KeyModifier {
     pub(super) fn from_hash(hash: u64) -> Option<KeyModifier> {
-        let entry_index = (hash % 10) as usize;
+        let entry_index = (hash % 19) as usize;
         if HASH_COLISION_VALIDATOR[entry_index] != hash {
             return None;
         }
@@ -47,6 +67,8 @@ impl KeyModifier {
             KeyModifier::Alt => "Alt",
             KeyModifier::Shift => "Shift",
             KeyModifier::Ctrl => "Ctrl",
+            KeyModifier::Super => "Super",
+            KeyModifier::Meta => "Meta",
         }
     }
 }
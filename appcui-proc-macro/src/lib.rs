@@ -75,6 +75,7 @@ pub fn CustomControl(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::OnMouseEvent, TraitImplementation::Default);
     config.set(AppCUITrait::OnSiblingSelected, TraitImplementation::Default);
     config.set(AppCUITrait::OnThemeChanged, TraitImplementation::Default);
+    config.set(AppCUITrait::PersistState, TraitImplementation::Default);
 
     // control events
     config.set(AppCUITrait::ButtonEvents, TraitImplementation::DefaultNonOverwritable);
@@ -85,6 +86,7 @@ pub fn CustomControl(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::WindowEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::MenuEvents, TraitImplementation::Default);
     config.set(AppCUITrait::AppBarEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::ToastEvents, TraitImplementation::Default);
     config.set(AppCUITrait::CommandBarEvents, TraitImplementation::Default);
     config.set(AppCUITrait::ToolBarEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::ColorPickerEvents, TraitImplementation::DefaultNonOverwritable);
@@ -105,7 +107,7 @@ pub fn CustomControl(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::PathFinderEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericTreeViewEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::MarkdownEvents, TraitImplementation::DefaultNonOverwritable);
-    config.set(AppCUITrait::GenericBackgroundTaskEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::GenericBackgroundTaskEvents, TraitImplementation::Default);
     config.set(AppCUITrait::AccordionEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::TabEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::CharPickerEvents, TraitImplementation::DefaultNonOverwritable);
@@ -146,6 +148,7 @@ pub fn CustomContainer(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::OnMouseEvent, TraitImplementation::Default);
     config.set(AppCUITrait::OnSiblingSelected, TraitImplementation::Default);
     config.set(AppCUITrait::OnThemeChanged, TraitImplementation::Default);
+    config.set(AppCUITrait::PersistState, TraitImplementation::Default);
 
     // control events
     config.set(AppCUITrait::ButtonEvents, TraitImplementation::DefaultNonOverwritable);
@@ -156,6 +159,7 @@ pub fn CustomContainer(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::WindowEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::MenuEvents, TraitImplementation::Default);
     config.set(AppCUITrait::AppBarEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::ToastEvents, TraitImplementation::Default);
     config.set(AppCUITrait::CommandBarEvents, TraitImplementation::Default);
     config.set(AppCUITrait::ToolBarEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::ColorPickerEvents, TraitImplementation::DefaultNonOverwritable);
@@ -176,7 +180,7 @@ pub fn CustomContainer(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::PathFinderEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericTreeViewEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::MarkdownEvents, TraitImplementation::DefaultNonOverwritable);
-    config.set(AppCUITrait::GenericBackgroundTaskEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::GenericBackgroundTaskEvents, TraitImplementation::Default);
     config.set(AppCUITrait::AccordionEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::TabEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::CharPickerEvents, TraitImplementation::DefaultNonOverwritable);
@@ -241,6 +245,7 @@ pub fn Window(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::OnMouseEvent, TraitImplementation::BaseFallbackNonOverwritable);
     config.set(AppCUITrait::OnSiblingSelected, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::OnThemeChanged, TraitImplementation::Default);
+    config.set(AppCUITrait::PersistState, TraitImplementation::Default);
 
 
     // control events
@@ -252,6 +257,7 @@ pub fn Window(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::WindowEvents, TraitImplementation::Default);
     config.set(AppCUITrait::MenuEvents, TraitImplementation::Default);
     config.set(AppCUITrait::AppBarEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::ToastEvents, TraitImplementation::Default);
     config.set(AppCUITrait::CommandBarEvents, TraitImplementation::Default);
     config.set(AppCUITrait::ToolBarEvents, TraitImplementation::Default);
     config.set(AppCUITrait::ColorPickerEvents, TraitImplementation::Default);
@@ -311,6 +317,7 @@ pub fn ModalWindow(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::OnMouseEvent, TraitImplementation::BaseFallbackNonOverwritable);
     config.set(AppCUITrait::OnSiblingSelected, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::OnThemeChanged, TraitImplementation::Default);
+    config.set(AppCUITrait::PersistState, TraitImplementation::Default);
 
     // control events
     config.set(AppCUITrait::ButtonEvents, TraitImplementation::Default);
@@ -321,6 +328,7 @@ pub fn ModalWindow(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::WindowEvents, TraitImplementation::Default);
     config.set(AppCUITrait::MenuEvents, TraitImplementation::Default);
     config.set(AppCUITrait::AppBarEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::ToastEvents, TraitImplementation::Default);
     config.set(AppCUITrait::CommandBarEvents, TraitImplementation::Default);
     config.set(AppCUITrait::ToolBarEvents, TraitImplementation::Default);
     config.set(AppCUITrait::ColorPickerEvents, TraitImplementation::Default);
@@ -406,6 +414,7 @@ pub fn Desktop(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::OnMouseEvent, TraitImplementation::BaseFallbackNonOverwritable);
     config.set(AppCUITrait::OnSiblingSelected, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::OnThemeChanged, TraitImplementation::Default);
+    config.set(AppCUITrait::PersistState, TraitImplementation::Default);
 
     // control events
     config.set(AppCUITrait::ButtonEvents, TraitImplementation::DefaultNonOverwritable);
@@ -416,6 +425,7 @@ pub fn Desktop(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::WindowEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::MenuEvents, TraitImplementation::Default);
     config.set(AppCUITrait::AppBarEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::ToastEvents, TraitImplementation::Default);
     config.set(AppCUITrait::CommandBarEvents, TraitImplementation::Default);
     config.set(AppCUITrait::ToolBarEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::ColorPickerEvents, TraitImplementation::DefaultNonOverwritable);
@@ -436,7 +446,7 @@ pub fn Desktop(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::PathFinderEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericTreeViewEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::MarkdownEvents, TraitImplementation::DefaultNonOverwritable);
-    config.set(AppCUITrait::GenericBackgroundTaskEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::GenericBackgroundTaskEvents, TraitImplementation::Default);
     config.set(AppCUITrait::AccordionEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::TabEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::CharPickerEvents, TraitImplementation::DefaultNonOverwritable);
@@ -656,6 +666,64 @@ pub fn dropdownlisttype_derive(input: TokenStream) -> TokenStream {
     crate::derives::dropdownlisttype::derive(input)
 }
 
+/// Automatically implements the `dialogs::FormData` trait for a structure, so that it can be edited via `dialogs::form(...)`.
+/// 
+/// Every field of the structure becomes a field in the form (in the order they are declared). The control used to 
+/// edit the field is selected based on the type of the field:
+/// 
+/// | Field type                                          | Control          |
+/// | --------------------------------------------------- | ---------------- |
+/// | `String`                                            | TextField        |
+/// | `String` (with `password` attribute)                | Password         |
+/// | `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, ...  | NumericSelector  |
+/// | `bool`                                              | CheckBox         |
+/// | `NaiveDate`                                         | DatePicker       |
+/// | `NaiveTime`                                         | TimePicker       |
+/// | `Option<T>` or `T` (where `T` implements `EnumSelector`) | Selector    |
+/// 
+/// # Field Attribute Parameters
+/// 
+/// The `#[Field(...)]` attribute is optional and supports the following parameters:
+/// 
+/// | Parameter     | Type    | Description                                                                              |
+/// | ------------- | ------- | ---------------------------------------------------------------------------------------- |
+/// | `label`       | String  | The label of the field (can contain a hotkey). If not provided, the field name is used  |
+/// | `required`    | Flag    | A text field can not be empty, a `bool` field must be checked, an optional enum must have a value |
+/// | `password`    | Flag    | Use a Password control for a `String` field                                              |
+/// | `min`, `max`  | Integer | The range of a numeric field (or the length of a text field)                             |
+/// | `validator`   | Ident   | A function `fn(&dialogs::FieldValue) -> Result<(), String>` used to validate the field   |
+/// | `skip`        | Flag    | The field will not be part of the form                                                   |
+/// 
+/// # Example
+/// 
+/// ```no_compile
+/// use appcui::prelude::*;
+/// 
+/// #[derive(Form)]
+/// struct Connection {
+///     #[Field(label: "&Host", required)]
+///     host: String,
+///     
+///     #[Field(label: "&Port", min: 1, max: 65535)]
+///     port: u16,
+///     
+///     #[Field(label: "Pass&word", password)]
+///     password: String,
+///     
+///     #[Field(label: "Use &SSL")]
+///     ssl: bool,
+/// }
+/// 
+/// let c = Connection { host: String::from("localhost"), port: 22, password: String::new(), ssl: false };
+/// if let Some(c) = dialogs::form("Connection", c) {
+///     // c contains the values entered by the user
+/// }
+/// ```
+#[proc_macro_derive(Form, attributes(Field))]
+pub fn form_derive(input: TokenStream) -> TokenStream {
+    crate::derives::form::derive(input)
+}
+
 /// Use to quickly identify a key or a combination via a string
 /// Usage examples:
 /// * key!("F2")
@@ -678,6 +746,8 @@ pub fn dropdownlisttype_derive(input: TokenStream) -> TokenStream {
 /// * Shift
 /// * Ctrl
 /// * Alt
+/// * Super
+/// * Meta
 ///
 /// Modifiers can be used in combination with the simple `+` between them.
 #[proc_macro]
@@ -2146,6 +2216,7 @@ pub fn pathfinder(input: TokenStream) -> TokenStream {
 ///   - **NoSelection** - Disables item selection
 ///   - **HideHeader** - Hides the column header
 ///   - **MergeBorders** - Merges the borders of the tree view with the borders of the window (use box junctions to draw the tree view)
+///   - **ShowFooter** - Shows a footer row with the aggregated values of the columns (see `TreeView::set_column_aggregate`)
 /// * `left-scroll-margin` or `lsm` - Left scroll margin in characters (optional)
 /// * `top-scroll-margin` or `tsm` - Top scroll margin in characters (optional)
 /// * Position and size:
@@ -61,6 +61,9 @@ pub(crate) enum AppCUITrait {
     GenericBufferViewEvents = 52,
     HyperLinkEvents = 53,
     GenericHSliderEvents = 54,
+    ToastEvents = 55,
+    // state persistence
+    PersistState = 56,
 }
 
 #[repr(u8)]
@@ -132,6 +135,8 @@ impl AppCUITrait {
             AppCUITrait::GenericBufferViewEvents => "BufferViewEvents", // important to be without Generic
             AppCUITrait::HyperLinkEvents => "HyperLinkEvents",
             AppCUITrait::GenericHSliderEvents => "HSliderEvents",
+            AppCUITrait::ToastEvents => "ToastEvents",
+            AppCUITrait::PersistState => "PersistState",
         }
     }
     pub(crate) fn trait_type(&self) -> TraitType {
@@ -194,6 +199,8 @@ impl AppCUITrait {
             AppCUITrait::GenericBufferViewEvents => TraitType::ControlEvent,
             AppCUITrait::HyperLinkEvents => TraitType::ControlEvent,
             AppCUITrait::GenericHSliderEvents => TraitType::ControlEvent,
+            AppCUITrait::ToastEvents => TraitType::ControlEvent,
+            AppCUITrait::PersistState => TraitType::RawEvent,
         }
     }
     pub(crate) fn basefallback_implementation(&self) -> &'static str {
@@ -256,6 +263,8 @@ impl AppCUITrait {
             AppCUITrait::GenericBufferViewEvents => "",
             AppCUITrait::HyperLinkEvents => "",
             AppCUITrait::GenericHSliderEvents => "",
+            AppCUITrait::ToastEvents => "",
+            AppCUITrait::PersistState => "",
         }
     }
     pub(crate) fn default_implementation(&self) -> &'static str {
@@ -318,6 +327,8 @@ impl AppCUITrait {
             AppCUITrait::GenericBufferViewEvents => "impl$(TEMPLATE_TYPE) GenericBufferViewEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
             AppCUITrait::HyperLinkEvents => "impl$(TEMPLATE_TYPE) HyperLinkEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
             AppCUITrait::GenericHSliderEvents => "impl$(TEMPLATE_TYPE) GenericHSliderEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
+            AppCUITrait::ToastEvents => "impl$(TEMPLATE_TYPE) ToastEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
+            AppCUITrait::PersistState => "impl$(TEMPLATE_TYPE) PersistState for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
             
         }
     }
@@ -347,6 +358,7 @@ impl AppCUITrait {
             "OnExpand" => Some(AppCUITrait::OnExpand),
             "OnSiblingSelected" => Some(AppCUITrait::OnSiblingSelected),
             "OnThemeChanged" => Some(AppCUITrait::OnThemeChanged),
+            "PersistState" => Some(AppCUITrait::PersistState),
             // control events
             "ButtonEvents" | "Button" => Some(AppCUITrait::ButtonEvents),
             "CheckBoxEvents" | "CheckBox" => Some(AppCUITrait::CheckBoxEvents),
@@ -385,6 +397,7 @@ impl AppCUITrait {
             "BufferViewEvents" | "BufferView" => Some(AppCUITrait::GenericBufferViewEvents),
             "HyperLinkEvents" | "HyperLink" => Some(AppCUITrait::HyperLinkEvents),
             "HSliderEvents" | "HSlider" => Some(AppCUITrait::GenericHSliderEvents),
+            "ToastEvents" | "Toast" => Some(AppCUITrait::ToastEvents),
             _ => None,
         }
     }
@@ -448,6 +461,8 @@ impl AppCUITrait {
             52 => Some(AppCUITrait::GenericBufferViewEvents),
             53 => Some(AppCUITrait::HyperLinkEvents),
             54 => Some(AppCUITrait::GenericHSliderEvents),
+            55 => Some(AppCUITrait::ToastEvents),
+            56 => Some(AppCUITrait::PersistState),
             _ => None,
         };
         result?;
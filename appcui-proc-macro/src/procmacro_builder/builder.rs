@@ -181,9 +181,11 @@ fn generate_bufferview_events(a: &mut Arguments) -> String {
     }
     let mut on_current_pos_changed_code = String::new();
     let mut on_selection_changed_code = String::new();
+    let mut on_modified_code = String::new();
     for trait_name in a.template_events[&AppCUITrait::GenericBufferViewEvents].iter() {
         on_current_pos_changed_code.push_str(templates::BUFFERVIEW_ON_CURRENT_POS_CHANGED_DEF.replace("$(TYPE)", trait_name).as_str());
         on_selection_changed_code.push_str(templates::BUFFERVIEW_ON_SELECTION_CHANGED_DEF.replace("$(TYPE)", trait_name).as_str());
+        on_modified_code.push_str(templates::BUFFERVIEW_ON_MODIFIED_DEF.replace("$(TYPE)", trait_name).as_str());
     }
     templates::BUFFERVIEW_TRAIT_DEF
         .replace(
@@ -191,6 +193,7 @@ fn generate_bufferview_events(a: &mut Arguments) -> String {
             &on_current_pos_changed_code,
         )
         .replace("$(TYPE_ID_TRANSLATION_FOR_BUFFERVIEW_ON_SELECTION_CHANGED)", &on_selection_changed_code)
+        .replace("$(TYPE_ID_TRANSLATION_FOR_BUFFERVIEW_ON_MODIFIED)", &on_modified_code)
 }
 
 fn generate_treeview_events(a: &mut Arguments) -> String {
@@ -265,17 +268,20 @@ fn generate_backgroundtask_events(a: &mut Arguments) -> String {
     let mut on_start = String::new();
     let mut on_update = String::new();
     let mut on_finish = String::new();
+    let mut on_error = String::new();
     let mut on_query = String::new();
     for trait_name in a.template_events[&AppCUITrait::GenericBackgroundTaskEvents].iter() {
         on_start.push_str(templates::BACKGROUNDTASK_ON_START_DEF.replace("$(TYPE)", trait_name).as_str());
         on_update.push_str(templates::BACKGROUNDTASK_ON_UPDATE_DEF.replace("$(TYPE)", trait_name).as_str());
         on_finish.push_str(templates::BACKGROUNDTASK_ON_FINISH_DEF.replace("$(TYPE)", trait_name).as_str());
+        on_error.push_str(templates::BACKGROUNDTASK_ON_ERROR_DEF.replace("$(TYPE)", trait_name).as_str());
         on_query.push_str(templates::BACKGROUNDTASK_ON_QUERY_DEF.replace("$(TYPE)", trait_name).as_str());
     }
     templates::BACKGROUNDTASK_TRAIT_DEF
         .replace("$(TYPE_ID_TRANSLATION_FOR_BACKGROUNDTASK_ON_START)", &on_start)
         .replace("$(TYPE_ID_TRANSLATION_FOR_BACKGROUNDTASK_ON_UPDATE)", &on_update)
         .replace("$(TYPE_ID_TRANSLATION_FOR_BACKGROUNDTASK_ON_FINISH)", &on_finish)
+        .replace("$(TYPE_ID_TRANSLATION_FOR_BACKGROUNDTASK_ON_ERROR)", &on_error)
         .replace("$(TYPE_ID_TRANSLATION_FOR_BACKGROUNDTASK_ON_QUERY)", &on_query)
 }
 
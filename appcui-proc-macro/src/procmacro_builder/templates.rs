@@ -46,6 +46,7 @@ impl$(TEMPLATE_TYPE) OnPaint for $(STRUCT_NAME)$(TEMPLATE_DEF) {
 pub(crate) static ON_KEY_PRESSED_TRAIT: &str = "
 impl$(TEMPLATE_TYPE) OnKeyPressed for $(STRUCT_NAME)$(TEMPLATE_DEF) {
     fn on_key_pressed(&mut self, key: Key, character: char)->EventProcessStatus { return self.base.on_key_pressed(key, character); }
+    fn on_key_released(&mut self, key: Key)->EventProcessStatus { return self.base.on_key_released(key); }
 }
 ";
 
@@ -180,6 +181,7 @@ impl$(TEMPLATE_TYPE) GenericMenuEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {
 
 
 pub(crate) static EMIT_EVENTS_TEMPLATE: &str = "
+    pub const CLASS_HASH: u64 = $(STRUCT_NAME_HASH);
     #[repr(u32)]
     #[derive(Copy,Clone,Eq,PartialEq,Debug)]
     pub enum Events {
@@ -424,6 +426,13 @@ if std::any::TypeId::of::<$(TYPE)>() == type_id {
 }
 ";
 
+pub(crate) static BUFFERVIEW_ON_MODIFIED_DEF: &str = "
+if std::any::TypeId::of::<$(TYPE)>() == type_id {
+    let h: Handle<BufferView<$(TYPE)>> = unsafe { handle.unsafe_cast() };
+    return BufferViewEvents::<$(TYPE)>::on_modified(self, h);
+}
+";
+
 pub(crate) static BUFFERVIEW_TRAIT_DEF: &str = "
 trait BufferViewEvents<T: bufferview::BufferAccess+'static> {
     fn on_current_pos_changed(&mut self, handle: Handle<BufferView<T>>) -> EventProcessStatus {
@@ -432,6 +441,9 @@ trait BufferViewEvents<T: bufferview::BufferAccess+'static> {
     fn on_selection_changed(&mut self, handle: Handle<BufferView<T>>) -> EventProcessStatus {
         EventProcessStatus::Ignored
     }
+    fn on_modified(&mut self, handle: Handle<BufferView<T>>) -> EventProcessStatus {
+        EventProcessStatus::Ignored
+    }
 }
 impl$(TEMPLATE_TYPE) GenericBufferViewEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {
 
@@ -445,6 +457,11 @@ impl$(TEMPLATE_TYPE) GenericBufferViewEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {
         return EventProcessStatus::Ignored;
     }
 
+    fn on_modified(&mut self, handle: Handle<()>, type_id: std::any::TypeId) -> EventProcessStatus {
+        $(TYPE_ID_TRANSLATION_FOR_BUFFERVIEW_ON_MODIFIED)
+        return EventProcessStatus::Ignored;
+    }
+
 }
 ";
 
@@ -648,6 +665,13 @@ if let Some(bt) = self.background_task(unsafe { handle.unsafe_cast() }) {
     return BackgroundTaskEvents::<$(TYPE)>::on_finish(self, &bt);
 }
 ";
+pub(crate) static BACKGROUNDTASK_ON_ERROR_DEF: &str = "
+if let Some(bt) = self.background_task(unsafe { handle.unsafe_cast() }) {
+    if let Some(error) = bt.error() {
+        return BackgroundTaskEvents::<$(TYPE)>::on_error(self, &error, &bt);
+    }
+}
+";
 pub(crate) static BACKGROUNDTASK_ON_QUERY_DEF: &str = "
 if let Some(bt) = self.background_task(unsafe { handle.unsafe_cast() }) {
     if let Some(data_received) = bt.read() {
@@ -668,6 +692,9 @@ trait BackgroundTaskEvents<T: Send+'static, R: Send+'static> {
     fn on_finish(&mut self, task: &BackgroundTask<T,R>) -> EventProcessStatus {
         EventProcessStatus::Ignored
     }
+    fn on_error(&mut self, error: &str, task: &BackgroundTask<T,R>) -> EventProcessStatus {
+        EventProcessStatus::Ignored
+    }
     fn on_query(&mut self, value: T, task: &BackgroundTask<T,R>) -> R;
 }
 impl$(TEMPLATE_TYPE) GenericBackgroundTaskEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {
@@ -684,6 +711,10 @@ impl$(TEMPLATE_TYPE) GenericBackgroundTaskEvents for $(STRUCT_NAME)$(TEMPLATE_DE
         $(TYPE_ID_TRANSLATION_FOR_BACKGROUNDTASK_ON_FINISH)
         EventProcessStatus::Ignored
     }
+    fn on_error(&mut self, handle: Handle<()>) -> EventProcessStatus {
+        $(TYPE_ID_TRANSLATION_FOR_BACKGROUNDTASK_ON_ERROR)
+        EventProcessStatus::Ignored
+    }
     fn on_query(&mut self, handle: Handle<()>) -> EventProcessStatus {
         $(TYPE_ID_TRANSLATION_FOR_BACKGROUNDTASK_ON_QUERY)
         EventProcessStatus::Ignored
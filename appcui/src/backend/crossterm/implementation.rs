@@ -1,13 +1,13 @@
 use super::input::Input;
 use crate::{
     backend::{Backend, SystemEventReader},
-    graphics::{CharFlags, Color, Size, Surface},
+    graphics::{unicode, CharFlags, Color, Size, Surface},
     system::{Error, SystemEvent},
 };
 use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
 use crossterm::{
     cursor::{Hide, MoveTo, Show},
-    execute, queue,
+    execute,
     style::{Color as CrosstermColor, Print, ResetColor, SetBackgroundColor, SetForegroundColor},
     terminal::{disable_raw_mode, enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -145,13 +145,13 @@ impl Backend for CrossTerm {
         let mut current_bg = None;
         let mut flags = CharFlags::None;
         
-        let mut current_screen_char_index = 0;
         let mut position_changed = true; // Controls if cursor position has been changed
 
-        for ch in surface.chars.iter() {
+        for (current_screen_char_index, ch) in surface.chars.iter().enumerate() {
             let current_screen_char = &self.screen_chars[current_screen_char_index];
             // If the character is already painted, don't paint the character again
-            if *current_screen_char != *ch {
+            // (a grapheme cluster is always painted as its text is not part of the character)
+            if *current_screen_char != *ch || ch.flags.contains(CharFlags::Cluster) {
                 self.screen_chars[current_screen_char_index] = *ch;
                 if Some(ch.foreground) != current_fg {
                     queue!(stdout, SetForegroundColor(self.convert_color(ch.foreground))).unwrap();
@@ -188,7 +188,7 @@ impl Backend for CrossTerm {
                     flags = ch.flags;
                 }
                 if ! position_changed {
-                    queue!(stdout, MoveTo(x as u16, y as u16)).unwrap();
+                    queue!(stdout, MoveTo(x, y as u16)).unwrap();
                     position_changed = true;
                 }
                 if ! ch.code.is_ascii() {
@@ -196,12 +196,17 @@ impl Backend for CrossTerm {
                     // Cursor must be reposition after each wide character.
                     position_changed = false;
                 }   
-                queue!(stdout, Print(ch.code)).unwrap();
+                if ch.code == unicode::CONTINUATION_CHAR {
+                    // the second half of a wide character (already painted)
+                } else if let Some(text) = surface.cluster_text(current_screen_char_index) {
+                    queue!(stdout, Print(text)).unwrap();
+                } else {
+                    queue!(stdout, Print(ch.code)).unwrap();
+                }
             } else if position_changed {
                 position_changed = false;
             }
           
-            current_screen_char_index += 1;
             x += 1;
             if x >= w {
                 x = 0;
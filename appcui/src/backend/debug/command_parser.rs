@@ -124,6 +124,8 @@ impl<'a> CommandParser<'a> {
                 "Ctrl" => k |= KeyModifier::Ctrl,
                 "Alt" => k |= KeyModifier::Alt,
                 "Shift" => k |= KeyModifier::Shift,
+                "Super" => k |= KeyModifier::Super,
+                "Meta" => k |= KeyModifier::Meta,
                 "None" => k = KeyModifier::None,
                 _ => { return None; }
             }
@@ -142,6 +144,8 @@ impl<'a> CommandParser<'a> {
                 "Ctrl" => k.modifier |= KeyModifier::Ctrl,
                 "Alt" => k.modifier |= KeyModifier::Alt,
                 "Shift" => k.modifier |= KeyModifier::Shift,
+                "Super" => k.modifier |= KeyModifier::Super,
+                "Meta" => k.modifier |= KeyModifier::Meta,
                 "F1" => k.code = KeyCode::F1,
                 "F2" => k.code = KeyCode::F2,
                 "F3" => k.code = KeyCode::F3,
@@ -8,6 +8,7 @@ use crate::backend::utils::AnsiFormatter;
 use crate::graphics::Color;
 use crate::graphics::Point;
 use crate::graphics::Size;
+use crate::graphics::unicode;
 use crate::input::KeyModifier;
 use crate::prelude::CharFlags;
 use crate::system::Error;
@@ -82,15 +83,26 @@ impl DebugTerminal {
         // use FNV algorithm ==> https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
         let mut hash = 0xcbf29ce484222325u64;
         let mut buf = [0u8; 8];
-        for ch in &surface.chars {
-            buf[0] = ((ch.code as u32) & 0xFF) as u8;
-            buf[1] = (((ch.code as u32) >> 8) & 0xFF) as u8;
-            buf[2] = (((ch.code as u32) >> 16) & 0xFF) as u8;
-            buf[3] = (((ch.code as u32) >> 24) & 0xFF) as u8;
+        for (index, ch) in surface.chars.iter().enumerate() {
+            let mut code = ch.code as u32;
+            let mut flags = ch.flags;
+            if let Some(text) = surface.cluster_text(index) {
+                // hash all the characters of a grapheme cluster
+                for c in text.chars() {
+                    hash ^= c as u64;
+                    hash = hash.wrapping_mul(0x00000100000001B3u64);
+                }
+                code = 0;
+                flags.remove(CharFlags::Cluster);
+            }
+            buf[0] = (code & 0xFF) as u8;
+            buf[1] = ((code >> 8) & 0xFF) as u8;
+            buf[2] = ((code >> 16) & 0xFF) as u8;
+            buf[3] = ((code >> 24) & 0xFF) as u8;
             buf[4] = ch.foreground.as_color_index();
             buf[5] = ch.background.as_color_index();
-            buf[6] = ((ch.flags.get_value() >> 8) & 0xFF) as u8;
-            buf[7] = (ch.flags.get_value() & 0xFF) as u8;
+            buf[6] = ((flags.get_value() >> 8) & 0xFF) as u8;
+            buf[7] = (flags.get_value() & 0xFF) as u8;
             for b in buf {
                 hash ^= b as u64;
                 hash = hash.wrapping_mul(0x00000100000001B3u64);
@@ -266,7 +278,8 @@ impl Backend for DebugTerminal {
 
         let mut x = 0u32;
         let mut y = 0u32;
-        for ch in &surface.chars {
+        let mut after_wide_char = false;
+        for (index, ch) in surface.chars.iter().enumerate() {
             let mut fore = ch.foreground;
             let mut back = ch.background;
             if (x as i32 == cursor.x) && (y as i32 == cursor.y) {
@@ -277,9 +290,17 @@ impl Backend for DebugTerminal {
             self.ansi_buffer.set_char_flags(ch.flags);
             if ch.code <= ' ' {
                 self.ansi_buffer.write_char(' ');
+            } else if ch.code == unicode::CONTINUATION_CHAR {
+                // the second half of a wide character is already displayed by the wide character
+                if !after_wide_char {
+                    self.ansi_buffer.write_char(' ');
+                }
+            } else if let Some(text) = surface.cluster_text(index) {
+                self.ansi_buffer.write_string(text);
             } else {
                 self.ansi_buffer.write_char(ch.code);
             }
+            after_wide_char = surface.cell_width(index) == 2;
             self.ansi_buffer.reset_color();
             self.ansi_buffer.set_char_flags(CharFlags::None);
             x += 1;
@@ -125,7 +125,7 @@ impl Backend for NcursesTerminal {
         let mut utf8_buf: [u8; 8] = [0; 8];
         let mut skip_chars = 0;
 
-        for ch in surface.chars.iter() {
+        for (index, ch) in surface.chars.iter().enumerate() {
             if skip_chars > 0 {
                 skip_chars -= 1;
             } else {
@@ -142,10 +142,18 @@ impl Backend for NcursesTerminal {
                     ncursesapi::lib::ncurses_wattron(self.win, ncursesapi::constants::A_BOLD);
                 }
 
-                let _ = ncursesapi::lib::ncurses_mvaddstr(y, x, ch.code.encode_utf8(&mut utf8_buf));
-
-                let cw = ncurses_wcwidth(ch.code).max(1) - 1;
-                skip_chars += cw;
+                if let Some(text) = surface.cluster_text(index) {
+                    let _ = ncursesapi::lib::ncurses_mvaddstr(y, x, text);
+                    skip_chars += unicode::grapheme_width(text).max(1) as i32 - 1;
+                } else if ch.code == unicode::CONTINUATION_CHAR {
+                    // a continuation cell that does not follow a wide character
+                    let _ = ncursesapi::lib::ncurses_mvaddstr(y, x, " ");
+                } else {
+                    let _ = ncursesapi::lib::ncurses_mvaddstr(y, x, ch.code.encode_utf8(&mut utf8_buf));
+
+                    let cw = ncurses_wcwidth(ch.code).max(1) - 1;
+                    skip_chars += cw;
+                }
             }
 
             x += 1;
@@ -104,7 +104,6 @@ pub enum TermiosError {
     TcSetAttr,
     ReadStdInFailed,
     UnknownLetter(io::UnknownLetter),
-    UnknownKey,
 }
 
 impl From<io::UnknownLetter> for TermiosError {
@@ -1,5 +1,6 @@
 use crate::{
     backend::termios::api::TermiosError,
+    backend::utils::key_decoder::{self, DecodedKey, Sequence},
     input::{Key, KeyModifier, MouseButton},
     prelude::KeyCode,
 };
@@ -49,6 +50,8 @@ pub enum AnsiKeyCode {
     Letter(Letter),
     MouseButton(MouseButtonEvent),
     MouseMove(MouseButtonEvent),
+    // a key decoded from a CSI/SS3 sequence (legacy, kitty keyboard protocol or modifyOtherKeys)
+    Decoded(DecodedKey),
     Comma,
     Dot,
     Slash,
@@ -171,6 +174,7 @@ impl TryFrom<u8> for Letter {
 impl From<AnsiKey> for Key {
     fn from(value: AnsiKey) -> Self {
         let code = match value.code {
+            AnsiKeyCode::Decoded(decoded) => return decoded.key,
             AnsiKeyCode::Letter(letter) => match letter {
                 Letter::A => KeyCode::A,
                 Letter::B => KeyCode::B,
@@ -251,6 +255,9 @@ impl From<AnsiKey> for Key {
 // taken into condideration.
 const _CTRL_KEY_MASK: u8 = 0b0001_1111;
 
+// CSI sequences longer than this are not valid key sequences
+const MAX_CSI_LEN: usize = 32;
+
 const MOUSE_SHIFT_MASK: u8 = 4;
 const MOUSE_META_MASK: u8 = 8;
 const MOUSE_CTRL_MASK: u8 = 16;
@@ -296,6 +303,24 @@ impl TermiosReader {
         })
     }
 
+    fn decoded_key(sequence: &[u8]) -> AnsiKey {
+        let mut bytes = [0u8; 5];
+        let len = sequence.len().min(5);
+        bytes[..len].copy_from_slice(&sequence[..len]);
+        match key_decoder::decode(sequence) {
+            Some((Sequence::Key(decoded), _)) => AnsiKey {
+                bytes,
+                code: AnsiKeyCode::Decoded(decoded),
+                modifier: decoded.key.modifier,
+            },
+            _ => AnsiKey {
+                bytes,
+                code: AnsiKeyCode::Unknown,
+                modifier: KeyModifier::None,
+            },
+        }
+    }
+
     pub fn read_key() -> Result<AnsiKey, TermiosError> {
         while let Ok(c) = checked_stdin_read() {
             if c == 0 {
@@ -324,65 +349,27 @@ impl TermiosReader {
                             let byte_2 = try_check_stdin_read().unwrap_or(0);
                             match byte_2 {
                                 79 => {
-                                    // Here we read from F1 to F4 inclusive
-                                    // We need to read a 3rd byte
-                                    let byte_3 = checked_stdin_read()?;
-                                    // Fill in the appropriate key
+                                    // SS3 sequences: F1 to F4 (and arrows/Home/End in application cursor mode)
                                     // F1 -> 27 79 80
-                                    // F2 -> 27 79 81
-                                    // F3 -> 27 79 82
-                                    // F4 -> 27 79 83
-                                    let key = match byte_3 {
-                                        80 => AnsiKeyCode::F1,
-                                        81 => AnsiKeyCode::F2,
-                                        82 => AnsiKeyCode::F3,
-                                        83 => AnsiKeyCode::F4,
-                                        _ => return Err(TermiosError::UnknownKey),
-                                    };
-                                    let code = [c, byte_2, byte_3, 0, 0];
-                                    (code, key, modifier)
+                                    // Up -> 27 79 65
+                                    let byte_3 = checked_stdin_read()?;
+                                    return Ok(Self::decoded_key(&[c, byte_2, byte_3]));
                                 }
                                 91 => {
                                     // We need to read a 3rd byte
                                     let byte_3 = checked_stdin_read()?;
-                                    match byte_3 {
-                                        // Here we read from F5 to F8 inclusive
-                                        // F5 -> 27 91 49 53 126
-                                        // F6 -> 27 91 49 55 126
-                                        // F7 -> 27 91 49 56 126
-                                        // F8 -> 27 91 49 57 126
-                                        49 => {
-                                            let (byte_4, byte_5) = (checked_stdin_read()?, checked_stdin_read()?);
-                                            let key = match (byte_4, byte_5) {
-                                                (53, 126) => AnsiKeyCode::F5,
-                                                (55, 126) => AnsiKeyCode::F6,
-                                                (56, 126) => AnsiKeyCode::F7,
-                                                (57, 126) => AnsiKeyCode::F8,
-                                                _ => return Err(TermiosError::UnknownKey),
-                                            };
-                                            ([c, byte_2, byte_3, byte_4, byte_5], key, modifier)
-                                        }
-                                        // F9 -> 27 91 50 48 126
-                                        // F10 -> 27 91 50 49 126
-                                        // F11 -> Seems to be hardwired by the OS
-                                        // F12 -> 27 91 50 52 126
-                                        50 => {
-                                            let (byte_4, byte_5) = (checked_stdin_read()?, checked_stdin_read()?);
-                                            let key = match (byte_4, byte_5) {
-                                                (48, 126) => AnsiKeyCode::F9,
-                                                (49, 126) => AnsiKeyCode::F10,
-                                                (52, 126) => AnsiKeyCode::F12,
-                                                _ => return Err(TermiosError::UnknownKey),
-                                            };
-                                            ([c, byte_2, byte_3, byte_4, byte_5], key, modifier)
-                                        }
-                                        65 => ([c, byte_2, byte_3, 0, 0], AnsiKeyCode::_Up, modifier),
-                                        66 => ([c, byte_2, byte_3, 0, 0], AnsiKeyCode::_Down, modifier),
-                                        67 => ([c, byte_2, byte_3, 0, 0], AnsiKeyCode::_Right, modifier),
-                                        68 => ([c, byte_2, byte_3, 0, 0], AnsiKeyCode::_Left, modifier),
-                                        77 => return Self::parse_mouse_event(),
-                                        _ => return Err(TermiosError::UnknownKey),
+                                    if byte_3 == 77 {
+                                        return Self::parse_mouse_event();
+                                    }
+                                    // CSI sequences: everything up to the final byte (0x40 to 0x7E)
+                                    // F5 -> 27 91 49 53 126
+                                    // Ctrl+Shift+A (kitty keyboard protocol) -> 27 91 57 55 59 54 117
+                                    // Ctrl+Enter (modifyOtherKeys) -> 27 91 50 55 59 53 59 49 51 126
+                                    let mut sequence = vec![c, byte_2, byte_3];
+                                    while !(0x40..=0x7E).contains(sequence.last().unwrap_or(&0)) && sequence.len() < MAX_CSI_LEN {
+                                        sequence.push(checked_stdin_read()?);
                                     }
+                                    return Ok(Self::decoded_key(&sequence));
                                 }
                                 0 => ([c, byte_2, 0, 0, 0], AnsiKeyCode::_Escape, modifier),
                                 _ => ([c, byte_2, 0, 0, 0], AnsiKeyCode::Unknown, modifier),
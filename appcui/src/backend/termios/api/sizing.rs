@@ -45,7 +45,7 @@ pub(crate) fn listen_for_resizes () -> Result<(), std::io::Error> {
     let _ = get_resize_notification();
     
     unsafe {
-        if SIG_ERR == signal(SIGWINCH, handle_resize as sighandler_t) {
+        if SIG_ERR == signal(SIGWINCH, handle_resize as *const () as sighandler_t) {
             return Err(std::io::Error::last_os_error());
         };
     };
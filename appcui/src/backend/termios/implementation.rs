@@ -11,6 +11,7 @@ use super::{
     input::Input,
     size_reader::SizeReader,
 };
+use crate::backend::utils::key_decoder::{self, KITTY_FLAGS};
 use crate::backend::utils::{AnsiFlags, AnsiFormatter};
 use crate::{
     backend::{termios::api::sizing::listen_for_resizes, Backend, SystemEventReader},
@@ -20,6 +21,19 @@ use crate::{
 
 #[cfg(target_family = "unix")]
 use super::api::Termios;
+use super::api::io::try_check_stdin_read;
+
+// how many times (25ms each) we wait for the terminal to reply to the keyboard protocol queries
+const MAX_REPLY_WAIT_COUNT: u32 = 20;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum KeyboardMode {
+    Legacy,
+    // kitty progressive keyboard protocol
+    Kitty,
+    // xterm modifyOtherKeys (level 2)
+    ModifyOtherKeys,
+}
 
 /// Represents a terminal interface that has support for termios API terminals, supported by unix
 /// family and outputs ANSI escape codes and receives input from
@@ -28,9 +42,11 @@ pub struct TermiosTerminal {
     size: Size,
     orig_termios: Termios,
     ansi_buffer: AnsiFormatter,
+    keyboard_mode: KeyboardMode,
 }
 
 impl TermiosTerminal {
+    #[allow(clippy::new_ret_no_self)]
     pub(crate) fn new(builder: &crate::system::Builder, sender: Sender<SystemEvent>) -> Result<Box<dyn Backend>, Error> {
         let Ok(orig_termios) = Termios::enable_raw_mode() else {
             return Err(Error::new(
@@ -50,6 +66,7 @@ impl TermiosTerminal {
                     AnsiFlags::None
                 },
             ),
+            keyboard_mode: KeyboardMode::Legacy,
         };
 
         if let Err(err) = listen_for_resizes() {
@@ -82,12 +99,62 @@ impl TermiosTerminal {
         t.ansi_buffer.write_string("\x1b[?1000h\x1b[?1002h\x1b[?1003h");
         t.ansi_buffer.execute();
 
+        if builder.enhanced_keyboard || builder.modify_other_keys {
+            t.enable_enhanced_keyboard(builder.modify_other_keys);
+        }
+
         Input::new().start(sender.clone());
         SizeReader::new(get_resize_notification().clone()).start(sender);
         Ok(Box::new(t))
     }
 }
 
+impl TermiosTerminal {
+    // must be called before the input thread starts (the replies of the terminal are read from stdin)
+    fn enable_enhanced_keyboard(&mut self, force_modify_other_keys: bool) {
+        // query the kitty keyboard flags, the modifyOtherKeys resource (XTQMODKEYS) and then the primary device attributes;
+        // all terminals reply to the last query, so if there is no reply to one of the first two the feature is not supported
+        self.ansi_buffer.clear();
+        self.ansi_buffer.write_string("\x1b[?u\x1b[?4m\x1b[c");
+        self.ansi_buffer.execute();
+        let mut reply = Vec::new();
+        let mut wait_count = 0;
+        while wait_count < MAX_REPLY_WAIT_COUNT {
+            match try_check_stdin_read() {
+                Some(b) => {
+                    reply.push(b);
+                    // the device attributes reply (ESC [ ? ... c) is the last one
+                    if b == b'c' {
+                        break;
+                    }
+                }
+                None => wait_count += 1,
+            }
+        }
+        self.keyboard_mode = if key_decoder::has_keyboard_flags_reply(&reply) {
+            KeyboardMode::Kitty
+        } else if force_modify_other_keys || key_decoder::has_modify_other_keys_reply(&reply) {
+            KeyboardMode::ModifyOtherKeys
+        } else {
+            KeyboardMode::Legacy
+        };
+        self.ansi_buffer.clear();
+        match self.keyboard_mode {
+            KeyboardMode::Kitty => self.ansi_buffer.write_string(&format!("\x1b[>{KITTY_FLAGS}u")),
+            KeyboardMode::ModifyOtherKeys => self.ansi_buffer.write_string("\x1b[>4;2m"),
+            KeyboardMode::Legacy => {}
+        }
+        self.ansi_buffer.execute();
+    }
+    fn disable_enhanced_keyboard(&mut self) {
+        match self.keyboard_mode {
+            KeyboardMode::Kitty => self.ansi_buffer.write_string("\x1b[<u"),
+            KeyboardMode::ModifyOtherKeys => self.ansi_buffer.write_string("\x1b[>4m"),
+            KeyboardMode::Legacy => {}
+        }
+    }
+}
+
 impl Backend for TermiosTerminal {
     fn update_screen(&mut self, surface: &Surface) {
         //self.clear();
@@ -132,6 +199,7 @@ impl Backend for TermiosTerminal {
         self.ansi_buffer.clear();
         self.ansi_buffer.reset_screen();
         self.ansi_buffer.disable_mouse_events();
+        self.disable_enhanced_keyboard();
         self.ansi_buffer.execute();
         self.orig_termios.restore();
     }
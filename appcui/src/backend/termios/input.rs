@@ -2,12 +2,15 @@ use crate::input::Key;
 use crate::input::KeyCode;
 use crate::input::MouseButton;
 use crate::system::KeyPressedEvent;
+use crate::system::KeyReleasedEvent;
 use crate::system::MouseButtonDownEvent;
 use crate::system::MouseButtonUpEvent;
 use crate::system::MouseMoveEvent;
 use crate::system::SystemEvent;
 use crate::backend::SystemEventReader;
 
+use crate::backend::utils::key_decoder::KeyEventKind;
+
 use super::api::io::AnsiKeyCode;
 use super::api::io::TermiosReader;
 
@@ -50,6 +53,17 @@ impl SystemEventReader for Input {
                     }));
                 }
 
+                if let AnsiKeyCode::Decoded(decoded) = ansi_key.code() {
+                    // key repeats are reported as normal key presses
+                    return match decoded.kind {
+                        KeyEventKind::Release => Some(SystemEvent::KeyReleased(KeyReleasedEvent { key: decoded.key })),
+                        _ => Some(SystemEvent::KeyPressed(KeyPressedEvent {
+                            key: decoded.key,
+                            character: decoded.character,
+                        })),
+                    };
+                }
+
                 // We take the initial 4 bytes an we try to convert them into an `u32`
                 let bytes = ansi_key.bytes().get(0..4)?;
                 let value = u32::from_le_bytes(bytes.try_into().unwrap_or([0; 4]));
@@ -1,4 +1,6 @@
 mod ansi_formatter;
+#[cfg(target_family = "unix")]
+pub(crate) mod key_decoder;
 #[cfg(target_os = "windows")]
 pub(crate) mod win32;
 #[cfg(test)]
@@ -0,0 +1,312 @@
+//! Decoder for the escape sequences that terminals send for keys when the
+//! kitty progressive keyboard protocol or xterm's modifyOtherKeys mode is enabled
+//! (it also understands the legacy `CSI`/`SS3` sequences for function, navigation and arrow keys).
+//!
+//! Kitty protocol: <https://sw.kovidgoyal.net/kitty/keyboard-protocol/>
+
+use crate::input::{Key, KeyCode, KeyModifier};
+
+const ESC: u8 = 0x1B;
+const MAX_PARAMS: usize = 4;
+const MAX_SUB_PARAMS: usize = 3;
+// sequences longer than this are considered invalid (and are skipped)
+const MAX_SEQUENCE_LEN: usize = 64;
+
+// kitty modifier bits (the value sent by the terminal is 1 + bits)
+const MOD_SHIFT: u32 = 1;
+const MOD_ALT: u32 = 2;
+const MOD_CTRL: u32 = 4;
+const MOD_SUPER: u32 = 8;
+const MOD_META: u32 = 32;
+
+/// The progressive enhancement flags that are requested from a terminal that supports the kitty protocol:
+/// disambiguate escape codes (1) and report event types (2)
+pub(crate) const KITTY_FLAGS: u32 = 0b11;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum KeyEventKind {
+    Press,
+    Repeat,
+    Release,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct DecodedKey {
+    pub(crate) key: Key,
+    pub(crate) character: char,
+    pub(crate) kind: KeyEventKind,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum Sequence {
+    Key(DecodedKey),
+    // response to the `CSI ? u` query (the keyboard enhancement flags that are currently enabled)
+    KeyboardFlags(u32),
+    // response to the `CSI ? 4 m` query (XTQMODKEYS - the value of the modifyOtherKeys resource)
+    ModifyOtherKeys(u32),
+    // response to the `CSI c` query (primary device attributes)
+    DeviceAttributes,
+    // a valid sequence that does not translate into a key (e.g. a modifier key or a cursor position report)
+    Ignored,
+}
+
+#[derive(Default)]
+struct Params {
+    values: [[u32; MAX_SUB_PARAMS]; MAX_PARAMS],
+    count: usize,
+}
+
+impl Params {
+    fn parse(bytes: &[u8]) -> Option<Params> {
+        let mut p = Params { count: 1, ..Default::default() };
+        let mut sub = 0;
+        for &b in bytes {
+            match b {
+                b'0'..=b'9' => {
+                    if p.count <= MAX_PARAMS && sub < MAX_SUB_PARAMS {
+                        let v = &mut p.values[p.count - 1][sub];
+                        *v = v.checked_mul(10)?.checked_add((b - b'0') as u32)?;
+                    }
+                }
+                b';' => {
+                    p.count += 1;
+                    sub = 0;
+                }
+                b':' => sub += 1,
+                _ => return None,
+            }
+        }
+        p.count = p.count.min(MAX_PARAMS);
+        Some(p)
+    }
+    #[inline(always)]
+    fn get(&self, index: usize, sub_index: usize) -> u32 {
+        if index < self.count {
+            self.values[index][sub_index]
+        } else {
+            0
+        }
+    }
+    // the modifier bits (without the lock bits) and the event type from a `modifiers:event` parameter
+    fn modifiers(&self, index: usize) -> (u32, KeyEventKind) {
+        let bits = self.get(index, 0).saturating_sub(1);
+        let kind = match self.get(index, 1) {
+            2 => KeyEventKind::Repeat,
+            3 => KeyEventKind::Release,
+            _ => KeyEventKind::Press,
+        };
+        (bits, kind)
+    }
+}
+
+/// Decodes one escape sequence from the beginning of `bytes` (that must start with `ESC [` or `ESC O`).
+/// Returns the decoded sequence and the number of bytes it used, or `None` if `bytes` does not start with
+/// a complete escape sequence.
+pub(crate) fn decode(bytes: &[u8]) -> Option<(Sequence, usize)> {
+    if bytes.len() < 3 || bytes[0] != ESC {
+        return None;
+    }
+    match bytes[1] {
+        b'[' => {
+            let end = bytes.iter().skip(2).take(MAX_SEQUENCE_LEN).position(|b| (0x40..=0x7E).contains(b))? + 2;
+            Some((decode_csi(&bytes[2..end], bytes[end]), end + 1))
+        }
+        b'O' => {
+            let seq = match legacy_key_code(bytes[2]) {
+                Some(code) => key_sequence(code, 0, KeyEventKind::Press, '\0'),
+                None => Sequence::Ignored,
+            };
+            Some((seq, 3))
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if `bytes` contains a reply to the `CSI ? u` query (meaning that the terminal supports the kitty keyboard protocol).
+pub(crate) fn has_keyboard_flags_reply(bytes: &[u8]) -> bool {
+    has_reply(bytes, |seq| matches!(seq, Sequence::KeyboardFlags(_)))
+}
+
+/// Returns `true` if `bytes` contains a reply to the `CSI ? 4 m` query (meaning that the terminal supports xterm's modifyOtherKeys mode).
+pub(crate) fn has_modify_other_keys_reply(bytes: &[u8]) -> bool {
+    has_reply(bytes, |seq| matches!(seq, Sequence::ModifyOtherKeys(_)))
+}
+
+fn has_reply(mut bytes: &[u8], is_reply: fn(&Sequence) -> bool) -> bool {
+    while !bytes.is_empty() {
+        match decode(bytes) {
+            Some((seq, _)) if is_reply(&seq) => return true,
+            Some((_, size)) => bytes = &bytes[size..],
+            None => bytes = &bytes[1..],
+        }
+    }
+    false
+}
+
+fn decode_csi(body: &[u8], final_byte: u8) -> Sequence {
+    let (marker, body) = match body.first() {
+        Some(b'?' | b'>' | b'<' | b'=') => (body[0], &body[1..]),
+        _ => (0, body),
+    };
+    let Some(p) = Params::parse(body) else {
+        return Sequence::Ignored;
+    };
+    match (marker, final_byte) {
+        (b'?', b'u') => Sequence::KeyboardFlags(p.get(0, 0)),
+        (b'?', b'c') => Sequence::DeviceAttributes,
+        (b'>', b'm') if p.get(0, 0) == 4 => Sequence::ModifyOtherKeys(p.get(1, 0)),
+        (0, b'u') => {
+            // CSI code[:shifted[:base]] ; modifiers[:event] ; text u
+            let (bits, kind) = p.modifiers(1);
+            let text = p.get(2, 0);
+            let character = if text != 0 { text } else if (bits & MOD_SHIFT) != 0 && p.get(0, 1) != 0 { p.get(0, 1) } else { p.get(0, 0) };
+            unicode_key(p.get(0, 0), bits, kind, character)
+        }
+        (0, b'~') => {
+            let (bits, kind) = p.modifiers(1);
+            match p.get(0, 0) {
+                // xterm modifyOtherKeys: CSI 27 ; modifiers ; code ~
+                27 => {
+                    let code = p.get(2, 0);
+                    unicode_key(code, bits, kind, code)
+                }
+                n => match tilde_key_code(n) {
+                    Some(code) => key_sequence(code, bits, kind, '\0'),
+                    None => Sequence::Ignored,
+                },
+            }
+        }
+        (0, b'Z') => key_sequence(KeyCode::Tab, MOD_SHIFT, KeyEventKind::Press, '\0'),
+        // F3 is not decoded from `CSI R` (the same sequence is used for cursor position reports)
+        (0, b'R') => Sequence::Ignored,
+        (0, b) => match legacy_key_code(b) {
+            Some(code) => {
+                let (bits, kind) = p.modifiers(1);
+                key_sequence(code, bits, kind, '\0')
+            }
+            None => Sequence::Ignored,
+        },
+        _ => Sequence::Ignored,
+    }
+}
+
+// keys that are encoded as `CSI 1 ; modifiers X` (or `SS3 X`)
+fn legacy_key_code(b: u8) -> Option<KeyCode> {
+    match b {
+        b'A' => Some(KeyCode::Up),
+        b'B' => Some(KeyCode::Down),
+        b'C' => Some(KeyCode::Right),
+        b'D' => Some(KeyCode::Left),
+        b'H' => Some(KeyCode::Home),
+        b'F' => Some(KeyCode::End),
+        b'P' => Some(KeyCode::F1),
+        b'Q' => Some(KeyCode::F2),
+        b'R' => Some(KeyCode::F3),
+        b'S' => Some(KeyCode::F4),
+        _ => None,
+    }
+}
+
+// keys that are encoded as `CSI number ; modifiers ~`
+fn tilde_key_code(n: u32) -> Option<KeyCode> {
+    match n {
+        1 | 7 => Some(KeyCode::Home),
+        2 => Some(KeyCode::Insert),
+        3 => Some(KeyCode::Delete),
+        4 | 8 => Some(KeyCode::End),
+        5 => Some(KeyCode::PageUp),
+        6 => Some(KeyCode::PageDown),
+        11 => Some(KeyCode::F1),
+        12 => Some(KeyCode::F2),
+        13 => Some(KeyCode::F3),
+        14 => Some(KeyCode::F4),
+        15 => Some(KeyCode::F5),
+        17 => Some(KeyCode::F6),
+        18 => Some(KeyCode::F7),
+        19 => Some(KeyCode::F8),
+        20 => Some(KeyCode::F9),
+        21 => Some(KeyCode::F10),
+        23 => Some(KeyCode::F11),
+        24 => Some(KeyCode::F12),
+        _ => None,
+    }
+}
+
+// keys that are identified by a unicode code point (and the functional keys from the private use area used by kitty)
+fn unicode_key(code: u32, mut bits: u32, kind: KeyEventKind, character: u32) -> Sequence {
+    let key_code = match code {
+        8 | 127 => KeyCode::Backspace,
+        9 => KeyCode::Tab,
+        13 => KeyCode::Enter,
+        27 => KeyCode::Escape,
+        32 => KeyCode::Space,
+        0x30..=0x39 => KeyCode::from((code - 0x30) as u8 + KeyCode::N0 as u8),
+        0x61..=0x7A => KeyCode::from((code - 0x61) as u8 + KeyCode::A as u8),
+        0x41..=0x5A => {
+            // modifyOtherKeys reports the shifted letter
+            bits |= MOD_SHIFT;
+            KeyCode::from((code - 0x41) as u8 + KeyCode::A as u8)
+        }
+        // keypad keys
+        57399..=57408 => KeyCode::from((code - 57399) as u8 + KeyCode::N0 as u8),
+        57414 => KeyCode::Enter,
+        57417 => KeyCode::Left,
+        57418 => KeyCode::Right,
+        57419 => KeyCode::Up,
+        57420 => KeyCode::Down,
+        57421 => KeyCode::PageUp,
+        57422 => KeyCode::PageDown,
+        57423 => KeyCode::Home,
+        57424 => KeyCode::End,
+        57425 => KeyCode::Insert,
+        57426 => KeyCode::Delete,
+        // other keys from the private use area (modifier keys, media keys, Caps Lock, ...)
+        0xE000..=0xF8FF => return Sequence::Ignored,
+        _ => KeyCode::None,
+    };
+    let character = match code {
+        // keypad characters
+        57399..=57408 => (code - 57399) + 0x30,
+        57409 => '.' as u32,
+        57410 => '/' as u32,
+        57411 => '*' as u32,
+        57412 => '-' as u32,
+        57413 => '+' as u32,
+        57415 => '=' as u32,
+        _ => character,
+    };
+    let mut ch = char::from_u32(character).filter(|c| !c.is_control()).unwrap_or('\0');
+    if key_code == KeyCode::None && ch == '\0' {
+        return Sequence::Ignored;
+    }
+    if (bits & MOD_SHIFT) != 0 && ch.is_ascii_lowercase() {
+        ch = ch.to_ascii_uppercase();
+    }
+    key_sequence(key_code, bits, kind, ch)
+}
+
+fn key_sequence(code: KeyCode, bits: u32, kind: KeyEventKind, character: char) -> Sequence {
+    let mut modifier = KeyModifier::None;
+    if (bits & MOD_SHIFT) != 0 {
+        modifier |= KeyModifier::Shift;
+    }
+    if (bits & MOD_ALT) != 0 {
+        modifier |= KeyModifier::Alt;
+    }
+    if (bits & MOD_CTRL) != 0 {
+        modifier |= KeyModifier::Ctrl;
+    }
+    if (bits & MOD_SUPER) != 0 {
+        modifier |= KeyModifier::Super;
+    }
+    if (bits & MOD_META) != 0 {
+        modifier |= KeyModifier::Meta;
+    }
+    // the character is only reported for text input (not for shortcuts such as Ctrl+A)
+    let is_shortcut = (bits & (MOD_ALT | MOD_CTRL | MOD_SUPER | MOD_META)) != 0;
+    Sequence::Key(DecodedKey {
+        key: Key::new(code, modifier),
+        character: if is_shortcut || kind == KeyEventKind::Release { '\0' } else { character },
+        kind,
+    })
+}
@@ -190,4 +190,123 @@ fn check_ansi_renderer_with_cursor() {
     s.set_cursor(2, 0);
     a.render(&s, Point::ORIGIN);
     assert_eq!(a.text(),"\u{1b}[0m\u{1b}[?25l\u{1b}[1;1H\u{1b}[38;2;255;0;0m\u{1b}[48;2;0;0;255mHe\u{1b}[38;2;255;0;255m\u{1b}[48;2;0;128;0m\u{1b}[1mll\u{1b}[38;2;255;0;0m\u{1b}[48;2;0;0;255m\u{1b}[22mo\u{1b}[1;3H\u{1b}[?25h");
-}
\ No newline at end of file
+}
+#[cfg(target_family = "unix")]
+fn decode_key(bytes: &[u8]) -> (Key, char, crate::backend::utils::key_decoder::KeyEventKind) {
+    use crate::backend::utils::key_decoder::{decode, Sequence};
+    match decode(bytes) {
+        Some((Sequence::Key(k), size)) => {
+            assert_eq!(size, bytes.len());
+            (k.key, k.character, k.kind)
+        }
+        other => panic!("Expected a key for {:?}, but got {:?}", bytes, other),
+    }
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn check_key_decoder_kitty_keys() {
+    use crate::backend::utils::key_decoder::KeyEventKind;
+    // Ctrl+Shift+A (ambiguous with Ctrl+A in legacy mode)
+    assert_eq!(decode_key(b"\x1b[97;6u"), (key!("Ctrl+Shift+A").into(), '\0', KeyEventKind::Press));
+    // Ctrl+Enter, Ctrl+Tab, Shift+Space
+    assert_eq!(decode_key(b"\x1b[13;5u"), (key!("Ctrl+Enter").into(), '\0', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[9;5u"), (key!("Ctrl+Tab").into(), '\0', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[32;2u"), (key!("Shift+Space").into(), ' ', KeyEventKind::Press));
+    // Escape (disambiguated from the start of an escape sequence)
+    assert_eq!(decode_key(b"\x1b[27u"), (key!("Escape").into(), '\0', KeyEventKind::Press));
+    // Alt+1
+    assert_eq!(decode_key(b"\x1b[49;3u"), (key!("Alt+1").into(), '\0', KeyEventKind::Press));
+    // shifted key and associated text
+    assert_eq!(decode_key(b"\x1b[97:65;2u"), (key!("Shift+A").into(), 'A', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[97;1;97u"), (key!("A").into(), 'a', KeyEventKind::Press));
+    // a key without a KeyCode equivalent (only the character is reported)
+    assert_eq!(decode_key(b"\x1b[44;2u"), (Key::new(KeyCode::None, KeyModifier::Shift), ',', KeyEventKind::Press));
+    // keypad keys
+    assert_eq!(decode_key(b"\x1b[57400u"), (key!("1").into(), '1', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[57414;5u"), (key!("Ctrl+Enter").into(), '\0', KeyEventKind::Press));
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn check_key_decoder_super_and_meta() {
+    use crate::backend::utils::key_decoder::KeyEventKind;
+    assert_eq!(decode_key(b"\x1b[115;9u"), (key!("Super+S").into(), '\0', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[120;33u"), (key!("Meta+X").into(), '\0', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[1;13A"), (key!("Ctrl+Super+Up").into(), '\0', KeyEventKind::Press));
+    // hyper and the lock bits (Caps Lock, Num Lock) are ignored
+    assert_eq!(decode_key(b"\x1b[97;213u"), (key!("Ctrl+A").into(), '\0', KeyEventKind::Press));
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn check_key_decoder_event_types() {
+    use crate::backend::utils::key_decoder::KeyEventKind;
+    assert_eq!(decode_key(b"\x1b[97;1:1u"), (key!("A").into(), 'a', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[97;1:2u"), (key!("A").into(), 'a', KeyEventKind::Repeat));
+    assert_eq!(decode_key(b"\x1b[97;1:3u"), (key!("A").into(), '\0', KeyEventKind::Release));
+    assert_eq!(decode_key(b"\x1b[1;1:3D"), (key!("Left").into(), '\0', KeyEventKind::Release));
+    assert_eq!(decode_key(b"\x1b[3;5:2~"), (key!("Ctrl+Delete").into(), '\0', KeyEventKind::Repeat));
+    assert_eq!(decode_key(b"\x1b[1;2:3P"), (key!("Shift+F1").into(), '\0', KeyEventKind::Release));
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn check_key_decoder_modify_other_keys() {
+    use crate::backend::utils::key_decoder::KeyEventKind;
+    assert_eq!(decode_key(b"\x1b[27;6;65~"), (key!("Ctrl+Shift+A").into(), '\0', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[27;5;13~"), (key!("Ctrl+Enter").into(), '\0', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[27;5;9~"), (key!("Ctrl+Tab").into(), '\0', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[27;2;32~"), (key!("Shift+Space").into(), ' ', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[27;3;97~"), (key!("Alt+A").into(), '\0', KeyEventKind::Press));
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn check_key_decoder_legacy_sequences() {
+    use crate::backend::utils::key_decoder::KeyEventKind;
+    assert_eq!(decode_key(b"\x1bOP"), (key!("F1").into(), '\0', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1bOA"), (key!("Up").into(), '\0', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[15~"), (key!("F5").into(), '\0', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[24;2~"), (key!("Shift+F12").into(), '\0', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[13~"), (key!("F3").into(), '\0', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[5~"), (key!("PageUp").into(), '\0', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[1;5C"), (key!("Ctrl+Right").into(), '\0', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[H"), (key!("Home").into(), '\0', KeyEventKind::Press));
+    assert_eq!(decode_key(b"\x1b[Z"), (key!("Shift+Tab").into(), '\0', KeyEventKind::Press));
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn check_key_decoder_replies_and_invalid_sequences() {
+    use crate::backend::utils::key_decoder::{decode, has_keyboard_flags_reply, has_modify_other_keys_reply, DecodedKey, KeyEventKind, Sequence};
+    assert_eq!(decode(b"\x1b[?3u"), Some((Sequence::KeyboardFlags(3), 5)));
+    assert_eq!(decode(b"\x1b[?62;22c"), Some((Sequence::DeviceAttributes, 9)));
+    // cursor position report, modifier keys (Left Shift) and unknown sequences
+    assert_eq!(decode(b"\x1b[12;40R"), Some((Sequence::Ignored, 8)));
+    assert_eq!(decode(b"\x1b[57441;2u"), Some((Sequence::Ignored, 10)));
+    assert_eq!(decode(b"\x1b[99~"), Some((Sequence::Ignored, 5)));
+    assert_eq!(decode(b"\x1b[1;5X"), Some((Sequence::Ignored, 6)));
+    // incomplete sequences
+    assert_eq!(decode(b"\x1b[97;5"), None);
+    assert_eq!(decode(b"\x1b["), None);
+    assert_eq!(decode(b"abc"), None);
+    // only the first sequence is decoded
+    let up = DecodedKey {
+        key: key!("Up").into(),
+        character: '\0',
+        kind: KeyEventKind::Press,
+    };
+    assert_eq!(decode(b"\x1b[Ax"), Some((Sequence::Key(up), 3)));
+    assert!(has_keyboard_flags_reply(b"\x1b[?0u\x1b[?62;22c"));
+    assert!(has_keyboard_flags_reply(b"x\x1b[?1u"));
+    assert!(!has_keyboard_flags_reply(b"\x1b[?62;22c"));
+    assert!(!has_keyboard_flags_reply(b""));
+    // XTQMODKEYS reply (only the modifyOtherKeys resource - 4 - is a reply)
+    assert_eq!(decode(b"\x1b[>4;2m"), Some((Sequence::ModifyOtherKeys(2), 7)));
+    assert_eq!(decode(b"\x1b[>4m"), Some((Sequence::ModifyOtherKeys(0), 5)));
+    assert_eq!(decode(b"\x1b[>1;2m"), Some((Sequence::Ignored, 7)));
+    assert!(has_modify_other_keys_reply(b"\x1b[>4;0m\x1b[?62;22c"));
+    assert!(!has_modify_other_keys_reply(b"\x1b[?1u\x1b[?62;22c"));
+    assert!(!has_modify_other_keys_reply(b"\x1b[?62;22c"));
+}
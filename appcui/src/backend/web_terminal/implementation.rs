@@ -1,5 +1,6 @@
 use crate::{
     backend::Backend,
+    graphics::unicode,
     prelude::{CharFlags, ErrorKind, Key, KeyCode, KeyModifier, MouseButton, MouseWheelDirection, Size, Surface},
     system::Error,
     system::{KeyPressedEvent, MouseButtonDownEvent, MouseButtonUpEvent, MouseMoveEvent, MouseWheelEvent, SystemEvent},
@@ -611,7 +612,8 @@ impl WebTerminal {
                     continue;
                 };
 
-                let char_width_cells = self.is_emoji(cell.code) as usize + 1; // Emoji take 2 cells, others take 1
+                // wide characters (CJK, emoji) take 2 cells, others take 1
+                let char_width_cells = if surface.cell_width(index) == 2 && x + 1 < width { 2 } else { 1 };
 
                 let pos_x = x as f64 * cell_width;
                 let pos_y = y as f64 * cell_height;
@@ -632,7 +634,11 @@ impl WebTerminal {
                 context.set_stroke_style_str(&self.rgba_color);
 
                 let render_center_x = pos_x + (char_width_cells as f64 * cell_width) / 2.0;
-                context.fill_text(&cell.code.to_string(), render_center_x, pos_y)?;
+                if let Some(text) = surface.cluster_text(index) {
+                    context.fill_text(text, render_center_x, pos_y)?;
+                } else if cell.code != unicode::CONTINUATION_CHAR {
+                    context.fill_text(&cell.code.to_string(), render_center_x, pos_y)?;
+                }
 
                 if cell.flags.contains(CharFlags::Underline) {
                     context.begin_path();
@@ -653,20 +659,6 @@ impl WebTerminal {
         Ok(())
     }
 
-    fn is_emoji(&self, c: char) -> bool {
-        matches!(
-            c as u32,
-            0x2600..=0x27BF      // Miscellaneous Symbols and Dingbats
-            | 0x1F300..=0x1F5FF  // Miscellaneous Symbols and Pictographs
-            | 0x1F600..=0x1F64F  // Emoticons
-            | 0x1F900..=0x1F9FF  // Supplemental Symbols and Pictographs
-            | 0x2100..=0x214F    // Letterlike Symbols
-            | 0x2300..=0x23FF    // Miscellaneous Technical
-            | 0x2B00..=0x2BFF    // Miscellaneous Symbols and Arrows
-            | 0x1F1E6..=0x1F1FF  // Enclosed Alphanumeric Supplement (for flag emojis)
-        )
-    }
-
     fn render_cursor(&self, surface: &Surface) -> Result<(), JsValue> {
         if !surface.cursor.is_visible() {
             return Ok(());
@@ -4,6 +4,7 @@ use super::super::Backend;
 use super::input::Input;
 use crate::backend::utils::win32::constants::*;
 use crate::backend::utils::win32::structs::*;
+use crate::graphics::unicode;
 use crate::graphics::*;
 use crate::system::Error;
 use crate::system::SystemEvent;
@@ -82,7 +83,13 @@ impl Backend for WindowsConsoleTerminal {
                 screen_char.attr |= COMMON_LVB_UNDERSCORE;
             }
 
-            match ch.code as u32 {
+            // the console can not display a grapheme cluster --> its first character (the code of the cell) is used
+            let code = if ch.code == unicode::CONTINUATION_CHAR {
+                '\0'
+            } else {
+                ch.code
+            };
+            match code as u32 {
                 0 => {
                     screen_char.code = 32;
                     if surrogate_used > 0 {
@@ -92,7 +99,7 @@ impl Backend for WindowsConsoleTerminal {
                     }
                 }
                 0x0001..=0xD7FF => {
-                    screen_char.code = ch.code as u16;
+                    screen_char.code = code as u16;
                     if surrogate_used > 0 {
                         surrogate_used -= 1;
                     } else {
@@ -101,7 +108,7 @@ impl Backend for WindowsConsoleTerminal {
                 }
                 0x10000..=0x10FFFF => {
                     // surrogate pair
-                    let v = (ch.code as u32) - 0x10000;
+                    let v = (code as u32) - 0x10000;
                     let h = v / 0x400 + 0xD800;
                     let l = v % 0x400 + 0xDC00;
                     screen_char.code = h as u16;
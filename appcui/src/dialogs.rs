@@ -20,13 +20,32 @@
 //!
 //! For file operations, the module offers:
 //! * [`open`] - A dialog for selecting a file to open
+//! * [`open_multiple`] - A dialog for selecting one or more files to open
 //! * [`save`] - A dialog for selecting a location to save a file
 //!
+//! # Form Dialogs
+//!
+//! For dialogs with multiple fields:
+//! * [`Form`] - A builder for a dialog with multiple typed fields (text, password, number, bool, enum, date and time) and validation rules
+//! * [`form`] - Edits an object that implements the [`FormData`] trait (usually via `#[derive(Form)]`)
+//!
+//! # Progress Dialogs
+//!
+//! For long running operations:
+//! * [`progress`] - Runs a task on a background thread and shows its progress (with ETA) in a modal dialog. The task can be canceled or moved in background (where its progress is shown in the app bar)
+//!
 //! # Folder Selection Dialogs
 //!
 //! For folder selection:
 //! * [`select_folder`] - A dialog for selecting a folder
 //!
+//! # Async Dialogs
+//!
+//! Every dialog has an async version (e.g. [`open_async`], [`validate_async`], [`input_async`]) that returns a future
+//! that can be awaited from a task started via `App::spawn_local`. The dialog is opened when the function is called
+//! (without starting a modal loop, so the event loop and other tasks keep running) and the future resolves, once the
+//! dialog is closed, to the same value as the blocking version.
+//!
 //! # Examples
 //!
 //! ```rust,no_run
@@ -53,14 +72,19 @@
 //!     println!("Selected file: {:?}", file_path);
 //! }
 //! ```
+mod async_dialogs;
 mod dialog_buttons;
 mod dialog_result;
 mod extension_selection_dialog;
 mod file_mask;
+mod file_preview;
 mod folder_select_dialog;
+mod form;
+mod form_dialog;
 mod generic_alert_dialog;
 mod input_dialog;
 mod open_save_dialog;
+mod progress_dialog;
 mod root_select_dialog;
 #[cfg(test)]
 mod tests;
@@ -71,13 +95,19 @@ use crate::{
     prelude::{window, ModalWindowMethods},
     utils::{self, Navigator},
 };
+pub use async_dialogs::{
+    alert_async, error_async, form_async, input_async, message_async, open_async, open_multiple_async, proceed_async, retry_async, save_async,
+    select_folder_async, validate_async, validate_or_cancel_async,
+};
 use dialog_buttons::DialogButtons;
 use dialog_result::DialogResult;
 use file_mask::FileMask;
+pub use form::{FieldValidator, FieldValue, Form, FormData, FormValues};
 use folder_select_dialog::{FolderExplorer, FolderSelectionDialogResult};
 use generic_alert_dialog::GenericAlertDialog;
 use input_dialog::StringImputDialog;
 use open_save_dialog::{FileExplorer, OpenSaveDialogResult};
+pub use progress_dialog::{BackgroundProgress, ProgressConnector, ProgressResult};
 use EnumBitFlags::EnumBitFlags;
 
 /// Result of a validation dialog with a cancel option.
@@ -276,7 +306,11 @@ pub fn validate(title: &str, caption: &str) -> bool {
 /// ```
 pub fn validate_or_cancel(title: &str, caption: &str) -> ValidateOrCancelResult {
     let w = GenericAlertDialog::new(title, caption, DialogButtons::YesNoCancel, window::Background::Notification);
-    match w.show() {
+    validate_or_cancel_result(w.show())
+}
+
+fn validate_or_cancel_result(result: Option<DialogResult>) -> ValidateOrCancelResult {
+    match result {
         Some(DialogResult::Yes) => ValidateOrCancelResult::Yes,
         Some(DialogResult::No) => ValidateOrCancelResult::No,
         _ => ValidateOrCancelResult::Cancel,
@@ -326,6 +360,7 @@ pub enum SaveFileDialogFlags {
 pub enum OpenFileDialogFlags {
     Icons = 1,
     CheckIfFileExists = 2,
+    Preview = 4,
 }
 
 #[EnumBitFlags(bits = 8)]
@@ -333,14 +368,15 @@ pub enum SelectFolderDialogFlags {
     Icons = 1,
 }
 
-pub(super) fn inner_save<T>(
+// creates the file explorer used by the save dialog (without showing it)
+fn save_dialog<T>(
     title: &str,
     file_name: &str,
     location: Location,
     extension_mask: Option<&str>,
     flags: SaveFileDialogFlags,
     nav: T,
-) -> Option<PathBuf>
+) -> FileExplorer<T>
 where
     T: crate::utils::Navigator<crate::utils::fs::Entry, crate::utils::fs::Root, PathBuf> + 'static,
 {
@@ -354,13 +390,7 @@ where
             if flags.contains(SaveFileDialogFlags::ValidateOverwrite) {
                 inner_flags |= open_save_dialog::InnerFlags::ValidateOverwrite;
             }
-
-            let w = FileExplorer::new(file_name, title, location, mask_list, nav, inner_flags);
-            let result = w.show();
-            match result {
-                Some(OpenSaveDialogResult::Path(path)) => Some(path),
-                _ => None,
-            }
+            FileExplorer::new(file_name, title, location, mask_list, nav, inner_flags)
         }
         Err(err_msg) => {
             panic!(
@@ -370,14 +400,30 @@ where
     }
 }
 
-pub(super) fn inner_open<T>(
+pub(super) fn inner_save<T>(
     title: &str,
     file_name: &str,
     location: Location,
     extension_mask: Option<&str>,
-    flags: OpenFileDialogFlags,
+    flags: SaveFileDialogFlags,
     nav: T,
 ) -> Option<PathBuf>
+where
+    T: crate::utils::Navigator<crate::utils::fs::Entry, crate::utils::fs::Root, PathBuf> + 'static,
+{
+    selected_path(save_dialog(title, file_name, location, extension_mask, flags, nav).show())
+}
+
+// creates the file explorer used by the open dialogs (without showing it)
+fn open_dialog<T>(
+    title: &str,
+    file_name: &str,
+    location: Location,
+    extension_mask: Option<&str>,
+    flags: OpenFileDialogFlags,
+    multiple: bool,
+    nav: T,
+) -> FileExplorer<T>
 where
     T: crate::utils::Navigator<crate::utils::fs::Entry, crate::utils::fs::Root, PathBuf> + 'static,
 {
@@ -391,13 +437,14 @@ where
             if flags.contains(OpenFileDialogFlags::CheckIfFileExists) {
                 inner_flags |= open_save_dialog::InnerFlags::CheckIfFileExists;
             }
-
-            let w = FileExplorer::new(file_name, title, location, mask_list, nav, inner_flags);
-            let result = w.show();
-            match result {
-                Some(OpenSaveDialogResult::Path(path)) => Some(path),
-                _ => None,
+            if flags.contains(OpenFileDialogFlags::Preview) {
+                inner_flags |= open_save_dialog::InnerFlags::Preview;
+            }
+            if multiple {
+                inner_flags |= open_save_dialog::InnerFlags::MultipleOpen;
             }
+
+            FileExplorer::new(file_name, title, location, mask_list, nav, inner_flags)
         }
         Err(err_msg) => {
             panic!(
@@ -407,12 +454,59 @@ where
     }
 }
 
+// the path selected in a save or open dialog
+fn selected_path(result: Option<OpenSaveDialogResult>) -> Option<PathBuf> {
+    match result {
+        Some(OpenSaveDialogResult::Path(path)) => Some(path),
+        _ => None,
+    }
+}
+
+// the paths selected in an open dialog that allows multiple selection
+fn selected_paths(result: Option<OpenSaveDialogResult>) -> Vec<PathBuf> {
+    match result {
+        Some(OpenSaveDialogResult::Path(path)) => vec![path],
+        Some(OpenSaveDialogResult::MultiplePaths(paths)) => paths,
+        _ => Vec::new(),
+    }
+}
+
+pub(super) fn inner_open<T>(
+    title: &str,
+    file_name: &str,
+    location: Location,
+    extension_mask: Option<&str>,
+    flags: OpenFileDialogFlags,
+    nav: T,
+) -> Option<PathBuf>
+where
+    T: crate::utils::Navigator<crate::utils::fs::Entry, crate::utils::fs::Root, PathBuf> + 'static,
+{
+    selected_path(open_dialog(title, file_name, location, extension_mask, flags, false, nav).show())
+}
+
+pub(super) fn inner_open_multiple<T>(
+    title: &str,
+    location: Location,
+    extension_mask: Option<&str>,
+    flags: OpenFileDialogFlags,
+    nav: T,
+) -> Vec<PathBuf>
+where
+    T: crate::utils::Navigator<crate::utils::fs::Entry, crate::utils::fs::Root, PathBuf> + 'static,
+{
+    selected_paths(open_dialog(title, "", location, extension_mask, flags, true, nav).show())
+}
+
 pub(super) fn inner_select_folder<T>(title: &str, location: Location, flags: SelectFolderDialogFlags, nav: T) -> Option<PathBuf>
 where
     T: crate::utils::Navigator<crate::utils::fs::Entry, crate::utils::fs::Root, PathBuf> + 'static,
 {
-    let w = FolderExplorer::new(title, location, nav, flags);
-    let result = w.show();
+    selected_folder(FolderExplorer::new(title, location, nav, flags).show())
+}
+
+// the folder selected in a select folder dialog
+fn selected_folder(result: Option<FolderSelectionDialogResult>) -> Option<PathBuf> {
     match result {
         Some(FolderSelectionDialogResult::Path(path)) => Some(path),
         _ => None,
@@ -482,6 +576,31 @@ pub fn open(title: &str, file_name: &str, location: Location, extension_mask: Op
     inner_open(title, file_name, location, extension_mask, flags, utils::fs::Navigator::new())
 }
 
+/// Opens a file dialog for selecting one or more files and returns the paths of the selected files (or an empty vector if the user canceled the operation).
+/// Files are selected using the checkboxes from the list (`Space` or `Insert` toggles the current file, `Shift` + arrow keys selects a range).
+/// If no file is checked, the file from the name field is returned.
+/// # Arguments
+/// * `title` - The title of the dialog.
+/// * `location` - The initial location of the dialog (one of Current, Last or Path). If Last is used, the dialog will open in the last location used by the user.
+/// * `extension_mask` - A string that specifies the file extensions that can be selected by the user. The format is `name1 = [ext1, ext2, ... extn], name2 = [...], ...`. If None is provided, all files will be displayed.
+/// * `flags` - Flags that specify the behavior of the dialog (use `OpenFileDialogFlags::Preview` to display a preview pane for the current file - images are previewed only for uncompressed 24 or 32 bits BMP files).
+///
+/// # Example
+/// ```rust,no_run
+/// use appcui::dialogs;
+///
+/// let files = dialogs::open_multiple("Open files",
+///                                    dialogs::Location::Current,
+///                                    Some("Text files = [txt]"),
+///                                    dialogs::OpenFileDialogFlags::Icons | dialogs::OpenFileDialogFlags::Preview);
+/// for path in files {
+///     println!("File opened: {:?}", path);
+/// }
+/// ```
+pub fn open_multiple(title: &str, location: Location, extension_mask: Option<&str>, flags: OpenFileDialogFlags) -> Vec<PathBuf> {
+    inner_open_multiple(title, location, extension_mask, flags, utils::fs::Navigator::new())
+}
+
 /// Opens a dialog for selecting a folder and returns the path of the folder selected by the user or None if the user canceled the operation.
 /// # Arguments
 /// * `title` - The title of the dialog.
@@ -529,3 +648,72 @@ where
 {
     StringImputDialog::new(title, text, value, validation).show()
 }
+
+/// Opens a form dialog for editing an object that implements the [`FormData`] trait (usually implemented via the `#[derive(Form)]` macro).
+/// Returns the updated object if the user pressed `OK` or `None` if the user canceled the operation.
+///
+/// # Example
+/// ```rust,no_run
+/// use appcui::prelude::*;
+///
+/// #[derive(Form)]
+/// struct Connection {
+///     #[Field(label: "&Host", required)]
+///     host: String,
+///     #[Field(label: "&Port", min: 1, max: 65535)]
+///     port: u16,
+///     #[Field(label: "Use &SSL")]
+///     ssl: bool,
+/// }
+///
+/// let c = Connection { host: String::from("localhost"), port: 22, ssl: true };
+/// if let Some(c) = dialogs::form("Connection", c) {
+///     // c contains the values entered by the user
+/// }
+/// ```
+pub fn form<T: FormData>(title: &str, mut value: T) -> Option<T> {
+    let values = value.form(title).show()?;
+    value.update(&values);
+    Some(value)
+}
+
+/// Runs a task on a background thread and shows a modal dialog with its progress, status and estimated time until completion.
+/// The task receives a [`ProgressConnector`] that can be used to report the progress (via `progress(done, total)`),
+/// to change the status text (via `status(text)`) and to check if the user canceled the operation (via `should_stop()`).
+///
+/// The dialog has a **Cancel** button (the same as pressing `Escape`) and, if the application has an app bar, a **Background**
+/// button that closes the dialog and shows the progress of the task in the app bar.
+///
+/// Returns:
+/// * `ProgressResult::Completed(value)` - if the task finished and returned `value`
+/// * `ProgressResult::Canceled` - if the user canceled the task
+/// * `ProgressResult::Background(task)` - if the task was moved in background (the result can be obtained later via `task.take_result()`)
+/// * `ProgressResult::Failed(message)` - if the task panicked (`message` is the panic message)
+///
+/// # Example
+/// ```rust,no_run
+/// use appcui::prelude::*;
+///
+/// let result = dialogs::progress("Copying", |p| {
+///     let files = 100;
+///     for i in 0..files {
+///         if p.should_stop() {
+///             return i;
+///         }
+///         p.status(&format!("Copying file {} of {}", i + 1, files));
+///         p.progress(i, files);
+///         std::thread::sleep(std::time::Duration::from_millis(50));
+///     }
+///     files
+/// });
+/// if let dialogs::ProgressResult::Completed(count) = result {
+///     // count files were copied
+/// }
+/// ```
+pub fn progress<R, F>(title: &str, task: F) -> ProgressResult<R>
+where
+    R: Send + 'static,
+    F: FnOnce(&ProgressConnector) -> R + Send + 'static,
+{
+    progress_dialog::run(title, task)
+}
@@ -0,0 +1,137 @@
+use super::dialog_buttons::DialogButtons;
+use super::dialog_result::DialogResult;
+use super::folder_select_dialog::FolderExplorer;
+use super::form_dialog::FormDialog;
+use super::generic_alert_dialog::GenericAlertDialog;
+use super::input_dialog::StringImputDialog;
+use super::{FormData, Location, OpenFileDialogFlags, SaveFileDialogFlags, SelectFolderDialogFlags, ValidateOrCancelResult};
+use crate::prelude::{window, ModalWindow};
+use crate::utils::{self, Navigator};
+use std::future::Future;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+// the dialog is opened right away (without a modal loop) and the returned future resolves when it is closed
+fn show_alert(
+    title: &str,
+    caption: &str,
+    buttons: DialogButtons,
+    background: window::Background,
+) -> impl Future<Output = Option<DialogResult>> + 'static {
+    ModalWindow::show_async(GenericAlertDialog::new(title, caption, buttons, background))
+}
+
+/// Async version of [`error`](super::error).
+pub fn error_async(title: &str, caption: &str) -> impl Future<Output = ()> + 'static {
+    let result = show_alert(title, caption, DialogButtons::Ok, window::Background::Error);
+    async move {
+        result.await;
+    }
+}
+
+/// Async version of [`retry`](super::retry).
+pub fn retry_async(title: &str, caption: &str) -> impl Future<Output = bool> + 'static {
+    let result = show_alert(title, caption, DialogButtons::RetryCancel, window::Background::Error);
+    async move { result.await == Some(DialogResult::Retry) }
+}
+
+/// Async version of [`alert`](super::alert).
+pub fn alert_async(title: &str, caption: &str) -> impl Future<Output = ()> + 'static {
+    let result = show_alert(title, caption, DialogButtons::Ok, window::Background::Warning);
+    async move {
+        result.await;
+    }
+}
+
+/// Async version of [`proceed`](super::proceed).
+pub fn proceed_async(title: &str, caption: &str) -> impl Future<Output = bool> + 'static {
+    let result = show_alert(title, caption, DialogButtons::YesNo, window::Background::Warning);
+    async move { result.await == Some(DialogResult::Yes) }
+}
+
+/// Async version of [`message`](super::message).
+pub fn message_async(title: &str, caption: &str) -> impl Future<Output = ()> + 'static {
+    let result = show_alert(title, caption, DialogButtons::Ok, window::Background::Notification);
+    async move {
+        result.await;
+    }
+}
+
+/// Async version of [`validate`](super::validate).
+pub fn validate_async(title: &str, caption: &str) -> impl Future<Output = bool> + 'static {
+    let result = show_alert(title, caption, DialogButtons::YesNo, window::Background::Notification);
+    async move { result.await == Some(DialogResult::Yes) }
+}
+
+/// Async version of [`validate_or_cancel`](super::validate_or_cancel).
+pub fn validate_or_cancel_async(title: &str, caption: &str) -> impl Future<Output = ValidateOrCancelResult> + 'static {
+    let result = show_alert(title, caption, DialogButtons::YesNoCancel, window::Background::Notification);
+    async move { super::validate_or_cancel_result(result.await) }
+}
+
+/// Async version of [`save`](super::save).
+pub fn save_async(
+    title: &str,
+    file_name: &str,
+    location: Location,
+    extension_mask: Option<&str>,
+    flags: SaveFileDialogFlags,
+) -> impl Future<Output = Option<PathBuf>> + 'static {
+    let w = super::save_dialog(title, file_name, location, extension_mask, flags, utils::fs::Navigator::new());
+    let result = ModalWindow::show_async(w);
+    async move { super::selected_path(result.await) }
+}
+
+/// Async version of [`open`](super::open).
+pub fn open_async(
+    title: &str,
+    file_name: &str,
+    location: Location,
+    extension_mask: Option<&str>,
+    flags: OpenFileDialogFlags,
+) -> impl Future<Output = Option<PathBuf>> + 'static {
+    let w = super::open_dialog(title, file_name, location, extension_mask, flags, false, utils::fs::Navigator::new());
+    let result = ModalWindow::show_async(w);
+    async move { super::selected_path(result.await) }
+}
+
+/// Async version of [`open_multiple`](super::open_multiple).
+pub fn open_multiple_async(
+    title: &str,
+    location: Location,
+    extension_mask: Option<&str>,
+    flags: OpenFileDialogFlags,
+) -> impl Future<Output = Vec<PathBuf>> + 'static {
+    let w = super::open_dialog(title, "", location, extension_mask, flags, true, utils::fs::Navigator::new());
+    let result = ModalWindow::show_async(w);
+    async move { super::selected_paths(result.await) }
+}
+
+/// Async version of [`select_folder`](super::select_folder).
+pub fn select_folder_async(title: &str, location: Location, flags: SelectFolderDialogFlags) -> impl Future<Output = Option<PathBuf>> + 'static {
+    let result = ModalWindow::show_async(FolderExplorer::new(title, location, utils::fs::Navigator::new(), flags));
+    async move { super::selected_folder(result.await) }
+}
+
+/// Async version of [`input`](super::input).
+pub fn input_async<T>(
+    title: &str,
+    text: &str,
+    value: Option<T>,
+    validation: Option<super::InputCallback<T>>,
+) -> impl Future<Output = Option<T>> + 'static
+where
+    T: FromStr + Sized + std::fmt::Display + 'static,
+{
+    ModalWindow::show_async(StringImputDialog::new(title, text, value, validation))
+}
+
+/// Async version of [`form`](super::form).
+pub fn form_async<T: FormData + 'static>(title: &str, mut value: T) -> impl Future<Output = Option<T>> + 'static {
+    let result = ModalWindow::show_async(FormDialog::new(value.form(title)));
+    async move {
+        let values = result.await?;
+        value.update(&values);
+        Some(value)
+    }
+}
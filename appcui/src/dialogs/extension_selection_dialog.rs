@@ -10,26 +10,39 @@ pub(super) struct ExtensionSelectionDialog {
 
 impl ExtensionSelectionDialog {
     pub(super) fn new(file_mask: &FileMask) -> Self {
+        let locale = Locale::current();
         let mut me = Self {
-            base: ModalWindow::new("Select Extension", layout!("a:c,w:50,h:9"), window::Flags::NoCloseButton),
+            base: ModalWindow::new(
+                locale.text("filedialog.select_extension"),
+                layout!("a:c,w:50,h:9"),
+                window::Flags::NoCloseButton,
+            ),
             combo: Handle::None,
             btn_ok: Handle::None,
             btn_cancel: Handle::None,
         };
         
-        me.add(Label::new("Please select an extension to add to the file:", layout!("l:1,t:1,r:1,h:1")));
+        me.add(Label::new(locale.text("filedialog.select_extension_text"), layout!("l:1,t:1,r:1,h:1")));
         
         let mut combo = ComboBox::new(layout!("l:1,t:3,r:1,h:1"), combobox::Flags::None);        
         for i in 0..file_mask.extensions_count() {
             combo.add(file_mask.extension(i));
         }
-        combo.add("Do Nothing (keep the file as it is)");
+        combo.add(locale.text("filedialog.keep_extension"));
         combo.set_index(0);
         
         me.combo = me.add(combo);
         
-        me.btn_ok = me.add(Button::with_type("&OK", layout!("l:12,b:0,w:13"), button::Type::Normal));
-        me.btn_cancel = me.add(Button::with_type("&Cancel", layout!("l:26,b:0,w:13"), button::Type::Normal));
+        me.btn_ok = me.add(Button::with_type(
+            locale.text("filedialog.ok"),
+            layout!("l:12,b:0,w:13"),
+            button::Type::Normal,
+        ));
+        me.btn_cancel = me.add(Button::with_type(
+            locale.text("dialog.cancel"),
+            layout!("l:26,b:0,w:13"),
+            button::Type::Normal,
+        ));
         
         let combo_handle = me.combo;
         me.request_focus_for_control(combo_handle);
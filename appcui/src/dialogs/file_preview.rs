@@ -0,0 +1,54 @@
+use crate::graphics::Image;
+
+pub(super) const PREVIEW_MAX_SIZE: usize = 0x2000;
+pub(super) const IMAGE_PREVIEW_MAX_SIZE: usize = 0x40_0000;
+const PREVIEW_MAX_LINES: usize = 100;
+
+pub(super) enum FilePreview {
+    Text(String),
+    Binary(Vec<u8>),
+    Image(Image),
+}
+
+impl FilePreview {
+    pub(super) fn is_image(name: &str) -> bool {
+        name.rsplit_once('.').is_some_and(|(_, ext)| ext.eq_ignore_ascii_case("bmp"))
+    }
+    // reads (via `read`, that returns at most the requested number of bytes from the start of the file) only
+    // the part of the file needed for its preview - for images the header is read first, so that unsupported
+    // or very large images are never loaded while the user moves through the file list
+    pub(super) fn load(name: &str, read: impl Fn(usize) -> Option<Vec<u8>>) -> Option<Self> {
+        if Self::is_image(name) {
+            let header = read(Image::BMP_HEADER_SIZE)?;
+            if let Some(size) = Image::bmp_file_size(&header).filter(|size| *size <= IMAGE_PREVIEW_MAX_SIZE) {
+                return Some(Self::new(name, read(size)?));
+            }
+        }
+        Some(Self::new(name, read(PREVIEW_MAX_SIZE)?))
+    }
+    pub(super) fn new(name: &str, mut content: Vec<u8>) -> Self {
+        if Self::is_image(name) {
+            if let Some(img) = Image::from_bmp(&content) {
+                return FilePreview::Image(img);
+            }
+        }
+        content.truncate(PREVIEW_MAX_SIZE);
+        if let Some(text) = Self::text(&content) {
+            FilePreview::Text(text)
+        } else {
+            FilePreview::Binary(content)
+        }
+    }
+    fn text(content: &[u8]) -> Option<String> {
+        let s = match std::str::from_utf8(content) {
+            Ok(s) => s,
+            // the content might be truncated in the middle of an UTF-8 character
+            Err(e) if e.error_len().is_none() => std::str::from_utf8(&content[..e.valid_up_to()]).ok()?,
+            Err(_) => return None,
+        };
+        if s.chars().any(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t')) {
+            return None;
+        }
+        Some(s.lines().take(PREVIEW_MAX_LINES).collect::<Vec<&str>>().join("\n"))
+    }
+}
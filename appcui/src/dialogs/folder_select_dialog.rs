@@ -66,7 +66,8 @@ where
             }
             Location::Path(p) => p.to_path_buf(),
         };
-        w.add(label!("&Path,x:1,y:1,w:4"));
+        let locale = Locale::current();
+        w.add(Label::new(locale.text("filedialog.path"), layout!("x:1,y:1,w:4")));
         let mut pf = GenericPathFinder::with_navigator(
             w.path.as_path().as_os_str().to_str().unwrap_or(""),
             layout!("l:6,t:1,r:1"),
@@ -93,8 +94,8 @@ where
         tv.sort(0, true);
         w.tv = p.add(tv);
         w.add(p);
-        w.b_ok = w.add(button!("&OK,r:1,b:0,w:13"));
-        w.b_cancel = w.add(button!("&Cancel,r:15,b:0,w:13"));
+        w.b_ok = w.add(Button::new(locale.text("filedialog.ok"), layout!("r:1,b:0,w:13")));
+        w.b_cancel = w.add(Button::new(locale.text("dialog.cancel"), layout!("r:15,b:0,w:13")));
         w.set_size_bounds(40, 17, u16::MAX, u16::MAX);
         let h = w.tv;
         w.request_focus_for_control(h);
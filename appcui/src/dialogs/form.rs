@@ -0,0 +1,325 @@
+use super::form_dialog::FormDialog;
+use crate::prelude::*;
+use chrono::{NaiveDate, NaiveTime};
+
+/// A validation function for a form field. It receives the value of the field and returns `Ok(())` if the value is valid
+/// or `Err(message)` with the message that will be displayed (inline) under the field otherwise.
+pub type FieldValidator = fn(&FieldValue) -> Result<(), String>;
+
+/// The value of a field from a form (as returned by the [`Form::show`] method).
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldValue {
+    Text(String),
+    Number(i64),
+    Bool(bool),
+    Enum(Option<u32>),
+    Date(NaiveDate),
+    Time(NaiveTime),
+}
+
+impl FieldValue {
+    /// Returns the text of a text or password field or `None` for other field types.
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            FieldValue::Text(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+    /// Returns the value of a numeric field or `None` for other field types.
+    pub fn as_number(&self) -> Option<i64> {
+        match self {
+            FieldValue::Number(v) => Some(*v),
+            _ => None,
+        }
+    }
+    /// Returns the state of a boolean field or `None` for other field types.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            FieldValue::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+    /// Returns the variant selected in an enum field or `None` if no variant was selected (or if the field is not an enum field).
+    pub fn as_enum<E: EnumSelector>(&self) -> Option<E> {
+        match self {
+            FieldValue::Enum(Some(index)) => E::from_index(*index),
+            _ => None,
+        }
+    }
+    /// Returns the value of a date field or `None` for other field types.
+    pub fn as_date(&self) -> Option<NaiveDate> {
+        match self {
+            FieldValue::Date(v) => Some(*v),
+            _ => None,
+        }
+    }
+    /// Returns the value of a time field or `None` for other field types.
+    pub fn as_time(&self) -> Option<NaiveTime> {
+        match self {
+            FieldValue::Time(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+/// The values of all fields from a form, indexed by the name of the field.
+#[derive(Clone, Debug, Default)]
+pub struct FormValues {
+    values: Vec<(String, FieldValue)>,
+}
+
+impl FormValues {
+    pub(super) fn add(&mut self, name: &str, value: FieldValue) {
+        self.values.push((name.to_string(), value));
+    }
+    /// Returns the value of the field with the specified name or `None` if the form has no such field.
+    pub fn get(&self, name: &str) -> Option<&FieldValue> {
+        self.values.iter().find(|(n, _)| n == name).map(|(_, v)| v)
+    }
+    /// Returns the text of a text or password field.
+    pub fn text(&self, name: &str) -> Option<&str> {
+        self.get(name)?.as_text()
+    }
+    /// Returns the value of a numeric field.
+    pub fn number(&self, name: &str) -> Option<i64> {
+        self.get(name)?.as_number()
+    }
+    /// Returns the state of a boolean field.
+    pub fn bool(&self, name: &str) -> Option<bool> {
+        self.get(name)?.as_bool()
+    }
+    /// Returns the variant selected in an enum field (or `None` if no variant was selected).
+    pub fn enumeration<E: EnumSelector>(&self, name: &str) -> Option<E> {
+        self.get(name)?.as_enum::<E>()
+    }
+    /// Returns the value of a date field.
+    pub fn date(&self, name: &str) -> Option<NaiveDate> {
+        self.get(name)?.as_date()
+    }
+    /// Returns the value of a time field.
+    pub fn time(&self, name: &str) -> Option<NaiveTime> {
+        self.get(name)?.as_time()
+    }
+    /// Returns the number of fields.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+    /// Returns `true` if the form has no fields.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// A type that can be edited through a form dialog. This trait is usually implemented via the `#[derive(Form)]` macro.
+pub trait FormData {
+    /// Creates the form (the list of fields) used to edit the current object.
+    fn form(&self, title: &str) -> Form;
+    /// Updates the current object with the values returned by the form.
+    fn update(&mut self, values: &FormValues);
+}
+
+pub(super) type EnumControlBuilder = fn(&mut FormDialog, Option<u32>, Layout, bool) -> Handle<()>;
+pub(super) type EnumValueReader = fn(&FormDialog, Handle<()>) -> Option<u32>;
+
+pub(super) enum FieldKind {
+    Text(String),
+    Password(String),
+    Number(i64),
+    Bool(bool),
+    Enum {
+        value: Option<u32>,
+        create: EnumControlBuilder,
+        read: EnumValueReader,
+    },
+    Date(NaiveDate),
+    Time(NaiveTime),
+}
+
+pub(super) struct FormField {
+    pub(super) name: String,
+    pub(super) label: String,
+    pub(super) kind: FieldKind,
+    pub(super) required: bool,
+    pub(super) range: Option<(i64, i64)>,
+    pub(super) validator: Option<FieldValidator>,
+}
+
+impl FormField {
+    pub(super) fn validate(&self, value: &FieldValue) -> Result<(), String> {
+        if self.required {
+            match value {
+                FieldValue::Text(s) if s.trim().is_empty() => return Err(Locale::current().text("form.required").to_string()),
+                FieldValue::Bool(false) => return Err(Locale::current().text("form.must_be_checked").to_string()),
+                FieldValue::Enum(None) => return Err(Locale::current().text("form.must_be_selected").to_string()),
+                _ => {}
+            }
+        }
+        if let Some((min, max)) = self.range {
+            match value {
+                FieldValue::Number(v) if *v < min || *v > max => {
+                    return Err(Self::range_message("form.range", min, max));
+                }
+                FieldValue::Text(s) => {
+                    let len = s.chars().count() as i64;
+                    // an empty (optional) field is not checked against the range
+                    if (len < min || len > max) && (len != 0 || self.required) {
+                        return Err(Self::range_message("form.length", min, max));
+                    }
+                }
+                _ => {}
+            }
+        }
+        if let Some(validator) = self.validator {
+            validator(value)?;
+        }
+        Ok(())
+    }
+    fn range_message(id: &str, min: i64, max: i64) -> String {
+        Locale::current()
+            .text(id)
+            .replace("{min}", &min.to_string())
+            .replace("{max}", &max.to_string())
+    }
+}
+
+fn add_selector<E>(dialog: &mut FormDialog, value: Option<u32>, layout: Layout, allow_none: bool) -> Handle<()>
+where
+    E: EnumSelector + Copy + Eq + 'static,
+{
+    let flags = if allow_none {
+        selector::Flags::AllowNoneVariant
+    } else {
+        selector::Flags::None
+    };
+    dialog.add(Selector::<E>::new(value.and_then(E::from_index), layout, flags)).cast()
+}
+
+fn selector_index<E>(dialog: &FormDialog, handle: Handle<()>) -> Option<u32>
+where
+    E: EnumSelector + Copy + Eq + 'static,
+{
+    let value = dialog.control(handle.cast::<Selector<E>>())?.try_value()?;
+    (0..E::COUNT).find(|i| E::from_index(*i) == Some(value))
+}
+
+/// A builder for a modal dialog with multiple (typed) fields.
+///
+/// Each field has a unique name (used to retrieve its value) and a label (displayed in front of the field).
+/// The `required`, `range` and `validator` methods apply to the last added field. When the user presses
+/// the `OK` button every field is validated and the error messages are displayed under each invalid field.
+///
+/// # Example
+/// ```rust,no_run
+/// use appcui::prelude::*;
+///
+/// let result = dialogs::Form::new("New connection")
+///     .text("host", "&Host", "localhost").required()
+///     .number("port", "&Port", 22).range(1, 65535)
+///     .text("user", "&User", "").required()
+///     .password("password", "Pass&word", "")
+///     .bool("ssl", "Use &SSL", true)
+///     .show();
+/// if let Some(values) = result {
+///     let host = values.text("host").unwrap();
+///     let port = values.number("port").unwrap();
+/// }
+/// ```
+pub struct Form {
+    pub(super) title: String,
+    pub(super) fields: Vec<FormField>,
+}
+
+impl Form {
+    /// Creates a new (empty) form with the specified title.
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            fields: Vec::new(),
+        }
+    }
+    fn add_field(mut self, name: &str, label: &str, kind: FieldKind) -> Self {
+        if self.fields.iter().any(|f| f.name == name) {
+            panic!("A field with the name '{name}' already exists in the form !");
+        }
+        self.fields.push(FormField {
+            name: name.to_string(),
+            label: label.to_string(),
+            kind,
+            required: false,
+            range: None,
+            validator: None,
+        });
+        self
+    }
+    fn last_field(&mut self) -> &mut FormField {
+        self.fields
+            .last_mut()
+            .expect("You need to add a field to the form before setting its validation rules !")
+    }
+    /// Adds a text field (a `TextField` control) with the specified name, label and initial value.
+    pub fn text(self, name: &str, label: &str, value: &str) -> Self {
+        self.add_field(name, label, FieldKind::Text(value.to_string()))
+    }
+    /// Adds a password field (a `Password` control) with the specified name, label and initial value.
+    pub fn password(self, name: &str, label: &str, value: &str) -> Self {
+        self.add_field(name, label, FieldKind::Password(value.to_string()))
+    }
+    /// Adds a numeric field (a `NumericSelector` control) with the specified name, label and initial value.
+    /// If a range is set (via the `range` method), the values of the selector will be limited to that range.
+    pub fn number(self, name: &str, label: &str, value: i64) -> Self {
+        self.add_field(name, label, FieldKind::Number(value))
+    }
+    /// Adds a boolean field (a `CheckBox` control) with the specified name, label (used as the caption of the checkbox) and initial state.
+    pub fn bool(self, name: &str, label: &str, value: bool) -> Self {
+        self.add_field(name, label, FieldKind::Bool(value))
+    }
+    /// Adds an enum field (a `Selector` control) with the specified name, label and initial value.
+    /// If the field is not required (or the initial value is `None`) the selector will also allow the `None` variant.
+    pub fn enumeration<E>(self, name: &str, label: &str, value: Option<E>) -> Self
+    where
+        E: EnumSelector + Copy + Eq + 'static,
+    {
+        let index = value.and_then(|v| (0..E::COUNT).find(|i| E::from_index(*i) == Some(v)));
+        self.add_field(
+            name,
+            label,
+            FieldKind::Enum {
+                value: index,
+                create: add_selector::<E>,
+                read: selector_index::<E>,
+            },
+        )
+    }
+    /// Adds a date field (a `DatePicker` control) with the specified name, label and initial value.
+    pub fn date(self, name: &str, label: &str, value: NaiveDate) -> Self {
+        self.add_field(name, label, FieldKind::Date(value))
+    }
+    /// Adds a time field (a `TimePicker` control) with the specified name, label and initial value.
+    pub fn time(self, name: &str, label: &str, value: NaiveTime) -> Self {
+        self.add_field(name, label, FieldKind::Time(value))
+    }
+    /// Marks the last added field as required. A required text field can not be empty, a required boolean field must be checked
+    /// and a required enum field must have a value selected.
+    pub fn required(mut self) -> Self {
+        self.last_field().required = true;
+        self
+    }
+    /// Sets a range for the last added field. For numeric fields the value must be between `min` and `max` (inclusive) and
+    /// for text or password fields the number of characters must be between `min` and `max`. The range is ignored for other field types.
+    pub fn range(mut self, min: i64, max: i64) -> Self {
+        if min > max {
+            panic!("Invalid range: min ({min}) must be smaller or equal to max ({max}) !");
+        }
+        self.last_field().range = Some((min, max));
+        self
+    }
+    /// Sets a custom validation function for the last added field. The validator is called after the `required` and `range` checks.
+    pub fn validator(mut self, validator: FieldValidator) -> Self {
+        self.last_field().validator = Some(validator);
+        self
+    }
+    /// Shows the form (as a modal dialog) and returns the values of all fields if the user pressed `OK` or `None` if the dialog was canceled.
+    pub fn show(self) -> Option<FormValues> {
+        FormDialog::new(self).show()
+    }
+}
@@ -0,0 +1,195 @@
+use super::form::{FieldKind, FieldValue, Form, FormField, FormValues};
+use crate::prelude::*;
+use crate::system::RuntimeManager;
+use chrono::Timelike;
+
+const CONTROL_WIDTH: i32 = 30;
+const BUTTON_WIDTH: i32 = 13;
+
+#[CustomControl(overwrite = OnPaint, internal = true)]
+pub(super) struct ErrorLabel {
+    text: String,
+}
+impl ErrorLabel {
+    fn new(layout: Layout) -> Self {
+        Self {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Visible | StatusFlags::Enabled),
+            text: String::new(),
+        }
+    }
+    fn set_text(&mut self, text: &str) {
+        self.text.clear();
+        self.text.push_str(text);
+    }
+}
+impl OnPaint for ErrorLabel {
+    fn on_paint(&self, surface: &mut Surface, theme: &Theme) {
+        surface.write_string(0, 0, &self.text, theme.text.error, false);
+    }
+}
+
+#[ModalWindow(internal: true, response: FormValues, events: ButtonEvents+TextFieldEvents+PasswordEvents)]
+pub(super) struct FormDialog {
+    fields: Vec<FormField>,
+    controls: Vec<(Handle<()>, Handle<ErrorLabel>)>,
+    btn_ok: Handle<Button>,
+}
+
+impl FormDialog {
+    fn label_width(label: &str) -> i32 {
+        let count = label.chars().count() as i32;
+        if label.contains('&') {
+            count - 1
+        } else {
+            count
+        }
+    }
+    pub(super) fn new(form: Form) -> Self {
+        let label_width = form
+            .fields
+            .iter()
+            .filter(|f| !matches!(f.kind, FieldKind::Bool(_)))
+            .map(|f| Self::label_width(&f.label))
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let client_width = (label_width + CONTROL_WIDTH + 3)
+            .max(BUTTON_WIDTH * 2 + 6)
+            .max(form.title.chars().count() as i32 + 8);
+        let height = form.fields.len() as u32 * 2 + 5;
+        let layout = LayoutBuilder::new()
+            .alignment(Alignment::Center)
+            .width((client_width + 2) as u32)
+            .height(height)
+            .build();
+        let mut me = Self {
+            base: ModalWindow::new(&form.title, layout, window::Flags::NoCloseButton),
+            fields: Vec::new(),
+            controls: Vec::with_capacity(form.fields.len()),
+            btn_ok: Handle::None,
+        };
+        let x = label_width + 2;
+        for (index, field) in form.fields.iter().enumerate() {
+            let y = 1 + index as i32 * 2;
+            let control_layout = LayoutBuilder::new().left_anchor(x).top_anchor(y).height(1).right_anchor(1).build();
+            if !matches!(field.kind, FieldKind::Bool(_)) {
+                me.add(Label::new(
+                    &field.label,
+                    LayoutBuilder::new().x(1).y(y).width(label_width as u32).height(1).build(),
+                ));
+            }
+            let handle = me.add_field_control(field, control_layout);
+            let error = me.add(ErrorLabel::new(LayoutBuilder::new().left_anchor(x).top_anchor(y + 1).height(1).right_anchor(1).build()));
+            me.controls.push((handle, error));
+        }
+        let center = client_width / 2;
+        let locale = Locale::current();
+        me.btn_ok = me.add(Button::with_type(
+            locale.text("dialog.ok"),
+            LayoutBuilder::new()
+                .left_anchor(center - BUTTON_WIDTH - 1)
+                .bottom_anchor(0)
+                .width(BUTTON_WIDTH as u32)
+                .build(),
+            button::Type::Normal,
+        ));
+        me.add(Button::with_type(
+            locale.text("dialog.cancel"),
+            LayoutBuilder::new().left_anchor(center + 1).bottom_anchor(0).width(BUTTON_WIDTH as u32).build(),
+            button::Type::Normal,
+        ));
+        if let Some((first, _)) = me.controls.first() {
+            RuntimeManager::get().request_focus_for_control(*first);
+        }
+        me.fields = form.fields;
+        me
+    }
+    fn add_field_control(&mut self, field: &FormField, layout: Layout) -> Handle<()> {
+        match &field.kind {
+            FieldKind::Text(value) => self.add(TextField::new(value, layout, textfield::Flags::ProcessEnter)).cast(),
+            FieldKind::Password(value) => {
+                let mut p = Password::new(layout);
+                p.set_password(value);
+                self.add(p).cast()
+            }
+            FieldKind::Number(value) => {
+                let (min, max) = field.range.unwrap_or((i64::MIN, i64::MAX));
+                let value = (*value).clamp(min, max);
+                self.add(NumericSelector::<i64>::new(value, min, max, 1, layout, numericselector::Flags::None))
+                    .cast()
+            }
+            FieldKind::Bool(value) => self.add(CheckBox::new(&field.label, layout, *value)).cast(),
+            FieldKind::Enum { value, create, .. } => create(self, *value, layout, value.is_none() || !field.required),
+            FieldKind::Date(value) => self.add(DatePicker::with_date(*value, layout)).cast(),
+            FieldKind::Time(value) => {
+                let flags = if value.second() != 0 {
+                    timepicker::Flags::Seconds
+                } else {
+                    timepicker::Flags::None
+                };
+                self.add(TimePicker::with_time(*value, layout, flags)).cast()
+            }
+        }
+    }
+    fn field_value(&self, index: usize) -> Option<FieldValue> {
+        let handle = self.controls[index].0;
+        let value = match &self.fields[index].kind {
+            FieldKind::Text(_) => FieldValue::Text(self.control(handle.cast::<TextField>())?.text().to_string()),
+            FieldKind::Password(_) => FieldValue::Text(self.control(handle.cast::<Password>())?.password().to_string()),
+            FieldKind::Number(_) => FieldValue::Number(self.control(handle.cast::<NumericSelector<i64>>())?.value()),
+            FieldKind::Bool(_) => FieldValue::Bool(self.control(handle.cast::<CheckBox>())?.is_checked()),
+            FieldKind::Enum { read, .. } => FieldValue::Enum(read(self, handle)),
+            FieldKind::Date(_) => FieldValue::Date(self.control(handle.cast::<DatePicker>())?.date()),
+            FieldKind::Time(_) => FieldValue::Time(self.control(handle.cast::<TimePicker>())?.time()),
+        };
+        Some(value)
+    }
+    fn validate(&mut self) {
+        let mut values = FormValues::default();
+        let mut first_invalid = None;
+        for index in 0..self.fields.len() {
+            let Some(value) = self.field_value(index) else {
+                continue;
+            };
+            let result = self.fields[index].validate(&value);
+            let error = self.controls[index].1;
+            if let Some(label) = self.control_mut(error) {
+                label.set_text(result.as_ref().err().map(|e| e.as_str()).unwrap_or(""));
+            }
+            if result.is_err() && first_invalid.is_none() {
+                first_invalid = Some(self.controls[index].0);
+            }
+            values.add(&self.fields[index].name, value);
+        }
+        if let Some(handle) = first_invalid {
+            RuntimeManager::get().request_focus_for_control(handle);
+        } else {
+            self.exit_with(values);
+        }
+    }
+}
+
+impl ButtonEvents for FormDialog {
+    fn on_pressed(&mut self, handle: Handle<Button>) -> EventProcessStatus {
+        if handle == self.btn_ok {
+            self.validate();
+        } else {
+            self.exit();
+        }
+        EventProcessStatus::Processed
+    }
+}
+
+impl TextFieldEvents for FormDialog {
+    fn on_validate(&mut self, _: Handle<TextField>, _: &str) -> EventProcessStatus {
+        self.validate();
+        EventProcessStatus::Processed
+    }
+}
+
+impl PasswordEvents for FormDialog {
+    fn on_accept(&mut self, _: Handle<Password>) -> EventProcessStatus {
+        self.validate();
+        EventProcessStatus::Processed
+    }
+}
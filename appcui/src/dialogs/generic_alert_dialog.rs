@@ -112,26 +112,27 @@ impl GenericAlertDialog {
         // add the buttons
         let mut x = ((window_width - (buttons.count() * GenericAlertDialog::BUTTON_SIZE)) >> 1) as i32;
         let y = (window_height - 4) as i32;
+        let locale = Locale::current();
         match buttons {
             DialogButtons::Ok => {
-                w.b_ok = w.add(GenericAlertDialog::add_button("&Ok", x, y));
+                w.b_ok = w.add(GenericAlertDialog::add_button(locale.text("dialog.ok"), x, y));
             }
             DialogButtons::YesNo => {
-                w.b_yes = w.add(GenericAlertDialog::add_button("&Yes", x, y));
+                w.b_yes = w.add(GenericAlertDialog::add_button(locale.text("dialog.yes"), x, y));
                 x += GenericAlertDialog::BUTTON_SIZE as i32;
-                w.b_no = w.add(GenericAlertDialog::add_button("&No", x, y));
+                w.b_no = w.add(GenericAlertDialog::add_button(locale.text("dialog.no"), x, y));
             }
             DialogButtons::YesNoCancel => {
-                w.b_yes = w.add(GenericAlertDialog::add_button("&Yes", x, y));
+                w.b_yes = w.add(GenericAlertDialog::add_button(locale.text("dialog.yes"), x, y));
                 x += GenericAlertDialog::BUTTON_SIZE as i32;
-                w.b_no = w.add(GenericAlertDialog::add_button("&No", x, y));
+                w.b_no = w.add(GenericAlertDialog::add_button(locale.text("dialog.no"), x, y));
                 x += GenericAlertDialog::BUTTON_SIZE as i32;
-                w.b_cancel = w.add(GenericAlertDialog::add_button("&Cancel", x, y));
+                w.b_cancel = w.add(GenericAlertDialog::add_button(locale.text("dialog.cancel"), x, y));
             }
             DialogButtons::RetryCancel => {
-                w.b_retry = w.add(GenericAlertDialog::add_button("&Retry", x, y));
+                w.b_retry = w.add(GenericAlertDialog::add_button(locale.text("dialog.retry"), x, y));
                 x += GenericAlertDialog::BUTTON_SIZE as i32;
-                w.b_cancel = w.add(GenericAlertDialog::add_button("&Cancel", x, y));
+                w.b_cancel = w.add(GenericAlertDialog::add_button(locale.text("dialog.cancel"), x, y));
             }
         }
 
@@ -27,8 +27,13 @@ where
             validation,
         };
         me.add(Label::new(text, layout!("l:1,t:1,r:1,b:5")));
-        me.btn_ok = me.add(Button::with_type("&Ok", layout!("l:5,b:0,w:13"), button::Type::Normal));
-        me.add(Button::with_type("&Cancel", layout!("l:21,b:0,w:13"), button::Type::Normal));
+        let locale = Locale::current();
+        me.btn_ok = me.add(Button::with_type(locale.text("dialog.ok"), layout!("l:5,b:0,w:13"), button::Type::Normal));
+        me.add(Button::with_type(
+            locale.text("dialog.cancel"),
+            layout!("l:21,b:0,w:13"),
+            button::Type::Normal,
+        ));
         let content = if let Some(value) = value {
             format! {"{value}"}
         } else {
@@ -48,12 +53,11 @@ where
                     Ok(value)
                 }
             } else {
-                let msg = format!("Invalid value: '{text}'");
-                Err(msg)
+                Err(Locale::current().text("dialog.invalid_value").replace("{value}", text))
             };
             match result {
                 Ok(value) => self.exit_with(value),
-                Err(err) => dialogs::error("Error", err.as_str()),
+                Err(err) => dialogs::error(Locale::current().text("dialog.error"), err.as_str()),
             }
         }
     }
@@ -3,6 +3,7 @@ use std::sync::{Mutex, OnceLock};
 
 use dialogs::extension_selection_dialog::ExtensionSelectionDialog;
 use dialogs::file_mask::FileMask;
+use dialogs::file_preview::FilePreview;
 use dialogs::root_select_dialog::RootSelectDialog;
 use fs::EntryType;
 
@@ -15,7 +16,7 @@ use EnumBitFlags::EnumBitFlags;
 
 pub(super) enum OpenSaveDialogResult {
     Path(PathBuf),
-    //MultiplePaths(Vec<PathBuf>),
+    MultiplePaths(Vec<PathBuf>),
     Cancel,
 }
 
@@ -26,6 +27,7 @@ pub(super) enum InnerFlags {
     MultipleOpen = 4,
     ValidateOverwrite = 8,
     CheckIfFileExists = 16,
+    Preview = 32,
 }
 
 pub(super) static LAST_PATH: OnceLock<Mutex<Option<PathBuf>>> = OnceLock::new();
@@ -44,6 +46,10 @@ where
     b_cancel: Handle<Button>,
     b_drive: Handle<Button>,
     mask: Handle<ComboBox>,
+    preview_info: Handle<Label>,
+    preview_text: Handle<TextArea>,
+    preview_hex: Handle<BufferView<Vec<u8>>>,
+    preview_image: Handle<ImageViewer>,
     extension_mask: Vec<FileMask>,
     nav: T,
     g_updir: listview::Group,
@@ -58,8 +64,17 @@ where
     T: Navigator<Entry, Root, PathBuf> + 'static,
 {
     pub(super) fn new(file_name: &str, title: &str, location: Location, extension_mask: Vec<FileMask>, nav: T, flags: InnerFlags) -> Self {
+        let has_preview = flags.contains(InnerFlags::Preview);
         let mut w = Self {
-            base: ModalWindow::new(title, layout!("a:c,w:70,h:20"), window::Flags::Sizeable),
+            base: ModalWindow::new(
+                title,
+                if has_preview {
+                    layout!("a:c,w:100,h:24")
+                } else {
+                    layout!("a:c,w:70,h:20")
+                },
+                window::Flags::Sizeable,
+            ),
             list: Handle::None,
             details: Handle::None,
             columns: Handle::None,
@@ -68,6 +83,10 @@ where
             b_cancel: Handle::None,
             b_drive: Handle::None,
             mask: Handle::None,
+            preview_info: Handle::None,
+            preview_text: Handle::None,
+            preview_hex: Handle::None,
+            preview_image: Handle::None,
             path_viewer: Handle::None,
             extension_mask,
             nav: nav.clone(),
@@ -93,7 +112,12 @@ where
             }
             Location::Path(p) => p.to_path_buf(),
         };
-        w.b_drive = w.add(button!("&Drive,x:1,y:1,w:7,type:Flat"));
+        let locale = Locale::current();
+        w.b_drive = w.add(Button::with_type(
+            locale.text("filedialog.drive"),
+            layout!("x:1,y:1,w:7"),
+            button::Type::Flat,
+        ));
         let pf = GenericPathFinder::with_navigator(
             w.path.as_path().as_os_str().to_str().unwrap_or(""),
             layout!("l:9,t:1,r:1"),
@@ -101,7 +125,11 @@ where
             nav,
         );
         w.path_viewer = w.add(pf);
-        let mut p = panel!("l:1,t:3,r:1,b:5");
+        let mut p = if has_preview {
+            panel!("l:1,t:3,r:37,b:5")
+        } else {
+            panel!("l:1,t:3,r:1,b:5")
+        };
         let mut lv: ListView<Entry> = ListView::new(
             layout!("d:f"),
             listview::Flags::SearchBar
@@ -114,35 +142,150 @@ where
                 | if !flags.contains(InnerFlags::MultipleOpen) {
                     listview::Flags::NoSelection
                 } else {
-                    listview::Flags::None
+                    listview::Flags::CheckBoxes
                 },
         );
         lv.set_components_toolbar_margins(2, 0);
-        w.g_updir = lv.add_group("UpDir");
-        w.g_folders = lv.add_group("Folders");
-        w.g_files = lv.add_group("Files");
+        w.g_updir = lv.add_group(locale.text("filedialog.updir"));
+        w.g_folders = lv.add_group(locale.text("filedialog.folders"));
+        w.g_files = lv.add_group(locale.text("filedialog.files"));
         w.list = p.add(lv);
         w.add(p);
-        w.add(label!("&Name,l:1,b:3,w:4"));
+        if has_preview {
+            w.add_preview_panel();
+        }
+        w.add(Label::new(locale.text("filedialog.name"), layout!("l:1,b:3,w:4")));
         let mut fname = TextField::new(file_name, layout!("l:6,b:3,r:11"), textfield::Flags::None);
         fname.set_hotkey(key!("Alt+N"));
         w.name = w.add(fname);
-        w.b_ok = w.add(button!("&OK,r:1,b:2,w:9"));
-        w.add(label!("&Type,l:1,b:1,w:4"));
+        w.b_ok = w.add(Button::new(locale.text("filedialog.ok"), layout!("r:1,b:2,w:9")));
+        w.add(Label::new(locale.text("filedialog.type"), layout!("l:1,b:1,w:4")));
         let mut mask = ComboBox::new(layout!("l:6,b:1,r:11"), combobox::Flags::None);
         for m in &w.extension_mask {
             mask.add(m.name());
         }
-        mask.add("All files");
+        mask.add(locale.text("filedialog.all_files"));
         mask.set_index(0);
         mask.set_hotkey(key!("Alt+T"));
         w.mask = w.add(mask);
-        w.b_cancel = w.add(button!("&Cancel,r:1,b:0,w:9"));
+        w.b_cancel = w.add(Button::new(locale.text("dialog.cancel"), layout!("r:1,b:0,w:9")));
         w.set_size_bounds(40, 17, u16::MAX, u16::MAX);
         let h = w.name;
         w.request_focus_for_control(h);
         w
     }
+    fn add_preview_panel(&mut self) {
+        let mut p = Panel::new(Locale::current().text("filedialog.preview"), layout!("t:3,r:1,w:35,b:5"));
+        self.preview_info = p.add(label!("'',l:0,t:0,r:0,h:2"));
+        let mut tx = TextArea::new("", layout!("l:0,t:2,r:0,b:0"), textarea::Flags::ReadOnly);
+        tx.set_visible(false);
+        self.preview_text = p.add(tx);
+        let mut bv = BufferView::new(layout!("l:0,t:2,r:0,b:0"), bufferview::Flags::ReadOnly | bufferview::Flags::HideHeader);
+        bv.set_visible(false);
+        self.preview_hex = p.add(bv);
+        let mut img = ImageViewer::new(
+            Image::new(1, 1).unwrap(),
+            layout!("l:0,t:2,r:0,b:0"),
+            image::RenderOptionsBuilder::new().character_set(image::CharacterSet::SmallBlocks).build(),
+            imageviewer::Flags::None,
+        );
+        img.set_visible(false);
+        self.preview_image = p.add(img);
+        self.add(p);
+    }
+    fn update_preview(&mut self, entry: Option<Entry>) {
+        if self.preview_info.is_none() {
+            return;
+        }
+        let locale = Locale::current();
+        let created = |e: &Entry| {
+            locale
+                .text("filedialog.created")
+                .replace("{date}", &e.created.format("%Y-%m-%d %H:%M:%S").to_string())
+        };
+        let info = match &entry {
+            Some(e) if e.entry_type == EntryType::File => format!(
+                "{}\n{}",
+                locale.text("filedialog.size").replace("{size}", &e.size.to_string()),
+                created(e)
+            ),
+            Some(e) if e.entry_type == EntryType::Folder => format!("{}\n{}", locale.text("filedialog.folder"), created(e)),
+            _ => String::new(),
+        };
+        let preview = match entry {
+            Some(e) if e.entry_type == EntryType::File => self
+                .nav
+                .join(&self.path, &e)
+                .and_then(|path| FilePreview::load(&e.name, |size| self.nav.read(&path, size))),
+            _ => None,
+        };
+        let h = self.preview_info;
+        if let Some(lb) = self.control_mut(h) {
+            lb.set_caption(&info);
+        }
+        let (mut show_text, mut show_hex, mut show_image) = (false, false, false);
+        match preview {
+            Some(FilePreview::Text(text)) => {
+                let h = self.preview_text;
+                if let Some(tx) = self.control_mut(h) {
+                    tx.set_text(&text);
+                }
+                show_text = true;
+            }
+            Some(FilePreview::Binary(content)) => {
+                let h = self.preview_hex;
+                if let Some(bv) = self.control_mut(h) {
+                    bv.set_buffer(content);
+                }
+                show_hex = true;
+            }
+            Some(FilePreview::Image(img)) => {
+                let h = self.preview_image;
+                if let Some(iv) = self.control_mut(h) {
+                    let available = iv.size();
+                    let mut opt = iv.render_options().clone();
+                    let scale = [
+                        image::Scale::NoScale,
+                        image::Scale::Scale50,
+                        image::Scale::Scale33,
+                        image::Scale::Scale25,
+                        image::Scale::Scale20,
+                        image::Scale::Scale10,
+                    ]
+                    .into_iter()
+                    .find(|s| {
+                        opt.set_scale(*s);
+                        let sz = img.render_size(&opt);
+                        sz.width <= available.width && sz.height <= available.height
+                    })
+                    .unwrap_or(image::Scale::Scale5);
+                    opt.set_scale(scale);
+                    iv.set_render_options(opt);
+                    iv.set_image(img);
+                }
+                show_image = true;
+            }
+            None => {}
+        }
+        let (h_text, h_hex, h_image) = (self.preview_text, self.preview_hex, self.preview_image);
+        if let Some(c) = self.control_mut(h_text) {
+            c.set_visible(show_text);
+        }
+        if let Some(c) = self.control_mut(h_hex) {
+            c.set_visible(show_hex);
+        }
+        if let Some(c) = self.control_mut(h_image) {
+            c.set_visible(show_image);
+        }
+    }
+    // shows an error message (from the locale) about a file
+    fn file_error(id: &str, path: &Path) {
+        let locale = Locale::current();
+        crate::dialogs::error(
+            locale.text("dialog.error"),
+            &locale.text(id).replace("{file}", &path.display().to_string()),
+        );
+    }
     fn update_last_path(&self, last_path: &Path) {
         if let Some(dir) = last_path.parent() {
             let mut new_path = dir.to_path_buf();
@@ -210,6 +353,7 @@ where
             lst.clear_search();
         }
         self.populate();
+        self.update_preview(None);
     }
     fn return_result_from_save(&mut self) {
         // get the file name
@@ -228,7 +372,9 @@ where
             if self.flags.contains(InnerFlags::ValidateOverwrite) {
                 match self.nav.exists(&result) {
                     Some(true) => {
-                        if !crate::dialogs::validate("Overwrite", format!("Do you want to overwrite the file: '{}'", result.display()).as_str()) {
+                        let locale = Locale::current();
+                        let msg = locale.text("filedialog.overwrite").replace("{file}", &result.display().to_string());
+                        if !crate::dialogs::validate(locale.text("filedialog.overwrite_title"), &msg) {
                             return;
                         }
                     }
@@ -236,7 +382,7 @@ where
                         // do nothing - the file does not exist
                     }
                     None => {
-                        crate::dialogs::error("Error", format!("Fail to check if file exists: '{}'", result.display()).as_str());
+                        Self::file_error("filedialog.check_failed", &result);
                         return;
                     }
                 }
@@ -263,18 +409,61 @@ where
             }
             self.exit_with(OpenSaveDialogResult::Path(result));
         } else {
-            crate::dialogs::error(
-                "Error",
-                format!(
-                    "Fail to join current path: '{}' with file name: '{}'",
-                    self.path.display(),
-                    entry.name.as_str()
-                )
-                .as_str(),
-            );
+            let locale = Locale::current();
+            let msg = locale
+                .text("filedialog.join_failed")
+                .replace("{path}", &self.path.display().to_string())
+                .replace("{file}", entry.name.as_str());
+            crate::dialogs::error(locale.text("dialog.error"), &msg);
         }
     }
 
+    fn selected_files(&self) -> Vec<PathBuf> {
+        let mut result = Vec::new();
+        if let Some(lv) = self.control(self.list) {
+            for index in 0..lv.items_count() {
+                if !lv.is_item_selected(index) {
+                    continue;
+                }
+                if let Some(e) = lv.item(index) {
+                    if e.entry_type != EntryType::File {
+                        continue;
+                    }
+                    if let Some(path) = self.nav.join(&self.path, e) {
+                        result.push(path);
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    fn return_result_from_open_multiple(&mut self) {
+        let selected = self.selected_files();
+        if selected.is_empty() {
+            // nothing checked - use the file from the name field
+            self.return_result_from_open();
+            return;
+        }
+        if self.flags.contains(InnerFlags::CheckIfFileExists) {
+            for path in &selected {
+                match self.nav.exists(path) {
+                    Some(true) => {}
+                    Some(false) => {
+                        Self::file_error("filedialog.not_found", path);
+                        return;
+                    }
+                    None => {
+                        Self::file_error("filedialog.check_failed", path);
+                        return;
+                    }
+                }
+            }
+        }
+        self.update_last_path(&selected[0]);
+        self.exit_with(OpenSaveDialogResult::MultiplePaths(selected));
+    }
+
     fn return_result_from_open(&mut self) {
         // get the file name
         let mut entry = Entry::default();
@@ -293,11 +482,11 @@ where
                         // do nothing --> all is good
                     }
                     Some(false) => {
-                        crate::dialogs::error("Error", format!("File '{}' does not exists !", result.display()).as_str());
+                        Self::file_error("filedialog.not_found", &result);
                         return;
                     }
                     None => {
-                        crate::dialogs::error("Error", format!("Fail to check if file exists: '{}'", result.display()).as_str());
+                        Self::file_error("filedialog.check_failed", &result);
                         return;
                     }
                 }
@@ -305,20 +494,19 @@ where
             self.update_last_path(&result);
             self.exit_with(OpenSaveDialogResult::Path(result));
         } else {
-            crate::dialogs::error(
-                "Error",
-                format!(
-                    "Fail to join current path: '{}' with file name: '{}'",
-                    self.path.display(),
-                    entry.name.as_str()
-                )
-                .as_str(),
-            );
+            let locale = Locale::current();
+            let msg = locale
+                .text("filedialog.join_failed")
+                .replace("{path}", &self.path.display().to_string())
+                .replace("{file}", entry.name.as_str());
+            crate::dialogs::error(locale.text("dialog.error"), &msg);
         }
     }
     fn return_result(&mut self) {
         if self.flags.contains(InnerFlags::Save) {
             self.return_result_from_save()
+        } else if self.flags.contains(InnerFlags::MultipleOpen) {
+            self.return_result_from_open_multiple()
         } else {
             self.return_result_from_open()
         }
@@ -413,6 +601,13 @@ where
     }
 
     fn on_current_item_changed(&mut self, handle: Handle<ListView<Entry>>) -> EventProcessStatus {
+        if !self.preview_info.is_none() {
+            let entry = self
+                .control(handle)
+                .and_then(|lv| lv.current_item())
+                .map(|e| Entry::new(e.name(), e.size, e.created, e.entry_type));
+            self.update_preview(entry);
+        }
         let current_item = if let Some(lv) = self.control(handle) {
             if let Some(e) = lv.current_item() {
                 if e.entry_type == EntryType::File {
@@ -0,0 +1,322 @@
+use crate::prelude::*;
+use crate::system::{panic_message, RuntimeManager};
+use crate::ui::appbar::ProgressIndicator;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex, Weak};
+
+type ProgressTask = Box<dyn FnOnce(&ProgressConnector) + Send>;
+// the task only notifies the dialog that the shared progress information has changed
+type Notify = ();
+
+pub(super) struct ProgressInfo {
+    pub(super) title: String,
+    pub(super) done: u64,
+    pub(super) total: u64,
+    pub(super) status: String,
+    pub(super) finished: bool,
+    pub(super) result_taken: bool,
+    // the message of the panic that ended the task
+    pub(super) error: Option<String>,
+}
+impl ProgressInfo {
+    pub(super) fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            done: 0,
+            total: 0,
+            status: String::new(),
+            finished: false,
+            result_taken: false,
+            error: None,
+        }
+    }
+    fn percentage(&self) -> Option<u8> {
+        if self.total == 0 {
+            None
+        } else {
+            Some(((self.done.min(self.total) as u128 * 100) / self.total as u128) as u8)
+        }
+    }
+}
+impl ProgressIndicator for Mutex<ProgressInfo> {
+    fn caption(&self) -> Option<String> {
+        let info = self.lock().ok()?;
+        if info.result_taken {
+            None
+        } else if info.error.is_some() {
+            Some(format!(" {} ", Locale::current().text("progress.failed").replace("{title}", &info.title)))
+        } else if info.finished {
+            Some(format!(" {} ", Locale::current().text("progress.done").replace("{title}", &info.title)))
+        } else if let Some(p) = info.percentage() {
+            Some(format!(" {}: {}% ", info.title, p))
+        } else {
+            Some(format!(" {}: ... ", info.title))
+        }
+    }
+}
+
+/// An object that is used by the task that runs in background (see [`progress`](crate::dialogs::progress)) to report its progress and status to the progress dialog.
+pub struct ProgressConnector<'a> {
+    conector: &'a BackgroundTaskConector<(), ()>,
+    info: Arc<Mutex<ProgressInfo>>,
+}
+impl ProgressConnector<'_> {
+    /// Reports that `done` items out of `total` have been processed so far.
+    /// The progress dialog is only notified when the percentage (or the total number of items) changes.
+    pub fn progress(&self, done: u64, total: u64) {
+        let changed = {
+            let mut info = self.info.lock().unwrap();
+            let old = (info.percentage(), info.total);
+            info.done = done;
+            info.total = total;
+            old != (info.percentage(), info.total)
+        };
+        if changed {
+            self.conector.notify(());
+        }
+    }
+    /// Updates the status text displayed in the progress dialog.
+    pub fn status(&self, text: &str) {
+        let changed = {
+            let mut info = self.info.lock().unwrap();
+            if info.status != text {
+                info.status.clear();
+                info.status.push_str(text);
+                true
+            } else {
+                false
+            }
+        };
+        if changed {
+            self.conector.notify(());
+        }
+    }
+    /// Returns `true` if the user canceled the operation. The task should check this method periodically and return as soon as possible if it returns `true`.
+    pub fn should_stop(&self) -> bool {
+        self.conector.should_stop()
+    }
+}
+
+/// A task that was moved in background (via the **Background** button of the progress dialog).
+/// While this object is alive, the progress of the task is displayed in the app bar.
+pub struct BackgroundProgress<R: Send + 'static> {
+    info: Arc<Mutex<ProgressInfo>>,
+    result: Arc<Mutex<Option<R>>>,
+    task: Handle<BackgroundTask<(), ()>>,
+}
+impl<R: Send + 'static> BackgroundProgress<R> {
+    /// Returns `true` if the task has finished.
+    pub fn is_finished(&self) -> bool {
+        self.info.lock().unwrap().finished
+    }
+    /// Returns the last progress reported by the task as a pair `(done, total)`.
+    pub fn progress(&self) -> (u64, u64) {
+        let info = self.info.lock().unwrap();
+        (info.done, info.total)
+    }
+    /// Returns the last status text reported by the task.
+    pub fn status(&self) -> String {
+        self.info.lock().unwrap().status.clone()
+    }
+    /// Returns the panic message if the task panicked or `None` otherwise.
+    pub fn error(&self) -> Option<String> {
+        self.info.lock().unwrap().error.clone()
+    }
+    /// Requests the task to stop. The task will stop the next time it calls `ProgressConnector::should_stop()`.
+    pub fn cancel(&self) {
+        if let Some(task) = BackgroundTask::from_handle(self.task) {
+            task.stop();
+        }
+    }
+    /// Returns the result of the task if the task has finished or `None` otherwise. Once the result is taken, the indicator from the app bar is removed.
+    pub fn take_result(&self) -> Option<R> {
+        let r = self.result.lock().unwrap().take();
+        if r.is_some() {
+            self.info.lock().unwrap().result_taken = true;
+            RuntimeManager::get().request_update_command_and_app_bars();
+        }
+        r
+    }
+}
+
+/// The result of a progress dialog (see [`progress`](crate::dialogs::progress)).
+pub enum ProgressResult<R: Send + 'static> {
+    /// The task has finished and returned the value
+    Completed(R),
+    /// The user canceled the task
+    Canceled,
+    /// The task was moved in background and it is still running
+    Background(BackgroundProgress<R>),
+    /// The task panicked (the value is the panic message)
+    Failed(String),
+}
+
+pub(super) enum ProgressOutcome {
+    Completed,
+    Canceled,
+    Failed(String),
+    Background(Arc<Mutex<ProgressInfo>>, Handle<BackgroundTask<(), ()>>),
+}
+
+#[ModalWindow(internal: true, response: ProgressOutcome, events: ButtonEvents+WindowEvents+BackgroundTaskEvents<Notify,Notify>)]
+pub(super) struct ProgressDialog {
+    task_fn: Option<ProgressTask>,
+    info: Arc<Mutex<ProgressInfo>>,
+    task: Handle<BackgroundTask<(), ()>>,
+    pb: Handle<ProgressBar>,
+    btn_background: Handle<Button>,
+    btn_cancel: Handle<Button>,
+    canceled: bool,
+}
+
+impl ProgressDialog {
+    pub(super) fn new(title: &str, task_fn: ProgressTask) -> Self {
+        let mut me = Self {
+            base: ModalWindow::new(title, layout!("a:c,w:60,h:8"), window::Flags::NoCloseButton),
+            task_fn: Some(task_fn),
+            info: Arc::new(Mutex::new(ProgressInfo::new(title))),
+            task: Handle::None,
+            pb: Handle::None,
+            btn_background: Handle::None,
+            btn_cancel: Handle::None,
+            canceled: false,
+        };
+        me.pb = me.add(ProgressBar::new(0, layout!("l:1,t:1,r:1,h:2"), progressbar::Flags::None));
+        let locale = Locale::current();
+        if RuntimeManager::get().has_appbar() {
+            me.btn_background = me.add(Button::new(locale.text("dialog.background"), layout!("l:13,b:0,w:14")));
+            me.btn_cancel = me.add(Button::new(locale.text("dialog.cancel"), layout!("l:29,b:0,w:14")));
+        } else {
+            me.btn_cancel = me.add(Button::new(locale.text("dialog.cancel"), layout!("l:21,b:0,w:14")));
+        }
+        me
+    }
+    fn update_progress(&mut self) {
+        let (done, total, status) = {
+            let info = self.info.lock().unwrap();
+            (info.done, info.total, info.status.clone())
+        };
+        let canceled = self.canceled;
+        let h = self.pb;
+        if let Some(pb) = self.control_mut(h) {
+            if pb.count() != total {
+                pb.reset(total);
+            }
+            pb.update_progress(done);
+            if !canceled {
+                pb.update_text(&status);
+            }
+        }
+    }
+    fn cancel(&mut self) {
+        if self.canceled {
+            return;
+        }
+        self.canceled = true;
+        if let Some(task) = BackgroundTask::from_handle(self.task) {
+            task.stop();
+        }
+        let h = self.pb;
+        if let Some(pb) = self.control_mut(h) {
+            pb.update_text(Locale::current().text("dialog.canceling"));
+        }
+        for h in [self.btn_background, self.btn_cancel] {
+            if let Some(b) = self.control_mut(h) {
+                b.set_enabled(false);
+            }
+        }
+    }
+    fn move_to_background(&mut self) {
+        let weak: Weak<Mutex<ProgressInfo>> = Arc::downgrade(&self.info);
+        RuntimeManager::get().get_appbar().add_progress_indicator(weak);
+        if let Some(task) = BackgroundTask::from_handle(self.task) {
+            // the dialog will be closed - no events should be sent to it
+            task.update_control_handle(Handle::None);
+        }
+        self.exit_with(ProgressOutcome::Background(self.info.clone(), self.task));
+    }
+}
+
+impl WindowEvents for ProgressDialog {
+    fn on_activate(&mut self) {
+        if let Some(task_fn) = self.task_fn.take() {
+            let info = self.info.clone();
+            self.task = BackgroundTask::<(), ()>::spawn(
+                move |conector: &BackgroundTaskConector<(), ()>| {
+                    let c = ProgressConnector {
+                        conector,
+                        info: info.clone(),
+                    };
+                    // a panic is reported through the progress information (so that the task is always marked as finished)
+                    let outcome = panic::catch_unwind(AssertUnwindSafe(|| task_fn(&c)));
+                    let mut info = info.lock().unwrap();
+                    if let Err(payload) = outcome {
+                        info.error = Some(panic_message(payload));
+                    }
+                    info.finished = true;
+                },
+                self.handle(),
+            );
+        }
+    }
+    fn on_cancel(&mut self) -> ActionRequest {
+        self.cancel();
+        ActionRequest::Deny
+    }
+}
+
+impl ButtonEvents for ProgressDialog {
+    fn on_pressed(&mut self, handle: Handle<Button>) -> EventProcessStatus {
+        if handle == self.btn_background {
+            self.move_to_background();
+        } else {
+            self.cancel();
+        }
+        EventProcessStatus::Processed
+    }
+}
+
+impl BackgroundTaskEvents<Notify, Notify> for ProgressDialog {
+    fn on_update(&mut self, _: Notify, _: &BackgroundTask<Notify, Notify>) -> EventProcessStatus {
+        self.update_progress();
+        EventProcessStatus::Processed
+    }
+    fn on_finish(&mut self, _: &BackgroundTask<Notify, Notify>) -> EventProcessStatus {
+        let error = self.info.lock().unwrap().error.take();
+        if let Some(error) = error {
+            self.exit_with(ProgressOutcome::Failed(error));
+        } else if self.canceled {
+            self.exit_with(ProgressOutcome::Canceled);
+        } else {
+            self.exit_with(ProgressOutcome::Completed);
+        }
+        EventProcessStatus::Processed
+    }
+    fn on_query(&mut self, _: Notify, _: &BackgroundTask<Notify, Notify>) {}
+}
+
+pub(super) fn run<R, F>(title: &str, task: F) -> ProgressResult<R>
+where
+    R: Send + 'static,
+    F: FnOnce(&ProgressConnector) -> R + Send + 'static,
+{
+    let result = Arc::new(Mutex::new(None));
+    let r = result.clone();
+    let outcome = ProgressDialog::new(
+        title,
+        Box::new(move |c: &ProgressConnector| {
+            let value = task(c);
+            *r.lock().unwrap() = Some(value);
+        }),
+    )
+    .show();
+    match outcome {
+        Some(ProgressOutcome::Completed) => match result.lock().unwrap().take() {
+            Some(value) => ProgressResult::Completed(value),
+            None => ProgressResult::Canceled,
+        },
+        Some(ProgressOutcome::Background(info, task)) => ProgressResult::Background(BackgroundProgress { info, result, task }),
+        Some(ProgressOutcome::Failed(error)) => ProgressResult::Failed(error),
+        _ => ProgressResult::Canceled,
+    }
+}
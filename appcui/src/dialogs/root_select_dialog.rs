@@ -12,13 +12,18 @@ pub(super) struct RootSelectDialog {
 impl RootSelectDialog {
     pub(super) fn new(roots_list: Vec<Root>, icons: bool) -> Self {
         let mut w = Self {
-            base: ModalWindow::new("Devices", layout!("a:c,w:50,h:15"), window::Flags::None),
+            base: ModalWindow::new(
+                Locale::current().text("filedialog.devices"),
+                layout!("a:c,w:50,h:15"),
+                window::Flags::None,
+            ),
             list: Handle::None,
             b_ok: Handle::None,
             b_cancel: Handle::None,
         };
-        w.b_ok = w.add(button!("&Ok,l:11,b:0,w:11"));
-        w.b_cancel = w.add(button!("&Cancel,l:25,b:0,w:11"));
+        let locale = Locale::current();
+        w.b_ok = w.add(Button::new(locale.text("dialog.ok"), layout!("l:11,b:0,w:11")));
+        w.b_cancel = w.add(Button::new(locale.text("dialog.cancel"), layout!("l:25,b:0,w:11")));
         let mut lv = ListView::<Root>::new(
             layout!("d:f"),
             if icons { listview::Flags::LargeIcons } else { listview::Flags::None },
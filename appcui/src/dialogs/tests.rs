@@ -4,7 +4,9 @@ use crate::dialogs;
 use crate::prelude::*;
 
 use super::FileMask;
+#[cfg(target_os = "windows")]
 use super::OpenFileDialogFlags;
+#[cfg(target_os = "windows")]
 use super::SaveFileDialogFlags;
 use super::SelectFolderDialogFlags;
 
@@ -29,6 +31,7 @@ impl ButtonEvents for CallbackWin {
     }
 }
 
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
 static VFS: &str = "
     r,C:\\,1000000,100000,SYSTEM,fixed   
     r,D:\\,123456,123,USB Drive,removable
@@ -54,11 +57,13 @@ static VFS: &str = "
     f,D:\\Windows\\melody.mp3,0,2019-03-12 12:31:55,
 ";
 
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
 static FILE_MASK: &str = "Images = [jpg,png,bmp], 
                           Documents = [txt,docx], 
                           Executable and scripts = [exe,dll,js,py,ps1,sh,bat,cmd], 
                           Presentation = [ppt]";
 
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
 enum OpenSaveTestWindowFlags {
     Save(dialogs::SaveFileDialogFlags),
     Open(dialogs::OpenFileDialogFlags),
@@ -73,6 +78,7 @@ struct OpenSaveTestWindow<'a> {
     mask: Option<&'static str>,
 }
 
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
 impl<'a> OpenSaveTestWindow<'a> {
     fn save(title: &str, file_name: &str, location: dialogs::Location<'a>, save_flags: dialogs::SaveFileDialogFlags) -> Self {
         let mut w = Self {
@@ -143,6 +149,7 @@ struct FolderSelectDialog {
     loc: String,
     flags: SelectFolderDialogFlags,
 }
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
 impl FolderSelectDialog {
     fn new(loc: &str, flags: SelectFolderDialogFlags) -> Self {
         let mut w = Self {
@@ -1600,4 +1607,419 @@ fn check_save_select_extension_not_called_due_all_files_mask() {
         SaveFileDialogFlags::Icons | SaveFileDialogFlags::ValidateOverwrite,
     ));
     a.run();
-}
\ No newline at end of file
+}
+#[cfg(not(target_os = "windows"))]
+static UNIX_VFS: &str = "
+    r,/,1000000,100000,ROOT,fixed
+    d,/home/docs,0,2024-01-10 12:00:00,
+    f,/home/data.bin,6,2023-02-05 09:12:25,\x01\x02\x03ABC
+    f,/home/notes.txt,24,2023-02-05 09:12:25,First line\\nSecond line
+    f,/home/readme.txt,11,2023-02-05 09:12:25,Hello world
+";
+
+#[cfg(not(target_os = "windows"))]
+#[Window(events = ButtonEvents, internal: true)]
+struct OpenMultipleTestWindow {
+    info: Handle<Label>,
+    flags: dialogs::OpenFileDialogFlags,
+}
+
+#[cfg(not(target_os = "windows"))]
+impl OpenMultipleTestWindow {
+    fn new(flags: dialogs::OpenFileDialogFlags) -> Self {
+        let mut w = Self {
+            base: window!("Test, d:f"),
+            info: Handle::None,
+            flags,
+        };
+        w.add(button!("'Press Me',a:c,w:14,h:100%"));
+        w.info = w.add(label!("'',x:0,y:0,w:100%,h:2"));
+        w
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+impl ButtonEvents for OpenMultipleTestWindow {
+    fn on_pressed(&mut self, _handle: Handle<Button>) -> EventProcessStatus {
+        let nav = crate::utils::fs::NavSimulator::with_csv(UNIX_VFS, false, "/home/");
+        let result = dialogs::inner_open_multiple("Open", dialogs::Location::Current, None, self.flags, nav);
+        let txt = format!("{result:?}");
+        let h = self.info;
+        if let Some(info) = self.control_mut(h) {
+            info.set_caption(&txt);
+        }
+        EventProcessStatus::Processed
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn check_open_multiple_with_preview() {
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Initial State')
+        CheckHash(0x1F0107357917C5D4)
+        Key.Pressed(Enter)
+        Paint('2. Show open dialog with preview');
+        CheckHash(0xE31AADF3CE96AB66)
+        Mouse.Click(30,11,left)
+        Paint('3. data.bin (hex preview)');
+        CheckHash(0x5FB83BF20ED7706F)
+        Key.Pressed(Space)
+        Key.Pressed(Down)
+        Paint('4. data.bin checked, notes.txt (text preview)');
+        CheckHash(0xF78071F3972E3E77)
+        Key.Pressed(Insert)
+        Paint('5. notes.txt checked, readme.txt is current');
+        CheckHash(0xA3665510A07FAFDF)
+        Key.Pressed(Enter)
+        Paint('6. [//home/data.bin, //home/notes.txt]');
+        CheckHash(0xF8E863F08DA76C37)
+    ";
+    let mut a = App::debug(110, 30, script).build().unwrap();
+    a.add_window(OpenMultipleTestWindow::new(dialogs::OpenFileDialogFlags::Preview));
+    a.run();
+}
+
+#[test]
+fn check_file_preview_kind() {
+    use super::file_preview::FilePreview;
+    assert!(matches!(FilePreview::new("a.txt", b"line 1\nline 2".to_vec()), FilePreview::Text(t) if t == "line 1\nline 2"));
+    assert!(matches!(FilePreview::new("a.bin", vec![0, 1, 2]), FilePreview::Binary(_)));
+    // the buffer ends in the middle of an UTF-8 character
+    assert!(matches!(FilePreview::new("a.txt", vec![b'a', 0xC3]), FilePreview::Text(t) if t == "a"));
+    // not a valid bitmap
+    assert!(matches!(FilePreview::new("a.bmp", vec![b'B', b'M', 0]), FilePreview::Binary(_)));
+    assert!(FilePreview::is_image("picture.BMP"));
+    assert!(!FilePreview::is_image("bmp"));
+}
+
+#[test]
+fn check_file_preview_reads_only_needed_bytes() {
+    use super::file_preview::{FilePreview, PREVIEW_MAX_SIZE};
+    use std::cell::RefCell;
+    // a 32 bits BMP file (only the header is relevant, the pixels are all black)
+    let bmp = |width: u32, height: u32| {
+        let mut data = vec![0u8; 54];
+        data[0] = b'B';
+        data[1] = b'M';
+        data[10..14].copy_from_slice(&54u32.to_le_bytes());
+        data[18..22].copy_from_slice(&width.to_le_bytes());
+        data[22..26].copy_from_slice(&height.to_le_bytes());
+        data[28..30].copy_from_slice(&32u16.to_le_bytes());
+        data.resize(54 + (width as usize * height as usize * 4).min(0x10000), 0);
+        data
+    };
+    let requests = RefCell::new(Vec::new());
+    let load = |name: &str, content: &[u8]| {
+        requests.borrow_mut().clear();
+        FilePreview::load(name, |size| {
+            requests.borrow_mut().push(size);
+            Some(content[..size.min(content.len())].to_vec())
+        })
+    };
+    // a small image is fully read (after its header)
+    assert!(matches!(load("small.bmp", &bmp(4, 2)), Some(FilePreview::Image(_))));
+    assert_eq!(*requests.borrow(), vec![54, 54 + 4 * 2 * 4]);
+    // a large image is never loaded - only its first bytes are read (and shown as a binary file)
+    assert!(matches!(load("large.bmp", &bmp(0xF000, 0xF000)), Some(FilePreview::Binary(_))));
+    assert_eq!(*requests.borrow(), vec![54, PREVIEW_MAX_SIZE]);
+    // files that are not images are read only once
+    assert!(matches!(load("a.txt", b"text"), Some(FilePreview::Text(_))));
+    assert_eq!(*requests.borrow(), vec![PREVIEW_MAX_SIZE]);
+    // the file can not be read
+    assert!(FilePreview::load("a.bmp", |_| None).is_none());
+}
+
+#[test]
+fn check_form_builder() {
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Initial State')
+        CheckHash(0x2DB0A1B7CDF52AA8)
+        Key.Pressed(Space)
+        Paint('2. Form opened (focus on host)')
+        CheckHash(0xF12AE65210636512)
+        Key.Pressed(Enter)
+        Paint('3. Host is required and password is too short')
+        CheckHash(0x9CC47B510DF981A6)
+        Key.TypeText('server')
+        Key.Pressed(Tab,2)
+        Key.TypeText('secret')
+        Key.Pressed(Enter)
+        Paint('4. Back to main window (title is server:22:abcsecret:true)')
+        CheckHash(0x588B954876C61439)
+    ";
+
+    #[Window(events = ButtonEvents, internal: true)]
+    struct MyWin {}
+
+    impl MyWin {
+        fn new() -> Self {
+            let mut me = Self {
+                base: window!("Test,a:c,w:40,h:7"),
+            };
+            me.add(button!("Click,a:c,w:15,h:100%"));
+            me
+        }
+    }
+    impl ButtonEvents for MyWin {
+        fn on_pressed(&mut self, _: Handle<Button>) -> EventProcessStatus {
+            let result = dialogs::Form::new("Connection")
+                .text("host", "&Host", "")
+                .required()
+                .number("port", "&Port", 22)
+                .range(1, 65535)
+                .password("password", "Pass&word", "abc")
+                .range(4, 16)
+                .bool("ssl", "Use &SSL", true)
+                .show();
+            if let Some(v) = result {
+                let title = format!(
+                    "{}:{}:{}:{}",
+                    v.text("host").unwrap(),
+                    v.number("port").unwrap(),
+                    v.text("password").unwrap(),
+                    v.bool("ssl").unwrap()
+                );
+                self.set_title(&title);
+            }
+            EventProcessStatus::Processed
+        }
+    }
+
+    let mut a = App::debug(60, 16, script).build().unwrap();
+    a.add_window(MyWin::new());
+    a.run();
+}
+
+#[test]
+fn check_form_derive() {
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Initial State')
+        CheckHash(0x2DB0A1B7CDF52AA8)
+        Key.Pressed(Space)
+        Paint('2. Form opened')
+        CheckHash(0xA0D7906A8E639825)
+        Key.Pressed(Ctrl+A)
+        Key.Pressed(Backspace)
+        Key.Pressed(Enter)
+        Paint('3. Name is required')
+        CheckHash(0x9270D55C3977EB1D)
+        Key.TypeText('bob')
+        Key.Pressed(Enter)
+        Paint('4. Name must start with an upper-case letter')
+        CheckHash(0x16012903967E8E74)
+        Key.Pressed(Ctrl+A)
+        Key.Pressed(Backspace)
+        Key.TypeText('John')
+        Key.Pressed(Enter)
+        Paint('5. Back to main window (title is John:Medium:None:2024-05-01)')
+        CheckHash(0xF81C6BB3FDB22F2F)
+        Key.Pressed(Space)
+        Key.Pressed(Escape)
+        Paint('6. Form canceled (title is the same)')
+        CheckHash(0xF81C6BB3FDB22F2F)
+    ";
+
+    #[derive(EnumSelector, Eq, PartialEq, Copy, Clone, Debug)]
+    enum Portion {
+        Small,
+        Medium,
+        Large,
+    }
+
+    fn check_name(value: &dialogs::FieldValue) -> Result<(), String> {
+        match value.as_text() {
+            Some(s) if s.starts_with(|c: char| c.is_ascii_uppercase()) => Ok(()),
+            _ => Err("Must start with an upper-case letter".to_string()),
+        }
+    }
+
+    #[derive(Form)]
+    struct Order {
+        #[Field(label: "&Name", required, validator: check_name)]
+        name: String,
+        #[Field(label: "&Size")]
+        size: Portion,
+        #[Field(label: "&Extra")]
+        extra: Option<Portion>,
+        #[Field(label: "&Date")]
+        date: chrono::NaiveDate,
+        #[Field(skip)]
+        id: u32,
+    }
+
+    #[Window(events = ButtonEvents, internal: true)]
+    struct MyWin {}
+
+    impl MyWin {
+        fn new() -> Self {
+            let mut me = Self {
+                base: window!("Test,a:c,w:40,h:7"),
+            };
+            me.add(button!("Click,a:c,w:15,h:100%"));
+            me
+        }
+    }
+    impl ButtonEvents for MyWin {
+        fn on_pressed(&mut self, _: Handle<Button>) -> EventProcessStatus {
+            let order = Order {
+                name: "John".to_string(),
+                size: Portion::Medium,
+                extra: None,
+                date: chrono::NaiveDate::from_ymd_opt(2024, 5, 1).unwrap(),
+                id: 7,
+            };
+            if let Some(o) = dialogs::form("Order", order) {
+                assert_eq!(o.id, 7);
+                let title = format!("{}:{:?}:{:?}:{}", o.name, o.size, o.extra, o.date);
+                self.set_title(&title);
+            }
+            EventProcessStatus::Processed
+        }
+    }
+
+    let mut a = App::debug(60, 16, script).build().unwrap();
+    a.add_window(MyWin::new());
+    a.run();
+}
+
+#[test]
+fn check_form_field_validation() {
+    let f = dialogs::Form::new("x").text("a", "A", "").required().range(2, 4);
+    let field = &f.fields[0];
+    assert!(field.validate(&dialogs::FieldValue::Text(String::new())).is_err());
+    assert!(field.validate(&dialogs::FieldValue::Text("a".to_string())).is_err());
+    assert!(field.validate(&dialogs::FieldValue::Text("abc".to_string())).is_ok());
+    assert!(field.validate(&dialogs::FieldValue::Text("abcde".to_string())).is_err());
+    let f = dialogs::Form::new("x").number("n", "N", 5).range(1, 10).validator(|v| {
+        if v.as_number() == Some(7) {
+            Err("7 is not allowed".to_string())
+        } else {
+            Ok(())
+        }
+    });
+    let field = &f.fields[0];
+    assert!(field.validate(&dialogs::FieldValue::Number(0)).is_err());
+    assert!(field.validate(&dialogs::FieldValue::Number(5)).is_ok());
+    assert_eq!(field.validate(&dialogs::FieldValue::Number(7)), Err("7 is not allowed".to_string()));
+}
+
+#[test]
+fn check_form_derive_number_limits() {
+    use dialogs::FormData;
+
+    #[derive(Form)]
+    struct Limits {
+        port: u16,
+        #[Field(min: 1)]
+        count: u32,
+        size: u64,
+        offset: i8,
+    }
+
+    let mut l = Limits {
+        port: 80,
+        count: 3_000_000_000,
+        size: u64::MAX,
+        offset: -1,
+    };
+    let f = l.form("Limits");
+    assert_eq!(f.fields[0].range, Some((0, u16::MAX as i64)));
+    assert_eq!(f.fields[1].range, Some((1, u32::MAX as i64)));
+    assert_eq!(f.fields[2].range, Some((0, i64::MAX)));
+    assert_eq!(f.fields[3].range, Some((i8::MIN as i64, i8::MAX as i64)));
+    assert!(f.fields[0].validate(&dialogs::FieldValue::Number(70000)).is_err());
+    assert!(f.fields[1].validate(&dialogs::FieldValue::Number(3_000_000_000)).is_ok());
+
+    let mut values = dialogs::FormValues::default();
+    values.add("port", dialogs::FieldValue::Number(70000));
+    values.add("count", dialogs::FieldValue::Number(4_000_000_000));
+    values.add("size", dialogs::FieldValue::Number(5));
+    values.add("offset", dialogs::FieldValue::Number(-128));
+    l.update(&values);
+    // a value that does not fit in the type of the field is not written back
+    assert_eq!(l.port, 80);
+    assert_eq!(l.count, 4_000_000_000);
+    assert_eq!(l.size, 5);
+    assert_eq!(l.offset, -128);
+}
+
+#[test]
+fn check_progress_indicator_caption() {
+    use super::progress_dialog::ProgressInfo;
+    use crate::ui::appbar::ProgressIndicator;
+    use std::sync::Mutex;
+
+    let info = Mutex::new(ProgressInfo::new("Copy"));
+    assert_eq!(info.caption(), Some(" Copy: ... ".to_string()));
+    {
+        let mut i = info.lock().unwrap();
+        i.total = 200;
+        i.done = 50;
+    }
+    assert_eq!(info.caption(), Some(" Copy: 25% ".to_string()));
+    info.lock().unwrap().done = 500;
+    assert_eq!(info.caption(), Some(" Copy: 100% ".to_string()));
+    info.lock().unwrap().finished = true;
+    assert_eq!(info.caption(), Some(" Copy: done ".to_string()));
+    info.lock().unwrap().error = Some("task panicked".to_string());
+    assert_eq!(info.caption(), Some(" Copy: failed ".to_string()));
+    info.lock().unwrap().result_taken = true;
+    assert_eq!(info.caption(), None);
+}
+
+#[test]
+fn check_async_dialogs() {
+    let script = "
+        Paint.Enable(false)
+        Paint('Initial State')
+        CheckHash(0x90DB478C0FC0C3A9)
+        Key.Pressed(Enter)
+        Paint('Validate dialog (opened after the button event)')
+        CheckHash(0x5AB0D6F7212F24B2)
+        Key.Pressed(Enter)
+        Paint('Message dialog with the result (No is the default button)')
+        CheckHash(0x4F085BB5FBA5CA5D)
+        Key.Pressed(Enter)
+        Paint('Back to the initial window')
+        CheckHash(0x90DB478C0FC0C3A9)
+    ";
+    let mut a = App::debug(60, 12, script).build().unwrap();
+    a.add_window(CallbackWin::new(|| {
+        App::spawn_local(async {
+            let result = dialogs::validate_async("Question", "Continue ?").await;
+            dialogs::message_async("Result", if result { "Yes" } else { "No" }).await;
+        });
+    }));
+    a.run();
+}
+
+#[test]
+fn check_async_dialogs_from_two_tasks() {
+    let script = "
+        Paint.Enable(false)
+        Paint('Initial State')
+        CheckHash(0x90DB478C0FC0C3A9)
+        Key.Pressed(Enter)
+        Paint('Second dialog on top of the first one')
+        CheckHash(0x92D3513EEF017800)
+        Key.Pressed(Enter)
+        Paint('First dialog (the second one was closed)')
+        CheckHash(0x55FB3C90FCDB1E66)
+        Key.Pressed(Enter)
+        Paint('Back to the initial window')
+        CheckHash(0x90DB478C0FC0C3A9)
+    ";
+    let mut a = App::debug(60, 12, script).build().unwrap();
+    a.add_window(CallbackWin::new(|| {
+        // both dialogs are opened right away (the second one does not wait for the first one to be closed)
+        let first = dialogs::message_async("First", "First dialog");
+        let second = dialogs::message_async("Second", "Second dialog");
+        App::spawn_local(first);
+        App::spawn_local(second);
+    }));
+    a.run();
+}
@@ -19,6 +19,7 @@ mod surface;
 mod text_format;
 mod orthogonal_direction;
 mod box_junction;
+pub(crate) mod unicode;
 
 #[cfg(test)]
 mod surface_tester;
@@ -10,6 +10,9 @@ pub enum CharFlags {
     CurlyUnderline = 0x0010,
     DottedUnderline = 0x0020,
     StrikeThrough = 0x0040, 
+    /// Set by a surface for a cell that contains a grapheme cluster made of multiple characters (the text of the cluster is
+    /// stored by the surface and the code of the cell is the first character of the cluster). This flag can not be set manually.
+    Cluster = 0x0080,
 }
 
 /// Represents attributes of a character such as foreground color, background color, and flags.
@@ -197,6 +197,8 @@ impl Character {
     /// The flags are always updated.
     #[inline(always)]
     pub fn set(&mut self, ch: Character) {
+        // the `Cluster` flag is kept only if the code of the cell is not changed
+        let cluster = (ch.code == (0 as char)) && self.flags.contains(CharFlags::Cluster);
         if ch.code != (0 as char) {
             self.code = ch.code;
         }
@@ -207,6 +209,11 @@ impl Character {
             self.background = ch.background;
         }
         self.flags = ch.flags;
+        if cluster {
+            self.flags.set(CharFlags::Cluster);
+        } else {
+            self.flags.remove(CharFlags::Cluster);
+        }
     }
 }
 
@@ -48,6 +48,7 @@ mod character_set;
 mod render_options;
 mod bit_tile;
 mod string_format_parser;
+mod bmp;
 mod glyph;
 #[cfg(test)]
 mod tests;
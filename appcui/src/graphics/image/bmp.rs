@@ -0,0 +1,80 @@
+use super::super::Size;
+
+const BI_RGB: u32 = 0;
+const BI_BITFIELDS: u32 = 3;
+pub(super) const HEADER_SIZE: usize = 54;
+
+fn read_u16(buffer: &[u8], offset: usize) -> Option<u16> {
+    let b = buffer.get(offset..offset + 2)?;
+    Some(u16::from_le_bytes([b[0], b[1]]))
+}
+fn read_u32(buffer: &[u8], offset: usize) -> Option<u32> {
+    let b = buffer.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+struct Header {
+    data_offset: usize,
+    width: usize,
+    height: usize,
+    bottom_up: bool,
+    bytes_per_pixel: usize,
+    stride: usize,
+}
+
+impl Header {
+    fn parse(buffer: &[u8]) -> Option<Header> {
+        if buffer.len() < HEADER_SIZE || buffer[0] != b'B' || buffer[1] != b'M' {
+            return None;
+        }
+        let data_offset = read_u32(buffer, 10)? as usize;
+        let width = read_u32(buffer, 18)? as i32;
+        let height = read_u32(buffer, 22)? as i32;
+        let bpp = read_u16(buffer, 28)? as usize;
+        let compression = read_u32(buffer, 30)?;
+        if width <= 0 || height == 0 || width > 0xF000 || height.unsigned_abs() > 0xF000 {
+            return None;
+        }
+        match (bpp, compression) {
+            (24, BI_RGB) | (32, BI_RGB) | (32, BI_BITFIELDS) => {}
+            _ => return None,
+        }
+        Some(Header {
+            data_offset,
+            width: width as usize,
+            height: height.unsigned_abs() as usize,
+            // positive height means that the image is stored bottom-up
+            bottom_up: height > 0,
+            bytes_per_pixel: bpp / 8,
+            stride: (width as usize * bpp).div_ceil(32) * 4,
+        })
+    }
+    fn file_size(&self) -> usize {
+        self.data_offset + self.stride * self.height
+    }
+}
+
+/// Returns the number of bytes (from the start of the file) needed to decode a supported BMP file,
+/// computed from its first `HEADER_SIZE` bytes.
+pub(super) fn file_size(header: &[u8]) -> Option<usize> {
+    Some(Header::parse(header)?.file_size())
+}
+
+/// Decodes an uncompressed 24 or 32 bits BMP file into a list of ARGB values (one u32 per pixel, top-down order).
+pub(super) fn decode(buffer: &[u8]) -> Option<(Vec<u32>, Size)> {
+    let header = Header::parse(buffer)?;
+    if header.file_size() > buffer.len() {
+        return None;
+    }
+    let (w, h, bytes_per_pixel) = (header.width, header.height, header.bytes_per_pixel);
+    let mut pixels = Vec::with_capacity(w * h);
+    for y in 0..h {
+        let row = if header.bottom_up { h - 1 - y } else { y };
+        let start = header.data_offset + row * header.stride;
+        for x in 0..w {
+            let p = &buffer[start + x * bytes_per_pixel..start + (x + 1) * bytes_per_pixel];
+            pixels.push(0xFF00_0000 | ((p[2] as u32) << 16) | ((p[1] as u32) << 8) | (p[0] as u32));
+        }
+    }
+    Some((pixels, Size::new(w as u32, h as u32)))
+}
@@ -97,6 +97,38 @@ impl Image {
         Some(me)
     }
 
+    /// Creates a new image from the content of a BMP file.
+    ///
+    /// Only uncompressed BMP files with 24 or 32 bits per pixel are supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The content of the BMP file (including the file header)
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Image)` - If the buffer contains a valid (and supported) BMP image
+    /// * `None` - If the format is invalid or not supported
+    pub fn from_bmp(buffer: &[u8]) -> Option<Image> {
+        let (pixels, size) = super::bmp::decode(buffer)?;
+        Image::from_buffer(&pixels, size, false)
+    }
+
+    /// Returns the number of bytes (from the start of the file) that [`Image::from_bmp`] needs to decode a BMP file,
+    /// computed only from its header. This allows the caller to read the header first and load the rest of the
+    /// file only if the image is supported and not too large.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(size)` - If `header` starts with the header of a supported BMP image
+    /// * `None` - If the header is incomplete, invalid or the format is not supported
+    pub(crate) fn bmp_file_size(header: &[u8]) -> Option<usize> {
+        super::bmp::file_size(header)
+    }
+
+    /// The number of bytes from the start of a BMP file that [`Image::bmp_file_size`] needs.
+    pub(crate) const BMP_HEADER_SIZE: usize = super::bmp::HEADER_SIZE;
+
     /// Clears the entire image with the specified pixel color.
     ///
     /// # Arguments
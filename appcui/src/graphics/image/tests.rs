@@ -1003,4 +1003,27 @@ fn check_glyph_write_str_outside() {
     // we should se
     // ....
     // ..He
-}
\ No newline at end of file
+}
+#[test]
+fn check_image_from_bmp() {
+    // 2x2 image, 24 bits per pixel, bottom-up (each row is padded to 8 bytes)
+    let mut bmp: Vec<u8> = vec![
+        b'B', b'M', 70, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, // file header
+        40, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 1, 0, 24, 0, 0, 0, 0, 0, 16, 0, 0, 0, // info header
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    // bottom row: blue, green
+    bmp.extend_from_slice(&[255, 0, 0, 0, 255, 0, 0, 0]);
+    // top row: red, white
+    bmp.extend_from_slice(&[0, 0, 255, 255, 255, 255, 0, 0]);
+    let img = Image::from_bmp(&bmp).unwrap();
+    assert_eq!(img.size(), Size::new(2, 2));
+    assert_eq!(img.pixel(0, 0), Some(Pixel::with_rgb(255, 0, 0)));
+    assert_eq!(img.pixel(1, 0), Some(Pixel::with_rgb(255, 255, 255)));
+    assert_eq!(img.pixel(0, 1), Some(Pixel::with_rgb(0, 0, 255)));
+    assert_eq!(img.pixel(1, 1), Some(Pixel::with_rgb(0, 255, 0)));
+
+    // truncated data or invalid signature
+    assert!(Image::from_bmp(&bmp[..60]).is_none());
+    assert!(Image::from_bmp(b"PNG").is_none());
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::path::Path;
 
 use super::image::Glyph;
@@ -19,6 +20,7 @@ use super::TextFormat;
 use crate::prelude::CharFlags;
 use crate::prelude::RenderOptions;
 use super::BOX_JUNCTION;
+use super::unicode;
 
 #[repr(u8)]
 #[derive(PartialEq, Clone, Copy)]
@@ -60,6 +62,8 @@ pub struct Surface {
     base_clip: ClipArea,
     right_most: i32,
     bottom_most: i32,
+    // the text of the grapheme clusters made of multiple characters (indexed by the position of the cell)
+    clusters: HashMap<usize, String>,
 }
 
 impl Surface {
@@ -86,6 +90,7 @@ impl Surface {
             cursor: Cursor::new(),
             right_most: (w - 1) as i32,
             bottom_most: (h - 1) as i32,
+            clusters: HashMap::new(),
         };
         s.chars.resize(count, Character::default());
         s
@@ -267,13 +272,90 @@ impl Surface {
     /// let mut surface = Surface::new(100, 50);
     /// surface.write_char(10, 10, Character::new('A', Color::White, Color::Black, CharFlags::None));
     /// ```
+    /// A wide character (such as a CJK ideograph or most emojis) uses two cells: the character is written at the specified
+    /// position and the next cell is marked as its continuation. If only one of the two cells is visible, a space is written instead.
     #[inline(always)]
     pub fn write_char(&mut self, x: i32, y: i32, ch: Character) {
-        if let Some(pos) = self.coords_to_position(x, y) {
+        if unicode::char_width(ch.code) == 2 {
+            self.write_cell(x, y, ch, 2);
+        } else if let Some(pos) = self.coords_to_position(x, y) {
             self.chars[pos].set(ch);
         }
     }
 
+    // writes a character that uses `width` cells and returns the position of its first cell (if the character is entirely visible)
+    fn write_cell(&mut self, x: i32, y: i32, mut ch: Character, width: u32) -> Option<usize> {
+        if width < 2 {
+            let pos = self.coords_to_position(x, y)?;
+            self.chars[pos].set(ch);
+            return Some(pos);
+        }
+        let first = self.coords_to_position(x, y);
+        let second = self.coords_to_position(x + 1, y);
+        match (first, second) {
+            (Some(first), Some(second)) => {
+                self.chars[first].set(ch);
+                ch.code = unicode::CONTINUATION_CHAR;
+                self.chars[second].set(ch);
+                Some(first)
+            }
+            (Some(pos), None) | (None, Some(pos)) => {
+                // only half of the character is visible
+                ch.code = ' ';
+                self.chars[pos].set(ch);
+                None
+            }
+            (None, None) => None,
+        }
+    }
+
+    // writes a grapheme cluster that uses `width` cells. A cluster made of multiple characters is stored as its first
+    // character (marked with the `Cluster` flag) and its text is kept (for that cell) in the `clusters` map.
+    fn write_grapheme(&mut self, x: i32, y: i32, mut ch: Character, grapheme: &str, width: u32) {
+        let mut chars = grapheme.chars();
+        ch.code = chars.next().unwrap_or(' ');
+        let single_char = chars.next().is_none();
+        let Some(pos) = self.write_cell(x, y, ch, width) else {
+            return;
+        };
+        if single_char {
+            return;
+        }
+        self.chars[pos].flags.set(CharFlags::Cluster);
+        let text = self.clusters.entry(pos).or_default();
+        if text != grapheme {
+            text.clear();
+            text.push_str(grapheme);
+        }
+    }
+
+    // copies a cell from another surface (including the text of the grapheme cluster from that cell)
+    fn copy_cell(&mut self, x: i32, y: i32, ch: Character, surface: &Surface, index: usize) {
+        if ch.flags.contains(CharFlags::Cluster) {
+            if let Some(text) = surface.clusters.get(&index).filter(|t| t.starts_with(ch.code)) {
+                self.write_grapheme(x, y, ch, text, unicode::grapheme_width(text));
+                return;
+            }
+        }
+        self.write_char(x, y, ch);
+    }
+
+    /// Returns the text of the grapheme cluster (made of multiple characters) stored in the cell from the specified index.
+    pub(crate) fn cluster_text(&self, index: usize) -> Option<&str> {
+        if !self.chars.get(index)?.flags.contains(CharFlags::Cluster) {
+            return None;
+        }
+        self.clusters.get(&index).map(|t| t.as_str())
+    }
+
+    /// Returns the number of cells (0, 1 or 2) used to display the character (or the grapheme cluster) from the specified index.
+    pub(crate) fn cell_width(&self, index: usize) -> u32 {
+        match self.cluster_text(index) {
+            Some(text) => unicode::grapheme_width(text),
+            None => self.chars.get(index).map(|c| unicode::char_width(c.code)).unwrap_or(1),
+        }
+    }
+
     /// Returns the character at the specified position. If the position is outside the clip area, `None` will be returned.
     #[inline]
     pub fn char(&self, x: i32, y: i32) -> Option<&Character> {
@@ -291,6 +373,9 @@ impl Surface {
             for c in &mut self.chars {
                 c.set(ch);
             }
+            if ch.code != (0 as char) {
+                self.clusters.clear();
+            }
         } else {
             // only the clip must pe cleared
             let mut pos = self.clip.left as usize;
@@ -308,11 +393,13 @@ impl Surface {
 
     /// Resets the entire surface by filling it with a provided character and by resetting the coordinates and the clip area.
     /// You can use this method method if you want to fill a surface with a transparent character (e.g. if you want that surface to be printed on another surface via draw_surface method)
-    pub fn reset(&mut self, ch: Character) {
+    pub fn reset(&mut self, mut ch: Character) {
         self.reset_clip_and_origin();
+        ch.flags.remove(CharFlags::Cluster);
         for c in &mut self.chars {
             *c = ch;
         }
+        self.clusters.clear();
     }
 
     /// Fills a horizontal line with the specified character type, color and attributes. If the line is outside the clip area, it will not be drawn.
@@ -718,6 +805,7 @@ impl Surface {
         };
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn draw_orthogonal_line(
         &mut self,
         x1: i32,
@@ -1022,6 +1110,7 @@ impl Surface {
         // obtinem junction-ul - daca e valid il suprascriem (dar doar character)
         if let Some(junction) = BOX_JUNCTION.resolve(left, up, right, down) {
             self.chars[pos].code = junction;
+            self.chars[pos].flags.remove(CharFlags::Cluster);
         }
     }
 
@@ -1054,7 +1143,7 @@ impl Surface {
         let mut index = 0usize;
         for s_y in 0..=surface.bottom_most {
             for s_x in 0..=surface.right_most {
-                self.write_char(x + s_x, y + s_y, surface.chars[index]);
+                self.copy_cell(x + s_x, y + s_y, surface.chars[index], surface, index);
                 index += 1;
             }
         }
@@ -1090,7 +1179,7 @@ impl Surface {
         let mut index = 0usize;
         for s_y in 0..=surface.bottom_most {
             for s_x in 0..=surface.right_most {
-                self.write_char(x + s_x, y + s_y, transform(surface.chars[index]));
+                self.copy_cell(x + s_x, y + s_y, transform(surface.chars[index]), surface, index);
                 index += 1;
             }
         }
@@ -1132,6 +1221,8 @@ impl Surface {
 
     /// Writes a string at the specified position, from left to right using a specific character attribute. If the text is outside the clip area, it will not be drawn.
     /// The `multi-line` parameter specifices if the text should interpret new line characters as a new line or not. if set to `false` the code of this method is optimized to write the text faster.
+    /// Every grapheme cluster (a character together with its combining marks, an emoji sequence or a flag) is written in one cell,
+    /// or in two cells if it is a wide character (such as a CJK ideograph or an emoji).
     ///
     /// Example:
     /// ```rust
@@ -1144,32 +1235,30 @@ impl Surface {
     ///                      false);
     /// ```
     pub fn write_string(&mut self, x: i32, y: i32, text: &str, attr: CharAttribute, multi_line: bool) {
-        let mut c = Character::new(' ', attr.foreground, attr.background, attr.flags);
+        let c = Character::new(' ', attr.foreground, attr.background, attr.flags);
         if !multi_line {
             // single line support
             if !self.clip.contains_y(y + self.origin.y) {
                 return; // no need to draw
             }
-            for (p_x, ch) in (x..).zip(text.chars()) {
-                if let Some(pos) = self.coords_to_position(p_x, y) {
-                    c.code = ch;
-                    self.chars[pos].set(c);
-                }
+            let mut p_x = x;
+            for grapheme in unicode::graphemes(text) {
+                let width = unicode::grapheme_width(grapheme);
+                self.write_grapheme(p_x, y, c, grapheme, width);
+                p_x += width as i32;
             }
         } else {
             let mut p_x = x;
             let mut p_y = y;
-            for ch in text.chars() {
-                if (ch == '\n') || (ch == '\r') {
+            for grapheme in unicode::graphemes(text) {
+                if grapheme.starts_with(['\n', '\r']) {
                     p_y += 1;
                     p_x = x;
                     continue;
                 }
-                if let Some(pos) = self.coords_to_position(p_x, p_y) {
-                    c.code = ch;
-                    self.chars[pos].set(c);
-                }
-                p_x += 1;
+                let width = unicode::grapheme_width(grapheme);
+                self.write_grapheme(p_x, p_y, c, grapheme, width);
+                p_x += width as i32;
             }
         }
     }
@@ -1234,51 +1323,51 @@ impl Surface {
             TextAlignment::Right => format.x + 1 - width as i32,
         };
         let right_margin = left_margin + (width as i32);
-        let mut c = Character::with_attributes(' ', format.char_attr);
-
-        if format.has_hotkey() {
-            let hkpos = format.hotkey_pos as usize;
-            for (cpos, ch) in (ch_index..).zip(text.chars()) {
-                if (x >= left_margin) && (x < right_margin) {
-                    if let Some(pos) = self.coords_to_position(x, y) {
-                        if cpos == hkpos {
-                            self.chars[pos].set(Character::with_attributes(ch, format.hotkey_attr));
-                        } else {
-                            c.code = ch;
-                            self.chars[pos].set(c);
-                        }
-                    }
-                }
-                x += 1;
+        let c = Character::with_attributes(' ', format.char_attr);
+        let hkpos = if format.has_hotkey() { format.hotkey_pos as usize } else { usize::MAX };
+        let mut cpos = ch_index;
+
+        for grapheme in unicode::graphemes(text) {
+            if x >= right_margin {
+                break;
             }
-        } else {
-            for (_, ch) in (ch_index..).zip(text.chars()) {
-                if (x >= left_margin) && (x < right_margin) {
-                    if let Some(pos) = self.coords_to_position(x, y) {
-                        c.code = ch;
-                        self.chars[pos].set(c);
-                    }
+            let w = unicode::grapheme_width(grapheme) as i32;
+            let cell = if cpos == hkpos {
+                Character::with_attributes(' ', format.hotkey_attr)
+            } else {
+                c
+            };
+            if (x >= left_margin) && (x + w <= right_margin) {
+                self.write_grapheme(x, y, cell, grapheme, w as u32);
+            } else if x + w > left_margin {
+                // a wide character that is only partially visible --> fill the visible part with spaces
+                for p_x in x.max(left_margin)..(x + w).min(right_margin) {
+                    self.write_cell(p_x, y, cell, 1);
                 }
-                x += 1;
             }
+            x += w;
+            cpos += grapheme.chars().count();
         }
     }
     fn write_text_multi_line_no_wrap(&mut self, text: &str, format: &TextFormat) {
         let mut y = format.y;
         let mut start_ofs = 0usize;
         let mut chars_count = 0u16;
+        let mut line_chars = 0usize;
         let mut ch_index = 0usize;
-        for (index, ch) in text.char_indices() {
-            if (ch == '\n') || (ch == '\r') {
+        for (index, grapheme) in unicode::grapheme_indices(text) {
+            if grapheme.starts_with(['\n', '\r']) {
                 if chars_count > 0 {
                     self.write_text_single_line(&text[start_ofs..index], y, chars_count, ch_index, format, chars_count);
                 }
                 y += 1;
-                ch_index += (chars_count as usize) + 1;
+                ch_index += line_chars + grapheme.chars().count();
                 chars_count = 0;
-                start_ofs = index + 1;
+                line_chars = 0;
+                start_ofs = index + grapheme.len();
             } else {
-                chars_count += 1;
+                chars_count += unicode::grapheme_width(grapheme) as u16;
+                line_chars += grapheme.chars().count();
             }
         }
         if chars_count > 0 {
@@ -1292,27 +1381,32 @@ impl Surface {
         let mut y = format.y;
         let mut start_ofs = 0usize;
         let mut chars_count = 0u16;
+        let mut line_chars = 0usize;
         let mut ch_index = 0usize;
-        for (index, ch) in text.char_indices() {
-            if (ch == '\n') || (ch == '\r') {
+        for (index, grapheme) in unicode::grapheme_indices(text) {
+            if grapheme.starts_with(['\n', '\r']) {
                 if chars_count > 0 {
                     self.write_text_single_line(&text[start_ofs..index], y, chars_count, ch_index, format, width);
                 }
                 y += 1;
-                ch_index += (chars_count as usize) + 1;
+                ch_index += line_chars + grapheme.chars().count();
                 chars_count = 0;
-                start_ofs = index + 1;
+                line_chars = 0;
+                start_ofs = index + grapheme.len();
                 continue;
             }
-            if chars_count == width {
+            let w = unicode::grapheme_width(grapheme) as u16;
+            if (chars_count > 0) && (chars_count + w > width) {
                 self.write_text_single_line(&text[start_ofs..index], y, chars_count, ch_index, format, width);
                 y += 1;
-                ch_index += chars_count as usize;
-                chars_count = 1; // current character
+                ch_index += line_chars;
+                chars_count = w; // current character
+                line_chars = grapheme.chars().count();
                 start_ofs = index;
                 continue;
             }
-            chars_count += 1;
+            chars_count += w;
+            line_chars += grapheme.chars().count();
         }
         if chars_count > 0 {
             self.write_text_single_line(&text[start_ofs..], y, chars_count, ch_index, format, width);
@@ -1338,9 +1432,12 @@ impl Surface {
         let mut last_char_type = CharacterType::Undefined;
         let mut strip_spaces = false;
 
-        for (offset, ch) in text.char_indices() {
-            let char_type = CharacterType::from(ch);
-            current_char_index += 1;
+        for (offset, grapheme) in unicode::grapheme_indices(text) {
+            let char_type = CharacterType::from(grapheme.chars().next().unwrap_or(' '));
+            let w = unicode::grapheme_width(grapheme) as u16;
+            // the index of the first character of the current grapheme
+            let char_start = current_char_index;
+            current_char_index += grapheme.chars().count();
             if strip_spaces {
                 if char_type == CharacterType::Space {
                     continue;
@@ -1349,7 +1446,7 @@ impl Surface {
                 end_ofs = offset;
                 chars_count = 0;
                 strip_spaces = false;
-                ch_index = current_char_index - 1;
+                ch_index = char_start;
             }
             if ((last_char_type == CharacterType::Word) && (last_char_type != char_type)) || (last_char_type == CharacterType::Other) {
                 // we have either a word or a punctuation mark that is finished
@@ -1360,39 +1457,37 @@ impl Surface {
                 // we have a possible new start (either an word or a punctuation mark)
                 next_ofs = offset;
                 chars_count_next_ofs = chars_count;
-                current_char_index_on_next = current_char_index - 1;
+                current_char_index_on_next = char_start;
             }
 
-            if (char_type == CharacterType::NewLine) || (chars_count == width) {
+            if (char_type == CharacterType::NewLine) || ((chars_count > 0) && (chars_count + w > width)) {
                 if end_ofs <= start_ofs {
-                    //println!("Word bigger than the line (start={start_ofs}, end={end_ofs}, index={index})");
+                    // word bigger than the line
                     end_ofs = offset;
                     chars_count_end_ofs = chars_count;
                 }
                 // print the part
-                // println!("start={} end={} =>'{}' , next={} index={} =>'{}'",start_ofs,end_ofs,&text[start_ofs..end_ofs],next_ofs,index,&text[next_ofs..index]);
                 self.write_text_single_line(&text[start_ofs..end_ofs], y, chars_count_end_ofs, ch_index, format, width);
                 if char_type == CharacterType::NewLine {
-                    start_ofs = offset + 1;
+                    start_ofs = offset + grapheme.len();
                     ch_index = current_char_index;
                     chars_count = 0;
                 } else if next_ofs >= end_ofs {
                     start_ofs = next_ofs;
                     ch_index = current_char_index_on_next;
-                    chars_count = 1 + chars_count - chars_count_next_ofs;
+                    chars_count = w + chars_count - chars_count_next_ofs;
                 } else {
                     start_ofs = offset;
-                    ch_index = current_char_index - 1;
-                    chars_count = 1; // current char
+                    ch_index = char_start;
+                    chars_count = w; // current char
                     strip_spaces = char_type == CharacterType::Space;
                 }
                 last_char_type = char_type;
-                //println!("   ->new_start={}, chars_count={}, index={} [{strip_spaces}]=> '{}'\n",start_ofs,chars_count,index,&text[start_ofs..]);
                 y += 1;
                 continue;
             }
             last_char_type = char_type;
-            chars_count += 1;
+            chars_count += w;
         }
         if chars_count > 0 {
             self.write_text_single_line(&text[start_ofs..], y, chars_count, ch_index, format, width);
@@ -1426,7 +1521,7 @@ impl Surface {
                 let chars_count = if format.has_chars_count() {
                     format.chars_count
                 } else {
-                    text.chars().count() as u16
+                    unicode::text_width(text) as u16
                 };
                 self.write_text_single_line(text, format.y, chars_count, 0, format, u16::MAX);
             }
@@ -1434,7 +1529,7 @@ impl Surface {
                 let chars_count = if format.has_chars_count() {
                     format.chars_count
                 } else {
-                    text.chars().count() as u16
+                    unicode::text_width(text) as u16
                 };
                 self.write_text_single_line(text, format.y, chars_count, 0, format, width);
             } // TextWrap::Character => self.write_text_multi_line_character_wrap(text, format),
@@ -1503,6 +1598,7 @@ impl Surface {
         self.chars.clear();
         self.chars.reserve(count);
         self.chars.resize(count, Character::default());
+        self.clusters.clear();
         self.right_most = (w as i32) - 1;
         self.bottom_most = (h as i32) - 1;
         self.size.width = w;
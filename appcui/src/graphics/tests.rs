@@ -8,6 +8,7 @@ use crate::graphics::Point;
 use crate::graphics::Rect;
 use crate::graphics::Size;
 use crate::graphics::WrapType;
+use crate::graphics::unicode;
 use crate::prelude::SpecialChar;
 
 use super::CharAttribute;
@@ -1959,4 +1960,49 @@ fn check_box_junction_on_surface_rect_double() {
 
     //s.print(false);
     assert_eq!(s.compute_hash(), 0xBADCD5976D211FFE);
-}
\ No newline at end of file
+}
+#[test]
+fn check_write_string_wide_and_combining() {
+    let mut s = SurfaceTester::new(20, 3);
+    let attr = CharAttribute::with_color(Color::White, Color::Black);
+    // CJK (2 cells), a combining mark (merged with the previous char) and an emoji (2 cells)
+    s.write_string(0, 0, "a中e\u{301}😀b", attr, false);
+    assert_eq!(s.char(0, 0).unwrap().code, 'a');
+    assert_eq!(s.char(1, 0).unwrap().code, '中');
+    assert_eq!(s.char(3, 0).unwrap().code, 'e');
+    assert!(s.char(3, 0).unwrap().flags.contains(CharFlags::Cluster));
+    assert_eq!(s.cluster_text(3), Some("e\u{301}"));
+    assert_eq!(s.char(4, 0).unwrap().code, '😀');
+    assert_eq!(s.char(6, 0).unwrap().code, 'b');
+    // a wide char that is only half visible is replaced with a space
+    s.set_clip(0, 1, 2, 1);
+    s.write_string(2, 1, "中", attr, false);
+    assert_eq!(s.char(2, 1).unwrap().code, ' ');
+    s.reset_clip();
+    assert_eq!(unicode::text_width("a中e\u{301}😀b"), 7);
+}
+#[test]
+fn check_write_string_clusters() {
+    let mut s = SurfaceTester::new(10, 2);
+    let attr = CharAttribute::with_color(Color::White, Color::Black);
+    // a character from the private use area is written as it is (it is not a grapheme cluster)
+    s.write_string(0, 0, "\u{100000}e\u{301}", attr, false);
+    assert_eq!(s.char(0, 0).unwrap().code, '\u{100000}');
+    assert_eq!(s.cluster_text(0), None);
+    assert_eq!(s.cluster_text(1), Some("e\u{301}"));
+    // changing the colors of the cell keeps the cluster, overwriting the cell removes it
+    s.fill_horizontal_line(0, 0, 9, Character::new(0 as char, Color::Red, Color::Transparent, CharFlags::None));
+    assert_eq!(s.cluster_text(1), Some("e\u{301}"));
+    s.write_char(1, 0, Character::with_attributes('x', attr));
+    assert_eq!(s.cluster_text(1), None);
+    // a cluster is copied together with the surface
+    s.write_string(2, 0, "🇷🇴", attr, false);
+    let mut d = Surface::new(10, 2);
+    d.draw_surface(0, 1, &s);
+    assert_eq!(d.cluster_text(12), Some("🇷🇴"));
+    assert_eq!(d.cell_width(12), 2);
+    assert_eq!(d.char(3, 1).unwrap().code, unicode::CONTINUATION_CHAR);
+    // the clusters are removed when the surface is cleared
+    s.clear(Character::with_attributes(' ', attr));
+    assert_eq!(s.cluster_text(2), None);
+}
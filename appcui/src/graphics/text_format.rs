@@ -103,7 +103,7 @@ impl TextFormat {
         self.wrap_type = wrap_type;
     }
 
-    /// Sets the number of characters in the text buffer. This is usefull to perform some optimizations in particular for unicode characters.
+    /// Sets the width (in cells) of the text buffer (wide characters such as CJK ideographs or emojis use two cells). This is usefull to perform some optimizations in particular for unicode characters.
     #[inline(always)]
     pub fn set_chars_count(&mut self, value: u16) {
         self.chars_count = value;
@@ -209,7 +209,7 @@ impl TextFormatBuilder {
         self
     }
 
-    /// Sets the number of characters in the text buffer. This is usefull to perform some optimizations in particular for unicode characters.
+    /// Sets the width (in cells) of the text buffer (wide characters such as CJK ideographs or emojis use two cells). This is usefull to perform some optimizations in particular for unicode characters.
     #[inline(always)]
     pub fn chars_count(mut self, value: u16) -> Self {
         self.format.set_chars_count(value);
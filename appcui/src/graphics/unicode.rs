@@ -0,0 +1,221 @@
+/// The code of the second cell of a wide (two cells) character. A cell with this code is never painted by itself
+/// (the wide character from the previous cell covers it).
+pub(crate) const CONTINUATION_CHAR: char = '\u{10FFFE}';
+
+const ZWJ: char = '\u{200D}';
+
+#[inline(always)]
+fn is_control(ch: char) -> bool {
+    (ch as u32) < 0x20 || matches!(ch as u32, 0x7F..=0x9F)
+}
+
+#[inline(always)]
+fn is_regional_indicator(ch: char) -> bool {
+    matches!(ch as u32, 0x1F1E6..=0x1F1FF)
+}
+
+#[inline(always)]
+fn is_emoji_modifier(ch: char) -> bool {
+    matches!(ch as u32, 0x1F3FB..=0x1F3FF)
+}
+
+// characters that can follow a zero width joiner in an emoji sequence
+#[inline(always)]
+fn is_pictographic(ch: char) -> bool {
+    matches!(ch as u32, 0xA9 | 0xAE | 0x203C | 0x2049 | 0x2122 | 0x2139 | 0x2190..=0x21FF | 0x2300..=0x23FF | 0x2460..=0x27BF | 0x2900..=0x2BFF | 0x3030 | 0x303D | 0x3297 | 0x3299 | 0x1F000..=0x1FAFF)
+}
+
+// combining marks, variation selectors, joiners and other characters that are displayed together with the previous one
+#[inline(always)]
+fn is_zero_width(ch: char) -> bool {
+    matches!(ch as u32,
+        0x0300..=0x036F | 0x0483..=0x0489 | 0x0591..=0x05BD | 0x05BF | 0x05C1..=0x05C2 | 0x05C4..=0x05C5 | 0x05C7 |
+        0x0610..=0x061A | 0x064B..=0x065F | 0x0670 | 0x06D6..=0x06DC | 0x06DF..=0x06E4 | 0x06E7..=0x06E8 |
+        0x06EA..=0x06ED | 0x0711 | 0x0730..=0x074A | 0x07A6..=0x07B0 | 0x07EB..=0x07F3 | 0x07FD | 0x0816..=0x0819 |
+        0x081B..=0x0823 | 0x0825..=0x0827 | 0x0829..=0x082D | 0x0859..=0x085B | 0x0898..=0x089F | 0x08CA..=0x08E1 |
+        0x08E3..=0x0903 | 0x093A..=0x093C | 0x093E..=0x094F | 0x0951..=0x0957 | 0x0962..=0x0963 | 0x0981..=0x0983 |
+        0x09BC | 0x09BE..=0x09CD | 0x09D7 | 0x09E2..=0x09E3 | 0x09FE | 0x0A01..=0x0A03 | 0x0A3C..=0x0A51 |
+        0x0A70..=0x0A71 | 0x0A75 | 0x0A81..=0x0A83 | 0x0ABC | 0x0ABE..=0x0ACD | 0x0AE2..=0x0AE3 | 0x0AFA..=0x0AFF |
+        0x0B01..=0x0B03 | 0x0B3C | 0x0B3E..=0x0B57 | 0x0B62..=0x0B63 | 0x0B82 | 0x0BBE..=0x0BCD | 0x0BD7 |
+        0x0C00..=0x0C04 | 0x0C3C | 0x0C3E..=0x0C56 | 0x0C62..=0x0C63 | 0x0C81..=0x0C83 | 0x0CBC | 0x0CBE..=0x0CD6 |
+        0x0CE2..=0x0CE3 | 0x0D00..=0x0D03 | 0x0D3B..=0x0D3C | 0x0D3E..=0x0D4D | 0x0D57 | 0x0D62..=0x0D63 |
+        0x0D81..=0x0D83 | 0x0DCA..=0x0DDF | 0x0DF2..=0x0DF3 | 0x0E31 | 0x0E34..=0x0E3A | 0x0E47..=0x0E4E | 0x0EB1 |
+        0x0EB4..=0x0EBC | 0x0EC8..=0x0ECE | 0x0F18..=0x0F19 | 0x0F35 | 0x0F37 | 0x0F39 | 0x0F3E..=0x0F3F |
+        0x0F71..=0x0F84 | 0x0F86..=0x0F87 | 0x0F8D..=0x0FBC | 0x0FC6 | 0x102B..=0x103E | 0x1056..=0x1059 |
+        0x105E..=0x1060 | 0x1062..=0x1064 | 0x1067..=0x106D | 0x1071..=0x1074 | 0x1082..=0x108D | 0x108F |
+        0x109A..=0x109D | 0x1160..=0x11FF | 0x135D..=0x135F | 0x1712..=0x1715 | 0x1732..=0x1734 | 0x1752..=0x1753 |
+        0x1772..=0x1773 | 0x17B4..=0x17D3 | 0x17DD | 0x180B..=0x180F | 0x1885..=0x1886 | 0x18A9 | 0x1920..=0x193B |
+        0x1A17..=0x1A1B | 0x1A55..=0x1A7F | 0x1AB0..=0x1AFF | 0x1B00..=0x1B04 | 0x1B34..=0x1B44 | 0x1B6B..=0x1B73 |
+        0x1B80..=0x1B82 | 0x1BA1..=0x1BAD | 0x1BE6..=0x1BF3 | 0x1C24..=0x1C37 | 0x1CD0..=0x1CD2 | 0x1CD4..=0x1CE8 |
+        0x1CED | 0x1CF4 | 0x1CF7..=0x1CF9 | 0x1DC0..=0x1DFF | 0x200B..=0x200F | 0x202A..=0x202E | 0x2060..=0x2064 |
+        0x20D0..=0x20F0 | 0x2CEF..=0x2CF1 | 0x2D7F | 0x2DE0..=0x2DFF | 0x302A..=0x302F | 0x3099..=0x309A |
+        0xA66F..=0xA672 | 0xA674..=0xA67D | 0xA69E..=0xA69F | 0xA6F0..=0xA6F1 | 0xA802 | 0xA806 | 0xA80B |
+        0xA823..=0xA827 | 0xA82C | 0xA880..=0xA881 | 0xA8B4..=0xA8C5 | 0xA8E0..=0xA8F1 | 0xA8FF | 0xA926..=0xA92D |
+        0xA947..=0xA953 | 0xA980..=0xA983 | 0xA9B3..=0xA9C0 | 0xAA29..=0xAA36 | 0xAA43 | 0xAA4C..=0xAA4D |
+        0xAAEB..=0xAAEF | 0xAAF5..=0xAAF6 | 0xABE3..=0xABEA | 0xABEC..=0xABED | 0xD7B0..=0xD7FB | 0xFB1E |
+        0xFE00..=0xFE0F | 0xFE20..=0xFE2F | 0xFEFF | 0x1F3FB..=0x1F3FF | 0xE0000..=0xE007F | 0xE0100..=0xE01EF
+    )
+}
+
+#[inline(always)]
+fn is_wide(ch: char) -> bool {
+    matches!(ch as u32,
+        0x1100..=0x115F | 0x231A..=0x231B | 0x2329..=0x232A | 0x23E9..=0x23EC | 0x23F0 | 0x23F3 | 0x25FD..=0x25FE |
+        0x2614..=0x2615 | 0x2630..=0x2637 | 0x2648..=0x2653 | 0x267F | 0x268A..=0x268F | 0x2693 | 0x26A1 |
+        0x26AA..=0x26AB | 0x26BD..=0x26BE | 0x26C4..=0x26C5 | 0x26CE | 0x26D4 | 0x26EA | 0x26F2..=0x26F3 | 0x26F5 |
+        0x26FA | 0x26FD | 0x2705 | 0x270A..=0x270B | 0x2728 | 0x274C | 0x274E | 0x2753..=0x2755 | 0x2757 |
+        0x2795..=0x2797 | 0x27B0 | 0x27BF | 0x2B1B..=0x2B1C | 0x2B50 | 0x2B55 | 0x2E80..=0x2E99 | 0x2E9B..=0x2EF3 |
+        0x2F00..=0x2FD5 | 0x2FF0..=0x303E | 0x3041..=0x3096 | 0x3099..=0x30FF | 0x3105..=0x312F | 0x3131..=0x318E |
+        0x3190..=0x31E5 | 0x31EF..=0x321E | 0x3220..=0x3247 | 0x3250..=0xA48C | 0xA490..=0xA4C6 | 0xA960..=0xA97C |
+        0xAC00..=0xD7A3 | 0xF900..=0xFAFF | 0xFE10..=0xFE19 | 0xFE30..=0xFE52 | 0xFE54..=0xFE66 | 0xFE68..=0xFE6B |
+        0xFF01..=0xFF60 | 0xFFE0..=0xFFE6 | 0x16FE0..=0x16FE4 | 0x16FF0..=0x16FF6 | 0x17000..=0x18CD5 |
+        0x18CFF..=0x18D1E | 0x18D80..=0x18DF2 | 0x1AFF0..=0x1AFF3 | 0x1AFF5..=0x1AFFB | 0x1AFFD..=0x1AFFE |
+        0x1B000..=0x1B122 | 0x1B132 | 0x1B150..=0x1B152 | 0x1B155 | 0x1B164..=0x1B167 | 0x1B170..=0x1B2FB |
+        0x1D300..=0x1D356 | 0x1D360..=0x1D376 | 0x1F004 | 0x1F0CF | 0x1F18E | 0x1F191..=0x1F19A | 0x1F200..=0x1F202 |
+        0x1F210..=0x1F23B | 0x1F240..=0x1F248 | 0x1F250..=0x1F251 | 0x1F260..=0x1F265 | 0x1F300..=0x1F320 |
+        0x1F32D..=0x1F335 | 0x1F337..=0x1F37C | 0x1F37E..=0x1F393 | 0x1F3A0..=0x1F3CA | 0x1F3CF..=0x1F3D3 |
+        0x1F3E0..=0x1F3F0 | 0x1F3F4 | 0x1F3F8..=0x1F43E | 0x1F440 | 0x1F442..=0x1F4FC | 0x1F4FF..=0x1F53D |
+        0x1F54B..=0x1F54E | 0x1F550..=0x1F567 | 0x1F57A | 0x1F595..=0x1F596 | 0x1F5A4 | 0x1F5FB..=0x1F64F |
+        0x1F680..=0x1F6C5 | 0x1F6CC | 0x1F6D0..=0x1F6D2 | 0x1F6D5..=0x1F6D8 | 0x1F6DC..=0x1F6DF | 0x1F6EB..=0x1F6EC |
+        0x1F6F4..=0x1F6FC | 0x1F7E0..=0x1F7EB | 0x1F7F0 | 0x1F90C..=0x1F93A | 0x1F93C..=0x1F945 | 0x1F947..=0x1F9FF |
+        0x1FA70..=0x1FA7C | 0x1FA80..=0x1FA8A | 0x1FA8E..=0x1FAC6 | 0x1FAC8 | 0x1FACD..=0x1FADC | 0x1FADF..=0x1FAEA |
+        0x1FAEF..=0x1FAF8 | 0x20000..=0x2FFFD | 0x30000..=0x3FFFD
+    )
+}
+
+// true if there is no grapheme cluster boundary between `prev` and `next`
+// (`regional_indicators` is the number of consecutive regional indicators that end with `prev`)
+fn joins(prev: char, next: char, regional_indicators: usize) -> bool {
+    if prev == '\r' {
+        return next == '\n';
+    }
+    if is_control(prev) || is_control(next) || next == '\u{200B}' || prev == '\u{200B}' {
+        return false;
+    }
+    if is_zero_width(next) {
+        return true;
+    }
+    if prev == ZWJ {
+        return is_pictographic(next);
+    }
+    is_regional_indicator(prev) && is_regional_indicator(next) && (regional_indicators % 2 == 1)
+}
+
+/// Returns the number of cells (0, 1 or 2) used to display a character.
+/// Combining marks, variation selectors and joiners don't use any cell (they are displayed over the previous character),
+/// while east asian wide characters and most emojis use two cells.
+pub(crate) fn char_width(ch: char) -> u32 {
+    if (ch as u32) < 0x300 {
+        // fast path (ASCII and latin characters)
+        return 1;
+    }
+    if ch == CONTINUATION_CHAR {
+        return 0;
+    }
+    if is_zero_width(ch) && !is_emoji_modifier(ch) {
+        0
+    } else if is_wide(ch) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Returns the number of cells (1 or 2) used to display a grapheme cluster.
+pub(crate) fn grapheme_width(grapheme: &str) -> u32 {
+    let mut chars = grapheme.chars();
+    let Some(first) = chars.next() else {
+        return 0;
+    };
+    let mut width = char_width(first).max(1);
+    for ch in chars {
+        match ch {
+            // emoji presentation (e.g. U+2764 U+FE0F)
+            '\u{FE0F}' => width = 2,
+            // text presentation
+            '\u{FE0E}' => width = 1,
+            // a flag (a pair of regional indicators)
+            _ if is_regional_indicator(ch) && is_regional_indicator(first) => width = 2,
+            _ => {}
+        }
+    }
+    width
+}
+
+/// Returns the number of cells used to display a text (on a single line).
+pub(crate) fn text_width(text: &str) -> u32 {
+    graphemes(text).map(grapheme_width).sum()
+}
+
+/// Returns the byte offset of the end of the grapheme cluster that starts at `pos`.
+pub(crate) fn next_grapheme_boundary(text: &str, pos: usize) -> usize {
+    let mut chars = text[pos..].char_indices();
+    let Some((_, first)) = chars.next() else {
+        return text.len();
+    };
+    let mut prev = first;
+    let mut regional_indicators = is_regional_indicator(first) as usize;
+    for (ofs, ch) in chars {
+        if !joins(prev, ch, regional_indicators) {
+            return pos + ofs;
+        }
+        regional_indicators = if is_regional_indicator(ch) { regional_indicators + 1 } else { 0 };
+        prev = ch;
+    }
+    text.len()
+}
+
+/// Returns the byte offset of the start of the grapheme cluster that ends at `pos`.
+pub(crate) fn previous_grapheme_boundary(text: &str, pos: usize) -> usize {
+    let head = &text[..pos];
+    let Some((mut start, mut current)) = head.char_indices().next_back() else {
+        return 0;
+    };
+    while let Some((prev_start, prev)) = head[..start].char_indices().next_back() {
+        let regional_indicators = if is_regional_indicator(prev) {
+            head[..start].chars().rev().take_while(|c| is_regional_indicator(*c)).count()
+        } else {
+            0
+        };
+        if !joins(prev, current, regional_indicators) {
+            break;
+        }
+        start = prev_start;
+        current = prev;
+    }
+    start
+}
+
+/// An iterator over the grapheme clusters of a text (a simplified version of the Unicode text segmentation rules:
+/// combining marks, variation selectors, emoji modifiers, zero width joiner sequences and flags are kept together).
+pub(crate) struct Graphemes<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.text.len() {
+            return None;
+        }
+        let start = self.pos;
+        self.pos = next_grapheme_boundary(self.text, start);
+        Some(&self.text[start..self.pos])
+    }
+}
+
+/// Returns an iterator over the grapheme clusters of a text.
+pub(crate) fn graphemes(text: &str) -> Graphemes<'_> {
+    Graphemes { text, pos: 0 }
+}
+
+/// Returns an iterator over the grapheme clusters of a text and their byte offsets.
+pub(crate) fn grapheme_indices(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut pos = 0;
+    graphemes(text).map(move |grapheme| {
+        let offset = pos;
+        pos += grapheme.len();
+        (offset, grapheme)
+    })
+}
@@ -4,6 +4,7 @@
 
 mod key;
 mod key_code;
+mod key_map;
 mod key_modifier;
 mod mouse_event;
 mod mouse_button;
@@ -16,6 +17,8 @@ mod tests;
 pub use self::key::Key;
 pub use self::key_code::KeyCode;
 pub use self::key_modifier::KeyModifier;
+pub use self::key_map::KeyMap;
+pub(crate) use self::key_map::ChordStatus;
 pub use self::mouse_event::MouseEvent;
 pub use self::mouse_event::MouseEventData;
 pub use self::mouse_button::MouseButton;
@@ -1,4 +1,5 @@
 use std::fmt::Display;
+use std::str::FromStr;
 
 use super::KeyCode;
 use super::KeyModifier;
@@ -165,7 +166,7 @@ impl From<u16> for Key {
     fn from(value: u16) -> Self {
         let k = (value & 0xFF) as u8;
         let m = (value >> 8) as u8;
-        if (k >= 64) || (m >= 8) {
+        if (k >= 64) || (m >= 32) {
             Key::None
         } else {
             Self {
@@ -289,3 +290,57 @@ impl Display for Key {
         write!(f, "{}{}", self.modifier.name(), self.code.name())
     }
 }
+
+impl FromStr for Key {
+    type Err = crate::system::Error;
+
+    /// Creates a new key from its name (the same format as the one used by the `key!` macro or by the `Display` trait),
+    /// for example `"Ctrl+Alt+F"`, `"Shift+Tab"` or `"F2"`. The names are case insensitive.
+    ///
+    /// # Example
+    /// ```rust
+    /// use appcui::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let key = Key::from_str("Ctrl+Shift+A").unwrap();
+    /// assert_eq!(key, Key::new(KeyCode::A, KeyModifier::Ctrl | KeyModifier::Shift));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |reason: &str| crate::system::Error::new(crate::system::ErrorKind::InvalidParameter, format!("Invalid key '{s}': {reason}"));
+        let mut key = Key::None;
+        for part in s.trim().split('+').map(str::trim) {
+            let modifier = match part.to_ascii_lowercase().as_str() {
+                "ctrl" => KeyModifier::Ctrl,
+                "alt" => KeyModifier::Alt,
+                "shift" => KeyModifier::Shift,
+                "super" => KeyModifier::Super,
+                "meta" => KeyModifier::Meta,
+                _ => KeyModifier::None,
+            };
+            if modifier != KeyModifier::None {
+                if key.modifier.contains(modifier) {
+                    return Err(error(&format!("modifier '{part}' is used twice")));
+                }
+                key.modifier |= modifier;
+                continue;
+            }
+            if key.code != KeyCode::None {
+                return Err(error("only one key can be used (besides modifiers)"));
+            }
+            key.code = match part.to_ascii_lowercase().as_str() {
+                "back" => KeyCode::Backspace,
+                "esc" => KeyCode::Escape,
+                "del" => KeyCode::Delete,
+                "ins" => KeyCode::Insert,
+                name => match (1..64u8).map(KeyCode::from).find(|c| c.name().eq_ignore_ascii_case(name)) {
+                    Some(code) => code,
+                    None => return Err(error(&format!("unknown key '{part}'"))),
+                },
+            };
+        }
+        if key.code == KeyCode::None {
+            return Err(error("a key (besides modifiers) is required"));
+        }
+        Ok(key)
+    }
+}
@@ -0,0 +1,493 @@
+use super::Key;
+use appcui_proc_macro::key;
+use crate::system::{Error, ErrorKind, RuntimeManager};
+use std::fmt::Write;
+use std::str::FromStr;
+
+const MAX_CHORD_KEYS: usize = 4;
+
+// the built-in actions (`control.action`) and their default keys
+static ACTIONS: &[(&str, u16)] = &[
+    // TextArea
+    ("textarea.move_up", key!("Up")),
+    ("textarea.move_down", key!("Down")),
+    ("textarea.page_up", key!("PageUp")),
+    ("textarea.page_down", key!("PageDown")),
+    ("textarea.move_left", key!("Left")),
+    ("textarea.move_right", key!("Right")),
+    ("textarea.select_up", key!("Shift+Up")),
+    ("textarea.select_down", key!("Shift+Down")),
+    ("textarea.select_left", key!("Shift+Left")),
+    ("textarea.select_right", key!("Shift+Right")),
+    ("textarea.word_left", key!("Ctrl+Left")),
+    ("textarea.word_right", key!("Ctrl+Right")),
+    ("textarea.select_word_left", key!("Ctrl+Shift+Left")),
+    ("textarea.select_word_right", key!("Ctrl+Shift+Right")),
+    ("textarea.select_all", key!("Ctrl+A")),
+    ("textarea.copy", key!("Ctrl+C")),
+    ("textarea.paste", key!("Ctrl+V")),
+    ("textarea.delete_back", key!("Back")),
+    ("textarea.delete", key!("Delete")),
+    ("textarea.delete_word_back", key!("Ctrl+Back")),
+    ("textarea.delete_word", key!("Ctrl+Delete")),
+    ("textarea.new_line", key!("Enter")),
+    // TextField
+    ("textfield.move_left", key!("Left")),
+    ("textfield.move_right", key!("Right")),
+    ("textfield.move_up", key!("Up")),
+    ("textfield.move_down", key!("Down")),
+    ("textfield.home", key!("Home")),
+    ("textfield.end", key!("End")),
+    ("textfield.word_left", key!("Ctrl+Left")),
+    ("textfield.word_right", key!("Ctrl+Right")),
+    ("textfield.select_all", key!("Ctrl+A")),
+    ("textfield.copy", key!("Ctrl+C")),
+    ("textfield.cut", key!("Ctrl+X")),
+    ("textfield.paste", key!("Ctrl+V")),
+    ("textfield.upper_case", key!("Ctrl+Shift+U")),
+    ("textfield.lower_case", key!("Ctrl+U")),
+    ("textfield.undo", key!("Ctrl+Z")),
+    ("textfield.redo", key!("Ctrl+Y")),
+    ("textfield.delete_back", key!("Back")),
+    ("textfield.delete", key!("Delete")),
+    ("textfield.validate", key!("Enter")),
+    // ListView
+    ("listview.move_up", key!("Up")),
+    ("listview.move_down", key!("Down")),
+    ("listview.scroll_up", key!("Ctrl+Alt+Up")),
+    ("listview.scroll_down", key!("Ctrl+Alt+Down")),
+    ("listview.home", key!("Home")),
+    ("listview.end", key!("End")),
+    ("listview.page_up", key!("PageUp")),
+    ("listview.page_down", key!("PageDown")),
+    ("listview.toggle_selection", key!("Space")),
+    ("listview.select_down", key!("Shift+Down")),
+    ("listview.select_up", key!("Shift+Up")),
+    ("listview.select_all", key!("Ctrl+A")),
+    ("listview.copy", key!("Ctrl+C")),
+    ("listview.action", key!("Enter")),
+    // Window
+    ("window.next_control", key!("Tab")),
+    ("window.previous_control", key!("Shift+Tab")),
+    ("window.resize_mode", key!("Ctrl+Alt+M")),
+    ("window.close", key!("Escape")),
+    // ListBox
+    ("listbox.move_up", key!("Up")),
+    ("listbox.move_down", key!("Down")),
+    ("listbox.scroll_left", key!("Left")),
+    ("listbox.scroll_right", key!("Right")),
+    ("listbox.scroll_to_start", key!("Ctrl+Alt+Left")),
+    ("listbox.scroll_to_end", key!("Ctrl+Alt+Right")),
+    ("listbox.scroll_up", key!("Ctrl+Alt+Up")),
+    ("listbox.scroll_down", key!("Ctrl+Alt+Down")),
+    ("listbox.home", key!("Home")),
+    ("listbox.end", key!("End")),
+    ("listbox.page_up", key!("PageUp")),
+    ("listbox.page_down", key!("PageDown")),
+    ("listbox.toggle_check", key!("Space")),
+    ("listbox.action", key!("Enter")),
+    // TreeView
+    ("treeview.move_up", key!("Up")),
+    ("treeview.move_down", key!("Down")),
+    ("treeview.scroll_up", key!("Ctrl+Alt+Up")),
+    ("treeview.scroll_down", key!("Ctrl+Alt+Down")),
+    ("treeview.home", key!("Home")),
+    ("treeview.end", key!("End")),
+    ("treeview.page_up", key!("PageUp")),
+    ("treeview.page_down", key!("PageDown")),
+    ("treeview.select_down", key!("Shift+Down")),
+    ("treeview.select_up", key!("Shift+Up")),
+    ("treeview.select_to_start", key!("Shift+Home")),
+    ("treeview.select_to_end", key!("Shift+End")),
+    ("treeview.select_page_up", key!("Shift+PageUp")),
+    ("treeview.select_page_down", key!("Shift+PageDown")),
+    ("treeview.fold", key!("Space")),
+    ("treeview.fold_all", key!("Ctrl+Space")),
+    ("treeview.copy", key!("Ctrl+C")),
+    ("treeview.action", key!("Enter")),
+    // Tab
+    ("tab.next_page", key!("Ctrl+Tab")),
+    ("tab.previous_page", key!("Ctrl+Shift+Tab")),
+    // Accordion
+    ("accordion.next_panel", key!("Ctrl+Tab")),
+    ("accordion.previous_panel", key!("Ctrl+Shift+Tab")),
+    // Menu
+    ("menu.move_up", key!("Up")),
+    ("menu.move_down", key!("Down")),
+    ("menu.home", key!("Home")),
+    ("menu.end", key!("End")),
+    ("menu.page_up", key!("PageUp")),
+    ("menu.page_down", key!("PageDown")),
+    ("menu.run", key!("Enter")),
+    ("menu.close", key!("Escape")),
+    ("menu.parent_menu", key!("Left")),
+    ("menu.open_submenu", key!("Right")),
+    // AppBar (while a menu from the app bar is opened)
+    ("appbar.previous_menu", key!("Left")),
+    ("appbar.next_menu", key!("Right")),
+    // Desktop
+    ("desktop.toggle_tiling", key!("Ctrl+Alt+T")),
+    ("desktop.window_switcher", key!("Alt+0")),
+    ("desktop.focus_left", key!("Alt+Shift+Left")),
+    ("desktop.focus_right", key!("Alt+Shift+Right")),
+    ("desktop.focus_up", key!("Alt+Shift+Up")),
+    ("desktop.focus_down", key!("Alt+Shift+Down")),
+    ("desktop.move_left", key!("Ctrl+Alt+Shift+Left")),
+    ("desktop.move_right", key!("Ctrl+Alt+Shift+Right")),
+    ("desktop.move_up", key!("Ctrl+Alt+Shift+Up")),
+    ("desktop.move_down", key!("Ctrl+Alt+Shift+Down")),
+];
+
+#[derive(Copy, Clone, PartialEq, Eq, Default)]
+struct Chord {
+    keys: [Key; MAX_CHORD_KEYS],
+    count: u8,
+}
+
+impl Chord {
+    fn single(key: Key) -> Self {
+        let mut c = Chord::default();
+        c.push(key);
+        c
+    }
+    fn push(&mut self, key: Key) -> bool {
+        if (self.count as usize) >= MAX_CHORD_KEYS {
+            return false;
+        }
+        self.keys[self.count as usize] = key;
+        self.count += 1;
+        true
+    }
+    #[inline(always)]
+    fn keys(&self) -> &[Key] {
+        &self.keys[..self.count as usize]
+    }
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+    fn starts_with(&self, prefix: &Chord) -> bool {
+        self.count > prefix.count && self.keys().starts_with(prefix.keys())
+    }
+    fn parse(text: &str) -> Result<Chord, Error> {
+        let mut c = Chord::default();
+        for part in text.split_whitespace() {
+            if !c.push(Key::from_str(part)?) {
+                return Err(Error::new(
+                    ErrorKind::InvalidParameter,
+                    format!("Invalid key chord '{text}': a chord can have at most {MAX_CHORD_KEYS} keys"),
+                ));
+            }
+        }
+        Ok(c)
+    }
+    fn write_to(&self, output: &mut String) {
+        for (index, key) in self.keys().iter().enumerate() {
+            if index > 0 {
+                output.push(' ');
+            }
+            let _ = write!(output, "{key}");
+        }
+    }
+}
+
+pub(crate) enum ChordStatus {
+    // the key is not part of a multi-key chord
+    None,
+    // the key was added to the chord that is being typed
+    Pending,
+    // the key completed a chord (the focused control will receive it via `KeyMap::translate(...)`)
+    Completed,
+    // the key does not continue any chord (the chord that was being typed is discarded)
+    Cancelled,
+}
+
+/// A `KeyMap` holds the keys (or key chords) that trigger the built-in actions of the controls (such as moving the
+/// cursor one word to the left in a `TextArea` or selecting all items in a `ListView`).
+///
+/// Every action has a name in the format `control.action` (e.g. `textarea.word_left` or `listview.select_all`) and a default key.
+/// An action can be bound to another key or to a sequence of up to 4 keys (a chord, such as `Ctrl+K Ctrl+C`) - in this case
+/// the keys have to be pressed one after another and, while the chord is being typed, the keys that were pressed so far
+/// are displayed in the command bar (if any). Once an action is bound to another key, its default key no longer triggers it.
+///
+/// The key map is set when the application is created (via `App::new().key_map(...)`) or later on via `App::set_key_map(...)`.
+///
+/// # Example
+/// ```rust
+/// use appcui::prelude::*;
+///
+/// let mut km = KeyMap::new();
+/// km.bind("textarea.word_left", "Alt+B").unwrap();
+/// km.bind("listview.select_all", "Ctrl+K Ctrl+A").unwrap();
+/// // or (from a configuration file)
+/// km.load("textarea.word_right = Alt+F\n# comment\nwindow.close = Ctrl+W").unwrap();
+/// assert_eq!(km.chord("textarea.word_right").as_deref(), Some("Alt+F"));
+/// ```
+#[derive(Clone)]
+pub struct KeyMap {
+    bindings: Vec<Chord>,
+    customized: bool,
+    has_chords: bool,
+    pending: Chord,
+    completed: Chord,
+}
+
+impl KeyMap {
+    /// Creates a new key map with the default keys for all actions.
+    pub fn new() -> Self {
+        Self {
+            bindings: ACTIONS.iter().map(|(_, key)| Chord::single(Key::from(*key))).collect(),
+            customized: false,
+            has_chords: false,
+            pending: Chord::default(),
+            completed: Chord::default(),
+        }
+    }
+
+    /// Returns the names of all built-in actions.
+    pub fn actions() -> impl Iterator<Item = &'static str> {
+        ACTIONS.iter().map(|(name, _)| *name)
+    }
+
+    /// Binds an action to a key (e.g. `"Alt+B"`) or to a chord (keys separated by spaces, e.g. `"Ctrl+K Ctrl+C"`).
+    /// An empty string (or `"None"`) unbinds the action. Returns an error if the action or the keys are not valid or if
+    /// the keys conflict with the keys of another action (see `load(...)` for a way to swap the keys of two actions):
+    /// * two actions of the same control can not be bound to the same keys
+    /// * the keys of an action can not be the first keys of a chord bound to another action (the chord would hide them)
+    pub fn bind(&mut self, action: &str, chord: &str) -> Result<(), Error> {
+        let index = Self::action_index(action)?;
+        let chord = Self::parse_chord(chord)?;
+        self.check_conflict(index, &chord)?;
+        self.bindings[index] = chord;
+        self.update_flags();
+        Ok(())
+    }
+
+    /// Restores the default key of an action. Returns an error if the default key is now used by another action.
+    pub fn reset(&mut self, action: &str) -> Result<(), Error> {
+        let index = Self::action_index(action)?;
+        let chord = Chord::single(Key::from(ACTIONS[index].1));
+        self.check_conflict(index, &chord)?;
+        self.bindings[index] = chord;
+        self.update_flags();
+        Ok(())
+    }
+
+    /// Returns the key (or chord) an action is bound to (in the same format as the one used by `bind(...)`) or `None` if the
+    /// action does not exist or is not bound to any key.
+    pub fn chord(&self, action: &str) -> Option<String> {
+        let index = Self::action_index(action).ok()?;
+        let chord = &self.bindings[index];
+        if chord.is_empty() {
+            return None;
+        }
+        let mut s = String::new();
+        chord.write_to(&mut s);
+        Some(s)
+    }
+
+    /// Loads the bindings from a text (usually the content of a configuration file) where every line has the format
+    /// `action = keys` (for example `textarea.word_left = Alt+B`). Empty lines and lines that start with `#` are ignored.
+    ///
+    /// The conflicts between actions (see `bind(...)`) are checked after all lines were loaded (so the keys of two actions can be swapped).
+    /// If a line is not valid (the error contains the line number) or if there are conflicts, an error is returned and the key map is not modified.
+    pub fn load(&mut self, text: &str) -> Result<(), Error> {
+        let mut bindings = self.bindings.clone();
+        for (line_index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((action, chord)) = line.split_once('=') else {
+                return Err(Error::new(
+                    ErrorKind::InvalidParameter,
+                    format!("Line {}: expecting 'action = keys' but found '{line}'", line_index + 1),
+                ));
+            };
+            match Self::action_index(action.trim()).and_then(|index| Ok((index, Self::parse_chord(chord)?))) {
+                Ok((index, chord)) => bindings[index] = chord,
+                Err(err) => return Err(Error::new(ErrorKind::InvalidParameter, format!("Line {}: {err}", line_index + 1))),
+            }
+        }
+        let previous = std::mem::replace(&mut self.bindings, bindings);
+        for index in 0..ACTIONS.len() {
+            if let Err(err) = self.check_conflict(index, &self.bindings[index]) {
+                self.bindings = previous;
+                return Err(err);
+            }
+        }
+        self.update_flags();
+        Ok(())
+    }
+
+    /// Loads the bindings from a configuration file (see `load(...)` for the format of the file).
+    pub fn load_from_file(&mut self, path: &std::path::Path) -> Result<(), Error> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => self.load(&text),
+            Err(err) => Err(Error::new(ErrorKind::InvalidParameter, format!("Fail to read '{}': {err}", path.display()))),
+        }
+    }
+
+    /// Returns all the bindings in the format used by `load(...)` (one action per line).
+    pub fn to_config_string(&self) -> String {
+        let mut s = String::new();
+        for ((name, _), chord) in ACTIONS.iter().zip(&self.bindings) {
+            s.push_str(name);
+            s.push_str(" = ");
+            if chord.is_empty() {
+                s.push_str("None");
+            } else {
+                chord.write_to(&mut s);
+            }
+            s.push('\n');
+        }
+        s
+    }
+
+    fn action_index(action: &str) -> Result<usize, Error> {
+        ACTIONS
+            .iter()
+            .position(|(name, _)| *name == action)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidParameter, format!("Unknown action: '{action}'")))
+    }
+
+    fn parse_chord(text: &str) -> Result<Chord, Error> {
+        match text.trim() {
+            "" => Ok(Chord::default()),
+            c if c.eq_ignore_ascii_case("none") => Ok(Chord::default()),
+            c => Chord::parse(c),
+        }
+    }
+
+    // checks if the action `index` can be bound to `chord` (the keys must not be ambiguous)
+    fn check_conflict(&self, index: usize, chord: &Chord) -> Result<(), Error> {
+        if chord.is_empty() {
+            return Ok(());
+        }
+        let name = ACTIONS[index].0;
+        for (other_index, ((other_name, _), other)) in ACTIONS.iter().zip(&self.bindings).enumerate() {
+            if other_index == index || other.is_empty() {
+                continue;
+            }
+            let same_keys = other == chord && Self::scope(other_name) == Self::scope(name);
+            if same_keys || other.starts_with(chord) || chord.starts_with(other) {
+                let mut keys = String::new();
+                chord.write_to(&mut keys);
+                let mut other_keys = String::new();
+                other.write_to(&mut other_keys);
+                return Err(Error::new(
+                    ErrorKind::InvalidParameter,
+                    format!("Keys '{keys}' of action '{name}' conflict with keys '{other_keys}' of action '{other_name}'"),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn scope(name: &str) -> &str {
+        name.split_once('.').map(|(scope, _)| scope).unwrap_or(name)
+    }
+
+    fn update_flags(&mut self) {
+        self.customized = ACTIONS.iter().zip(&self.bindings).any(|((_, key), chord)| *chord != Chord::single(Key::from(*key)));
+        self.has_chords = self.bindings.iter().any(|c| c.count > 1);
+    }
+
+    // processes a key from a multi-key chord
+    pub(crate) fn process_chord_key(&mut self, key: Key) -> ChordStatus {
+        if !self.has_chords {
+            return ChordStatus::None;
+        }
+        let mut candidate = self.pending;
+        candidate.push(key);
+        if self.bindings.iter().any(|c| c.starts_with(&candidate)) {
+            self.pending = candidate;
+            return ChordStatus::Pending;
+        }
+        if self.pending.is_empty() {
+            return ChordStatus::None;
+        }
+        self.pending = Chord::default();
+        if self.bindings.contains(&candidate) {
+            self.completed = candidate;
+            ChordStatus::Completed
+        } else {
+            ChordStatus::Cancelled
+        }
+    }
+
+    // the keys of the chord that is being typed (e.g. "Ctrl+K ...")
+    pub(crate) fn pending_chord_text(&self) -> Option<String> {
+        if self.pending.is_empty() {
+            return None;
+        }
+        let mut s = String::new();
+        self.pending.write_to(&mut s);
+        s.push_str(" ...");
+        Some(s)
+    }
+
+    // discards the keys of the chord that is being typed (returns true if there was such a chord)
+    pub(crate) fn cancel_pending_chord(&mut self) -> bool {
+        let pending = !self.pending.is_empty();
+        self.pending = Chord::default();
+        pending
+    }
+
+    pub(crate) fn clear_completed_chord(&mut self) {
+        self.completed = Chord::default();
+    }
+
+    // translates a key received by a control (whose actions are named `scope.action`) into the default key of the action
+    // it is bound to, so that the control can process it as it would process the default key. The default key of an action
+    // that was bound to another key is translated into `Key::None`.
+    pub(crate) fn translate(&self, scope: &str, key: Key, character: char) -> (Key, char) {
+        if !self.completed.is_empty() {
+            return match self.find(scope, |_, chord| *chord == self.completed) {
+                Some(default_key) => (default_key, '\0'),
+                None => (Key::None, '\0'),
+            };
+        }
+        if !self.customized {
+            return (key, character);
+        }
+        let chord = Chord::single(key);
+        if let Some(default_key) = self.find(scope, |_, c| *c == chord) {
+            return if default_key == key { (key, character) } else { (default_key, '\0') };
+        }
+        if self.find(scope, |default_key, _| default_key == key).is_some() {
+            return (Key::None, '\0');
+        }
+        (key, character)
+    }
+
+    // translates a key (see `translate(...)`) using the key map of the running application
+    // (the key is not changed if there is no application - e.g. a control that is used outside of an application)
+    pub(crate) fn translate_key(scope: &str, key: Key, character: char) -> (Key, char) {
+        if RuntimeManager::is_instantiated() {
+            RuntimeManager::get().key_map().translate(scope, key, character)
+        } else {
+            (key, character)
+        }
+    }
+
+    fn find<F>(&self, scope: &str, f: F) -> Option<Key>
+    where
+        F: Fn(Key, &Chord) -> bool,
+    {
+        for ((name, default_key), chord) in ACTIONS.iter().zip(&self.bindings) {
+            let in_scope = name.len() > scope.len() && name.starts_with(scope) && name.as_bytes()[scope.len()] == b'.';
+            if in_scope && f(Key::from(*default_key), chord) {
+                return Some(Key::from(*default_key));
+            }
+        }
+        None
+    }
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
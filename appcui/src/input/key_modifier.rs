@@ -5,9 +5,11 @@ pub enum KeyModifier {
     Alt = 0x01,
     Ctrl = 0x02,
     Shift = 0x04,
+    Super = 0x08,
+    Meta = 0x10,
 }
 
-static KEY_NAME: [&str; 8] = [
+static KEY_NAME: [&str; 32] = [
     /* 0 */ "",
     /* 1 */ "Alt+",
     /* 2 */ "Ctrl+",
@@ -16,6 +18,30 @@ static KEY_NAME: [&str; 8] = [
     /* 5 */ "Alt+Shift+",
     /* 6 */ "Ctrl+Shift+",
     /* 7 */ "Ctrl+Alt+Shift+",
+    /* 8 */ "Super+",
+    /* 9 */ "Alt+Super+",
+    /* 10 */ "Ctrl+Super+",
+    /* 11 */ "Ctrl+Alt+Super+",
+    /* 12 */ "Shift+Super+",
+    /* 13 */ "Alt+Shift+Super+",
+    /* 14 */ "Ctrl+Shift+Super+",
+    /* 15 */ "Ctrl+Alt+Shift+Super+",
+    /* 16 */ "Meta+",
+    /* 17 */ "Alt+Meta+",
+    /* 18 */ "Ctrl+Meta+",
+    /* 19 */ "Ctrl+Alt+Meta+",
+    /* 20 */ "Shift+Meta+",
+    /* 21 */ "Alt+Shift+Meta+",
+    /* 22 */ "Ctrl+Shift+Meta+",
+    /* 23 */ "Ctrl+Alt+Shift+Meta+",
+    /* 24 */ "Super+Meta+",
+    /* 25 */ "Alt+Super+Meta+",
+    /* 26 */ "Ctrl+Super+Meta+",
+    /* 27 */ "Ctrl+Alt+Super+Meta+",
+    /* 28 */ "Shift+Super+Meta+",
+    /* 29 */ "Alt+Shift+Super+Meta+",
+    /* 30 */ "Ctrl+Shift+Super+Meta+",
+    /* 31 */ "Ctrl+Alt+Shift+Super+Meta+",
 ];
 
 impl KeyModifier {
@@ -32,13 +58,13 @@ impl KeyModifier {
     /// let name = modifier.name();
     /// ```
     pub fn name(&self) -> &'static str {
-        if self.value < 8 {
+        if self.value < 32 {
             return KEY_NAME[self.value as usize];
         }
         ""
     }
     pub(crate) fn name_from_index(index: usize) -> &'static str {
-        if index < 8 { KEY_NAME[index] } else { "" }
+        if index < 32 { KEY_NAME[index] } else { "" }
     }
 }
 
@@ -58,7 +84,7 @@ impl From<u8> for KeyModifier {
     /// let modifier = KeyModifier::from(0x01);
     /// ```         
     fn from(value: u8) -> Self {
-        if value < 8 {
+        if value < 32 {
             let mut result = KeyModifier::None;
             if (value & 1) != 0 {
                 result |= KeyModifier::Alt;
@@ -69,6 +95,12 @@ impl From<u8> for KeyModifier {
             if (value & 4) != 0 {
                 result |= KeyModifier::Shift;
             }
+            if (value & 8) != 0 {
+                result |= KeyModifier::Super;
+            }
+            if (value & 16) != 0 {
+                result |= KeyModifier::Meta;
+            }
             return result;
         }
         KeyModifier::None
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use super::ChordStatus;
 
 #[test]
 fn check_from_for_keycode() {
@@ -241,11 +242,145 @@ fn check_keymodifier_from() {
     assert_eq!(KeyModifier::from(5), KeyModifier::Shift | KeyModifier::Alt);
     assert_eq!(KeyModifier::from(6), KeyModifier::Ctrl | KeyModifier::Shift);
     assert_eq!(KeyModifier::from(7), KeyModifier::Shift | KeyModifier::Ctrl | KeyModifier::Alt);
-    assert_eq!(KeyModifier::from(8), KeyModifier::None);
+    assert_eq!(KeyModifier::from(8), KeyModifier::Super);
+    assert_eq!(KeyModifier::from(16), KeyModifier::Meta);
+    assert_eq!(KeyModifier::from(26), KeyModifier::Ctrl | KeyModifier::Super | KeyModifier::Meta);
+    assert_eq!(KeyModifier::from(32), KeyModifier::None);
 }
 
 #[test]
 fn check_key_display() {
     let key = Key::new(KeyCode::A, KeyModifier::Ctrl|KeyModifier::Shift);
     assert_eq!(key.to_string(), "Ctrl+Shift+A");
-}
\ No newline at end of file
+    let key = Key::new(KeyCode::Enter, KeyModifier::Ctrl | KeyModifier::Super);
+    assert_eq!(key.to_string(), "Ctrl+Super+Enter");
+    assert_eq!(key!("Ctrl+Super+Enter"), key.value());
+    assert_eq!(Key::from(key!("Alt+Meta+F1")), Key::new(KeyCode::F1, KeyModifier::Alt | KeyModifier::Meta));
+}
+#[test]
+fn check_key_from_str() {
+    use std::str::FromStr;
+    assert_eq!(Key::from_str("Ctrl+Shift+A").unwrap(), Key::new(KeyCode::A, KeyModifier::Ctrl | KeyModifier::Shift));
+    assert_eq!(Key::from_str("alt+f1").unwrap(), Key::new(KeyCode::F1, KeyModifier::Alt));
+    assert_eq!(Key::from_str("Esc").unwrap(), Key::new(KeyCode::Escape, KeyModifier::None));
+    assert_eq!(Key::from_str("Super+Enter").unwrap(), Key::new(KeyCode::Enter, KeyModifier::Super));
+    assert!(Key::from_str("Ctrl+").is_err());
+    assert!(Key::from_str("Ctrl+Foo").is_err());
+    assert!(Key::from_str("Hyper+A").is_err());
+}
+
+#[test]
+fn check_keymap_bind() {
+    let mut km = KeyMap::new();
+    assert_eq!(km.chord("textarea.word_left").as_deref(), Some("Ctrl+Left"));
+    km.bind("textarea.word_left", "alt+b").unwrap();
+    assert_eq!(km.chord("textarea.word_left").as_deref(), Some("Alt+B"));
+    km.bind("listview.select_all", "Ctrl+K Ctrl+A").unwrap();
+    assert_eq!(km.chord("listview.select_all").as_deref(), Some("Ctrl+K Ctrl+A"));
+    km.bind("window.close", "None").unwrap();
+    assert_eq!(km.chord("window.close"), None);
+    km.reset("window.close").unwrap();
+    assert_eq!(km.chord("window.close").as_deref(), Some("Escape"));
+    assert!(km.bind("textarea.unknown", "Alt+B").is_err());
+    assert!(km.bind("textarea.copy", "Alt+Foo").is_err());
+    assert!(km.bind("textarea.copy", "F1 F2 F3 F4 F5").is_err());
+    assert_eq!(km.chord("textarea.unknown"), None);
+    assert!(KeyMap::actions().any(|a| a == "textfield.undo"));
+    assert!(KeyMap::actions().any(|a| a == "menu.run"));
+}
+
+#[test]
+fn check_keymap_bind_conflicts() {
+    let mut km = KeyMap::new();
+    // two actions of the same control can not use the same keys
+    let err = km.bind("textarea.copy", "Ctrl+V").unwrap_err();
+    assert!(err.to_string().contains("textarea.paste"));
+    assert_eq!(km.chord("textarea.copy").as_deref(), Some("Ctrl+C"));
+    // ... but actions of different controls can
+    km.bind("textarea.copy", "Ctrl+Insert").unwrap();
+    km.bind("listview.copy", "Ctrl+Insert").unwrap();
+    // a key can not be the first key of a chord (from any control)
+    assert!(km.bind("listview.select_all", "Ctrl+Insert Ctrl+A").is_err());
+    km.bind("listview.select_all", "Ctrl+K Ctrl+A").unwrap();
+    assert!(km.bind("treeview.copy", "Ctrl+K").is_err());
+    assert!(km.bind("textarea.copy", "Ctrl+K Ctrl+A Ctrl+B").is_err());
+    // the default key of an action can not be restored if it is used by another action
+    km.bind("textarea.word_left", "None").unwrap();
+    km.bind("textarea.word_right", "Ctrl+Left").unwrap();
+    assert!(km.reset("textarea.word_left").is_err());
+    km.reset("textarea.word_right").unwrap();
+    km.reset("textarea.word_left").unwrap();
+}
+
+#[test]
+fn check_keymap_load() {
+    let mut km = KeyMap::new();
+    km.load("# my keys\n\ntextarea.word_right = Alt+F\n  window.close = Ctrl+W  \n").unwrap();
+    assert_eq!(km.chord("textarea.word_right").as_deref(), Some("Alt+F"));
+    assert_eq!(km.chord("window.close").as_deref(), Some("Ctrl+W"));
+    // an invalid line - nothing is loaded
+    let err = km.load("textarea.copy = Ctrl+Insert\nwindow.close Ctrl+Q").unwrap_err();
+    assert!(err.to_string().contains("Line 2"));
+    assert_eq!(km.chord("textarea.copy").as_deref(), Some("Ctrl+C"));
+    assert!(km.load("textfield.undo = Ctrl+Q\nlistview.foo = F2").unwrap_err().to_string().contains("Line 2"));
+    assert_eq!(km.chord("textfield.undo").as_deref(), Some("Ctrl+Z"));
+    // conflicts are checked after all lines were loaded (keys can be swapped)
+    km.load("textarea.copy = Ctrl+V\ntextarea.paste = Ctrl+C").unwrap();
+    assert_eq!(km.chord("textarea.copy").as_deref(), Some("Ctrl+V"));
+    assert!(km.load("textarea.copy = Ctrl+C").unwrap_err().to_string().contains("textarea.paste"));
+    assert_eq!(km.chord("textarea.copy").as_deref(), Some("Ctrl+V"));
+
+    let mut copy = KeyMap::new();
+    copy.load(&km.to_config_string()).unwrap();
+    assert_eq!(copy.to_config_string(), km.to_config_string());
+}
+
+#[test]
+fn check_keymap_translate() {
+    let mut km = KeyMap::new();
+    let key = |text: &str| text.parse::<Key>().unwrap();
+    // default key map - keys are not changed
+    assert_eq!(km.translate("textarea", key("Ctrl+Left"), '\0'), (key("Ctrl+Left"), '\0'));
+    assert_eq!(km.translate("textarea", key("B"), 'b'), (key("B"), 'b'));
+
+    km.bind("textarea.word_left", "Alt+B").unwrap();
+    // the new key is translated into the default one
+    assert_eq!(km.translate("textarea", key("Alt+B"), '\0'), (key("Ctrl+Left"), '\0'));
+    // the old key no longer triggers the action
+    assert_eq!(km.translate("textarea", key("Ctrl+Left"), '\0'), (Key::None, '\0'));
+    // other scopes are not affected
+    assert_eq!(km.translate("textfield", key("Ctrl+Left"), '\0'), (key("Ctrl+Left"), '\0'));
+    assert_eq!(km.translate("textfield", key("Alt+B"), '\0'), (key("Alt+B"), '\0'));
+    // unbound keys are not changed
+    assert_eq!(km.translate("textarea", key("X"), 'x'), (key("X"), 'x'));
+
+    // swapped keys
+    km.load("textarea.copy = Ctrl+V\ntextarea.paste = Ctrl+C").unwrap();
+    assert_eq!(km.translate("textarea", key("Ctrl+V"), '\0'), (key("Ctrl+C"), '\0'));
+    assert_eq!(km.translate("textarea", key("Ctrl+C"), '\0'), (key("Ctrl+V"), '\0'));
+}
+
+#[test]
+fn check_keymap_chords() {
+    let mut km = KeyMap::new();
+    let key = |text: &str| text.parse::<Key>().unwrap();
+    // no chords - keys are processed normally
+    assert!(matches!(km.process_chord_key(key("Ctrl+K")), ChordStatus::None));
+
+    km.bind("listview.select_all", "Ctrl+K Ctrl+A").unwrap();
+    assert!(matches!(km.process_chord_key(key("Ctrl+A")), ChordStatus::None));
+    assert!(matches!(km.process_chord_key(key("Ctrl+K")), ChordStatus::Pending));
+    assert_eq!(km.pending_chord_text().as_deref(), Some("Ctrl+K ..."));
+    assert!(matches!(km.process_chord_key(key("Ctrl+A")), ChordStatus::Completed));
+    assert_eq!(km.pending_chord_text(), None);
+    assert_eq!(km.translate("listview", Key::None, '\0'), (key("Ctrl+A"), '\0'));
+    assert_eq!(km.translate("textarea", Key::None, '\0'), (Key::None, '\0'));
+    km.clear_completed_chord();
+    assert_eq!(km.translate("listview", Key::None, '\0'), (Key::None, '\0'));
+
+    // a key that does not continue the chord cancels it
+    assert!(matches!(km.process_chord_key(key("Ctrl+K")), ChordStatus::Pending));
+    assert!(matches!(km.process_chord_key(key("Ctrl+B")), ChordStatus::Cancelled));
+    assert_eq!(km.pending_chord_text(), None);
+    assert!(matches!(km.process_chord_key(key("Ctrl+B")), ChordStatus::None));
+}
@@ -15,6 +15,7 @@ pub use super::log;
 pub use super::system::*;
 pub use super::ui::accordion;
 pub use super::ui::accordion::events::AccordionEvents;
+pub use super::ui::buffercompareview;
 pub use super::ui::bufferview;
 pub use super::ui::bufferview::events::GenericBufferViewEvents;
 pub use super::ui::button;
@@ -60,6 +61,8 @@ pub use super::ui::menu::Menu;
 pub use super::ui::appbar;
 pub use super::ui::appbar::AppBar;
 pub use super::ui::appbar::events::AppBarEvents;
+pub use super::ui::toast;
+pub use super::ui::toast::events::ToastEvents;
 pub use super::ui::numericselector;
 pub use super::ui::numericselector::events::GenericNumericSelectorEvents;
 pub use super::ui::password;
@@ -47,6 +47,18 @@
 //! - Handle task lifecycle events
 //! - Manage task state and results
 //!
+//! ### Async Tasks
+//! A lightweight executor (integrated in the event loop) runs futures on the UI thread:
+//! - `App::spawn_local` polls a future in between the events of the event loop
+//! - `App::spawn_blocking` runs a closure on a separate thread and awaits its result
+//! - `JoinHandle` can be awaited to obtain the result of a task
+//!
+//! ### UI Proxy
+//! The `UiProxy` structure (obtained via `App::proxy`) can be used from any thread to:
+//! - Run closures on the UI thread
+//! - Send custom events to the event loop
+//! - Request a repaint of the screen
+//!
 //! ### Theme Management
 //! The theme system provides consistent styling across the application:
 //! - Predefined themes (Default, Dark Gray, Light)
@@ -54,6 +66,12 @@
 //! - Menu-specific theming
 //! - Color and style management
 //!
+//! ### Localization
+//! The `Locale` structure holds the texts of the built-in UI strings and the locale-specific formats:
+//! - Predefined locales (English, German, Romanian)
+//! - Message catalogs that can be loaded from a file (for built-in strings or application messages)
+//! - Decimal and thousands separators, date order and the first day of the week
+//!
 //! ## Error Handling
 //! The system module provides error handling through the `Error` type:
 //! - `InitializationFailure`: Failed to initialize system components
@@ -73,9 +91,11 @@ mod builder;
 mod clipboard;
 mod control_handle_manager;
 mod error;
+mod executor;
 #[cfg(feature = "EVENT_RECORDER")]
 mod event_recorder;
 mod handle;
+mod locale;
 mod menu_handle_manager;
 mod runtime_manager;
 mod runtime_manager_traits;
@@ -84,6 +104,7 @@ mod tests;
 mod theme;
 mod timer;
 mod tooltip;
+mod ui_proxy;
 mod system_event;
 
 pub(crate) use self::control_handle_manager::ControlHandleManager;
@@ -98,18 +119,25 @@ pub use self::theme::Themes;
 pub use self::app::App;
 pub use self::background_task::BackgroundTask;
 pub use self::background_task::BackgroundTaskConector;
+pub(crate) use self::background_task::panic_message;
 pub use self::builder::Builder;
 pub use self::clipboard::Clipboard;
 pub use self::error::Error;
 pub use self::error::ErrorKind;
+pub use self::executor::JoinHandle;
+pub use self::locale::DateOrder;
+pub use self::locale::Locale;
+pub use self::locale::Locales;
 pub(crate) use self::runtime_manager::RuntimeManager;
 pub(crate) use self::runtime_manager_traits::LayoutMethods;
 pub(crate) use self::runtime_manager_traits::PaintMethods;
 pub(crate) use self::runtime_manager_traits::TimerMethods;
 pub use self::timer::Timer;
+pub use self::ui_proxy::UiProxy;
 pub(crate) use self::tooltip::ToolTip;
 
 pub(crate) use self::system_event::KeyPressedEvent;
+pub(crate) use self::system_event::KeyReleasedEvent;
 pub(crate) use self::system_event::KeyModifierChangedEvent;
 pub(crate) use self::system_event::MouseButtonDownEvent;
 pub(crate) use self::system_event::MouseButtonUpEvent;
@@ -1,13 +1,18 @@
+use std::future::Future;
 use std::marker::PhantomData;
 use std::sync::Mutex;
 
 use super::Error;
 use super::ErrorKind;
 use super::Handle;
+use super::JoinHandle;
+use super::Locale;
 use super::RuntimeManager;
 use super::Theme;
 use super::ThemeMethods;
+use super::UiProxy;
 use crate::graphics::Size;
+use crate::input::KeyMap;
 use crate::backend::Type;
 use crate::ui::common::traits::*;
 
@@ -136,6 +141,116 @@ impl App {
         RuntimeManager::get().set_theme(theme);
     }
 
+    /// Sets the key map (the keys that trigger the built-in actions of the controls) for the current application.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut km = KeyMap::new();
+    /// km.bind("window.close", "Ctrl+W").unwrap();
+    /// App::set_key_map(km);
+    /// ```
+    pub fn set_key_map(key_map: KeyMap) {
+        if !App::is_created() {
+            panic!("App::set_key_map can only be called after the App has been created !");
+        }
+        RuntimeManager::get().set_key_map(key_map);
+    }
+
+    /// Sets the locale (the texts of the built-in UI strings and the way numbers and dates are formatted) for the current application.
+    /// The captions of the controls that were already created are not changed.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut locale = Locale::new(Locales::Romanian);
+    /// locale.load_from_file(std::path::Path::new("ro.catalog")).unwrap();
+    /// App::set_locale(locale);
+    /// ```
+    pub fn set_locale(locale: Locale) {
+        if !App::is_created() {
+            panic!("App::set_locale can only be called after the App has been created !");
+        }
+        RuntimeManager::get().set_locale(locale);
+    }
+
+    /// Returns the text of a message (a built-in one or one loaded by the application in the current locale).
+    /// If the message is not found, the id is returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut locale = Locale::new(Locales::German);
+    /// locale.set_text("app.save", "Speichern");
+    /// let app = App::new().locale(locale).build().unwrap();
+    /// assert_eq!(App::translate("app.save"), "Speichern");
+    /// ```
+    pub fn translate(id: &str) -> String {
+        Locale::current().text(id).to_string()
+    }
+
+    /// Runs a future on the UI thread (using the executor that is integrated in the AppCUI event loop) and returns
+    /// a [`JoinHandle`] that can be awaited to obtain the value returned by the future.
+    ///
+    /// The future is polled for the first time right after the current event is processed and, after that, every time
+    /// it is woken up. A future is never polled from within an event handler, so it can safely use the AppCUI API
+    /// (e.g. `Window::update_control(...)` or the async dialogs such as `dialogs::open_async(...)`).
+    /// The future does not have to be `Send`, but it should not block (use `App::spawn_blocking(...)` for blocking work).
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appcui::prelude::*;
+    ///
+    /// fn load(label: Handle<Label>) {
+    ///     App::spawn_local(async move {
+    ///         let text = App::spawn_blocking(|| std::fs::read_to_string("notes.txt").unwrap_or_default()).await;
+    ///         if dialogs::validate_async("Load", "Display the content of the file ?").await {
+    ///             Window::update_control(label, move |l| l.set_caption(&text));
+    ///         }
+    ///     });
+    /// }
+    /// ```
+    pub fn spawn_local<F>(future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + 'static,
+        F::Output: 'static,
+    {
+        if !App::is_created() {
+            panic!("App::spawn_local can only be called after the App has been created !");
+        }
+        RuntimeManager::get().get_executor().spawn(future)
+    }
+
+    /// Runs a closure on a separate thread and returns a [`JoinHandle`] that can be awaited (from a future started via
+    /// `App::spawn_local(...)`) to obtain the value returned by the closure. If the closure panics, the panic is
+    /// propagated to the future that awaits the handle.
+    ///
+    /// For single threaded backends (such as the one used for unit testing) the closure is executed right away on the UI thread.
+    pub fn spawn_blocking<F, R>(task: F) -> JoinHandle<R>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        if !App::is_created() {
+            panic!("App::spawn_blocking can only be called after the App has been created !");
+        }
+        let rm = RuntimeManager::get();
+        let single_threaded = rm.is_single_threaded();
+        rm.get_executor().spawn_blocking(task, single_threaded)
+    }
+
+    /// Returns an [`UiProxy`] object that can be cloned and moved to any thread in order to run closures on the UI thread,
+    /// send custom events or request a repaint of the screen.
+    pub fn proxy() -> UiProxy {
+        if !App::is_created() {
+            panic!("App::proxy can only be called after the App has been created !");
+        }
+        RuntimeManager::get().proxy()
+    }
+
     pub(crate) fn drop_app() {
         if APP_CREATED_MUTEX.is_poisoned() {
             APP_CREATED_MUTEX.clear_poison();
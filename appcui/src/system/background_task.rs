@@ -9,4 +9,5 @@ pub use self::background_task_conector::BackgroundTaskConector;
 use self::single_channel::SingleChannel;
 use self::status_update_request::StatusUpdateRequest;
 pub use self::background_task::BackgroundTask;
-pub(crate) use self::background_task_manager::BackgroundTaskManager;
\ No newline at end of file
+pub(crate) use self::background_task_manager::BackgroundTaskManager;
+pub(crate) use self::task::panic_message;
\ No newline at end of file
@@ -1,6 +1,6 @@
 use crate::{
     system::{Handle, RuntimeManager},
-    ui::Window,
+    ui::{common::traits::Control, Window},
 };
 
 use super::{
@@ -34,6 +34,38 @@ impl<T: Send, R: Send> BackgroundTask<T, R> {
     /// Starts a new background task. The task will run in a separate thread.
     /// The task will receive a conector that can be used to send and receive data from the main thread.
     pub fn run(task: fn(conector: &BackgroundTaskConector<T, R>), receiver: Handle<Window>) -> Handle<BackgroundTask<T, R>> {
+        Self::spawn(task, receiver)
+    }
+
+    /// Starts a new background task from a closure. The task will run in a separate thread and, unlike `run(...)`,
+    /// it can capture (move) any data it needs (paths, configuration objects, `Arc` references, ...).
+    ///
+    /// The `receiver` can be the handle of any control (a window, a custom control or the desktop) that
+    /// implements `BackgroundTaskEvents<T, R>`. The value returned by the closure is delivered with the `on_finish`
+    /// event and can be obtained (only once) via the `take_result()` method of the task. If the closure panics,
+    /// the `on_error` event is sent to the receiver (with the panic message) before `on_finish`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appcui::prelude::*;
+    ///
+    /// fn count_lines(path: String, receiver: Handle<Window>) -> Handle<BackgroundTask<u32, ()>> {
+    ///     BackgroundTask::<u32, ()>::spawn(
+    ///         move |conector| {
+    ///             let content = std::fs::read_to_string(&path).unwrap_or_default();
+    ///             conector.notify(content.len() as u32);
+    ///             content.lines().count()
+    ///         },
+    ///         receiver,
+    ///     )
+    /// }
+    /// ```
+    pub fn spawn<F, O, C>(task: F, receiver: Handle<C>) -> Handle<BackgroundTask<T, R>>
+    where
+        F: FnOnce(&BackgroundTaskConector<T, R>) -> O + Send + 'static,
+        O: Send + 'static,
+        C: Control,
+    {
         let btm = RuntimeManager::get().get_background_task_manager();
         let id = btm.add_task(InnerTask::<T, R>::new(receiver.cast()));
         if let Some(t) = btm.get_mut::<T, R>(id) {
@@ -45,6 +77,25 @@ impl<T: Send, R: Send> BackgroundTask<T, R> {
         }
     }
 
+    /// Returns the value returned by the closure of a task started via `spawn(...)`. The value is set before the
+    /// `on_finish` event is sent to the receiver (where it is usually taken) and it can only be taken once.
+    /// It returns None if the task is still running, if it panicked or if the value was already taken.
+    ///
+    /// # Panics
+    ///
+    /// If `O` is not the type returned by the closure of the task.
+    pub fn take_result<O: Send + 'static>(&self) -> Option<O> {
+        let btm = RuntimeManager::get().get_background_task_manager();
+        btm.get_mut::<T, R>(self.id as usize)?.take_result::<O>()
+    }
+
+    /// Returns the message of the panic that ended the background task or None if the task did not panic
+    /// (or it is still running).
+    pub fn error(&self) -> Option<String> {
+        let btm = RuntimeManager::get().get_background_task_manager();
+        btm.get::<T, R>(self.id as usize)?.error()
+    }
+
     /// Reads the data sent by the background task. If there is no data, it returns None.
     /// This method is not meant to be used directly (it will be used by the generated code).
     pub fn read(&self) -> Option<T> {
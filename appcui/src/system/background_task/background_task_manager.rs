@@ -54,6 +54,10 @@ impl BackgroundTaskManager {
         }
         self.tasks[index].as_ref().map(|interface| interface.receiver_control_handle())
     }
+    #[inline(always)]
+    pub(crate) fn has_panicked(&self, index: usize) -> bool {
+        self.tasks.get(index).and_then(|t| t.as_ref()).map(|t| t.has_panicked()).unwrap_or(false)
+    }
     pub(crate) fn remove_task(&mut self, handle: Handle<()>) {
         let index = handle.index();
         if index < self.tasks.len() {
@@ -2,6 +2,7 @@ use super::{BackgroundTask, BackgroundTaskConector, SingleChannel, StatusUpdateR
 use crate::system::{Handle, RuntimeManager};
 use std::{
     any::{Any, TypeId},
+    panic::{self, AssertUnwindSafe},
     sync::{Arc, Condvar, Mutex},
 };
 
@@ -17,6 +18,7 @@ pub(crate) trait Task {
     fn as_any_mut(&mut self) -> &mut dyn Any;
     fn validate(&self, t: TypeId, r: TypeId) -> bool;
     fn receiver_control_handle(&self) -> Handle<()>;
+    fn has_panicked(&self) -> bool;
 }
 
 pub(crate) struct InnerTask<T: Send, R: Send> {
@@ -24,6 +26,11 @@ pub(crate) struct InnerTask<T: Send, R: Send> {
     pub(crate) main_to_task: SingleChannel<R>,
     pub(crate) task_to_main: SingleChannel<T>,
     state: Arc<(Mutex<StatusUpdateRequest>, Condvar)>,
+    // the message of the panic that ended the task
+    panic_message: Arc<Mutex<Option<String>>>,
+    // the value returned by the task closure (and the name of its type)
+    result: Arc<Mutex<Option<Box<dyn Any + Send>>>>,
+    result_type: &'static str,
 }
 
 impl<T: Send + 'static, R: Send + 'static> InnerTask<T, R> {
@@ -33,10 +40,17 @@ impl<T: Send + 'static, R: Send + 'static> InnerTask<T, R> {
             main_to_task: SingleChannel::new(),
             task_to_main: SingleChannel::new(),
             state: Arc::new((Mutex::new(StatusUpdateRequest::None), Condvar::new())),
+            panic_message: Arc::new(Mutex::new(None)),
+            result: Arc::new(Mutex::new(None)),
+            result_type: std::any::type_name::<()>(),
         }
     }
 
-    pub(super) fn run(&mut self, task: fn(conector: &BackgroundTaskConector<T, R>), handle: Handle<BackgroundTask<T, R>>) {
+    pub(super) fn run<F, O>(&mut self, task: F, handle: Handle<BackgroundTask<T, R>>)
+    where
+        F: FnOnce(&BackgroundTaskConector<T, R>) -> O + Send + 'static,
+        O: Send + 'static,
+    {
         let conector = BackgroundTaskConector::new(
             handle,
             RuntimeManager::get().get_system_event_sender(),
@@ -44,26 +58,45 @@ impl<T: Send + 'static, R: Send + 'static> InnerTask<T, R> {
             self.main_to_task.take_ownership_for_receiver().unwrap(),
             self.state.clone(),
         );
+        let message = self.panic_message.clone();
+        let output = self.result.clone();
+        self.result_type = std::any::type_name::<O>();
+        let body = move || {
+            conector.notify_start();
+            match panic::catch_unwind(AssertUnwindSafe(|| task(&conector))) {
+                Ok(value) => *output.lock().unwrap() = Some(Box::new(value)),
+                Err(payload) => *message.lock().unwrap() = Some(panic_message(payload)),
+            }
+            conector.notify_end();
+        };
 
         #[cfg(not(target_arch = "wasm32"))]
         {
-            thread::spawn(move || {
-                conector.notify_start();
-                task(&conector);
-                conector.notify_end();
-            });
+            thread::spawn(body);
         }
 
         #[cfg(target_arch = "wasm32")]
         {
-            rayon::spawn(move || {
-                conector.notify_start();
-                task(&conector);
-                conector.notify_end();
-            });
+            rayon::spawn(body);
         }
     }
 
+    pub(super) fn take_result<O: 'static>(&mut self) -> Option<O> {
+        let value = self.result.lock().unwrap().take()?;
+        match value.downcast::<O>() {
+            Ok(value) => Some(*value),
+            Err(_) => panic!(
+                "the closure of the background task returns a value of type `{}` (not `{}`)",
+                self.result_type,
+                std::any::type_name::<O>()
+            ),
+        }
+    }
+
+    pub(super) fn error(&self) -> Option<String> {
+        self.panic_message.lock().unwrap().clone()
+    }
+
     pub(super) fn pause(&mut self) {
         let (lock, cvar) = &*self.state;
         let mut status = lock.lock().unwrap();
@@ -113,4 +146,17 @@ impl<T: Send + 'static, R: Send + 'static> Task for InnerTask<T, R> {
     fn receiver_control_handle(&self) -> Handle<()> {
         self.control
     }
+    fn has_panicked(&self) -> bool {
+        self.panic_message.lock().unwrap().is_some()
+    }
+}
+
+pub(crate) fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("background task panicked")
+    }
 }
@@ -1,5 +1,6 @@
 use crate::backend;
 use crate::graphics::*;
+use crate::input::KeyMap;
 use crate::system::*;
 use crate::ui::common::traits::*;
 use crate::ui::common::*;
@@ -19,6 +20,10 @@ pub struct Builder {
     pub(crate) log_append: bool,
     pub(crate) use_color_schema: bool,
     pub(crate) restore_screen: bool,
+    pub(crate) enhanced_keyboard: bool,
+    pub(crate) modify_other_keys: bool,
+    pub(crate) key_map: KeyMap,
+    pub(crate) locale: Locale,
 }
 impl Builder {
     pub(crate) fn new() -> Self {
@@ -37,6 +42,10 @@ impl Builder {
             log_append: false,
             use_color_schema: true,
             restore_screen: true,
+            enhanced_keyboard: false,
+            modify_other_keys: false,
+            key_map: KeyMap::new(),
+            locale: Locale::new(Locales::English),
         }
     }
     /// Builds the application using the current settings.
@@ -89,6 +98,18 @@ impl Builder {
         self.theme = theme;
         self
     }
+    /// Sets the key map (the keys that trigger the built-in actions of the controls). If not specified, the default keys will be used.
+    #[inline(always)]
+    pub fn key_map(mut self, key_map: KeyMap) -> Self {
+        self.key_map = key_map;
+        self
+    }
+    /// Sets the locale (the texts of the built-in UI strings and the way numbers and dates are formatted). If not specified, the English locale will be used.
+    #[inline(always)]
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
     /// Sets the number of timers that can be used in the application.
     #[inline(always)]
     pub fn timers_count(mut self, count: u8) -> Self {
@@ -119,4 +140,30 @@ impl Builder {
         self.restore_screen = enable;
         self
     }
+
+    /// If enabled the backend will ask the terminal to report keys unambiguously (using the kitty keyboard protocol or,
+    /// if not available, xterm's `modifyOtherKeys` mode if the terminal reports that it supports it). This makes combinations such as `Ctrl+Shift+letter`, `Ctrl+Enter`,
+    /// `Ctrl+Tab` or `Shift+Space` available, adds the `Super` and `Meta` modifiers and (for the kitty protocol) reports key repeats and
+    /// key releases (received via the `on_key_released` method of the `OnKeyPressed` trait).
+    /// By default this option is disabled.
+    ///
+    /// **Remarks:** This option is only used by the Termios backend. The support is detected when the application starts
+    /// and the terminal is restored to its original keyboard mode when the application ends.
+    #[inline(always)]
+    pub fn enhanced_keyboard(mut self, enable: bool) -> Self {
+        self.enhanced_keyboard = enable;
+        self
+    }
+
+    /// Same as `enhanced_keyboard(true)`, but if the terminal does not support the kitty keyboard protocol, xterm's `modifyOtherKeys`
+    /// mode is enabled even if the terminal does not reply to the `XTQMODKEYS` query (some terminals support the mode without
+    /// supporting the query). By default this option is disabled.
+    ///
+    /// **Remarks:** This option is only used by the Termios backend. Only enable it for terminals that are known to support
+    /// `modifyOtherKeys`, otherwise some keys might not be recognized.
+    #[inline(always)]
+    pub fn modify_other_keys(mut self, enable: bool) -> Self {
+        self.modify_other_keys = enable;
+        self
+    }
 }
@@ -128,6 +128,7 @@ impl EventRecorder {
                     RuntimeManager::get().request_update();
                 }
             }
+            SystemEvent::KeyReleased(_) => {}
             SystemEvent::KeyModifierChanged(_) => {}
             SystemEvent::Resize(new_size) => self.add_resize(*new_size),
             SystemEvent::MouseButtonDown(evnt) => self.add_mouse_button_down(evnt),
@@ -157,15 +158,26 @@ impl EventRecorder {
         // use FNV algorithm ==> https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
         let mut hash = 0xcbf29ce484222325u64;
         let mut buf = [0u8; 8];
-        for ch in &surface.chars {
-            buf[0] = ((ch.code as u32) & 0xFF) as u8;
-            buf[1] = (((ch.code as u32) >> 8) & 0xFF) as u8;
-            buf[2] = (((ch.code as u32) >> 16) & 0xFF) as u8;
-            buf[3] = (((ch.code as u32) >> 24) & 0xFF) as u8;
+        for (index, ch) in surface.chars.iter().enumerate() {
+            let mut code = ch.code as u32;
+            let mut flags = ch.flags;
+            if let Some(text) = surface.cluster_text(index) {
+                // hash all the characters of a grapheme cluster (the same way the debug backend does)
+                for c in text.chars() {
+                    hash ^= c as u64;
+                    hash = hash.wrapping_mul(0x00000100000001B3u64);
+                }
+                code = 0;
+                flags.remove(CharFlags::Cluster);
+            }
+            buf[0] = (code & 0xFF) as u8;
+            buf[1] = ((code >> 8) & 0xFF) as u8;
+            buf[2] = ((code >> 16) & 0xFF) as u8;
+            buf[3] = ((code >> 24) & 0xFF) as u8;
             buf[4] = ch.foreground.as_color_index();
             buf[5] = ch.background.as_color_index();
-            buf[6] = ((ch.flags.get_value() >> 8) & 0xFF) as u8;
-            buf[7] = (ch.flags.get_value() & 0xFF) as u8;
+            buf[6] = ((flags.get_value() >> 8) & 0xFF) as u8;
+            buf[7] = (flags.get_value() & 0xFF) as u8;
             for b in buf {
                 hash = hash ^ (b as u64);
                 hash = hash.wrapping_mul(0x00000100000001B3u64);
@@ -0,0 +1,6 @@
+mod executor;
+mod join_handle;
+
+pub(crate) use self::executor::Executor;
+pub use self::join_handle::JoinHandle;
+use self::join_handle::JoinState;
@@ -0,0 +1,170 @@
+use super::{JoinHandle, JoinState};
+use crate::system::SystemEvent;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Wake, Waker};
+
+type LocalFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+enum Slot {
+    Free,
+    Pending(LocalFuture),
+    // the future was taken out of the slot while it is polled (a future can start a modal loop that polls other tasks)
+    // `woken` records a wake that arrived while the task was running (it has to be polled again)
+    Running { woken: bool },
+}
+
+// the indexes of the tasks that were woken up (and need to be polled)
+struct ReadyQueue {
+    ids: Mutex<VecDeque<usize>>,
+    // None for single threaded backends (they don't read the system event channel)
+    sender: Option<Sender<SystemEvent>>,
+}
+
+impl ReadyQueue {
+    fn push(&self, id: usize) {
+        let mut ids = self.ids.lock().unwrap();
+        if ids.contains(&id) {
+            return;
+        }
+        ids.push_back(id);
+        drop(ids);
+        // wake up the event loop (if it waits for a system event)
+        if let Some(sender) = &self.sender {
+            let _ = sender.send(SystemEvent::FutureWake);
+        }
+    }
+}
+
+struct TaskWaker {
+    id: usize,
+    queue: Arc<ReadyQueue>,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.queue.push(self.id);
+    }
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.queue.push(self.id);
+    }
+}
+
+// a single threaded executor that polls futures on the UI thread (in between the events of the event loop)
+pub(crate) struct Executor {
+    slots: Vec<Slot>,
+    queue: Arc<ReadyQueue>,
+}
+
+impl Executor {
+    pub(crate) fn new(sender: Option<Sender<SystemEvent>>) -> Self {
+        Self {
+            slots: Vec::new(),
+            queue: Arc::new(ReadyQueue {
+                ids: Mutex::new(VecDeque::new()),
+                sender,
+            }),
+        }
+    }
+
+    pub(crate) fn spawn<F>(&mut self, future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + 'static,
+        F::Output: 'static,
+    {
+        let state = JoinState::new();
+        let result = state.clone();
+        let task: LocalFuture = Box::pin(async move {
+            let value = future.await;
+            JoinState::complete(&result, Ok(value));
+        });
+        let id = match self.slots.iter().position(|s| matches!(s, Slot::Free)) {
+            Some(index) => {
+                self.slots[index] = Slot::Pending(task);
+                index
+            }
+            None => {
+                self.slots.push(Slot::Pending(task));
+                self.slots.len() - 1
+            }
+        };
+        // every new task is polled at least once
+        self.queue.push(id);
+        JoinHandle::new(state)
+    }
+
+    pub(crate) fn spawn_blocking<F, R>(&mut self, task: F, single_threaded: bool) -> JoinHandle<R>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let state = JoinState::new();
+        if single_threaded {
+            // single threaded backends (e.g. the debug backend) run the task right away (on the UI thread)
+            JoinState::complete(&state, std::panic::catch_unwind(std::panic::AssertUnwindSafe(task)));
+        } else {
+            let result = state.clone();
+            let body = move || {
+                JoinState::complete(&result, std::panic::catch_unwind(std::panic::AssertUnwindSafe(task)));
+            };
+            #[cfg(not(target_arch = "wasm32"))]
+            std::thread::spawn(body);
+            #[cfg(target_arch = "wasm32")]
+            rayon::spawn(body);
+        }
+        JoinHandle::new(state)
+    }
+
+    pub(crate) fn ready_count(&self) -> usize {
+        self.queue.ids.lock().unwrap().len()
+    }
+
+    // takes the next task that needs to be polled (the slot is marked as running)
+    pub(crate) fn next_ready(&mut self) -> Option<(usize, LocalFuture, Waker)> {
+        loop {
+            let id = self.queue.ids.lock().unwrap().pop_front()?;
+            if id >= self.slots.len() {
+                continue;
+            }
+            match &mut self.slots[id] {
+                Slot::Pending(_) => {
+                    let Slot::Pending(future) = std::mem::replace(&mut self.slots[id], Slot::Running { woken: false }) else {
+                        unreachable!()
+                    };
+                    let waker = Waker::from(Arc::new(TaskWaker {
+                        id,
+                        queue: self.queue.clone(),
+                    }));
+                    return Some((id, future, waker));
+                }
+                // the task is being polled (from an outer loop) - it will be queued again once that poll ends
+                Slot::Running { woken } => *woken = true,
+                // a task that already finished can still be woken up
+                Slot::Free => {}
+            }
+        }
+    }
+
+    // puts back a task that was polled (if it is still pending) or releases its slot
+    pub(crate) fn complete_poll(&mut self, id: usize, future: Option<LocalFuture>) {
+        if id < self.slots.len() {
+            let woken = matches!(self.slots[id], Slot::Running { woken: true });
+            self.slots[id] = match future {
+                Some(future) => {
+                    if woken {
+                        self.queue.push(id);
+                    }
+                    Slot::Pending(future)
+                }
+                None => Slot::Free,
+            };
+        }
+    }
+
+    pub(crate) fn poll(future: &mut LocalFuture, waker: &Waker) -> bool {
+        future.as_mut().poll(&mut Context::from_waker(waker)).is_ready()
+    }
+}
@@ -0,0 +1,101 @@
+use std::any::Any;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+pub(super) struct JoinState<T> {
+    value: Option<T>,
+    panic: Option<Box<dyn Any + Send>>,
+    finished: bool,
+    waker: Option<Waker>,
+}
+
+impl<T> JoinState<T> {
+    pub(super) fn new() -> Arc<Mutex<JoinState<T>>> {
+        Arc::new(Mutex::new(JoinState {
+            value: None,
+            panic: None,
+            finished: false,
+            waker: None,
+        }))
+    }
+    pub(super) fn complete(state: &Mutex<JoinState<T>>, result: Result<T, Box<dyn Any + Send>>) {
+        let waker = {
+            let mut s = state.lock().unwrap();
+            match result {
+                Ok(value) => s.value = Some(value),
+                Err(payload) => s.panic = Some(payload),
+            }
+            s.finished = true;
+            s.waker.take()
+        };
+        // the lock is released before waking up the task that waits for the result
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+// completes a JoinHandle with a value produced outside of a task (e.g. the result of a modal window)
+pub(crate) struct Completion<T> {
+    state: Arc<Mutex<JoinState<T>>>,
+}
+
+impl<T> Completion<T> {
+    pub(crate) fn complete(self, value: T) {
+        JoinState::complete(&self.state, Ok(value));
+    }
+}
+
+/// A handle to a task started via `App::spawn_local(...)` or `App::spawn_blocking(...)`.
+///
+/// A `JoinHandle` is a future that resolves to the value returned by the task, so it can be awaited
+/// from another task that runs on the AppCUI executor. If the task panicked (for tasks started
+/// with `App::spawn_blocking(...)`), the panic is propagated to the task that awaits the handle.
+/// Dropping a `JoinHandle` does not cancel the task.
+pub struct JoinHandle<T> {
+    state: Arc<Mutex<JoinState<T>>>,
+}
+
+impl<T> JoinHandle<T> {
+    pub(super) fn new(state: Arc<Mutex<JoinState<T>>>) -> Self {
+        Self { state }
+    }
+
+    // a handle that resolves once the returned completion is completed
+    pub(crate) fn pending() -> (Self, Completion<T>) {
+        let state = JoinState::new();
+        (Self { state: state.clone() }, Completion { state })
+    }
+
+    /// Returns `true` if the task has finished (its result can be obtained without waiting)
+    pub fn is_finished(&self) -> bool {
+        self.state.lock().unwrap().finished
+    }
+
+    /// Returns the value returned by the task if the task has finished or None otherwise.
+    /// The value can only be taken once (after it was taken, awaiting the handle will never complete).
+    pub fn try_take(&mut self) -> Option<T> {
+        self.state.lock().unwrap().value.take()
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut s = self.state.lock().unwrap();
+        if let Some(payload) = s.panic.take() {
+            drop(s);
+            std::panic::resume_unwind(payload);
+        }
+        match s.value.take() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                s.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
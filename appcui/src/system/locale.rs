@@ -0,0 +1,638 @@
+use super::{Error, ErrorKind, RuntimeManager};
+use crate::utils::FormatDate;
+use chrono::{Datelike, NaiveDate, Weekday};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+// the built-in messages (`component.message`) and their default (English) text
+static MESSAGES: &[(&str, &str)] = &[
+    // Dialogs
+    ("dialog.ok", "&Ok"),
+    ("dialog.yes", "&Yes"),
+    ("dialog.no", "&No"),
+    ("dialog.cancel", "&Cancel"),
+    ("dialog.retry", "&Retry"),
+    ("dialog.background", "&Background"),
+    ("dialog.canceling", "Canceling ..."),
+    ("dialog.error", "Error"),
+    ("dialog.invalid_value", "Invalid value: '{value}'"),
+    // Forms
+    ("form.required", "This field is required"),
+    ("form.must_be_checked", "This option must be checked"),
+    ("form.must_be_selected", "A value must be selected"),
+    ("form.range", "Must be between {min} and {max}"),
+    ("form.length", "Must have {min} to {max} characters"),
+    // File and folder dialogs
+    ("filedialog.ok", "&OK"),
+    ("filedialog.drive", "&Drive"),
+    ("filedialog.name", "&Name"),
+    ("filedialog.type", "&Type"),
+    ("filedialog.path", "&Path"),
+    ("filedialog.all_files", "All files"),
+    ("filedialog.updir", "UpDir"),
+    ("filedialog.folders", "Folders"),
+    ("filedialog.files", "Files"),
+    ("filedialog.preview", "Preview"),
+    ("filedialog.devices", "Devices"),
+    ("filedialog.folder", "Folder"),
+    ("filedialog.size", "Size: {size} bytes"),
+    ("filedialog.created", "Created: {date}"),
+    ("filedialog.overwrite_title", "Overwrite"),
+    ("filedialog.overwrite", "Do you want to overwrite the file: '{file}'"),
+    ("filedialog.not_found", "File '{file}' does not exists !"),
+    ("filedialog.check_failed", "Fail to check if file exists: '{file}'"),
+    ("filedialog.join_failed", "Fail to join current path: '{path}' with file name: '{file}'"),
+    ("filedialog.select_extension", "Select Extension"),
+    ("filedialog.select_extension_text", "Please select an extension to add to the file:"),
+    ("filedialog.keep_extension", "Do Nothing (keep the file as it is)"),
+    // DatePicker
+    ("datepicker.long_date", "{year}, {month}, {day}"),
+    ("datepicker.jan", "Jan"),
+    ("datepicker.feb", "Feb"),
+    ("datepicker.mar", "Mar"),
+    ("datepicker.apr", "Apr"),
+    ("datepicker.may", "May"),
+    ("datepicker.jun", "Jun"),
+    ("datepicker.jul", "Jul"),
+    ("datepicker.aug", "Aug"),
+    ("datepicker.sep", "Sep"),
+    ("datepicker.oct", "Oct"),
+    ("datepicker.nov", "Nov"),
+    ("datepicker.dec", "Dec"),
+    ("datepicker.mon", "Mo"),
+    ("datepicker.tue", "Tu"),
+    ("datepicker.wed", "We"),
+    ("datepicker.thu", "Th"),
+    ("datepicker.fri", "Fr"),
+    ("datepicker.sat", "Sa"),
+    ("datepicker.sun", "Su"),
+    // TimePicker
+    ("timepicker.am", "AM"),
+    ("timepicker.pm", "PM"),
+    // ListView / TreeView items
+    ("listitem.true", "True"),
+    ("listitem.false", "False"),
+    ("listitem.yes", "Yes"),
+    ("listitem.no", "No"),
+    ("listitem.seconds", "sec"),
+    ("listitem.paused", "Paused"),
+    ("listitem.queued", "Queued"),
+    ("listitem.stopped", "Stopped"),
+    ("listitem.error", "Error"),
+    ("listitem.completed", "Completed"),
+    // Units (numbers and durations displayed by the ListView / TreeView items)
+    ("unit.bytes", "B"),
+    ("unit.kilobytes", "KB"),
+    ("unit.megabytes", "MB"),
+    ("unit.gigabytes", "GB"),
+    ("unit.terabytes", "TB"),
+    ("unit.kilometers", "km"),
+    ("unit.meters", "m"),
+    ("unit.centimeters", "cm"),
+    ("unit.millimeters", "mm"),
+    ("unit.inches", "in"),
+    ("unit.feet", "ft"),
+    ("unit.yards", "yd"),
+    ("unit.miles", "mi"),
+    ("unit.cubic_millimeters", "mm³"),
+    ("unit.cubic_centimeters", "cm³"),
+    ("unit.cubic_meters", "m³"),
+    ("unit.cubic_kilometers", "km³"),
+    ("unit.liters", "l"),
+    ("unit.milliliters", "ml"),
+    ("unit.gallons", "gal"),
+    ("unit.cubic_feet", "ft³"),
+    ("unit.cubic_inches", "in³"),
+    ("unit.cubic_yards", "yd³"),
+    ("unit.cubic_miles", "mi³"),
+    ("unit.kilograms", "kg"),
+    ("unit.grams", "gr"),
+    ("unit.milligrams", "mg"),
+    ("unit.pounds", "lb"),
+    ("unit.tons", "t"),
+    ("unit.kilometers_per_hour", "km/h"),
+    ("unit.meters_per_hour", "m/h"),
+    ("unit.kilometers_per_second", "km/s"),
+    ("unit.meters_per_second", "m/s"),
+    ("unit.miles_per_hour", "mph"),
+    ("unit.miles_per_second", "mps"),
+    ("unit.knots", "knots"),
+    ("unit.feet_per_second", "ft/s"),
+    ("unit.mach", "mach"),
+    ("unit.square_millimeters", "mm²"),
+    ("unit.square_centimeters", "cm²"),
+    ("unit.square_meters", "m²"),
+    ("unit.square_kilometers", "km²"),
+    ("unit.hectares", "ha"),
+    ("unit.ares", "a"),
+    ("unit.square_feet", "ft²"),
+    ("unit.square_inches", "in²"),
+    ("unit.square_yards", "yd²"),
+    ("unit.square_miles", "mi²"),
+    ("unit.celsius", "°C"),
+    ("unit.fahrenheit", "°F"),
+    ("unit.kelvin", "°K"),
+    ("unit.days", "d"),
+    ("unit.hours", "h"),
+    ("unit.minutes", "m"),
+    ("unit.seconds", "s"),
+    // Window switcher
+    ("switcher.title", "Windows"),
+    ("switcher.filter", "Filter:"),
+    ("switcher.empty", "No windows"),
+    // AppBar, toast notifications and progress indicators
+    ("appbar.notifications", "Notifications"),
+    ("appbar.unread_notifications", "Notifications ({unread})"),
+    ("toast.notifications", "Notifications"),
+    ("toast.empty", "There are no notifications"),
+    ("toast.clear", "C&lear"),
+    ("toast.close", "&Close"),
+    ("toast.info", "Info"),
+    ("toast.warning", "Warning"),
+    ("toast.error", "Error"),
+    ("progress.failed", "{title}: failed"),
+    ("progress.done", "{title}: done"),
+];
+
+static GERMAN: &[(&str, &str)] = &[
+    ("dialog.ok", "&Ok"),
+    ("dialog.yes", "&Ja"),
+    ("dialog.no", "&Nein"),
+    ("dialog.cancel", "&Abbrechen"),
+    ("dialog.retry", "&Wiederholen"),
+    ("dialog.background", "&Hintergrund"),
+    ("dialog.canceling", "Wird abgebrochen ..."),
+    ("dialog.error", "Fehler"),
+    ("dialog.invalid_value", "Ungültiger Wert: '{value}'"),
+    ("form.required", "Dieses Feld ist erforderlich"),
+    ("form.must_be_checked", "Diese Option muss aktiviert sein"),
+    ("form.must_be_selected", "Es muss ein Wert ausgewählt werden"),
+    ("form.range", "Muss zwischen {min} und {max} liegen"),
+    ("form.length", "Muss {min} bis {max} Zeichen haben"),
+    ("filedialog.ok", "&OK"),
+    ("filedialog.drive", "&Gerät"),
+    ("filedialog.name", "&Name"),
+    ("filedialog.type", "&Typ"),
+    ("filedialog.path", "&Pfad"),
+    ("filedialog.all_files", "Alle Dateien"),
+    ("filedialog.updir", "Nach oben"),
+    ("filedialog.folders", "Ordner"),
+    ("filedialog.files", "Dateien"),
+    ("filedialog.preview", "Vorschau"),
+    ("filedialog.devices", "Geräte"),
+    ("filedialog.folder", "Ordner"),
+    ("filedialog.size", "Größe: {size} Bytes"),
+    ("filedialog.created", "Erstellt: {date}"),
+    ("filedialog.overwrite_title", "Überschreiben"),
+    ("filedialog.overwrite", "Möchten Sie die Datei überschreiben: '{file}'"),
+    ("filedialog.not_found", "Die Datei '{file}' existiert nicht !"),
+    (
+        "filedialog.check_failed",
+        "Es konnte nicht geprüft werden, ob die Datei existiert: '{file}'",
+    ),
+    (
+        "filedialog.join_failed",
+        "Der Pfad '{path}' kann nicht mit dem Dateinamen '{file}' verbunden werden",
+    ),
+    ("filedialog.select_extension", "Erweiterung auswählen"),
+    ("filedialog.select_extension_text", "Bitte wählen Sie eine Erweiterung für die Datei aus:"),
+    ("filedialog.keep_extension", "Nichts tun (Datei unverändert lassen)"),
+    ("datepicker.long_date", "{day}. {month} {year}"),
+    ("datepicker.jan", "Jan"),
+    ("datepicker.feb", "Feb"),
+    ("datepicker.mar", "Mär"),
+    ("datepicker.apr", "Apr"),
+    ("datepicker.may", "Mai"),
+    ("datepicker.jun", "Jun"),
+    ("datepicker.jul", "Jul"),
+    ("datepicker.aug", "Aug"),
+    ("datepicker.sep", "Sep"),
+    ("datepicker.oct", "Okt"),
+    ("datepicker.nov", "Nov"),
+    ("datepicker.dec", "Dez"),
+    ("datepicker.mon", "Mo"),
+    ("datepicker.tue", "Di"),
+    ("datepicker.wed", "Mi"),
+    ("datepicker.thu", "Do"),
+    ("datepicker.fri", "Fr"),
+    ("datepicker.sat", "Sa"),
+    ("datepicker.sun", "So"),
+    ("listitem.true", "Wahr"),
+    ("listitem.false", "Falsch"),
+    ("listitem.yes", "Ja"),
+    ("listitem.no", "Nein"),
+    ("listitem.seconds", "Sek"),
+    ("listitem.paused", "Pausiert"),
+    ("listitem.queued", "Wartend"),
+    ("listitem.stopped", "Gestoppt"),
+    ("listitem.error", "Fehler"),
+    ("listitem.completed", "Fertig"),
+    ("unit.grams", "g"),
+    ("unit.knots", "kn"),
+    ("unit.mach", "Mach"),
+    ("unit.days", "T"),
+    ("switcher.title", "Fenster"),
+    ("switcher.filter", "Filter:"),
+    ("switcher.empty", "Keine Fenster"),
+    ("appbar.notifications", "Benachrichtigungen"),
+    ("appbar.unread_notifications", "Benachrichtigungen ({unread})"),
+    ("toast.notifications", "Benachrichtigungen"),
+    ("toast.empty", "Es gibt keine Benachrichtigungen"),
+    ("toast.clear", "&Leeren"),
+    ("toast.close", "&Schließen"),
+    ("toast.info", "Info"),
+    ("toast.warning", "Warnung"),
+    ("toast.error", "Fehler"),
+    ("progress.failed", "{title}: fehlgeschlagen"),
+    ("progress.done", "{title}: fertig"),
+];
+
+static ROMANIAN: &[(&str, &str)] = &[
+    ("dialog.ok", "&Ok"),
+    ("dialog.yes", "&Da"),
+    ("dialog.no", "&Nu"),
+    ("dialog.cancel", "&Anulează"),
+    ("dialog.retry", "&Reîncearcă"),
+    ("dialog.background", "În &fundal"),
+    ("dialog.canceling", "Se anulează ..."),
+    ("dialog.error", "Eroare"),
+    ("dialog.invalid_value", "Valoare invalidă: '{value}'"),
+    ("form.required", "Acest câmp este obligatoriu"),
+    ("form.must_be_checked", "Această opțiune trebuie bifată"),
+    ("form.must_be_selected", "Trebuie selectată o valoare"),
+    ("form.range", "Trebuie să fie între {min} și {max}"),
+    ("form.length", "Trebuie să aibă între {min} și {max} caractere"),
+    ("filedialog.ok", "&OK"),
+    ("filedialog.drive", "&Disc"),
+    ("filedialog.name", "&Nume"),
+    ("filedialog.type", "&Tip"),
+    ("filedialog.path", "&Cale"),
+    ("filedialog.all_files", "Toate fișierele"),
+    ("filedialog.updir", "Sus"),
+    ("filedialog.folders", "Directoare"),
+    ("filedialog.files", "Fișiere"),
+    ("filedialog.preview", "Previzualizare"),
+    ("filedialog.devices", "Dispozitive"),
+    ("filedialog.folder", "Director"),
+    ("filedialog.size", "Dimensiune: {size} octeți"),
+    ("filedialog.created", "Creat: {date}"),
+    ("filedialog.overwrite_title", "Suprascriere"),
+    ("filedialog.overwrite", "Doriți să suprascrieți fișierul: '{file}'"),
+    ("filedialog.not_found", "Fișierul '{file}' nu există !"),
+    ("filedialog.check_failed", "Nu se poate verifica dacă fișierul există: '{file}'"),
+    ("filedialog.join_failed", "Calea '{path}' nu poate fi unită cu numele de fișier '{file}'"),
+    ("filedialog.select_extension", "Selectați extensia"),
+    ("filedialog.select_extension_text", "Selectați extensia care va fi adăugată fișierului:"),
+    ("filedialog.keep_extension", "Nicio acțiune (fișierul rămâne neschimbat)"),
+    ("datepicker.long_date", "{day} {month} {year}"),
+    ("datepicker.jan", "Ian"),
+    ("datepicker.feb", "Feb"),
+    ("datepicker.mar", "Mar"),
+    ("datepicker.apr", "Apr"),
+    ("datepicker.may", "Mai"),
+    ("datepicker.jun", "Iun"),
+    ("datepicker.jul", "Iul"),
+    ("datepicker.aug", "Aug"),
+    ("datepicker.sep", "Sep"),
+    ("datepicker.oct", "Oct"),
+    ("datepicker.nov", "Noi"),
+    ("datepicker.dec", "Dec"),
+    ("datepicker.mon", "Lu"),
+    ("datepicker.tue", "Ma"),
+    ("datepicker.wed", "Mi"),
+    ("datepicker.thu", "Jo"),
+    ("datepicker.fri", "Vi"),
+    ("datepicker.sat", "Sâ"),
+    ("datepicker.sun", "Du"),
+    ("listitem.true", "Adevărat"),
+    ("listitem.false", "Fals"),
+    ("listitem.yes", "Da"),
+    ("listitem.no", "Nu"),
+    ("listitem.seconds", "sec"),
+    ("listitem.paused", "Pauză"),
+    ("listitem.queued", "În așteptare"),
+    ("listitem.stopped", "Oprit"),
+    ("listitem.error", "Eroare"),
+    ("listitem.completed", "Finalizat"),
+    ("unit.grams", "g"),
+    ("unit.knots", "noduri"),
+    ("unit.days", "z"),
+    ("switcher.title", "Ferestre"),
+    ("switcher.filter", "Filtru:"),
+    ("switcher.empty", "Nicio fereastră"),
+    ("appbar.notifications", "Notificări"),
+    ("appbar.unread_notifications", "Notificări ({unread})"),
+    ("toast.notifications", "Notificări"),
+    ("toast.empty", "Nu există notificări"),
+    ("toast.clear", "&Golește"),
+    ("toast.close", "&Închide"),
+    ("toast.info", "Info"),
+    ("toast.warning", "Avertisment"),
+    ("toast.error", "Eroare"),
+    ("progress.failed", "{title}: eșuat"),
+    ("progress.done", "{title}: terminat"),
+];
+
+const MONTHS: [&str; 12] = [
+    "datepicker.jan",
+    "datepicker.feb",
+    "datepicker.mar",
+    "datepicker.apr",
+    "datepicker.may",
+    "datepicker.jun",
+    "datepicker.jul",
+    "datepicker.aug",
+    "datepicker.sep",
+    "datepicker.oct",
+    "datepicker.nov",
+    "datepicker.dec",
+];
+const WEEKDAYS: [&str; 7] = [
+    "datepicker.mon",
+    "datepicker.tue",
+    "datepicker.wed",
+    "datepicker.thu",
+    "datepicker.fri",
+    "datepicker.sat",
+    "datepicker.sun",
+];
+
+/// The predefined locales (message catalogs and number / date formats) that come with AppCUI.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Locales {
+    /// English (the default locale)
+    English,
+    /// German
+    German,
+    /// Romanian
+    Romanian,
+}
+
+/// The order in which the day, the month and the year are displayed in a numeric date.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DateOrder {
+    /// `dd.mm.yyyy`
+    DayMonthYear,
+    /// `mm.dd.yyyy`
+    MonthDayYear,
+    /// `yyyy.mm.dd`
+    YearMonthDay,
+}
+
+/// A `Locale` holds the texts of the built-in UI strings (such as the captions of the buttons from the dialogs, the names
+/// of the months from a `DatePicker` or the texts displayed by a `ListView` for boolean values) together with the way
+/// numbers and dates are formatted (the decimal and thousands separators, the order of the components of a date and the
+/// first day of the week).
+///
+/// Every message has an id in the format `component.message` (e.g. `dialog.cancel` or `datepicker.jan`) and a default
+/// (English) text. Some messages contain parameters (e.g. `{file}`) that are replaced when the message is displayed.
+/// Besides the built-in messages, an application can load its own messages (with any id) and obtain their text
+/// via `App::translate(...)`.
+///
+/// The locale is set when the application is created (via `App::new().locale(...)`) or later on via `App::set_locale(...)`.
+/// The captions of the controls that were already created (e.g. the buttons of an opened dialog) are not changed.
+///
+/// # Example
+/// ```rust
+/// use appcui::prelude::*;
+///
+/// let mut locale = Locale::new(Locales::German);
+/// assert_eq!(locale.text("dialog.yes"), "&Ja");
+/// // or (from a message catalog)
+/// locale.load("dialog.yes = &Jawohl\n# comment\napp.greeting = Hallo !").unwrap();
+/// assert_eq!(locale.text("app.greeting"), "Hallo !");
+/// locale.set_number_separators(',', ' ').unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Locale {
+    messages: HashMap<String, String>,
+    decimal_separator: u8,
+    thousands_separator: u8,
+    date_order: DateOrder,
+    date_separator: u8,
+    first_weekday: Weekday,
+}
+
+impl Locale {
+    /// Creates a new locale based on one of the predefined locales.
+    pub fn new(locale: Locales) -> Self {
+        let (catalog, decimal_separator, thousands_separator) = match locale {
+            Locales::English => (&[][..], b'.', b','),
+            Locales::German => (GERMAN, b',', b'.'),
+            Locales::Romanian => (ROMANIAN, b',', b'.'),
+        };
+        Self {
+            messages: catalog.iter().map(|(id, text)| (id.to_string(), text.to_string())).collect(),
+            decimal_separator,
+            thousands_separator,
+            date_order: DateOrder::DayMonthYear,
+            date_separator: b'.',
+            first_weekday: Weekday::Mon,
+        }
+    }
+
+    /// Returns the ids of all built-in messages.
+    pub fn messages() -> impl Iterator<Item = &'static str> {
+        MESSAGES.iter().map(|(id, _)| *id)
+    }
+
+    /// Returns the text of a message. If the locale does not translate the message, the default (English) text of a
+    /// built-in message is returned, and if the message is not a built-in one, the id itself is returned.
+    pub fn text<'a>(&'a self, id: &'a str) -> &'a str {
+        if let Some(text) = self.messages.get(id) {
+            return text;
+        }
+        MESSAGES.iter().find(|(name, _)| *name == id).map(|(_, text)| *text).unwrap_or(id)
+    }
+
+    /// Sets the text of a message (a built-in one or a message of the application).
+    pub fn set_text(&mut self, id: &str, text: &str) {
+        self.messages.insert(id.to_string(), text.to_string());
+    }
+
+    /// Loads the messages from a text (usually the content of a catalog file) where every line has the format
+    /// `id = text` (for example `dialog.cancel = &Abbrechen`). A `\n` sequence within a text is replaced with a new line.
+    /// Empty lines and lines that start with `#` are ignored. If a line is not valid, an error (that contains the line
+    /// number) is returned and the messages from the previous lines remain loaded.
+    pub fn load(&mut self, text: &str) -> Result<(), Error> {
+        for (line_index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line.split_once('=') {
+                Some((id, text)) if !id.trim().is_empty() => self.set_text(id.trim(), &text.trim().replace("\\n", "\n")),
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidParameter,
+                        format!("Line {}: expecting 'id = text' but found '{line}'", line_index + 1),
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Loads the messages from a catalog file (see `load(...)` for the format of the file).
+    pub fn load_from_file(&mut self, path: &std::path::Path) -> Result<(), Error> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => self.load(&text),
+            Err(err) => Err(Error::new(
+                ErrorKind::InvalidParameter,
+                format!("Fail to read '{}': {err}", path.display()),
+            )),
+        }
+    }
+
+    /// Returns the texts of all built-in messages in the format used by `load(...)` (one message per line). This can be
+    /// used as a starting point for a new catalog.
+    pub fn to_catalog_string(&self) -> String {
+        let mut s = String::new();
+        for (id, _) in MESSAGES {
+            s.push_str(id);
+            s.push_str(" = ");
+            s.push_str(&self.text(id).replace('\n', "\\n"));
+            s.push('\n');
+        }
+        s
+    }
+
+    /// Sets the characters used as a decimal separator and as a thousands separator when a number is displayed.
+    /// Both characters must be different ASCII characters (other than a digit).
+    pub fn set_number_separators(&mut self, decimal: char, thousands: char) -> Result<(), Error> {
+        let decimal = Self::separator(decimal)?;
+        let thousands = Self::separator(thousands)?;
+        if decimal == thousands {
+            return Err(Error::new(
+                ErrorKind::InvalidParameter,
+                "The decimal and thousands separators must be different".to_string(),
+            ));
+        }
+        self.decimal_separator = decimal;
+        self.thousands_separator = thousands;
+        Ok(())
+    }
+
+    /// Returns the character used as a decimal separator.
+    #[inline(always)]
+    pub fn decimal_separator(&self) -> char {
+        self.decimal_separator as char
+    }
+
+    /// Returns the character used as a thousands separator.
+    #[inline(always)]
+    pub fn thousands_separator(&self) -> char {
+        self.thousands_separator as char
+    }
+
+    /// Sets the order of the components of a numeric date and the character used to separate them
+    /// (an ASCII character other than a digit).
+    pub fn set_date_format(&mut self, order: DateOrder, separator: char) -> Result<(), Error> {
+        self.date_separator = Self::separator(separator)?;
+        self.date_order = order;
+        Ok(())
+    }
+
+    /// Returns the order of the components of a numeric date.
+    #[inline(always)]
+    pub fn date_order(&self) -> DateOrder {
+        self.date_order
+    }
+
+    /// Returns the character used to separate the components of a numeric date.
+    #[inline(always)]
+    pub fn date_separator(&self) -> char {
+        self.date_separator as char
+    }
+
+    /// Sets the first day of the week (used by the calendar of a `DatePicker`).
+    #[inline(always)]
+    pub fn set_first_weekday(&mut self, day: Weekday) {
+        self.first_weekday = day;
+    }
+
+    /// Returns the first day of the week.
+    #[inline(always)]
+    pub fn first_weekday(&self) -> Weekday {
+        self.first_weekday
+    }
+
+    // the locale of the application (or the default one if the application was not created)
+    pub(crate) fn current() -> &'static Locale {
+        if RuntimeManager::is_instantiated() {
+            RuntimeManager::get().locale()
+        } else {
+            static DEFAULT: OnceLock<Locale> = OnceLock::new();
+            DEFAULT.get_or_init(|| Locale::new(Locales::English))
+        }
+    }
+
+    // the short name of a month (1 to 12)
+    pub(crate) fn month_name(&self, month: u32) -> &str {
+        self.text(MONTHS[(month.clamp(1, 12) - 1) as usize])
+    }
+
+    // the short name of a day of the week
+    pub(crate) fn weekday_name(&self, day: Weekday) -> &str {
+        self.text(WEEKDAYS[day.num_days_from_monday() as usize])
+    }
+
+    // replaces the decimal point and the thousands separator (written by FormatNumber as '.' and ',')
+    // with the ones from the locale (only between two digits, so that a unit or a prefix is never changed)
+    pub(crate) fn localize_number(&self, buffer: &mut [u8]) {
+        if self.decimal_separator == b'.' && self.thousands_separator == b',' {
+            return;
+        }
+        for index in 1..buffer.len().saturating_sub(1) {
+            if !buffer[index - 1].is_ascii_digit() || !buffer[index + 1].is_ascii_digit() {
+                continue;
+            }
+            match buffer[index] {
+                b'.' => buffer[index] = self.decimal_separator,
+                b',' => buffer[index] = self.thousands_separator,
+                _ => {}
+            }
+        }
+    }
+
+    // a numeric date (with a four digits or a two digits year)
+    pub(crate) fn format_date(&self, date: &NaiveDate, short_year: bool) -> String {
+        let mut buf = [0u8; 10];
+        let sep = self.date_separator;
+        let text = if short_year {
+            FormatDate::short(date, &mut buf, sep)
+        } else {
+            FormatDate::dmy(date, &mut buf, sep)
+        };
+        let Some(text) = text else {
+            return String::new();
+        };
+        let (d, m, y, sep) = (&text[0..2], &text[3..5], &text[6..], sep as char);
+        match self.date_order {
+            DateOrder::DayMonthYear => text.to_string(),
+            DateOrder::MonthDayYear => format!("{m}{sep}{d}{sep}{y}"),
+            DateOrder::YearMonthDay => format!("{y}{sep}{m}{sep}{d}"),
+        }
+    }
+
+    // a date that contains the name of the month (in the format from the `datepicker.long_date` message)
+    pub(crate) fn format_long_date(&self, date: &NaiveDate) -> String {
+        // FormatDate::normal --> "yyyy, Mon, dd"
+        let mut buf = [0u8; 13];
+        let Some(text) = FormatDate::normal(date, &mut buf) else {
+            return String::new();
+        };
+        self.text("datepicker.long_date")
+            .replace("{year}", &text[0..4])
+            .replace("{month}", self.month_name(date.month()))
+            .replace("{day}", &text[11..13])
+    }
+
+    fn separator(ch: char) -> Result<u8, Error> {
+        if ch.is_ascii() && !ch.is_ascii_digit() && !ch.is_ascii_control() {
+            Ok(ch as u8)
+        } else {
+            Err(Error::new(ErrorKind::InvalidParameter, format!("Invalid separator: '{ch}'")))
+        }
+    }
+}
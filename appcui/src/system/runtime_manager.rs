@@ -1,29 +1,36 @@
 use std::cell::RefCell;
 use std::sync::mpsc::{Receiver, Sender};
+use std::sync::Arc;
 
 use self::layout::ControlLayout;
 use self::menu::events::MousePressedMenuResult;
 
 use super::background_task::BackgroundTaskManager;
+use super::executor::Executor;
 use super::runtime_manager_traits::*;
 use super::timer::TimerManager;
+use super::ui_proxy::{ProxyMessage, ProxyQueue, UiProxy};
 use super::{ControlHandleManager, Handle, MenuHandleManager, Theme, ToolTip};
 use crate::backend::{self, Backend};
 use crate::graphics::{Point, Rect, Size, Surface};
-use crate::input::{Key, KeyModifier, MouseButton, MouseEvent, MouseEventData};
+use crate::input::{ChordStatus, Key, KeyMap, KeyModifier, MouseButton, MouseEvent, MouseEventData};
 use crate::prelude::*;
 use crate::ui::appbar::events::{AppBarEvent, AppBarEvents};
 use crate::ui::command_bar::events::GenericCommandBarEvents;
 use crate::ui::command_bar::{events::CommandBarEvent, CommandBar};
 use crate::ui::common::control_manager::ParentLayout;
-use crate::ui::common::ControlEvent;
+use crate::ui::common::control_event_wrapper::CustomEventData;
+use crate::ui::common::{ControlEvent, ControlEventData};
 use crate::ui::common::ControlManager;
 use crate::ui::desktop::EmptyDesktop;
 use crate::ui::menu::events::{GenericMenuEvents, MenuEvent};
+use crate::ui::toast::{Toast, ToastAction, ToastManager};
 use crate::ui::window::events::WindowEvents;
 use crate::ui::{AppBar, Menu};
 use crate::utils::VectorIndex;
 
+pub(crate) type AsyncModalCallback = Box<dyn FnOnce(&mut RuntimeManager)>;
+
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Debug)]
 enum LoopStatus {
@@ -57,14 +64,19 @@ enum ExpandStatus {
 
 pub(crate) struct RuntimeManager {
     theme: Theme,
+    key_map: KeyMap,
+    locale: Locale,
     backend: Box<dyn Backend>,
     surface: Surface,
     controls: *mut ControlHandleManager,
     menus: *mut MenuHandleManager,
     timers_manager: TimerManager,
     task_manager: BackgroundTaskManager,
+    executor: Executor,
+    proxy_queue: Arc<ProxyQueue>,
     desktop_handle: Handle<()>,
     tooltip: ToolTip,
+    toasts: ToastManager,
     commandbar: Option<CommandBar>,
     appbar: Option<AppBar>,
     recompute_layout: bool,
@@ -78,6 +90,7 @@ pub(crate) struct RuntimeManager {
     single_window: bool,
     loop_status: LoopStatus,
     request_focus: Option<Handle<()>>,
+    dropped_window: Option<(Handle<()>, Option<Point>)>,
     current_focus: Option<Handle<()>>,
     request_default_action: Option<Handle<()>>,
     expanded_control: ExpandedControlInfo,
@@ -90,6 +103,8 @@ pub(crate) struct RuntimeManager {
     mouse_locked_object: MouseLockedObject,
     opened_menu_handle: Handle<Menu>,
     modal_windows: Vec<Handle<()>>,
+    // modal windows shown via ModalWindow::show_async(...) and the callback that sends their result (once they are closed)
+    async_modal_windows: Vec<(Handle<()>, AsyncModalCallback)>,
     to_remove_list: Vec<Handle<()>>,
     event_receiver: Receiver<SystemEvent>,
     event_sender: Sender<SystemEvent>,
@@ -115,15 +130,22 @@ impl RuntimeManager {
         let (sender, receiver) = std::sync::mpsc::channel::<SystemEvent>();
         let backend_term = backend::new(&builder, sender.clone())?;
         let term_sz = backend_term.size();
+        // single threaded backends do not read the system event channel (so there is no need to wake up the event loop)
+        let wake_sender = if backend_term.is_single_threaded() { None } else { Some(sender.clone()) };
+        let executor = Executor::new(wake_sender.clone());
+        let proxy_queue = ProxyQueue::new(wake_sender);
         let surface = Surface::new(term_sz.width, term_sz.height);
         let mut manager = RuntimeManager {
             theme: builder.theme,
+            key_map: builder.key_map,
+            locale: builder.locale,
             backend: backend_term,
             event_receiver: receiver,
             event_sender: sender,
             surface,
             desktop_handle: Handle::new(0),
             tooltip: ToolTip::new(),
+            toasts: ToastManager::new(),
             recompute_layout: true,
             repaint: true,
             desktop_os_start_called: false,
@@ -134,6 +156,7 @@ impl RuntimeManager {
             mouse_pos: Point::new(-1, -1),
             key_modifier: KeyModifier::None,
             request_focus: None,
+            dropped_window: None,
             current_focus: None,
             request_default_action: None,
             mouse_over_control: Handle::None,
@@ -142,6 +165,7 @@ impl RuntimeManager {
             focus_chain: Vec::with_capacity(16),
             events: Vec::with_capacity(16),
             modal_windows: Vec::with_capacity(16),
+            async_modal_windows: Vec::new(),
             to_remove_list: Vec::with_capacity(4),
             commandbar_event: None,
             menu_event: None,
@@ -149,6 +173,8 @@ impl RuntimeManager {
             controls: Box::into_raw(Box::new(ControlHandleManager::new())),
             timers_manager: TimerManager::new(builder.max_timer_count),
             task_manager: BackgroundTaskManager::new(),
+            executor,
+            proxy_queue,
             menus: Box::into_raw(Box::new(MenuHandleManager::new())),
             loop_status: LoopStatus::Normal,
             mouse_locked_object: MouseLockedObject::None,
@@ -249,6 +275,34 @@ impl RuntimeManager {
     pub(crate) fn hide_tooltip(&mut self) {
         self.tooltip.hide();
     }
+    pub(crate) fn show_toast(&mut self, toast: Toast, receiver: Handle<()>) -> Handle<Toast> {
+        let timeout = toast.timeout;
+        let handle = self.toasts.add(toast, receiver);
+        // single threaded backends (e.g. the debug backend) do not read the system event channel
+        if let Some(milliseconds) = timeout.filter(|_| !self.backend.is_single_threaded()) {
+            let sender = self.event_sender.clone();
+            let wait = move || {
+                std::thread::sleep(std::time::Duration::from_millis(milliseconds as u64));
+                let _ = sender.send(SystemEvent::ToastExpired(handle));
+            };
+            #[cfg(not(target_arch = "wasm32"))]
+            std::thread::spawn(wait);
+            #[cfg(target_arch = "wasm32")]
+            rayon::spawn(wait);
+        }
+        self.repaint = true;
+        self.update_command_and_app_bars = true;
+        handle
+    }
+    pub(crate) fn dismiss_toast(&mut self, handle: Handle<Toast>) {
+        if self.toasts.remove(handle) {
+            self.repaint = true;
+        }
+    }
+    #[inline(always)]
+    pub(crate) fn get_toasts(&mut self) -> &mut ToastManager {
+        &mut self.toasts
+    }
     pub(crate) fn close_opened_menu(&mut self) {
         if !self.opened_menu_handle.is_none() {
             self.opened_menu_handle = Handle::None;
@@ -332,6 +386,13 @@ impl RuntimeManager {
         self.update_command_and_app_bars = true;
         self.repaint = true;
     }
+    pub(crate) fn request_recompute_parent_indexes(&mut self) {
+        self.recompute_parent_indexes = true;
+    }
+    // a window was moved (and dropped at the specified point) or resized by the user
+    pub(crate) fn request_dock_window(&mut self, handle: Handle<()>, point: Option<Point>) {
+        self.dropped_window = Some((handle, point));
+    }
     pub(crate) fn request_update(&mut self) {
         self.update_command_and_app_bars = true;
         self.repaint = true;
@@ -414,6 +475,9 @@ impl RuntimeManager {
         }
         handle.cast()
     }
+    pub(crate) fn add_async_modal_window(&mut self, handle: Handle<()>, on_close: AsyncModalCallback) {
+        self.async_modal_windows.push((handle, on_close));
+    }
     pub(crate) fn get_control_mut<T>(&mut self, handle: Handle<T>) -> Option<&mut T>
     where
         T: Control + 'static,
@@ -444,6 +508,16 @@ impl RuntimeManager {
     }
 
     #[inline(always)]
+    pub(crate) fn get_executor(&mut self) -> &mut Executor {
+        &mut self.executor
+    }
+    #[inline(always)]
+    pub(crate) fn is_single_threaded(&self) -> bool {
+        self.backend.is_single_threaded()
+    }
+    pub(crate) fn proxy(&self) -> UiProxy {
+        UiProxy::new(self.proxy_queue.clone())
+    }
     pub(crate) fn get_system_event_sender(&self) -> std::sync::mpsc::Sender<SystemEvent> {
         self.event_sender.clone()
     }
@@ -463,6 +537,16 @@ impl RuntimeManager {
         let menus = unsafe { &mut *self.menus };
         menus.get_mut(handle)
     }
+    #[inline(always)]
+    pub(crate) fn has_appbar(&self) -> bool {
+        self.appbar.is_some()
+    }
+    fn update_progress_indicators(&mut self) {
+        // tasks that run in background (with an indicator in the app bar) have no receiver control
+        if self.appbar.as_ref().is_some_and(|a| a.has_progress_indicators()) {
+            self.request_update_command_and_app_bars();
+        }
+    }
     pub(crate) fn get_appbar(&mut self) -> &mut AppBar {
         self.appbar.as_mut().expect("AppBar (application bar) was not enabled ! Have you forgot to add '.app_bar()' when you initialized the Application ? (e.g. App::new().app_bar().build())")
     }
@@ -514,12 +598,46 @@ impl RuntimeManager {
     pub(crate) fn update_desktop_window_count(&mut self) {
         let controls = unsafe { &mut *self.controls };
         if let Some(desktop) = controls.get_mut(self.desktop_handle.cast()) {
+            if !self.single_window {
+                desktop.get_mut::<Desktop>().sync_tiles();
+            }
             let count = desktop.base().children.len();
             DesktopEvents::on_update_window_count(desktop.control_mut(), count);
         }
     }
 
+    // paints a window (and all of its controls) in a separate surface with the size of the window
+    // (used for the previews from the window switcher). Hidden windows (e.g. inactive tabs in tiling mode) are painted as well.
+    pub(crate) fn window_snapshot(&mut self, handle: Handle<()>) -> Option<Surface> {
+        let controls = unsafe { &mut *self.controls };
+        let base = controls.get_mut(handle)?.base_mut();
+        let r = Rect::with_point_and_size(base.screen_origin, base.size());
+        let visible = base.is_visible();
+        base.set_visible(true);
+        let mut surface = Surface::new(self.surface.size.width, self.surface.size.height);
+        std::mem::swap(&mut self.surface, &mut surface);
+        self.surface.reset_clip_and_origin();
+        self.paint_control(handle);
+        std::mem::swap(&mut self.surface, &mut surface);
+        surface.reset_clip_and_origin();
+        if let Some(control) = controls.get_mut(handle) {
+            control.base_mut().set_visible(visible);
+        }
+        let mut snapshot = Surface::new(r.width(), r.height());
+        snapshot.draw_surface(-r.left(), -r.top(), &surface);
+        Some(snapshot)
+    }
+
     pub(crate) fn tick(&mut self, single_threaded: bool) {
+        // 0. Poll the futures that were woken up (or spawned) since the last tick
+        if self.executor.ready_count() > 0 {
+            self.poll_futures();
+        }
+        // 0. Process the messages posted (from other threads) via an UiProxy
+        if !self.proxy_queue.is_empty() {
+            self.process_proxy_messages();
+        }
+
         // 1. Process events from command bar
         if let Some(event) = self.commandbar_event {
             self.process_commandbar_event(event);
@@ -547,6 +665,13 @@ impl RuntimeManager {
             self.update_command_and_app_bars = true;
         }
 
+        // 5. if a window was moved or resized with the mouse, the desktop might need to dock it (in tiling mode)
+        if let Some((handle, point)) = self.dropped_window.take() {
+            if !self.single_window {
+                self.get_controls_mut().desktop_mut().get_mut::<Desktop>().dock_dropped_window(handle, point);
+            }
+        }
+
         // If we reach this point, there should not be any change in the logic of controls
         if self.recompute_parent_indexes {
             self.update_parent_indexes(self.get_root_control_handle());
@@ -606,6 +731,11 @@ impl RuntimeManager {
             }
         }
 
+        // an async modal window does not have its own loop (the current loop continues after it is closed)
+        if self.loop_status == LoopStatus::ExitCurrentLoop {
+            self.close_async_modal_window();
+        }
+
         #[cfg(target_arch = "wasm32")]
         {
             if self.loop_status == LoopStatus::ExitCurrentLoop {
@@ -614,6 +744,56 @@ impl RuntimeManager {
         }
     }
 
+    fn poll_futures(&mut self) {
+        // only the tasks that are ready now are polled (a task that wakes itself will be polled on the next tick)
+        for _ in 0..self.executor.ready_count() {
+            let Some((id, mut future, waker)) = self.executor.next_ready() else {
+                break;
+            };
+            let finished = Executor::poll(&mut future, &waker);
+            self.executor.complete_poll(id, if finished { None } else { Some(future) });
+        }
+        self.repaint = true;
+    }
+
+    fn process_proxy_messages(&mut self) {
+        // messages posted while processing the queue (from a closure) are processed on the next tick
+        let count = self.proxy_queue.len();
+        for _ in 0..count {
+            let Some(message) = self.proxy_queue.pop() else {
+                break;
+            };
+            match message {
+                ProxyMessage::Closure(f) => f(),
+                ProxyMessage::CustomEvent { handle, class_hash, event_id } => {
+                    let controls = unsafe { &mut *self.controls };
+                    if let Some(control) = controls.get(handle) {
+                        self.events.push(ControlEvent {
+                            emitter: handle,
+                            receiver: control.base().event_processor,
+                            data: ControlEventData::Custom(CustomEventData { class_hash, event_id }),
+                        });
+                    }
+                }
+                ProxyMessage::Repaint => {}
+            }
+        }
+        self.repaint = true;
+    }
+
+    fn close_async_modal_window(&mut self) {
+        let Some(handle) = self.modal_windows.last().copied() else {
+            return;
+        };
+        let Some(index) = self.async_modal_windows.iter().position(|(h, _)| *h == handle) else {
+            return;
+        };
+        let (_, on_close) = self.async_modal_windows.remove(index);
+        // the result has to be read before the window is removed
+        on_close(self);
+        self.exit_loop();
+    }
+
     fn exit_loop(&mut self) {
         // if we are in a modal loop --> we just need to change loop_statup
         // and delete the window and its children (mark them for deleteion)
@@ -702,6 +882,7 @@ impl RuntimeManager {
         match sys_event {
             SystemEvent::AppClose => self.loop_status = LoopStatus::StopApp,
             SystemEvent::KeyPressed(event) => self.process_keypressed_event(event),
+            SystemEvent::KeyReleased(event) => self.process_keyreleased_event(event),
             SystemEvent::KeyModifierChanged(event) => self.process_key_modifier_changed_event(event.new_state),
             SystemEvent::Resize(new_size) => {
                 self.backend.on_resize(new_size);
@@ -719,6 +900,9 @@ impl RuntimeManager {
             SystemEvent::BackgroundTaskEnd(h) => BackgroundTaskMethods::on_finish(self, h),
             SystemEvent::BackgroundTaskNotify(h) => BackgroundTaskMethods::on_notify(self, h),
             SystemEvent::BackgroundTaskQuery(h) => BackgroundTaskMethods::on_query(self, h),
+            SystemEvent::ToastExpired(h) => self.dismiss_toast(h),
+            // the futures are polled at the beginning of the next tick
+            SystemEvent::FutureWake | SystemEvent::ProxyWake => {}
         }
     }
     fn remove_control(&mut self, handle: Handle<()>, unlink_from_parent: bool) -> (Handle<()>, bool) {
@@ -916,6 +1100,12 @@ impl RuntimeManager {
     fn process_appbar_event(&mut self, event: AppBarEvent) {
         let controls = unsafe { &mut *self.controls };
         match event {
+            AppBarEvent::ButtonClick(ev) if self.appbar.as_ref().is_some_and(|a| a.is_notifications_button(ev.button_handle)) => {
+                // the notification center is a modal window (the current event must be cleared before its loop starts)
+                self.appbar_event = None;
+                crate::ui::toast::show_history();
+                return;
+            }
             AppBarEvent::ButtonClick(ev) => {
                 if let Some(control) = controls.get_mut(ev.control_receiver_handle) {
                     AppBarEvents::on_button_click(control.control_mut(), ev.button_handle);
@@ -972,6 +1162,10 @@ impl RuntimeManager {
                     break;
                 }
             }
+            appbar.show_progress_indicators();
+            if self.toasts.has_history() {
+                appbar.show_notifications_button(self.toasts.unread());
+            }
             appbar.update_positions();
         }
         self.process_menu_and_cmdbar_mousemove(self.mouse_pos.x, self.mouse_pos.y);
@@ -1081,6 +1275,14 @@ impl RuntimeManager {
             }
         }
 
+        // the top level window from the chain (used to keep the most recently used order of the desktop windows)
+        let chain_len = self.focus_chain.len();
+        let focused_window = if chain_len >= 2 && self.focus_chain[chain_len - 1] == self.desktop_handle {
+            Some(self.focus_chain[chain_len - 2])
+        } else {
+            None
+        };
+
         // 3. now lets call on_focus (in the reverse order --> from parent to child)
         let mut parent_handle = None;
         while let Some(handle) = self.focus_chain.pop() {
@@ -1112,6 +1314,11 @@ impl RuntimeManager {
         }
         self.current_focus = Some(handle);
         self.request_focus = None;
+        if let Some(window) = focused_window.filter(|_| !self.single_window) {
+            if let Some(desktop) = controls.get_mut(self.desktop_handle) {
+                desktop.get_mut::<Desktop>().update_mru(window);
+            }
+        }
         // check default actio
         if handle == self.request_default_action.unwrap_or(Handle::None) {
             if let Some(c) = controls.get_mut(handle) {
@@ -1150,10 +1357,14 @@ impl RuntimeManager {
             appbar.update_width(new_size.width);
         }
         // resize the desktop as well
+        let single_window = self.single_window;
         let desktop = self.get_controls_mut().desktop_mut();
         let original_size = desktop.base().size();
         desktop.base_mut().set_size(new_size.width as u16, new_size.height as u16);
         desktop.control_mut().on_resize(original_size, new_size);
+        if !single_window {
+            desktop.get_mut::<Desktop>().update_tiles();
+        }
         self.recompute_layout = true;
     }
 
@@ -1311,6 +1522,11 @@ impl PaintMethods for RuntimeManager {
         if let Some(appbar) = &self.appbar {
             appbar.paint(&mut self.surface, &self.theme);
         }
+        if !self.toasts.is_empty() {
+            let area = self.get_desktop_rect();
+            self.toasts.arrange(area);
+            self.toasts.paint(&mut self.surface, &self.theme);
+        }
         if self.tooltip.is_visible() {
             self.tooltip.paint(&mut self.surface, &self.theme);
         }
@@ -1327,6 +1543,10 @@ impl PaintMethods for RuntimeManager {
             if base.prepare_paint(&mut self.surface) {
                 // paint is possible
                 element.control().on_paint(&mut self.surface, &self.theme);
+                if handle == self.desktop_handle && !self.single_window {
+                    // the regions of the desktop in tiling mode (painted even if a custom desktop overwrites OnPaint)
+                    element.get::<Desktop>().paint_tiles(&mut self.surface, &self.theme);
+                }
                 let children_count = base.children.len();
                 if base.focused_child_index.in_range(children_count) {
                     // draw from the next visible element until
@@ -1382,12 +1602,34 @@ impl KeyboardMethods for RuntimeManager {
                 }
             }
         }
-        // 2. check controls
+        // 2. check for multi-key chords (the hotkeys of the menus from the app bar have priority over the chords)
+        if self.appbar.as_ref().is_some_and(|appbar| appbar.is_menu_hotkey(event.key)) {
+            if self.key_map.cancel_pending_chord() {
+                self.update_pending_chord();
+            }
+        } else {
+            match self.key_map.process_chord_key(event.key) {
+                ChordStatus::None => {}
+                ChordStatus::Pending | ChordStatus::Cancelled => {
+                    self.update_pending_chord();
+                    return;
+                }
+                ChordStatus::Completed => {
+                    self.update_pending_chord();
+                    // controls receive the completed chord via KeyMap::translate(...)
+                    self.process_control_keypressed_event(self.get_root_control_handle(), Key::None, '\0');
+                    self.key_map.clear_completed_chord();
+                    self.repaint = true;
+                    return;
+                }
+            }
+        }
+        // 3. check controls
         if self.process_control_keypressed_event(self.get_root_control_handle(), event.key, event.character) == EventProcessStatus::Processed {
             self.repaint = true;
             return;
         };
-        // 3. check cmdbar
+        // 4. check cmdbar
         if let Some(cmdbar) = self.commandbar.as_mut() {
             self.commandbar_event = cmdbar.get_event(event.key);
             if self.commandbar_event.is_some() {
@@ -1395,7 +1637,7 @@ impl KeyboardMethods for RuntimeManager {
                 return;
             }
         }
-        // 4. check the appbar
+        // 5. check the appbar
         if let Some(appbar) = self.appbar.as_mut() {
             if appbar.on_key_event(event.key, false) == EventProcessStatus::Processed {
                 self.repaint = true;
@@ -1403,6 +1645,34 @@ impl KeyboardMethods for RuntimeManager {
             }
         }
     }
+    fn process_keyreleased_event(&mut self, event: KeyReleasedEvent) {
+        // key releases are only sent to the focused control (and its parents) if no menu is opened
+        if self.get_opened_menu().is_some() {
+            return;
+        }
+        if self.process_control_keyreleased_event(self.get_root_control_handle(), event.key) == EventProcessStatus::Processed {
+            self.repaint = true;
+        }
+    }
+    fn process_control_keyreleased_event(&mut self, handle: Handle<()>, key: Key) -> EventProcessStatus {
+        let controls = unsafe { &mut *self.controls };
+        if let Some(control) = controls.get_mut(handle) {
+            let base = control.base();
+            if !base.is_active() {
+                return EventProcessStatus::Ignored;
+            }
+            if base.focused_child_index.in_range(base.children.len()) {
+                let handle_child = base.children[base.focused_child_index.index()];
+                if self.process_control_keyreleased_event(handle_child, key) == EventProcessStatus::Processed {
+                    return EventProcessStatus::Processed;
+                }
+            }
+            if base.can_receive_input() {
+                return control.control_mut().on_key_released(key);
+            }
+        }
+        EventProcessStatus::Ignored
+    }
     fn process_control_keypressed_event(&mut self, handle: Handle<()>, key: Key, character: char) -> EventProcessStatus {
         let controls = unsafe { &mut *self.controls };
         if let Some(control) = controls.get_mut(handle) {
@@ -1718,6 +1988,23 @@ impl MouseMethods for RuntimeManager {
         if !self.opened_menu_handle.is_none() && self.process_menu_mouse_click(self.opened_menu_handle, event.x, event.y) {
             return;
         }
+        // check toasts (they are painted on top of the desktop and windows)
+        match self.toasts.on_mouse_pressed(event.x, event.y) {
+            ToastAction::None => {}
+            ToastAction::Consumed => return,
+            ToastAction::Dismiss(toast) => {
+                self.dismiss_toast(toast);
+                return;
+            }
+            ToastAction::Action { receiver, toast, id } => {
+                self.dismiss_toast(toast);
+                let controls = unsafe { &mut *self.controls };
+                if let Some(control) = controls.get_mut(receiver) {
+                    ToastEvents::on_action(control.control_mut(), toast, id);
+                }
+                return;
+            }
+        }
         // check appbar (only if y==0 - the event is on the appbar)
         if event.y == 0 {
             if let Some(appbar) = self.appbar.as_mut() {
@@ -1843,6 +2130,30 @@ impl MouseMethods for RuntimeManager {
         }
     }
 }
+impl RuntimeManager {
+    #[inline(always)]
+    pub(crate) fn key_map(&self) -> &KeyMap {
+        &self.key_map
+    }
+    pub(crate) fn set_key_map(&mut self, key_map: KeyMap) {
+        self.key_map = key_map;
+        self.update_pending_chord();
+    }
+    #[inline(always)]
+    pub(crate) fn locale(&self) -> &Locale {
+        &self.locale
+    }
+    pub(crate) fn set_locale(&mut self, locale: Locale) {
+        self.locale = locale;
+        self.repaint = true;
+    }
+    fn update_pending_chord(&mut self) {
+        if let Some(commandbar) = self.commandbar.as_mut() {
+            commandbar.set_pending_chord(self.key_map.pending_chord_text());
+        }
+        self.repaint = true;
+    }
+}
 impl ThemeMethods for RuntimeManager {
     #[inline(always)]
     fn theme(&self) -> &Theme {
@@ -1974,15 +2285,25 @@ impl BackgroundTaskMethods for RuntimeManager {
                 self.repaint = true;
             }
         }
+        self.update_progress_indicators();
     }
 
     fn on_finish(&mut self, backgroundtask_handle: Handle<()>) {
+        // a task that panicked is reported (via on_error) before it is finished
+        if self.task_manager.has_panicked(backgroundtask_handle.index()) {
+            if let Some(c) = self.background_task_handle_to_control(backgroundtask_handle) {
+                if GenericBackgroundTaskEvents::on_error(c.control_mut(), backgroundtask_handle) == EventProcessStatus::Processed {
+                    self.repaint = true;
+                }
+            }
+        }
         if let Some(c) = self.background_task_handle_to_control(backgroundtask_handle) {
             if GenericBackgroundTaskEvents::on_finish(c.control_mut(), backgroundtask_handle) == EventProcessStatus::Processed {
                 self.repaint = true;
             }
         }
         self.task_manager.remove_task(backgroundtask_handle);
+        self.update_progress_indicators();
     }
 
     fn on_query(&mut self, backgroundtask_handle: Handle<()>) {
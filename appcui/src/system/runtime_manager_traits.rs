@@ -7,6 +7,7 @@ use super::timer::TimerManager;
 use super::Handle;
 use super::Theme;
 use super::KeyPressedEvent;
+use super::KeyReleasedEvent;
 use super::MouseButtonDownEvent;
 use super::MouseWheelEvent;
 use super::MouseMoveEvent;
@@ -29,6 +30,8 @@ pub(crate) trait KeyboardMethods {
     fn process_key_modifier_changed_event(&mut self, new_state: KeyModifier);
     fn process_keypressed_event(&mut self, event: KeyPressedEvent);
     fn process_control_keypressed_event(&mut self, handle: Handle<()>, key: Key, character: char) -> EventProcessStatus;
+    fn process_keyreleased_event(&mut self, event: KeyReleasedEvent);
+    fn process_control_keyreleased_event(&mut self, handle: Handle<()>, key: Key) -> EventProcessStatus;
 }
 pub(crate) trait MouseMethods {
     fn coordinates_to_child_control(&mut self, handle: Handle<()>, x: i32, y: i32, ignore_expanded: bool) -> Handle<()>;
@@ -2,6 +2,7 @@ use crate::{
     input::{Key, KeyModifier, MouseButton, MouseWheelDirection},
     system::Handle,
     graphics::Size,
+    ui::toast::Toast,
 };
 
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -44,6 +45,10 @@ pub(crate) struct KeyPressedEvent {
     pub(crate) character: char,
 }
 #[derive(Copy, Clone, PartialEq, Debug)]
+pub(crate) struct KeyReleasedEvent {
+    pub(crate) key: Key,
+}
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub(crate) struct KeyModifierChangedEvent {
     pub(crate) new_state: KeyModifier,
     pub(crate) old_state: KeyModifier,
@@ -89,6 +94,7 @@ pub(crate) struct TimerPausedEvent {
 pub(crate) enum SystemEvent {
     AppClose,
     KeyPressed(KeyPressedEvent),
+    KeyReleased(KeyReleasedEvent),
     KeyModifierChanged(KeyModifierChangedEvent),
     Resize(Size),
     MouseButtonDown(MouseButtonDownEvent),
@@ -103,6 +109,9 @@ pub(crate) enum SystemEvent {
     BackgroundTaskEnd(Handle<()>),
     BackgroundTaskNotify(Handle<()>),
     BackgroundTaskQuery(Handle<()>),
+    ToastExpired(Handle<Toast>),
+    FutureWake,
+    ProxyWake,
 }
 
 impl SystemEvent {
@@ -1,6 +1,9 @@
 use appcui_proc_macro::*;
 
 use super::App;
+use super::DateOrder;
+use super::Locale;
+use super::Locales;
 use super::Theme;
 use super::Themes;
 use super::ToolTip;
@@ -357,3 +360,294 @@ fn check_create_app_with_windows_terminal() {
     let _ = App::with_backend(crate::backend::Type::WindowsConsole).build();
 }
 
+
+#[test]
+fn check_async_tasks_order() {
+    use std::cell::RefCell;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::rc::Rc;
+    use std::task::{Context, Poll};
+
+    // a future that is pending once (and wakes itself up)
+    struct YieldNow(bool);
+    impl Future for YieldNow {
+        type Output = ();
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                return Poll::Ready(());
+            }
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    #[CustomControl(overwrite:OnKeyPressed, internal: true)]
+    struct TestControl {
+        log: Rc<RefCell<Vec<String>>>,
+    }
+    impl OnKeyPressed for TestControl {
+        fn on_key_pressed(&mut self, _key: Key, character: char) -> EventProcessStatus {
+            self.log.borrow_mut().push(format!("key {character}"));
+            let log = self.log.clone();
+            match character {
+                'a' => {
+                    App::spawn_local(async move {
+                        log.borrow_mut().push(String::from("start a"));
+                        let value = App::spawn_blocking(|| 'A').await;
+                        log.borrow_mut().push(format!("end {value}"));
+                    });
+                }
+                'b' => {
+                    App::spawn_local(async move {
+                        log.borrow_mut().push(String::from("start b"));
+                        YieldNow(false).await;
+                        log.borrow_mut().push(String::from("yield b"));
+                        YieldNow(false).await;
+                        log.borrow_mut().push(String::from("end b"));
+                    });
+                }
+                'c' => {
+                    // a task that waits for the result of another task
+                    let inner_log = log.clone();
+                    let first = App::spawn_local(async move {
+                        YieldNow(false).await;
+                        inner_log.borrow_mut().push(String::from("first c"));
+                        3
+                    });
+                    App::spawn_local(async move {
+                        let value = first.await;
+                        log.borrow_mut().push(format!("second c {value}"));
+                    });
+                }
+                _ => {}
+            }
+            EventProcessStatus::Processed
+        }
+    }
+    let script = "
+        Paint.Enable(false)
+        Key.Pressed(A)
+        Key.Pressed(B)
+        Key.Pressed(X)
+        Key.Pressed(C)
+        Key.Pressed(X)
+        Key.Pressed(X)
+    ";
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mut a = App::debug(60, 10, script).build().unwrap();
+    let mut w = window!("Test,d:f");
+    w.add(TestControl {
+        base: ControlBase::new(layout!("d:f"), true),
+        log: log.clone(),
+    });
+    a.add_window(w);
+    let handle = App::spawn_blocking(|| 10);
+    assert!(handle.is_finished());
+    a.run();
+    // a task is polled after the event that spawned (or woke) it and before the next input event
+    assert_eq!(
+        log.borrow().join(", "),
+        "key a, start a, end A, key b, start b, yield b, key x, end b, key c, first c, key x, second c 3, key x"
+    );
+}
+
+#[test]
+fn check_async_task_woken_while_running() {
+    use std::cell::RefCell;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::rc::Rc;
+    use std::task::{Context, Poll};
+
+    // a future that wakes itself up and then shows a (blocking) dialog - the executor polls other tasks
+    // while the dialog is opened, but the wake must not be lost
+    struct WakeThenBlock {
+        polled: bool,
+        log: Rc<RefCell<Vec<String>>>,
+    }
+    impl Future for WakeThenBlock {
+        type Output = ();
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.polled {
+                return Poll::Ready(());
+            }
+            self.polled = true;
+            cx.waker().wake_by_ref();
+            crate::dialogs::message("Message", "Blocking");
+            self.log.borrow_mut().push(String::from("dialog closed"));
+            Poll::Pending
+        }
+    }
+
+    #[CustomControl(overwrite:OnKeyPressed, internal: true)]
+    struct TestControl {
+        log: Rc<RefCell<Vec<String>>>,
+    }
+    impl OnKeyPressed for TestControl {
+        fn on_key_pressed(&mut self, _key: Key, character: char) -> EventProcessStatus {
+            self.log.borrow_mut().push(format!("key {character}"));
+            if character == 'a' {
+                let log = self.log.clone();
+                App::spawn_local(async move {
+                    WakeThenBlock { polled: false, log: log.clone() }.await;
+                    log.borrow_mut().push(String::from("end a"));
+                });
+            }
+            EventProcessStatus::Processed
+        }
+    }
+    let script = "
+        Paint.Enable(false)
+        Key.Pressed(A)
+        Key.Pressed(Enter)
+        Key.Pressed(X)
+    ";
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mut a = App::debug(60, 10, script).build().unwrap();
+    let mut w = window!("Test,d:f");
+    w.add(TestControl {
+        base: ControlBase::new(layout!("d:f"), true),
+        log: log.clone(),
+    });
+    a.add_window(w);
+    a.run();
+    assert_eq!(log.borrow().join(", "), "key a, dialog closed, end a, key x");
+}
+
+#[test]
+fn check_ui_proxy() {
+    use std::sync::{Arc, Mutex};
+
+    #[CustomControl(overwrite:OnKeyPressed, emit: Done, internal: true)]
+    struct ProxyControl {
+        log: Arc<Mutex<Vec<String>>>,
+    }
+    impl OnKeyPressed for ProxyControl {
+        fn on_key_pressed(&mut self, _key: Key, character: char) -> EventProcessStatus {
+            self.log.lock().unwrap().push(format!("key {character}"));
+            if character == 'a' {
+                let proxy = App::proxy();
+                let log = self.log.clone();
+                let handle = self.handle;
+                std::thread::spawn(move || {
+                    let inner_log = log.clone();
+                    let inner_proxy = proxy.clone();
+                    proxy.post(move || {
+                        inner_log.lock().unwrap().push(String::from("closure"));
+                        // posted from the UI thread --> processed on the next tick
+                        inner_proxy.post(move || inner_log.lock().unwrap().push(String::from("inner closure")));
+                    });
+                    proxy.post_custom_event(handle, proxycontrol::CLASS_HASH, u32::from(proxycontrol::Events::Done));
+                    proxy.request_repaint();
+                })
+                .join()
+                .unwrap();
+            }
+            EventProcessStatus::Processed
+        }
+    }
+
+    #[Window(custom_events = ProxyControlEvents, internal: true)]
+    struct ProxyWindow {
+        log: Arc<Mutex<Vec<String>>>,
+    }
+    impl ProxyControlEvents for ProxyWindow {
+        fn on_event(&mut self, _handle: Handle<ProxyControl>, event: proxycontrol::Events) -> EventProcessStatus {
+            self.log.lock().unwrap().push(format!("event {event:?}"));
+            EventProcessStatus::Processed
+        }
+    }
+
+    let script = "
+        Paint.Enable(false)
+        Key.Pressed(A)
+        Key.Pressed(B)
+        Key.Pressed(C)
+    ";
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut a = App::debug(60, 10, script).build().unwrap();
+    let mut w = ProxyWindow {
+        base: window!("Test,d:f"),
+        log: log.clone(),
+    };
+    w.add(ProxyControl {
+        base: ControlBase::new(layout!("d:f"), true),
+        log: log.clone(),
+    });
+    a.add_window(w);
+    a.run();
+    // the messages are processed (in the order they were posted) before the next input event
+    assert_eq!(log.lock().unwrap().join(", "), "key a, closure, event Done, inner closure, key b, key c");
+}
+
+#[test]
+fn check_locale_messages() {
+    let mut locale = Locale::new(Locales::German);
+    assert_eq!(locale.text("dialog.cancel"), "&Abbrechen");
+    assert_eq!(locale.text("datepicker.oct"), "Okt");
+    assert_eq!(locale.text("toast.empty"), "Es gibt keine Benachrichtigungen");
+    assert_eq!(Locale::new(Locales::Romanian).text("progress.done"), "{title}: terminat");
+    // not translated --> the default (English) text or the id
+    assert_eq!(Locale::new(Locales::English).text("dialog.cancel"), "&Cancel");
+    assert_eq!(locale.text("app.unknown"), "app.unknown");
+    locale.set_text("app.save", "Speichern");
+    assert_eq!(locale.text("app.save"), "Speichern");
+    assert!(Locale::messages().any(|id| id == "timepicker.pm"));
+    let catalog = Locale::new(Locales::Romanian).to_catalog_string();
+    assert!(catalog.contains("dialog.yes = &Da\n"));
+    assert_eq!(catalog.lines().count(), Locale::messages().count());
+}
+
+#[test]
+fn check_locale_load() {
+    let mut locale = Locale::new(Locales::English);
+    locale
+        .load("# my messages\n\ndialog.yes = &Yeah\n  app.lines =  first\\nsecond  \n")
+        .unwrap();
+    assert_eq!(locale.text("dialog.yes"), "&Yeah");
+    assert_eq!(locale.text("app.lines"), "first\nsecond");
+    let err = locale.load("dialog.no = &Nope\ndialog.cancel &Abort").unwrap_err();
+    assert!(err.to_string().contains("Line 2"));
+    assert_eq!(locale.text("dialog.no"), "&Nope");
+    assert!(locale.load(" = text").is_err());
+    assert!(locale.load_from_file(std::path::Path::new("/this/file/does/not/exist.catalog")).is_err());
+}
+
+#[test]
+fn check_locale_formats() {
+    let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 7).unwrap();
+    let mut locale = Locale::new(Locales::English);
+    assert_eq!(locale.format_date(&date, false), "07.03.2025");
+    assert_eq!(locale.format_long_date(&date), "2025, Mar, 07");
+    locale.set_date_format(DateOrder::MonthDayYear, '/').unwrap();
+    assert_eq!(locale.format_date(&date, false), "03/07/2025");
+    locale.set_date_format(DateOrder::YearMonthDay, '-').unwrap();
+    assert_eq!(locale.format_date(&date, true), "25-03-07");
+    assert!(locale.set_date_format(DateOrder::YearMonthDay, '1').is_err());
+    assert_eq!(locale.date_separator(), '-');
+
+    let mut buf = *b"USD 1,234,567.89";
+    locale.localize_number(&mut buf);
+    assert_eq!(&buf, b"USD 1,234,567.89");
+    let german = Locale::new(Locales::German);
+    german.localize_number(&mut buf);
+    assert_eq!(&buf, b"USD 1.234.567,89");
+    assert_eq!(german.format_long_date(&date), "07. Mär 2025");
+    assert_eq!(german.first_weekday(), chrono::Weekday::Mon);
+
+    assert!(locale.set_number_separators(',', ',').is_err());
+    assert!(locale.set_number_separators('ä', '.').is_err());
+    locale.set_number_separators(',', ' ').unwrap();
+    assert_eq!((locale.decimal_separator(), locale.thousands_separator()), (',', ' '));
+    let mut buf = *b"1,234.5";
+    locale.localize_number(&mut buf);
+    assert_eq!(&buf, b"1 234,5");
+    // only the separators between digits are changed (not the ones from a unit)
+    let mut buf = *b"-1,5.0 Std.";
+    german.localize_number(&mut buf);
+    assert_eq!(&buf, b"-1.5,0 Std.");
+    assert_eq!(german.text("unit.days"), "T");
+    assert_eq!(Locale::new(Locales::English).text("unit.kilobytes"), "KB");
+}
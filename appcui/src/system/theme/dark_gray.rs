@@ -23,6 +23,7 @@ pub(crate) fn new() -> Theme {
             enphasized_1: charattr!("white"),
             enphasized_2: charattr!("white"),
             enphasized_3: charattr!("white"),
+            modified: charattr!("white,black"),
         },
         symbol: SymbolTheme {
             inactive: charattr!("silver"),
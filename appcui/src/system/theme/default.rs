@@ -23,6 +23,7 @@ pub(crate) fn new() -> Theme {
             enphasized_1: charattr!("aqua"),
             enphasized_2: charattr!("green"),
             enphasized_3: charattr!("pink"),
+            modified: charattr!("white,dr"),
         },
         symbol: SymbolTheme {
             inactive: charattr!("gray"),
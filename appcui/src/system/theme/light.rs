@@ -23,6 +23,7 @@ pub(crate) fn new() -> Theme {
             enphasized_1: charattr!("dr"),
             enphasized_2: charattr!("dg"),
             enphasized_3: charattr!("olive"),
+            modified: charattr!("white,dr"),
         },
         symbol: SymbolTheme {
             inactive: charattr!("gray"),
@@ -23,6 +23,7 @@ pub struct TextTheme {
     pub enphasized_1: CharAttribute,
     pub enphasized_2: CharAttribute,
     pub enphasized_3: CharAttribute,
+    pub modified: CharAttribute,
 }
 #[derive(Default)]
 pub struct ToolTipTheme {
@@ -0,0 +1,98 @@
+use super::{Handle, SystemEvent};
+use std::collections::VecDeque;
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+
+pub(crate) enum ProxyMessage {
+    Closure(Box<dyn FnOnce() + Send>),
+    CustomEvent { handle: Handle<()>, class_hash: u64, event_id: u32 },
+    Repaint,
+}
+
+pub(crate) struct ProxyQueue {
+    messages: Mutex<VecDeque<ProxyMessage>>,
+    // None for single threaded backends (they don't read the system event channel)
+    sender: Option<Sender<SystemEvent>>,
+}
+
+impl ProxyQueue {
+    pub(crate) fn new(sender: Option<Sender<SystemEvent>>) -> Arc<Self> {
+        Arc::new(Self {
+            messages: Mutex::new(VecDeque::new()),
+            sender,
+        })
+    }
+    pub(crate) fn is_empty(&self) -> bool {
+        self.messages.lock().unwrap().is_empty()
+    }
+    pub(crate) fn len(&self) -> usize {
+        self.messages.lock().unwrap().len()
+    }
+    pub(crate) fn pop(&self) -> Option<ProxyMessage> {
+        self.messages.lock().unwrap().pop_front()
+    }
+    fn push(&self, message: ProxyMessage) {
+        self.messages.lock().unwrap().push_back(message);
+        // wake up the event loop (if it waits for a system event)
+        if let Some(sender) = &self.sender {
+            let _ = sender.send(SystemEvent::ProxyWake);
+        }
+    }
+}
+
+/// A cloneable object (that implements `Send` and `Sync`) that can be used from any thread to communicate with the UI thread.
+/// A proxy is obtained via `App::proxy()` and can be moved into threads that were not started via a `BackgroundTask`.
+///
+/// All messages are processed (in the order they were posted) by the UI thread, right before the next event is processed.
+/// If the application was closed, the messages posted through a proxy are silently ignored.
+///
+/// # Example
+/// ```rust,no_run
+/// use appcui::prelude::*;
+///
+/// fn start_download(label: Handle<Label>) {
+///     let proxy = App::proxy();
+///     std::thread::spawn(move || {
+///         // ... some long running operation ...
+///         proxy.post(move || {
+///             Window::update_control(label, |l| l.set_caption("Download completed"));
+///         });
+///     });
+/// }
+/// ```
+#[derive(Clone)]
+pub struct UiProxy {
+    queue: Arc<ProxyQueue>,
+}
+
+impl UiProxy {
+    pub(crate) fn new(queue: Arc<ProxyQueue>) -> Self {
+        Self { queue }
+    }
+
+    /// Posts a closure that will be executed on the UI thread. The closure can use the AppCUI API
+    /// (e.g. `Window::update_control(...)`, `App::spawn_local(...)` or the dialogs from the `dialogs` module).
+    pub fn post<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.queue.push(ProxyMessage::Closure(Box::new(f)));
+    }
+
+    /// Posts a custom event on behalf of the control identified by `handle` (the same way `raise_custom_event(...)`
+    /// from the control's base would). The event is received by the `CustomEvents::on_event(...)` method of the
+    /// object that processes the events of that control (usually its window). If the control does not exist
+    /// anymore when the event is processed, the event is ignored.
+    pub fn post_custom_event<T>(&self, handle: Handle<T>, class_hash: u64, event_id: u32) {
+        self.queue.push(ProxyMessage::CustomEvent {
+            handle: handle.cast(),
+            class_hash,
+            event_id,
+        });
+    }
+
+    /// Requests the UI thread to repaint the screen.
+    pub fn request_repaint(&self) {
+        self.queue.push(ProxyMessage::Repaint);
+    }
+}
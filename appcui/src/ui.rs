@@ -93,6 +93,7 @@ pub mod textfield;
 pub mod richtextfield;
 pub mod menu;
 pub mod appbar;
+pub mod toast;
 pub mod command_bar;
 pub mod window;
 pub mod button;
@@ -125,6 +126,7 @@ pub mod textarea;
 pub mod graphview;
 pub mod timepicker;
 pub mod bufferview;
+pub mod buffercompareview;
 pub mod hslider;
 
 // re-export
@@ -176,6 +178,8 @@ pub use textarea::TextArea;
 pub use timepicker::TimePicker;
 pub use bufferview::BufferView;
 pub use bufferview::BufferAccess;
+pub use bufferview::DataInspector;
+pub use buffercompareview::BufferCompareView;
 pub use menu::Menu;
 pub use appbar::AppBar;
 pub use graphview::GraphView;
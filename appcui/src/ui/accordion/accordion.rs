@@ -287,7 +287,8 @@ impl OnMouseEvent for Accordion {
 }
 impl OnKeyPressed for Accordion {
     fn on_key_pressed(&mut self, key: Key, _character: char) -> EventProcessStatus {
-        match key.value() {
+        let (translated, _) = KeyMap::translate_key("accordion", key, '\0');
+        match translated.value() {
             key!("Ctrl+Tab") => {
                 let mut idx = self.base.focused_child_index;
                 idx.add(1, self.base.children.len(), Strategy::RotateFromInvalidState);
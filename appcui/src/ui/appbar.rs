@@ -10,6 +10,7 @@ mod button;
 mod label;
 mod side;
 mod item_status;
+mod progress_indicator;
 #[cfg(test)]
 mod tests;
 
@@ -17,6 +18,8 @@ mod tests;
 use self::app_bar_item::AppBarItem;
 use self::item_base::ItemBase;
 use self::item_status::ItemStatus;
+use self::progress_indicator::ProgressIndicatorItem;
+pub(crate) use self::progress_indicator::ProgressIndicator;
 pub use self::menu_button::MenuButton;
 pub use self::separator::Separator;
 pub use self::label::Label;
@@ -1,11 +1,12 @@
-use super::AppBarItem;
+use super::{AppBarItem, ProgressIndicator, ProgressIndicatorItem, Side};
 use crate::graphics::*;
 use crate::input::*;
-use crate::system::{Handle, RuntimeManager, Theme};
+use crate::system::{Handle, Locale, RuntimeManager, Theme};
 use crate::ui::appbar;
 use crate::ui::appbar::ItemStatus;
 use crate::ui::common::traits::EventProcessStatus;
 use crate::utils::HandleManager;
+use std::sync::Weak;
 
 macro_rules! const_cast {
     ($obj:expr, $from:ty, $to:ty) => {
@@ -56,6 +57,9 @@ pub struct AppBar {
     opened_menu: Option<VisibleIndex>,
     pressed_item: Option<VisibleIndex>,
     last_mouse_pos: Point,
+    indicators: Vec<ProgressIndicatorItem>,
+    free_labels: Vec<Handle<appbar::Label>>,
+    notifications: Handle<appbar::Button>,
 }
 impl AppBar {
     const LEFT_RIGHT_MIN_SPACE: i32 = 5;
@@ -69,6 +73,9 @@ impl AppBar {
             opened_menu: None,
             pressed_item: None,
             last_mouse_pos: Point::new(i32::MAX, i32::MAX),
+            indicators: Vec::new(),
+            free_labels: Vec::new(),
+            notifications: Handle::None,
         }
     }
     /// Add an item to the app bar. The item has to be converted to an AppBarItem.
@@ -143,6 +150,71 @@ impl AppBar {
         self.shown_items.clear();
         self.receiver_control_handle = Handle::None;
     }
+    pub(crate) fn add_progress_indicator(&mut self, indicator: Weak<dyn ProgressIndicator>) {
+        let label = if let Some(h) = self.free_labels.pop() {
+            h
+        } else {
+            self.add(appbar::Label::new("", u8::MAX, Side::Right))
+        };
+        self.indicators.push(ProgressIndicatorItem { indicator, label });
+    }
+    #[inline(always)]
+    pub(crate) fn has_progress_indicators(&self) -> bool {
+        !self.indicators.is_empty()
+    }
+    pub(crate) fn show_progress_indicators(&mut self) {
+        let mut index = 0;
+        while index < self.indicators.len() {
+            let label = self.indicators[index].label;
+            if let Some(caption) = self.indicators[index].indicator.upgrade().and_then(|i| i.caption()) {
+                if let Some(item) = self.get_mut(label) {
+                    if item.caption() != caption {
+                        item.set_caption(&caption);
+                    }
+                }
+                self.push_shown_item(label.cast());
+                index += 1;
+            } else {
+                // the indicator is no longer needed - its label can be reused
+                self.free_labels.push(label);
+                self.indicators.remove(index);
+            }
+        }
+    }
+    pub(crate) fn show_notifications_button(&mut self, unread: usize) {
+        if self.notifications.is_none() {
+            let tooltip = Locale::current().text("appbar.notifications");
+            self.notifications = self.add(appbar::Button::with_tooltip("", tooltip, u8::MAX, Side::Right));
+        }
+        let locale = Locale::current();
+        let caption = if unread > 0 {
+            format!(" {} ", locale.text("appbar.unread_notifications").replace("{unread}", &unread.to_string()))
+        } else {
+            format!(" {} ", locale.text("appbar.notifications"))
+        };
+        let h = self.notifications;
+        if let Some(button) = self.get_mut(h) {
+            if button.caption() != caption {
+                button.set_caption(&caption);
+            }
+        }
+        self.push_shown_item(h.cast());
+    }
+    #[inline(always)]
+    pub(crate) fn is_notifications_button(&self, handle: Handle<appbar::Button>) -> bool {
+        !self.notifications.is_none() && self.notifications == handle
+    }
+    fn push_shown_item(&mut self, handle: Handle<()>) {
+        if let Some(item) = self.manager.get(handle.cast()) {
+            let base = item.base();
+            self.shown_items.push(AppBarItemPos {
+                idx: handle.index() as u32,
+                x: base.x() as i16,
+                width: base.width(),
+                order: base.order(),
+            });
+        }
+    }
     pub(crate) fn close(&mut self) {
         self.opened_menu = None;
         self.mouse_pos = MousePos::None;
@@ -397,6 +469,13 @@ impl AppBar {
         }
         false
     }
+    // returns true if the key opens one of the menus from the app bar (e.g. Alt+F)
+    pub(crate) fn is_menu_hotkey(&self, key: Key) -> bool {
+        self.shown_items
+            .iter()
+            .filter_map(|pos| self.manager.element(pos.idx as usize))
+            .any(|elem| elem.is_enabled() && key == elem.hotkey())
+    }
     fn process_key(&mut self, key: Key) -> EventProcessStatus {
         match key.code {
             KeyCode::Left => {
@@ -413,6 +492,7 @@ impl AppBar {
 
     pub(crate) fn on_key_event(&mut self, key: Key, menu_is_opened: bool) -> EventProcessStatus {
         if menu_is_opened {
+            let (key, _) = KeyMap::translate_key("appbar", key, '\0');
             if key.modifier.is_empty() {
                 return self.process_key(key);
             }
@@ -0,0 +1,14 @@
+use super::Label;
+use crate::system::Handle;
+use std::sync::Weak;
+
+/// An object (usually a task that runs in background) whose status is displayed in the app bar.
+pub(crate) trait ProgressIndicator {
+    /// Returns the text that will be displayed in the app bar or `None` if the indicator should be hidden.
+    fn caption(&self) -> Option<String>;
+}
+
+pub(super) struct ProgressIndicatorItem {
+    pub(super) indicator: Weak<dyn ProgressIndicator>,
+    pub(super) label: Handle<Label>,
+}
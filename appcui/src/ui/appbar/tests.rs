@@ -782,6 +782,84 @@ fn check_with_keys() {
     a.run();
 }
 
+#[test]
+fn check_with_key_map() {
+    #[Window(events : AppBarEvents+MenuEvents, commands  : A, internal: true)]
+    struct MyWindow {
+        h_file: Handle<MenuButton>,
+        h_edit: Handle<MenuButton>,
+        lb: Handle<Label>,
+    }
+    impl MyWindow {
+        fn new() -> Self {
+            let mut w = Self {
+                base: window!("Test,a:c,w:40,h:8"),
+                h_file: Handle::None,
+                h_edit: Handle::None,
+                lb: Handle::None,
+            };
+            w.lb = w.add(label!("None,a:c,w:30,h:1"));
+            w.h_file = w.appbar().add(MenuButton::new(
+                "&File",
+                menu!("class: MyWindow, items=[{New,cmd:A},{&Save,cmd:A},{&Open,cmd:A}]"),
+                0,
+                Side::Left,
+            ));
+            w.h_edit = w.appbar().add(MenuButton::new(
+                "&Edit",
+                menu!("class: MyWindow, items=[{&Copy,cmd:A},{&Paste,cmd:A},{&Cut,cmd:A}]"),
+                0,
+                Side::Left,
+            ));
+            w
+        }
+    }
+    impl MenuEvents for MyWindow {
+        fn on_command(&mut self, menu: Handle<Menu>, item: Handle<menu::Command>, _: mywindow::Commands) {
+            if let Some(i) = self.menuitem(menu, item) {
+                let s = String::from(i.caption());
+                let h = self.lb;
+                if let Some(l) = self.control_mut(h) {
+                    l.set_caption(&s);
+                }
+            }
+        }
+    }
+    impl AppBarEvents for MyWindow {
+        fn on_update(&self, appbar: &mut AppBar) {
+            appbar.show(self.h_file);
+            appbar.show(self.h_edit);
+        }
+    }
+
+    let script = "
+            Paint.Enable(false)
+            Paint('1. Initial state')
+            CheckHash(0x3742EBF85776F665)
+            Key.Pressed(Alt+F)
+            Paint('2. File menu opened (Alt+F is a menu hotkey, not the start of a chord)')
+            CheckHash(0x1B0ACC1869D7362D)
+            Key.Pressed(J)
+            Paint('3. J moves down')
+            CheckHash(0xA56C70BFF2BE53E5)
+            Key.Pressed(Down)
+            Paint('4. Down no longer moves')
+            CheckHash(0xA56C70BFF2BE53E5)
+            Key.Pressed(Ctrl+L)
+            Paint('5. Ctrl+L opens the Edit menu')
+            CheckHash(0xB944FD5579781708)
+            Key.Pressed(J,2)
+            Key.Pressed(Enter)
+            Paint('6. Paste was selected (the Edit menu is opened without a current item)')
+            CheckHash(0x247440A915256103)
+        ";
+    let mut km = KeyMap::new();
+    km.load("menu.move_down = J\nappbar.next_menu = Ctrl+L\nwindow.close = Alt+F X").unwrap();
+    let mut a = App::debug(60, 20, script).app_bar().key_map(km).build().unwrap();
+    a.add_window(MyWindow::new());
+    a.run();
+}
+
 #[test]
 fn check_recursive_shortcuts() {
     #[Window(events : MenuEvents+AppBarEvents, commands : A, internal: true)]
@@ -881,7 +959,8 @@ fn check_recursive_shortcuts() {
     }
 
     let script = "
-            Paint.Enable(false)
+            Paint.Enable(true)
+        Error.Disable(true)
             Paint('Initial State')
             CheckHash(0x91b83be85febb5c)
             Paint('State_3')
@@ -991,7 +1070,8 @@ fn check_side_parameter() {
         }
     }
     let script = "
-        Paint.Enable(false)
+        Paint.Enable(true)
+        Error.Disable(true)
         Paint('1. initial order - File,Edit on left, Help or Right')
         CheckHash(0x57E37E1F8A5C816A)
         Mouse.Move(59,0)
@@ -2382,3 +2462,67 @@ fn check_switchbutton_hotkey() {
     ";
     App::debug(60, 10, script).desktop(MyDesktop::new()).app_bar().build().unwrap().run();
 }
+
+#[test]
+fn check_progress_indicator() {
+    use crate::system::RuntimeManager;
+    use crate::ui::appbar::ProgressIndicator;
+    use std::sync::{Arc, Mutex};
+
+    struct Indicator(Mutex<Option<String>>);
+    impl ProgressIndicator for Indicator {
+        fn caption(&self) -> Option<String> {
+            self.0.lock().unwrap().clone()
+        }
+    }
+
+    #[Window(events = ButtonEvents, internal: true)]
+    struct MyWin {
+        indicator: Option<Arc<Indicator>>,
+        b_update: Handle<Button>,
+        b_remove: Handle<Button>,
+    }
+    impl MyWin {
+        fn new() -> Self {
+            let mut w = MyWin {
+                base: window!("Test,a:c,w:40,h:8"),
+                indicator: Some(Arc::new(Indicator(Mutex::new(Some(" Copy: 10% ".to_string()))))),
+                b_update: Handle::None,
+                b_remove: Handle::None,
+            };
+            w.b_update = w.add(button!("&Update,x:1,y:1,w:15"));
+            w.b_remove = w.add(button!("&Remove,x:20,y:1,w:15"));
+            let indicator = Arc::downgrade(w.indicator.as_ref().unwrap());
+            RuntimeManager::get().get_appbar().add_progress_indicator(indicator);
+            w
+        }
+    }
+    impl ButtonEvents for MyWin {
+        fn on_pressed(&mut self, handle: Handle<Button>) -> EventProcessStatus {
+            if handle == self.b_update {
+                if let Some(i) = &self.indicator {
+                    *i.0.lock().unwrap() = Some(" Copy: done ".to_string());
+                }
+            } else {
+                self.indicator = None;
+            }
+            // the app bar is refreshed when a task notifies its progress
+            RuntimeManager::get().request_update_command_and_app_bars();
+            EventProcessStatus::Processed
+        }
+    }
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Indicator (Copy: 10%) on the right side of the app bar')
+        CheckHash(0x3DA899517A1C950)
+        Key.Pressed(Alt+U)
+        Paint('2. Indicator shows Copy: done')
+        CheckHash(0x996189DDF7501108)
+        Key.Pressed(Alt+R)
+        Paint('3. Indicator removed')
+        CheckHash(0x40C4E3C70613DAF7)
+    ";
+    let mut a = App::debug(60, 10, script).app_bar().build().unwrap();
+    a.add_window(MyWin::new());
+    a.run();
+}
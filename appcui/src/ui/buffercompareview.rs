@@ -0,0 +1,14 @@
+//! A UI control that compares two buffers side by side.
+//!
+//! [`BufferCompareView`] hosts two synchronized [`BufferView`](crate::ui::BufferView) panes and highlights
+//! the bytes that differ between them (changed bytes as well as small insertions and deletions).
+
+mod buffercompareview;
+mod difference;
+#[cfg(test)]
+mod tests;
+
+pub use self::buffercompareview::BufferCompareView;
+pub use self::difference::Difference;
+pub use self::difference::DifferenceKind;
+pub use self::difference::DifferenceSummary;
@@ -0,0 +1,285 @@
+use super::difference::{self, Difference, DifferenceKind, DifferenceSummary, Reader};
+use crate::prelude::*;
+use crate::ui::bufferview::{BufferAccess, Codepage, ColumnsCount, DataRepresentationFormat, Endian, Flags, OffsetFormat};
+use std::ops::Range;
+
+const DEFAULT_MAX_SHIFT: u64 = 64;
+
+#[CustomControl(overwrite = [OnPaint, OnKeyPressed, OnResize], internal = true)]
+/// A control that displays two buffers side by side (in two synchronized [`BufferView`] panes) and highlights
+/// the bytes that differ between them.
+///
+/// Small insertions and deletions are detected, so that the content that follows them is still aligned.
+/// Scrolling, cursor movement and selection are mirrored from one pane to the other one.
+pub struct BufferCompareView<T>
+where
+    T: BufferAccess + 'static,
+{
+    left: Handle<BufferView<T>>,
+    right: Handle<BufferView<T>>,
+    differences: Vec<Difference>,
+    summary: DifferenceSummary,
+    current: Option<usize>,
+    max_shift: u64,
+}
+
+impl<T: BufferAccess + 'static> BufferCompareView<T> {
+    /// Creates a new compare view over the `left` and `right` buffers at the given `layout`.
+    ///
+    /// `flags` are applied to both [`BufferView`] panes (for example [`Flags::ShowAddress`] or [`Flags::ReadOnly`]).
+    /// The differences are computed right away.
+    pub fn new(left: T, right: T, layout: Layout, flags: Flags) -> Self {
+        let mut obj = Self {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Visible | StatusFlags::Enabled | StatusFlags::AcceptInput),
+            left: Handle::None,
+            right: Handle::None,
+            differences: Vec::new(),
+            summary: DifferenceSummary::default(),
+            current: None,
+            max_shift: DEFAULT_MAX_SHIFT,
+        };
+        obj.set_size_bounds(5, 2, u16::MAX, u16::MAX);
+        obj.left = obj.add_child(Self::new_pane(left, flags));
+        obj.right = obj.add_child(Self::new_pane(right, flags));
+        obj.compute_differences();
+        obj
+    }
+    fn new_pane(buffer: T, flags: Flags) -> BufferView<T> {
+        let mut bv = BufferView::with_buffer(buffer, LayoutBuilder::new().x(0).y(0).width(1).height(1).build(), flags);
+        bv.set_compare_pane();
+        bv
+    }
+    /// Returns the handle of the left [`BufferView`] pane.
+    #[inline(always)]
+    pub fn left(&self) -> Handle<BufferView<T>> {
+        self.left
+    }
+    /// Returns the handle of the right [`BufferView`] pane.
+    #[inline(always)]
+    pub fn right(&self) -> Handle<BufferView<T>> {
+        self.right
+    }
+    /// Sets the maximum size (in bytes) of an insertion or deletion that is detected when the buffers are compared (default 64).
+    /// Larger shifts are reported as changed bytes. The differences are recomputed.
+    pub fn set_max_shift(&mut self, max_shift: u64) {
+        self.max_shift = max_shift;
+        self.compute_differences();
+    }
+    /// Compares the two buffers again (for example after one of them was modified).
+    pub fn compute_differences(&mut self) {
+        let rm = RuntimeManager::get();
+        let left_len = rm.get_control(self.left).map(|bv| bv.bytes_count()).unwrap_or(0);
+        let right_len = rm.get_control(self.right).map(|bv| bv.bytes_count()).unwrap_or(0);
+        let (hl, hr) = (self.left, self.right);
+        let left = Reader::new(left_len, |pos, output: &mut [u8]| {
+            RuntimeManager::get()
+                .get_control_mut(hl)
+                .map(|bv| bv.read_buffer(pos, output))
+                .unwrap_or(0)
+        });
+        let right = Reader::new(right_len, |pos, output: &mut [u8]| {
+            RuntimeManager::get()
+                .get_control_mut(hr)
+                .map(|bv| bv.read_buffer(pos, output))
+                .unwrap_or(0)
+        });
+        self.differences = difference::compute(left, right, self.max_shift);
+        self.summary = DifferenceSummary::new(&self.differences);
+        self.current = None;
+        for (h, is_left) in [(self.left, true), (self.right, false)] {
+            let ranges: Vec<(Range<u64>, DifferenceKind)> = self
+                .differences
+                .iter()
+                .map(|d| (d.range(is_left).clone(), d.kind))
+                .filter(|(r, _)| !r.is_empty())
+                .collect();
+            if let Some(bv) = RuntimeManager::get().get_control_mut(h) {
+                bv.set_differences(ranges);
+            }
+        }
+        let state = RuntimeManager::get().get_control(self.left).map(|bv| bv.view_state());
+        if let Some(state) = state {
+            self.sync_from(true, state);
+        }
+    }
+    /// Returns the differences between the two buffers (sorted by their offsets).
+    #[inline(always)]
+    pub fn differences(&self) -> &[Difference] {
+        &self.differences
+    }
+    /// Returns the number of differences and the number of changed, inserted and deleted bytes.
+    #[inline(always)]
+    pub fn summary(&self) -> DifferenceSummary {
+        self.summary
+    }
+    /// Returns the index of the difference under the cursor (if any).
+    #[inline(always)]
+    pub fn current_difference(&self) -> Option<usize> {
+        self.current
+    }
+    /// Moves the cursor (of the focused pane) to the next difference and selects it.
+    /// Returns `false` if there is no difference after the cursor.
+    pub fn goto_next_difference(&mut self) -> bool {
+        let (is_left, pos) = self.active_pane_position();
+        let next = match difference::index_at(&self.differences, pos, is_left) {
+            Some(index) => index + 1,
+            None => self.differences.partition_point(|d| d.range(is_left).start <= pos),
+        };
+        self.goto_difference(next)
+    }
+    /// Moves the cursor (of the focused pane) to the previous difference and selects it.
+    /// Returns `false` if there is no difference before the cursor.
+    pub fn goto_previous_difference(&mut self) -> bool {
+        let (is_left, pos) = self.active_pane_position();
+        let previous = match difference::index_at(&self.differences, pos, is_left) {
+            Some(index) => index.checked_sub(1),
+            None => self.differences.partition_point(|d| d.range(is_left).start <= pos).checked_sub(1),
+        };
+        previous.is_some_and(|index| self.goto_difference(index))
+    }
+    /// Moves the cursor to the difference with the specified index and selects its bytes.
+    /// Returns `false` if the index is invalid.
+    pub fn goto_difference(&mut self, index: usize) -> bool {
+        let Some(d) = self.differences.get(index) else {
+            return false;
+        };
+        let (is_left, _) = self.active_pane_position();
+        let r = d.range(is_left).clone();
+        let h = if is_left { self.left } else { self.right };
+        let selection = if r.is_empty() { None } else { Some((r.start, r.end)) };
+        let state = RuntimeManager::get().get_control_mut(h).map(|bv| bv.move_to(r.start, selection));
+        if let Some(state) = state {
+            self.sync_from(is_left, state);
+        }
+        self.current = Some(index);
+        true
+    }
+    /// Sets the data representation format used by both panes.
+    pub fn set_data_representation_format(&mut self, format: DataRepresentationFormat) {
+        self.update_panes(|bv| bv.set_data_representation_format(format));
+    }
+    /// Sets the byte order used by both panes for multi-byte formats.
+    pub fn set_endian(&mut self, endian: Endian) {
+        self.update_panes(|bv| bv.set_endian(endian));
+    }
+    /// Sets the code page used by both panes to render characters.
+    pub fn set_codepage(&mut self, cp: Codepage) {
+        self.update_panes(|bv| bv.set_codepage(cp));
+    }
+    /// Sets the number of data columns of both panes.
+    pub fn set_columns_count(&mut self, count: ColumnsCount) {
+        self.update_panes(|bv| bv.set_columns_count(count));
+    }
+    /// Sets the format used by both panes to display offsets in the address column.
+    pub fn set_offset_format(&mut self, format: OffsetFormat) {
+        self.update_panes(|bv| bv.set_offset_format(format));
+    }
+
+    fn update_panes<F: Fn(&mut BufferView<T>)>(&mut self, f: F) {
+        for h in [self.left, self.right] {
+            if let Some(bv) = RuntimeManager::get().get_control_mut(h) {
+                f(bv);
+            }
+        }
+        let state = RuntimeManager::get().get_control(self.left).map(|bv| bv.view_state());
+        if let Some(state) = state {
+            self.sync_from(true, state);
+        }
+    }
+    // returns the pane that has the focus (left if none of them has it) and its cursor position
+    fn active_pane_position(&self) -> (bool, u64) {
+        let rm = RuntimeManager::get();
+        if let Some(bv) = rm.get_control(self.right) {
+            if bv.has_focus() {
+                return (false, bv.current_pos());
+            }
+        }
+        (true, rm.get_control(self.left).map(|bv| bv.current_pos()).unwrap_or(0))
+    }
+    // mirrors the cursor, scroll position and selection of one pane into the other one
+    fn sync_from(&mut self, from_left: bool, state: (u64, u64, Option<(u64, u64)>)) {
+        let (pos, start_view, selection) = state;
+        let map = |p: u64| difference::map_position(&self.differences, p, from_left);
+        let target_pos = map(pos);
+        // the cursor is kept on the same row in both panes
+        let target_start_view = if start_view <= pos {
+            target_pos.saturating_sub(pos - start_view)
+        } else {
+            target_pos.saturating_add(start_view - pos)
+        };
+        let target_selection = selection.map(|(start, end)| (map(start), map(end - 1) + 1));
+        self.current = difference::index_at(&self.differences, pos, from_left);
+        let target = if from_left { self.right } else { self.left };
+        if let Some(bv) = RuntimeManager::get().get_control_mut(target) {
+            bv.set_view_state(target_pos, target_start_view, target_selection);
+        }
+    }
+    pub(crate) fn sync_panes(handle: Handle<BufferCompareView<T>>, source: Handle<BufferView<T>>, state: (u64, u64, Option<(u64, u64)>)) {
+        if let Some(cv) = RuntimeManager::get().get_control_mut(handle) {
+            let from_left = source == cv.left;
+            cv.sync_from(from_left, state);
+        }
+    }
+    fn update_pane_sizes(&mut self, size: Size) {
+        let height = size.height.saturating_sub(1) as u16;
+        let left_width = (size.width.saturating_sub(1) / 2) as u16;
+        let right_width = (size.width as u16).saturating_sub(left_width + 1);
+        let rm = RuntimeManager::get();
+        if let Some(bv) = rm.get_control_mut(self.left) {
+            bv.set_position(0, 0);
+            bv.set_size(left_width, height);
+        }
+        if let Some(bv) = rm.get_control_mut(self.right) {
+            bv.set_position(left_width as i32 + 1, 0);
+            bv.set_size(right_width, height);
+        }
+    }
+}
+
+impl<T: BufferAccess + 'static> OnPaint for BufferCompareView<T> {
+    fn on_paint(&self, surface: &mut Surface, theme: &Theme) {
+        let size = self.size();
+        let x = (size.width.saturating_sub(1) / 2) as i32;
+        let bottom = size.height as i32 - 1;
+        let line_attr = if self.is_enabled() { theme.lines.normal } else { theme.lines.inactive };
+        surface.draw_vertical_line(x, 0, bottom - 1, LineType::Single, line_attr);
+        let attr = if self.is_enabled() { theme.text.normal } else { theme.text.inactive };
+        surface.fill_horizontal_line(0, bottom, size.width as i32 - 1, Character::with_attributes(' ', attr));
+        let text = if self.summary.differences == 0 {
+            "No differences".to_string()
+        } else {
+            format!(
+                "Differences: {}  Changed: {}  Inserted: {}  Deleted: {}",
+                self.summary.differences, self.summary.changed, self.summary.inserted, self.summary.deleted
+            )
+        };
+        surface.write_string(1, bottom, &text, attr, false);
+        if let Some(index) = self.current {
+            let text = format!("[{}/{}]", index + 1, self.summary.differences);
+            let attr = if self.is_enabled() { theme.text.highlighted } else { theme.text.inactive };
+            surface.write_string(size.width as i32 - 1 - text.len() as i32, bottom, &text, attr, false);
+        }
+    }
+}
+
+impl<T: BufferAccess + 'static> OnKeyPressed for BufferCompareView<T> {
+    fn on_key_pressed(&mut self, key: Key, _character: char) -> EventProcessStatus {
+        match key.value() {
+            key!("F7") => {
+                self.goto_next_difference();
+                EventProcessStatus::Processed
+            }
+            key!("Shift+F7") => {
+                self.goto_previous_difference();
+                EventProcessStatus::Processed
+            }
+            _ => EventProcessStatus::Ignored,
+        }
+    }
+}
+
+impl<T: BufferAccess + 'static> OnResize for BufferCompareView<T> {
+    fn on_resize(&mut self, _old_size: Size, new_size: Size) {
+        self.update_pane_sizes(new_size);
+    }
+}
@@ -0,0 +1,235 @@
+use std::ops::Range;
+
+// number of consecutive bytes that have to match for the two buffers to be considered aligned again
+const SYNC_LEN: u64 = 8;
+const WINDOW_SIZE: usize = 0x10000;
+// when the window is moved, it also keeps some of the bytes before the requested position
+const WINDOW_BACKTRACK: u64 = 0x400;
+
+/// The type of a [`Difference`] between the left and the right buffer of a [`BufferCompareView`](super::BufferCompareView).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DifferenceKind {
+    /// Bytes that exist in both buffers but have different values (the two ranges have the same size).
+    Changed,
+    /// Bytes that exist only in the right buffer (the left range is empty).
+    Inserted,
+    /// Bytes that exist only in the left buffer (the right range is empty).
+    Deleted,
+}
+
+/// A range of bytes that differs between the left and the right buffer of a [`BufferCompareView`](super::BufferCompareView).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Difference {
+    pub kind: DifferenceKind,
+    /// The bytes from the left buffer.
+    pub left: Range<u64>,
+    /// The bytes from the right buffer.
+    pub right: Range<u64>,
+}
+
+impl Difference {
+    #[inline(always)]
+    pub(super) fn range(&self, left: bool) -> &Range<u64> {
+        if left {
+            &self.left
+        } else {
+            &self.right
+        }
+    }
+    pub(super) fn contains(&self, pos: u64, left: bool) -> bool {
+        let r = self.range(left);
+        (r.start <= pos && pos < r.end) || (r.is_empty() && r.start == pos)
+    }
+}
+
+/// Statistics about the differences between two buffers.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DifferenceSummary {
+    /// Number of difference ranges.
+    pub differences: usize,
+    /// Number of bytes that have different values.
+    pub changed: u64,
+    /// Number of bytes that exist only in the right buffer.
+    pub inserted: u64,
+    /// Number of bytes that exist only in the left buffer.
+    pub deleted: u64,
+}
+
+impl DifferenceSummary {
+    pub(super) fn new(differences: &[Difference]) -> Self {
+        let mut summary = DifferenceSummary {
+            differences: differences.len(),
+            ..Default::default()
+        };
+        for d in differences {
+            match d.kind {
+                DifferenceKind::Changed => summary.changed += d.left.end - d.left.start,
+                DifferenceKind::Inserted => summary.inserted += d.right.end - d.right.start,
+                DifferenceKind::Deleted => summary.deleted += d.left.end - d.left.start,
+            }
+        }
+        summary
+    }
+}
+
+// a window over a buffer (the buffer is never read entirely in memory)
+pub(super) struct Reader<F: FnMut(u64, &mut [u8]) -> u64> {
+    read: F,
+    len: u64,
+    start: u64,
+    data: Vec<u8>,
+}
+
+impl<F: FnMut(u64, &mut [u8]) -> u64> Reader<F> {
+    pub(super) fn new(len: u64, read: F) -> Self {
+        Self {
+            read,
+            len,
+            start: 0,
+            data: Vec::new(),
+        }
+    }
+    fn get(&mut self, pos: u64) -> Option<u8> {
+        if pos >= self.len {
+            return None;
+        }
+        if pos < self.start || pos >= self.start + self.data.len() as u64 {
+            self.start = pos.saturating_sub(WINDOW_BACKTRACK);
+            let count = (self.len - self.start).min(WINDOW_SIZE as u64) as usize;
+            self.data.resize(count, 0);
+            let read = (self.read)(self.start, &mut self.data) as usize;
+            self.data.truncate(read);
+        }
+        self.data.get((pos - self.start) as usize).copied()
+    }
+}
+
+struct Differ<L, R>
+where
+    L: FnMut(u64, &mut [u8]) -> u64,
+    R: FnMut(u64, &mut [u8]) -> u64,
+{
+    left: Reader<L>,
+    right: Reader<R>,
+    result: Vec<Difference>,
+}
+
+impl<L, R> Differ<L, R>
+where
+    L: FnMut(u64, &mut [u8]) -> u64,
+    R: FnMut(u64, &mut [u8]) -> u64,
+{
+    #[inline(always)]
+    fn same(&mut self, i: u64, j: u64) -> bool {
+        let a = self.left.get(i);
+        a.is_some() && a == self.right.get(j)
+    }
+    // checks if the buffers are aligned at (i, j) - the next SYNC_LEN bytes are identical or both buffers end with identical bytes
+    fn aligned(&mut self, i: u64, j: u64) -> bool {
+        let n = SYNC_LEN.min(self.left.len.saturating_sub(i)).min(self.right.len.saturating_sub(j));
+        if n < SYNC_LEN && (i + n != self.left.len || j + n != self.right.len) {
+            return false;
+        }
+        (0..n).all(|k| self.same(i + k, j + k))
+    }
+    fn add(&mut self, kind: DifferenceKind, left: Range<u64>, right: Range<u64>) {
+        if let Some(last) = self.result.last_mut() {
+            if last.kind == kind && last.left.end == left.start && last.right.end == right.start {
+                last.left.end = left.end;
+                last.right.end = right.end;
+                return;
+            }
+        }
+        self.result.push(Difference { kind, left, right });
+    }
+    fn run(&mut self, max_shift: u64) {
+        let (mut i, mut j) = (0u64, 0u64);
+        // number of consecutive changed bytes (the alignment is searched only at the start of a changed block)
+        let mut changed = 0u64;
+        while i < self.left.len && j < self.right.len {
+            if self.same(i, j) {
+                i += 1;
+                j += 1;
+                changed = 0;
+                continue;
+            }
+            if changed < max_shift && !self.aligned(i + 1, j + 1) {
+                let mut shift = None;
+                for k in 1..=max_shift {
+                    if j + k <= self.right.len && self.aligned(i, j + k) {
+                        shift = Some((DifferenceKind::Inserted, k));
+                        break;
+                    }
+                    if i + k <= self.left.len && self.aligned(i + k, j) {
+                        shift = Some((DifferenceKind::Deleted, k));
+                        break;
+                    }
+                }
+                match shift {
+                    Some((DifferenceKind::Inserted, k)) => {
+                        self.add(DifferenceKind::Inserted, i..i, j..j + k);
+                        j += k;
+                        changed = 0;
+                        continue;
+                    }
+                    Some((_, k)) => {
+                        self.add(DifferenceKind::Deleted, i..i + k, j..j);
+                        i += k;
+                        changed = 0;
+                        continue;
+                    }
+                    None => {}
+                }
+            }
+            self.add(DifferenceKind::Changed, i..i + 1, j..j + 1);
+            i += 1;
+            j += 1;
+            changed += 1;
+        }
+        if i < self.left.len {
+            self.add(DifferenceKind::Deleted, i..self.left.len, j..j);
+        }
+        if j < self.right.len {
+            self.add(DifferenceKind::Inserted, i..i, j..self.right.len);
+        }
+    }
+}
+
+// computes the differences between two buffers; insertions and deletions of up to `max_shift` bytes are detected
+pub(super) fn compute<L, R>(left: Reader<L>, right: Reader<R>, max_shift: u64) -> Vec<Difference>
+where
+    L: FnMut(u64, &mut [u8]) -> u64,
+    R: FnMut(u64, &mut [u8]) -> u64,
+{
+    let mut differ = Differ {
+        left,
+        right,
+        result: Vec::new(),
+    };
+    differ.run(max_shift);
+    differ.result
+}
+
+// translates a position from one buffer to the other one (left to right if `from_left` is true)
+pub(super) fn map_position(differences: &[Difference], pos: u64, from_left: bool) -> u64 {
+    let idx = differences.partition_point(|d| d.range(from_left).start <= pos);
+    if idx == 0 {
+        return pos;
+    }
+    let d = &differences[idx - 1];
+    let src = d.range(from_left);
+    let dst = d.range(!from_left);
+    if pos < src.end {
+        dst.start + (pos - src.start).min((dst.end - dst.start).saturating_sub(1))
+    } else {
+        dst.end + (pos - src.end)
+    }
+}
+
+// returns the index of the difference that contains `pos` (if any)
+pub(super) fn index_at(differences: &[Difference], pos: u64, left: bool) -> Option<usize> {
+    let first = differences.partition_point(|d| d.range(left).end < pos);
+    (first..differences.len())
+        .take_while(|i| differences[*i].range(left).start <= pos)
+        .find(|i| differences[*i].contains(pos, left))
+}
@@ -0,0 +1,212 @@
+use super::difference::{compute, index_at, map_position, Reader};
+use super::{BufferCompareView, Difference, DifferenceKind, DifferenceSummary};
+use crate::prelude::*;
+use crate::ui::bufferview::{ColumnsCount, DataRepresentationFormat, Flags, HexFormat, OffsetFormat};
+
+fn diff(left: &[u8], right: &[u8], max_shift: u64) -> Vec<Difference> {
+    let read = |data: &[u8], pos: u64, output: &mut [u8]| {
+        let data = &data[pos as usize..];
+        let count = data.len().min(output.len());
+        output[..count].copy_from_slice(&data[..count]);
+        count as u64
+    };
+    compute(
+        Reader::new(left.len() as u64, |pos, output: &mut [u8]| read(left, pos, output)),
+        Reader::new(right.len() as u64, |pos, output: &mut [u8]| read(right, pos, output)),
+        max_shift,
+    )
+}
+
+fn d(kind: DifferenceKind, left: std::ops::Range<u64>, right: std::ops::Range<u64>) -> Difference {
+    Difference { kind, left, right }
+}
+
+#[test]
+fn check_identical_buffers() {
+    assert!(diff(b"0123456789", b"0123456789", 64).is_empty());
+    assert!(diff(b"", b"", 64).is_empty());
+}
+
+#[test]
+fn check_changed_bytes() {
+    let r = diff(b"0123456789ABCDEFGHIJ", b"0x2345yz89ABCDEFGHIJ", 64);
+    assert_eq!(
+        r,
+        vec![
+            d(DifferenceKind::Changed, 1..2, 1..2),
+            d(DifferenceKind::Changed, 6..8, 6..8),
+        ]
+    );
+    assert_eq!(
+        DifferenceSummary::new(&r),
+        DifferenceSummary {
+            differences: 2,
+            changed: 3,
+            inserted: 0,
+            deleted: 0
+        }
+    );
+}
+
+#[test]
+fn check_insert_and_delete_alignment() {
+    // 3 bytes inserted and 1 byte deleted - the rest of the buffers is aligned
+    let left = b"HEADER--0123456789abcdefghij--TAIL-OF-THE-BUFFER";
+    let right = b"HEADER--012XYZ3456789abcdefhij--TAIL-OF-THE-BUFFER";
+    let r = diff(left, right, 64);
+    assert_eq!(
+        r,
+        vec![
+            d(DifferenceKind::Inserted, 11..11, 11..14),
+            d(DifferenceKind::Deleted, 24..25, 27..27),
+        ]
+    );
+    let summary = DifferenceSummary::new(&r);
+    assert_eq!((summary.inserted, summary.deleted, summary.changed), (3, 1, 0));
+    // shifts larger than the limit are reported as changed bytes
+    let r = diff(left, right, 2);
+    assert!(r.iter().all(|d| d.kind != DifferenceKind::Inserted || d.left.start == left.len() as u64));
+    assert_eq!(r[0].kind, DifferenceKind::Changed);
+}
+
+#[test]
+fn check_different_lengths() {
+    assert_eq!(diff(b"0123456789", b"0123", 64), vec![d(DifferenceKind::Deleted, 4..10, 4..4)]);
+    assert_eq!(diff(b"0123", b"0123456789", 64), vec![d(DifferenceKind::Inserted, 4..4, 4..10)]);
+    assert_eq!(diff(b"", b"01", 64), vec![d(DifferenceKind::Inserted, 0..0, 0..2)]);
+}
+
+#[test]
+fn check_large_buffers() {
+    // differences that are far apart (the buffers are read in windows)
+    let left: Vec<u8> = (0..300_000u32).map(|i| (i % 251) as u8).collect();
+    let mut right = left.clone();
+    right[100_000] ^= 0xFF;
+    right.drain(200_000..200_010);
+    let r = diff(&left, &right, 64);
+    assert_eq!(
+        r,
+        vec![
+            d(DifferenceKind::Changed, 100_000..100_001, 100_000..100_001),
+            d(DifferenceKind::Deleted, 200_000..200_010, 200_000..200_000),
+        ]
+    );
+}
+
+#[test]
+fn check_position_mapping() {
+    let differences = vec![
+        d(DifferenceKind::Changed, 2..4, 2..4),
+        d(DifferenceKind::Inserted, 10..10, 10..13),
+        d(DifferenceKind::Deleted, 20..25, 23..23),
+    ];
+    assert_eq!(map_position(&differences, 0, true), 0);
+    assert_eq!(map_position(&differences, 3, true), 3);
+    assert_eq!(map_position(&differences, 10, true), 13);
+    assert_eq!(map_position(&differences, 15, true), 18);
+    assert_eq!(map_position(&differences, 22, true), 23);
+    assert_eq!(map_position(&differences, 30, true), 28);
+    assert_eq!(map_position(&differences, 11, false), 10);
+    assert_eq!(map_position(&differences, 18, false), 15);
+    assert_eq!(map_position(&differences, 28, false), 30);
+    assert_eq!(index_at(&differences, 3, true), Some(0));
+    assert_eq!(index_at(&differences, 10, true), Some(1));
+    assert_eq!(index_at(&differences, 12, false), Some(1));
+    assert_eq!(index_at(&differences, 23, false), Some(2));
+    assert_eq!(index_at(&differences, 5, true), None);
+}
+
+fn compare_data() -> (Vec<u8>, Vec<u8>) {
+    let left: Vec<u8> = (0..96u8).collect();
+    let mut right = left.clone();
+    right[5] = 0xFF;
+    right.splice(20..20, [0xAA, 0xBB, 0xCC]);
+    right[60] = 0xEE;
+    (left, right)
+}
+
+#[test]
+fn check_navigation_methods() {
+    let _a = App::debug(60, 15, "Paint.Enable(false)").build().unwrap();
+    let (left, right) = compare_data();
+    let mut cv = BufferCompareView::new(left, right, layout!("d:f"), Flags::None);
+    assert_eq!(
+        cv.differences(),
+        &[
+            d(DifferenceKind::Changed, 5..6, 5..6),
+            d(DifferenceKind::Inserted, 20..20, 20..23),
+            d(DifferenceKind::Changed, 57..58, 60..61),
+        ]
+    );
+    assert_eq!(cv.summary().changed, 2);
+    assert_eq!(cv.summary().inserted, 3);
+    assert!(cv.goto_next_difference());
+    assert_eq!(cv.current_difference(), Some(0));
+    assert!(cv.goto_next_difference());
+    assert!(cv.goto_next_difference());
+    assert_eq!(cv.current_difference(), Some(2));
+    assert!(!cv.goto_next_difference());
+    assert!(cv.goto_previous_difference());
+    assert_eq!(cv.current_difference(), Some(1));
+    assert!(!cv.goto_difference(3));
+}
+
+#[test]
+fn check_linked_panes() {
+    #[Window(events = BufferViewEvents<Vec<u8>>, internal = true)]
+    struct MyWin {
+        cv: Handle<BufferCompareView<Vec<u8>>>,
+    }
+    impl MyWin {
+        fn new() -> Self {
+            let mut w = Self {
+                base: window!("Test,d:f"),
+                cv: Handle::None,
+            };
+            let (left, right) = compare_data();
+            let mut cv = BufferCompareView::new(left, right, layout!("d:f"), Flags::ShowAddress);
+            cv.set_columns_count(ColumnsCount::Fixed(4));
+            cv.set_data_representation_format(DataRepresentationFormat::Hex(HexFormat::Byte));
+            cv.set_offset_format(OffsetFormat::Hex);
+            w.cv = w.add(cv);
+            w
+        }
+    }
+    impl BufferViewEvents<Vec<u8>> for MyWin {
+        fn on_current_pos_changed(&mut self, _handle: Handle<BufferView<Vec<u8>>>) -> EventProcessStatus {
+            let h = self.cv;
+            let Some((l, r, current)) = self.control(h).map(|cv| (cv.left(), cv.right(), cv.current_difference())) else {
+                return EventProcessStatus::Ignored;
+            };
+            let lp = self.control(l).map(|bv| bv.current_pos()).unwrap_or(0);
+            let rp = self.control(r).map(|bv| bv.current_pos()).unwrap_or(0);
+            let title = format!("L:{lp} R:{rp} D:{current:?}");
+            self.set_title(&title);
+            EventProcessStatus::Processed
+        }
+    }
+
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Initial state (focus on right pane)')
+        CheckHash(0x4B9DD0D18F5932)
+        Key.Pressed(F7)
+        Paint('2. First difference selected in both panes (05)')
+        CheckHash(0x3EE7E48658928474)
+        Key.Pressed(F7)
+        Paint('3. Inserted bytes AA BB CC selected on the right, cursor on 14 on the left')
+        CheckHash(0x148947F35EB5D2F8)
+        Key.Pressed(Down,2)
+        Paint('4. L:25 R:28 D:None')
+        CheckHash(0x72B00393DF2F623F)
+        Key.Pressed(Shift+F7)
+        Paint('5. Back to inserted bytes')
+        CheckHash(0xA70301DA6AF054E5)
+        Key.Pressed(PageDown)
+        Paint('6. Both panes scrolled (L:65 R:68, aligned after the insertion)')
+        CheckHash(0xCA49B0E616265B39)
+    ";
+    let mut a = App::debug(80, 16, script).build().unwrap();
+    a.add_window(MyWin::new());
+    a.run();
+}
@@ -3,6 +3,8 @@
 //! [`BufferView`] renders buffer data in multiple representations (hex, decimal, binary,
 //! integers, floats, characters) with optional address and interval-name columns, string
 //! decoding, selection, search, and in-place editing when the backing buffer allows it.
+//! Binary structures can be described with a [`Template`] and overlaid on the buffer content, and the bytes at
+//! the cursor can be decoded (and edited) in many interpretations at once with a [`DataInspector`].
 
 pub mod events;
 mod bufferview;
@@ -14,6 +16,13 @@ mod codepage;
 mod selection;
 mod search_parser;
 mod buffer;
+mod journal;
+mod file_buffer;
+mod template;
+mod template_parser;
+mod template_field;
+mod inspector_type;
+mod data_inspector;
 #[cfg(test)]
 mod tests;
 
@@ -22,6 +31,8 @@ use self::interval::IntervalSet;
 use self::interval::Segment;
 use self::selection::Selection;
 use self::buffer::Buffer;
+use self::journal::Edit;
+use self::journal::Journal;
 
 pub use self::bufferview::BufferView;
 pub use self::initialization_flags::Flags;
@@ -36,3 +47,13 @@ pub use self::format::OffsetFormat;
 pub use self::interval::Interval;
 pub use self::codepage::Codepage;
 pub use self::buffer::BufferAccess;
+pub use self::file_buffer::FileBuffer;
+pub use self::template::Template;
+pub use self::template::FieldType;
+pub use self::template::Count;
+pub use self::template::Condition;
+pub use self::template::Comparison;
+pub use self::template::TemplateError;
+pub use self::template_field::TemplateField;
+pub use self::inspector_type::InspectorType;
+pub use self::data_inspector::DataInspector;
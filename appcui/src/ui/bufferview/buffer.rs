@@ -16,6 +16,35 @@ pub trait BufferAccess : Default {
     /// Changes the buffer length to `new_size`, filling any newly added bytes with `fill_byte`.
     /// Returns `false` if resizing is not supported.
     fn resize(&mut self, new_size: u64, fill_byte: u8) -> bool;
+    /// Copies the bytes starting at `pos` into `output` and returns the number of bytes copied.
+    ///
+    /// Fewer bytes are copied if the end of the buffer is reached or a byte is not readable. The default
+    /// implementation reads the bytes one by one via [`Self::get`]; override it when the data can be read in blocks.
+    fn read(&mut self, pos: u64, output: &mut [u8]) -> usize {
+        let mut count = 0;
+        for (p, o) in (pos..).zip(output.iter_mut()) {
+            match self.get(p) {
+                Some(b) => *o = b,
+                None => break,
+            }
+            count += 1;
+        }
+        count
+    }
+    /// Returns whether the buffer implements [`Self::insert`] and [`Self::delete`] natively.
+    ///
+    /// If not, insertions and deletions are performed by resizing the buffer and moving the bytes that follow.
+    fn can_insert_delete(&self) -> bool {
+        false
+    }
+    /// Inserts `bytes` at `pos`. Only called if [`Self::can_insert_delete`] returns `true`.
+    fn insert(&mut self, _pos: u64, _bytes: &[u8]) -> bool {
+        false
+    }
+    /// Removes `count` bytes starting at `pos`. Only called if [`Self::can_insert_delete`] returns `true`.
+    fn delete(&mut self, _pos: u64, _count: u64) -> bool {
+        false
+    }
 }
 
 pub(super) struct Buffer<T: BufferAccess> {
@@ -59,18 +88,11 @@ impl<T: BufferAccess> Buffer<T> {
     }
     pub(super) fn read_bytes(&mut self, pos: u64, output: &mut [u8]) -> u64 {
         let len = self.len();
-        let mut i = 0;
-        let mut pos = pos;
-        while pos < len && i < output.len() {
-            if let Some(b) = self.data.get(pos) {
-                output[i] = b;
-                i += 1;
-                pos += 1;
-            } else {
-                break;
-            }
+        if pos >= len {
+            return 0;
         }
-        i as u64
+        let count = (len - pos).min(output.len() as u64) as usize;
+        self.data.read(pos, &mut output[..count]) as u64
     }
     pub(super) fn overwrite_bytes(&mut self, pos: u64, bytes: &[u8]) -> bool {
         if pos.saturating_add(bytes.len() as u64) > self.len() {
@@ -112,6 +134,9 @@ impl<T: BufferAccess> Buffer<T> {
             return true;
         }
         let count = count.min(len - pos);
+        if self.data.can_insert_delete() {
+            return self.data.delete(pos, count);
+        }
         let mut src = pos + count;
         let mut dst = pos;
         let mut temp = [0u8; 4096];
@@ -136,6 +161,9 @@ impl<T: BufferAccess> Buffer<T> {
         if bytes.is_empty() {
             return true;
         }
+        if self.data.can_insert_delete() {
+            return self.data.insert(pos, bytes);
+        }
         let k = bytes.len() as u64;
         if !self.data.resize(old_len + k, 0u8) {
             return false;
@@ -217,4 +245,13 @@ impl BufferAccess for Vec<u8> {
             false
         }
     }
+    fn read(&mut self, pos: u64, output: &mut [u8]) -> usize {
+        if pos >= self.len() as u64 {
+            return 0;
+        }
+        let data = &self[pos as usize..];
+        let count = data.len().min(output.len());
+        output[..count].copy_from_slice(&data[..count]);
+        count
+    }
 }
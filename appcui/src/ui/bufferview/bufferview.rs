@@ -2,16 +2,15 @@ use super::events::EventData;
 use super::format::*;
 use super::output_buffer::OutputBuffer;
 use super::*;
-use super::{Codepage, Interval, IntervalSet, Segment, Selection};
+use super::{Codepage, Interval, IntervalSet, Segment, Selection, Template, TemplateError, TemplateField};
 use crate::prelude::*;
+use crate::ui::buffercompareview::{BufferCompareView, DifferenceKind};
 use flat_string::FlatString;
+use std::ops::Range;
 
 const MAX_ADDRESS_WIDTH: u32 = 24;
 const MAX_INTERVAL_NAME_WIDTH: u32 = 64;
-#[cfg(target_endian = "little")]
-const NATIVE_ENDIAN: Endian = Endian::Little;
-#[cfg(target_endian = "big")]
-const NATIVE_ENDIAN: Endian = Endian::Big;
+const SEARCH_CHUNK_SIZE: usize = 0x10000;
 
 /// Identifies one of the two resizable columns (address or interval name) by its vertical separator line.
 #[derive(Copy, Clone, PartialEq, Eq)]
@@ -88,6 +87,7 @@ where
     mouse_capture: bool,
     selecting: bool,
     intervals: IntervalSet,
+    template_fields: Vec<TemplateField>,
     selection: Selection,
     current_segment: Segment,
     current_segment_attr: CharAttribute,
@@ -97,6 +97,10 @@ where
     active_panel: ActivePanel,
     search_bytes: Vec<u8>,
     edit_text: FlatString<32>,
+    journal: Journal,
+    // true if the control is a pane of a BufferCompareView (its parent)
+    compare_pane: bool,
+    differences: Vec<(Range<u64>, DifferenceKind)>,
 }
 
 impl<T: BufferAccess + 'static> BufferView<T> {
@@ -131,6 +135,7 @@ impl<T: BufferAccess + 'static> BufferView<T> {
             mouse_capture: false,
             selecting: false,
             intervals: IntervalSet::new(),
+            template_fields: Vec::new(),
             selection: Selection::NONE,
             current_segment: Segment::default(),
             current_segment_attr: CharAttribute::default(),
@@ -140,6 +145,9 @@ impl<T: BufferAccess + 'static> BufferView<T> {
             active_panel: ActivePanel::DataRepresentation,
             search_bytes: Vec::new(),
             edit_text: FlatString::new(),
+            journal: Journal::new(),
+            compare_pane: false,
+            differences: Vec::new(),
         }
     }
     /// Creates a new buffer view with a default [`BufferAccess`] instance at the given `layout`.
@@ -293,6 +301,51 @@ impl<T: BufferAccess + 'static> BufferView<T> {
         self.intervals.set(intervals);
         self.paint_buffer();
     }
+    /// Decodes the fields described by `template` starting at `offset` and overlays them on the buffer.
+    ///
+    /// Every decoded value is shown as a colored region (replacing the intervals set via [`Self::set_intervals`]),
+    /// and the decoded structure can be retrieved via [`Self::template_fields`]. If the template cannot be applied
+    /// (for example, a field exceeds the end of the buffer) an error is returned and the view is left unchanged.
+    pub fn apply_template(&mut self, template: &Template, offset: u64) -> Result<(), TemplateError> {
+        let fields = super::template::decode(template, &mut self.buffer, offset)?;
+        self.intervals.set(&super::template::intervals(&fields));
+        self.template_fields = fields;
+        self.paint_buffer();
+        Ok(())
+    }
+    /// Removes the fields decoded by [`Self::apply_template`] (and their colored regions).
+    pub fn clear_template(&mut self) {
+        if self.template_fields.is_empty() {
+            return;
+        }
+        self.template_fields.clear();
+        self.intervals.clear();
+        self.paint_buffer();
+    }
+    /// Returns the fields decoded by the last call to [`Self::apply_template`] in pre-order (each structure or
+    /// array is followed by its fields). The first entry describes the template itself.
+    #[inline(always)]
+    pub fn template_fields(&self) -> &[TemplateField] {
+        &self.template_fields
+    }
+    /// Returns the innermost decoded field that contains the byte at `pos`, if any.
+    pub fn template_field_at(&self, pos: u64) -> Option<&TemplateField> {
+        // fields are stored in pre-order - the last field that contains the position is the innermost one
+        self.template_fields.iter().rev().find(|f| f.contains(pos))
+    }
+    /// Moves the cursor to the first byte of the decoded field with the specified index and selects the bytes of that field.
+    ///
+    /// Returns `false` if the index is invalid or the field is empty.
+    pub fn goto_template_field(&mut self, index: usize) -> bool {
+        let Some((offset, size)) = self.template_fields.get(index).map(|f| (f.offset, f.size)) else {
+            return false;
+        };
+        if size == 0 || offset >= self.buffer.len() {
+            return false;
+        }
+        self.goto_position(offset, false, false);
+        self.set_selection(offset, offset + size)
+    }
     /// Shows or hides the ASCII string panel below the data area.
     pub fn set_ascii_strings_visible(&mut self, visible: bool) {
         if visible == self.flags.contains(Flags::ShowAsciiStrings) {
@@ -363,6 +416,7 @@ impl<T: BufferAccess + 'static> BufferView<T> {
             return false;
         }
         self.selection = Selection::new(start, end);
+        self.sync_compare_view();
         true
     }
     /// Returns the cursor position as a byte offset into the buffer.
@@ -376,6 +430,7 @@ impl<T: BufferAccess + 'static> BufferView<T> {
     #[inline(always)]
     pub fn set_current_pos(&mut self, pos: u64) {
         self.goto_position(pos, false, false);
+        self.sync_compare_view();
     }
     /// Returns the total number of bytes in the backing buffer.
     #[inline(always)]
@@ -385,11 +440,26 @@ impl<T: BufferAccess + 'static> BufferView<T> {
     /// Removes `count` bytes starting at `pos`.
     ///
     /// Returns `false` if the control is read-only or the underlying buffer cannot be resized.
+    /// If more bytes than the limit set via [`Self::set_undo_size_limit`] are removed, the change (and the ones
+    /// made before it) can not be undone.
     pub fn delete_bytes(&mut self, pos: u64, count: u64) -> bool {
         if self.flags.contains(Flags::ReadOnly) {
             return false;
         }
+        let removed = count.min(self.buffer.len().saturating_sub(pos));
+        let mut bytes = Vec::new();
+        if self.buffer.can_resize() && self.journal.can_record(removed) {
+            self.read_buffer_into_vec(pos, removed, &mut bytes);
+        }
         let result = self.buffer.delete(pos, count);
+        if result && removed > 0 {
+            let edit = Edit::Delete { pos, count: removed, bytes };
+            if self.journal.can_record(removed) {
+                self.journal.record(vec![edit]);
+            } else {
+                self.journal.record_without_undo(edit);
+            }
+        }
         self.validate_position_is_in_buffer();
         result
     }
@@ -401,6 +471,9 @@ impl<T: BufferAccess + 'static> BufferView<T> {
             return false;
         }
         let result = self.buffer.insert(pos, bytes);
+        if result && !bytes.is_empty() {
+            self.journal.record(vec![Edit::Insert { pos, bytes: bytes.to_vec() }]);
+        }
         self.validate_position_is_in_buffer();
         result
     }
@@ -411,7 +484,16 @@ impl<T: BufferAccess + 'static> BufferView<T> {
         if self.flags.contains(Flags::ReadOnly) {
             return false;
         }
+        let mut old = Vec::new();
+        self.read_buffer_into_vec(pos, bytes.len() as u64, &mut old);
         let result = self.buffer.overwrite_bytes(pos, bytes);
+        if result && !bytes.is_empty() {
+            self.journal.record(vec![Edit::Overwrite {
+                pos,
+                old,
+                new: bytes.to_vec(),
+            }]);
+        }
         self.paint_buffer();
         result
     }
@@ -419,23 +501,58 @@ impl<T: BufferAccess + 'static> BufferView<T> {
     ///
     /// Clears the selection and adjusts the cursor if it would fall past the new end.
     /// Returns `false` if the control is read-only or resizing is not supported.
+    /// If the buffer is truncated by more bytes than the limit set via [`Self::set_undo_size_limit`], the change
+    /// (and the ones made before it) can not be undone.
     pub fn resize_buffer(&mut self, new_size: u64, fill_byte: u8) -> bool {
         if self.flags.contains(Flags::ReadOnly) {
             return false;
         }
         self.clear_selection();
+        let old_size = self.buffer.len();
+        let removed_count = old_size.saturating_sub(new_size);
+        let mut removed = Vec::new();
+        if self.buffer.can_resize() && removed_count > 0 && self.journal.can_record(removed_count) {
+            self.read_buffer_into_vec(new_size, removed_count, &mut removed);
+        }
         let result = self.buffer.resize(new_size, fill_byte);
+        if result && new_size != old_size {
+            let edit = Edit::Resize {
+                old_size,
+                new_size,
+                fill_byte,
+                removed,
+            };
+            if self.journal.can_record(removed_count) {
+                self.journal.record(vec![edit]);
+            } else {
+                self.journal.record_without_undo(edit);
+            }
+        }
         self.validate_position_is_in_buffer();
         result
     }
     /// Sets `count` consecutive bytes starting at `pos` to `value`.
     ///
     /// Returns `false` if the control is read-only or the range is invalid.
+    /// If `count` is larger than the limit set via [`Self::set_undo_size_limit`], the change (and the ones made
+    /// before it) can not be undone.
     pub fn fill_buffer(&mut self, pos: u64, count: u64, value: u8) -> bool {
         if self.flags.contains(Flags::ReadOnly) {
             return false;
         }
+        let mut old = Vec::new();
+        let in_range = pos.checked_add(count).is_some_and(|end| end <= self.buffer.len());
+        if self.buffer.can_edit() && in_range && self.journal.can_record(count) {
+            self.read_buffer_into_vec(pos, count, &mut old);
+        }
         let result = self.buffer.fill(pos, count, value);
+        if result && count > 0 {
+            if self.journal.can_record(count) {
+                self.journal.record(vec![Edit::Fill { pos, count, value, old }]);
+            } else {
+                self.journal.record_without_undo(Edit::Fill { pos, count, value, old });
+            }
+        }
         self.paint_buffer();
         result
     }
@@ -457,15 +574,164 @@ impl<T: BufferAccess + 'static> BufferView<T> {
         let n = self.read_bytes(pos, &mut output[start..]) as usize;
         output.truncate(start + n);
     }
+    /// Reverts the last change made to the buffer (by typing or through one of the editing methods) and moves
+    /// the cursor where the change was made.
+    ///
+    /// Returns `false` if the control is read-only or there is nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        if self.flags.contains(Flags::ReadOnly) {
+            return false;
+        }
+        self.edit_text.clear();
+        match self.journal.undo(&mut self.buffer) {
+            Some(pos) => {
+                self.after_journal_change(pos);
+                true
+            }
+            None => false,
+        }
+    }
+    /// Re-applies the last change reverted by [`Self::undo`].
+    ///
+    /// Returns `false` if the control is read-only or there is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        if self.flags.contains(Flags::ReadOnly) {
+            return false;
+        }
+        self.edit_text.clear();
+        match self.journal.redo(&mut self.buffer) {
+            Some(pos) => {
+                self.after_journal_change(pos);
+                true
+            }
+            None => false,
+        }
+    }
+    /// Returns `true` if there is a change that can be reverted with [`Self::undo`].
+    #[inline(always)]
+    pub fn can_undo(&self) -> bool {
+        self.journal.can_undo()
+    }
+    /// Returns `true` if there is a change that can be re-applied with [`Self::redo`].
+    #[inline(always)]
+    pub fn can_redo(&self) -> bool {
+        self.journal.can_redo()
+    }
+    /// Returns `true` if the buffer was modified since it was set (or since the last call to [`Self::clear_undo_history`]).
+    #[inline(always)]
+    pub fn is_modified(&self) -> bool {
+        self.journal.is_modified()
+    }
+    /// Returns the ranges of bytes (sorted and non-overlapping) that were modified since the buffer was set
+    /// (or since the last call to [`Self::clear_undo_history`]).
+    ///
+    /// Inserted bytes are reported as modified. For deleted bytes, the byte right before the deletion point is reported.
+    #[inline(always)]
+    pub fn modified_ranges(&self) -> &[std::ops::Range<u64>] {
+        self.journal.modified_ranges()
+    }
+    /// Forgets all changes that can be undone or redone and clears the modified ranges (for example after the
+    /// content of the buffer was saved).
+    pub fn clear_undo_history(&mut self) {
+        self.journal.clear();
+        self.paint_buffer();
+    }
+    /// Sets the maximum number of changes that can be undone (default 1000). Older changes are dropped, but the
+    /// bytes they modified are still reported by [`Self::modified_ranges`].
+    pub fn set_undo_limit(&mut self, limit: usize) {
+        self.journal.set_limit(limit);
+    }
+    /// Sets the maximum number of bytes (default 16 MB) that a change made via [`Self::delete_bytes`],
+    /// [`Self::resize_buffer`] or [`Self::fill_buffer`] can replace or remove and still be undone (the bytes are
+    /// copied in memory to undo the change). A larger change is applied, but it can not be undone and it also
+    /// clears the undo and redo history (the bytes it modified are still reported by [`Self::modified_ranges`]).
+    pub fn set_undo_size_limit(&mut self, size: u64) {
+        self.journal.set_max_edit_size(size);
+    }
+    // overwrites bytes on behalf of the user (for example, from a data inspector) - the change is reported via on_modified
+    pub(crate) fn edit_bytes(&mut self, pos: u64, bytes: &[u8]) -> bool {
+        if !self.can_edit() || !self.overwrite_bytes(pos, bytes) {
+            return false;
+        }
+        self.emit_modified_event();
+        true
+    }
+    pub(crate) fn set_compare_pane(&mut self) {
+        self.compare_pane = true;
+    }
+    pub(crate) fn set_differences(&mut self, differences: Vec<(Range<u64>, DifferenceKind)>) {
+        self.differences = differences;
+        self.paint_buffer();
+    }
+    // reads bytes regardless of the ReadOnly flag
+    pub(crate) fn read_buffer(&mut self, pos: u64, output: &mut [u8]) -> u64 {
+        self.buffer.read_bytes(pos, output)
+    }
+    // returns the position of the cursor, the first visible byte and the selection
+    pub(crate) fn view_state(&self) -> (u64, u64, Option<(u64, u64)>) {
+        (self.pos, self.start_view, self.selection.range())
+    }
+    // updates the view as requested by the compare view (no events are raised)
+    pub(crate) fn set_view_state(&mut self, pos: u64, start_view: u64, selection: Option<(u64, u64)>) {
+        self.pos = pos.min(self.buffer.len().saturating_sub(1));
+        self.start_view = start_view.min(self.buffer.len().saturating_sub(1));
+        self.selection = match selection {
+            Some((start, end)) if start < end && end <= self.buffer.len() => Selection::new(start, end),
+            _ => Selection::NONE,
+        };
+        self.ensure_visible();
+        self.update_scrollbars();
+        self.paint_buffer();
+    }
+    // moves the cursor (scrolling if needed) and sets the selection without notifying the compare view
+    pub(crate) fn move_to(&mut self, pos: u64, selection: Option<(u64, u64)>) -> (u64, u64, Option<(u64, u64)>) {
+        self.selection.clear();
+        if !self.goto_position(pos, false, false) {
+            self.paint_buffer();
+        }
+        if let Some((start, end)) = selection {
+            if start < end && end <= self.buffer.len() {
+                self.selection = Selection::new(start, end);
+            }
+        }
+        self.view_state()
+    }
+    fn sync_compare_view(&mut self) {
+        if self.compare_pane {
+            BufferCompareView::<T>::sync_panes(self.parent.cast(), self.handle.cast(), self.view_state());
+        }
+    }
+    fn difference_attr(&self, start: u64, end: u64) -> Option<CharAttribute> {
+        let idx = self.differences.partition_point(|(r, _)| r.end <= start);
+        match self.differences.get(idx) {
+            Some((r, DifferenceKind::Changed)) if r.start < end => Some(self.theme().text.error),
+            Some((r, _)) if r.start < end => Some(self.theme().text.warning),
+            _ => None,
+        }
+    }
+    fn read_buffer_into_vec(&mut self, pos: u64, count: u64, output: &mut Vec<u8>) {
+        output.resize(count as usize, 0);
+        let n = self.buffer.read_bytes(pos, output) as usize;
+        output.truncate(n);
+    }
+    fn after_journal_change(&mut self, pos: u64) {
+        self.clear_selection();
+        let pos = pos.min(self.buffer.len().saturating_sub(1));
+        if !self.goto_position(pos, false, false) {
+            self.paint_buffer();
+        }
+    }
     fn reset(&mut self) {
         self.pos = 0;
         self.selection.clear();
         self.start_view = 0;
         self.intervals.clear();
+        self.template_fields.clear();
         self.current_segment = Segment::default();
         self.current_segment_attr = CharAttribute::default();
         self.edit_text.clear();
         self.search_bytes.clear();
+        self.journal.clear();
         self.hovered_separator = None;
         self.hovered_panel = None;
         self.mouse_capture = false;
@@ -725,8 +991,12 @@ impl<T: BufferAccess + 'static> BufferView<T> {
                     }
                 }
                 let ch = self.cp.get(b);
-                self.buf_surface
-                    .write_char(cwp.x, cwp.y, Character::with_attributes(ch, self.current_segment_attr));
+                let attr = if self.journal.is_modified_range(cwp.pos, cwp.pos + 1) {
+                    self.theme().text.modified
+                } else {
+                    self.difference_attr(cwp.pos, cwp.pos + 1).unwrap_or(self.current_segment_attr)
+                };
+                self.buf_surface.write_char(cwp.x, cwp.y, Character::with_attributes(ch, attr));
                 cwp.move_cursor(1);
             } else {
                 // error - unable to reach character / byte
@@ -745,6 +1015,7 @@ impl<T: BufferAccess + 'static> BufferView<T> {
         let bytes_count = self.repr.format.bytes_count().min(8) as usize;
         let display_chars_width = (self.repr.format.display_chars() + 1) as i32;
         let inactive_attr = self.theme().text.inactive;
+        let modified_attr = self.theme().text.modified;
         let start_x_char = 1 + ((self.repr.format.display_chars() + 1) * self.repr.columns_count + 3) as i32;
         let inactive_data_panel = if self.flags.contains(Flags::NoPanelDimming) {
             false
@@ -782,10 +1053,16 @@ impl<T: BufferAccess + 'static> BufferView<T> {
                     bytes[0..bytes_count].reverse();
                 }
                 self.repr.format.write(bytes, &mut output);
-                let attr = if inactive_data_panel { inactive_attr } else { self.current_segment_attr };
+                let end = cwp.pos + bytes_count as u64;
+                let normal_attr = if self.journal.is_modified_range(cwp.pos, end) {
+                    modified_attr
+                } else {
+                    self.difference_attr(cwp.pos, end).unwrap_or(self.current_segment_attr)
+                };
+                let attr = if inactive_data_panel { inactive_attr } else { normal_attr };
                 let x = cwp.x * display_chars_width + 1;
                 self.buf_surface.write_ascii(x, cwp.y, output.as_slice(), attr, false);
-                let attr = if inactive_char_panel { inactive_attr } else { self.current_segment_attr };
+                let attr = if inactive_char_panel { inactive_attr } else { normal_attr };
                 let x = start_x_char + cwp.x * bytes_count as i32;
                 for (i, ch) in chars.iter().enumerate().take(bytes_count) {
                     self.buf_surface.write_char(x + i as i32, cwp.y, Character::with_attributes(*ch, attr));
@@ -1391,40 +1668,46 @@ impl<T: BufferAccess + 'static> BufferView<T> {
         }
         self.update_scrollbars();
     }
-    #[inline(always)]
-    fn matches_bytes(&mut self, pos: u64) -> bool {
-        let bytes = &self.search_bytes;
-        if bytes.is_empty() {
-            return true;
-        }
-        if pos.saturating_add(bytes.len() as u64) > self.buffer.len() {
-            return false;
-        }
-        for (index, &byte) in bytes.iter().enumerate() {
-            if self.buffer.get(pos + index as u64) != Some(byte) {
-                return false;
-            }
+    // returns the first position from [from, to) where the searched bytes are found (the buffer is read in chunks)
+    fn search_bytes_in_range(&mut self, from: u64, to: u64) -> Option<u64> {
+        let bytes_len = self.search_bytes.len();
+        let buffer_len = self.buffer.len();
+        let mut chunk = vec![0u8; SEARCH_CHUNK_SIZE + bytes_len - 1];
+        let mut pos = from;
+        while pos < to {
+            let count = (to - pos).min(SEARCH_CHUNK_SIZE as u64);
+            // the chunk also contains the bytes of the matches that start at its end
+            let needed = (count + bytes_len as u64 - 1).min(buffer_len - pos) as usize;
+            let read = self.buffer.read_bytes(pos, &mut chunk[..needed]) as usize;
+            if let Some(index) = chunk[..read]
+                .windows(bytes_len)
+                .take(count as usize)
+                .position(|w| w == self.search_bytes.as_slice())
+            {
+                return Some(pos + index as u64);
+            }
+            pos += count;
         }
-        true
+        None
     }
     fn search_bytes_from_offset(&mut self, offset: u64) -> Option<u64> {
-        let bytes = &self.search_bytes;
-        if bytes.is_empty() {
+        if self.search_bytes.is_empty() {
             return None;
         }
         let buffer_len = self.buffer.len();
-        let bytes_len = bytes.len() as u64;
+        let bytes_len = self.search_bytes.len() as u64;
         if bytes_len > buffer_len {
             return None;
         }
         let start_offset = offset % buffer_len;
-        for i in 0..buffer_len - bytes_len + 1 {
-            let pos = (i + start_offset) % buffer_len;
-            if self.matches_bytes(pos) {
+        let last = buffer_len - bytes_len + 1;
+        if start_offset < last {
+            if let Some(pos) = self.search_bytes_in_range(start_offset, last) {
                 return Some(pos);
             }
         }
-        None
+        // wrap around (from the start of the buffer) - a match can start before the offset and overlap it
+        self.search_bytes_in_range(0, start_offset.min(last))
     }
     fn emit_current_pos_changed_event(&self) {
         self.raise_event(ControlEvent {
@@ -1436,6 +1719,16 @@ impl<T: BufferAccess + 'static> BufferView<T> {
             }),
         });
     }
+    fn emit_modified_event(&self) {
+        self.raise_event(ControlEvent {
+            emitter: self.handle,
+            receiver: self.event_processor,
+            data: ControlEventData::BufferView(EventData {
+                event_type: super::events::BufferViewEventTypes::Modified,
+                type_id: std::any::TypeId::of::<T>(),
+            }),
+        });
+    }
     fn emit_selection_update_event(&self) {
         self.raise_event(ControlEvent {
             emitter: self.handle,
@@ -1548,7 +1841,7 @@ impl<T: BufferAccess + 'static> BufferView<T> {
         EventProcessStatus::Ignored
     }
     #[inline(always)]
-    fn can_edit(&self) -> bool {
+    pub(crate) fn can_edit(&self) -> bool {
         self.buffer.can_edit() && !self.flags.contains(Flags::ReadOnly)
     }
     fn update_buffer_content(&mut self) {
@@ -1561,7 +1854,26 @@ impl<T: BufferAccess + 'static> BufferView<T> {
             self.repr.format.convert_to_bytes(&self.edit_text)
         };
         if len > 0 {
-            let _ = self.buffer.write_bytes(self.pos, &bytes[..len as usize]);
+            let pos = self.pos;
+            let new = &bytes[..len as usize];
+            let old_size = self.buffer.len();
+            let mut old = Vec::new();
+            self.read_buffer_into_vec(pos, len as u64, &mut old);
+            if self.buffer.write_bytes(pos, new) {
+                let new_size = self.buffer.len();
+                let mut group = Vec::with_capacity(2);
+                if new_size > old_size {
+                    group.push(Edit::Resize {
+                        old_size,
+                        new_size,
+                        fill_byte: 0,
+                        removed: Vec::new(),
+                    });
+                }
+                group.push(Edit::Overwrite { pos, old, new: new.to_vec() });
+                self.journal.record(group);
+                self.emit_modified_event();
+            }
             // daca nu s-a updatat si paint-ul - redesenam
             if !self.goto_position(self.pos.saturating_add(len as u64), false, true) {
                 self.paint_buffer();
@@ -1574,6 +1886,18 @@ impl<T: BufferAccess + 'static> BufferView<T> {
             return EventProcessStatus::Ignored;
         }
         match key.value() {
+            key!("Ctrl+Z") => {
+                if self.undo() {
+                    self.emit_modified_event();
+                }
+                return EventProcessStatus::Processed;
+            }
+            key!("Ctrl+Y") => {
+                if self.redo() {
+                    self.emit_modified_event();
+                }
+                return EventProcessStatus::Processed;
+            }
             key!("Backspace") => {
                 if !self.edit_text.is_empty() {
                     self.edit_text.pop();
@@ -1810,6 +2134,16 @@ impl<T: BufferAccess + 'static> OnPaint for BufferView<T> {
 
 impl<T: BufferAccess + 'static> OnKeyPressed for BufferView<T> {
     fn on_key_pressed(&mut self, key: Key, character: char) -> EventProcessStatus {
+        let result = self.process_key(key, character);
+        if result == EventProcessStatus::Processed {
+            self.sync_compare_view();
+        }
+        result
+    }
+}
+
+impl<T: BufferAccess + 'static> BufferView<T> {
+    fn process_key(&mut self, key: Key, character: char) -> EventProcessStatus {
         if self.process_selector_key(key) == EventProcessStatus::Processed {
             return EventProcessStatus::Processed;
         }
@@ -1839,6 +2173,16 @@ impl<T: BufferAccess + 'static> OnKeyPressed for BufferView<T> {
 
 impl<T: BufferAccess + 'static> OnMouseEvent for BufferView<T> {
     fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        let result = self.process_mouse_event(event);
+        if result == EventProcessStatus::Processed {
+            self.sync_compare_view();
+        }
+        result
+    }
+}
+
+impl<T: BufferAccess + 'static> BufferView<T> {
+    fn process_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
         if self.comp.process_mouse_event(event) {
             self.update_scroll_pos_from_scrollbars();
             return EventProcessStatus::Processed;
@@ -0,0 +1,297 @@
+use super::{BufferAccess, Endian, InspectorType};
+use crate::prelude::*;
+
+const NAME_WIDTH: i32 = 12;
+
+// each interpretation that depends on the byte order is shown twice (little endian first)
+const ROWS: [(InspectorType, Endian); 35] = [
+    (InspectorType::U8, Endian::Little),
+    (InspectorType::I8, Endian::Little),
+    (InspectorType::U16, Endian::Little),
+    (InspectorType::U16, Endian::Big),
+    (InspectorType::I16, Endian::Little),
+    (InspectorType::I16, Endian::Big),
+    (InspectorType::U32, Endian::Little),
+    (InspectorType::U32, Endian::Big),
+    (InspectorType::I32, Endian::Little),
+    (InspectorType::I32, Endian::Big),
+    (InspectorType::U64, Endian::Little),
+    (InspectorType::U64, Endian::Big),
+    (InspectorType::I64, Endian::Little),
+    (InspectorType::I64, Endian::Big),
+    (InspectorType::F16, Endian::Little),
+    (InspectorType::F16, Endian::Big),
+    (InspectorType::F32, Endian::Little),
+    (InspectorType::F32, Endian::Big),
+    (InspectorType::F64, Endian::Little),
+    (InspectorType::F64, Endian::Big),
+    (InspectorType::UnixTime32, Endian::Little),
+    (InspectorType::UnixTime32, Endian::Big),
+    (InspectorType::UnixTime64, Endian::Little),
+    (InspectorType::UnixTime64, Endian::Big),
+    (InspectorType::FileTime, Endian::Little),
+    (InspectorType::FileTime, Endian::Big),
+    (InspectorType::DosDateTime, Endian::Little),
+    (InspectorType::DosDateTime, Endian::Big),
+    (InspectorType::Guid, Endian::Little),
+    (InspectorType::Guid, Endian::Big),
+    (InspectorType::Utf8, Endian::Little),
+    (InspectorType::Utf16, Endian::Little),
+    (InspectorType::Utf16, Endian::Big),
+    (InspectorType::ULeb128, Endian::Little),
+    (InspectorType::SLeb128, Endian::Little),
+];
+
+#[CustomControl(overwrite = [OnPaint, OnKeyPressed, OnMouseEvent, OnFocus], internal = true)]
+/// A side panel that decodes the bytes at the cursor of a [`BufferView`] in every supported interpretation
+/// (integers, floats, timestamps, GUIDs, characters and LEB128 values), in both little and big endian.
+///
+/// The panel follows the cursor of the [`BufferView`] it is bound to. Each value can be edited - the new value
+/// is written back into the buffer at the cursor position.
+pub struct DataInspector<T>
+where
+    T: BufferAccess + 'static,
+{
+    bufferview: Handle<BufferView<T>>,
+    current: usize,
+    top: usize,
+    editing: bool,
+    edit_text: String,
+    edit_error: bool,
+}
+
+impl<T: BufferAccess + 'static> DataInspector<T> {
+    /// Creates a new data inspector bound to the [`BufferView`] with the specified handle.
+    ///
+    /// The handle can be [`Handle::None`] and set later via [`Self::set_bufferview`].
+    pub fn new(bufferview: Handle<BufferView<T>>, layout: Layout) -> Self {
+        let mut obj = Self {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Visible | StatusFlags::Enabled | StatusFlags::AcceptInput),
+            bufferview,
+            current: 0,
+            top: 0,
+            editing: false,
+            edit_text: String::new(),
+            edit_error: false,
+        };
+        obj.set_size_bounds(NAME_WIDTH as u16 + 2, 2, u16::MAX, u16::MAX);
+        obj
+    }
+    /// Binds the inspector to another [`BufferView`].
+    pub fn set_bufferview(&mut self, bufferview: Handle<BufferView<T>>) {
+        self.bufferview = bufferview;
+        self.cancel_edit();
+    }
+    /// Returns the handle of the [`BufferView`] the inspector is bound to.
+    #[inline(always)]
+    pub fn bufferview(&self) -> Handle<BufferView<T>> {
+        self.bufferview
+    }
+    /// Returns the value of the bytes at the cursor of the bound [`BufferView`] in the specified interpretation.
+    ///
+    /// Returns `None` if there are not enough bytes after the cursor or if the bytes are not a valid value
+    /// (for example an invalid UTF-8 sequence or date).
+    pub fn value(&self, value_type: InspectorType, endian: Endian) -> Option<String> {
+        let (_, bytes, count) = self.read()?;
+        value_type.decode(&bytes[..count], endian)
+    }
+    /// Parses `text` in the specified interpretation and writes the resulting bytes at the cursor of the bound [`BufferView`].
+    ///
+    /// Returns `false` if the text is not a valid value or if the bytes could not be written (for example, the
+    /// [`BufferView`] is read-only or there are not enough bytes after the cursor).
+    pub fn set_value(&mut self, value_type: InspectorType, endian: Endian, text: &str) -> bool {
+        let Some(bytes) = value_type.encode(text, endian) else {
+            return false;
+        };
+        let h = self.bufferview;
+        let Some(bv) = RuntimeManager::get().get_control_mut(h) else {
+            return false;
+        };
+        let pos = bv.current_pos();
+        bv.edit_bytes(pos, &bytes)
+    }
+    /// Returns the interpretation (and the byte order) of the current row.
+    pub fn current_value_type(&self) -> (InspectorType, Endian) {
+        ROWS[self.current]
+    }
+
+    // returns the cursor position of the bound bufferview and the bytes from that position
+    fn read(&self) -> Option<(u64, [u8; InspectorType::MAX_BYTES], usize)> {
+        let bv = RuntimeManager::get().get_control_mut(self.bufferview)?;
+        let pos = bv.current_pos();
+        let mut bytes = [0u8; InspectorType::MAX_BYTES];
+        let count = bv.read_buffer(pos, &mut bytes) as usize;
+        Some((pos, bytes, count))
+    }
+    fn is_editable(&self) -> bool {
+        RuntimeManager::get().get_control(self.bufferview).is_some_and(|bv| bv.can_edit())
+    }
+    fn visible_rows(&self) -> usize {
+        // the first line shows the cursor position
+        (self.size().height as usize).saturating_sub(1).max(1)
+    }
+    fn move_to(&mut self, index: usize) {
+        self.current = index.min(ROWS.len() - 1);
+        let visible = self.visible_rows();
+        if self.current < self.top {
+            self.top = self.current;
+        } else if self.current >= self.top + visible {
+            self.top = self.current + 1 - visible;
+        }
+    }
+    fn start_edit(&mut self, text: String) {
+        if !self.is_editable() {
+            return;
+        }
+        self.editing = true;
+        self.edit_text = text;
+        self.edit_error = false;
+    }
+    fn cancel_edit(&mut self) {
+        self.editing = false;
+        self.edit_text.clear();
+        self.edit_error = false;
+    }
+    fn commit_edit(&mut self) {
+        let (value_type, endian) = ROWS[self.current];
+        let text = std::mem::take(&mut self.edit_text);
+        if self.set_value(value_type, endian, &text) {
+            self.cancel_edit();
+        } else {
+            self.edit_text = text;
+            self.edit_error = true;
+        }
+    }
+    fn row_name(value_type: InspectorType, endian: Endian) -> String {
+        if !value_type.depends_on_endian() {
+            value_type.name().to_string()
+        } else if endian == Endian::Little {
+            format!("{} LE", value_type.name())
+        } else {
+            format!("{} BE", value_type.name())
+        }
+    }
+}
+
+impl<T: BufferAccess + 'static> OnPaint for DataInspector<T> {
+    fn on_paint(&self, surface: &mut Surface, theme: &Theme) {
+        let enabled = self.is_enabled();
+        let text_attr = if enabled { theme.text.normal } else { theme.text.inactive };
+        let name_attr = if enabled { theme.text.focused } else { theme.text.inactive };
+        let width = self.size().width as i32;
+        surface.clear(Character::with_attributes(' ', text_attr));
+        let data = self.read();
+        let header = match &data {
+            Some((pos, _, _)) => format!("Offset: {pos:X}h ({pos})"),
+            None => "Offset: -".to_string(),
+        };
+        surface.write_string(1, 0, &header, if enabled { theme.text.highlighted } else { theme.text.inactive }, false);
+        let visible = self.visible_rows();
+        for (y, index) in (self.top..ROWS.len()).take(visible).enumerate() {
+            let y = y as i32 + 1;
+            let (value_type, endian) = ROWS[index];
+            let is_current = index == self.current && self.has_focus();
+            if is_current {
+                surface.fill_horizontal_line(0, y, width - 1, Character::with_attributes(' ', theme.list_current_item.focus));
+            }
+            let attr = if is_current { theme.list_current_item.focus } else { name_attr };
+            surface.write_string(1, y, &Self::row_name(value_type, endian), attr, false);
+            if is_current && self.editing {
+                let attr = if self.edit_error { theme.text.error } else { theme.editor.focused };
+                surface.fill_horizontal_line(NAME_WIDTH, y, width - 1, Character::with_attributes(' ', attr));
+                surface.write_string(NAME_WIDTH + 1, y, &self.edit_text, attr, false);
+                surface.set_cursor(NAME_WIDTH + 1 + self.edit_text.chars().count() as i32, y);
+                continue;
+            }
+            let value = data.as_ref().and_then(|(_, bytes, count)| value_type.decode(&bytes[..*count], endian));
+            let attr = if is_current { theme.list_current_item.focus } else { text_attr };
+            match value {
+                Some(value) => surface.write_string(NAME_WIDTH + 1, y, &value, attr, false),
+                None => surface.write_string(NAME_WIDTH + 1, y, "-", if is_current { attr } else { theme.text.inactive }, false),
+            };
+        }
+    }
+}
+
+impl<T: BufferAccess + 'static> OnKeyPressed for DataInspector<T> {
+    fn on_key_pressed(&mut self, key: Key, character: char) -> EventProcessStatus {
+        if self.editing {
+            match key.value() {
+                key!("Enter") => self.commit_edit(),
+                key!("Escape") => self.cancel_edit(),
+                key!("Back") => {
+                    self.edit_text.pop();
+                    self.edit_error = false;
+                }
+                _ if character >= ' ' => {
+                    self.edit_text.push(character);
+                    self.edit_error = false;
+                }
+                // navigation keys cancel the edit and move to another row
+                key!("Up") | key!("Down") | key!("PageUp") | key!("PageDown") => {
+                    self.cancel_edit();
+                    return self.on_key_pressed(key, character);
+                }
+                _ => return EventProcessStatus::Ignored,
+            }
+            return EventProcessStatus::Processed;
+        }
+        let page = self.visible_rows();
+        match key.value() {
+            key!("Up") => self.move_to(self.current.saturating_sub(1)),
+            key!("Down") => self.move_to(self.current + 1),
+            key!("PageUp") => self.move_to(self.current.saturating_sub(page)),
+            key!("PageDown") => self.move_to(self.current + page),
+            key!("Home") => self.move_to(0),
+            key!("End") => self.move_to(ROWS.len() - 1),
+            key!("Enter") | key!("F2") => {
+                let (value_type, endian) = ROWS[self.current];
+                let text = self.value(value_type, endian).unwrap_or_default();
+                self.start_edit(text);
+            }
+            _ if character > ' ' => self.start_edit(character.to_string()),
+            _ => return EventProcessStatus::Ignored,
+        }
+        EventProcessStatus::Processed
+    }
+}
+
+impl<T: BufferAccess + 'static> OnMouseEvent for DataInspector<T> {
+    fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        match event {
+            MouseEvent::Pressed(data) | MouseEvent::DoubleClick(data) => {
+                if data.y < 1 {
+                    return EventProcessStatus::Ignored;
+                }
+                let index = self.top + data.y as usize - 1;
+                if index >= ROWS.len() {
+                    return EventProcessStatus::Ignored;
+                }
+                self.cancel_edit();
+                self.move_to(index);
+                if matches!(event, MouseEvent::DoubleClick(_)) {
+                    let (value_type, endian) = ROWS[index];
+                    let text = self.value(value_type, endian).unwrap_or_default();
+                    self.start_edit(text);
+                }
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Wheel(direction) => {
+                let max_top = ROWS.len().saturating_sub(self.visible_rows());
+                match direction {
+                    MouseWheelDirection::Up => self.top = self.top.saturating_sub(1),
+                    MouseWheelDirection::Down => self.top = (self.top + 1).min(max_top),
+                    _ => return EventProcessStatus::Ignored,
+                }
+                EventProcessStatus::Processed
+            }
+            _ => EventProcessStatus::Ignored,
+        }
+    }
+}
+
+impl<T: BufferAccess + 'static> OnFocus for DataInspector<T> {
+    fn on_lose_focus(&mut self) {
+        self.cancel_edit();
+    }
+}
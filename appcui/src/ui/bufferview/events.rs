@@ -14,6 +14,10 @@ pub trait GenericBufferViewEvents {
     fn on_selection_changed(&mut self, _handle: Handle<()>, _type_id: TypeId) -> EventProcessStatus {
         EventProcessStatus::Ignored
     }
+    /// Called when the content of a [`super::BufferView`] is changed by the user (by typing, undo or redo).
+    fn on_modified(&mut self, _handle: Handle<()>, _type_id: TypeId) -> EventProcessStatus {
+        EventProcessStatus::Ignored
+    }
 }
 
 
@@ -21,6 +25,7 @@ pub trait GenericBufferViewEvents {
 pub(crate) enum BufferViewEventTypes {
     CurrentPosChanged,
     SelectionChanged,
+    Modified,
 }
 
 #[derive(Copy, Clone)]
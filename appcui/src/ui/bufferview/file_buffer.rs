@@ -0,0 +1,462 @@
+use super::BufferAccess;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+const DEFAULT_PAGE_SIZE: usize = 0x10000;
+const DEFAULT_CACHE_PAGES: usize = 64;
+const MIN_PAGE_SIZE: usize = 16;
+const COPY_CHUNK_SIZE: usize = 0x10000;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Source {
+    File,
+    Added,
+    Fill(u8),
+}
+
+// a piece of the logical content: `len` bytes starting from `start` in the original file or in the added buffer
+#[derive(Copy, Clone)]
+struct Piece {
+    source: Source,
+    start: u64,
+    len: u64,
+}
+
+struct PageCache {
+    page_size: usize,
+    capacity: usize,
+    pages: HashMap<u64, (Vec<u8>, u64)>,
+    tick: u64,
+}
+
+impl PageCache {
+    fn new(page_size: usize, capacity: usize) -> Self {
+        Self {
+            page_size,
+            capacity: capacity.max(1),
+            pages: HashMap::new(),
+            tick: 0,
+        }
+    }
+    fn clear(&mut self) {
+        self.pages.clear();
+    }
+    fn page(&mut self, file: &mut File, index: u64) -> Option<&[u8]> {
+        self.tick += 1;
+        let tick = self.tick;
+        if !self.pages.contains_key(&index) {
+            let mut data = vec![0u8; self.page_size];
+            file.seek(SeekFrom::Start(index * self.page_size as u64)).ok()?;
+            let mut count = 0;
+            while count < data.len() {
+                match file.read(&mut data[count..]) {
+                    Ok(0) => break,
+                    Ok(n) => count += n,
+                    Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                    Err(_) => return None,
+                }
+            }
+            data.truncate(count);
+            if self.pages.len() >= self.capacity {
+                // evict the least recently used page
+                if let Some(oldest) = self.pages.iter().min_by_key(|(_, (_, t))| *t).map(|(k, _)| *k) {
+                    self.pages.remove(&oldest);
+                }
+            }
+            self.pages.insert(index, (data, tick));
+        }
+        let entry = self.pages.get_mut(&index)?;
+        entry.1 = tick;
+        Some(&entry.0)
+    }
+}
+
+/// A [`BufferAccess`] implementation over a file that is not loaded in memory.
+///
+/// The content of the file is read in fixed-size pages (64 KB by default) when needed, and the most recently used
+/// pages are kept in a cache (64 pages by default). Changes (writes, insertions and deletions) are kept in memory
+/// and are written to the file only when [`FileBuffer::commit`] or [`FileBuffer::save_as`] is called. This makes
+/// `FileBuffer` suitable for very large files (e.g. disk images) that do not fit in memory.
+///
+/// ```rust,no_run
+/// use appcui::prelude::*;
+/// use appcui::ui::bufferview::FileBuffer;
+///
+/// let buffer = FileBuffer::open("disk.img").unwrap();
+/// let bv = BufferView::with_buffer(buffer, layout!("d:f"), bufferview::Flags::ScrollBars);
+/// ```
+pub struct FileBuffer {
+    path: PathBuf,
+    file: Option<File>,
+    read_only: bool,
+    cache: PageCache,
+    pieces: Vec<Piece>,
+    // logical offset of the first byte of each piece
+    starts: Vec<u64>,
+    added: Vec<u8>,
+    // bytes of the original file that were overwritten (indexed by their offset in the file)
+    patches: BTreeMap<u64, u8>,
+    len: u64,
+    modified: bool,
+}
+
+impl Default for FileBuffer {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::new(),
+            file: None,
+            read_only: false,
+            cache: PageCache::new(DEFAULT_PAGE_SIZE, DEFAULT_CACHE_PAGES),
+            pieces: Vec::new(),
+            starts: Vec::new(),
+            added: Vec::new(),
+            patches: BTreeMap::new(),
+            len: 0,
+            modified: false,
+        }
+    }
+}
+
+impl FileBuffer {
+    /// Opens a file. The file is only read when its content is needed; changes are kept in memory until
+    /// [`FileBuffer::commit`] or [`FileBuffer::save_as`] is called.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut fb = Self::default();
+        fb.load(path.as_ref())?;
+        Ok(fb)
+    }
+    /// Opens a file that can not be modified (writes, insertions and deletions are refused).
+    pub fn open_read_only(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut fb = Self::open(path)?;
+        fb.read_only = true;
+        Ok(fb)
+    }
+    /// Sets the size (in bytes) of the pages that are read from the file (minimum 16 bytes). The page cache is cleared.
+    pub fn with_page_size(mut self, size: usize) -> Self {
+        self.cache = PageCache::new(size.max(MIN_PAGE_SIZE), self.cache.capacity);
+        self
+    }
+    /// Sets the maximum number of pages that are kept in memory (minimum 1). The page cache is cleared.
+    pub fn with_cache_pages(mut self, count: usize) -> Self {
+        self.cache = PageCache::new(self.cache.page_size, count);
+        self
+    }
+    /// Returns the path of the file.
+    #[inline(always)]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    /// Returns `true` if the buffer has changes that were not written to the file.
+    #[inline(always)]
+    pub fn is_modified(&self) -> bool {
+        self.modified
+    }
+    /// Discards all changes that were not written to the file.
+    pub fn discard_changes(&mut self) {
+        self.added.clear();
+        self.patches.clear();
+        self.reset_pieces(self.file_size());
+        self.modified = false;
+    }
+    /// Writes all changes to the file.
+    ///
+    /// If bytes were only overwritten, the changes are written in place. Otherwise (bytes were inserted or deleted)
+    /// the content is written to a temporary file that replaces the original one.
+    pub fn commit(&mut self) -> io::Result<()> {
+        if !self.modified {
+            return Ok(());
+        }
+        if self.file.is_none() {
+            return Err(io::Error::new(io::ErrorKind::NotFound, "no file is associated with this buffer"));
+        }
+        let only_patches = self.pieces.len() == 1 && self.pieces[0].source == Source::File && self.pieces[0].start == 0 && self.len == self.file_size();
+        if only_patches {
+            let mut file = OpenOptions::new().write(true).open(&self.path)?;
+            let mut iter = self.patches.iter().peekable();
+            while let Some((&start, &b)) = iter.next() {
+                // group consecutive bytes in one write
+                let mut run = vec![b];
+                while let Some((&next, &b)) = iter.peek() {
+                    if next != start + run.len() as u64 {
+                        break;
+                    }
+                    run.push(b);
+                    iter.next();
+                }
+                file.seek(SeekFrom::Start(start))?;
+                file.write_all(&run)?;
+            }
+            file.flush()?;
+            let path = self.path.clone();
+            self.load(&path)
+        } else {
+            let mut tmp = self.path.as_os_str().to_owned();
+            tmp.push(".tmp");
+            let tmp = PathBuf::from(tmp);
+            self.write_to(&tmp)?;
+            // the original file must be closed before it is replaced
+            self.file = None;
+            if let Err(e) = std::fs::rename(&tmp, &self.path) {
+                let _ = std::fs::remove_file(&tmp);
+                self.file = File::open(&self.path).ok();
+                return Err(e);
+            }
+            let path = self.path.clone();
+            self.load(&path)
+        }
+    }
+    /// Writes the content of the buffer (including all changes) to a new file. From this point on, the buffer is associated with the new file.
+    pub fn save_as(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if self.file.is_some() && path == self.path {
+            return self.commit();
+        }
+        self.write_to(path)?;
+        self.read_only = false;
+        self.load(path)
+    }
+
+    fn file_size(&self) -> u64 {
+        self.file.as_ref().and_then(|f| f.metadata().ok()).map(|m| m.len()).unwrap_or(0)
+    }
+    fn load(&mut self, path: &Path) -> io::Result<()> {
+        let file = File::open(path)?;
+        let size = file.metadata()?.len();
+        self.path = path.to_path_buf();
+        self.file = Some(file);
+        self.cache.clear();
+        self.added.clear();
+        self.patches.clear();
+        self.reset_pieces(size);
+        self.modified = false;
+        Ok(())
+    }
+    fn reset_pieces(&mut self, size: u64) {
+        self.pieces.clear();
+        if size > 0 {
+            self.pieces.push(Piece {
+                source: Source::File,
+                start: 0,
+                len: size,
+            });
+        }
+        self.update_starts();
+    }
+    fn write_to(&mut self, path: &Path) -> io::Result<()> {
+        let mut output = io::BufWriter::new(File::create(path)?);
+        let mut chunk = vec![0u8; COPY_CHUNK_SIZE];
+        let mut pos = 0;
+        while pos < self.len {
+            let count = (self.len - pos).min(COPY_CHUNK_SIZE as u64) as usize;
+            if self.read(pos, &mut chunk[..count]) != count {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "unable to read the content of the buffer"));
+            }
+            output.write_all(&chunk[..count])?;
+            pos += count as u64;
+        }
+        output.flush()
+    }
+    fn update_starts(&mut self) {
+        self.starts.clear();
+        let mut pos = 0;
+        for p in self.pieces.iter() {
+            self.starts.push(pos);
+            pos += p.len;
+        }
+        self.len = pos;
+    }
+    // returns the index of the piece that contains `pos` and the offset of `pos` within that piece
+    fn locate(&self, pos: u64) -> Option<(usize, u64)> {
+        if pos >= self.len {
+            return None;
+        }
+        let index = self.starts.partition_point(|s| *s <= pos) - 1;
+        Some((index, pos - self.starts[index]))
+    }
+    // makes sure that a piece starts at `pos` and returns its index (or the number of pieces if `pos` is the end of the buffer)
+    fn split_at(&mut self, pos: u64) -> usize {
+        let Some((index, offset)) = self.locate(pos) else {
+            return self.pieces.len();
+        };
+        if offset == 0 {
+            return index;
+        }
+        let piece = self.pieces[index];
+        let second = Piece {
+            source: piece.source,
+            start: if matches!(piece.source, Source::Fill(_)) { 0 } else { piece.start + offset },
+            len: piece.len - offset,
+        };
+        self.pieces[index].len = offset;
+        self.pieces.insert(index + 1, second);
+        self.update_starts();
+        index + 1
+    }
+    fn read_file_byte(&mut self, offset: u64) -> Option<u8> {
+        if let Some(b) = self.patches.get(&offset) {
+            return Some(*b);
+        }
+        let file = self.file.as_mut()?;
+        let page_size = self.cache.page_size as u64;
+        let page = self.cache.page(file, offset / page_size)?;
+        page.get((offset % page_size) as usize).copied()
+    }
+}
+
+impl BufferAccess for FileBuffer {
+    fn count(&self) -> u64 {
+        self.len
+    }
+    fn get(&mut self, pos: u64) -> Option<u8> {
+        let (index, offset) = self.locate(pos)?;
+        let piece = self.pieces[index];
+        match piece.source {
+            Source::File => self.read_file_byte(piece.start + offset),
+            Source::Added => self.added.as_slice().get((piece.start + offset) as usize).copied(),
+            Source::Fill(b) => Some(b),
+        }
+    }
+    fn can_write(&self) -> bool {
+        !self.read_only
+    }
+    fn set(&mut self, pos: u64, value: u8) -> bool {
+        if self.read_only {
+            return false;
+        }
+        let Some((index, offset)) = self.locate(pos) else {
+            return false;
+        };
+        let piece = self.pieces[index];
+        match piece.source {
+            Source::File => {
+                self.patches.insert(piece.start + offset, value);
+            }
+            Source::Added => self.added[(piece.start + offset) as usize] = value,
+            Source::Fill(_) => {
+                // the byte is moved into its own piece (from the added buffer)
+                self.delete(pos, 1);
+                self.insert(pos, &[value]);
+            }
+        }
+        self.modified = true;
+        true
+    }
+    fn can_resize(&self) -> bool {
+        !self.read_only
+    }
+    fn resize(&mut self, new_size: u64, fill_byte: u8) -> bool {
+        if self.read_only {
+            return false;
+        }
+        if new_size < self.len {
+            return self.delete(new_size, self.len - new_size);
+        }
+        if new_size > self.len {
+            self.pieces.push(Piece {
+                source: Source::Fill(fill_byte),
+                start: 0,
+                len: new_size - self.len,
+            });
+            self.update_starts();
+            self.modified = true;
+        }
+        true
+    }
+    fn read(&mut self, pos: u64, output: &mut [u8]) -> usize {
+        let mut count = 0;
+        while count < output.len() {
+            let Some((index, offset)) = self.locate(pos + count as u64) else {
+                break;
+            };
+            let piece = self.pieces[index];
+            let n = ((piece.len - offset) as usize).min(output.len() - count);
+            let out = &mut output[count..count + n];
+            match piece.source {
+                Source::Added => {
+                    let start = (piece.start + offset) as usize;
+                    out.copy_from_slice(&self.added[start..start + n]);
+                }
+                Source::Fill(b) => out.fill(b),
+                Source::File => {
+                    let page_size = self.cache.page_size as u64;
+                    let mut file_pos = piece.start + offset;
+                    let mut i = 0;
+                    while i < n {
+                        let Some(file) = self.file.as_mut() else {
+                            return count + i;
+                        };
+                        let Some(page) = self.cache.page(file, file_pos / page_size) else {
+                            return count + i;
+                        };
+                        let page_offset = (file_pos % page_size) as usize;
+                        if page_offset >= page.len() {
+                            return count + i;
+                        }
+                        let m = (page.len() - page_offset).min(n - i);
+                        out[i..i + m].copy_from_slice(&page[page_offset..page_offset + m]);
+                        i += m;
+                        file_pos += m as u64;
+                    }
+                    let first = piece.start + offset;
+                    for (&p, &b) in self.patches.range(first..first + n as u64) {
+                        out[(p - first) as usize] = b;
+                    }
+                }
+            }
+            count += n;
+        }
+        count
+    }
+    fn can_insert_delete(&self) -> bool {
+        !self.read_only
+    }
+    fn insert(&mut self, pos: u64, bytes: &[u8]) -> bool {
+        if self.read_only || pos > self.len {
+            return false;
+        }
+        if bytes.is_empty() {
+            return true;
+        }
+        let start = self.added.len() as u64;
+        self.added.extend_from_slice(bytes);
+        let index = self.split_at(pos);
+        // consecutive insertions (e.g. typing) extend the previous piece
+        if index > 0 {
+            let prev = &mut self.pieces[index - 1];
+            if prev.source == Source::Added && prev.start + prev.len == start {
+                prev.len += bytes.len() as u64;
+                self.update_starts();
+                self.modified = true;
+                return true;
+            }
+        }
+        self.pieces.insert(
+            index,
+            Piece {
+                source: Source::Added,
+                start,
+                len: bytes.len() as u64,
+            },
+        );
+        self.update_starts();
+        self.modified = true;
+        true
+    }
+    fn delete(&mut self, pos: u64, count: u64) -> bool {
+        if self.read_only || pos > self.len {
+            return false;
+        }
+        let count = count.min(self.len - pos);
+        if count == 0 {
+            return true;
+        }
+        let first = self.split_at(pos);
+        let last = self.split_at(pos + count);
+        self.pieces.drain(first..last);
+        self.update_starts();
+        self.modified = true;
+        true
+    }
+}
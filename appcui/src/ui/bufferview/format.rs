@@ -15,6 +15,13 @@ mod tests;
 
 use super::OutputBuffer;
 
+pub(super) use self::float::{f16_to_f64, f64_to_f16};
+
+#[cfg(target_endian = "little")]
+pub(super) const NATIVE_ENDIAN: Endian = Endian::Little;
+#[cfg(target_endian = "big")]
+pub(super) const NATIVE_ENDIAN: Endian = Endian::Big;
+
 /// How byte offsets are shown in the address column of a [`super::BufferView`].
 #[derive(Copy, Clone)]
 pub enum OffsetFormat {
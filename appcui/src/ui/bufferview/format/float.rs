@@ -111,6 +111,46 @@ fn nearest_fp8_byte(v: f64, decode: fn(u8) -> f64, is_finite: fn(u8) -> bool) ->
     best
 }
 
+// IEEE 754 half precision (binary16) - used by the data inspector
+pub(crate) fn f16_to_f64(bits: u16) -> f64 {
+    let exp = (bits >> 10) & 0x1F;
+    let frac = (bits & 0x3FF) as f64;
+    let v = match exp {
+        0 => 2f64.powi(-14) * (frac / 1024.0),
+        31 if frac == 0.0 => f64::INFINITY,
+        31 => f64::NAN,
+        _ => 2f64.powi(exp as i32 - 15) * (1.0 + frac / 1024.0),
+    };
+    if bits & 0x8000 != 0 { -v } else { v }
+}
+
+pub(crate) fn f64_to_f16(v: f64) -> u16 {
+    if v.is_nan() {
+        return 0x7E00;
+    }
+    let sign = if v.is_sign_negative() { 0x8000 } else { 0 };
+    let a = v.abs();
+    // 65520 is the first value that rounds to infinity (the largest finite value is 65504)
+    if a >= 65520.0 {
+        return sign | 0x7C00;
+    }
+    if a < 2f64.powi(-14) {
+        // subnormal values (a carry into the exponent produces the smallest normal value)
+        return sign | (a / 2f64.powi(-24)).round_ties_even() as u16;
+    }
+    let mut exp = a.log2().floor() as i32;
+    let mut mantissa = a / 2f64.powi(exp);
+    if mantissa >= 2.0 {
+        mantissa /= 2.0;
+        exp += 1;
+    } else if mantissa < 1.0 {
+        mantissa *= 2.0;
+        exp -= 1;
+    }
+    let frac = ((mantissa - 1.0) * 1024.0).round_ties_even() as u16;
+    sign | ((((exp + 15) as u16) << 10) + frac)
+}
+
 pub(super) fn display_chars(format: FloatFormat) -> u32 {
     match format {
         FloatFormat::Scientific32 => F32_WIDTH as u32, // 9
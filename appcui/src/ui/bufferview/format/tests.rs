@@ -1,6 +1,6 @@
 use super::super::OutputBuffer;
 use super::{
-    bin, float, hex, int, oct, uint, DataRepresentationFormat, FloatFormat, HexFormat, IntFormat,
+    bin, f16_to_f64, f64_to_f16, float, hex, int, oct, uint, DataRepresentationFormat, FloatFormat, HexFormat, IntFormat,
     UIntFormat, ValidateResult,
 };
 
@@ -490,4 +490,23 @@ fn data_representation_format_bytes_count_and_display_chars() {
     assert_eq!(DataRepresentationFormat::Bin.display_chars(), 8);
     assert_eq!(DataRepresentationFormat::Char.display_chars(), 1);
 }
-    
\ No newline at end of file
+    
+#[test]
+fn check_f16_conversions() {
+    assert_eq!(f16_to_f64(0x3C00), 1.0);
+    assert_eq!(f16_to_f64(0xC000), -2.0);
+    assert_eq!(f16_to_f64(0x7BFF), 65504.0);
+    assert_eq!(f16_to_f64(0x0001), 2f64.powi(-24));
+    assert_eq!(f16_to_f64(0x7C00), f64::INFINITY);
+    assert!(f16_to_f64(0x7E00).is_nan());
+    assert_eq!(f64_to_f16(1.0), 0x3C00);
+    assert_eq!(f64_to_f16(-2.0), 0xC000);
+    assert_eq!(f64_to_f16(0.1), 0x2E66);
+    assert_eq!(f64_to_f16(65504.0), 0x7BFF);
+    assert_eq!(f64_to_f16(65520.0), 0x7C00);
+    assert_eq!(f64_to_f16(2f64.powi(-24)), 0x0001);
+    assert_eq!(f64_to_f16(-0.0), 0x8000);
+    for bits in [0x0000u16, 0x03FF, 0x0400, 0x3555, 0x7BFF, 0xBC01] {
+        assert_eq!(f64_to_f16(f16_to_f64(bits)), bits);
+    }
+}
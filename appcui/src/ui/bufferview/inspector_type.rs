@@ -0,0 +1,398 @@
+use super::format::*;
+use super::OutputBuffer;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, Timelike};
+
+// seconds between 1601-01-01 (the FILETIME epoch) and 1970-01-01 (the Unix epoch)
+const FILETIME_UNIX_DIFF: i64 = 11_644_473_600;
+const FILETIME_TICKS_PER_SECOND: i64 = 10_000_000;
+const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+const DATETIME_FRACTION_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.f";
+
+/// The interpretations of the bytes at the cursor that are shown by a [`super::DataInspector`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InspectorType {
+    /// 8-bit unsigned integer.
+    U8,
+    /// 8-bit signed integer.
+    I8,
+    /// 16-bit unsigned integer.
+    U16,
+    /// 16-bit signed integer.
+    I16,
+    /// 32-bit unsigned integer.
+    U32,
+    /// 32-bit signed integer.
+    I32,
+    /// 64-bit unsigned integer.
+    U64,
+    /// 64-bit signed integer.
+    I64,
+    /// IEEE 754 half precision value (2 bytes).
+    F16,
+    /// IEEE 754 single precision value (4 bytes).
+    F32,
+    /// IEEE 754 double precision value (8 bytes).
+    F64,
+    /// Number of seconds since 1970-01-01 stored as a 32-bit unsigned integer.
+    UnixTime32,
+    /// Number of seconds since 1970-01-01 stored as a 64-bit signed integer.
+    UnixTime64,
+    /// Windows `FILETIME` - number of 100ns intervals since 1601-01-01 (8 bytes).
+    FileTime,
+    /// MS-DOS date and time - a 16-bit time followed by a 16-bit date (4 bytes).
+    DosDateTime,
+    /// A 16 bytes GUID (for little endian the first three groups are stored in little endian, as on Windows).
+    Guid,
+    /// A character encoded in UTF-8 (1 to 4 bytes).
+    Utf8,
+    /// A character encoded in UTF-16 (2 or 4 bytes).
+    Utf16,
+    /// An unsigned LEB128 variable length integer (1 to 10 bytes).
+    ULeb128,
+    /// A signed LEB128 variable length integer (1 to 10 bytes).
+    SLeb128,
+}
+
+impl InspectorType {
+    /// The maximum number of bytes needed to decode any of the interpretations.
+    pub(super) const MAX_BYTES: usize = 16;
+
+    /// Returns the name of the interpretation.
+    pub fn name(&self) -> &'static str {
+        match self {
+            InspectorType::U8 => "u8",
+            InspectorType::I8 => "i8",
+            InspectorType::U16 => "u16",
+            InspectorType::I16 => "i16",
+            InspectorType::U32 => "u32",
+            InspectorType::I32 => "i32",
+            InspectorType::U64 => "u64",
+            InspectorType::I64 => "i64",
+            InspectorType::F16 => "f16",
+            InspectorType::F32 => "f32",
+            InspectorType::F64 => "f64",
+            InspectorType::UnixTime32 => "unix32",
+            InspectorType::UnixTime64 => "unix64",
+            InspectorType::FileTime => "FILETIME",
+            InspectorType::DosDateTime => "DOS time",
+            InspectorType::Guid => "GUID",
+            InspectorType::Utf8 => "UTF-8",
+            InspectorType::Utf16 => "UTF-16",
+            InspectorType::ULeb128 => "ULEB128",
+            InspectorType::SLeb128 => "SLEB128",
+        }
+    }
+    /// Returns `true` if the interpretation depends on the byte order (and is shown for both endians).
+    pub fn depends_on_endian(&self) -> bool {
+        !matches!(
+            self,
+            InspectorType::U8 | InspectorType::I8 | InspectorType::Utf8 | InspectorType::ULeb128 | InspectorType::SLeb128
+        )
+    }
+    fn number_format(&self) -> Option<DataRepresentationFormat> {
+        match self {
+            InspectorType::U8 => Some(DataRepresentationFormat::UInt(UIntFormat::U8)),
+            InspectorType::I8 => Some(DataRepresentationFormat::Int(IntFormat::I8)),
+            InspectorType::U16 => Some(DataRepresentationFormat::UInt(UIntFormat::U16)),
+            InspectorType::I16 => Some(DataRepresentationFormat::Int(IntFormat::I16)),
+            InspectorType::U32 => Some(DataRepresentationFormat::UInt(UIntFormat::U32)),
+            InspectorType::I32 => Some(DataRepresentationFormat::Int(IntFormat::I32)),
+            InspectorType::U64 => Some(DataRepresentationFormat::UInt(UIntFormat::U64)),
+            InspectorType::I64 => Some(DataRepresentationFormat::Int(IntFormat::I64)),
+            InspectorType::F32 => Some(DataRepresentationFormat::Float(FloatFormat::Scientific32)),
+            InspectorType::F64 => Some(DataRepresentationFormat::Float(FloatFormat::Scientific64)),
+            _ => None,
+        }
+    }
+
+    // decodes the value stored at the start of `bytes` (None if there are not enough bytes or the value is not valid)
+    pub(super) fn decode(&self, bytes: &[u8], endian: Endian) -> Option<String> {
+        match self {
+            InspectorType::U8
+            | InspectorType::I8
+            | InspectorType::U16
+            | InspectorType::I16
+            | InspectorType::U32
+            | InspectorType::I32
+            | InspectorType::U64
+            | InspectorType::I64 => {
+                let format = self.number_format()?;
+                let mut output = OutputBuffer::new();
+                format.write(read_number(bytes, format.bytes_count() as usize, endian)?, &mut output);
+                Some(String::from_utf8_lossy(output.as_slice()).trim().trim_start_matches('+').to_string())
+            }
+            InspectorType::F16 => {
+                let b = read_number(bytes, 2, endian)?;
+                Some(format_float(f16_to_f64(u16::from_ne_bytes([b[0], b[1]]))))
+            }
+            // floats are stored in little endian by the data panel formats
+            InspectorType::F32 => {
+                let b = read_number(bytes, 4, endian)?;
+                Some(format_float(f32::from_le_bytes([b[0], b[1], b[2], b[3]])))
+            }
+            InspectorType::F64 => Some(format_float(f64::from_le_bytes(read_number(bytes, 8, endian)?))),
+            InspectorType::UnixTime32 => {
+                let b = read_number(bytes, 4, endian)?;
+                format_unix_time(u32::from_ne_bytes([b[0], b[1], b[2], b[3]]) as i64)
+            }
+            InspectorType::UnixTime64 => format_unix_time(i64::from_ne_bytes(read_number(bytes, 8, endian)?)),
+            InspectorType::FileTime => {
+                let ticks = u64::from_ne_bytes(read_number(bytes, 8, endian)?) as i64;
+                if ticks < 0 {
+                    return None;
+                }
+                let secs = ticks / FILETIME_TICKS_PER_SECOND - FILETIME_UNIX_DIFF;
+                let nanos = (ticks % FILETIME_TICKS_PER_SECOND) as u32 * 100;
+                DateTime::from_timestamp(secs, nanos).map(|d| d.naive_utc().format(DATETIME_FRACTION_FORMAT).to_string())
+            }
+            InspectorType::DosDateTime => {
+                let b = read_number(bytes, 4, endian)?;
+                let value = u32::from_ne_bytes([b[0], b[1], b[2], b[3]]);
+                let (time, date) = (value & 0xFFFF, value >> 16);
+                NaiveDate::from_ymd_opt(1980 + (date >> 9) as i32, (date >> 5) & 0xF, date & 0x1F)
+                    .and_then(|d| d.and_hms_opt(time >> 11, (time >> 5) & 0x3F, (time & 0x1F) * 2))
+                    .map(|d| d.format(DATETIME_FORMAT).to_string())
+            }
+            InspectorType::Guid => {
+                let mut b: [u8; 16] = bytes.get(..16)?.try_into().ok()?;
+                if endian == Endian::Little {
+                    swap_guid_groups(&mut b);
+                }
+                let hex: String = b.iter().map(|v| format!("{v:02X}")).collect();
+                Some(format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..]))
+            }
+            InspectorType::Utf8 => {
+                let len = match *bytes.first()? {
+                    0x00..=0x7F => 1,
+                    0xC0..=0xDF => 2,
+                    0xE0..=0xEF => 3,
+                    0xF0..=0xF7 => 4,
+                    _ => return None,
+                };
+                let ch = std::str::from_utf8(bytes.get(..len)?).ok()?.chars().next()?;
+                Some(format_char(ch))
+            }
+            InspectorType::Utf16 => {
+                let unit = |i: usize| read_number(bytes.get(i..)?, 2, endian).map(|b| u16::from_ne_bytes([b[0], b[1]]));
+                let first = unit(0)?;
+                let units = if (0xD800..0xDC00).contains(&first) { vec![first, unit(2)?] } else { vec![first] };
+                let ch = char::decode_utf16(units).next()?.ok()?;
+                Some(format_char(ch))
+            }
+            InspectorType::ULeb128 => read_leb128(bytes, false).map(|v| v.to_string()),
+            InspectorType::SLeb128 => read_leb128(bytes, true).map(|v| (v as i64).to_string()),
+        }
+    }
+
+    // converts a text into the bytes that have to be written in the buffer (None if the text is not a valid value)
+    pub(super) fn encode(&self, text: &str, endian: Endian) -> Option<Vec<u8>> {
+        let text = text.trim();
+        match self {
+            InspectorType::U8
+            | InspectorType::I8
+            | InspectorType::U16
+            | InspectorType::I16
+            | InspectorType::U32
+            | InspectorType::I32
+            | InspectorType::U64
+            | InspectorType::I64
+            | InspectorType::F32
+            | InspectorType::F64 => {
+                let format = self.number_format()?;
+                if text.is_empty() || format.validate(text) == ValidateResult::FormatError || !fits(format, text) {
+                    return None;
+                }
+                let (bytes, len) = format.convert_to_bytes(text);
+                (len > 0).then(|| write_number(bytes, len as usize, endian))
+            }
+            InspectorType::F16 => {
+                let format = DataRepresentationFormat::Float(FloatFormat::Scientific64);
+                if text.is_empty() || format.validate(text) == ValidateResult::FormatError {
+                    return None;
+                }
+                let value = text.parse::<f64>().ok()?;
+                let mut bytes = [0u8; 8];
+                bytes[..2].copy_from_slice(&f64_to_f16(value).to_ne_bytes());
+                Some(write_number(bytes, 2, endian))
+            }
+            InspectorType::UnixTime32 => {
+                let secs = u32::try_from(parse_datetime(text)?.and_utc().timestamp()).ok()?;
+                Some(write_number(extend(&secs.to_ne_bytes()), 4, endian))
+            }
+            InspectorType::UnixTime64 => {
+                let secs = parse_datetime(text)?.and_utc().timestamp();
+                Some(write_number(secs.to_ne_bytes(), 8, endian))
+            }
+            InspectorType::FileTime => {
+                let d = parse_datetime(text)?.and_utc();
+                let ticks = (d.timestamp() + FILETIME_UNIX_DIFF)
+                    .checked_mul(FILETIME_TICKS_PER_SECOND)?
+                    .checked_add(d.timestamp_subsec_nanos() as i64 / 100)?;
+                let ticks = u64::try_from(ticks).ok()?;
+                Some(write_number(ticks.to_ne_bytes(), 8, endian))
+            }
+            InspectorType::DosDateTime => {
+                let d = parse_datetime(text)?;
+                if !(1980..=2107).contains(&d.year()) {
+                    return None;
+                }
+                let date = (((d.year() - 1980) as u32) << 9) | (d.month() << 5) | d.day();
+                let time = (d.hour() << 11) | (d.minute() << 5) | (d.second() / 2);
+                Some(write_number(extend(&((date << 16) | time).to_ne_bytes()), 4, endian))
+            }
+            InspectorType::Guid => {
+                let hex: String = text.trim_matches(|c| c == '{' || c == '}').chars().filter(|c| *c != '-').collect();
+                if hex.len() != 32 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return None;
+                }
+                let mut b = [0u8; 16];
+                for (i, v) in b.iter_mut().enumerate() {
+                    *v = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()?;
+                }
+                if endian == Endian::Little {
+                    swap_guid_groups(&mut b);
+                }
+                Some(b.to_vec())
+            }
+            InspectorType::Utf8 => {
+                let ch = parse_char(text)?;
+                let mut buf = [0u8; 4];
+                Some(ch.encode_utf8(&mut buf).as_bytes().to_vec())
+            }
+            InspectorType::Utf16 => {
+                let ch = parse_char(text)?;
+                let mut units = [0u16; 2];
+                let mut result = Vec::with_capacity(4);
+                for unit in ch.encode_utf16(&mut units).iter() {
+                    result.extend_from_slice(&write_number(extend(&unit.to_ne_bytes()), 2, endian));
+                }
+                Some(result)
+            }
+            InspectorType::ULeb128 => {
+                if text.is_empty() || DataRepresentationFormat::UInt(UIntFormat::U64).validate(text) == ValidateResult::FormatError {
+                    return None;
+                }
+                Some(write_leb128(text.parse::<u64>().ok()? as i128, false))
+            }
+            InspectorType::SLeb128 => {
+                if text.is_empty() || DataRepresentationFormat::Int(IntFormat::I64).validate(text) == ValidateResult::FormatError {
+                    return None;
+                }
+                Some(write_leb128(text.parse::<i64>().ok()? as i128, true))
+            }
+        }
+    }
+}
+
+// reads `count` bytes and arranges them in the native byte order (the same way the data panel does it)
+fn read_number(bytes: &[u8], count: usize, endian: Endian) -> Option<[u8; 8]> {
+    let mut result = extend(bytes.get(..count)?);
+    if endian != NATIVE_ENDIAN && count > 1 {
+        result[..count].reverse();
+    }
+    Some(result)
+}
+
+fn write_number(mut bytes: [u8; 8], count: usize, endian: Endian) -> Vec<u8> {
+    if endian != NATIVE_ENDIAN && count > 1 {
+        bytes[..count].reverse();
+    }
+    bytes[..count].to_vec()
+}
+
+fn extend(bytes: &[u8]) -> [u8; 8] {
+    let mut result = [0u8; 8];
+    result[..bytes.len()].copy_from_slice(bytes);
+    result
+}
+
+// the integer formats truncate values that do not fit - the inspector rejects them instead
+fn fits(format: DataRepresentationFormat, text: &str) -> bool {
+    let range = match format {
+        DataRepresentationFormat::UInt(f) => 0..=(u64::MAX >> (64 - 8 * f as u32)) as i128,
+        DataRepresentationFormat::Int(f) => {
+            let max = (i64::MAX >> (64 - 8 * f as u32)) as i128;
+            -max - 1..=max
+        }
+        _ => return true,
+    };
+    text.parse::<i128>().is_ok_and(|v| range.contains(&v))
+}
+
+// very small or very large values are shown in scientific notation
+fn format_float<F: Copy + Into<f64> + std::fmt::Display + std::fmt::LowerExp>(value: F) -> String {
+    let abs = value.into().abs();
+    if abs != 0.0 && abs.is_finite() && !(1e-4..1e15).contains(&abs) {
+        format!("{value:e}")
+    } else {
+        value.to_string()
+    }
+}
+
+fn swap_guid_groups(b: &mut [u8; 16]) {
+    b[..4].reverse();
+    b[4..6].reverse();
+    b[6..8].reverse();
+}
+
+fn format_unix_time(secs: i64) -> Option<String> {
+    DateTime::from_timestamp(secs, 0).map(|d| d.naive_utc().format(DATETIME_FORMAT).to_string())
+}
+
+fn parse_datetime(text: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(text, DATETIME_FRACTION_FORMAT).ok()
+}
+
+fn format_char(ch: char) -> String {
+    if ch.is_control() {
+        format!("U+{:04X}", ch as u32)
+    } else {
+        format!("{} (U+{:04X})", ch, ch as u32)
+    }
+}
+
+// accepts a single character or a code point in the `U+XXXX` form (as it is displayed)
+fn parse_char(text: &str) -> Option<char> {
+    if let Some(idx) = text.find("U+").or_else(|| text.find("u+")) {
+        let hex: String = text[idx + 2..].chars().take_while(|c| c.is_ascii_hexdigit()).collect();
+        return char::from_u32(u32::from_str_radix(&hex, 16).ok()?);
+    }
+    let mut chars = text.chars();
+    let ch = chars.next()?;
+    chars.next().is_none().then_some(ch)
+}
+
+// returns the decoded value (sign extended for signed values)
+fn read_leb128(bytes: &[u8], signed: bool) -> Option<u64> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    for b in bytes.iter().take(10) {
+        value |= ((b & 0x7F) as u64).checked_shl(shift).unwrap_or(0);
+        shift += 7;
+        if b & 0x80 == 0 {
+            if signed && shift < 64 && (b & 0x40) != 0 {
+                value |= u64::MAX << shift;
+            }
+            return Some(value);
+        }
+    }
+    None
+}
+
+fn write_leb128(mut value: i128, signed: bool) -> Vec<u8> {
+    let mut result = Vec::with_capacity(10);
+    loop {
+        let b = (value & 0x7F) as u8;
+        value >>= 7;
+        let done = if signed {
+            (value == 0 && b & 0x40 == 0) || (value == -1 && b & 0x40 != 0)
+        } else {
+            value == 0
+        };
+        if done {
+            result.push(b);
+            return result;
+        }
+        result.push(b | 0x80);
+    }
+}
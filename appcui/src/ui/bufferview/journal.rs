@@ -0,0 +1,233 @@
+use super::{Buffer, BufferAccess};
+use std::ops::Range;
+
+const DEFAULT_UNDO_LIMIT: usize = 1000;
+const DEFAULT_MAX_EDIT_SIZE: u64 = 0x100_0000;
+
+// the bytes an edit replaces (or removes) are kept in memory so that it can be undone - for edits that are too large
+// (see Journal::can_record) these lists are empty and the edit is only used to track the modified ranges
+pub(super) enum Edit {
+    Overwrite { pos: u64, old: Vec<u8>, new: Vec<u8> },
+    Fill { pos: u64, count: u64, value: u8, old: Vec<u8> },
+    Insert { pos: u64, bytes: Vec<u8> },
+    Delete { pos: u64, count: u64, bytes: Vec<u8> },
+    Resize { old_size: u64, new_size: u64, fill_byte: u8, removed: Vec<u8> },
+}
+
+impl Edit {
+    // position where the cursor is moved after the edit is undone or redone
+    pub(super) fn pos(&self) -> u64 {
+        match self {
+            Edit::Overwrite { pos, .. } | Edit::Fill { pos, .. } | Edit::Insert { pos, .. } | Edit::Delete { pos, .. } => *pos,
+            Edit::Resize { old_size, new_size, .. } => (*old_size).min(*new_size),
+        }
+    }
+    fn apply<T: BufferAccess>(&self, buffer: &mut Buffer<T>) -> bool {
+        match self {
+            Edit::Overwrite { pos, new, .. } => buffer.overwrite_bytes(*pos, new),
+            Edit::Fill { pos, count, value, .. } => buffer.fill(*pos, *count, *value),
+            Edit::Insert { pos, bytes } => buffer.insert(*pos, bytes),
+            Edit::Delete { pos, count, .. } => buffer.delete(*pos, *count),
+            Edit::Resize { new_size, fill_byte, .. } => buffer.resize(*new_size, *fill_byte),
+        }
+    }
+    fn revert<T: BufferAccess>(&self, buffer: &mut Buffer<T>) -> bool {
+        match self {
+            Edit::Overwrite { pos, old, .. } | Edit::Fill { pos, old, .. } => buffer.overwrite_bytes(*pos, old),
+            Edit::Insert { pos, bytes } => buffer.delete(*pos, bytes.len() as u64),
+            Edit::Delete { pos, bytes, .. } => buffer.insert(*pos, bytes),
+            Edit::Resize {
+                old_size,
+                new_size,
+                removed,
+                ..
+            } => buffer.resize(*old_size, 0) && (removed.is_empty() || buffer.overwrite_bytes(*new_size, removed)),
+        }
+    }
+}
+
+// a sorted list of disjoint ranges (in the current coordinates of the buffer)
+#[derive(Default, Clone)]
+struct ModifiedRanges {
+    ranges: Vec<Range<u64>>,
+}
+
+impl ModifiedRanges {
+    fn mark(&mut self, start: u64, end: u64) {
+        if start >= end {
+            return;
+        }
+        // all ranges that overlap or touch [start, end) are merged into one
+        let first = self.ranges.partition_point(|r| r.end < start);
+        let last = self.ranges.partition_point(|r| r.start <= end);
+        let mut merged = start..end;
+        if first < last {
+            merged.start = merged.start.min(self.ranges[first].start);
+            merged.end = merged.end.max(self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, std::iter::once(merged));
+    }
+    fn insert(&mut self, pos: u64, count: u64) {
+        let mut split = None;
+        for r in self.ranges.iter_mut() {
+            if r.start >= pos {
+                r.start += count;
+                r.end += count;
+            } else if r.end > pos {
+                split = Some(pos + count..r.end + count);
+                r.end = pos;
+            }
+        }
+        if let Some(tail) = split {
+            self.mark(tail.start, tail.end);
+        }
+        self.mark(pos, pos + count);
+    }
+    fn delete(&mut self, pos: u64, count: u64) {
+        let end = pos + count;
+        self.ranges.retain_mut(|r| {
+            if r.start >= end {
+                r.start -= count;
+                r.end -= count;
+            } else if r.end > pos {
+                let tail = r.end.saturating_sub(end);
+                r.start = r.start.min(pos);
+                r.end = r.start.max(pos) + tail;
+            }
+            r.start < r.end
+        });
+        // the bytes around the deletion point are now adjacent (and they have been modified)
+        self.mark(pos.saturating_sub(1), pos.max(1));
+    }
+    fn apply(&mut self, edit: &Edit) {
+        match edit {
+            Edit::Overwrite { pos, new, .. } => self.mark(*pos, *pos + new.len() as u64),
+            Edit::Fill { pos, count, .. } => self.mark(*pos, *pos + *count),
+            Edit::Insert { pos, bytes } => self.insert(*pos, bytes.len() as u64),
+            Edit::Delete { pos, count, .. } => self.delete(*pos, *count),
+            Edit::Resize { old_size, new_size, .. } => {
+                if new_size > old_size {
+                    self.insert(*old_size, new_size - old_size);
+                } else {
+                    self.delete(*new_size, old_size - new_size);
+                }
+            }
+        }
+    }
+}
+
+pub(super) struct Journal {
+    undo: Vec<Vec<Edit>>,
+    redo: Vec<Vec<Edit>>,
+    // modified ranges produced by the edits that were dropped from the undo list (because of the limit)
+    base: ModifiedRanges,
+    modified: ModifiedRanges,
+    limit: usize,
+    max_edit_size: u64,
+}
+
+impl Journal {
+    pub(super) fn new() -> Self {
+        Self {
+            undo: Vec::new(),
+            redo: Vec::new(),
+            base: ModifiedRanges::default(),
+            modified: ModifiedRanges::default(),
+            limit: DEFAULT_UNDO_LIMIT,
+            max_edit_size: DEFAULT_MAX_EDIT_SIZE,
+        }
+    }
+    pub(super) fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.base = ModifiedRanges::default();
+        self.modified = ModifiedRanges::default();
+    }
+    pub(super) fn set_limit(&mut self, limit: usize) {
+        self.limit = limit.max(1);
+        self.enforce_limit();
+    }
+    pub(super) fn set_max_edit_size(&mut self, size: u64) {
+        self.max_edit_size = size;
+    }
+    // true if the bytes replaced (or removed) by an edit are few enough to be copied in the journal
+    #[inline(always)]
+    pub(super) fn can_record(&self, size: u64) -> bool {
+        size <= self.max_edit_size
+    }
+    #[inline(always)]
+    pub(super) fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+    #[inline(always)]
+    pub(super) fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+    #[inline(always)]
+    pub(super) fn is_modified(&self) -> bool {
+        !self.modified.ranges.is_empty()
+    }
+    #[inline(always)]
+    pub(super) fn modified_ranges(&self) -> &[Range<u64>] {
+        &self.modified.ranges
+    }
+    pub(super) fn is_modified_range(&self, start: u64, end: u64) -> bool {
+        let idx = self.modified.ranges.partition_point(|r| r.end <= start);
+        self.modified.ranges.get(idx).is_some_and(|r| r.start < end)
+    }
+    pub(super) fn record(&mut self, group: Vec<Edit>) {
+        if group.is_empty() {
+            return;
+        }
+        for edit in group.iter() {
+            self.modified.apply(edit);
+        }
+        self.undo.push(group);
+        self.redo.clear();
+        self.enforce_limit();
+    }
+    // records an edit that is too large to be undone (its lists of bytes are empty) - the previous edits can not be
+    // undone or redone either (their positions might be invalid), but all modified bytes are still reported
+    pub(super) fn record_without_undo(&mut self, edit: Edit) {
+        self.modified.apply(&edit);
+        self.base = self.modified.clone();
+        self.undo.clear();
+        self.redo.clear();
+    }
+    // reverts the last group of edits and returns the position of its first edit
+    pub(super) fn undo<T: BufferAccess>(&mut self, buffer: &mut Buffer<T>) -> Option<u64> {
+        let group = self.undo.pop()?;
+        for edit in group.iter().rev() {
+            edit.revert(buffer);
+        }
+        let pos = group.first().map(|e| e.pos()).unwrap_or_default();
+        self.redo.push(group);
+        self.recompute();
+        Some(pos)
+    }
+    // re-applies the last undone group of edits and returns the position of its first edit
+    pub(super) fn redo<T: BufferAccess>(&mut self, buffer: &mut Buffer<T>) -> Option<u64> {
+        let group = self.redo.pop()?;
+        for edit in group.iter() {
+            edit.apply(buffer);
+            self.modified.apply(edit);
+        }
+        let pos = group.first().map(|e| e.pos()).unwrap_or_default();
+        self.undo.push(group);
+        Some(pos)
+    }
+    fn enforce_limit(&mut self) {
+        if self.undo.len() > self.limit {
+            let dropped: Vec<Vec<Edit>> = self.undo.drain(..self.undo.len() - self.limit).collect();
+            for edit in dropped.iter().flatten() {
+                self.base.apply(edit);
+            }
+        }
+    }
+    fn recompute(&mut self) {
+        self.modified = self.base.clone();
+        for edit in self.undo.iter().flatten() {
+            self.modified.apply(edit);
+        }
+    }
+}
@@ -0,0 +1,460 @@
+use super::{Buffer, BufferAccess, Endian, Interval, TemplateField};
+use crate::graphics::{CharAttribute, Color};
+use std::fmt;
+
+const MAX_ARRAY_ITEMS: u64 = 0x10000;
+const MAX_DEPTH: usize = 32;
+const MAX_DISPLAYED_BYTES: u64 = 16;
+const MAX_DISPLAYED_CHARS: u64 = 64;
+const FIELD_COLORS: [Color; 4] = [Color::Aqua, Color::Yellow, Color::Pink, Color::Green];
+
+/// Errors produced when a [`Template`] is parsed from its text description or applied over a buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateError {
+    /// The text description is invalid (line number and a description of the problem).
+    Syntax(u32, String),
+    /// A count or a condition refers to a field that was not decoded before it.
+    UnknownField(String),
+    /// A field used as a count or in a condition is not a (non-negative) integer.
+    InvalidValue(String),
+    /// An array has more items than the maximum supported number of items (65536).
+    TooManyItems(String),
+    /// A field exceeds the end of the buffer.
+    OutOfBounds(String),
+    /// Structures are nested too deep (more than 32 levels).
+    TooDeep(String),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::Syntax(line, message) => write!(f, "line {line}: {message}"),
+            TemplateError::UnknownField(name) => write!(f, "unknown field '{name}'"),
+            TemplateError::InvalidValue(name) => write!(f, "field '{name}' is not a valid count or integer value"),
+            TemplateError::TooManyItems(name) => write!(f, "array '{name}' has too many items"),
+            TemplateError::OutOfBounds(name) => write!(f, "field '{name}' exceeds the end of the buffer"),
+            TemplateError::TooDeep(name) => write!(f, "structure '{name}' is nested too deep"),
+        }
+    }
+}
+
+/// The number of items of a string, byte sequence or array field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Count {
+    /// A fixed number of items.
+    Fixed(u64),
+    /// The number of items is the value of a previously decoded integer field.
+    Field(String),
+}
+impl From<u64> for Count {
+    fn from(value: u64) -> Self {
+        Count::Fixed(value)
+    }
+}
+impl From<&str> for Count {
+    fn from(value: &str) -> Self {
+        Count::Field(value.to_string())
+    }
+}
+
+/// The type of a field from a [`Template`].
+#[derive(Clone)]
+pub enum FieldType {
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    F32,
+    F64,
+    /// An ASCII string stored on a number of bytes (the displayed text stops at the first NUL character).
+    String(Count),
+    /// A sequence of raw bytes (displayed in hexadecimal).
+    Bytes(Count),
+    /// An array of items of the same type.
+    Array(Box<FieldType>, Count),
+    /// A nested structure.
+    Struct(Template),
+}
+
+impl FieldType {
+    /// Creates an array of `count` items of type `item`. The count can be a number or the name of a previously decoded field.
+    pub fn array(item: FieldType, count: impl Into<Count>) -> Self {
+        FieldType::Array(Box::new(item), count.into())
+    }
+    fn primitive_size(&self) -> Option<u64> {
+        match self {
+            FieldType::U8 | FieldType::I8 => Some(1),
+            FieldType::U16 | FieldType::I16 => Some(2),
+            FieldType::U32 | FieldType::I32 | FieldType::F32 => Some(4),
+            FieldType::U64 | FieldType::I64 | FieldType::F64 => Some(8),
+            _ => None,
+        }
+    }
+    fn type_name(&self, count: Option<u64>) -> String {
+        let base = match self {
+            FieldType::U8 => "u8",
+            FieldType::U16 => "u16",
+            FieldType::U32 => "u32",
+            FieldType::U64 => "u64",
+            FieldType::I8 => "i8",
+            FieldType::I16 => "i16",
+            FieldType::I32 => "i32",
+            FieldType::I64 => "i64",
+            FieldType::F32 => "f32",
+            FieldType::F64 => "f64",
+            FieldType::String(_) => "char",
+            FieldType::Bytes(_) => "u8",
+            FieldType::Array(item, _) => return format!("{}[{}]", item.type_name(None), count.unwrap_or_default()),
+            FieldType::Struct(t) => t.name.as_str(),
+        };
+        match count {
+            Some(count) => format!("{base}[{count}]"),
+            None => base.to_string(),
+        }
+    }
+}
+
+/// The relation used by a [`Condition`] to compare the value of a field with a constant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    /// All bits from the constant are set in the value of the field.
+    BitsSet,
+}
+
+/// A condition that decides whether a field is present, based on the value of a previously decoded integer field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Condition {
+    field: String,
+    comparison: Comparison,
+    value: i128,
+}
+impl Condition {
+    /// Creates a condition that compares the value of the field `field` with `value`.
+    pub fn new(field: &str, comparison: Comparison, value: i128) -> Self {
+        Self {
+            field: field.to_string(),
+            comparison,
+            value,
+        }
+    }
+    fn matches(&self, value: i128) -> bool {
+        match self.comparison {
+            Comparison::Equal => value == self.value,
+            Comparison::NotEqual => value != self.value,
+            Comparison::Less => value < self.value,
+            Comparison::LessOrEqual => value <= self.value,
+            Comparison::Greater => value > self.value,
+            Comparison::GreaterOrEqual => value >= self.value,
+            Comparison::BitsSet => (value & self.value) == self.value,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(super) struct Field {
+    pub(super) name: String,
+    pub(super) kind: FieldType,
+    pub(super) conditions: Vec<Condition>,
+}
+
+/// A description of a binary structure (a list of typed fields) that can be applied over the content of a
+/// [`BufferView`](super::BufferView) via [`BufferView::apply_template`](super::BufferView::apply_template).
+///
+/// A template can be built in code:
+/// ```rust
+/// use appcui::prelude::*;
+/// use appcui::ui::bufferview::{Comparison, Condition, FieldType, Template};
+///
+/// let entry = Template::new("Entry").field("kind", FieldType::U8).field("size", FieldType::U16);
+/// let header = Template::new("Header")
+///     .field("magic", FieldType::String(4.into()))
+///     .field("count", FieldType::U16)
+///     .field("entries", FieldType::array(FieldType::Struct(entry), "count"))
+///     .field_if("crc", FieldType::U32, Condition::new("count", Comparison::Greater, 0));
+/// ```
+/// or parsed from a text description (see [`Template::parse`]).
+#[derive(Clone)]
+pub struct Template {
+    pub(super) name: String,
+    pub(super) endian: Endian,
+    pub(super) fields: Vec<Field>,
+}
+
+impl Template {
+    /// Creates an empty template (a structure with no fields) with the specified name. Multi-byte values are read as little endian.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            endian: Endian::Little,
+            fields: Vec::new(),
+        }
+    }
+    /// Sets the byte order used to read the multi-byte values of this template.
+    pub fn endian(mut self, endian: Endian) -> Self {
+        self.endian = endian;
+        self
+    }
+    /// Adds a new field at the end of the template.
+    pub fn field(mut self, name: &str, kind: FieldType) -> Self {
+        self.fields.push(Field {
+            name: name.to_string(),
+            kind,
+            conditions: Vec::new(),
+        });
+        self
+    }
+    /// Adds a new field at the end of the template. The field is only decoded if `condition` holds.
+    pub fn field_if(mut self, name: &str, kind: FieldType, condition: Condition) -> Self {
+        self.fields.push(Field {
+            name: name.to_string(),
+            kind,
+            conditions: vec![condition],
+        });
+        self
+    }
+    /// Parses a template from a C-like text description:
+    /// ```text
+    /// endian little;          // optional (little or big)
+    /// struct Entry {
+    ///     u8 kind;
+    ///     u16 size;
+    /// }
+    /// struct Header {
+    ///     char magic[4];      // ASCII string
+    ///     u16 count;
+    ///     u8 flags;
+    ///     Entry entries[count];
+    ///     if (flags & 1) u32 crc;
+    ///     if (count > 2) {
+    ///         u8 extra[16];   // raw bytes
+    ///     }
+    /// }
+    /// ```
+    /// Supported types are `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32`, `i64`, `f32`, `f64`, `char` and
+    /// any previously defined structure. Conditions compare a previously decoded field with a constant using
+    /// `==`, `!=`, `<`, `<=`, `>`, `>=` or `&` (all bits set). The last structure from the text is the root of the template.
+    pub fn parse(text: &str) -> Result<Self, TemplateError> {
+        super::template_parser::parse(text)
+    }
+    /// Returns the name of the template.
+    #[inline(always)]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+enum Value {
+    Int(i128),
+    Other,
+}
+
+struct Decoder<'a, T: BufferAccess> {
+    buffer: &'a mut Buffer<T>,
+    fields: Vec<TemplateField>,
+    scopes: Vec<Vec<(String, Value)>>,
+}
+
+impl<T: BufferAccess> Decoder<'_, T> {
+    fn lookup(&self, name: &str) -> Result<i128, TemplateError> {
+        for scope in self.scopes.iter().rev() {
+            if let Some((_, value)) = scope.iter().rev().find(|(n, _)| n == name) {
+                return match value {
+                    Value::Int(v) => Ok(*v),
+                    Value::Other => Err(TemplateError::InvalidValue(name.to_string())),
+                };
+            }
+        }
+        Err(TemplateError::UnknownField(name.to_string()))
+    }
+    fn resolve(&self, count: &Count) -> Result<u64, TemplateError> {
+        match count {
+            Count::Fixed(value) => Ok(*value),
+            Count::Field(name) => u64::try_from(self.lookup(name)?).map_err(|_| TemplateError::InvalidValue(name.clone())),
+        }
+    }
+    fn push(&mut self, name: &str, type_name: String, offset: u64, parent: usize, is_leaf: bool) -> usize {
+        let index = self.fields.len();
+        let depth = self.fields[parent].depth + 1;
+        self.fields.push(TemplateField {
+            name: name.to_string(),
+            type_name,
+            offset,
+            size: 0,
+            value: String::new(),
+            depth,
+            index,
+            parent: Some(parent),
+            is_leaf,
+        });
+        index
+    }
+    fn check_range(&self, name: &str, offset: u64, size: u64) -> Result<u64, TemplateError> {
+        match offset.checked_add(size) {
+            Some(end) if end <= self.buffer.len() => Ok(end),
+            _ => Err(TemplateError::OutOfBounds(name.to_string())),
+        }
+    }
+    fn decode_struct(&mut self, template: &Template, offset: u64, parent: usize) -> Result<u64, TemplateError> {
+        if self.scopes.len() >= MAX_DEPTH {
+            return Err(TemplateError::TooDeep(template.name.clone()));
+        }
+        self.scopes.push(Vec::new());
+        let mut pos = offset;
+        for field in template.fields.iter() {
+            let mut present = true;
+            for condition in field.conditions.iter() {
+                if !condition.matches(self.lookup(&condition.field)?) {
+                    present = false;
+                    break;
+                }
+            }
+            if present {
+                pos = self.decode_field(&field.name, &field.kind, pos, parent, template.endian)?;
+            }
+        }
+        self.scopes.pop();
+        Ok(pos)
+    }
+    fn decode_primitive(&mut self, name: &str, kind: &FieldType, offset: u64, parent: usize, endian: Endian) -> Result<u64, TemplateError> {
+        let size = kind.primitive_size().unwrap_or(1);
+        let end = self.check_range(name, offset, size)?;
+        let mut bytes = [0u8; 8];
+        if self.buffer.read_bytes(offset, &mut bytes[..size as usize]) != size {
+            return Err(TemplateError::OutOfBounds(name.to_string()));
+        }
+        let raw = match endian {
+            Endian::Little => u64::from_le_bytes(bytes),
+            Endian::Big => u64::from_be_bytes(bytes) >> (64 - size * 8),
+        };
+        let (text, value) = match kind {
+            FieldType::U8 | FieldType::U16 | FieldType::U32 | FieldType::U64 => (format!("{raw} (0x{raw:X})"), Value::Int(raw as i128)),
+            FieldType::I8 => (format!("{}", raw as u8 as i8), Value::Int(raw as u8 as i8 as i128)),
+            FieldType::I16 => (format!("{}", raw as u16 as i16), Value::Int(raw as u16 as i16 as i128)),
+            FieldType::I32 => (format!("{}", raw as u32 as i32), Value::Int(raw as u32 as i32 as i128)),
+            FieldType::I64 => (format!("{}", raw as i64), Value::Int(raw as i64 as i128)),
+            FieldType::F32 => (format!("{}", f32::from_bits(raw as u32)), Value::Other),
+            _ => (format!("{}", f64::from_bits(raw)), Value::Other),
+        };
+        let index = self.push(name, kind.type_name(None), offset, parent, true);
+        self.fields[index].size = size;
+        self.fields[index].value = text;
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push((name.to_string(), value));
+        }
+        Ok(end)
+    }
+    fn decode_sequence(&mut self, name: &str, kind: &FieldType, count: u64, offset: u64, parent: usize) -> Result<u64, TemplateError> {
+        let end = self.check_range(name, offset, count)?;
+        let is_string = matches!(kind, FieldType::String(_));
+        let displayed = count.min(if is_string { MAX_DISPLAYED_CHARS } else { MAX_DISPLAYED_BYTES });
+        let mut bytes = vec![0u8; displayed as usize];
+        let read = self.buffer.read_bytes(offset, &mut bytes) as usize;
+        bytes.truncate(read);
+        let mut text = String::with_capacity(displayed as usize * 3 + 5);
+        if is_string {
+            text.push('"');
+            for b in bytes.iter().take_while(|b| **b != 0) {
+                text.push(if b.is_ascii_graphic() || *b == b' ' { *b as char } else { '.' });
+            }
+            text.push('"');
+        } else {
+            for (i, b) in bytes.iter().enumerate() {
+                if i > 0 {
+                    text.push(' ');
+                }
+                text.push_str(&format!("{b:02X}"));
+            }
+        }
+        if displayed < count && (!is_string || !bytes.contains(&0)) {
+            text.push_str(" ...");
+        }
+        let index = self.push(name, kind.type_name(Some(count)), offset, parent, true);
+        self.fields[index].size = count;
+        self.fields[index].value = text;
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push((name.to_string(), Value::Other));
+        }
+        Ok(end)
+    }
+    fn decode_field(&mut self, name: &str, kind: &FieldType, offset: u64, parent: usize, endian: Endian) -> Result<u64, TemplateError> {
+        match kind {
+            FieldType::String(count) | FieldType::Bytes(count) => {
+                let count = self.resolve(count)?;
+                self.decode_sequence(name, kind, count, offset, parent)
+            }
+            FieldType::Array(item, count) => {
+                let count = self.resolve(count)?;
+                if count > MAX_ARRAY_ITEMS {
+                    return Err(TemplateError::TooManyItems(name.to_string()));
+                }
+                let index = self.push(name, kind.type_name(Some(count)), offset, parent, false);
+                let mut pos = offset;
+                for i in 0..count {
+                    let item_name = format!("[{i}]");
+                    pos = self.decode_field(&item_name, item, pos, index, endian).map_err(|e| match e {
+                        TemplateError::OutOfBounds(n) if n.starts_with('[') => TemplateError::OutOfBounds(format!("{name}{n}")),
+                        e => e,
+                    })?;
+                }
+                self.fields[index].size = pos - offset;
+                self.fields[index].value = format!("{count} items");
+                Ok(pos)
+            }
+            FieldType::Struct(template) => {
+                let index = self.push(name, kind.type_name(None), offset, parent, false);
+                let end = self.decode_struct(template, offset, index)?;
+                self.fields[index].size = end - offset;
+                Ok(end)
+            }
+            _ => self.decode_primitive(name, kind, offset, parent, endian),
+        }
+    }
+}
+
+/// Decodes the fields of `template` starting from `offset`. The result is a flat list of fields in pre-order
+/// (every field is followed by its children); the first entry describes the template itself.
+pub(super) fn decode<T: BufferAccess>(template: &Template, buffer: &mut Buffer<T>, offset: u64) -> Result<Vec<TemplateField>, TemplateError> {
+    let mut decoder = Decoder {
+        buffer,
+        fields: vec![TemplateField {
+            name: template.name.clone(),
+            type_name: template.name.clone(),
+            offset,
+            size: 0,
+            value: String::new(),
+            depth: 0,
+            index: 0,
+            parent: None,
+            is_leaf: false,
+        }],
+        scopes: Vec::new(),
+    };
+    let end = decoder.decode_struct(template, offset, 0)?;
+    decoder.fields[0].size = end - offset;
+    Ok(decoder.fields)
+}
+
+/// Builds the intervals (one for every non-empty leaf field) used to color the decoded fields in the buffer view.
+pub(super) fn intervals(fields: &[TemplateField]) -> Vec<Interval> {
+    let mut result = Vec::new();
+    for field in fields.iter().filter(|f| f.is_leaf && f.size > 0) {
+        let attr = CharAttribute::with_fore_color(FIELD_COLORS[result.len() % FIELD_COLORS.len()]);
+        // array items are named "[index]" - the name of the array is added in front of it
+        let name = match field.parent {
+            Some(parent) if field.name.starts_with('[') => format!("{}{}", fields[parent].name, field.name),
+            _ => field.name.clone(),
+        };
+        result.push(Interval::new(field.offset, field.size, attr, &name));
+    }
+    result
+}
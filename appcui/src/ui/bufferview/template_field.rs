@@ -0,0 +1,148 @@
+use crate::graphics::TextAlignment;
+use crate::system::Handle;
+use crate::ui::components::listitem::formats::NumericFormat;
+use crate::ui::components::listitem::{ListItem, RenderMethod};
+use crate::ui::components::Column;
+use crate::ui::treeview::{Item, TreeView};
+use std::cmp::Ordering;
+
+/// A field decoded by [`BufferView::apply_template`](super::BufferView::apply_template).
+///
+/// `TemplateField` implements [`ListItem`] so that the decoded structure can be displayed in a
+/// [`TreeView`] (the structure inspector) via [`TreeView::set_template_fields`].
+#[derive(Clone, Debug)]
+pub struct TemplateField {
+    pub(super) name: String,
+    pub(super) type_name: String,
+    pub(super) offset: u64,
+    pub(super) size: u64,
+    pub(super) value: String,
+    pub(super) depth: u16,
+    pub(super) index: usize,
+    pub(super) parent: Option<usize>,
+    pub(super) is_leaf: bool,
+}
+
+impl TemplateField {
+    /// Returns the name of the field (array items are named `[index]`).
+    #[inline(always)]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Returns the type of the field (e.g. `u32`, `char[16]` or the name of a structure).
+    #[inline(always)]
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+    /// Returns the offset (in the buffer) of the first byte of the field.
+    #[inline(always)]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+    /// Returns the size of the field in bytes.
+    #[inline(always)]
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+    /// Returns the decoded value of the field as text (empty for structures).
+    #[inline(always)]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+    /// Returns the nesting level of the field (0 for the template itself).
+    #[inline(always)]
+    pub fn depth(&self) -> u16 {
+        self.depth
+    }
+    /// Returns the index of the field in the list returned by [`BufferView::template_fields`](super::BufferView::template_fields).
+    #[inline(always)]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    /// Returns the index of the structure or array that contains this field, or `None` for the template itself.
+    #[inline(always)]
+    pub fn parent(&self) -> Option<usize> {
+        self.parent
+    }
+    #[inline(always)]
+    pub(super) fn contains(&self, pos: u64) -> bool {
+        pos >= self.offset && pos - self.offset < self.size
+    }
+}
+
+impl ListItem for TemplateField {
+    fn columns_count() -> u16 {
+        5
+    }
+    fn column(index: u16) -> Column {
+        match index {
+            0 => Column::new("&Name", 32, TextAlignment::Left),
+            1 => Column::new("&Type", 12, TextAlignment::Left),
+            2 => Column::new("&Offset", 10, TextAlignment::Right),
+            3 => Column::new("&Size", 8, TextAlignment::Right),
+            _ => Column::new("&Value", 30, TextAlignment::Left),
+        }
+    }
+    fn render_method(&'_ self, column_index: u16) -> Option<RenderMethod<'_>> {
+        match column_index {
+            0 => Some(RenderMethod::Text(&self.name)),
+            1 => Some(RenderMethod::Text(&self.type_name)),
+            2 => Some(RenderMethod::UInt64(self.offset, NumericFormat::Hex)),
+            3 => Some(RenderMethod::UInt64(self.size, NumericFormat::Normal)),
+            4 => Some(RenderMethod::Text(&self.value)),
+            _ => None,
+        }
+    }
+    fn compare(&self, other: &Self, column_index: u16) -> Ordering {
+        match column_index {
+            0 => self.name.cmp(&other.name),
+            1 => self.type_name.cmp(&other.type_name),
+            3 => self.size.cmp(&other.size),
+            4 => self.value.cmp(&other.value),
+            _ => self.offset.cmp(&other.offset),
+        }
+    }
+    fn matches(&self, text: &str) -> bool {
+        self.name.contains(text) || self.value.contains(text)
+    }
+}
+
+impl TreeView<TemplateField> {
+    /// Replaces the content of the tree view with the fields decoded by [`BufferView::apply_template`](super::BufferView::apply_template)
+    /// (as returned by [`BufferView::template_fields`](super::BufferView::template_fields)).
+    pub fn set_template_fields(&mut self, fields: &[TemplateField]) {
+        self.clear();
+        let mut handles: Vec<Handle<Item<TemplateField>>> = Vec::with_capacity(fields.len());
+        for field in fields.iter() {
+            let h = match field.parent.and_then(|p| handles.get(p)) {
+                Some(parent) => self.add_to_parent(field.clone(), *parent),
+                None => self.add(field.clone()),
+            };
+            handles.push(h);
+        }
+    }
+    /// Moves the cursor to the innermost field that contains the byte from `offset` (the parents of that field are expanded if needed).
+    /// Returns `false` if no field contains that byte.
+    pub fn move_cursor_to_offset(&mut self, offset: u64) -> bool {
+        let mut found = None;
+        let mut level = self.root_items().to_vec();
+        while let Some(h) = level.iter().copied().find(|h| self.item(*h).map(|i| i.value().contains(offset)) == Some(true)) {
+            found = Some(h);
+            level = self.item(h).map(|i| i.children().to_vec()).unwrap_or_default();
+        }
+        let Some(handle) = found else {
+            return false;
+        };
+        // all ancestors must be expanded for the item to be visible
+        let mut parents = Vec::new();
+        let mut p = self.item(handle).and_then(|i| i.parent());
+        while let Some(h) = p {
+            parents.push(h);
+            p = self.item(h).and_then(|i| i.parent());
+        }
+        for h in parents.into_iter().rev() {
+            self.expand_item(h, false);
+        }
+        self.move_cursor_to(handle)
+    }
+}
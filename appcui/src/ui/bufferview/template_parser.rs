@@ -0,0 +1,243 @@
+use super::template::Field;
+use super::{Comparison, Condition, Count, Endian, FieldType, Template, TemplateError};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(i128),
+    Symbol(&'static str),
+}
+
+const SYMBOLS: [&str; 14] = ["==", "!=", "<=", ">=", "<", ">", "&", "{", "}", "[", "]", "(", ")", ";"];
+
+fn tokenize(text: &str) -> Result<Vec<(Token, u32)>, TemplateError> {
+    let bytes = text.as_bytes();
+    let mut tokens = Vec::new();
+    let mut line = 1u32;
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let b = bytes[pos];
+        if b == b'\n' {
+            line += 1;
+            pos += 1;
+        } else if b.is_ascii_whitespace() {
+            pos += 1;
+        } else if text[pos..].starts_with("//") {
+            while pos < bytes.len() && bytes[pos] != b'\n' {
+                pos += 1;
+            }
+        } else if b.is_ascii_alphabetic() || b == b'_' {
+            let start = pos;
+            while pos < bytes.len() && (bytes[pos].is_ascii_alphanumeric() || bytes[pos] == b'_') {
+                pos += 1;
+            }
+            tokens.push((Token::Ident(text[start..pos].to_string()), line));
+        } else if b.is_ascii_digit() || (b == b'-' && pos + 1 < bytes.len() && bytes[pos + 1].is_ascii_digit()) {
+            let start = pos;
+            pos += 1;
+            while pos < bytes.len() && bytes[pos].is_ascii_alphanumeric() {
+                pos += 1;
+            }
+            let word = &text[start..pos];
+            let (negative, digits) = match word.strip_prefix('-') {
+                Some(d) => (true, d),
+                None => (false, word),
+            };
+            let value = match digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+                Some(hex) => i128::from_str_radix(hex, 16),
+                None => digits.parse::<i128>(),
+            }
+            .map_err(|_| TemplateError::Syntax(line, format!("invalid number '{word}'")))?;
+            tokens.push((Token::Number(if negative { -value } else { value }), line));
+        } else if let Some(symbol) = SYMBOLS.iter().find(|s| text[pos..].starts_with(**s)) {
+            tokens.push((Token::Symbol(symbol), line));
+            pos += symbol.len();
+        } else {
+            let ch = text[pos..].chars().next().unwrap_or('?');
+            return Err(TemplateError::Syntax(line, format!("unexpected character '{ch}'")));
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, u32)>,
+    pos: usize,
+    endian: Endian,
+    structs: Vec<Template>,
+}
+
+impl Parser {
+    fn line(&self) -> u32 {
+        self.tokens
+            .get(self.pos)
+            .or_else(|| self.tokens.last())
+            .map(|(_, line)| *line)
+            .unwrap_or(1)
+    }
+    fn error<R>(&self, message: &str) -> Result<R, TemplateError> {
+        Err(TemplateError::Syntax(self.line(), message.to_string()))
+    }
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).map(|(t, _)| t.clone());
+        if token.is_some() {
+            self.pos += 1;
+        }
+        token
+    }
+    fn is_symbol(&self, symbol: &str) -> bool {
+        matches!(self.peek(), Some(Token::Symbol(s)) if *s == symbol)
+    }
+    fn expect_symbol(&mut self, symbol: &str) -> Result<(), TemplateError> {
+        if self.is_symbol(symbol) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            self.error(&format!("expecting '{symbol}'"))
+        }
+    }
+    fn expect_ident(&mut self, what: &str) -> Result<String, TemplateError> {
+        match self.peek() {
+            Some(Token::Ident(name)) => {
+                let name = name.clone();
+                self.pos += 1;
+                Ok(name)
+            }
+            _ => self.error(&format!("expecting {what}")),
+        }
+    }
+    fn parse_endian(&mut self) -> Result<(), TemplateError> {
+        self.endian = match self.expect_ident("'little' or 'big'")?.as_str() {
+            "little" => Endian::Little,
+            "big" => Endian::Big,
+            _ => return self.error("expecting 'little' or 'big'"),
+        };
+        self.expect_symbol(";")
+    }
+    fn parse_struct(&mut self) -> Result<(), TemplateError> {
+        let name = self.expect_ident("a structure name")?;
+        if self.structs.iter().any(|s| s.name == name) {
+            return self.error(&format!("structure '{name}' is already defined"));
+        }
+        self.expect_symbol("{")?;
+        let mut template = Template::new(&name).endian(self.endian);
+        let mut conditions = Vec::new();
+        self.parse_block(&mut template.fields, &mut conditions)?;
+        self.structs.push(template);
+        Ok(())
+    }
+    // parses statements until the closing '}' (that is also consumed)
+    fn parse_block(&mut self, fields: &mut Vec<Field>, conditions: &mut Vec<Condition>) -> Result<(), TemplateError> {
+        loop {
+            if self.is_symbol("}") {
+                self.pos += 1;
+                return Ok(());
+            }
+            if self.peek().is_none() {
+                return self.error("expecting '}'");
+            }
+            self.parse_statement(fields, conditions)?;
+        }
+    }
+    fn parse_statement(&mut self, fields: &mut Vec<Field>, conditions: &mut Vec<Condition>) -> Result<(), TemplateError> {
+        let line = self.line();
+        let type_name = self.expect_ident("a type or 'if'")?;
+        if type_name == "if" {
+            self.expect_symbol("(")?;
+            let field = self.expect_ident("a field name")?;
+            let comparison = match self.next() {
+                Some(Token::Symbol("==")) => Comparison::Equal,
+                Some(Token::Symbol("!=")) => Comparison::NotEqual,
+                Some(Token::Symbol("<")) => Comparison::Less,
+                Some(Token::Symbol("<=")) => Comparison::LessOrEqual,
+                Some(Token::Symbol(">")) => Comparison::Greater,
+                Some(Token::Symbol(">=")) => Comparison::GreaterOrEqual,
+                Some(Token::Symbol("&")) => Comparison::BitsSet,
+                _ => return self.error("expecting a comparison operator"),
+            };
+            let value = match self.next() {
+                Some(Token::Number(value)) => value,
+                _ => return self.error("expecting a number"),
+            };
+            self.expect_symbol(")")?;
+            conditions.push(Condition::new(&field, comparison, value));
+            if self.is_symbol("{") {
+                self.pos += 1;
+                self.parse_block(fields, conditions)?;
+            } else {
+                self.parse_statement(fields, conditions)?;
+            }
+            conditions.pop();
+            return Ok(());
+        }
+        let name = self.expect_ident("a field name")?;
+        let count = if self.is_symbol("[") {
+            self.pos += 1;
+            let count = match self.next() {
+                Some(Token::Number(value)) if value >= 0 && value <= u64::MAX as i128 => Count::Fixed(value as u64),
+                Some(Token::Ident(field)) => Count::Field(field),
+                _ => return self.error("expecting a number or a field name"),
+            };
+            self.expect_symbol("]")?;
+            Some(count)
+        } else {
+            None
+        };
+        self.expect_symbol(";")?;
+        let kind = match type_name.as_str() {
+            "u8" => FieldType::U8,
+            "u16" => FieldType::U16,
+            "u32" => FieldType::U32,
+            "u64" => FieldType::U64,
+            "i8" => FieldType::I8,
+            "i16" => FieldType::I16,
+            "i32" => FieldType::I32,
+            "i64" => FieldType::I64,
+            "f32" => FieldType::F32,
+            "f64" => FieldType::F64,
+            "char" => FieldType::String(Count::Fixed(1)),
+            _ => match self.structs.iter().find(|s| s.name == type_name) {
+                Some(s) => FieldType::Struct(s.clone()),
+                None => return Err(TemplateError::Syntax(line, format!("unknown type '{type_name}'"))),
+            },
+        };
+        let kind = match (kind, count) {
+            (FieldType::String(_), Some(count)) => FieldType::String(count),
+            (FieldType::U8, Some(count)) => FieldType::Bytes(count),
+            (kind, Some(count)) => FieldType::Array(Box::new(kind), count),
+            (kind, None) => kind,
+        };
+        fields.push(Field {
+            name,
+            kind,
+            conditions: conditions.clone(),
+        });
+        Ok(())
+    }
+}
+
+pub(super) fn parse(text: &str) -> Result<Template, TemplateError> {
+    let mut parser = Parser {
+        tokens: tokenize(text)?,
+        pos: 0,
+        endian: Endian::Little,
+        structs: Vec::new(),
+    };
+    while let Some(token) = parser.next() {
+        match token {
+            Token::Ident(word) if word == "endian" => parser.parse_endian()?,
+            Token::Ident(word) if word == "struct" => parser.parse_struct()?,
+            _ => {
+                parser.pos -= 1;
+                return parser.error("expecting 'struct' or 'endian'");
+            }
+        }
+    }
+    match parser.structs.pop() {
+        Some(template) => Ok(template),
+        None => Err(TemplateError::Syntax(1, "no structure was defined".to_string())),
+    }
+}
@@ -6,7 +6,8 @@ use super::bufferview::BufferView;
 use super::search_parser::{parse, Error};
 use super::{
     ColumnsCount, DataRepresentationFormat, Endian, Flags, FloatFormat, HexFormat, IntFormat,
-    Interval, OffsetFormat, UIntFormat, Codepage,
+    Interval, OffsetFormat, UIntFormat, Codepage, Comparison, Condition, Count, FieldType, Template,
+    TemplateError, TemplateField, FileBuffer, DataInspector, InspectorType,
 };
 use super::format::ValidateResult;
 use super::output_buffer::OutputBuffer;
@@ -2375,10 +2376,10 @@ fn check_bufferview_resize_buffer_with_commands() {
         CheckHash(0x33E7D9CFB22E16E8)
         Key.Pressed(F4)
         Paint('4. Resize bigger once')
-        CheckHash(0x192587547D66F859)
+        CheckHash(0x2C0782616D9E43B9)
         Key.Pressed(F4)
         Paint('5. Resize bigger twice')
-        CheckHash(0x58A114604C574F67)
+        CheckHash(0xDCD9C22416F71FA7)
     ";
 
     #[Window(events=CommandBarEvents,commands:A+B, internal:true)]
@@ -2445,10 +2446,10 @@ fn check_bufferview_insert_and_overwrite_bytes_with_commands() {
         CheckHash(0xB79FD1E5D3F89AFC)
         Key.Pressed(F3)
         Paint('3. Insert bytes')
-        CheckHash(0x6A4AB3D62B9B8ED1)
+        CheckHash(0xDFAA67527433D62D)
         Key.Pressed(F4)
         Paint('4. Overwrite bytes')
-        CheckHash(0xFA4F3E2E8D8F1F85)
+        CheckHash(0x97E53A744FFC7029)
     ";
 
     #[Window(events=CommandBarEvents,commands:A+B, internal:true)]
@@ -2510,15 +2511,15 @@ fn check_bufferview_bin_edit_with_tab_and_right() {
         CheckHash(0x6D1403D1B65FE07F)
         Key.TypeText('10101010')
         Paint('2. First byte fully written')
-        CheckHash(0x81D9CF40AEBAA9AF)
+        CheckHash(0xD36B68378CB3E27F)
         Key.TypeText('1111')
         Key.Pressed(Tab)
         Paint('3. Second byte committed with Tab')
-        CheckHash(0xDC7F7AC34C9DA3E6)
+        CheckHash(0x4DC01955DE09414E)
         Key.TypeText('0011')
         Key.Pressed(Right)
         Paint('4. Third byte committed with Right')
-        CheckHash(0x83EE8CC0DBECCCCF)
+        CheckHash(0xAF56D02E1025E433)
     ";
     let mut a = App::debug(60, 15, script).build().unwrap();
     let mut w = window!("Test,a:c,w:60,h:12,flags: Sizeable");
@@ -2535,15 +2536,15 @@ fn check_bufferview_oct_edit_with_tab_and_right() {
         CheckHash(0x48418EB0061457AC)
         Key.TypeText('377')
         Paint('2. First byte fully written')
-        CheckHash(0x975FEBE0425ADDA7)
+        CheckHash(0xF3D650A5DEBD6FF8)
         Key.TypeText('12')
         Key.Pressed(Tab)
         Paint('3. Second byte committed with Tab')
-        CheckHash(0xF526FA131AE10C4F)
+        CheckHash(0xF838795536281BE3)
         Key.TypeText('7')
         Key.Pressed(Right)
         Paint('4. Third byte committed with Right')
-        CheckHash(0x9303DEA38DA4498D)
+        CheckHash(0xDFD06F37EA19843E)
     ";
     let mut a = App::debug(60, 15, script).build().unwrap();
     let mut w = window!("Test,a:c,w:60,h:12,flags: Sizeable");
@@ -2561,15 +2562,15 @@ fn check_bufferview_uint_edit_with_tab_enter_and_right() {
         Key.TypeText('255')
         Key.Pressed(Tab)
         Paint('2. First byte committed with Tab')
-        CheckHash(0x7F83D904B7084BB8)
+        CheckHash(0x640653575992A127)
         Key.TypeText('42')
         Key.Pressed(Enter)
         Paint('3. Second byte committed with Enter')
-        CheckHash(0x9BAE79A8E449BF65)
+        CheckHash(0x5C38CBA69280FC8D)
         Key.TypeText('7')
         Key.Pressed(Right)
         Paint('4. Third byte committed with Right')
-        CheckHash(0xA05BBB3ABA4EE57B)
+        CheckHash(0x65DF22F5547DF80C)
     ";
     let mut a = App::debug(60, 15, script).build().unwrap();
     let mut w = window!("Test,a:c,w:60,h:12,flags: Sizeable");
@@ -2587,15 +2588,15 @@ fn check_bufferview_int_edit_with_tab_enter_and_right() {
         Key.TypeText('-1')
         Key.Pressed(Tab)
         Paint('2. First byte committed with Tab')
-        CheckHash(0x21403C761E4D6061)
+        CheckHash(0x94981AC5179970B5)
         Key.TypeText('12')
         Key.Pressed(Enter)
         Paint('3. Second byte committed with Enter')
-        CheckHash(0xE0F2C458B15A4FA9)
+        CheckHash(0x7C40FEE150B291F9)
         Key.TypeText('-7')
         Key.Pressed(Right)
         Paint('4. Third byte committed with Right')
-        CheckHash(0x7CAADAA48536E9B8)
+        CheckHash(0xB68A7FADF701CC38)
     ";
     let mut a = App::debug(60, 15, script).build().unwrap();
     let mut w = window!("Test,a:c,w:60,h:12,flags: Sizeable");
@@ -2613,15 +2614,15 @@ fn check_bufferview_float_edit_with_tab_enter_and_right() {
         Key.TypeText('+240.000')
         Key.Pressed(Tab)
         Paint('2. First byte committed with Tab')
-        CheckHash(0x62108A34EE1C9B25)
+        CheckHash(0x78AF5A3BF89D181)
         Key.TypeText('+0.500')
         Key.Pressed(Enter)
         Paint('3. Second byte committed with Enter')
-        CheckHash(0x7FD13B93098BD4DE)
+        CheckHash(0x811969838CEC941E)
         Key.TypeText('+1.500')
         Key.Pressed(Right)
         Paint('4. Third byte committed with Right')
-        CheckHash(0xEA681B51D283EA4E)
+        CheckHash(0x9A3351DFF5870BEE)
     ";
     let mut a = App::debug(60, 15, script).build().unwrap();
     let mut w = window!("Test,a:c,w:60,h:12,flags: Sizeable");
@@ -3318,10 +3319,10 @@ fn check_bufferview_search() {
         CheckHash(0x6F386642B4BD3242)
         Key.TypeText('hex:05')
         Paint('2. Search for 0x05')
-        CheckHash(0x7FB0D059F32AD815)
+        CheckHash(0x48E1D965508CE34D)
         Key.Pressed(Enter)
         Paint('3. Find next match')
-        CheckHash(0x6A4FB0875345E87E)
+        CheckHash(0x8888CA8CC5FE386A)
         Key.Pressed(Escape)
         Paint('4. Clear search')
         CheckHash(0x97CFA8E09EF9879D)
@@ -3368,8 +3369,8 @@ fn check_bufferview_search_bar_mouse_text() {
         Paint('2. Search for Hello')
         CheckHash(0xCA141C0D46AD4D79)
         Key.Pressed(Enter)
-        Paint('3. Find next Hello match')
-        CheckHash(0x37A4B939ABC9114B)
+        Paint('3. Find next wraps to the same Hello match')
+        CheckHash(0xCA141C0D46AD4D79)
         Key.Pressed(Escape)
         Paint('4. Clear search')
         CheckHash(0x3116CE71CD75C896)
@@ -3395,8 +3396,8 @@ fn check_bufferview_search_bar_backspace_then_enter() {
         Paint('3. Backspace twice')
         CheckHash(0x7E4B024AD0043F16)
         Key.Pressed(Enter)
-        Paint('4. Find next match')
-        CheckHash(0x5984B53AE5B978F8)
+        Paint('4. Find next wraps to the same match')
+        CheckHash(0x7E4B024AD0043F16)
     ";
     let mut a = App::debug(60, 15, script).build().unwrap();
     let mut w = window!("Test,a:c,w:60,h:12,flags: Sizeable");
@@ -3414,10 +3415,10 @@ fn check_bufferview_search_without_search_bar_ignored() {
         Mouse.Click(3,12,left)
         Key.TypeText('text:Hello')
         Paint('2. Search ignored without search bar')
-        CheckHash(0x32434095719B8557)
+        CheckHash(0xDCCCF409488E6D63)
         Key.Pressed(Enter)
         Paint('3. Enter ignored without search bar')
-        CheckHash(0x32434095719B8557)
+        CheckHash(0xDCCCF409488E6D63)
     ";
     let mut a = App::debug(60, 15, script).build().unwrap();
     let mut w = window!("Test,a:c,w:60,h:12,flags: Sizeable");
@@ -3677,7 +3678,7 @@ fn check_bufferview_hex_dword_edit_backspace_enter() {
         CheckHash(0x1FB03E4D7715EFFC)
         Key.Pressed(Enter)
         Paint('4. Enter commits DWORD from first two digits')
-        CheckHash(0xE8CEE275B66EAE42)
+        CheckHash(0xDDA9D21D4812D586)
     ";
     let mut a = App::debug(60, 15, script).build().unwrap();
     let mut w = window!("Test,a:c,w:60,h:12,flags: Sizeable");
@@ -4069,3 +4070,744 @@ fn check_bufferview_on_selection_changed_event() {
     a.add_window(MyWin::new());
     a.run();
 }
+
+const TEMPLATE_HEADER: &str = "
+    // a header with a variable number of entries
+    struct Entry {
+        u8 kind;
+        u16 size;
+    }
+    struct Header {
+        char magic[4];
+        u16 count;
+        u8 flags;
+        Entry entries[count];
+        if (flags & 1) u32 crc;
+        if (count > 5) {
+            u8 extra[4];
+        }
+    }
+";
+
+fn test_template_data() -> Vec<u8> {
+    let mut data = b"HDR\0".to_vec();
+    data.extend_from_slice(&[0x02, 0x00, 0x01, 0x07, 0x10, 0x00, 0x08, 0x20, 0x00, 0x78, 0x56, 0x34, 0x12]);
+    data.resize(32, 0xFF);
+    data
+}
+
+#[test]
+fn template_parse_and_apply() {
+    let _a = App::debug(60, 15, "Paint.Enable(false)").build().unwrap();
+    let mut bv = make_bufferview(test_template_data());
+    let t = Template::parse(TEMPLATE_HEADER).unwrap();
+    assert_eq!(t.name(), "Header");
+    assert!(bv.apply_template(&t, 0).is_ok());
+    let fields: Vec<(&str, &str, u64, u64, &str)> = bv
+        .template_fields()
+        .iter()
+        .map(|f| (f.name(), f.type_name(), f.offset(), f.size(), f.value()))
+        .collect();
+    assert_eq!(
+        fields,
+        vec![
+            ("Header", "Header", 0, 17, ""),
+            ("magic", "char[4]", 0, 4, "\"HDR\""),
+            ("count", "u16", 4, 2, "2 (0x2)"),
+            ("flags", "u8", 6, 1, "1 (0x1)"),
+            ("entries", "Entry[2]", 7, 6, "2 items"),
+            ("[0]", "Entry", 7, 3, ""),
+            ("kind", "u8", 7, 1, "7 (0x7)"),
+            ("size", "u16", 8, 2, "16 (0x10)"),
+            ("[1]", "Entry", 10, 3, ""),
+            ("kind", "u8", 10, 1, "8 (0x8)"),
+            ("size", "u16", 11, 2, "32 (0x20)"),
+            ("crc", "u32", 13, 4, "305419896 (0x12345678)"),
+        ]
+    );
+    let f = bv.template_field_at(12).unwrap();
+    assert_eq!((f.name(), f.index(), f.parent(), f.depth()), ("size", 10, Some(8), 3));
+    assert!(bv.template_field_at(17).is_none());
+    // every decoded value is colored (and named) as an interval
+    assert_eq!(bv.interval_name_at(0), Some("magic"));
+    assert_eq!(bv.interval_name_at(8), Some("size"));
+    assert_eq!(bv.interval_name_at(14), Some("crc"));
+    assert_eq!(bv.interval_name_at(17), None);
+    // go to a field
+    assert!(bv.goto_template_field(4));
+    assert_eq!(bv.current_pos(), 7);
+    assert_eq!(bv.selection(), Some((7, 13)));
+    assert!(!bv.goto_template_field(100));
+    bv.clear_template();
+    assert!(bv.template_fields().is_empty());
+    assert_eq!(bv.interval_name_at(0), None);
+}
+
+#[test]
+fn template_builder_with_big_endian_and_conditions() {
+    let _a = App::debug(60, 15, "Paint.Enable(false)").build().unwrap();
+    let mut bv = make_bufferview(vec![0x00, 0x02, 0x12, 0x34, 0xFF, 0xFE, 0x80, 0x41, 0x42, 0x00, 0x43]);
+    let t = Template::new("Data")
+        .endian(Endian::Big)
+        .field("count", FieldType::U16)
+        .field("values", FieldType::array(FieldType::I16, "count"))
+        .field_if("missing", FieldType::U32, Condition::new("count", Comparison::Greater, 2))
+        .field_if("sign", FieldType::I8, Condition::new("count", Comparison::Equal, 2))
+        .field("name", FieldType::String(Count::Fixed(4)));
+    assert!(bv.apply_template(&t, 0).is_ok());
+    let values: Vec<(&str, &str)> = bv.template_fields().iter().map(|f| (f.name(), f.value())).collect();
+    assert_eq!(
+        values,
+        vec![
+            ("Data", ""),
+            ("count", "2 (0x2)"),
+            ("values", "2 items"),
+            ("[0]", "4660"),
+            ("[1]", "-2"),
+            ("sign", "-128"),
+            ("name", "\"AB\""),
+        ]
+    );
+    assert_eq!(bv.interval_name_at(5), Some("values[1]"));
+    // apply the same template from another offset
+    assert_eq!(bv.apply_template(&t, 4), Err(TemplateError::OutOfBounds("values[2]".to_string())));
+}
+
+#[test]
+fn template_errors() {
+    let _a = App::debug(60, 15, "Paint.Enable(false)").build().unwrap();
+    assert_eq!(
+        Template::parse("struct A {\n  u8 x;\n  u24 y;\n}").err(),
+        Some(TemplateError::Syntax(3, "unknown type 'u24'".to_string()))
+    );
+    assert_eq!(
+        Template::parse("struct A {\n  u8 x\n}").err(),
+        Some(TemplateError::Syntax(3, "expecting ';'".to_string()))
+    );
+    assert_eq!(
+        Template::parse("struct A { u8 x; }\nstruct A { u8 y; }").err(),
+        Some(TemplateError::Syntax(2, "structure 'A' is already defined".to_string()))
+    );
+    assert_eq!(
+        Template::parse("struct A { if (x ~ 1) u8 y; }").err(),
+        Some(TemplateError::Syntax(1, "unexpected character '~'".to_string()))
+    );
+    assert_eq!(Template::parse("// nothing").err(), Some(TemplateError::Syntax(1, "no structure was defined".to_string())));
+    assert_eq!(Template::parse("endian big; struct B { u16 v; }").unwrap().name(), "B");
+
+    let mut bv = make_bufferview(test_template_data());
+    let t = Template::parse(TEMPLATE_HEADER).unwrap();
+    assert!(bv.apply_template(&t, 0).is_ok());
+    // errors leave the previously applied template unchanged
+    assert_eq!(bv.apply_template(&t, 20), Err(TemplateError::OutOfBounds("size".to_string())));
+    assert_eq!(bv.template_fields().len(), 12);
+    let t = Template::parse("struct A { u8 data[size]; }").unwrap();
+    assert_eq!(bv.apply_template(&t, 0), Err(TemplateError::UnknownField("size".to_string())));
+    let t = Template::parse("struct A { f32 size; u8 data[size]; }").unwrap();
+    assert_eq!(bv.apply_template(&t, 0), Err(TemplateError::InvalidValue("size".to_string())));
+    let t = Template::parse("struct A { i8 size; u8 data[size]; }").unwrap();
+    assert_eq!(bv.apply_template(&t, 31), Err(TemplateError::InvalidValue("size".to_string())));
+    let t = Template::parse("struct A { u32 n; u16 v[n]; }").unwrap();
+    assert_eq!(bv.apply_template(&t, 17), Err(TemplateError::TooManyItems("v".to_string())));
+    assert_eq!(format!("{}", TemplateError::OutOfBounds("crc".to_string())), "field 'crc' exceeds the end of the buffer");
+}
+
+#[test]
+fn check_template_structure_inspector_sync() {
+    #[Window(events = BufferViewEvents<Vec<u8>>+TreeViewEvents<TemplateField>, internal = true)]
+    struct MyWin {
+        bv: Handle<BufferView<Vec<u8>>>,
+        tv: Handle<TreeView<TemplateField>>,
+    }
+    impl MyWin {
+        fn new() -> Self {
+            let mut w = Self {
+                base: window!("Test,d:f"),
+                bv: Handle::None,
+                tv: Handle::None,
+            };
+            let mut bv = BufferView::with_buffer(test_template_data(), layout!("l:0,t:0,r:0,h:6"), Flags::ShowAddress | Flags::ShowIntervalNames);
+            bv.set_columns_count(ColumnsCount::Fixed(8));
+            bv.set_data_representation_format(DataRepresentationFormat::Hex(HexFormat::Byte));
+            bv.set_offset_format(OffsetFormat::Hex);
+            bv.set_interval_name_width(8);
+            bv.apply_template(&Template::parse(TEMPLATE_HEADER).unwrap(), 0).unwrap();
+            let mut tv = TreeView::new(layout!("l:0,t:6,r:0,b:0"), treeview::Flags::None);
+            tv.set_template_fields(bv.template_fields());
+            w.bv = w.add(bv);
+            w.tv = w.add(tv);
+            w
+        }
+    }
+    impl BufferViewEvents<Vec<u8>> for MyWin {
+        fn on_current_pos_changed(&mut self, handle: Handle<BufferView<Vec<u8>>>) -> EventProcessStatus {
+            let Some(pos) = self.control(handle).map(|bv| bv.current_pos()) else {
+                return EventProcessStatus::Ignored;
+            };
+            let h = self.tv;
+            let name = self.control_mut(h).and_then(|tv| {
+                tv.move_cursor_to_offset(pos);
+                tv.current_item().map(|i| i.value().name().to_string())
+            });
+            self.set_title(&format!("pos:{} field:{}", pos, name.unwrap_or_default()));
+            EventProcessStatus::Processed
+        }
+    }
+    impl TreeViewEvents<TemplateField> for MyWin {
+        fn on_current_item_changed(&mut self, handle: Handle<TreeView<TemplateField>>, item: Handle<treeview::Item<TemplateField>>) -> EventProcessStatus {
+            let Some(index) = self.control(handle).and_then(|tv| tv.item(item)).map(|i| i.value().index()) else {
+                return EventProcessStatus::Ignored;
+            };
+            let h = self.bv;
+            let selection = self.control_mut(h).and_then(|bv| {
+                bv.goto_template_field(index);
+                bv.selection()
+            });
+            self.set_title(&format!("field:{} selection:{:?}", index, selection));
+            EventProcessStatus::Processed
+        }
+    }
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Decoded fields are colored, inspector is populated')
+        CheckHash(0x9F6D24158F375B2F)
+        Key.Pressed(Down,4)
+        Paint('2. Inspector on entries - bytes 7..12 are selected')
+        CheckHash(0x463D376D6F9E49A2)
+        Key.Pressed(Tab)
+        Key.Pressed(Right,5)
+        Paint('3. Cursor on offset 12 - inspector on size (from entries[1])')
+        CheckHash(0x69108FCD673561C1)
+    ";
+    let mut a = App::debug(100, 24, script).build().unwrap();
+    a.add_window(MyWin::new());
+    a.run();
+}
+
+struct TempFile {
+    path: std::path::PathBuf,
+}
+impl TempFile {
+    fn new(name: &str, content: &[u8]) -> Self {
+        let path = std::env::temp_dir().join(format!("appcui_file_buffer_{}_{}.bin", std::process::id(), name));
+        std::fs::write(&path, content).unwrap();
+        Self { path }
+    }
+    fn content(&self) -> Vec<u8> {
+        std::fs::read(&self.path).unwrap()
+    }
+}
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+fn file_buffer_content(fb: &mut FileBuffer) -> Vec<u8> {
+    let mut out = vec![0u8; fb.count() as usize];
+    assert_eq!(fb.read(0, &mut out), out.len());
+    out
+}
+
+#[test]
+fn file_buffer_reads_pages_on_demand() {
+    let data: Vec<u8> = (0..200u32).map(|i| (i * 7) as u8).collect();
+    let tf = TempFile::new("pages", &data);
+    // small pages and a cache of only two pages, to force page evictions
+    let mut fb = FileBuffer::open(&tf.path).unwrap().with_page_size(16).with_cache_pages(2);
+    assert_eq!(fb.count(), 200);
+    assert_eq!(fb.get(0), Some(0));
+    assert_eq!(fb.get(199), Some((199 * 7) as u8));
+    assert_eq!(fb.get(200), None);
+    assert_eq!(fb.get(17), Some(17 * 7));
+    assert_eq!(fb.get(150), Some((150u32 * 7) as u8));
+    assert_eq!(fb.get(1), Some(7));
+    let mut out = [0u8; 40];
+    assert_eq!(fb.read(10, &mut out), 40);
+    assert_eq!(&out[..], &data[10..50]);
+    assert_eq!(fb.read(190, &mut out), 10);
+    assert_eq!(&out[..10], &data[190..]);
+    assert_eq!(file_buffer_content(&mut fb), data);
+    assert!(!fb.is_modified());
+}
+
+#[test]
+fn file_buffer_overlay_and_piece_table() {
+    let tf = TempFile::new("overlay", b"0123456789");
+    let mut fb = FileBuffer::open(&tf.path).unwrap().with_page_size(16);
+    assert!(fb.set(2, b'A'));
+    assert!(fb.is_modified());
+    assert_eq!(file_buffer_content(&mut fb), b"01A3456789");
+    assert!(fb.can_insert_delete());
+    assert!(fb.insert(5, b"xy"));
+    assert!(fb.insert(7, b"z"));
+    assert_eq!(file_buffer_content(&mut fb), b"01A34xyz56789");
+    assert!(fb.delete(0, 2));
+    assert!(fb.delete(9, 100));
+    assert_eq!(file_buffer_content(&mut fb), b"A34xyz567");
+    assert!(fb.set(4, b'Y'));
+    assert!(fb.resize(12, b'-'));
+    assert!(fb.set(10, b'!'));
+    assert_eq!(file_buffer_content(&mut fb), b"A34xYz567-!-");
+    assert!(!fb.insert(100, b"?"));
+    // nothing is written to the file until the changes are committed
+    assert_eq!(tf.content(), b"0123456789");
+    fb.discard_changes();
+    assert!(!fb.is_modified());
+    assert_eq!(file_buffer_content(&mut fb), b"0123456789");
+}
+
+#[test]
+fn file_buffer_commit_and_save_as() {
+    let tf = TempFile::new("commit", b"0123456789");
+    let mut fb = FileBuffer::open(&tf.path).unwrap();
+    // overwrites only - written in place
+    assert!(fb.set(0, b'a'));
+    assert!(fb.set(1, b'b'));
+    assert!(fb.set(8, b'c'));
+    fb.commit().unwrap();
+    assert!(!fb.is_modified());
+    assert_eq!(tf.content(), b"ab234567c9");
+    // insertions and deletions - the file is rewritten
+    assert!(fb.insert(10, b"XYZ"));
+    assert!(fb.delete(2, 3));
+    fb.commit().unwrap();
+    assert_eq!(tf.content(), b"ab567c9XYZ");
+    assert_eq!(file_buffer_content(&mut fb), b"ab567c9XYZ");
+    // save as a new file
+    let copy = TempFile::new("commit_copy", b"");
+    assert!(fb.set(0, b'#'));
+    fb.save_as(&copy.path).unwrap();
+    assert_eq!(copy.content(), b"#b567c9XYZ");
+    assert_eq!(tf.content(), b"ab567c9XYZ");
+    assert_eq!(fb.path(), copy.path.as_path());
+    assert!(!fb.is_modified());
+}
+
+#[test]
+fn file_buffer_read_only() {
+    let tf = TempFile::new("read_only", b"0123");
+    let mut fb = FileBuffer::open_read_only(&tf.path).unwrap();
+    assert!(!fb.can_write());
+    assert!(!fb.can_resize());
+    assert!(!fb.set(0, 1));
+    assert!(!fb.insert(0, b"a"));
+    assert!(!fb.resize(10, 0));
+    assert_eq!(file_buffer_content(&mut fb), b"0123");
+    assert!(FileBuffer::open(std::env::temp_dir().join("appcui_file_buffer_missing.bin")).is_err());
+}
+
+#[test]
+fn file_buffer_in_bufferview() {
+    let mut data = vec![0u8; 100_000];
+    data[70_000..70_004].copy_from_slice(b"FIND");
+    data[10..14].copy_from_slice(b"FIND");
+    let tf = TempFile::new("bufferview", &data);
+    let script = "
+        Paint.Enable(false)
+        Key.Pressed(Tab)
+        Key.TypeText('FIND')
+        Key.Pressed(Enter)
+        Key.Pressed(Enter)
+    ";
+    let mut a = App::debug(80, 20, script).build().unwrap();
+    let mut w = window!("Test,d:f");
+    let mut bv = BufferView::with_buffer(FileBuffer::open(&tf.path).unwrap().with_page_size(4096), layout!("d:f"), Flags::SearchBar);
+    assert!(bv.insert_bytes(0, b"HEAD"));
+    assert!(bv.delete_bytes(4, 2));
+    assert_eq!(bv.bytes_count(), 100_002);
+    let mut out = Vec::new();
+    bv.read_bytes_into_vec(0, 8, &mut out);
+    assert_eq!(out, b"HEAD\0\0\0\0");
+    bv.set_current_pos(100);
+    w.add(bv);
+    a.add_window(w);
+    a.run();
+}
+
+fn bufferview_content<T: BufferAccess + 'static>(bv: &mut BufferView<T>) -> Vec<u8> {
+    let mut out = Vec::new();
+    bv.read_bytes_into_vec(0, bv.bytes_count(), &mut out);
+    out
+}
+
+#[test]
+fn undo_redo_editing_methods() {
+    let _a = App::debug(60, 15, "Paint.Enable(false)").build().unwrap();
+    let mut bv = make_bufferview(b"0123456789".to_vec());
+    assert!(!bv.can_undo());
+    assert!(!bv.is_modified());
+    assert!(bv.overwrite_bytes(2, b"AB"));
+    assert!(bv.insert_bytes(5, b"xyz"));
+    assert!(bv.delete_bytes(0, 1));
+    assert!(bv.fill_buffer(8, 2, b'.'));
+    assert!(bv.resize_buffer(14, b'-'));
+    assert!(bv.resize_buffer(12, 0));
+    assert_eq!(bufferview_content(&mut bv), b"1AB4xyz5..89");
+    assert!(bv.is_modified());
+    // undo everything
+    let mut states = Vec::new();
+    while bv.undo() {
+        states.push(bufferview_content(&mut bv));
+    }
+    assert_eq!(
+        states,
+        vec![
+            b"1AB4xyz5..89--".to_vec(),
+            b"1AB4xyz5..89".to_vec(),
+            b"1AB4xyz56789".to_vec(),
+            b"01AB4xyz56789".to_vec(),
+            b"01AB456789".to_vec(),
+            b"0123456789".to_vec(),
+        ]
+    );
+    assert!(!bv.can_undo());
+    assert!(!bv.is_modified());
+    assert!(bv.modified_ranges().is_empty());
+    // redo two steps
+    assert!(bv.redo());
+    assert!(bv.redo());
+    assert_eq!(bufferview_content(&mut bv), b"01AB4xyz56789");
+    assert_eq!(bv.current_pos(), 5);
+    // a new change drops the redo list
+    assert!(bv.overwrite_bytes(0, b"#"));
+    assert!(!bv.can_redo());
+    assert!(!bv.redo());
+    assert_eq!(bufferview_content(&mut bv), b"#1AB4xyz56789");
+}
+
+#[test]
+fn undo_modified_ranges() {
+    let _a = App::debug(60, 15, "Paint.Enable(false)").build().unwrap();
+    let mut bv = make_bufferview((0..100).collect());
+    assert!(bv.overwrite_bytes(10, &[1, 2, 3]));
+    assert!(bv.overwrite_bytes(50, &[1]));
+    assert_eq!(bv.modified_ranges(), &[10..13, 50..51]);
+    // ranges after the insertion point are shifted
+    assert!(bv.insert_bytes(20, &[0; 5]));
+    assert_eq!(bv.modified_ranges(), &[10..13, 20..25, 55..56]);
+    // an insertion in the middle of a modified range extends it
+    assert!(bv.insert_bytes(11, &[0; 2]));
+    assert_eq!(bv.modified_ranges(), &[10..15, 22..27, 57..58]);
+    // a deletion removes the deleted bytes and marks the byte before the deletion point
+    assert!(bv.delete_bytes(30, 10));
+    assert_eq!(bv.modified_ranges(), &[10..15, 22..27, 29..30, 47..48]);
+    assert!(bv.delete_bytes(12, 14));
+    assert_eq!(bv.modified_ranges(), &[10..13, 15..16, 33..34]);
+    assert!(bv.undo());
+    assert_eq!(bv.modified_ranges(), &[10..15, 22..27, 29..30, 47..48]);
+    // dropped changes (because of the undo limit) are still reported as modified
+    bv.set_undo_limit(1);
+    assert!(bv.undo());
+    assert!(!bv.undo());
+    assert_eq!(bv.modified_ranges(), &[10..15, 22..27, 57..58]);
+    assert!(bv.is_modified());
+    bv.clear_undo_history();
+    assert!(!bv.is_modified());
+    assert!(!bv.can_redo());
+    assert!(bv.modified_ranges().is_empty());
+}
+
+#[test]
+fn undo_size_limit() {
+    let _a = App::debug(60, 15, "Paint.Enable(false)").build().unwrap();
+    let mut bv = make_bufferview(b"0123456789".to_vec());
+    bv.set_undo_size_limit(4);
+    // a fill within the limit can be undone
+    assert!(bv.fill_buffer(0, 4, b'x'));
+    assert_eq!(bufferview_content(&mut bv), b"xxxx456789");
+    assert!(bv.undo());
+    assert_eq!(bufferview_content(&mut bv), b"0123456789");
+    assert!(bv.redo());
+    assert_eq!(bufferview_content(&mut bv), b"xxxx456789");
+    // larger changes are applied but they (and the previous ones) can not be undone
+    assert!(bv.fill_buffer(4, 5, b'y'));
+    assert_eq!(bufferview_content(&mut bv), b"xxxxyyyyy9");
+    assert!(!bv.can_undo());
+    assert_eq!(bv.modified_ranges().len(), 1);
+    assert_eq!(bv.modified_ranges()[0], 0..9);
+    assert!(bv.overwrite_bytes(9, b"z"));
+    assert!(bv.delete_bytes(0, 5));
+    assert_eq!(bufferview_content(&mut bv), b"yyyyz");
+    assert!(!bv.can_undo());
+    assert_eq!(bv.modified_ranges().len(), 1);
+    assert_eq!(bv.modified_ranges()[0], 0..5);
+    assert!(bv.insert_bytes(5, b"0123456789"));
+    assert!(bv.resize_buffer(6, 0));
+    assert_eq!(bufferview_content(&mut bv), b"yyyyz0");
+    assert!(!bv.can_undo() && !bv.can_redo());
+    assert!(bv.is_modified());
+    // a truncation within the limit can be undone
+    assert!(bv.resize_buffer(3, 0));
+    assert!(bv.undo());
+    assert_eq!(bufferview_content(&mut bv), b"yyyyz0");
+}
+
+#[test]
+fn undo_is_disabled_for_read_only_and_reset_on_set_buffer() {
+    let _a = App::debug(60, 15, "Paint.Enable(false)").build().unwrap();
+    let mut bv = make_bufferview(b"0123".to_vec());
+    assert!(bv.overwrite_bytes(0, b"9"));
+    assert!(bv.can_undo());
+    bv.set_buffer(b"abcd".to_vec());
+    assert!(!bv.can_undo());
+    assert!(!bv.is_modified());
+    let mut bv = BufferView::with_buffer(b"0123".to_vec(), layout!("d:f"), Flags::ReadOnly);
+    assert!(!bv.overwrite_bytes(0, b"9"));
+    assert!(!bv.undo());
+    assert!(!bv.is_modified());
+}
+
+#[test]
+fn undo_with_file_buffer() {
+    let tf = TempFile::new("undo", b"0123456789");
+    let _a = App::debug(60, 15, "Paint.Enable(false)").build().unwrap();
+    let mut bv = BufferView::with_buffer(FileBuffer::open(&tf.path).unwrap(), layout!("d:f"), Flags::None);
+    assert!(bv.delete_bytes(2, 3));
+    assert!(bv.insert_bytes(0, b"ab"));
+    assert!(bv.overwrite_bytes(4, b"Z"));
+    assert_eq!(bufferview_content(&mut bv), b"ab01Z6789");
+    assert!(bv.undo() && bv.undo() && bv.undo());
+    assert_eq!(bufferview_content(&mut bv), b"0123456789");
+    assert!(bv.redo());
+    assert_eq!(bufferview_content(&mut bv), b"0156789");
+}
+
+#[test]
+fn check_bufferview_undo_redo_keys() {
+    #[Window(events = BufferViewEvents<Vec<u8>>, internal = true)]
+    struct MyWin {
+        bv_handle: Handle<BufferView<Vec<u8>>>,
+        modified: u32,
+    }
+
+    impl MyWin {
+        fn new() -> Self {
+            let mut w = Self {
+                base: window!("Test,a:c,w:60,h:12,flags: Sizeable"),
+                bv_handle: Handle::None,
+                modified: 0,
+            };
+            w.bv_handle = w.add(make_bufferview(test_buffer_data()));
+            w
+        }
+    }
+
+    impl BufferViewEvents<Vec<u8>> for MyWin {
+        fn on_modified(&mut self, handle: Handle<BufferView<Vec<u8>>>) -> EventProcessStatus {
+            self.modified += 1;
+            let ranges = self
+                .control(handle)
+                .map(|bv| format!("{:?}", bv.modified_ranges()))
+                .unwrap_or_default();
+            let title = format!("M:{} {}", self.modified, ranges);
+            self.set_title(&title);
+            EventProcessStatus::Processed
+        }
+    }
+
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Initial state')
+        CheckHash(0x6F386642B4BD3242)
+        Key.TypeText('AB')
+        Key.Pressed(Right)
+        Key.TypeText('CD')
+        Paint('2. Bytes 0 and 2 modified and highlighted (M:2 [0..1, 2..3])')
+        CheckHash(0xA4E838DE0C60B82)
+        Key.Pressed(Ctrl+Z)
+        Paint('3. Undo byte 2, cursor on 02 (M:3 [0..1])')
+        CheckHash(0x5315ECCF5438908D)
+        Key.Pressed(Ctrl+Z)
+        Paint('4. Undo byte 0, cursor on 00 (M:4 [])')
+        CheckHash(0x7140AC02777806F1)
+        Key.Pressed(Ctrl+Z)
+        Paint('5. Nothing to undo (no event)')
+        CheckHash(0x7140AC02777806F1)
+        Key.Pressed(Ctrl+Y,2)
+        Paint('6. Redo both changes (M:6 [0..1, 2..3])')
+        CheckHash(0x1895E2962B2AD496)
+    ";
+
+    let mut a = App::debug(60, 15, script).build().unwrap();
+    a.add_window(MyWin::new());
+    a.run();
+}
+
+#[test]
+fn inspector_decode_numbers() {
+    let bytes = [0xFE, 0xFF, 0x01, 0x00, 0x00, 0x3C, 0x00, 0x80, 0x00, 0x00];
+    let decode = |t: InspectorType, e: Endian| t.decode(&bytes, e);
+    assert_eq!(decode(InspectorType::U8, Endian::Little).as_deref(), Some("254"));
+    assert_eq!(decode(InspectorType::I8, Endian::Little).as_deref(), Some("-2"));
+    assert_eq!(decode(InspectorType::U16, Endian::Little).as_deref(), Some("65534"));
+    assert_eq!(decode(InspectorType::U16, Endian::Big).as_deref(), Some("65279"));
+    assert_eq!(decode(InspectorType::I16, Endian::Little).as_deref(), Some("-2"));
+    assert_eq!(decode(InspectorType::U32, Endian::Little).as_deref(), Some("131070"));
+    assert_eq!(decode(InspectorType::I32, Endian::Big).as_deref(), Some("-16842496"));
+    assert_eq!(decode(InspectorType::U64, Endian::Little).as_deref(), Some("9223438007552573438"));
+    assert_eq!(decode(InspectorType::I64, Endian::Big).as_deref(), Some("-72337969499078528"));
+    assert_eq!(InspectorType::F16.decode(&[0x00, 0x3C], Endian::Little).as_deref(), Some("1"));
+    assert_eq!(InspectorType::F16.decode(&[0xC0, 0x00], Endian::Big).as_deref(), Some("-2"));
+    assert_eq!(InspectorType::F32.decode(&1.5f32.to_le_bytes(), Endian::Little).as_deref(), Some("1.5"));
+    assert_eq!(InspectorType::F64.decode(&(-0.25f64).to_be_bytes(), Endian::Big).as_deref(), Some("-0.25"));
+    // not enough bytes
+    assert_eq!(InspectorType::U32.decode(&bytes[..3], Endian::Little), None);
+    assert_eq!(InspectorType::U8.decode(&[], Endian::Little), None);
+}
+
+#[test]
+fn inspector_decode_timestamps_guids_and_chars() {
+    let unix = 1_700_000_000u32;
+    assert_eq!(InspectorType::UnixTime32.decode(&unix.to_le_bytes(), Endian::Little).as_deref(), Some("2023-11-14 22:13:20"));
+    assert_eq!(InspectorType::UnixTime64.decode(&(unix as i64).to_be_bytes(), Endian::Big).as_deref(), Some("2023-11-14 22:13:20"));
+    let filetime = 133_444_736_000_000_000u64 + 5;
+    assert_eq!(
+        InspectorType::FileTime.decode(&filetime.to_le_bytes(), Endian::Little).as_deref(),
+        Some("2023-11-14 22:13:20.000000500")
+    );
+    // 2020-05-17 13:45:30
+    let dos = (((2020 - 1980) << 9 | 5 << 5 | 17) << 16) | (13 << 11 | 45 << 5 | 15);
+    assert_eq!(InspectorType::DosDateTime.decode(&(dos as u32).to_le_bytes(), Endian::Little).as_deref(), Some("2020-05-17 13:45:30"));
+    assert_eq!(InspectorType::DosDateTime.decode(&[0, 0, 0, 0], Endian::Little), None);
+    let guid = [0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF];
+    assert_eq!(InspectorType::Guid.decode(&guid, Endian::Little).as_deref(), Some("00112233-4455-6677-8899-AABBCCDDEEFF"));
+    assert_eq!(InspectorType::Guid.decode(&guid, Endian::Big).as_deref(), Some("33221100-5544-7766-8899-AABBCCDDEEFF"));
+    assert_eq!(InspectorType::Utf8.decode("€x".as_bytes(), Endian::Little).as_deref(), Some("€ (U+20AC)"));
+    assert_eq!(InspectorType::Utf8.decode(&[0x0A], Endian::Little).as_deref(), Some("U+000A"));
+    assert_eq!(InspectorType::Utf8.decode(&[0xE2, 0x82], Endian::Little), None);
+    assert_eq!(InspectorType::Utf16.decode(&[0x3D, 0xD8, 0x00, 0xDE], Endian::Little).as_deref(), Some("😀 (U+1F600)"));
+    assert_eq!(InspectorType::Utf16.decode(&[0x00, 0x41], Endian::Big).as_deref(), Some("A (U+0041)"));
+    assert_eq!(InspectorType::ULeb128.decode(&[0xE5, 0x8E, 0x26, 0xFF], Endian::Little).as_deref(), Some("624485"));
+    assert_eq!(InspectorType::SLeb128.decode(&[0xC0, 0xBB, 0x78], Endian::Little).as_deref(), Some("-123456"));
+    assert_eq!(InspectorType::ULeb128.decode(&[0x80, 0x80], Endian::Little), None);
+}
+
+#[test]
+fn inspector_encode_values() {
+    let encode = |t: InspectorType, e: Endian, text: &str| t.encode(text, e);
+    assert_eq!(encode(InspectorType::U8, Endian::Little, "200"), Some(vec![200]));
+    assert_eq!(encode(InspectorType::U8, Endian::Little, "300"), None);
+    assert_eq!(encode(InspectorType::I8, Endian::Little, "-128"), Some(vec![0x80]));
+    assert_eq!(encode(InspectorType::I8, Endian::Little, "-129"), None);
+    assert_eq!(encode(InspectorType::U16, Endian::Big, "258"), Some(vec![1, 2]));
+    assert_eq!(encode(InspectorType::I32, Endian::Little, "-2"), Some(vec![0xFE, 0xFF, 0xFF, 0xFF]));
+    assert_eq!(encode(InspectorType::U64, Endian::Big, "1"), Some(vec![0, 0, 0, 0, 0, 0, 0, 1]));
+    assert_eq!(encode(InspectorType::U32, Endian::Little, "12a"), None);
+    assert_eq!(encode(InspectorType::U32, Endian::Little, ""), None);
+    assert_eq!(encode(InspectorType::F16, Endian::Little, "1"), Some(vec![0x00, 0x3C]));
+    assert_eq!(encode(InspectorType::F16, Endian::Big, "65504"), Some(vec![0x7B, 0xFF]));
+    assert_eq!(encode(InspectorType::F32, Endian::Big, "1.5"), Some(1.5f32.to_be_bytes().to_vec()));
+    assert_eq!(encode(InspectorType::F64, Endian::Little, "-0.25"), Some((-0.25f64).to_le_bytes().to_vec()));
+    assert_eq!(encode(InspectorType::UnixTime32, Endian::Little, "2023-11-14 22:13:20"), Some(1_700_000_000u32.to_le_bytes().to_vec()));
+    assert_eq!(encode(InspectorType::UnixTime32, Endian::Little, "1960-01-01 00:00:00"), None);
+    assert_eq!(encode(InspectorType::UnixTime64, Endian::Big, "1960-01-01 00:00:00"), Some((-315_619_200i64).to_be_bytes().to_vec()));
+    assert_eq!(
+        encode(InspectorType::FileTime, Endian::Little, "2023-11-14 22:13:20.000000500"),
+        Some((133_444_736_000_000_005u64).to_le_bytes().to_vec())
+    );
+    assert_eq!(encode(InspectorType::DosDateTime, Endian::Little, "1970-01-01 00:00:00"), None);
+    assert_eq!(encode(InspectorType::DosDateTime, Endian::Big, "1980-01-01 00:00:02"), Some(vec![0x00, 0x21, 0x00, 0x01]));
+    assert_eq!(
+        encode(InspectorType::Guid, Endian::Little, "{00112233-4455-6677-8899-AABBCCDDEEFF}"),
+        Some(vec![0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF])
+    );
+    assert_eq!(encode(InspectorType::Guid, Endian::Big, "0011"), None);
+    assert_eq!(encode(InspectorType::Utf8, Endian::Little, "€"), Some(vec![0xE2, 0x82, 0xAC]));
+    assert_eq!(encode(InspectorType::Utf8, Endian::Little, "€ (U+20AC)"), Some(vec![0xE2, 0x82, 0xAC]));
+    assert_eq!(encode(InspectorType::Utf8, Endian::Little, "ab"), None);
+    assert_eq!(encode(InspectorType::Utf16, Endian::Big, "U+1F600"), Some(vec![0xD8, 0x3D, 0xDE, 0x00]));
+    assert_eq!(encode(InspectorType::ULeb128, Endian::Little, "624485"), Some(vec![0xE5, 0x8E, 0x26]));
+    assert_eq!(encode(InspectorType::SLeb128, Endian::Little, "-123456"), Some(vec![0xC0, 0xBB, 0x78]));
+    assert_eq!(encode(InspectorType::SLeb128, Endian::Little, "63"), Some(vec![0x3F]));
+    assert_eq!(encode(InspectorType::SLeb128, Endian::Little, "64"), Some(vec![0xC0, 0x00]));
+    assert_eq!(encode(InspectorType::ULeb128, Endian::Little, "-1"), None);
+}
+
+#[test]
+fn inspector_value_and_set_value() {
+    let _a = App::debug(60, 15, "Paint.Enable(false)").build().unwrap();
+    let mut bv = BufferView::with_buffer(vec![0u8; 16], layout!("d:f"), Flags::None);
+    bv.set_current_pos(4);
+    let mut w = window!("Test,d:f");
+    let h = w.add(bv);
+    let mut di = DataInspector::<Vec<u8>>::new(h, layout!("d:f"));
+    assert_eq!(di.value(InspectorType::U32, Endian::Little).as_deref(), Some("0"));
+    assert!(di.set_value(InspectorType::U32, Endian::Big, "305419896"));
+    assert_eq!(di.value(InspectorType::U32, Endian::Little).as_deref(), Some("2018915346"));
+    assert!(!di.set_value(InspectorType::U8, Endian::Little, "256"));
+    // there are only 12 bytes after the cursor
+    assert!(!di.set_value(InspectorType::Guid, Endian::Little, "00112233-4455-6677-8899-AABBCCDDEEFF"));
+    assert_eq!(di.value(InspectorType::Guid, Endian::Little), None);
+    let bv = RuntimeManager::get().get_control_mut(h).unwrap();
+    assert_eq!(&bufferview_content(bv)[4..8], &[0x12, 0x34, 0x56, 0x78]);
+    assert!(bv.is_modified());
+    assert!(bv.undo());
+    assert_eq!(di.value(InspectorType::U32, Endian::Big).as_deref(), Some("0"));
+}
+
+#[test]
+fn check_data_inspector() {
+    #[Window(events = BufferViewEvents<Vec<u8>>, internal = true)]
+    struct MyWin {
+        bv: Handle<BufferView<Vec<u8>>>,
+    }
+    impl MyWin {
+        fn new() -> Self {
+            let mut w = Self {
+                base: window!("Test,d:f"),
+                bv: Handle::None,
+            };
+            let data = vec![0x48, 0x69, 0x00, 0x3C, 0x00, 0x00, 0x80, 0x3F, 0xE5, 0x8E, 0x26, 0x00, 0x80, 0x9A, 0x53, 0x65];
+            let mut bv = BufferView::with_buffer(data, layout!("l:0,t:0,w:36,b:0"), Flags::ShowAddress);
+            bv.set_columns_count(ColumnsCount::Fixed(4));
+            bv.set_data_representation_format(DataRepresentationFormat::Hex(HexFormat::Byte));
+            bv.set_offset_format(OffsetFormat::Hex);
+            w.bv = w.add(bv);
+            let h = w.bv;
+            w.add(DataInspector::new(h, layout!("l:36,t:0,r:0,b:0")));
+            w
+        }
+    }
+    impl BufferViewEvents<Vec<u8>> for MyWin {
+        fn on_modified(&mut self, handle: Handle<BufferView<Vec<u8>>>) -> EventProcessStatus {
+            let modified = self.control(handle).map(|bv| bv.modified_ranges().to_vec()).unwrap_or_default();
+            self.set_title(&format!("Modified: {modified:?}"));
+            EventProcessStatus::Processed
+        }
+    }
+
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Inspector at offset 0 (u8 = 72, UTF-8 = H)')
+        CheckHash(0x1394B46F095765B5)
+        Key.Pressed(Tab)
+        Key.Pressed(Right,2)
+        Key.Pressed(Tab)
+        Paint('2. Cursor at offset 2, inspector focused (f16 BE = 0, f16 LE row)')
+        CheckHash(0xFC40BF11F2A8C73C)
+        Key.Pressed(Down,2)
+        Key.Pressed(Enter)
+        Paint('3. Editing u16 LE (current value 15360)')
+        CheckHash(0x64571AE3EBDF04B8)
+        Key.Pressed(Backspace,5)
+        Key.TypeText('70000')
+        Key.Pressed(Enter)
+        Paint('4. Invalid value (does not fit) - shown in error color')
+        CheckHash(0x95012625BBED9AEA)
+        Key.Pressed(Backspace,5)
+        Key.TypeText('4660')
+        Key.Pressed(Enter)
+        Paint('5. u16 LE written: bytes 34 12 at offset 2 (Modified: [2..4])')
+        CheckHash(0x207EEA71956ACA67)
+        Key.Pressed(End)
+        Key.Pressed(Up)
+        Key.TypeText('x')
+        Key.Pressed(Escape)
+        Paint('6. Edit canceled - ULEB128 row is current')
+        CheckHash(0xD3C36DDDC8C843E7)
+    ";
+    let mut a = App::debug(90, 24, script).build().unwrap();
+    a.add_window(MyWin::new());
+    a.run();
+}
@@ -291,10 +291,10 @@ fn check_navigation_change_sets() {
         CheckHash(0xB88956750EA2542B)  
         Key.Pressed(Ctrl+Right)
         Paint('4. Move to set Animals')   
-        CheckHash(0x48BCC9693003963F)  
+        CheckHash(0x7EA3239C57C4CE1C)  
         Key.Pressed(Alt+Right)
         Paint('5. Last set (no change)')   
-        CheckHash(0x48BCC9693003963F)  
+        CheckHash(0x7EA3239C57C4CE1C)  
         Key.Pressed(Ctrl+Left)
         Paint('6. Move to set Braille')   
         CheckHash(0xB88956750EA2542B)  
@@ -452,16 +452,16 @@ fn check_change_sets_via_mouse_expand_from_top() {
         CheckHash(0x971965F815ED1D8B)  
         Mouse.Click(35,4,left)
         Paint('6. Set is now Animals')   
-        CheckHash(0x3A6BCF382C82CF17)  
+        CheckHash(0xA85E1CE56A2848A4)  
         Mouse.Click(35,4,left)
         Paint('7. Nothing changes ( > button is inactive)')   
-        CheckHash(0x3A6BCF382C82CF17)  
+        CheckHash(0xA85E1CE56A2848A4)  
         Mouse.Move(4,4)
         Paint('8. Hover over < button')   
-        CheckHash(0xD9346209BB83504F)  
+        CheckHash(0x574F4D551049A25C)  
         Mouse.Hold(4,4,left)
         Paint('9. Hold < button')   
-        CheckHash(0xE4D71946AF7731F3)  
+        CheckHash(0x13182ACE25CC318C)  
         Mouse.Release(4,4,left)
         Paint('10. Release < button (set is now Braille)')   
         CheckHash(0x271C2CA2EE660F4B)  
@@ -500,16 +500,16 @@ fn check_change_sets_via_mouse_expand_from_bottom() {
         CheckHash(0xE095C96310B964FF)  
         Mouse.Click(35,5,left)
         Paint('6. Set is now Animals')   
-        CheckHash(0x85E3C7186812D7E3)  
+        CheckHash(0x60F79FFD45C9B430)  
         Mouse.Click(35,5,left)
         Paint('7. Nothing changes ( > button is inactive)')   
-        CheckHash(0x85E3C7186812D7E3)  
+        CheckHash(0x60F79FFD45C9B430)  
         Mouse.Move(4,5)
         Paint('8. Hover over < button')   
-        CheckHash(0x77B705DDE9FDD07B)  
+        CheckHash(0x3A043DE25CDE8BA8)  
         Mouse.Hold(4,5,left)
         Paint('9. Hold < button')   
-        CheckHash(0x511E92286B205C27)  
+        CheckHash(0x2958A69FD44101E4)  
         Mouse.Release(4,5,left)
         Paint('10. Release < button (set is now Braille)')   
         CheckHash(0xE4686A1E30ABCC6F)  
@@ -622,10 +622,10 @@ fn check_mouse_wheel() {
         CheckHash(0xFA1089E0C91CE8D3)         
         Mouse.Wheel(20,4,right,1)
         Paint('7. New set: Animals')   
-        CheckHash(0x3A6BCF382C82CF17)         
+        CheckHash(0xA85E1CE56A2848A4)         
         Mouse.Wheel(20,4,right,1)
         Paint('8. Nothing changes (same set - Animals)')   
-        CheckHash(0x3A6BCF382C82CF17)         
+        CheckHash(0xA85E1CE56A2848A4)         
         Mouse.Wheel(20,4,left,1)
         Paint('9. New set: Braille')   
         CheckHash(0xFA1089E0C91CE8D3)         
@@ -33,6 +33,7 @@ pub struct CommandBar {
     hovered_index: u32,
     pressed_index: u32,
     receiver_control_handle: Handle<()>,
+    pending_chord: Option<String>,
 }
 
 impl CommandBar {
@@ -48,6 +49,7 @@ impl CommandBar {
             hovered_index: INVALID_INDEX,
             pressed_index: INVALID_INDEX,
             receiver_control_handle: Handle::None,
+            pending_chord: None,
         };
         for vec in &mut obj.indexes {
             vec.reserve(MAX_KEYS);
@@ -72,6 +74,11 @@ impl CommandBar {
         self.update_positions();
     }
 
+    // the keys of a multi-key chord that is being typed (while a chord is typed, the commands are not displayed)
+    pub(crate) fn set_pending_chord(&mut self, text: Option<String>) {
+        self.pending_chord = text;
+    }
+
     pub(crate) fn set_key_modifier(&mut self, modifier: KeyModifier) {
         if modifier != self.modifier {
             self.modifier = modifier;
@@ -196,6 +203,10 @@ impl CommandBar {
 
     pub(crate) fn paint(&self, surface: &mut Surface, theme: &Theme) {
         surface.fill_horizontal_line(0, self.y, self.width as i32, Character::with_attributes(' ', theme.menu.text.normal));
+        if let Some(chord) = &self.pending_chord {
+            surface.write_string(1, self.y, chord, theme.menu.shortcut.normal, false);
+            return;
+        }
         let modifier_name = self.modifier.name();
         if !modifier_name.is_empty() {
             surface.write_string(0, self.y, modifier_name, theme.menu.text.inactive, false);
@@ -226,7 +237,7 @@ impl CommandBar {
     }
 
     fn mouse_position_to_index(&self, x: i32, y: i32) -> Option<u32> {
-        if y != self.y {
+        if y != self.y || self.pending_chord.is_some() {
             return None;
         }
         let shift_idx = self.modifier.get_value() as usize;
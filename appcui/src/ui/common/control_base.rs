@@ -3,12 +3,14 @@ use super::control_manager::ParentLayout;
 use crate::graphics::*;
 use crate::input::*;
 use crate::prelude::colorpicker::events::ColorPickerEvents;
+use crate::system::BackgroundTask;
 use crate::system::Theme;
 use crate::system::ThemeMethods;
 use crate::system::Timer;
 use crate::system::TimerMethods;
 use crate::system::{Handle, LayoutMethods, RuntimeManager};
 use crate::ui::AppBar;
+use crate::ui::toast::Toast;
 use crate::ui::{
     button::events::ButtonEvents, checkbox::events::CheckBoxEvents, command_bar::events::GenericCommandBarEvents, common::traits::*, common::*,
     desktop::events::DesktopEvents, layout::*, menu::events::GenericMenuEvents, menu::Menu, menu::MenuItem, window::events::ToolBarEvents,
@@ -367,6 +369,21 @@ impl ControlBase {
         tm.get_mut(self.timer_handle)
     }
 
+    /// Returns the background task associated with a specific handle.
+    ///
+    /// # Parameters
+    ///
+    /// * `handle` - A handle to a background task
+    ///
+    /// # Returns
+    ///
+    /// * `Some(BackgroundTask<T, R>)` - The background task if the handle is valid
+    /// * `None` - If the handle is invalid
+    #[inline(always)]
+    pub fn background_task<T: Send + 'static, R: Send + 'static>(&self, handle: Handle<BackgroundTask<T, R>>) -> Option<BackgroundTask<T, R>> {
+        BackgroundTask::from_handle(handle)
+    }
+
     /// Returns `true` if the current control is visible or `false` otherwise
     #[inline(always)]
     pub fn is_visible(&self) -> bool {
@@ -658,6 +675,17 @@ impl ControlBase {
         RuntimeManager::get().get_appbar()
     }
 
+    /// Shows a toast (a transient notification) in the bottom-right corner of the desktop and returns a handle to it.
+    /// If the toast has action buttons, the `ToastEvents::on_action` event will be sent to the current control when one of them is clicked.
+    pub fn show_toast(&self, toast: Toast) -> Handle<Toast> {
+        RuntimeManager::get().show_toast(toast, self.handle)
+    }
+
+    /// Dismisses a toast that was previously shown via `show_toast(...)`. If the toast was already dismissed, nothing happens.
+    pub fn dismiss_toast(&self, handle: Handle<Toast>) {
+        RuntimeManager::get().dismiss_toast(handle);
+    }
+
     #[inline(always)]
     pub fn theme(&self) -> &Theme {
         RuntimeManager::get().theme()
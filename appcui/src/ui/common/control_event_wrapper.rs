@@ -151,6 +151,9 @@ impl ControlEvent {
                 bufferview::events::BufferViewEventTypes::SelectionChanged => {
                     GenericBufferViewEvents::on_selection_changed(receiver, self.emitter.cast(), data.type_id)
                 }
+                bufferview::events::BufferViewEventTypes::Modified => {
+                    GenericBufferViewEvents::on_modified(receiver, self.emitter.cast(), data.type_id)
+                }
             },
             ControlEventData::PathFinder(_) => PathFinderEvents::on_path_updated(receiver, self.emitter.cast()),
             ControlEventData::TreeView(data) => match data.event_type {
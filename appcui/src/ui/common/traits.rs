@@ -36,6 +36,7 @@ use crate::{
         timepicker::events::TimePickerEvents,
         hyperlink::events::HyperLinkEvents,
         hslider::events::GenericHSliderEvents,
+        toast::events::ToastEvents,
     },
 };
 
@@ -65,6 +66,11 @@ pub trait OnKeyPressed {
     fn on_key_pressed(&mut self, _key: Key, _character: char) -> EventProcessStatus {
         EventProcessStatus::Ignored
     }
+    /// Called when a key is released. Key releases are only reported by backends that support them
+    /// (e.g. the Termios backend with the enhanced keyboard protocol enabled via `App::new().enhanced_keyboard(true)`).
+    fn on_key_released(&mut self, _key: Key) -> EventProcessStatus {
+        EventProcessStatus::Ignored
+    }
 }
 pub trait OnMouseEvent {
     fn on_mouse_event(&mut self, _event: &MouseEvent) -> EventProcessStatus {
@@ -93,6 +99,20 @@ pub trait OnThemeChanged {
     fn on_theme_changed(&mut self, _theme: &Theme) {}
 }
 
+/// Allows a control to save and restore its state (such as the position of a splitter or the column widths of a list view)
+/// as part of the desktop layout (see `Desktop::save_layout()` and `Desktop::restore_layout(...)`).
+/// This trait is opt-in: a custom control that wants its state to be persisted has to overwrite it
+/// (e.g. `#[CustomControl(overwrite = PersistState)]`).
+pub trait PersistState {
+    /// Returns the state of the control as a single line of text or `None` if the control has no state to save.
+    fn save_state(&self) -> Option<String> {
+        None
+    }
+    /// Restores the state of the control from a text previously returned by `save_state()`.
+    /// Invalid or outdated states should be ignored.
+    fn restore_state(&mut self, _state: &str) {}
+}
+
 pub trait OnWindowRegistered {
     fn on_registered(&mut self) {}
 }
@@ -132,6 +152,9 @@ pub trait GenericBackgroundTaskEvents {
     fn on_finish(&mut self, _handle: Handle<()>) -> EventProcessStatus {
         EventProcessStatus::Ignored
     }
+    fn on_error(&mut self, _handle: Handle<()>) -> EventProcessStatus {
+        EventProcessStatus::Ignored
+    }
     fn on_query(&mut self, _handle: Handle<()>) -> EventProcessStatus {
         EventProcessStatus::Ignored
     }
@@ -148,6 +171,7 @@ pub trait Control:
     + OnWindowRegistered
     + OnSiblingSelected
     + OnThemeChanged
+    + PersistState
     /* events from each control */
     + ButtonEvents
     + CheckBoxEvents
@@ -186,6 +210,7 @@ pub trait Control:
     + TimePickerEvents
     + HyperLinkEvents
     + GenericHSliderEvents
+    + ToastEvents
 {
 }
 
@@ -584,10 +584,8 @@ where
             }
             MouseEvent::Wheel(direction) => {
                 match direction {
-                    MouseWheelDirection::Up => {
-                        if self.current_index > 0 {
-                            self.update_current_index(self.current_index - 1);
-                        }
+                    MouseWheelDirection::Up if self.current_index > 0 => {
+                        self.update_current_index(self.current_index - 1);
                     }
                     MouseWheelDirection::Down => self.update_current_index(self.current_index + 1),
                     _ => {}
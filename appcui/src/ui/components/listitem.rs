@@ -1,9 +1,12 @@
 pub(in crate::ui) mod listitem;
 pub(in crate::ui) mod formats;
 pub(in crate::ui) mod render_method;
+pub(in crate::ui) mod export;
 
 pub use self::listitem::ListItem;
 pub use self::render_method::RenderMethod;
+pub use self::export::ExportFormat;
+pub use self::export::ExportScope;
 
 pub use self::formats::AreaFormat;
 pub use self::formats::BoolFormat;
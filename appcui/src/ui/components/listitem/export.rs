@@ -0,0 +1,235 @@
+use super::RenderMethod;
+use crate::graphics::TextAlignment;
+use std::fmt::Write;
+
+/// The format used to export the content of a list view or a tree view
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ExportFormat {
+    /// Comma separated values (values that contain commas, quotes or new lines are quoted)
+    Csv,
+    /// Tab separated values (tabs and new lines from values are replaced with spaces)
+    Tsv,
+    /// An array of JSON objects (one object for each row, with the names of the columns as keys)
+    Json,
+    /// A Markdown table (the alignment of every column is preserved)
+    Markdown,
+}
+
+/// The rows that are exported from a list view or a tree view.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ExportScope {
+    /// All rows (that are not filtered out), in the order they are displayed
+    All,
+    /// The selected (checked) rows, in the order they are displayed
+    Selected,
+    /// The rows from the current group (for a list view) or the current item and its children (for a tree view)
+    CurrentGroup,
+}
+
+enum Value {
+    Text(String),
+    Number(String),
+    Bool(bool),
+    Empty,
+}
+
+impl Value {
+    fn new(render_method: Option<RenderMethod<'_>>, raw: bool) -> Value {
+        let Some(rm) = render_method else {
+            return Value::Empty;
+        };
+        if raw {
+            Value::raw(&rm)
+        } else {
+            // some formats pad the text (to be aligned when displayed)
+            let mut output: [u8; 256] = [0; 256];
+            match rm.string_representation(&mut output) {
+                Some(txt) => Value::Text(txt.trim().to_string()),
+                None => Value::Empty,
+            }
+        }
+    }
+    fn raw(rm: &RenderMethod<'_>) -> Value {
+        match rm {
+            RenderMethod::Text(txt) | RenderMethod::Ascii(txt) => Value::Text(txt.to_string()),
+            RenderMethod::DateTime(dt, _) => Value::Text(dt.to_string()),
+            RenderMethod::Time(t, _) => Value::Text(t.to_string()),
+            RenderMethod::Date(d, _) => Value::Text(d.to_string()),
+            RenderMethod::Duration(d, _) => Value::Number(d.num_seconds().to_string()),
+            RenderMethod::Int64(v, _) => Value::Number(v.to_string()),
+            RenderMethod::Bool(v, _) => Value::Bool(*v),
+            RenderMethod::Percentage(v, _) | RenderMethod::Float(v, _) | RenderMethod::Temperature(v, _) | RenderMethod::Currency(v, _) => {
+                if v.is_finite() {
+                    Value::Number(v.to_string())
+                } else {
+                    Value::Empty
+                }
+            }
+            RenderMethod::Status(status, _) => {
+                let mut output: [u8; 32] = [0; 32];
+                Value::Text(status.string_representation(&mut output).to_string())
+            }
+            RenderMethod::Rating(v, _) => Value::Number(v.to_string()),
+            RenderMethod::UInt64(v, _)
+            | RenderMethod::Size(v, _)
+            | RenderMethod::Area(v, _)
+            | RenderMethod::Distance(v, _)
+            | RenderMethod::Volume(v, _)
+            | RenderMethod::Weight(v, _)
+            | RenderMethod::Speed(v, _) => Value::Number(v.to_string()),
+            RenderMethod::Custom => Value::Empty,
+        }
+    }
+    fn as_str(&self) -> &str {
+        match self {
+            Value::Text(txt) | Value::Number(txt) => txt,
+            Value::Bool(true) => "true",
+            Value::Bool(false) => "false",
+            Value::Empty => "",
+        }
+    }
+}
+
+// builds the exported text, one row at a time
+pub(in crate::ui) struct Exporter {
+    format: ExportFormat,
+    raw: bool,
+    names: Vec<String>,
+    output: String,
+    rows: usize,
+}
+
+impl Exporter {
+    // `columns` are the names and the alignments of the exported columns
+    pub(in crate::ui) fn new(format: ExportFormat, raw: bool, columns: &[(&str, TextAlignment)]) -> Self {
+        let mut e = Self {
+            format,
+            raw,
+            names: columns.iter().map(|(name, _)| name.to_string()).collect(),
+            output: String::new(),
+            rows: 0,
+        };
+        match format {
+            ExportFormat::Csv | ExportFormat::Tsv => {
+                let values: Vec<Value> = columns.iter().map(|(name, _)| Value::Text(name.to_string())).collect();
+                e.write_line(&values);
+            }
+            ExportFormat::Json => e.output.push('['),
+            ExportFormat::Markdown => {
+                let values: Vec<Value> = columns.iter().map(|(name, _)| Value::Text(name.to_string())).collect();
+                e.write_line(&values);
+                e.output.push('|');
+                for (_, alignment) in columns {
+                    e.output.push_str(match alignment {
+                        TextAlignment::Left => " --- |",
+                        TextAlignment::Center => " :---: |",
+                        TextAlignment::Right => " ---: |",
+                    });
+                }
+                e.output.push('\n');
+            }
+        }
+        e
+    }
+
+    // adds a row (one render method for every column)
+    pub(in crate::ui) fn add_row<'a>(&mut self, cells: impl Iterator<Item = Option<RenderMethod<'a>>>) {
+        let values: Vec<Value> = cells.map(|rm| Value::new(rm, self.raw)).collect();
+        match self.format {
+            ExportFormat::Json => self.write_object(&values),
+            _ => self.write_line(&values),
+        }
+        self.rows += 1;
+    }
+
+    pub(in crate::ui) fn finish(mut self) -> String {
+        if self.format == ExportFormat::Json {
+            if self.rows > 0 {
+                self.output.push('\n');
+            }
+            self.output.push_str("]\n");
+        }
+        self.output
+    }
+
+    fn write_line(&mut self, values: &[Value]) {
+        if self.format == ExportFormat::Markdown {
+            self.output.push('|');
+        }
+        for (index, value) in values.iter().enumerate() {
+            let txt = value.as_str();
+            match self.format {
+                ExportFormat::Csv => {
+                    if index > 0 {
+                        self.output.push(',');
+                    }
+                    if txt.contains([',', '"', '\n', '\r']) {
+                        self.output.push('"');
+                        self.output.push_str(&txt.replace('"', "\"\""));
+                        self.output.push('"');
+                    } else {
+                        self.output.push_str(txt);
+                    }
+                }
+                ExportFormat::Tsv => {
+                    if index > 0 {
+                        self.output.push('\t');
+                    }
+                    self.output.extend(txt.chars().map(|c| if matches!(c, '\t' | '\n' | '\r') { ' ' } else { c }));
+                }
+                ExportFormat::Markdown => {
+                    self.output.push(' ');
+                    for c in txt.chars() {
+                        match c {
+                            '|' => self.output.push_str("\\|"),
+                            '\n' | '\r' => self.output.push(' '),
+                            _ => self.output.push(c),
+                        }
+                    }
+                    self.output.push_str(" |");
+                }
+                ExportFormat::Json => {}
+            }
+        }
+        self.output.push('\n');
+    }
+
+    fn write_object(&mut self, values: &[Value]) {
+        if self.rows > 0 {
+            self.output.push(',');
+        }
+        self.output.push_str("\n  {");
+        for (index, (name, value)) in self.names.iter().zip(values).enumerate() {
+            if index > 0 {
+                self.output.push_str(", ");
+            }
+            write_json_string(&mut self.output, name);
+            self.output.push_str(": ");
+            match value {
+                Value::Text(txt) => write_json_string(&mut self.output, txt),
+                Value::Number(txt) => self.output.push_str(txt),
+                Value::Bool(v) => self.output.push_str(if *v { "true" } else { "false" }),
+                Value::Empty => self.output.push_str("null"),
+            }
+        }
+        self.output.push('}');
+    }
+}
+
+fn write_json_string(output: &mut String, txt: &str) {
+    output.push('"');
+    for c in txt.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(output, "\\u{:04x}", c as u32);
+            }
+            _ => output.push(c),
+        }
+    }
+    output.push('"');
+}
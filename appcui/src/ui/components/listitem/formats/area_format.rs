@@ -1,15 +1,15 @@
 use crate::utils::FormatNumber;
 
-const MMP: FormatNumber = FormatNumber::new(10).suffix(" mm²").group(3, b',');
-const CMP: FormatNumber = FormatNumber::new(10).suffix(" cm²").group(3, b',');
-const MP: FormatNumber = FormatNumber::new(10).suffix(" m² ").group(3, b',');
-const KMP: FormatNumber = FormatNumber::new(10).suffix(" km²").group(3, b',');
-const HECTARES: FormatNumber = FormatNumber::new(10).suffix(" ha ").group(3, b',');
-const ARES: FormatNumber = FormatNumber::new(10).suffix(" a  ").group(3, b',');
-const FTP: FormatNumber = FormatNumber::new(10).suffix(" ft²").group(3, b',');
-const INP: FormatNumber = FormatNumber::new(10).suffix(" in²").group(3, b',');
-const YDP: FormatNumber = FormatNumber::new(10).suffix(" yd²").group(3, b',');
-const MIP: FormatNumber = FormatNumber::new(10).suffix(" mi²").group(3, b',');
+const MMP: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.square_millimeters", 3).group(3, b',');
+const CMP: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.square_centimeters", 3).group(3, b',');
+const MP: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.square_meters", 3).group(3, b',');
+const KMP: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.square_kilometers", 3).group(3, b',');
+const HECTARES: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.hectares", 3).group(3, b',');
+const ARES: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.ares", 3).group(3, b',');
+const FTP: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.square_feet", 3).group(3, b',');
+const INP: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.square_inches", 3).group(3, b',');
+const YDP: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.square_yards", 3).group(3, b',');
+const MIP: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.square_miles", 3).group(3, b',');
 
 
 // m2, cm2, km2, ha, a, ft2, in2, yd2, mi2
@@ -1,3 +1,5 @@
+use crate::system::Locale;
+
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub enum BoolFormat {
     TrueFalse,
@@ -11,16 +13,16 @@ impl BoolFormat {
         match self {
             BoolFormat::TrueFalse => {
                 if value {
-                    "True"
+                    Locale::current().text("listitem.true")
                 } else {
-                    "False"
+                    Locale::current().text("listitem.false")
                 }
             }
             BoolFormat::YesNo => {
                 if value {
-                    "Yes"
+                    Locale::current().text("listitem.yes")
                 } else {
-                    "No"
+                    Locale::current().text("listitem.no")
                 }
             }
             BoolFormat::XMinus => {
@@ -1,5 +1,6 @@
 use chrono::Duration;
 
+use crate::system::Locale;
 use crate::utils::FormatNumber;
 
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -30,9 +31,15 @@ pub enum DurationFormat {
     Details
 }
 
-const SECONDS: FormatNumber = FormatNumber::new(10).suffix(" sec");
+const SECONDS: FormatNumber = FormatNumber::new(10);
 impl DurationFormat {
     pub (crate) fn seconds<'a>(value: &Duration, output: &'a mut [u8]) -> Option<&'a str> {
-        SECONDS.write_number(value.num_seconds(), output)
+        // <value> <seconds>
+        let unit = Locale::current().text("listitem.seconds");
+        let end = output.len().checked_sub(unit.len() + 1)?;
+        let start = end - SECONDS.write_number(value.num_seconds(), &mut output[..end])?.len();
+        output[end] = b' ';
+        output[end + 1..].copy_from_slice(unit.as_bytes());
+        std::str::from_utf8(&output[start..]).ok()
     }
 }
\ No newline at end of file
@@ -1,13 +1,13 @@
 use crate::utils::FormatNumber;
 
-const KM: FormatNumber = FormatNumber::new(10).suffix(" km").group(3, b',');
-const M:  FormatNumber = FormatNumber::new(10).suffix(" m ").group(3, b',');
-const CM: FormatNumber = FormatNumber::new(10).suffix(" cm").group(3, b',');
-const MM: FormatNumber = FormatNumber::new(10).suffix(" mm").group(3, b',');
-const IN: FormatNumber = FormatNumber::new(10).suffix(" in").group(3, b',');
-const FT: FormatNumber = FormatNumber::new(10).suffix(" ft").group(3, b',');
-const YD: FormatNumber = FormatNumber::new(10).suffix(" yd").group(3, b',');
-const MI: FormatNumber = FormatNumber::new(10).suffix(" mi").group(3, b',');
+const KM: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.kilometers", 2).group(3, b',');
+const M:  FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.meters", 2).group(3, b',');
+const CM: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.centimeters", 2).group(3, b',');
+const MM: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.millimeters", 2).group(3, b',');
+const IN: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.inches", 2).group(3, b',');
+const FT: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.feet", 2).group(3, b',');
+const YD: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.yards", 2).group(3, b',');
+const MI: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.miles", 2).group(3, b',');
 
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub enum DistanceFormat {
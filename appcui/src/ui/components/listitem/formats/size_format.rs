@@ -1,15 +1,15 @@
 use crate::utils::FormatNumber;
 
-const BYTES: FormatNumber = FormatNumber::new(10).suffix(" B ").group(3, b',');
-const KILO_BYTES: FormatNumber = FormatNumber::new(10).suffix(" KB").group(3, b',');
-const MEGA_BYTES: FormatNumber = FormatNumber::new(10).suffix(" MB").group(3, b',');
-const GIGA_BYTES: FormatNumber = FormatNumber::new(10).suffix(" GB").group(3, b',');
-const TERA_BYTES: FormatNumber = FormatNumber::new(10).suffix(" TB").group(3, b',');
-const BYTES_DECIMALS: FormatNumber = FormatNumber::new(10).suffix(" B ").group(3, b',').decimals(2);
-const KILO_BYTES_DECIMALS: FormatNumber = FormatNumber::new(10).suffix(" KB").group(3, b',').decimals(2);
-const MEGA_BYTES_DECIMALS: FormatNumber = FormatNumber::new(10).suffix(" MB").group(3, b',').decimals(2);
-const GIGA_BYTES_DECIMALS: FormatNumber = FormatNumber::new(10).suffix(" GB").group(3, b',').decimals(2);
-const TERA_BYTES_DECIMALS: FormatNumber = FormatNumber::new(10).suffix(" TB").group(3, b',').decimals(2);
+const BYTES: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.bytes", 2).group(3, b',');
+const KILO_BYTES: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.kilobytes", 2).group(3, b',');
+const MEGA_BYTES: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.megabytes", 2).group(3, b',');
+const GIGA_BYTES: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.gigabytes", 2).group(3, b',');
+const TERA_BYTES: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.terabytes", 2).group(3, b',');
+const BYTES_DECIMALS: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.bytes", 2).group(3, b',').decimals(2);
+const KILO_BYTES_DECIMALS: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.kilobytes", 2).group(3, b',').decimals(2);
+const MEGA_BYTES_DECIMALS: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.megabytes", 2).group(3, b',').decimals(2);
+const GIGA_BYTES_DECIMALS: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.gigabytes", 2).group(3, b',').decimals(2);
+const TERA_BYTES_DECIMALS: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.terabytes", 2).group(3, b',').decimals(2);
 
 
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -1,14 +1,14 @@
 use crate::utils::FormatNumber;
 
-const KMH: FormatNumber             = FormatNumber::new(10).suffix(" km/h").group(3, b',');
-const METER_PER_HOUR: FormatNumber  = FormatNumber::new(10).suffix("  m/h").group(3, b',');
-const KMS: FormatNumber             = FormatNumber::new(10).suffix(" km/s").group(3, b',');
-const MPS: FormatNumber             = FormatNumber::new(10).suffix("  m/s").group(3, b',');
-const MPH: FormatNumber             = FormatNumber::new(10).suffix("  mph").group(3, b',');
-const MPS2: FormatNumber            = FormatNumber::new(10).suffix("  mps").group(3, b',');
-const KNOTS: FormatNumber           = FormatNumber::new(10).suffix(" knots").group(3, b',');
-const FPS: FormatNumber             = FormatNumber::new(10).suffix(" ft/s").group(3, b',');
-const MACH: FormatNumber            = FormatNumber::new(10).suffix(" mach").group(3, b',');
+const KMH: FormatNumber             = FormatNumber::new(10).suffix(" ").right_aligned_unit("unit.kilometers_per_hour", 4).group(3, b',');
+const METER_PER_HOUR: FormatNumber  = FormatNumber::new(10).suffix(" ").right_aligned_unit("unit.meters_per_hour", 4).group(3, b',');
+const KMS: FormatNumber             = FormatNumber::new(10).suffix(" ").right_aligned_unit("unit.kilometers_per_second", 4).group(3, b',');
+const MPS: FormatNumber             = FormatNumber::new(10).suffix(" ").right_aligned_unit("unit.meters_per_second", 4).group(3, b',');
+const MPH: FormatNumber             = FormatNumber::new(10).suffix(" ").right_aligned_unit("unit.miles_per_hour", 4).group(3, b',');
+const MPS2: FormatNumber            = FormatNumber::new(10).suffix(" ").right_aligned_unit("unit.miles_per_second", 4).group(3, b',');
+const KNOTS: FormatNumber           = FormatNumber::new(10).suffix(" ").right_aligned_unit("unit.knots", 4).group(3, b',');
+const FPS: FormatNumber             = FormatNumber::new(10).suffix(" ").right_aligned_unit("unit.feet_per_second", 4).group(3, b',');
+const MACH: FormatNumber            = FormatNumber::new(10).suffix(" ").right_aligned_unit("unit.mach", 4).group(3, b',');
 
 
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -1,3 +1,4 @@
+use crate::system::Locale;
 use crate::utils::FormatNumber;
 
 #[derive(Copy, Clone)]
@@ -96,7 +97,7 @@ pub enum StatusFormat {
 }
 
 const PERCENTAGE_RUNNING: FormatNumber = FormatNumber::new(10).suffix("%").fill(4, b' ');
-const PERCENTAGE_PAUSED: FormatNumber = FormatNumber::new(10).suffix("%)").prefix("Paused (");
+const PERCENTAGE_PAUSED: FormatNumber = FormatNumber::new(10).suffix("%)");
 
 impl Status {
     #[inline(always)]
@@ -111,12 +112,23 @@ impl Status {
     }
     pub(crate) fn string_representation<'a>(&self, output_buffer: &'a mut [u8]) -> &'a str {
         match self {
-            Status::Paused(value) => PERCENTAGE_PAUSED.write_float(Status::proc(*value), output_buffer).unwrap_or(""),
-            Status::Completed => "Completed",
+            Status::Paused(value) => Status::paused(*value, output_buffer).unwrap_or(""),
+            Status::Completed => Locale::current().text("listitem.completed"),
             Status::Running(value) => PERCENTAGE_RUNNING.write_float(Status::proc(*value), output_buffer).unwrap_or(""),
-            Status::Error => "Error",
-            Status::Queued => "Queued",
-            Status::Stopped => "Stopped",
+            Status::Error => Locale::current().text("listitem.error"),
+            Status::Queued => Locale::current().text("listitem.queued"),
+            Status::Stopped => Locale::current().text("listitem.stopped"),
         }
     }
+    // <paused> (xx%)
+    fn paused(value: f32, output_buffer: &mut [u8]) -> Option<&str> {
+        let end = output_buffer.len();
+        let start = end - PERCENTAGE_PAUSED.write_float(Status::proc(value), output_buffer)?.len();
+        let text = Locale::current().text("listitem.paused");
+        let text_start = start.checked_sub(text.len() + 2)?;
+        output_buffer[text_start..start - 2].copy_from_slice(text.as_bytes());
+        output_buffer[start - 2] = b' ';
+        output_buffer[start - 1] = b'(';
+        std::str::from_utf8(&output_buffer[text_start..]).ok()
+    }
 }
@@ -1,8 +1,8 @@
 use crate::utils::FormatNumber;
 
-const CELSIUS: FormatNumber = FormatNumber::new(10).decimals(1).suffix("°C");
-const FAHRENHEIT: FormatNumber = FormatNumber::new(10).decimals(1).suffix("°F");
-const KELVIN: FormatNumber = FormatNumber::new(10).decimals(1).suffix("°K");
+const CELSIUS: FormatNumber = FormatNumber::new(10).decimals(1).unit("unit.celsius", 0);
+const FAHRENHEIT: FormatNumber = FormatNumber::new(10).decimals(1).unit("unit.fahrenheit", 0);
+const KELVIN: FormatNumber = FormatNumber::new(10).decimals(1).unit("unit.kelvin", 0);
 
 
 #[derive(Copy, Clone, Eq, PartialEq)]
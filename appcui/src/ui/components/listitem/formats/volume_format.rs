@@ -1,16 +1,16 @@
 use crate::utils::FormatNumber;
 
-const CUBIC_MM: FormatNumber = FormatNumber::new(10).suffix(" mm³").group(3, b',');
-const CUBIC_CM: FormatNumber = FormatNumber::new(10).suffix(" cm³").group(3, b',');
-const CUBIC_M: FormatNumber = FormatNumber::new(10).suffix(" m³ ").group(3, b',');
-const CUBIC_KM: FormatNumber = FormatNumber::new(10).suffix(" km³").group(3, b',');
-const LITERS: FormatNumber = FormatNumber::new(10).suffix(" l  ").group(3, b',');
-const MILLILITERS: FormatNumber = FormatNumber::new(10).suffix(" ml ").group(3, b',');
-const GALLONS: FormatNumber = FormatNumber::new(10).suffix(" gal").group(3, b',');
-const CUBIC_FT: FormatNumber = FormatNumber::new(10).suffix(" ft³").group(3, b',');
-const CUBIC_IN: FormatNumber = FormatNumber::new(10).suffix(" in³").group(3, b',');
-const CUBIC_YD: FormatNumber = FormatNumber::new(10).suffix(" yd³").group(3, b',');
-const CUBIC_MI: FormatNumber = FormatNumber::new(10).suffix(" mi³").group(3, b',');
+const CUBIC_MM: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.cubic_millimeters", 3).group(3, b',');
+const CUBIC_CM: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.cubic_centimeters", 3).group(3, b',');
+const CUBIC_M: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.cubic_meters", 3).group(3, b',');
+const CUBIC_KM: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.cubic_kilometers", 3).group(3, b',');
+const LITERS: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.liters", 3).group(3, b',');
+const MILLILITERS: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.milliliters", 3).group(3, b',');
+const GALLONS: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.gallons", 3).group(3, b',');
+const CUBIC_FT: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.cubic_feet", 3).group(3, b',');
+const CUBIC_IN: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.cubic_inches", 3).group(3, b',');
+const CUBIC_YD: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.cubic_yards", 3).group(3, b',');
+const CUBIC_MI: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.cubic_miles", 3).group(3, b',');
 
 
 
@@ -1,10 +1,10 @@
 use crate::utils::FormatNumber;
 
-const KG: FormatNumber = FormatNumber::new(10).suffix(" kg").group(3, b',');
-const GR: FormatNumber = FormatNumber::new(10).suffix(" gr").group(3, b',');
-const MG: FormatNumber = FormatNumber::new(10).suffix(" mg").group(3, b',');
-const LB: FormatNumber = FormatNumber::new(10).suffix(" lb").group(3, b',');
-const TON: FormatNumber = FormatNumber::new(10).suffix(" t ").group(3, b',');
+const KG: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.kilograms", 2).group(3, b',');
+const GR: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.grams", 2).group(3, b',');
+const MG: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.milligrams", 2).group(3, b',');
+const LB: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.pounds", 2).group(3, b',');
+const TON: FormatNumber = FormatNumber::new(10).suffix(" ").unit("unit.tons", 2).group(3, b',');
 
 
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -1,3 +1,4 @@
+use crate::graphics::unicode;
 use crate::prelude::*;
 use crate::utils::format_datetime::FormatDuration;
 use crate::utils::{FormatDate, FormatDateTime, FormatRatings, FormatTime};
@@ -81,7 +82,8 @@ impl<'a> RenderMethod<'a> {
     fn paint_currency(value: f64, format: &CurrencyFormat, surface: &mut Surface, rd: &RenderData) {
         let (currency_name, len) = format.name();
         let mut output: [u8; 48] = [0; 48];
-        let txt = CurrencyFormat::NUMERIC_FORMAT.write_float(value, &mut output).unwrap_or("?");
+        let number_len = CurrencyFormat::NUMERIC_FORMAT.write_float(value, &mut output).map(str::len);
+        let txt = RenderMethod::localize_number(&mut output, number_len).unwrap_or("?");
         let attr = rd.attr.unwrap_or(rd.theme.text.focused);
         surface.write_string(0, 0, currency_name, attr, false);
         let pos = ((rd.width as i32) - (txt.len() as i32)).max(len as i32 + 1);
@@ -128,7 +130,7 @@ impl<'a> RenderMethod<'a> {
         } else {
             let mut output: [u8; 32] = [0; 32];
             let txt = status.string_representation(&mut output);
-            RenderMethod::paint_ascii(txt, surface, rd);
+            RenderMethod::paint_text(txt, surface, rd);
         }
     }
 
@@ -144,6 +146,7 @@ impl<'a> RenderMethod<'a> {
                 true
             }
             RenderMethod::Bool(_, _)
+            | RenderMethod::Duration(_, _)
             | RenderMethod::Temperature(_, _)
             | RenderMethod::Area(_, _)
             | RenderMethod::Rating(_, _)
@@ -160,7 +163,6 @@ impl<'a> RenderMethod<'a> {
             | RenderMethod::DateTime(_, _)
             | RenderMethod::Time(_, _)
             | RenderMethod::Date(_, _)
-            | RenderMethod::Duration(_, _)
             | RenderMethod::Int64(_, _)
             | RenderMethod::UInt64(_, _)
             | RenderMethod::Float(_, _)
@@ -184,7 +186,32 @@ impl<'a> RenderMethod<'a> {
             RenderMethod::Custom => false,
         }
     }
+    // replaces the decimal and thousands separators of a number (written at the end of the output buffer by FormatNumber)
+    // with the ones from the locale
+    fn localize_number(output: &mut [u8], len: Option<usize>) -> Option<&str> {
+        let start = output.len() - len?;
+        Locale::current().localize_number(&mut output[start..]);
+        std::str::from_utf8(&output[start..]).ok()
+    }
     pub(in crate::ui) fn string_representation(&self, output: &'a mut [u8]) -> Option<&'a str> {
+        let len = match self {
+            RenderMethod::Int64(value, format) => format.formatter().write_number(*value, output).map(str::len),
+            RenderMethod::UInt64(value, format) => format.formatter().write_number(*value, output).map(str::len),
+            RenderMethod::Float(value, format) => format.formatter().write_float(*value, output).map(str::len),
+            RenderMethod::Percentage(value, format) => format.formatter().write_float(*value * 100.0, output).map(str::len),
+            RenderMethod::Temperature(value, format) => format.formatter().write_float(*value, output).map(str::len),
+            RenderMethod::Currency(value, format) => format.formatter().write_float(*value, output).map(str::len),
+            RenderMethod::Size(value, format) => format.write(*value, output).map(str::len),
+            RenderMethod::Area(value, format) => format.write(*value, output).map(str::len),
+            RenderMethod::Distance(value, format) => format.write(*value, output).map(str::len),
+            RenderMethod::Volume(value, format) => format.write(*value, output).map(str::len),
+            RenderMethod::Weight(value, format) => format.write(*value, output).map(str::len),
+            RenderMethod::Speed(value, format) => format.write(*value, output).map(str::len),
+            _ => return self.text_representation(output),
+        };
+        RenderMethod::localize_number(output, len)
+    }
+    fn text_representation(&self, output: &'a mut [u8]) -> Option<&'a str> {
         match self {
             RenderMethod::Text(txt) => Some(txt),
             RenderMethod::Ascii(txt) => Some(txt),
@@ -216,26 +243,14 @@ impl<'a> RenderMethod<'a> {
                 }
                 RatingFormat::Asterix(count) => FormatRatings::two_chars(' ', '*', *value, *count, (*count as u8).min(MAX_RATING_STARS), output),
             },
-            RenderMethod::Int64(value, format) => format.formatter().write_number(*value, output),
-            RenderMethod::UInt64(value, format) => format.formatter().write_number(*value, output),
-            RenderMethod::Float(value, format) => format.formatter().write_float(*value, output),
-            RenderMethod::Percentage(value, format) => format.formatter().write_float(*value * 100.0, output),
-            RenderMethod::Temperature(value, format) => format.formatter().write_float(*value, output),
             RenderMethod::Bool(value, format) => Some(format.text(*value)),
-            RenderMethod::Size(value, format) => format.write(*value, output),
-            RenderMethod::Area(value, format) => format.write(*value, output),
-            RenderMethod::Distance(value, format) => format.write(*value, output),
-            RenderMethod::Volume(value, format) => format.write(*value, output),
-            RenderMethod::Weight(value, format) => format.write(*value, output),
-            RenderMethod::Speed(value, format) => format.write(*value, output),
             RenderMethod::Status(status, _) => Some(status.string_representation(output)),
-            RenderMethod::Currency(value, format) => format.formatter().write_float(*value, output),
-            RenderMethod::Custom => None,
+            _ => None,
         }
     }
     pub(in crate::ui) fn min_width(&self) -> u32 {
         match self {
-            RenderMethod::Text(txt) => txt.chars().count() as u32,
+            RenderMethod::Text(txt) => unicode::text_width(txt),
             RenderMethod::Ascii(txt) => txt.len() as u32,
             RenderMethod::DateTime(_, _) => 19,
             RenderMethod::Time(_, format) => match format {
@@ -250,7 +265,7 @@ impl<'a> RenderMethod<'a> {
             },
             RenderMethod::Duration(_, _) => {
                 let mut output: [u8; 64] = [0; 64];
-                self.string_representation(&mut output).map(|p| p.len() as u32).unwrap_or(0)
+                self.string_representation(&mut output).map(unicode::text_width).unwrap_or(0)
             }
             RenderMethod::Int64(value, format) => {
                 let mut output: [u8; 64] = [0; 64];
@@ -304,10 +319,10 @@ impl<'a> RenderMethod<'a> {
                 let mut output: [u8; 64] = [0; 64];
                 format.formatter().write_float(*value, &mut output).map(|p| p.len() as u32).unwrap_or(0)
             }
-            RenderMethod::Bool(value, format) => format.text(*value).chars().count() as u32,
+            RenderMethod::Bool(value, format) => unicode::text_width(format.text(*value)),
             RenderMethod::Status(status, _) => {
                 let mut output: [u8; 32] = [0; 32];
-                status.string_representation(&mut output).len() as u32
+                unicode::text_width(status.string_representation(&mut output))
             }
             RenderMethod::Rating(value, format) => match format {
                 RatingFormat::Numerical(max_value) => {
@@ -321,4 +336,46 @@ impl<'a> RenderMethod<'a> {
             RenderMethod::Custom => 0,
         }
     }
+    // the numeric value of a render method (if it has one) - used to compute aggregates (sum, average, ...)
+    pub(in crate::ui) fn numeric_value(&self) -> Option<f64> {
+        match self {
+            RenderMethod::Duration(value, _) => Some(value.num_milliseconds() as f64),
+            RenderMethod::Int64(value, _) => Some(*value as f64),
+            RenderMethod::UInt64(value, _) => Some(*value as f64),
+            RenderMethod::Size(value, _) => Some(*value as f64),
+            RenderMethod::Percentage(value, _) => Some(*value),
+            RenderMethod::Float(value, _) => Some(*value),
+            RenderMethod::Temperature(value, _) => Some(*value),
+            RenderMethod::Area(value, _) => Some(*value as f64),
+            RenderMethod::Rating(value, _) => Some(*value as f64),
+            RenderMethod::Currency(value, _) => Some(*value),
+            RenderMethod::Distance(value, _) => Some(*value as f64),
+            RenderMethod::Volume(value, _) => Some(*value as f64),
+            RenderMethod::Weight(value, _) => Some(*value as f64),
+            RenderMethod::Speed(value, _) => Some(*value as f64),
+            _ => None,
+        }
+    }
+    // a render method of the same type and format as the current one, that displays another value
+    // (the value is rounded for integer types)
+    pub(in crate::ui) fn with_numeric_value(&self, value: f64) -> Option<RenderMethod<'static>> {
+        let unsigned = value.round().max(0.0) as u64;
+        match self {
+            RenderMethod::Duration(_, format) => Some(RenderMethod::Duration(Duration::milliseconds(value.round() as i64), *format)),
+            RenderMethod::Int64(_, format) => Some(RenderMethod::Int64(value.round() as i64, *format)),
+            RenderMethod::UInt64(_, format) => Some(RenderMethod::UInt64(unsigned, *format)),
+            RenderMethod::Size(_, format) => Some(RenderMethod::Size(unsigned, *format)),
+            RenderMethod::Percentage(_, format) => Some(RenderMethod::Percentage(value, *format)),
+            RenderMethod::Float(_, format) => Some(RenderMethod::Float(value, *format)),
+            RenderMethod::Temperature(_, format) => Some(RenderMethod::Temperature(value, *format)),
+            RenderMethod::Area(_, format) => Some(RenderMethod::Area(unsigned, *format)),
+            RenderMethod::Rating(_, format) => Some(RenderMethod::Rating(unsigned.min(u32::MAX as u64) as u32, *format)),
+            RenderMethod::Currency(_, format) => Some(RenderMethod::Currency(value, *format)),
+            RenderMethod::Distance(_, format) => Some(RenderMethod::Distance(unsigned, *format)),
+            RenderMethod::Volume(_, format) => Some(RenderMethod::Volume(unsigned, *format)),
+            RenderMethod::Weight(_, format) => Some(RenderMethod::Weight(unsigned, *format)),
+            RenderMethod::Speed(_, format) => Some(RenderMethod::Speed(unsigned, *format)),
+            _ => None,
+        }
+    }
 }
@@ -92,10 +92,8 @@ impl GenericScrollBar {
     #[inline(always)]
     pub(super) fn update_value_from_mouse(&mut self, mouse_pos: i32, new_status: MouseOnScrollbarStatus) {
         match new_status {
-            MouseOnScrollbarStatus::PressedOnMinimizeArrow => {
-                if self.value > 0 {
-                    self.set_value(self.value - 1);
-                }
+            MouseOnScrollbarStatus::PressedOnMinimizeArrow if self.value > 0 => {
+                self.set_value(self.value - 1);
             }
             MouseOnScrollbarStatus::PressedOnMaximizeArrow => {
                 self.set_value(self.value + 1);
@@ -41,7 +41,7 @@ pub struct DatePicker {
 }
 
 impl DatePicker {
-    const DAYS: [&'static str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+    // the keys J, F, M, A, S, O, N and D jump to the (English) month that starts with that letter
     const MONTHS: [&'static str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
 
     /// Creates a new date picker with a NaiveDate and a layout.
@@ -186,14 +186,20 @@ impl DatePicker {
     }
 
     fn format_very_short_date(selected_date: NaiveDate) -> String {
-        selected_date.format("%d.%m.%y").to_string()
+        Locale::current().format_date(&selected_date, true)
     }
     fn format_short_date(selected_date: NaiveDate) -> String {
-        selected_date.format("%d.%m.%Y").to_string()
+        Locale::current().format_date(&selected_date, false)
     }
 
     fn format_long_date(selected_date: NaiveDate) -> String {
-        selected_date.format("%Y, %b, %d").to_string()
+        Locale::current().format_long_date(&selected_date)
+    }
+
+    // the names of the months and days are displayed in a fixed space
+    fn first_chars(text: &str, count: usize) -> &str {
+        let end = text.char_indices().nth(count).map(|(pos, _)| pos).unwrap_or(text.len());
+        &text[..end]
     }
 
     fn get_date_size(&self) -> DateSize {
@@ -208,10 +214,11 @@ impl DatePicker {
     }
     fn get_printed_chars(&self) -> u32 {
         let wdth = self.size().width - MIN_WIDTH_FOR_DATE_NAME;
-        if (self.date_string.len() as u32) > wdth {
+        let len = self.date_string.chars().count() as u32;
+        if len > wdth {
             wdth
         } else {
-            self.date_string.len() as u32
+            len
         }
     }
 
@@ -228,13 +235,9 @@ impl DatePicker {
     }
 
     fn get_first_day_index(&self) -> i32 {
-        let first_day = self.virtual_date.with_day(1).unwrap().format("%a").to_string();
-        for i in 0..DatePicker::DAYS.len() {
-            if first_day.starts_with(DatePicker::DAYS[i]) {
-                return i as i32;
-            }
-        }
-        0
+        let first_day = self.virtual_date.with_day(1).unwrap().weekday().num_days_from_monday();
+        let first_weekday = Locale::current().first_weekday().num_days_from_monday();
+        ((first_day + 7 - first_weekday) % 7) as i32
     }
 }
 
@@ -259,27 +262,13 @@ impl OnPaint for DatePicker {
             .wrap_type(WrapType::SingleLineWrap((size.width - MIN_WIDTH_FOR_DATE_NAME) as u16))
             .build();
         surface.fill_horizontal_line(0, self.header_y_ofs, (size.width - MINSPACE_FOR_DATE_DRAWING) as i32, space_char);
-        let mut buf: [u8; 16] = [0; 16];
-        match date_size {
-            DateSize::Large => {
-                if let Some(txt) = crate::utils::FormatDate::normal(&self.selected_date, &mut buf) {
-                    format.set_chars_count(txt.len() as u16);
-                    surface.write_text(txt, &format);
-                }
-            }
-            DateSize::Small => {
-                if let Some(txt) = crate::utils::FormatDate::dmy(&self.selected_date, &mut buf, b'.') {
-                    format.set_chars_count(txt.len() as u16);
-                    surface.write_text(txt, &format);
-                }
-            }
-            DateSize::VerySmall => {
-                if let Some(txt) = crate::utils::FormatDate::short(&self.selected_date, &mut buf, b'.') {
-                    format.set_chars_count(txt.len() as u16);
-                    surface.write_text(txt, &format);
-                }
-            }
-        }
+        let txt = match date_size {
+            DateSize::Large => Self::format_long_date(self.selected_date),
+            DateSize::Small => Self::format_short_date(self.selected_date),
+            DateSize::VerySmall => Self::format_very_short_date(self.selected_date),
+        };
+        format.set_chars_count(txt.chars().count() as u16);
+        surface.write_text(&txt, &format);
 
         if size.width >= MINSPACE_FOR_DROPBUTTON_DRAWING {
             let px = (size.width - MINSPACE_FOR_DROPBUTTON_DRAWING) as i32;
@@ -391,18 +380,16 @@ impl OnPaint for DatePicker {
                 theme,
             );
 
-            let month = Self::MONTHS[self.virtual_date.month0() as usize];
-            surface.write_ascii(22, 1 + self.expanded_panel_y, month.as_bytes(), col, false);
+            let locale = Locale::current();
+            let month = Self::first_chars(locale.month_name(self.virtual_date.month()), 3);
+            surface.write_string(22, 1 + self.expanded_panel_y, month, col, false);
 
             let mut x = 2;
-            for i in 0..7 {
-                surface.write_ascii(
-                    x,
-                    3 + self.expanded_panel_y,
-                    DatePicker::DAYS[i].as_bytes(),
-                    theme.menu.text.inactive,
-                    false,
-                );
+            let mut day = locale.first_weekday();
+            for _ in 0..7 {
+                let name = Self::first_chars(locale.weekday_name(day), 2);
+                surface.write_string(x, 3 + self.expanded_panel_y, name, theme.menu.text.inactive, false);
+                day = day.succ();
                 x += 4;
             }
 
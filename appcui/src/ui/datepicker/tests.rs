@@ -522,4 +522,25 @@ fn check_expanded_keys(){
     w.add(DatePicker::new("2024-06-13", layout!("a:c,w:19,h:100%")));
     a.add_window(w);
     a.run();
-}
\ No newline at end of file
+}
+#[test]
+fn check_locale() {
+    let script = "
+        Paint.Enable(false)
+        Paint('1. German: 13. Mär 2024 | 2024-03-13 | 24-03-13')
+        CheckHash(0xB24C3FF2D36F6CD0)
+        Key.Pressed(Space)
+        Paint('2. expanded: Mär, week starts on Sunday (So Mo Di ...)')
+        CheckHash(0xCCF685A82D000018)
+    ";
+    let mut locale = Locale::new(Locales::German);
+    locale.set_date_format(DateOrder::YearMonthDay, '-').unwrap();
+    locale.set_first_weekday(chrono::Weekday::Sun);
+    let mut a = App::debug(60, 20, script).locale(locale).build().unwrap();
+    let mut w = window!("Dates,a:c,w:50,h:18");
+    w.add(DatePicker::new("2024-03-13", layout!("x:1,y:1,w:19")));
+    w.add(DatePicker::new("2024-03-13", layout!("x:23,y:1,w:16")));
+    w.add(DatePicker::new("2024-03-13", layout!("x:23,y:3,w:14")));
+    a.add_window(w);
+    a.run();
+}
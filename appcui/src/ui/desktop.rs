@@ -5,10 +5,14 @@
 
 mod desktop;
 mod empty_desktop;
+mod layout;
+mod tiling;
+mod window_switcher;
 pub mod events;
 #[cfg(test)]
 mod tests;
 
 pub use self::desktop::Desktop;
 pub(crate) use self::empty_desktop::EmptyDesktop;
-pub use self::desktop::ArrangeWindowsMethod;
\ No newline at end of file
+pub use self::desktop::ArrangeWindowsMethod;
+pub use self::tiling::DockPosition;
\ No newline at end of file
@@ -1,3 +1,7 @@
+use super::layout::{ControlState, DesktopLayout, WindowLayout};
+use super::tiling::{DockPosition, TileTree};
+use super::window_switcher::WindowSwitcher;
+use crate::system::LayoutMethods;
 use crate::prelude::*;
 
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -8,8 +12,11 @@ pub enum ArrangeWindowsMethod {
     Grid,
 }
 
-#[CustomControl(overwrite=OnPaint+OnKeyPressed, internal=true, desktop=true)]
-pub struct Desktop {}
+#[CustomControl(overwrite=OnPaint+OnKeyPressed+OnMouseEvent, internal=true, desktop=true)]
+pub struct Desktop {
+    tiles: Option<TileTree>,
+    mru: Vec<Handle<()>>,
+}
 
 impl Desktop {
     /// Creates a new desktop object. This is something that the AppCUI does automatically unless a custom Desktop is provided.
@@ -24,6 +31,8 @@ impl Desktop {
                 layout!("x:0,y:0,w:100%,h:100%"),
                 StatusFlags::Visible | StatusFlags::Enabled | StatusFlags::AcceptInput | StatusFlags::DesktopControl,
             ),
+            tiles: None,
+            mru: Vec::new(),
         }
     }
 
@@ -85,7 +94,7 @@ impl Desktop {
         if (idx.in_range(len)) && (idx.index() != self.base.focused_child_index.index()) {
             let handle = self.base.children[idx.index()];
             if !handle.is_none() {
-                RuntimeManager::get().request_focus_for_control(handle);
+                self.focus_window(handle);
             }
         }
     }
@@ -185,7 +194,24 @@ impl Desktop {
             );
         }
     }
+    /// Arranges the windows of the desktop. In tiling mode, the regions of the desktop are rebuilt instead (`Cascade` places all
+    /// windows as tabs of the same region, `Vertical` places them side by side, `Horizontal` one below the other and `Grid` in
+    /// rows of regions with the same size).
     pub fn arrange_windows(&mut self, method: ArrangeWindowsMethod) {
+        if let Some(tiles) = self.tiles.as_mut() {
+            let windows: Vec<Handle<()>> = self.base.children.iter().copied().filter(|h| tiles.contains(*h)).collect();
+            match method {
+                ArrangeWindowsMethod::Cascade => tiles.build_tabs(windows),
+                ArrangeWindowsMethod::Vertical => tiles.build_rows(vec![windows]),
+                ArrangeWindowsMethod::Horizontal => tiles.build_rows(windows.into_iter().map(|h| vec![h]).collect()),
+                ArrangeWindowsMethod::Grid => {
+                    let columns = ((windows.len() as f32).sqrt().ceil() as usize).max(1);
+                    tiles.build_rows(windows.chunks(columns).map(|row| row.to_vec()).collect());
+                }
+            }
+            self.update_tiles();
+            return;
+        }
         let r = self.desktop_rect();
         match method {
             ArrangeWindowsMethod::Cascade => self.arrange_cascade(r),
@@ -195,6 +221,489 @@ impl Desktop {
         }
     }
 
+    /// Saves the layout of the desktop (the position, size, maximized state, z-order and focus of every window)
+    /// into a text that can later be passed to `restore_layout(...)` (for example to restore the arrangement
+    /// of the windows the next time the application starts).
+    ///
+    /// Only the windows that have an identifier (set via `Window::set_id(...)`) are saved (if several windows have the same
+    /// identifier, only the first one is saved). The state of the controls
+    /// that implement the `PersistState` trait (such as the position of a splitter, the column widths and sort order of a
+    /// `ListView` or the current page of a `Tab`) is saved as well.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appcui::prelude::*;
+    ///
+    /// #[Desktop(events = DesktopEvents)]
+    /// struct MyDesktop {}
+    /// impl DesktopEvents for MyDesktop {
+    ///     fn on_start(&mut self) {
+    ///         self.add_window(window!("Logs,id:logs,x:1,y:1,w:40,h:10"));
+    ///         if let Ok(text) = std::fs::read_to_string("layout.txt") {
+    ///             let _ = self.restore_layout(&text);
+    ///         }
+    ///     }
+    ///     fn on_close(&mut self) -> ActionRequest {
+    ///         let _ = std::fs::write("layout.txt", self.save_layout());
+    ///         ActionRequest::Allow
+    ///     }
+    /// }
+    /// ```
+    pub fn save_layout(&self) -> String {
+        let r = self.desktop_rect();
+        let mut layout = DesktopLayout::new(r.size());
+        let controls = RuntimeManager::get().get_controls();
+        let count = self.base.children.len();
+        let focused_index = self.base.focused_child_index;
+        // the windows are saved in the order they are painted (the focused window is always painted last)
+        let start = if focused_index.in_range(count) { focused_index.index() + 1 } else { 0 };
+        for index in (0..count).map(|i| (start + i) % count) {
+            let handle = &self.base.children[index];
+            let Some(cm) = controls.get(*handle) else {
+                continue;
+            };
+            if !cm.base().is_window_control() {
+                continue;
+            }
+            // all window controls start with a Window object
+            let win = cm.get::<Window>();
+            // windows with the same id can not be told apart when the layout is restored (only the first one is saved)
+            if win.id().is_empty() || layout.windows.iter().any(|w| w.id == win.id()) {
+                continue;
+            }
+            let wr = win.normal_rect();
+            let focused = focused_index.in_range(count) && index == focused_index.index();
+            let mut w = WindowLayout::new(
+                win.id(),
+                Rect::with_point_and_size(Point::new(wr.left() - r.left(), wr.top() - r.top()), wr.size()),
+                win.is_maximized(),
+                focused,
+            );
+            Self::save_control_states(*handle, &mut Vec::new(), &mut w.states);
+            layout.windows.push(w);
+        }
+        layout.write()
+    }
+
+    /// Restores a layout previously saved via `save_layout()`. Every saved window is matched (by its identifier) with a window
+    /// from the desktop and is moved and resized accordingly (saved windows that are not found are ignored and windows that are
+    /// not part of the layout keep their position but are placed below the restored ones). If the size of the terminal is different
+    /// from the one the layout was saved with, the windows are scaled proportionally and kept within the desktop.
+    ///
+    /// Returns an error if the text is not a valid layout or if more than one window from the desktop has the identifier
+    /// of a saved window (in this case no window is changed).
+    pub fn restore_layout(&mut self, layout: &str) -> Result<(), Error> {
+        let layout = DesktopLayout::parse(layout)?;
+        let mut windows: Vec<(usize, Handle<()>)> = Vec::with_capacity(layout.windows.len());
+        for (index, w) in layout.windows.iter().enumerate() {
+            let mut handles = self.find_windows(&w.id);
+            if handles.len() > 1 {
+                return Err(Error::new(
+                    ErrorKind::InvalidParameter,
+                    format!("Multiple windows from the desktop have the id '{}'", w.id),
+                ));
+            }
+            if let Some(handle) = handles.pop() {
+                windows.push((index, handle));
+            }
+        }
+        let r = self.desktop_rect();
+        let rm = RuntimeManager::get();
+        let mut restored: Vec<(usize, Handle<()>)> = Vec::with_capacity(windows.len());
+        let mut focus = None;
+        for (index, handle) in windows {
+            let w = &layout.windows[index];
+            if let Some(win) = rm.get_control_mut(handle.cast::<Window>()) {
+                let wr = w.rect_for(layout.size, r.size());
+                win.set_normal_rect(Rect::with_point_and_size(Point::new(wr.left() + r.left(), wr.top() + r.top()), wr.size()), w.maximized);
+            }
+            if w.focused {
+                focus = Some(handle);
+            }
+            restored.push((index, handle));
+        }
+        // z-order: the restored windows are placed (in the saved order) on top of the ones that are not part of the layout
+        let focused = self.active_window_handle().map(|h| h.cast::<()>());
+        self.base.children.retain(|h| !restored.iter().any(|(_, r)| r == h));
+        self.base.children.extend(restored.iter().map(|(_, h)| *h));
+        if let Some(index) = self.base.children.iter().position(|h| Some(*h) == focused) {
+            self.base.focused_child_index = VectorIndex::with_value(index);
+        }
+        rm.request_recompute_parent_indexes();
+        // the controls need their new size before their state is restored (e.g. the position of a splitter)
+        rm.recompute_layouts();
+        for (index, handle) in restored.iter() {
+            for c in layout.windows[*index].states.iter() {
+                Self::restore_control_state(*handle, &c.path, &c.state);
+            }
+        }
+        if let Some(handle) = focus {
+            rm.request_focus_for_control(handle);
+        }
+        rm.request_update();
+        Ok(())
+    }
+
+    fn find_windows(&self, id: &str) -> Vec<Handle<()>> {
+        if id.is_empty() {
+            return Vec::new();
+        }
+        let controls = RuntimeManager::get().get_controls();
+        self.base
+            .children
+            .iter()
+            .copied()
+            .filter(|handle| match controls.get(*handle) {
+                Some(cm) => cm.base().is_window_control() && cm.get::<Window>().id() == id,
+                None => false,
+            })
+            .collect()
+    }
+    fn save_control_states(handle: Handle<()>, path: &mut Vec<usize>, output: &mut Vec<ControlState>) {
+        let controls = RuntimeManager::get().get_controls();
+        let Some(cm) = controls.get(handle) else {
+            return;
+        };
+        if let Some(state) = cm.control().save_state() {
+            // the state must fit on one line
+            if !state.contains(['\n', '\r']) {
+                output.push(ControlState { path: path.clone(), state });
+            }
+        }
+        for (index, child) in cm.base().children.iter().enumerate() {
+            path.push(index);
+            Self::save_control_states(*child, path, output);
+            path.pop();
+        }
+    }
+    fn restore_control_state(mut handle: Handle<()>, path: &[usize], state: &str) {
+        let controls = RuntimeManager::get().get_controls_mut();
+        for index in path {
+            let Some(child) = controls.get(handle).and_then(|cm| cm.base().children.get(*index).copied()) else {
+                return;
+            };
+            handle = child;
+        }
+        if let Some(cm) = controls.get_mut(handle) {
+            cm.control_mut().restore_state(state);
+        }
+    }
+
+    /// Enables or disables the tiling mode of the desktop. In tiling mode the area of the desktop is split into regions that never
+    /// overlap: a new window is docked next to the focused one, a window that is dragged with the mouse is docked next to the window
+    /// it is dropped over (or, if it is dropped in the middle of a region, is added as a tab of that region) and the bars between
+    /// the regions can be dragged to resize them. When the tiling mode is disabled, the windows are moved back to the position
+    /// they had before the tiling mode was enabled.
+    ///
+    /// The tiling mode can also be toggled by the user via `Ctrl+Alt+T` (see the `desktop.*` actions of a `KeyMap`).
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appcui::prelude::*;
+    ///
+    /// #[Desktop(events = DesktopEvents)]
+    /// struct MyDesktop {}
+    /// impl DesktopEvents for MyDesktop {
+    ///     fn on_start(&mut self) {
+    ///         self.set_tiling_mode(true);
+    ///         self.add_window(window!("Left,a:c,w:20,h:10"));
+    ///         self.add_window(window!("Right,a:c,w:20,h:10"));
+    ///     }
+    /// }
+    /// ```
+    pub fn set_tiling_mode(&mut self, enabled: bool) {
+        if enabled == self.tiles.is_some() {
+            return;
+        }
+        if enabled {
+            let mut tiles = TileTree::default();
+            let controls = RuntimeManager::get().get_controls();
+            for handle in self.base.children.iter() {
+                if let Some(cm) = controls.get(*handle) {
+                    if cm.base().is_window_control() {
+                        let win = cm.get::<Window>();
+                        tiles.floating_rects.push((*handle, win.normal_rect(), win.is_maximized()));
+                    }
+                }
+            }
+            self.tiles = Some(tiles);
+            self.sync_tiles();
+        } else if let Some(tiles) = self.tiles.take() {
+            let controls = RuntimeManager::get().get_controls_mut();
+            for handle in tiles.windows() {
+                if let Some(cm) = controls.get_mut(handle) {
+                    cm.base_mut().set_visible(true);
+                    if let Some((_, r, maximized)) = tiles.floating_rects.iter().find(|(h, _, _)| *h == handle) {
+                        cm.get_mut::<Window>().set_normal_rect(*r, *maximized);
+                    }
+                }
+            }
+            RuntimeManager::get().request_update();
+        }
+    }
+
+    /// Returns `true` if the desktop is in tiling mode.
+    pub fn is_tiling_mode(&self) -> bool {
+        self.tiles.is_some()
+    }
+
+    /// Docks a window next to another window (or, if the position is `DockPosition::Tab`, as a tab in the region of that window).
+    /// This method only works in tiling mode.
+    pub fn dock_window<T, U>(&mut self, window: Handle<T>, target: Handle<U>, position: DockPosition) {
+        let (window, target) = (window.cast::<()>(), target.cast::<()>());
+        let Some(tiles) = self.tiles.as_mut() else {
+            return;
+        };
+        if window == target || !tiles.contains(window) || !tiles.contains(target) {
+            return;
+        }
+        tiles.remove(window);
+        tiles.insert(window, target, position);
+        self.update_tiles();
+        RuntimeManager::get().request_focus_for_control(window);
+    }
+
+    // adds the windows that were added to the desktop to the tiling tree and removes the ones that were closed
+    pub(crate) fn sync_tiles(&mut self) {
+        let r = self.desktop_rect();
+        let focused = self.active_window_handle().map(|h| h.cast::<()>()).unwrap_or(Handle::None);
+        let Some(tiles) = self.tiles.as_mut() else {
+            return;
+        };
+        for handle in tiles.windows() {
+            if !self.base.children.contains(&handle) {
+                tiles.remove(handle);
+            }
+        }
+        let controls = RuntimeManager::get().get_controls();
+        let mut target = focused;
+        for handle in self.base.children.iter().copied() {
+            if tiles.contains(handle) || !controls.get(handle).is_some_and(|cm| cm.base().is_window_control()) {
+                continue;
+            }
+            // the region is split along its longest side (a character is about twice as tall as it is wide)
+            let position = match tiles.region(target).or_else(|| tiles.regions().last().copied()) {
+                Some(region) if region.rect.width() < region.rect.height() * 2 => DockPosition::Bottom,
+                _ => DockPosition::Right,
+            };
+            tiles.insert(handle, target, position);
+            tiles.layout(r);
+            target = handle;
+        }
+        self.update_tiles();
+    }
+
+    // moves every window in its region (only the current window of a region is visible)
+    pub(crate) fn update_tiles(&mut self) {
+        let r = self.desktop_rect();
+        let Some(tiles) = self.tiles.as_mut() else {
+            return;
+        };
+        tiles.layout(r);
+        let controls = RuntimeManager::get().get_controls_mut();
+        for region in tiles.regions() {
+            let wr = region.window_rect();
+            for (index, handle) in region.windows.iter().enumerate() {
+                if let Some(cm) = controls.get_mut(*handle) {
+                    cm.base_mut().set_visible(index == region.current);
+                    cm.get_mut::<Window>().set_normal_rect(wr, false);
+                }
+            }
+        }
+        RuntimeManager::get().request_update();
+    }
+
+    // called after a window was moved or resized with the mouse (in tiling mode the window is docked where it was dropped)
+    pub(crate) fn dock_dropped_window(&mut self, handle: Handle<()>, point: Option<Point>) {
+        let Some(tiles) = self.tiles.as_mut() else {
+            return;
+        };
+        let Some(region) = tiles.region(handle) else {
+            return;
+        };
+        let wr = region.window_rect();
+        let moved = RuntimeManager::get()
+            .get_controls()
+            .get(handle)
+            .is_some_and(|cm| cm.base().position() != wr.top_left() || cm.base().size() != wr.size());
+        if !moved {
+            return;
+        }
+        if let Some(p) = point {
+            if let Some(region) = tiles.region_at(p) {
+                let position = region.dock_position(p);
+                let target = if region.current_window() != handle {
+                    region.current_window()
+                } else {
+                    region.windows.iter().copied().find(|h| *h != handle).unwrap_or(Handle::None)
+                };
+                let same_region = region.windows.contains(&handle);
+                if !(target.is_none() || same_region && position == DockPosition::Tab) {
+                    tiles.remove(handle);
+                    tiles.insert(handle, target, position);
+                    RuntimeManager::get().request_focus_for_control(handle);
+                }
+            }
+        }
+        self.update_tiles();
+    }
+
+    // makes sure that a window (that might be a hidden tab of a region in tiling mode) is visible and requests the focus for it
+    fn focus_window(&mut self, handle: Handle<()>) {
+        if let Some(region) = self.tiles.as_mut().and_then(|t| t.region_mut(handle)) {
+            if region.current_window() != handle {
+                region.current = region.windows.iter().position(|h| *h == handle).unwrap_or(0);
+                self.update_tiles();
+            }
+        }
+        RuntimeManager::get().request_focus_for_control(handle);
+    }
+
+    // the region next to the region of the focused window (in the specified direction)
+    fn neighbour_region(&self, direction: DockPosition) -> Option<(Handle<()>, Handle<()>)> {
+        let tiles = self.tiles.as_ref()?;
+        let focused = self.active_window_handle()?.cast::<()>();
+        let r = tiles.region(focused)?.rect;
+        // the regions are separated by a bar (one character wide)
+        let p = match direction {
+            DockPosition::Left => Point::new(r.left() - 2, r.center_y()),
+            DockPosition::Right => Point::new(r.right() + 2, r.center_y()),
+            DockPosition::Top => Point::new(r.center_x(), r.top() - 2),
+            DockPosition::Bottom => Point::new(r.center_x(), r.bottom() + 2),
+            DockPosition::Tab => return None,
+        };
+        Some((focused, tiles.region_at(p).map(|n| n.current_window()).unwrap_or(Handle::None)))
+    }
+
+    fn move_focus_to(&mut self, direction: DockPosition) {
+        if let Some((_, neighbour)) = self.neighbour_region(direction) {
+            if !neighbour.is_none() {
+                self.focus_window(neighbour);
+            }
+        }
+    }
+
+    // moves the focused window (as a tab) into the neighbour region or, if there is no region in that direction,
+    // splits it from the other windows of its region
+    fn move_window_to(&mut self, direction: DockPosition) {
+        let Some((focused, neighbour)) = self.neighbour_region(direction) else {
+            return;
+        };
+        let Some(tiles) = self.tiles.as_mut() else {
+            return;
+        };
+        let (target, position) = if !neighbour.is_none() {
+            (neighbour, DockPosition::Tab)
+        } else {
+            let other = tiles.region(focused).and_then(|r| r.windows.iter().copied().find(|h| *h != focused));
+            (other.unwrap_or(Handle::None), direction)
+        };
+        if target.is_none() {
+            return;
+        }
+        tiles.remove(focused);
+        tiles.insert(focused, target, position);
+        self.update_tiles();
+        RuntimeManager::get().request_focus_for_control(focused);
+    }
+
+    fn tab_at(&self, point: Point) -> Option<Handle<()>> {
+        let region = self.tiles.as_ref()?.region_at(point)?;
+        if !region.has_tabs() || point.y != region.rect.top() {
+            return None;
+        }
+        let controls = RuntimeManager::get().get_controls();
+        let tabs = region.tabs(|h| controls.get(h).map(|cm| cm.get::<Window>().title()).unwrap_or(""));
+        let index = tabs.iter().position(|(x, w)| point.x >= *x && point.x < *x + *w)?;
+        Some(region.windows[index])
+    }
+
+    // paints the bars between the regions and the tabs of the regions that hold multiple windows
+    pub(crate) fn paint_tiles(&self, surface: &mut Surface, theme: &Theme) {
+        let Some(tiles) = self.tiles.as_ref() else {
+            return;
+        };
+        for (index, split) in tiles.splits().iter().enumerate() {
+            let attr = if tiles.dragged_split == Some(index) {
+                theme.lines.pressed_or_selected
+            } else {
+                theme.lines.normal
+            };
+            let b = split.bar;
+            if split.vertical {
+                surface.draw_vertical_line_with_size(b.left(), b.top(), b.height(), LineType::Single, attr);
+            } else {
+                surface.draw_horizontal_line_with_size(b.left(), b.top(), b.width(), LineType::Single, attr);
+            }
+        }
+        let controls = RuntimeManager::get().get_controls();
+        let focused = self.active_window_handle().map(|h| h.cast::<()>()).unwrap_or(Handle::None);
+        for region in tiles.regions().into_iter().filter(|r| r.has_tabs()) {
+            let r = region.rect;
+            surface.fill_horizontal_line(r.left(), r.top(), r.right(), Character::with_attributes(' ', theme.tab.text.normal));
+            let title = |h: Handle<()>| controls.get(h).map(|cm| cm.get::<Window>().title()).unwrap_or("");
+            for (index, (x, w)) in region.tabs(title).into_iter().enumerate() {
+                let handle = region.windows[index];
+                let attr = match () {
+                    _ if index != region.current => theme.tab.text.normal,
+                    _ if handle == focused => theme.tab.text.pressed_or_selected,
+                    _ => theme.tab.text.hovered,
+                };
+                surface.fill_horizontal_line_with_size(x, r.top(), w as u32, Character::with_attributes(' ', attr));
+                let text: String = title(handle).chars().take((w - 2).max(0) as usize).collect();
+                surface.write_string(x + 1, r.top(), &text, attr, false);
+            }
+        }
+    }
+
+    /// Shows the window switcher: a modal overlay that lists all the windows from the desktop in the most recently used order
+    /// (with their title, tag and a scaled-down preview of their content). Typing filters the list (by title or tag), the arrow keys
+    /// select a window and `Enter` activates it. `Escape` closes the switcher without changing the focused window.
+    ///
+    /// The switcher is shown by default when `Alt+0` is pressed (this can be remapped via the `desktop.window_switcher` action
+    /// of a KeyMap), but it can also be bound to a different key.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appcui::prelude::*;
+    ///
+    /// #[Desktop(events = CommandBarEvents, commands: Switch)]
+    /// struct MyDesktop {}
+    /// impl CommandBarEvents for MyDesktop {
+    ///     fn on_update_commandbar(&self, commandbar: &mut CommandBar) {
+    ///         commandbar.set(key!("F12"), "Windows", mydesktop::Commands::Switch);
+    ///     }
+    ///     fn on_event(&mut self, command_id: mydesktop::Commands) {
+    ///         match command_id {
+    ///             mydesktop::Commands::Switch => self.show_window_switcher(),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn show_window_switcher(&mut self) {
+        let windows = self.windows_in_mru_order();
+        if windows.is_empty() {
+            return;
+        }
+        if let Some(handle) = WindowSwitcher::new(&windows).show() {
+            self.focus_window(handle.cast());
+        }
+    }
+
+    // moves a window in front of the most recently used list
+    pub(crate) fn update_mru(&mut self, handle: Handle<()>) {
+        let children = &self.base.children;
+        self.mru.retain(|h| *h != handle && children.contains(h));
+        self.mru.insert(0, handle);
+    }
+
+    // all windows from the desktop (the ones that were never focused are added at the end, in the order they were created)
+    fn windows_in_mru_order(&self) -> Vec<Handle<()>> {
+        let mut windows: Vec<Handle<()>> = self.mru.iter().copied().filter(|h| self.base.children.contains(h)).collect();
+        windows.extend(self.base.children.iter().copied().filter(|h| !self.mru.contains(h)));
+        windows
+    }
+
     /// Adds a new window to the desktop. The window will be displayed on top of all other windows.
     pub fn add_window<T>(&mut self, window: T) -> Handle<T>
     where
@@ -210,7 +719,29 @@ impl OnPaint for Desktop {
 }
 impl OnKeyPressed for Desktop {
     fn on_key_pressed(&mut self, key: Key, _: char) -> EventProcessStatus {
+        let (key, _) = KeyMap::translate_key("desktop", key, '\0');
+        let direction = match key.code {
+            KeyCode::Left => Some(DockPosition::Left),
+            KeyCode::Right => Some(DockPosition::Right),
+            KeyCode::Up => Some(DockPosition::Top),
+            KeyCode::Down => Some(DockPosition::Bottom),
+            _ => None,
+        };
+        if let Some(direction) = direction.filter(|_| self.is_tiling_mode()) {
+            if key == Key::new(key.code, KeyModifier::Alt | KeyModifier::Shift) {
+                self.move_focus_to(direction);
+                return EventProcessStatus::Processed;
+            }
+            if key == Key::new(key.code, KeyModifier::Ctrl | KeyModifier::Alt | KeyModifier::Shift) {
+                self.move_window_to(direction);
+                return EventProcessStatus::Processed;
+            }
+        }
         match key.value() {
+            key!("Ctrl+Alt+T") => {
+                self.set_tiling_mode(!self.is_tiling_mode());
+                return EventProcessStatus::Processed;
+            }
             key!("Escape") => {
                 if let Some(desktop_interface) = self.interface_mut() {
                     if DesktopEvents::on_close(desktop_interface) == ActionRequest::Allow {
@@ -228,21 +759,63 @@ impl OnKeyPressed for Desktop {
                 return EventProcessStatus::Processed;
             }
             key!("Alt+0") => {
-                // Dialogs::WindowManager::Show();
+                self.show_window_switcher();
                 return EventProcessStatus::Processed;
             }
             _ => {}
         }
         // check controls hot keys
         let controls = RuntimeManager::get().get_controls_mut();
-        for ctrl in self.children.iter() {
-            if let Some(child) = controls.get(*ctrl) {
-                if child.base().hotkey() == key {
-                    RuntimeManager::get().request_focus_for_control(*ctrl);
+        let hotkey_owner = self.children.iter().copied().find(|ctrl| controls.get(*ctrl).is_some_and(|c| c.base().hotkey() == key));
+        if let Some(handle) = hotkey_owner {
+            self.focus_window(handle);
+            return EventProcessStatus::Processed;
+        }
+        EventProcessStatus::Ignored
+    }
+}
+impl OnMouseEvent for Desktop {
+    fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        let Some(tiles) = self.tiles.as_mut() else {
+            return EventProcessStatus::Ignored;
+        };
+        match event {
+            MouseEvent::Pressed(data) => {
+                let p = Point::new(data.x, data.y);
+                if let Some(index) = tiles.split_bar_at(p) {
+                    tiles.dragged_split = Some(index);
                     return EventProcessStatus::Processed;
                 }
+                if let Some(handle) = self.tab_at(p) {
+                    self.focus_window(handle);
+                    return EventProcessStatus::Processed;
+                }
+                EventProcessStatus::Ignored
+            }
+            MouseEvent::Drag(data) => {
+                let Some(index) = tiles.dragged_split else {
+                    return EventProcessStatus::Ignored;
+                };
+                if let Some(split) = tiles.split_mut(index) {
+                    let r = split.rect;
+                    let (pos, size) = if split.vertical {
+                        (data.x - r.left(), r.width() as i32 - 1)
+                    } else {
+                        (data.y - r.top(), r.height() as i32 - 1)
+                    };
+                    split.percentage = (pos as f32 / size.max(1) as f32).clamp(0.0, 1.0);
+                }
+                self.update_tiles();
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Released(_) => {
+                if tiles.dragged_split.take().is_some() {
+                    EventProcessStatus::Processed
+                } else {
+                    EventProcessStatus::Ignored
+                }
             }
+            _ => EventProcessStatus::Ignored,
         }
-        EventProcessStatus::Ignored
     }
 }
@@ -0,0 +1,194 @@
+use crate::graphics::{Point, Rect, Size};
+use crate::system::{Error, ErrorKind};
+use std::fmt::Write;
+
+const WINDOW_SECTION: &str = "[window:";
+const DESKTOP_SECTION: &str = "[desktop]";
+
+pub(super) struct ControlState {
+    // the indexes of the control (and its parents) starting from the window
+    pub(super) path: Vec<usize>,
+    pub(super) state: String,
+}
+
+pub(super) struct WindowLayout {
+    pub(super) id: String,
+    // relative to the top-left corner of the desktop
+    pub(super) rect: Rect,
+    pub(super) maximized: bool,
+    pub(super) focused: bool,
+    pub(super) states: Vec<ControlState>,
+}
+
+impl WindowLayout {
+    pub(super) fn new(id: &str, rect: Rect, maximized: bool, focused: bool) -> Self {
+        Self {
+            id: id.to_string(),
+            rect,
+            maximized,
+            focused,
+            states: Vec::new(),
+        }
+    }
+    // translates the rect of the window from a desktop with the size `from` into a desktop with the size `to`
+    // (proportionally if the sizes are different) and makes sure that the window is visible
+    pub(super) fn rect_for(&self, from: Size, to: Size) -> Rect {
+        let scale = |value: i32, from: u32, to: u32| -> i32 {
+            if from == to || from == 0 {
+                value
+            } else {
+                ((value as i64) * (to as i64) / (from as i64)) as i32
+            }
+        };
+        let w = scale(self.rect.width() as i32, from.width, to.width).clamp(1, to.width.max(1) as i32);
+        let h = scale(self.rect.height() as i32, from.height, to.height).clamp(1, to.height.max(1) as i32);
+        let x = scale(self.rect.left(), from.width, to.width).clamp(0, (to.width as i32 - w).max(0));
+        let y = scale(self.rect.top(), from.height, to.height).clamp(0, (to.height as i32 - h).max(0));
+        Rect::with_point_and_size(Point::new(x, y), Size::new(w as u32, h as u32))
+    }
+}
+
+pub(super) struct DesktopLayout {
+    pub(super) size: Size,
+    // from bottom to top (the same order as the windows are kept by the desktop)
+    pub(super) windows: Vec<WindowLayout>,
+}
+
+impl DesktopLayout {
+    pub(super) fn new(size: Size) -> Self {
+        Self { size, windows: Vec::new() }
+    }
+
+    pub(super) fn parse(text: &str) -> Result<Self, Error> {
+        let mut layout = DesktopLayout::new(Size::new(0, 0));
+        let mut in_window = false;
+        for (line_index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: &str| Error::new(ErrorKind::InvalidParameter, format!("Line {}: {message} ('{line}')", line_index + 1));
+            if line.eq_ignore_ascii_case(DESKTOP_SECTION) {
+                in_window = false;
+                continue;
+            }
+            if let Some(id) = line.strip_prefix(WINDOW_SECTION) {
+                let Some(id) = Self::parse_id(id) else {
+                    return Err(error("expecting a window section (in the format [window:<id>])"));
+                };
+                if layout.windows.iter().any(|w| w.id == id) {
+                    return Err(error("duplicate window id"));
+                }
+                layout.windows.push(WindowLayout::new(&id, Rect::new(0, 0, 0, 0), false, false));
+                in_window = true;
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(error("expecting 'key = value'"));
+            };
+            let key = key.trim();
+            let value = value.trim();
+            if !in_window {
+                if key == "size" {
+                    layout.size = Self::parse_size(value).ok_or_else(|| error("invalid desktop size"))?;
+                }
+                // unknown keys are ignored (they might have been added by a newer version)
+                continue;
+            }
+            let Some(w) = layout.windows.last_mut() else {
+                continue;
+            };
+            match key {
+                "rect" => w.rect = Self::parse_rect(value).ok_or_else(|| error("invalid window rect"))?,
+                "maximized" => w.maximized = Self::parse_bool(value).ok_or_else(|| error("expecting true or false"))?,
+                "focused" => w.focused = Self::parse_bool(value).ok_or_else(|| error("expecting true or false"))?,
+                "state" => w.states.push(ControlState { path: Vec::new(), state: value.to_string() }),
+                _ => {
+                    if let Some(path) = key.strip_prefix("control.") {
+                        let path: Option<Vec<usize>> = path.split('.').map(|p| p.parse::<usize>().ok()).collect();
+                        let Some(path) = path else {
+                            return Err(error("invalid control path"));
+                        };
+                        w.states.push(ControlState { path, state: value.to_string() });
+                    }
+                }
+            }
+        }
+        Ok(layout)
+    }
+
+    pub(super) fn write(&self) -> String {
+        let mut s = String::with_capacity(256);
+        s.push_str(DESKTOP_SECTION);
+        let _ = writeln!(s, "\nsize = {}x{}", self.size.width, self.size.height);
+        for w in self.windows.iter() {
+            let _ = write!(s, "\n{WINDOW_SECTION}");
+            Self::write_id(&mut s, &w.id);
+            s.push_str("]\n");
+            let _ = writeln!(s, "rect = {},{},{},{}", w.rect.left(), w.rect.top(), w.rect.width(), w.rect.height());
+            let _ = writeln!(s, "maximized = {}", w.maximized);
+            let _ = writeln!(s, "focused = {}", w.focused);
+            for c in w.states.iter() {
+                s.push_str(if c.path.is_empty() { "state" } else { "control" });
+                for index in c.path.iter() {
+                    let _ = write!(s, ".{index}");
+                }
+                let _ = writeln!(s, " = {}", c.state);
+            }
+        }
+        s
+    }
+
+    // the id can contain any character ('\\', ']' and the new line characters are escaped with a backslash)
+    fn write_id(s: &mut String, id: &str) {
+        for ch in id.chars() {
+            match ch {
+                '\\' => s.push_str("\\\\"),
+                ']' => s.push_str("\\]"),
+                '\n' => s.push_str("\\n"),
+                '\r' => s.push_str("\\r"),
+                _ => s.push(ch),
+            }
+        }
+    }
+    // parses the text after "[window:" (an escaped id followed by ']')
+    fn parse_id(text: &str) -> Option<String> {
+        let mut id = String::with_capacity(text.len());
+        let mut chars = text.chars();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\\' => match chars.next()? {
+                    'n' => id.push('\n'),
+                    'r' => id.push('\r'),
+                    ch @ ('\\' | ']') => id.push(ch),
+                    _ => return None,
+                },
+                ']' => return if chars.next().is_none() { Some(id) } else { None },
+                _ => id.push(ch),
+            }
+        }
+        None
+    }
+    fn parse_size(value: &str) -> Option<Size> {
+        let (w, h) = value.split_once('x')?;
+        Some(Size::new(w.trim().parse().ok()?, h.trim().parse().ok()?))
+    }
+    fn parse_rect(value: &str) -> Option<Rect> {
+        let mut v = [0i32; 4];
+        let mut parts = value.split(',');
+        for item in v.iter_mut() {
+            *item = parts.next()?.trim().parse().ok()?;
+        }
+        if parts.next().is_some() || v[2] <= 0 || v[3] <= 0 {
+            return None;
+        }
+        Some(Rect::with_point_and_size(Point::new(v[0], v[1]), Size::new(v[2] as u32, v[3] as u32)))
+    }
+    fn parse_bool(value: &str) -> Option<bool> {
+        match value {
+            "true" => Some(true),
+            "false" => Some(false),
+            _ => None,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{prelude::*, ui};
+use crate::ui::desktop::ArrangeWindowsMethod;
 
 use super::EmptyDesktop;
 
@@ -736,3 +737,395 @@ fn check_commands_ignored() {
     let a = App::debug(40, 6, script).desktop(MyDesktop::new()).build().unwrap();
     a.run();
 }
+
+#[test]
+fn check_save_and_restore_layout() {
+    #[CustomControl(overwrite = PersistState, internal = true)]
+    struct Counter {
+        value: u32,
+    }
+    impl Counter {
+        fn new(value: u32) -> Self {
+            Self {
+                base: ControlBase::new(layout!("x:0,y:0,w:1,h:1"), true),
+                value,
+            }
+        }
+    }
+    impl PersistState for Counter {
+        fn save_state(&self) -> Option<String> {
+            Some(self.value.to_string())
+        }
+        fn restore_state(&mut self, state: &str) {
+            self.value = state.parse().unwrap_or(0);
+        }
+    }
+
+    #[Desktop(events = CommandBarEvents+DesktopEvents, commands: [Save, Change, Restore], internal = true)]
+    struct MyDesktop {
+        layout: String,
+        counter: Handle<Counter>,
+        splitter: Handle<VSplitter>,
+        tab: Handle<Tab>,
+    }
+    impl MyDesktop {
+        fn new() -> Self {
+            Self {
+                base: Desktop::new(),
+                layout: String::new(),
+                counter: Handle::None,
+                splitter: Handle::None,
+                tab: Handle::None,
+            }
+        }
+    }
+    impl DesktopEvents for MyDesktop {
+        fn on_start(&mut self) {
+            let mut w = window!("First,id:first,x:1,y:1,w:20,h:8");
+            self.counter = w.add(Counter::new(5));
+            self.add_window(w);
+            let mut w = window!("Second,id:second,x:24,y:2,w:34,h:10");
+            let mut vs = vsplitter!("pos:50%,d:f");
+            let mut t = tab!("l:0,t:0,r:0,b:0,tabs:['A','B','C']");
+            t.add(0, label!("Page-A,x:1,y:1,w:8,h:1"));
+            t.add(1, label!("Page-B,x:1,y:1,w:8,h:1"));
+            t.add(2, label!("Page-C,x:1,y:1,w:8,h:1"));
+            self.tab = vs.add(vsplitter::Panel::Right, t);
+            self.splitter = w.add(vs);
+            self.add_window(w);
+            self.add_window(window!("'No id',x:10,y:9,w:20,h:5"));
+        }
+    }
+    impl CommandBarEvents for MyDesktop {
+        fn on_update_commandbar(&self, commandbar: &mut CommandBar) {
+            commandbar.set(key!("F2"), "Save", mydesktop::Commands::Save);
+            commandbar.set(key!("F3"), "Change", mydesktop::Commands::Change);
+            commandbar.set(key!("F4"), "Restore", mydesktop::Commands::Restore);
+        }
+        fn on_event(&mut self, command: mydesktop::Commands) {
+            match command {
+                mydesktop::Commands::Save => {
+                    self.layout = self.save_layout();
+                    assert_eq!(
+                        self.layout,
+                        "[desktop]\nsize = 60x19\n\n[window:first]\nrect = 1,1,20,8\nmaximized = false\nfocused = false\ncontrol.0 = 5\n\n[window:second]\nrect = 24,2,34,10\nmaximized = false\nfocused = false\ncontrol.0 = 0.5\ncontrol.0.1.0 = 0\n"
+                    );
+                }
+                mydesktop::Commands::Change => {
+                    // change everything that is saved in the layout
+                    self.arrange_windows(ArrangeWindowsMethod::Cascade);
+                    Window::update_control(self.counter, |c| c.value = 9);
+                    Window::update_control(self.splitter, |vs| vs.set_position(5));
+                    Window::update_control(self.tab, |t| t.set_current_tab(2));
+                }
+                mydesktop::Commands::Restore => {
+                    let layout = self.layout.clone();
+                    self.restore_layout(&layout).unwrap();
+                    Window::update_control(self.counter, |c| assert_eq!(c.value, 5));
+                }
+            }
+        }
+    }
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Initial state')
+        CheckHash(0x73B950A8456E96D)
+        Key.Pressed(F2)
+        Key.Pressed(F3)
+        Paint('2. Windows arranged, splitter moved, tab C selected')
+        CheckHash(0xEF06CC80D3C7755D)
+        Key.Pressed(F4)
+        Paint('3. Layout restored (the window without id is at the bottom)')
+        CheckHash(0xB04377C1FD9125C0)
+    ";
+    let a = App::debug(60, 20, script).desktop(MyDesktop::new()).command_bar().build().unwrap();
+    a.run();
+}
+
+#[test]
+fn check_restore_layout_with_different_size() {
+    #[Desktop(events = DesktopEvents, internal = true)]
+    struct MyDesktop {}
+    impl MyDesktop {
+        fn new() -> Self {
+            Self { base: Desktop::new() }
+        }
+    }
+    impl DesktopEvents for MyDesktop {
+        fn on_start(&mut self) {
+            self.add_window(window!("A,id:a,x:0,y:0,w:20,h:5"));
+            self.add_window(window!("B,id:b,x:0,y:0,w:20,h:5"));
+            self.add_window(window!("C,id:c,x:0,y:0,w:20,h:5"));
+            self.add_window(window!("D,id:d,x:0,y:0,w:20,h:5"));
+            // invalid layouts (nothing is changed)
+            assert!(self.restore_layout("[window:a]\nrect = 1,2").is_err());
+            assert!(self.restore_layout("[window:a\nrect = 1,2,3,4").is_err());
+            assert!(self.restore_layout("[window:a]\nfocused = yes").is_err());
+            assert!(self.restore_layout("[window:a]\ncontrol.x.1 = 5").is_err());
+            assert!(self.restore_layout("[desktop]\nsize = 100").is_err());
+            assert!(self.restore_layout("[window:a]\n[window:b]\n[window:a]").is_err());
+            assert!(self.restore_layout("[window:a]b]\nrect = 1,2,3,4").is_err());
+            assert!(self.restore_layout("[window:a\\x]\nrect = 1,2,3,4").is_err());
+            // the layout was saved on a 120x40 desktop
+            let layout = "
+                # saved layout
+                [desktop]
+                size = 120x40
+                version = 2
+
+                [window:missing]
+                rect = 0,0,10,10
+
+                [window:a]
+                rect = 0,0,40,10
+                maximized = true
+
+                [window:c]
+                rect = 200,50,30,10
+                maximized = false
+
+                [window:b]
+                rect = 20,10,60,20
+                maximized = false
+                focused = true
+            ";
+            self.restore_layout(layout).unwrap();
+        }
+    }
+    let script = "
+        Paint.Enable(false)
+        Paint('B (focused) at 10,5 (30x10), C (15x5) moved inside the desktop, A maximized, D at the bottom')
+        CheckHash(0xA716C06DAE746BE0)
+        Key.Pressed(Ctrl+Tab)
+        Paint('D focused')
+        CheckHash(0x1611EDA04B13FD1D)
+    ";
+    let a = App::debug(60, 20, script).desktop(MyDesktop::new()).build().unwrap();
+    a.run();
+}
+
+#[test]
+fn check_layout_window_ids() {
+    #[Desktop(events = DesktopEvents, internal = true)]
+    struct MyDesktop {}
+    impl MyDesktop {
+        fn new() -> Self {
+            Self { base: Desktop::new() }
+        }
+    }
+    impl DesktopEvents for MyDesktop {
+        fn on_start(&mut self) {
+            let mut w = window!("A,x:0,y:0,w:20,h:5");
+            w.set_id("a]\\b\nc");
+            self.add_window(w);
+            self.add_window(window!("B,id:dup,x:5,y:5,w:20,h:5"));
+            self.add_window(window!("C,id:dup,x:10,y:10,w:20,h:5"));
+            // the id is escaped and only one of the windows with the same id is saved
+            let layout = self.save_layout();
+            assert!(layout.contains("[window:a\\]\\\\b\\nc]\n"));
+            assert_eq!(layout.matches("[window:dup]").count(), 1);
+            // the window with the duplicated id can not be restored (and nothing is changed)
+            assert!(self.restore_layout(&layout).is_err());
+            assert!(self.restore_layout("[window:dup]\nrect = 0,0,10,3").is_err());
+            self.restore_layout("[window:a\\]\\\\b\\nc]\nrect = 30,10,20,8\n\n[window:missing]\nrect = 0,0,10,3")
+                .unwrap();
+        }
+    }
+    let script = "
+        Paint.Enable(false)
+        Paint('A moved to 30,10 (20x8) and placed on top, B and C not moved')
+        CheckHash(0x2EF986D42C89133C)
+    ";
+    let a = App::debug(60, 20, script).desktop(MyDesktop::new()).build().unwrap();
+    a.run();
+}
+
+#[test]
+fn check_tiling_mode() {
+    let script = "
+        Paint.Enable(false)
+        Paint('Initial state (floating windows)')
+        CheckHash(0xEDF85CEA712207C9)
+        Key.Pressed(Ctrl+Alt+T)
+        Paint('Tiling: Win-1 | Win-2 / Win-3 (Win-3 has focus)')
+        CheckHash(0xA097630A3725BF87)
+        Key.Pressed(Alt+Shift+Up)
+        Paint('Win-2 has focus')
+        CheckHash(0xB5F5F1A3021246FC)
+        Key.Pressed(Alt+Shift+Left)
+        Paint('Win-1 has focus')
+        CheckHash(0xC2A008E2AEBEB8)
+        Key.Pressed(Alt+Shift+Left)
+        Paint('nothing changes')
+        CheckHash(0xC2A008E2AEBEB8)
+        Key.Pressed(Alt+Shift+Right)
+        Key.Pressed(Ctrl+Alt+Shift+Left)
+        Paint('Win-2 is a tab next to Win-1 (Win-1,Win-2 | Win-3)')
+        CheckHash(0xFEC54C7470B108CF)
+        Mouse.Click(3,0,left)
+        Paint('Win-1 is the current tab')
+        CheckHash(0xA0352DA6E3D6F064)
+        Key.Pressed(Ctrl+Alt+Shift+Down)
+        Paint('Win-1 is split from Win-2 and placed below it')
+        CheckHash(0xC33E8839C623B925)
+        Mouse.Drag(40,5,20,5)
+        Paint('the vertical bar was moved to x=20')
+        CheckHash(0x25BD8F26592BCEB5)
+        Mouse.Drag(30,0,1,5)
+        Paint('Win-3 is docked on the left side of Win-2 (Win-3 | Win-2 / Win-1)')
+        CheckHash(0xF414AF2443AABD85)
+        Key.Pressed(Ctrl+Alt+T)
+        Paint('Floating windows (back to the initial position)')
+        CheckHash(0xEDF85CEA712207C9)
+    ";
+    let mut a = App::debug(80, 20, script).build().unwrap();
+    a.add_window(window!("Win-1,x:1,y:1,w:20,h:10"));
+    a.add_window(window!("Win-2,x:16,y:3,w:20,h:10"));
+    a.add_window(window!("Win-3,x:31,y:5,w:20,h:10"));
+    a.run();
+}
+
+#[test]
+fn check_tiling_mode_with_new_windows() {
+    #[Desktop(events = CommandBarEvents+DesktopEvents, commands: [Add, Grid, Dock], internal = true)]
+    struct MyDesktop {
+        first: Handle<Window>,
+        count: u32,
+    }
+    impl MyDesktop {
+        fn new() -> Self {
+            Self {
+                base: Desktop::new(),
+                first: Handle::None,
+                count: 0,
+            }
+        }
+    }
+    impl DesktopEvents for MyDesktop {
+        fn on_start(&mut self) {
+            self.set_tiling_mode(true);
+            assert!(self.is_tiling_mode());
+            self.first = self.add_window(window!("First,x:1,y:1,w:20,h:5"));
+        }
+    }
+    impl CommandBarEvents for MyDesktop {
+        fn on_update_commandbar(&self, commandbar: &mut CommandBar) {
+            commandbar.set(key!("F2"), "Add", mydesktop::Commands::Add);
+            commandbar.set(key!("F3"), "Grid", mydesktop::Commands::Grid);
+            commandbar.set(key!("F4"), "Dock", mydesktop::Commands::Dock);
+        }
+        fn on_event(&mut self, command_id: mydesktop::Commands) {
+            match command_id {
+                mydesktop::Commands::Add => {
+                    self.count += 1;
+                    let w = Window::new(&format!("W{}", self.count), layout!("x:0,y:0,w:10,h:5"), window::Flags::None);
+                    self.add_window(w);
+                }
+                mydesktop::Commands::Grid => self.arrange_windows(desktop::ArrangeWindowsMethod::Grid),
+                mydesktop::Commands::Dock => {
+                    if let Some(active) = self.active_window_handle() {
+                        let first = self.first;
+                        self.dock_window(active, first, desktop::DockPosition::Tab);
+                    }
+                }
+            }
+        }
+    }
+    let script = "
+        Paint.Enable(false)
+        Paint('First window fills the desktop')
+        CheckHash(0x6E6FA43B0E0B6223)
+        Key.Pressed(F2)
+        Paint('W1 is docked on the right side of First')
+        CheckHash(0x2686694E3A23DF0F)
+        Key.Pressed(F2)
+        Paint('W2 is docked on the right side of W1')
+        CheckHash(0x76DB46D4A761FB88)
+        Key.Pressed(F2)
+        Paint('W3 is docked below W2')
+        CheckHash(0xB7E8B59F1E320DAA)
+        Key.Pressed(Escape)
+        Paint('W3 was closed (W2 fills its region again)')
+        CheckHash(0x1293D4A278882E18)
+        Key.Pressed(F3)
+        Paint('Grid: First | W1 / W2')
+        CheckHash(0x387E3F88C6BCBBFB)
+        Mouse.Click(30,10,left)
+        Key.Pressed(F4)
+        Paint('W2 is a tab next to First')
+        CheckHash(0x15F0E5B3D6E99218)
+        Resize(50,12)
+        Paint('Regions are resized (First,W2 | W1)')
+        CheckHash(0x8917B20B5A826B57)
+        Key.Pressed(Ctrl+Tab)
+        Paint('First is the current tab')
+        CheckHash(0x7E373F77BBC5C86B)
+    ";
+    let a = App::debug(60, 15, script).desktop(MyDesktop::new()).command_bar().build().unwrap();
+    a.run();
+}
+
+#[test]
+fn check_window_switcher() {
+    let script = "
+        Paint.Enable(false)
+        Paint('Initial state (Notes has focus)')
+        CheckHash(0xB9EBBB2B51391BBD)
+        Key.Pressed(Alt+0)
+        Paint('Switcher: Notes,Editor,Console (Editor is selected, with its preview)')
+        CheckHash(0x6A583E3659280D87)
+        Key.Pressed(Down)
+        Paint('Console is selected (preview of Console)')
+        CheckHash(0x197BB13AAC307A5)
+        Key.Pressed(Enter)
+        Paint('Console has focus')
+        CheckHash(0x1174294DDF914E8F)
+        Key.Pressed(Alt+0)
+        Paint('Switcher: Console,Notes,Editor (Notes is selected)')
+        CheckHash(0xB8A9DAE2184DF2E1)
+        Key.TypeText('log')
+        Paint('Filter: log -> only Console (tag: logs)')
+        CheckHash(0xE62D313E779A4F08)
+        Key.TypeText('x')
+        Paint('Filter: logx -> No windows')
+        CheckHash(0x4A62B820BDAE91D6)
+        Key.Pressed(Escape)
+        Paint('Console still has focus')
+        CheckHash(0x1174294DDF914E8F)
+    ";
+    let mut a = App::debug(60, 20, script).build().unwrap();
+    let mut w = window!("Editor,x:1,y:1,w:24,h:8,tag:src");
+    w.add(label!("'fn main() {}',x:1,y:1,w:20"));
+    a.add_window(w);
+    let mut w = window!("Console,x:20,y:4,w:24,h:8,tag:logs");
+    w.add(label!("'$ cargo build',x:1,y:1,w:20"));
+    a.add_window(w);
+    let mut w = window!("Notes,x:35,y:8,w:24,h:8");
+    w.add(label!("'TODO',x:1,y:1,w:20"));
+    a.add_window(w);
+    a.run();
+}
+
+#[test]
+fn check_window_switcher_scaled_preview() {
+    let script = "
+        Paint.Enable(false)
+        Key.Pressed(Alt+0)
+        Paint('Preview of the first window (scaled down)')
+        CheckHash(0x9E3A040AA3AAD588)
+        Key.Pressed(Tab)
+        Paint('Preview of the second window (scaled down)')
+        CheckHash(0x12F6907B9EC3D084)
+        Key.Pressed(Tab)
+        Paint('Back to the first window')
+        CheckHash(0x9E3A040AA3AAD588)
+    ";
+    let mut a = App::debug(50, 14, script).build().unwrap();
+    let mut w = window!("First,d:f");
+    w.add(label!("'1111111111111111111111111111',x:1,y:1,w:30"));
+    a.add_window(w);
+    let mut w = window!("Second,d:f");
+    w.add(label!("'2222',x:1,y:8,w:4"));
+    a.add_window(w);
+    a.run();
+}
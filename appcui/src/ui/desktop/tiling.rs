@@ -0,0 +1,376 @@
+use crate::graphics::{Point, Rect};
+use crate::system::Handle;
+
+/// The position where a window is docked (relative to a target window) when the desktop is in tiling mode.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum DockPosition {
+    /// The region of the target window is split and the window is placed on its left side
+    Left,
+    /// The region of the target window is split and the window is placed on its right side
+    Right,
+    /// The region of the target window is split and the window is placed above it
+    Top,
+    /// The region of the target window is split and the window is placed below it
+    Bottom,
+    /// The window is added as a new tab in the region of the target window
+    Tab,
+}
+
+// the maximum width of a tab (in the tab strip of a region that holds multiple windows)
+const MAX_TAB_WIDTH: i32 = 24;
+
+#[derive(Default)]
+pub(super) struct Region {
+    pub(super) windows: Vec<Handle<()>>,
+    pub(super) current: usize,
+    // the entire area of the region (including the tab strip if the region has multiple windows)
+    pub(super) rect: Rect,
+}
+
+impl Region {
+    fn with_window(handle: Handle<()>) -> Self {
+        Self {
+            windows: vec![handle],
+            current: 0,
+            rect: Rect::default(),
+        }
+    }
+    #[inline(always)]
+    pub(super) fn has_tabs(&self) -> bool {
+        self.windows.len() > 1
+    }
+    #[inline(always)]
+    pub(super) fn current_window(&self) -> Handle<()> {
+        self.windows.get(self.current).copied().unwrap_or(Handle::None)
+    }
+    // the area where the windows of the region are placed
+    pub(super) fn window_rect(&self) -> Rect {
+        if self.has_tabs() && self.rect.height() > 1 {
+            Rect::new(self.rect.left(), self.rect.top() + 1, self.rect.right(), self.rect.bottom())
+        } else {
+            self.rect
+        }
+    }
+    // the position (x) and width of every tab from the tab strip (the tabs that do not fit have the width 0)
+    pub(super) fn tabs<'a, F>(&self, title: F) -> Vec<(i32, i32)>
+    where
+        F: Fn(Handle<()>) -> &'a str,
+    {
+        let mut x = self.rect.left();
+        let mut result = Vec::with_capacity(self.windows.len());
+        for handle in self.windows.iter() {
+            let w = (title(*handle).chars().count() as i32 + 2).min(MAX_TAB_WIDTH).min(self.rect.right() + 1 - x).max(0);
+            result.push((x, w));
+            x += w + 1;
+        }
+        result
+    }
+    // where a window dropped at the specified point is docked (the middle of the region means a new tab)
+    pub(super) fn dock_position(&self, point: Point) -> DockPosition {
+        let r = self.rect;
+        let dx = (point.x - r.left()) as f32 / r.width() as f32;
+        let dy = (point.y - r.top()) as f32 / r.height() as f32;
+        if (0.25..0.75).contains(&dx) && (0.25..0.75).contains(&dy) {
+            return DockPosition::Tab;
+        }
+        [(dx, DockPosition::Left), (1.0 - dx, DockPosition::Right), (dy, DockPosition::Top), (1.0 - dy, DockPosition::Bottom)]
+            .into_iter()
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, position)| position)
+            .unwrap_or(DockPosition::Tab)
+    }
+}
+
+pub(super) struct Split {
+    // true if the two parts are placed side by side (separated by a vertical bar)
+    pub(super) vertical: bool,
+    pub(super) percentage: f32,
+    pub(super) rect: Rect,
+    pub(super) bar: Rect,
+    pub(super) first: Box<Node>,
+    pub(super) second: Box<Node>,
+}
+
+pub(super) enum Node {
+    Region(Region),
+    Split(Split),
+}
+
+enum RemoveResult {
+    NotFound,
+    Removed,
+    Empty,
+}
+
+impl Node {
+    fn region(&self, handle: Handle<()>) -> Option<&Region> {
+        match self {
+            Node::Region(r) => r.windows.contains(&handle).then_some(r),
+            Node::Split(s) => s.first.region(handle).or_else(|| s.second.region(handle)),
+        }
+    }
+    fn region_mut(&mut self, handle: Handle<()>) -> Option<&mut Region> {
+        match self {
+            Node::Region(r) => r.windows.contains(&handle).then_some(r),
+            Node::Split(s) => match s.first.region_mut(handle) {
+                Some(r) => Some(r),
+                None => s.second.region_mut(handle),
+            },
+        }
+    }
+    // the node of the region that contains the window
+    fn region_node_mut(&mut self, handle: Handle<()>) -> Option<&mut Node> {
+        match self {
+            Node::Region(r) => {
+                if r.windows.contains(&handle) {
+                    Some(self)
+                } else {
+                    None
+                }
+            }
+            Node::Split(s) => match s.first.region_node_mut(handle) {
+                Some(n) => Some(n),
+                None => s.second.region_node_mut(handle),
+            },
+        }
+    }
+    fn last_region_node_mut(&mut self) -> &mut Node {
+        match self {
+            Node::Region(_) => self,
+            Node::Split(s) => s.second.last_region_node_mut(),
+        }
+    }
+    fn remove(&mut self, handle: Handle<()>) -> RemoveResult {
+        match self {
+            Node::Region(r) => {
+                let Some(index) = r.windows.iter().position(|h| *h == handle) else {
+                    return RemoveResult::NotFound;
+                };
+                r.windows.remove(index);
+                if r.windows.is_empty() {
+                    return RemoveResult::Empty;
+                }
+                if r.current > index || r.current >= r.windows.len() {
+                    r.current = r.current.saturating_sub(1);
+                }
+                RemoveResult::Removed
+            }
+            Node::Split(s) => {
+                let (result, keep_first) = match s.first.remove(handle) {
+                    RemoveResult::NotFound => (s.second.remove(handle), true),
+                    result => (result, false),
+                };
+                if let RemoveResult::Empty = result {
+                    // the split is replaced by its other part
+                    let remaining = if keep_first { &mut s.first } else { &mut s.second };
+                    let remaining = std::mem::replace(remaining, Box::new(Node::Region(Region::default())));
+                    *self = *remaining;
+                    return RemoveResult::Removed;
+                }
+                result
+            }
+        }
+    }
+    fn layout(&mut self, r: Rect) {
+        match self {
+            Node::Region(region) => region.rect = r,
+            Node::Split(s) => {
+                s.rect = r;
+                let available = if s.vertical { r.width() as i32 } else { r.height() as i32 } - 1;
+                let first = if available >= 2 {
+                    ((available as f32 * s.percentage).round() as i32).clamp(1, available - 1)
+                } else {
+                    available.max(0)
+                };
+                let (first_rect, bar, second_rect) = if s.vertical {
+                    let x = r.left() + first;
+                    (
+                        Rect::new(r.left(), r.top(), x - 1, r.bottom()),
+                        Rect::new(x, r.top(), x, r.bottom()),
+                        Rect::new(x + 1, r.top(), r.right(), r.bottom()),
+                    )
+                } else {
+                    let y = r.top() + first;
+                    (
+                        Rect::new(r.left(), r.top(), r.right(), y - 1),
+                        Rect::new(r.left(), y, r.right(), y),
+                        Rect::new(r.left(), y + 1, r.right(), r.bottom()),
+                    )
+                };
+                s.bar = bar;
+                s.first.layout(first_rect);
+                s.second.layout(second_rect);
+            }
+        }
+    }
+    fn for_each_region<'a>(&'a self, f: &mut dyn FnMut(&'a Region)) {
+        match self {
+            Node::Region(r) => f(r),
+            Node::Split(s) => {
+                s.first.for_each_region(f);
+                s.second.for_each_region(f);
+            }
+        }
+    }
+    fn for_each_split<'a>(&'a self, f: &mut dyn FnMut(&'a Split)) {
+        if let Node::Split(s) = self {
+            f(s);
+            s.first.for_each_split(f);
+            s.second.for_each_split(f);
+        }
+    }
+    fn split_mut(&mut self, index: &mut usize) -> Option<&mut Split> {
+        match self {
+            Node::Region(_) => None,
+            Node::Split(s) => {
+                if *index == 0 {
+                    return Some(s);
+                }
+                *index -= 1;
+                if let Some(result) = s.first.split_mut(index) {
+                    return Some(result);
+                }
+                s.second.split_mut(index)
+            }
+        }
+    }
+    // builds a chain of splits where all nodes have the same size
+    fn chain(mut nodes: Vec<Node>, vertical: bool) -> Option<Node> {
+        let mut result = nodes.pop()?;
+        let mut count = 1;
+        while let Some(node) = nodes.pop() {
+            count += 1;
+            result = Node::Split(Split {
+                vertical,
+                percentage: 1.0 / count as f32,
+                rect: Rect::default(),
+                bar: Rect::default(),
+                first: Box::new(node),
+                second: Box::new(result),
+            });
+        }
+        Some(result)
+    }
+}
+
+#[derive(Default)]
+pub(super) struct TileTree {
+    pub(super) root: Option<Node>,
+    // the index of the split whose bar is being dragged with the mouse
+    pub(super) dragged_split: Option<usize>,
+    // the position and size of the windows before the tiling mode was enabled
+    pub(super) floating_rects: Vec<(Handle<()>, Rect, bool)>,
+}
+
+impl TileTree {
+    pub(super) fn contains(&self, handle: Handle<()>) -> bool {
+        self.region(handle).is_some()
+    }
+    pub(super) fn region(&self, handle: Handle<()>) -> Option<&Region> {
+        self.root.as_ref()?.region(handle)
+    }
+    pub(super) fn region_mut(&mut self, handle: Handle<()>) -> Option<&mut Region> {
+        self.root.as_mut()?.region_mut(handle)
+    }
+    pub(super) fn regions(&self) -> Vec<&Region> {
+        let mut v = Vec::new();
+        if let Some(root) = self.root.as_ref() {
+            root.for_each_region(&mut |r| v.push(r));
+        }
+        v
+    }
+    pub(super) fn splits(&self) -> Vec<&Split> {
+        let mut v = Vec::new();
+        if let Some(root) = self.root.as_ref() {
+            root.for_each_split(&mut |s| v.push(s));
+        }
+        v
+    }
+    pub(super) fn split_mut(&mut self, mut index: usize) -> Option<&mut Split> {
+        self.root.as_mut()?.split_mut(&mut index)
+    }
+    pub(super) fn region_at(&self, point: Point) -> Option<&Region> {
+        self.regions().into_iter().find(|r| r.rect.contains(point))
+    }
+    pub(super) fn split_bar_at(&self, point: Point) -> Option<usize> {
+        self.splits().iter().position(|s| s.bar.contains(point))
+    }
+    pub(super) fn windows(&self) -> Vec<Handle<()>> {
+        let mut v = Vec::new();
+        for r in self.regions() {
+            v.extend_from_slice(&r.windows);
+        }
+        v
+    }
+    pub(super) fn layout(&mut self, r: Rect) {
+        if let Some(root) = self.root.as_mut() {
+            root.layout(r);
+        }
+    }
+    pub(super) fn remove(&mut self, handle: Handle<()>) -> bool {
+        let Some(root) = self.root.as_mut() else {
+            return false;
+        };
+        match root.remove(handle) {
+            RemoveResult::NotFound => false,
+            RemoveResult::Removed => true,
+            RemoveResult::Empty => {
+                self.root = None;
+                true
+            }
+        }
+    }
+    // adds a window next to the target window (or next to the last region if the target is not part of the tree)
+    pub(super) fn insert(&mut self, handle: Handle<()>, target: Handle<()>, position: DockPosition) {
+        let Some(root) = self.root.as_mut() else {
+            self.root = Some(Node::Region(Region::with_window(handle)));
+            return;
+        };
+        let node = if root.region(target).is_some() {
+            root.region_node_mut(target).unwrap()
+        } else {
+            root.last_region_node_mut()
+        };
+        if position == DockPosition::Tab {
+            if let Node::Region(r) = node {
+                r.windows.push(handle);
+                r.current = r.windows.len() - 1;
+            }
+            return;
+        }
+        let old = std::mem::replace(node, Node::Region(Region::default()));
+        let new = Node::Region(Region::with_window(handle));
+        let (first, second) = match position {
+            DockPosition::Left | DockPosition::Top => (new, old),
+            _ => (old, new),
+        };
+        *node = Node::Split(Split {
+            vertical: matches!(position, DockPosition::Left | DockPosition::Right),
+            percentage: 0.5,
+            rect: Rect::default(),
+            bar: Rect::default(),
+            first: Box::new(first),
+            second: Box::new(second),
+        });
+    }
+    // rebuilds the tree so that the windows are placed in rows (from top to bottom) of equal size
+    pub(super) fn build_rows(&mut self, rows: Vec<Vec<Handle<()>>>) {
+        let rows: Vec<Node> = rows
+            .into_iter()
+            .filter_map(|row| Node::chain(row.into_iter().map(|h| Node::Region(Region::with_window(h))).collect(), true))
+            .collect();
+        self.root = Node::chain(rows, false);
+    }
+    // rebuilds the tree so that all windows are placed (as tabs) in the same region
+    pub(super) fn build_tabs(&mut self, windows: Vec<Handle<()>>) {
+        self.root = if windows.is_empty() {
+            None
+        } else {
+            Some(Node::Region(Region {
+                windows,
+                current: 0,
+                rect: Rect::default(),
+            }))
+        };
+    }
+}
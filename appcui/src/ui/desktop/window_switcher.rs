@@ -0,0 +1,253 @@
+use crate::prelude::*;
+use crate::system::RuntimeManager;
+
+const MAX_LIST_WIDTH: u32 = 30;
+
+// the response of the switcher (the window that should be activated)
+type WindowHandle = Handle<Window>;
+
+struct Entry {
+    handle: Handle<()>,
+    title: String,
+    tag: String,
+    preview: Option<Surface>,
+}
+
+impl Entry {
+    fn matches(&self, filter: &str) -> bool {
+        filter.is_empty() || self.title.to_lowercase().contains(filter) || self.tag.to_lowercase().contains(filter)
+    }
+}
+
+#[CustomControl(overwrite = OnPaint+OnKeyPressed+OnMouseEvent, internal = true)]
+struct SwitcherPanel {
+    entries: Vec<Entry>,
+    visible: Vec<usize>,
+    filter: String,
+    current: usize,
+    top: usize,
+}
+
+impl SwitcherPanel {
+    fn new(entries: Vec<Entry>) -> Self {
+        // the first window is the focused one --> select the previous one (just like Alt+Tab does)
+        let current = if entries.len() > 1 { 1 } else { 0 };
+        Self {
+            base: ControlBase::new(layout!("d:f"), true),
+            visible: (0..entries.len()).collect(),
+            entries,
+            filter: String::new(),
+            current,
+            top: 0,
+        }
+    }
+    fn current_window(&self) -> Option<Handle<()>> {
+        self.visible.get(self.current).map(|idx| self.entries[*idx].handle)
+    }
+    fn list_width(&self) -> u32 {
+        (self.size().width * 2 / 5).min(MAX_LIST_WIDTH)
+    }
+    fn list_height(&self) -> usize {
+        self.size().height.saturating_sub(2) as usize
+    }
+    fn update_filter(&mut self) {
+        let filter = self.filter.to_lowercase();
+        self.visible = (0..self.entries.len()).filter(|idx| self.entries[*idx].matches(&filter)).collect();
+        self.current = 0;
+        self.top = 0;
+    }
+    fn update_scroll(&mut self) {
+        let height = self.list_height().max(1);
+        if self.current < self.top {
+            self.top = self.current;
+        } else if self.current >= self.top + height {
+            self.top = self.current + 1 - height;
+        }
+    }
+    fn select(&mut self, index: usize) {
+        if !self.visible.is_empty() {
+            self.current = index.min(self.visible.len() - 1);
+            self.update_scroll();
+        }
+    }
+    fn select_next(&mut self, forward: bool) {
+        let count = self.visible.len();
+        if count > 0 {
+            self.current = if forward {
+                (self.current + 1) % count
+            } else {
+                (self.current + count - 1) % count
+            };
+            self.update_scroll();
+        }
+    }
+    fn paint_preview(&self, surface: &mut Surface, preview: &Surface, rect: Rect) {
+        // nearest-neighbour downscale (a preview is never enlarged) centered in the available space
+        let sz = preview.size();
+        if sz.width == 0 || sz.height == 0 {
+            return;
+        }
+        let w = sz.width.min(rect.width());
+        let h = sz.height.min(rect.height());
+        let left = rect.left() + ((rect.width() - w) / 2) as i32;
+        let top = rect.top() + ((rect.height() - h) / 2) as i32;
+        // the first and last rows/columns are always kept (so that the border of the window remains visible)
+        let scale = |v: u32, from: u32, to: u32| if to > 1 { (v * (from - 1) / (to - 1)) as i32 } else { 0 };
+        for y in 0..h {
+            for x in 0..w {
+                let sx = scale(x, sz.width, w);
+                let sy = scale(y, sz.height, h);
+                if let Some(ch) = preview.char(sx, sy) {
+                    surface.write_char(left + x as i32, top + y as i32, *ch);
+                }
+            }
+        }
+    }
+}
+
+impl OnPaint for SwitcherPanel {
+    fn on_paint(&self, surface: &mut Surface, theme: &Theme) {
+        let sz = self.size();
+        let list_width = self.list_width();
+        let locale = Locale::current();
+        // filter
+        let label = locale.text("switcher.filter");
+        let x = label.chars().count() as u32 + 1;
+        surface.write_string(0, 0, label, theme.text.normal, false);
+        // only the last part of the filter is shown if it does not fit
+        let skip = self.filter.chars().count().saturating_sub(sz.width.saturating_sub(x + 1) as usize);
+        let filter: String = self.filter.chars().skip(skip).collect();
+        surface.write_string(x as i32, 0, &filter, theme.editor.focused, false);
+        surface.set_cursor(x as i32 + filter.chars().count() as i32, 0);
+        surface.draw_horizontal_line_with_size(0, 1, sz.width, LineType::Single, theme.lines.normal);
+        surface.draw_vertical_line_with_size(list_width as i32, 2, sz.height.saturating_sub(2), LineType::Single, theme.lines.normal);
+        if self.visible.is_empty() {
+            surface.write_string(1, 2, locale.text("switcher.empty"), theme.text.inactive, false);
+            return;
+        }
+        // the list of windows (title + tag)
+        let width = list_width.saturating_sub(2) as usize;
+        for (row, idx) in self.visible.iter().skip(self.top).take(self.list_height()).enumerate() {
+            let entry = &self.entries[*idx];
+            let y = row as i32 + 2;
+            let (text_attr, tag_attr) = if row + self.top == self.current {
+                surface.fill_horizontal_line_with_size(0, y, list_width, Character::with_attributes(' ', theme.list_current_item.focus));
+                (theme.list_current_item.focus, theme.list_current_item.focus)
+            } else {
+                (theme.text.normal, theme.text.inactive)
+            };
+            let title: String = entry.title.chars().take(width).collect();
+            let title_len = title.chars().count();
+            surface.write_string(1, y, &title, text_attr, false);
+            if !entry.tag.is_empty() && title_len + 3 < width {
+                let tag: String = entry.tag.chars().take(width - title_len - 3).collect();
+                surface.write_string(title_len as i32 + 2, y, &format!("[{tag}]"), tag_attr, false);
+            }
+        }
+        // the preview of the selected window
+        if let Some(preview) = self.visible.get(self.current).and_then(|idx| self.entries[*idx].preview.as_ref()) {
+            if sz.width > list_width + 3 && sz.height > 2 {
+                let r = Rect::new(list_width as i32 + 2, 2, sz.width as i32 - 2, sz.height as i32 - 1);
+                self.paint_preview(surface, preview, r);
+            }
+        }
+    }
+}
+
+impl OnKeyPressed for SwitcherPanel {
+    fn on_key_pressed(&mut self, key: Key, character: char) -> EventProcessStatus {
+        match key.value() {
+            key!("Up") => self.select(self.current.saturating_sub(1)),
+            key!("Down") => self.select(self.current + 1),
+            key!("Home") => self.select(0),
+            key!("End") => self.select(usize::MAX),
+            key!("PageUp") => self.select(self.current.saturating_sub(self.list_height())),
+            key!("PageDown") => self.select(self.current + self.list_height()),
+            key!("Tab") | key!("Alt+0") => self.select_next(true),
+            key!("Shift+Tab") => self.select_next(false),
+            key!("Back") => {
+                if self.filter.pop().is_some() {
+                    self.update_filter();
+                }
+            }
+            _ => {
+                // Enter and Escape are processed by the switcher window (on_accept / on_cancel)
+                if character < ' ' || key.modifier.contains_one(KeyModifier::Ctrl | KeyModifier::Alt) {
+                    return EventProcessStatus::Ignored;
+                }
+                self.filter.push(character);
+                self.update_filter();
+            }
+        }
+        EventProcessStatus::Processed
+    }
+}
+
+impl OnMouseEvent for SwitcherPanel {
+    fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        match event {
+            MouseEvent::Pressed(data) => {
+                if data.x < 0 || data.x >= self.list_width() as i32 || data.y < 2 {
+                    return EventProcessStatus::Ignored;
+                }
+                let index = self.top + (data.y - 2) as usize;
+                if index >= self.visible.len() {
+                    return EventProcessStatus::Ignored;
+                }
+                self.select(index);
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Wheel(direction) => {
+                match direction {
+                    MouseWheelDirection::Up => self.select(self.current.saturating_sub(1)),
+                    MouseWheelDirection::Down => self.select(self.current + 1),
+                    _ => return EventProcessStatus::Ignored,
+                }
+                EventProcessStatus::Processed
+            }
+            _ => EventProcessStatus::Ignored,
+        }
+    }
+}
+
+#[ModalWindow(internal: true, response: WindowHandle, events: WindowEvents)]
+pub(super) struct WindowSwitcher {
+    panel: Handle<SwitcherPanel>,
+}
+
+impl WindowSwitcher {
+    pub(super) fn new(windows: &[Handle<()>]) -> Self {
+        let mut entries = Vec::with_capacity(windows.len());
+        for handle in windows {
+            let Some(win) = RuntimeManager::get().get_control::<Window>(handle.cast()) else {
+                continue;
+            };
+            let title = win.title().to_string();
+            let tag = win.tag().unwrap_or_default().to_string();
+            entries.push(Entry {
+                handle: *handle,
+                title,
+                tag,
+                preview: RuntimeManager::get().window_snapshot(*handle),
+            });
+        }
+        let mut me = Self {
+            base: ModalWindow::new(Locale::current().text("switcher.title"), layout!("a:c,w:80%,h:80%"), window::Flags::None),
+            panel: Handle::None,
+        };
+        me.panel = me.add(SwitcherPanel::new(entries));
+        me
+    }
+}
+
+impl WindowEvents for WindowSwitcher {
+    fn on_accept(&mut self) {
+        let h = self.panel;
+        if let Some(window) = self.control(h).and_then(|p| p.current_window()) {
+            self.exit_with(window.cast());
+        }
+    }
+    fn on_cancel(&mut self) -> ActionRequest {
+        ActionRequest::Allow
+    }
+}
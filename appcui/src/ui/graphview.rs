@@ -17,6 +17,10 @@ mod node;
 mod edge;
 mod graph;
 mod graph_rendering_options;
+mod dot;
+mod port;
+mod overview;
+mod cluster;
 pub mod events;
 #[cfg(test)]
 mod tests;
@@ -35,4 +39,9 @@ pub use self::node::EditableNode;
 pub use self::edge::Edge;
 pub use self::edge::EdgeBuilder;
 pub use self::edge::EditableEdge;
+pub use self::dot::DotError;
+pub use self::port::Port;
+pub use self::port::PortSide;
+pub use self::cluster::Cluster;
+pub use self::cluster::ClusterBuilder;
 
@@ -0,0 +1,289 @@
+use super::graph::ControlState;
+use super::Graph;
+use super::GraphNode;
+use crate::prelude::*;
+use std::collections::HashMap;
+
+// a node that does not belong to any cluster
+pub(super) const NO_CLUSTER: u32 = u32::MAX;
+
+/// A group of nodes (a subgraph) drawn as a bordered region with a title.
+///
+/// The layout algorithms keep the nodes of a cluster together, and a cluster can be collapsed into a single
+/// summary node (all the edges towards the nodes of the cluster are drawn towards the summary node).
+pub struct Cluster {
+    pub(super) title: String,
+    pub(super) nodes: Vec<u32>,
+    pub(super) border: LineType,
+    pub(super) attribute: Option<CharAttribute>,
+    pub(super) collapsed: bool,
+    // the frame of the cluster (or the summary node if the cluster is collapsed) - updated when the graph is painted
+    pub(super) rect: Rect,
+}
+impl Cluster {
+    /// Returns the title of the cluster
+    #[inline(always)]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+    /// Returns the indexes of the nodes from the cluster
+    #[inline(always)]
+    pub fn nodes(&self) -> &[u32] {
+        &self.nodes
+    }
+    /// Returns the line type used to draw the border of the cluster
+    #[inline(always)]
+    pub fn border(&self) -> LineType {
+        self.border
+    }
+    /// Returns the attribute used to draw the border and the title of the cluster (if any)
+    #[inline(always)]
+    pub fn attribute(&self) -> Option<CharAttribute> {
+        self.attribute
+    }
+    /// Returns `true` if the cluster is collapsed into a single summary node
+    #[inline(always)]
+    pub fn is_collapsed(&self) -> bool {
+        self.collapsed
+    }
+    // the text drawn within the summary node of a collapsed cluster
+    pub(super) fn summary(&self) -> String {
+        format!("{} ({})", self.title, self.nodes.len())
+    }
+}
+
+pub struct ClusterBuilder {
+    cluster: Cluster,
+}
+impl ClusterBuilder {
+    /// Create a new ClusterBuilder (the title is drawn on the top border of the cluster)
+    pub fn new(title: &str) -> Self {
+        Self {
+            cluster: Cluster {
+                title: title.to_string(),
+                nodes: Vec::new(),
+                border: LineType::Single,
+                attribute: None,
+                collapsed: false,
+                rect: Rect::new(0, 0, 0, 0),
+            },
+        }
+    }
+    /// Set the nodes (their indexes) that belong to the cluster. A node can only belong to one cluster
+    /// (if it is part of several clusters, only the first one is used).
+    pub fn nodes(mut self, nodes: &[u32]) -> Self {
+        self.cluster.nodes = nodes.to_vec();
+        self
+    }
+    /// Set the line type for the border of the cluster (default is LineType::Single)
+    pub fn border(mut self, line_type: LineType) -> Self {
+        self.cluster.border = line_type;
+        self
+    }
+    /// Set the attribute for the border and the title of the cluster (if not set, the default attribute will be used)
+    pub fn attribute(mut self, attr: CharAttribute) -> Self {
+        self.cluster.attribute = Some(attr);
+        self
+    }
+    /// Set if the cluster is collapsed into a single summary node (default is false)
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.cluster.collapsed = collapsed;
+        self
+    }
+    /// Builds the actual cluster
+    #[inline(always)]
+    pub fn build(self) -> Cluster {
+        self.cluster
+    }
+}
+
+impl<T> Graph<T>
+where
+    T: GraphNode,
+{
+    // removes the invalid (or duplicated) nodes from the clusters and rebuilds the node -> cluster map
+    pub(super) fn update_clusters(&mut self) {
+        let len = self.nodes.len();
+        self.node_cluster.clear();
+        self.node_cluster.resize(len, NO_CLUSTER);
+        for (index, c) in self.clusters.iter_mut().enumerate() {
+            let map = &mut self.node_cluster;
+            c.nodes.retain(|&n| {
+                if (n as usize) < len && map[n as usize] == NO_CLUSTER {
+                    map[n as usize] = index as u32;
+                    true
+                } else {
+                    false
+                }
+            });
+        }
+        if self.current_node < len && !self.is_node_visible(self.current_node) {
+            self.current_node = self.representative(self.current_node);
+        }
+        if self.hovered_node.is_some_and(|h| h >= len || !self.is_node_visible(h)) {
+            self.hovered_node = None;
+        }
+        self.update_cluster_rects();
+    }
+
+    // the frame of every cluster surrounds its nodes (one row above and below, two columns on the left and right);
+    // a collapsed cluster is a single box placed in the top-left corner of its nodes
+    pub(super) fn update_cluster_rects(&mut self) {
+        for c in &mut self.clusters {
+            let Some(first) = c.nodes.first() else {
+                c.rect = Rect::new(0, 0, 0, 0);
+                continue;
+            };
+            let mut r = self.nodes[*first as usize].rect;
+            for n in &c.nodes {
+                let nr = self.nodes[*n as usize].rect;
+                r = Rect::new(r.left().min(nr.left()), r.top().min(nr.top()), r.right().max(nr.right()), r.bottom().max(nr.bottom()));
+            }
+            c.rect = if c.collapsed {
+                let width = c.summary().chars().count() as i32 + 4;
+                Rect::new(r.left(), r.top(), r.left() + width - 1, r.top() + 2)
+            } else {
+                let width = (r.right() - r.left() + 5).max(c.title.chars().count() as i32 + 6);
+                Rect::new(r.left() - 2, r.top() - 1, r.left() - 3 + width, r.bottom() + 1)
+            };
+        }
+    }
+
+    // the index of the collapsed cluster the node belongs to (if any)
+    #[inline(always)]
+    pub(super) fn collapsed_cluster_of(&self, node: usize) -> Option<usize> {
+        let c = self.node_cluster.get(node).copied().unwrap_or(NO_CLUSTER);
+        if c != NO_CLUSTER && self.clusters[c as usize].collapsed {
+            Some(c as usize)
+        } else {
+            None
+        }
+    }
+
+    // the node that stands for a collapsed cluster (its first node) or the node itself
+    #[inline(always)]
+    pub(super) fn representative(&self, node: usize) -> usize {
+        match self.collapsed_cluster_of(node) {
+            Some(c) => self.clusters[c].nodes[0] as usize,
+            None => node,
+        }
+    }
+
+    // the nodes of a collapsed cluster (except for its first node) are not drawn and can not be selected
+    #[inline(always)]
+    pub(super) fn is_node_visible(&self, node: usize) -> bool {
+        self.representative(node) == node
+    }
+
+    // the rectangle where a node is drawn (the summary node if the node belongs to a collapsed cluster)
+    #[inline(always)]
+    pub(super) fn node_rect(&self, node: usize) -> Rect {
+        match self.collapsed_cluster_of(node) {
+            Some(c) => self.clusters[c].rect,
+            None => self.nodes[node].rect,
+        }
+    }
+
+    // for every edge: the number of edges it stands for - 0 for the edges within a collapsed cluster and for the edges
+    // that are aggregated into another one (all edges between a collapsed cluster and the same node are drawn once)
+    pub(super) fn compute_edge_aggregation(&self) -> Vec<u32> {
+        let mut result = vec![1; self.edges.len()];
+        if !self.clusters.iter().any(|c| c.collapsed) {
+            return result;
+        }
+        let mut first: HashMap<(usize, usize), usize> = HashMap::new();
+        for (index, e) in self.edges.iter().enumerate() {
+            let (from, to) = (e.from_node_id as usize, e.to_node_id as usize);
+            if self.collapsed_cluster_of(from).is_none() && self.collapsed_cluster_of(to).is_none() {
+                continue;
+            }
+            let (a, b) = (self.representative(from), self.representative(to));
+            if a == b {
+                result[index] = 0;
+                continue;
+            }
+            match first.get(&(a.min(b), a.max(b))) {
+                Some(&f) => {
+                    result[f] += 1;
+                    result[index] = 0;
+                }
+                None => {
+                    first.insert((a.min(b), a.max(b)), index);
+                }
+            }
+        }
+        result
+    }
+
+    // the borders of the expanded clusters (drawn before the edges and the nodes)
+    pub(super) fn paint_cluster_frames(&mut self, state: ControlState, theme: &Theme) {
+        let default_attr = state.edge_attr(theme);
+        for c in &self.clusters {
+            if c.collapsed || c.nodes.is_empty() {
+                continue;
+            }
+            let attr = match state {
+                ControlState::Focused => c.attribute.unwrap_or(default_attr),
+                _ => default_attr,
+            };
+            self.surface.draw_rect(c.rect, c.border, attr);
+        }
+    }
+
+    // the titles of the expanded clusters (drawn after the edges, so that the edges do not overwrite them)
+    pub(super) fn paint_cluster_titles(&mut self, state: ControlState, theme: &Theme) {
+        let default_attr = state.edge_attr(theme);
+        for c in &self.clusters {
+            if c.collapsed || c.nodes.is_empty() || c.title.is_empty() {
+                continue;
+            }
+            let attr = match state {
+                ControlState::Focused => c.attribute.unwrap_or(default_attr),
+                _ => default_attr,
+            };
+            self.surface.write_string(c.rect.left() + 2, c.rect.top(), &format!(" {} ", c.title), attr, false);
+        }
+    }
+
+    // the summary node of a collapsed cluster
+    pub(super) fn paint_cluster_summary(&mut self, cluster: usize, attr: CharAttribute) {
+        let c = &self.clusters[cluster];
+        self.surface.fill_rect(c.rect, Character::with_attributes(' ', attr));
+        self.surface.draw_rect(c.rect, c.border, attr);
+        self.surface.write_string(c.rect.left() + 2, c.rect.top() + 1, &c.summary(), attr, false);
+    }
+
+    // the expanded cluster whose title is drawn at the specified point (if any)
+    pub(super) fn cluster_title_at(&self, x: i32, y: i32) -> Option<usize> {
+        self.clusters.iter().position(|c| {
+            !c.collapsed && !c.nodes.is_empty() && y == c.rect.top() && x >= c.rect.left() + 2 && x < c.rect.left() + 4 + c.title.chars().count() as i32
+        })
+    }
+
+    // the cluster (if any) of a node
+    #[inline(always)]
+    pub(super) fn cluster_of(&self, node: usize) -> Option<usize> {
+        let c = self.node_cluster.get(node).copied().unwrap_or(NO_CLUSTER);
+        (c != NO_CLUSTER).then_some(c as usize)
+    }
+
+    // returns `true` if the state of the cluster was changed
+    pub(super) fn set_cluster_collapsed(&mut self, index: usize, collapsed: bool, control: &ControlBase) -> bool {
+        if index >= self.clusters.len() || self.clusters[index].collapsed == collapsed {
+            return false;
+        }
+        self.clusters[index].collapsed = collapsed;
+        self.update_clusters();
+        self.resize_graph(false);
+        self.repaint(control);
+        true
+    }
+
+    // the nodes that move together with a node (all the nodes of a collapsed cluster) and their current positions
+    pub(super) fn drag_anchors(&self, node: usize) -> Vec<(usize, Point)> {
+        match self.collapsed_cluster_of(node) {
+            Some(c) => self.clusters[c].nodes.iter().map(|n| (*n as usize, self.nodes[*n as usize].rect.top_left())).collect(),
+            None => vec![(node, self.nodes[node].rect.top_left())],
+        }
+    }
+}
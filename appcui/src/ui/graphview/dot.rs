@@ -0,0 +1,1044 @@
+use super::{Cluster, ClusterBuilder, Edge, EdgeBuilder, Graph, GraphNode, Node, NodeBuilder};
+use crate::graphics::*;
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Write;
+
+/// An error produced when a [`Graph`] is parsed from a Graphviz DOT description (via [`Graph::from_dot`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DotError {
+    /// The line (starting from 1) where the error was found.
+    pub line: u32,
+    /// The column (starting from 1) where the error was found.
+    pub column: u32,
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl DotError {
+    fn new(line: u32, column: u32, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for DotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum TokenKind {
+    // the text of the identifier and whether it was quoted (quoted identifiers are never keywords)
+    Id(String, bool),
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Equal,
+    Semicolon,
+    Comma,
+    Colon,
+    // `->` (directed) or `--` (undirected)
+    EdgeOp(bool),
+    End,
+}
+
+struct Token {
+    kind: TokenKind,
+    line: u32,
+    column: u32,
+}
+
+impl Token {
+    fn description(&self) -> String {
+        match &self.kind {
+            TokenKind::Id(text, true) => format!("\"{text}\""),
+            TokenKind::Id(text, false) => format!("'{text}'"),
+            TokenKind::LBrace => "'{'".to_string(),
+            TokenKind::RBrace => "'}'".to_string(),
+            TokenKind::LBracket => "'['".to_string(),
+            TokenKind::RBracket => "']'".to_string(),
+            TokenKind::Equal => "'='".to_string(),
+            TokenKind::Semicolon => "';'".to_string(),
+            TokenKind::Comma => "','".to_string(),
+            TokenKind::Colon => "':'".to_string(),
+            TokenKind::EdgeOp(true) => "'->'".to_string(),
+            TokenKind::EdgeOp(false) => "'--'".to_string(),
+            TokenKind::End => "end of input".to_string(),
+        }
+    }
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(&self.kind, TokenKind::Id(text, false) if text.eq_ignore_ascii_case(keyword))
+    }
+}
+
+struct Lexer {
+    chars: Vec<char>,
+    pos: usize,
+    line: u32,
+    column: u32,
+}
+
+impl Lexer {
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+    fn bump(&mut self) -> Option<char> {
+        let ch = self.peek(0)?;
+        self.pos += 1;
+        if ch == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(ch)
+    }
+    fn skip_whitespaces_and_comments(&mut self) -> Result<(), DotError> {
+        while let Some(ch) = self.peek(0) {
+            match ch {
+                // lines that start with '#' are C preprocessor output and are discarded
+                '#' => self.skip_line(),
+                '/' if self.peek(1) == Some('/') => self.skip_line(),
+                '/' if self.peek(1) == Some('*') => {
+                    let (line, column) = (self.line, self.column);
+                    self.bump();
+                    self.bump();
+                    loop {
+                        match self.bump() {
+                            Some('*') if self.peek(0) == Some('/') => {
+                                self.bump();
+                                break;
+                            }
+                            Some(_) => {}
+                            None => return Err(DotError::new(line, column, "unterminated comment")),
+                        }
+                    }
+                }
+                _ if ch.is_whitespace() => {
+                    self.bump();
+                }
+                _ => break,
+            }
+        }
+        Ok(())
+    }
+    fn skip_line(&mut self) {
+        while let Some(ch) = self.bump() {
+            if ch == '\n' {
+                break;
+            }
+        }
+    }
+    fn quoted_string(&mut self) -> Result<String, DotError> {
+        let (line, column) = (self.line, self.column);
+        self.bump();
+        let mut text = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(text),
+                Some('\\') => match self.peek(0) {
+                    Some('"') => {
+                        self.bump();
+                        text.push('"');
+                    }
+                    // a backslash followed by a new line continues the string on the next line
+                    Some('\n') => {
+                        self.bump();
+                    }
+                    // other escape sequences (like '\n' or '\l') are kept as they are and processed for labels
+                    Some(ch) => {
+                        self.bump();
+                        text.push('\\');
+                        text.push(ch);
+                    }
+                    None => text.push('\\'),
+                },
+                Some(ch) => text.push(ch),
+                None => return Err(DotError::new(line, column, "unterminated string")),
+            }
+        }
+    }
+    fn html_string(&mut self) -> Result<String, DotError> {
+        let (line, column) = (self.line, self.column);
+        self.bump();
+        let mut depth = 1;
+        let mut in_tag = false;
+        let mut text = String::new();
+        // only the text of an HTML-like label is kept (the tags are removed)
+        while depth > 0 {
+            match self.bump() {
+                Some('<') => {
+                    depth += 1;
+                    in_tag = true;
+                }
+                Some('>') => {
+                    depth -= 1;
+                    in_tag = false;
+                }
+                Some(ch) if !in_tag => text.push(ch),
+                Some(_) => {}
+                None => return Err(DotError::new(line, column, "unterminated HTML string")),
+            }
+        }
+        Ok(text)
+    }
+    fn next_token(&mut self) -> Result<Token, DotError> {
+        self.skip_whitespaces_and_comments()?;
+        let (line, column) = (self.line, self.column);
+        let Some(ch) = self.peek(0) else {
+            return Ok(Token {
+                kind: TokenKind::End,
+                line,
+                column,
+            });
+        };
+        let kind = match ch {
+            '{' | '}' | '[' | ']' | '=' | ';' | ',' | ':' => {
+                self.bump();
+                match ch {
+                    '{' => TokenKind::LBrace,
+                    '}' => TokenKind::RBrace,
+                    '[' => TokenKind::LBracket,
+                    ']' => TokenKind::RBracket,
+                    '=' => TokenKind::Equal,
+                    ';' => TokenKind::Semicolon,
+                    ',' => TokenKind::Comma,
+                    _ => TokenKind::Colon,
+                }
+            }
+            '-' if matches!(self.peek(1), Some('>') | Some('-')) => {
+                self.bump();
+                TokenKind::EdgeOp(self.bump() == Some('>'))
+            }
+            '"' => {
+                let mut text = self.quoted_string()?;
+                // "a" + "b" is the concatenation of the two strings
+                loop {
+                    let (pos, l, c) = (self.pos, self.line, self.column);
+                    self.skip_whitespaces_and_comments()?;
+                    if self.peek(0) != Some('+') {
+                        (self.pos, self.line, self.column) = (pos, l, c);
+                        break;
+                    }
+                    self.bump();
+                    self.skip_whitespaces_and_comments()?;
+                    if self.peek(0) != Some('"') {
+                        return Err(DotError::new(self.line, self.column, "expected a quoted string after '+'"));
+                    }
+                    text.push_str(&self.quoted_string()?);
+                }
+                TokenKind::Id(text, true)
+            }
+            '<' => TokenKind::Id(self.html_string()?, true),
+            '-' | '.' | '0'..='9' => {
+                let mut text = String::new();
+                if ch == '-' {
+                    text.push(ch);
+                    self.bump();
+                }
+                while let Some(ch) = self.peek(0) {
+                    if ch.is_ascii_digit() || ch == '.' {
+                        text.push(ch);
+                        self.bump();
+                    } else {
+                        break;
+                    }
+                }
+                if text.parse::<f64>().is_err() {
+                    return Err(DotError::new(line, column, format!("invalid number '{text}'")));
+                }
+                TokenKind::Id(text, false)
+            }
+            _ if ch == '_' || ch.is_alphabetic() => {
+                let mut text = String::new();
+                while let Some(ch) = self.peek(0) {
+                    if ch == '_' || ch.is_alphanumeric() {
+                        text.push(ch);
+                        self.bump();
+                    } else {
+                        break;
+                    }
+                }
+                TokenKind::Id(text, false)
+            }
+            _ => return Err(DotError::new(line, column, format!("unexpected character '{ch}'"))),
+        };
+        Ok(Token { kind, line, column })
+    }
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, DotError> {
+    let mut lexer = Lexer {
+        chars: text.chars().collect(),
+        pos: 0,
+        line: 1,
+        column: 1,
+    };
+    let mut tokens = Vec::new();
+    loop {
+        let token = lexer.next_token()?;
+        let end = token.kind == TokenKind::End;
+        tokens.push(token);
+        if end {
+            return Ok(tokens);
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Attribute {
+    name: String,
+    value: String,
+    line: u32,
+    column: u32,
+}
+
+#[derive(Clone, Default)]
+struct Attributes(Vec<Attribute>);
+
+impl Attributes {
+    fn set(&mut self, attr: Attribute) {
+        if let Some(a) = self.0.iter_mut().find(|a| a.name == attr.name) {
+            *a = attr;
+        } else {
+            self.0.push(attr);
+        }
+    }
+    fn extend(&mut self, attrs: &Attributes) {
+        for a in &attrs.0 {
+            self.set(a.clone());
+        }
+    }
+    fn get(&self, name: &str) -> Option<&Attribute> {
+        self.0.iter().find(|a| a.name == name)
+    }
+}
+
+// the default node and edge attributes (a subgraph inherits them from its parent)
+#[derive(Clone, Default)]
+struct Scope {
+    node: Attributes,
+    edge: Attributes,
+}
+
+struct DotNode {
+    id: String,
+    attrs: Attributes,
+}
+
+struct DotEdge {
+    from: usize,
+    to: usize,
+    from_port: Option<String>,
+    to_port: Option<String>,
+    attrs: Attributes,
+}
+
+// a `subgraph cluster_*` statement
+struct DotCluster {
+    name: String,
+    nodes: Vec<usize>,
+    attrs: Attributes,
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    directed: bool,
+    strict: bool,
+    nodes: Vec<DotNode>,
+    ids: HashMap<String, usize>,
+    edges: Vec<DotEdge>,
+    clusters: Vec<DotCluster>,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+    fn peek_kind(&self, offset: usize) -> &TokenKind {
+        &self.tokens[(self.pos + offset).min(self.tokens.len() - 1)].kind
+    }
+    fn advance(&mut self) -> &Token {
+        let token = &self.tokens[self.pos];
+        if token.kind != TokenKind::End {
+            self.pos += 1;
+        }
+        token
+    }
+    fn error(&self, expected: &str) -> DotError {
+        let token = self.peek();
+        DotError::new(token.line, token.column, format!("expected {expected}, found {}", token.description()))
+    }
+    fn expect(&mut self, kind: TokenKind, expected: &str) -> Result<(), DotError> {
+        if *self.peek_kind(0) == kind {
+            self.advance();
+            Ok(())
+        } else {
+            Err(self.error(expected))
+        }
+    }
+    fn skip(&mut self, kind: TokenKind) -> bool {
+        if *self.peek_kind(0) == kind {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+    fn id(&mut self, expected: &str) -> Result<(String, u32, u32), DotError> {
+        let token = self.peek();
+        if let TokenKind::Id(text, _) = &token.kind {
+            let result = (text.clone(), token.line, token.column);
+            self.advance();
+            Ok(result)
+        } else {
+            Err(self.error(expected))
+        }
+    }
+
+    fn parse(&mut self) -> Result<(), DotError> {
+        if self.peek().is_keyword("strict") {
+            self.strict = true;
+            self.advance();
+        }
+        if self.peek().is_keyword("digraph") {
+            self.directed = true;
+        } else if !self.peek().is_keyword("graph") {
+            return Err(self.error("'graph' or 'digraph'"));
+        }
+        self.advance();
+        if matches!(self.peek_kind(0), TokenKind::Id(_, _)) {
+            self.advance();
+        }
+        self.expect(TokenKind::LBrace, "'{'")?;
+        self.statements(&mut Scope::default(), &mut Attributes::default())?;
+        self.expect(TokenKind::RBrace, "'}'")?;
+        self.expect(TokenKind::End, "end of input")
+    }
+    // parses the statements until '}' and returns the nodes that were used in those statements
+    // (the graph attributes are added to `graph_attrs`)
+    fn statements(&mut self, scope: &mut Scope, graph_attrs: &mut Attributes) -> Result<Vec<usize>, DotError> {
+        let mut used = Vec::new();
+        loop {
+            match self.peek_kind(0) {
+                TokenKind::RBrace | TokenKind::End => return Ok(used),
+                TokenKind::Semicolon => {
+                    self.advance();
+                    continue;
+                }
+                _ => {}
+            }
+            let token = self.peek();
+            if token.is_keyword("graph") {
+                self.advance();
+                let attrs = self.attribute_list()?;
+                graph_attrs.extend(&attrs);
+            } else if token.is_keyword("node") {
+                self.advance();
+                let attrs = self.attribute_list()?;
+                scope.node.extend(&attrs);
+            } else if token.is_keyword("edge") {
+                self.advance();
+                let attrs = self.attribute_list()?;
+                scope.edge.extend(&attrs);
+            } else if matches!(token.kind, TokenKind::Id(_, _)) && *self.peek_kind(1) == TokenKind::Equal {
+                // graph attribute (ID = ID) - it does not influence the nodes or the edges
+                let (name, _, _) = self.id("an attribute name")?;
+                self.advance();
+                let (value, line, column) = self.id("an attribute value")?;
+                graph_attrs.set(Attribute { name, value, line, column });
+            } else {
+                let operand = self.operand(scope)?;
+                if matches!(self.peek_kind(0), TokenKind::EdgeOp(_)) {
+                    self.edge_chain(operand, scope, &mut used)?;
+                } else {
+                    if operand.is_node && *self.peek_kind(0) == TokenKind::LBracket {
+                        let attrs = self.attribute_list()?;
+                        self.nodes[operand.nodes[0]].attrs.extend(&attrs);
+                    }
+                    used.extend_from_slice(&operand.nodes);
+                }
+            }
+            self.skip(TokenKind::Semicolon);
+        }
+    }
+    // a node id (with an optional port) or a subgraph
+    fn operand(&mut self, scope: &Scope) -> Result<Operand, DotError> {
+        let token = self.peek();
+        if token.is_keyword("subgraph") || token.kind == TokenKind::LBrace {
+            let mut name = String::new();
+            if token.is_keyword("subgraph") {
+                self.advance();
+                if matches!(self.peek_kind(0), TokenKind::Id(_, _)) {
+                    name = self.id("a subgraph name")?.0;
+                }
+            }
+            self.expect(TokenKind::LBrace, "'{'")?;
+            let mut attrs = Attributes::default();
+            let nodes = self.statements(&mut scope.clone(), &mut attrs)?;
+            self.expect(TokenKind::RBrace, "'}'")?;
+            // subgraphs whose name starts with "cluster" are drawn as a group of nodes
+            if name.starts_with("cluster") {
+                let mut members = Vec::with_capacity(nodes.len());
+                for n in &nodes {
+                    if !members.contains(n) {
+                        members.push(*n);
+                    }
+                }
+                self.clusters.push(DotCluster { name, nodes: members, attrs });
+            }
+            return Ok(Operand {
+                nodes,
+                port: None,
+                is_node: false,
+            });
+        }
+        if matches!(token.kind, TokenKind::Id(_, false))
+            && ["graph", "digraph", "node", "edge", "strict"].iter().any(|k| token.is_keyword(k))
+        {
+            return Err(self.error("a node id"));
+        }
+        let (id, _, _) = self.id("a statement")?;
+        // node:port or node:port:compass (the nodes of a DOT graph only have compass ports, so the last one is kept)
+        let mut port = None;
+        while self.skip(TokenKind::Colon) {
+            port = Some(self.id("a port name")?.0);
+        }
+        let index = self.node(id, scope);
+        Ok(Operand {
+            nodes: vec![index],
+            port,
+            is_node: true,
+        })
+    }
+    fn edge_chain(&mut self, first: Operand, scope: &Scope, used: &mut Vec<usize>) -> Result<(), DotError> {
+        let mut operands = vec![first];
+        while let TokenKind::EdgeOp(directed) = *self.peek_kind(0) {
+            if directed != self.directed {
+                let token = self.peek();
+                let message = if directed {
+                    "'->' can not be used in an undirected graph (use '--')"
+                } else {
+                    "'--' can not be used in a directed graph (use '->')"
+                };
+                return Err(DotError::new(token.line, token.column, message));
+            }
+            self.advance();
+            operands.push(self.operand(scope)?);
+        }
+        let mut attrs = scope.edge.clone();
+        if *self.peek_kind(0) == TokenKind::LBracket {
+            attrs.extend(&self.attribute_list()?);
+        }
+        for pair in operands.windows(2) {
+            for &from in &pair[0].nodes {
+                for &to in &pair[1].nodes {
+                    let ports = (pair[0].port.clone(), pair[1].port.clone());
+                    self.add_edge(from, to, ports, &attrs);
+                }
+            }
+        }
+        for o in operands {
+            used.extend_from_slice(&o.nodes);
+        }
+        Ok(())
+    }
+    fn add_edge(&mut self, from: usize, to: usize, ports: (Option<String>, Option<String>), attrs: &Attributes) {
+        // a strict graph does not have multi-edges (the attributes are merged into the existing edge)
+        if self.strict {
+            let directed = self.directed;
+            let existing = self
+                .edges
+                .iter_mut()
+                .find(|e| (e.from == from && e.to == to) || (!directed && e.from == to && e.to == from));
+            if let Some(e) = existing {
+                e.attrs.extend(attrs);
+                return;
+            }
+        }
+        self.edges.push(DotEdge {
+            from,
+            to,
+            from_port: ports.0,
+            to_port: ports.1,
+            attrs: attrs.clone(),
+        });
+    }
+    fn node(&mut self, id: String, scope: &Scope) -> usize {
+        if let Some(index) = self.ids.get(&id) {
+            return *index;
+        }
+        let index = self.nodes.len();
+        self.ids.insert(id.clone(), index);
+        self.nodes.push(DotNode {
+            id,
+            attrs: scope.node.clone(),
+        });
+        index
+    }
+    // one or more [...] lists
+    fn attribute_list(&mut self) -> Result<Attributes, DotError> {
+        let mut attrs = Attributes::default();
+        if *self.peek_kind(0) != TokenKind::LBracket {
+            return Err(self.error("'['"));
+        }
+        while self.skip(TokenKind::LBracket) {
+            while !self.skip(TokenKind::RBracket) {
+                let (name, line, column) = self.id("an attribute name or ']'")?;
+                let (value, line, column) = if self.skip(TokenKind::Equal) {
+                    self.id("an attribute value")?
+                } else {
+                    ("true".to_string(), line, column)
+                };
+                attrs.set(Attribute { name, value, line, column });
+                if !self.skip(TokenKind::Comma) {
+                    self.skip(TokenKind::Semicolon);
+                }
+            }
+        }
+        Ok(attrs)
+    }
+}
+
+struct Operand {
+    nodes: Vec<usize>,
+    port: Option<String>,
+    // a single node (and not a subgraph)
+    is_node: bool,
+}
+
+fn attribute_error(attr: &Attribute, message: &str) -> DotError {
+    DotError::new(attr.line, attr.column, format!("{message} '{}' for attribute '{}'", attr.value, attr.name))
+}
+
+// X11 color names that are not AppCUI color names
+const COLOR_ALIASES: [(&str, Color); 11] = [
+    ("grey", Color::Gray),
+    ("darkgray", Color::Gray),
+    ("darkgrey", Color::Gray),
+    ("lightgray", Color::Silver),
+    ("lightgrey", Color::Silver),
+    ("cyan", Color::Aqua),
+    ("darkcyan", Color::Teal),
+    ("navy", Color::DarkBlue),
+    ("maroon", Color::DarkRed),
+    ("purple", Color::Magenta),
+    ("fuchsia", Color::Pink),
+];
+
+fn parse_color(attr: &Attribute) -> Result<Color, DotError> {
+    // a color list ("red:blue" or "red;0.5:blue") - only the first color is used
+    let value = attr.value.split([':', ';']).next().unwrap_or_default().trim();
+    if let Some(hex) = value.strip_prefix('#') {
+        if (hex.len() == 6 || hex.len() == 8) && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            let component = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap_or(0);
+            return Ok(Color::from_rgb(component(0), component(2), component(4)));
+        }
+        return Err(attribute_error(attr, "invalid color"));
+    }
+    let color = (0..=16)
+        .filter_map(Color::from_value)
+        .find(|c| c.name().eq_ignore_ascii_case(value))
+        .or_else(|| COLOR_ALIASES.iter().find(|(name, _)| name.eq_ignore_ascii_case(value)).map(|(_, c)| *c));
+    color.ok_or_else(|| attribute_error(attr, "unknown color"))
+}
+
+fn color_name(color: Color) -> Option<String> {
+    if color == Color::Transparent {
+        return None;
+    }
+    if let Some((r, g, b)) = color.rgb() {
+        return Some(format!("#{r:02x}{g:02x}{b:02x}"));
+    }
+    Some(color.name().to_ascii_lowercase())
+}
+
+fn parse_number(attr: &Attribute) -> Result<f64, DotError> {
+    attr.value.trim().parse::<f64>().map_err(|_| attribute_error(attr, "invalid number"))
+}
+
+fn parse_position(attr: &Attribute) -> Result<Point, DotError> {
+    // "x,y" or "x,y!" (the '!' that pins the node in Graphviz is ignored)
+    let value = attr.value.trim().trim_end_matches('!');
+    let mut parts = value.split(',').map(|p| p.trim().parse::<f64>());
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(x)), Some(Ok(y)), None) => Ok(Point::new(x.round() as i32, y.round() as i32)),
+        _ => Err(attribute_error(attr, "invalid position")),
+    }
+}
+
+// computes the line type from the `style` and `penwidth` attributes (`base` is the line type for the shape)
+fn line_type(attrs: &Attributes, base: LineType, double: bool) -> Result<LineType, DotError> {
+    let mut double = double;
+    let (mut bold, mut dashed, mut dotted, mut rounded) = (false, false, false, false);
+    if let Some(style) = attrs.get("style") {
+        for s in style.value.split(',').map(|s| s.trim()) {
+            match s.to_ascii_lowercase().as_str() {
+                "bold" => bold = true,
+                "dashed" => dashed = true,
+                "dotted" => dotted = true,
+                "rounded" => rounded = true,
+                "double" => double = true,
+                _ => {}
+            }
+        }
+    }
+    let thick = match attrs.get("penwidth") {
+        Some(attr) => parse_number(attr)? >= 3.0,
+        None => false,
+    };
+    Ok(if double {
+        LineType::Double
+    } else if thick {
+        LineType::Border
+    } else if bold {
+        LineType::SingleThick
+    } else if dotted {
+        LineType::Braille
+    } else if dashed && rounded {
+        LineType::AsciiRound
+    } else if dashed {
+        LineType::Ascii
+    } else if rounded {
+        LineType::SingleRound
+    } else {
+        base
+    })
+}
+
+fn node_border(attrs: &Attributes) -> Result<Option<LineType>, DotError> {
+    // the default shape in Graphviz is an ellipse
+    let shape = attrs.get("shape").map(|a| a.value.to_ascii_lowercase()).unwrap_or_else(|| "ellipse".to_string());
+    let base = match shape.as_str() {
+        "plaintext" | "plain" | "none" | "underline" => return Ok(None),
+        "ellipse" | "oval" | "circle" | "egg" | "point" => LineType::SingleRound,
+        _ => LineType::Single,
+    };
+    let double = shape.starts_with("double") || shape.starts_with("triple");
+    let double = match attrs.get("peripheries") {
+        Some(attr) => double || parse_number(attr)? >= 2.0,
+        None => double,
+    };
+    Ok(Some(line_type(attrs, base, double)?))
+}
+
+// translates the escape sequences from a label and returns the text and the alignment
+fn label_text(label: &str, id: &str) -> (String, TextAlignment) {
+    let mut text = String::with_capacity(label.len());
+    let mut align = TextAlignment::Center;
+    let mut chars = label.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            text.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some(c @ ('n' | 'l' | 'r')) => {
+                align = match c {
+                    'l' => TextAlignment::Left,
+                    'r' => TextAlignment::Right,
+                    _ => TextAlignment::Center,
+                };
+                text.push('\n');
+            }
+            Some('N') => text.push_str(id),
+            Some(c) => text.push(c),
+            None => text.push('\\'),
+        }
+    }
+    // a line terminator at the end of the label does not add an empty line
+    if text.ends_with('\n') {
+        text.pop();
+    }
+    (text, align)
+}
+
+fn write_label(out: &mut String, label: &str, align: TextAlignment) {
+    let terminator = match align {
+        TextAlignment::Left => "\\l",
+        TextAlignment::Right => "\\r",
+        TextAlignment::Center => "\\n",
+    };
+    out.push('"');
+    for ch in label.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str(terminator),
+            '\r' => {}
+            _ => out.push(ch),
+        }
+    }
+    // '\l' and '\r' justify the line that they end (the last line included)
+    if align != TextAlignment::Center {
+        out.push_str(terminator);
+    }
+    out.push('"');
+}
+
+// the DOT attributes that describe a line type (inverse of `line_type` and `node_border`)
+fn line_type_attributes(lt: LineType, node: bool, style: &mut Vec<&'static str>, attrs: &mut Vec<String>) {
+    match lt {
+        LineType::Single if !node => style.push("solid"),
+        LineType::Single => {}
+        LineType::Double if node => attrs.push("peripheries=2".to_string()),
+        LineType::Double => style.push("double"),
+        LineType::SingleThick => style.push("bold"),
+        LineType::Border => attrs.push("penwidth=3".to_string()),
+        LineType::Ascii => style.push("dashed"),
+        LineType::AsciiRound => style.extend(["rounded", "dashed"]),
+        LineType::SingleRound => style.push("rounded"),
+        LineType::Braille => style.push("dotted"),
+    }
+}
+
+fn write_attributes(out: &mut String, style: &[&str], attrs: &[String]) {
+    let mut first = true;
+    for a in attrs.iter().map(|a| a.as_str()) {
+        out.push_str(if first { " [" } else { ", " });
+        out.push_str(a);
+        first = false;
+    }
+    if !style.is_empty() {
+        out.push_str(if first { " [" } else { ", " });
+        let _ = write!(out, "style=\"{}\"", style.join(","));
+        first = false;
+    }
+    if !first {
+        out.push(']');
+    }
+}
+
+impl<T> Graph<T>
+where
+    T: GraphNode + From<String>,
+{
+    /// Creates a graph from a [Graphviz DOT](https://graphviz.org/doc/info/lang.html) description.
+    ///
+    /// Both `graph` and `digraph` descriptions (optionally `strict`) are supported, including subgraphs,
+    /// default `node`/`edge` attributes and edge chains (`a -> b -> c`). Node values are created from the
+    /// `label` attribute (or from the node id if there is no label). The following attributes are used:
+    /// * `shape`, `style`, `peripheries` and `penwidth` - the border of a node or the line type of an edge
+    /// * `color`, `fontcolor` and `fillcolor` - the colors of a node or of an edge
+    /// * `pos` - the position (`"x,y"`, in characters) of the top-left corner of a node
+    /// * `dir` - the direction of an edge
+    /// * `label` - the label of an edge (only the first line is used)
+    /// * `tailport` and `headport` (or the `node:port` syntax) - the ports where an edge is attached
+    ///
+    /// Subgraphs whose name starts with `cluster` (for example `subgraph cluster_db { ... }`) become clusters:
+    /// their `label` is the title of the cluster, while `style`, `penwidth` and `color` describe its border.
+    /// Nested clusters are flattened (a node belongs to the innermost cluster that contains it).
+    ///
+    /// Other attributes are ignored. Keep in mind that a [`GraphView`](super::GraphView) arranges the nodes
+    /// of a graph when it is set - use [`ArrangeMethod::None`](super::ArrangeMethod::None) to keep the positions
+    /// from the `pos` attributes.
+    pub fn from_dot(text: &str) -> Result<Self, DotError> {
+        let mut parser = Parser {
+            tokens: tokenize(text)?,
+            pos: 0,
+            directed: false,
+            strict: false,
+            nodes: Vec::new(),
+            ids: HashMap::new(),
+            edges: Vec::new(),
+            clusters: Vec::new(),
+        };
+        parser.parse()?;
+        let mut nodes: Vec<Node<T>> = Vec::with_capacity(parser.nodes.len());
+        for n in &parser.nodes {
+            let (label, align) = match n.attrs.get("label") {
+                Some(attr) => label_text(&attr.value, &n.id),
+                None => (n.id.clone(), TextAlignment::Center),
+            };
+            let mut builder = NodeBuilder::new(T::from(label)).text_alignment(align);
+            if let Some(border) = node_border(&n.attrs)? {
+                builder = builder.border(border);
+            }
+            let fore = match n.attrs.get("fontcolor").or_else(|| n.attrs.get("color")) {
+                Some(attr) => Some(parse_color(attr)?),
+                None => None,
+            };
+            let back = match n.attrs.get("fillcolor") {
+                Some(attr) => Some(parse_color(attr)?),
+                None => None,
+            };
+            if fore.is_some() || back.is_some() {
+                let attr = CharAttribute::with_color(fore.unwrap_or(Color::Transparent), back.unwrap_or(Color::Transparent));
+                builder = builder.text_attribute(attr);
+            }
+            if let Some(attr) = n.attrs.get("pos") {
+                builder = builder.position(parse_position(attr)?);
+            }
+            nodes.push(builder.build());
+        }
+        let mut edges: Vec<Edge> = Vec::with_capacity(parser.edges.len());
+        for e in &parser.edges {
+            let (mut from, mut to, mut directed) = (e.from, e.to, parser.directed);
+            let mut from_port = e.from_port.as_deref().or_else(|| e.attrs.get("tailport").map(|a| a.value.as_str()));
+            let mut to_port = e.to_port.as_deref().or_else(|| e.attrs.get("headport").map(|a| a.value.as_str()));
+            if let Some(attr) = e.attrs.get("dir") {
+                match attr.value.to_ascii_lowercase().as_str() {
+                    "forward" => directed = true,
+                    "back" => {
+                        (from, to) = (to, from);
+                        (from_port, to_port) = (to_port, from_port);
+                        directed = true;
+                    }
+                    "both" | "none" => directed = false,
+                    _ => return Err(attribute_error(attr, "invalid direction")),
+                }
+            }
+            let mut builder = EdgeBuilder::new(from as u32, to as u32).directed(directed);
+            if e.attrs.get("style").is_some() || e.attrs.get("penwidth").is_some() {
+                builder = builder.line_type(line_type(&e.attrs, LineType::Single, false)?);
+            }
+            if let Some(attr) = e.attrs.get("color") {
+                builder = builder.attribute(CharAttribute::with_fore_color(parse_color(attr)?));
+            }
+            if let Some(attr) = e.attrs.get("label") {
+                // edge labels are drawn on a single line
+                let (text, _) = label_text(&attr.value, "");
+                if let Some(line) = text.lines().next().filter(|l| !l.is_empty()) {
+                    builder = builder.label(line);
+                }
+            }
+            if let Some(port) = from_port.filter(|p| !p.is_empty() && *p != "_" && *p != "c") {
+                builder = builder.from_port(port);
+            }
+            if let Some(port) = to_port.filter(|p| !p.is_empty() && *p != "_" && *p != "c") {
+                builder = builder.to_port(port);
+            }
+            edges.push(builder.build());
+        }
+        let mut clusters: Vec<Cluster> = Vec::with_capacity(parser.clusters.len());
+        for c in &parser.clusters {
+            // the title is the first line of the label (or the name of the subgraph, without the "cluster" prefix)
+            let title = match c.attrs.get("label") {
+                Some(attr) => label_text(&attr.value, &c.name).0.lines().next().unwrap_or_default().to_string(),
+                None => c.name.trim_start_matches("cluster").trim_start_matches('_').to_string(),
+            };
+            let nodes: Vec<u32> = c.nodes.iter().map(|n| *n as u32).collect();
+            let mut builder = ClusterBuilder::new(&title).nodes(&nodes).border(line_type(&c.attrs, LineType::Single, false)?);
+            if let Some(attr) = c.attrs.get("fontcolor").or_else(|| c.attrs.get("color")) {
+                builder = builder.attribute(CharAttribute::with_fore_color(parse_color(attr)?));
+            }
+            clusters.push(builder.build());
+        }
+        Ok(Graph::with_clusters(nodes, edges, clusters))
+    }
+}
+
+impl<T> Graph<T>
+where
+    T: GraphNode,
+{
+    /// Converts the graph into a [Graphviz DOT](https://graphviz.org/doc/info/lang.html) description that can be
+    /// loaded back with [`Graph::from_dot`].
+    ///
+    /// Nodes are named `n0`, `n1`, ... (in the order of their indexes) and keep their label, border, colors,
+    /// text alignment and position (the `pos` attribute). Edge labels and ports are stored in the `label`,
+    /// `tailport` and `headport` attributes, and every cluster is written as a `subgraph cluster_<index>`.
+    /// If the graph contains both directed and undirected edges, a `digraph` is created and the undirected
+    /// edges have the `dir=none` attribute.
+    pub fn to_dot(&self) -> String {
+        let directed = self.edges.iter().any(|e| e.directed);
+        let mut out = String::with_capacity(64 + (self.nodes.len() + self.edges.len()) * 48);
+        out.push_str(if directed { "digraph {\n" } else { "graph {\n" });
+        let mut label = String::new();
+        let mut style = Vec::new();
+        let mut attrs = Vec::new();
+        for (index, n) in self.nodes.iter().enumerate() {
+            label.clear();
+            // the label is not limited (a large size means that the text is not wrapped)
+            if n.obj.write_label(&mut label, Size::new(u32::MAX, u32::MAX)).is_err() {
+                label.clear();
+            }
+            style.clear();
+            attrs.clear();
+            let mut text = String::from("label=");
+            write_label(&mut text, &label, n.text_align);
+            attrs.push(text);
+            match n.border {
+                Some(border) => {
+                    attrs.push("shape=box".to_string());
+                    line_type_attributes(border, true, &mut style, &mut attrs);
+                }
+                None => attrs.push("shape=plaintext".to_string()),
+            }
+            if let Some(attr) = n.text_attr {
+                if let Some(name) = color_name(attr.foreground) {
+                    attrs.push(format!("color=\"{name}\""));
+                }
+                if let Some(name) = color_name(attr.background) {
+                    attrs.push(format!("fillcolor=\"{name}\""));
+                    style.push("filled");
+                }
+            }
+            attrs.push(format!("pos=\"{},{}\"", n.rect.left(), n.rect.top()));
+            let _ = write!(out, "    n{index}");
+            write_attributes(&mut out, &style, &attrs);
+            out.push_str(";\n");
+        }
+        for (index, c) in self.clusters.iter().enumerate() {
+            style.clear();
+            attrs.clear();
+            let mut text = String::from("label=");
+            write_label(&mut text, &c.title, TextAlignment::Center);
+            attrs.push(text);
+            if c.border != LineType::Single {
+                line_type_attributes(c.border, false, &mut style, &mut attrs);
+            }
+            if let Some(name) = c.attribute.and_then(|a| color_name(a.foreground)) {
+                attrs.push(format!("color=\"{name}\""));
+            }
+            let _ = write!(out, "    subgraph cluster_{index} {{\n        graph");
+            write_attributes(&mut out, &style, &attrs);
+            out.push_str(";\n");
+            for n in &c.nodes {
+                let _ = writeln!(out, "        n{n};");
+            }
+            out.push_str("    }\n");
+        }
+        for e in &self.edges {
+            style.clear();
+            attrs.clear();
+            if directed && !e.directed {
+                attrs.push("dir=none".to_string());
+            }
+            if let Some(lt) = e.line_type {
+                line_type_attributes(lt, false, &mut style, &mut attrs);
+            }
+            if let Some(name) = e.attribute.and_then(|a| color_name(a.foreground)) {
+                attrs.push(format!("color=\"{name}\""));
+            }
+            if let Some(text) = &e.label {
+                let mut value = String::from("label=");
+                write_label(&mut value, text, TextAlignment::Center);
+                attrs.push(value);
+            }
+            if let Some(port) = &e.from_port {
+                let mut value = String::from("tailport=");
+                write_label(&mut value, port, TextAlignment::Center);
+                attrs.push(value);
+            }
+            if let Some(port) = &e.to_port {
+                let mut value = String::from("headport=");
+                write_label(&mut value, port, TextAlignment::Center);
+                attrs.push(value);
+            }
+            let op = if directed { "->" } else { "--" };
+            let _ = write!(out, "    n{} {op} n{}", e.from_node_id, e.to_node_id);
+            write_attributes(&mut out, &style, &attrs);
+            out.push_str(";\n");
+        }
+        out.push_str("}\n");
+        out
+    }
+}
@@ -6,6 +6,9 @@ pub struct Edge {
     pub(super) directed: bool,
     pub(super) attribute: Option<CharAttribute>,
     pub(super) line_type: Option<LineType>,
+    pub(super) label: Option<String>,
+    pub(super) from_port: Option<String>,
+    pub(super) to_port: Option<String>,
 }
 
 pub struct EdgeBuilder {
@@ -21,6 +24,9 @@ impl EdgeBuilder {
                 directed: false,
                 attribute: None,
                 line_type: None,
+                label: None,
+                from_port: None,
+                to_port: None,
             },
         }
     }
@@ -43,6 +49,26 @@ impl EdgeBuilder {
         self
     }
 
+    /// Set a text label for the edge (drawn along the edge, at its middle)
+    pub fn label(mut self, text: &str) -> Self {
+        self.edge.label = Some(text.to_string());
+        self
+    }
+
+    /// Set the port of the source node where the edge starts. The name is either the name of a port added to the node
+    /// via NodeBuilder::port or a compass point (`n`, `e`, `s` or `w`). If not set (or if the node has no such port),
+    /// the edge starts from the side of the node that is the closest to the target node.
+    pub fn from_port(mut self, name: &str) -> Self {
+        self.edge.from_port = Some(name.to_string());
+        self
+    }
+
+    /// Set the port of the target node where the edge ends (see EdgeBuilder::from_port for the format of the name)
+    pub fn to_port(mut self, name: &str) -> Self {
+        self.edge.to_port = Some(name.to_string());
+        self
+    }
+
     /// Builds the actual edge
     #[inline(always)]
     pub fn build(self) -> Edge {
@@ -99,4 +125,43 @@ impl<'a> EditableEdge<'a> {
             *self.changed = true;
         }
     }
-}   
\ No newline at end of file
+    /// Optional text label drawn along the edge.
+    #[inline(always)]
+    pub fn label(&self) -> Option<&str> {
+        self.edge.label.as_deref()
+    }
+    /// Sets the edge label. Marks the graph as changed if the value differs.
+    #[inline(always)]
+    pub fn set_label(&mut self, text: &str) {
+        if self.edge.label.as_deref() != Some(text) {
+            self.edge.label = Some(text.to_string());
+            *self.changed = true;
+        }
+    }
+    /// Removes the edge label. Marks the graph as changed if a label was present.
+    #[inline(always)]
+    pub fn clear_label(&mut self) {
+        if self.edge.label.is_some() {
+            self.edge.label = None;
+            *self.changed = true;
+        }
+    }
+    /// Name of the port of the source node where the edge starts (if any).
+    #[inline(always)]
+    pub fn from_port(&self) -> Option<&str> {
+        self.edge.from_port.as_deref()
+    }
+    /// Name of the port of the target node where the edge ends (if any).
+    #[inline(always)]
+    pub fn to_port(&self) -> Option<&str> {
+        self.edge.to_port.as_deref()
+    }
+    /// Sets (or clears, if `None`) the ports where the edge starts and ends. Marks the graph as changed if the values differ.
+    pub fn set_ports(&mut self, from_port: Option<&str>, to_port: Option<&str>) {
+        if self.edge.from_port.as_deref() != from_port || self.edge.to_port.as_deref() != to_port {
+            self.edge.from_port = from_port.map(|p| p.to_string());
+            self.edge.to_port = to_port.map(|p| p.to_string());
+            *self.changed = true;
+        }
+    }
+}
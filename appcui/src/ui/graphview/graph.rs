@@ -1,3 +1,4 @@
+use super::Cluster;
 use super::Edge;
 use super::EdgeBuilder;
 use super::EditableEdge;
@@ -6,12 +7,15 @@ use super::EditableNode;
 use super::GraphNode;
 use super::Node;
 use super::NodeBuilder;
+use super::Port;
+use super::PortSide;
 use super::RenderingOptions;
 use crate::prelude::*;
 use crate::utils::GlyphParser;
+use std::collections::HashMap;
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-enum ControlState {
+pub(super) enum ControlState {
     Disabled,
     Normal,
     Focused,
@@ -35,7 +39,7 @@ impl Direction {
 }
 
 impl ControlState {
-    fn new(control: &ControlBase) -> Self {
+    pub(super) fn new(control: &ControlBase) -> Self {
         if !control.is_enabled() {
             ControlState::Disabled
         } else if control.has_focus() {
@@ -45,14 +49,14 @@ impl ControlState {
         }
     }
     #[inline(always)]
-    fn node_attr(&self, theme: &Theme) -> CharAttribute {
+    pub(super) fn node_attr(&self, theme: &Theme) -> CharAttribute {
         match self {
             ControlState::Disabled => theme.text.inactive,
             ControlState::Normal | ControlState::Focused => theme.button.regular.text.normal,
         }
     }
     #[inline(always)]
-    fn edge_attr(&self, theme: &Theme) -> CharAttribute {
+    pub(super) fn edge_attr(&self, theme: &Theme) -> CharAttribute {
         match self {
             ControlState::Disabled => theme.lines.inactive,
             ControlState::Normal => theme.lines.normal,
@@ -60,16 +64,16 @@ impl ControlState {
         }
     }
     #[inline(always)]
-    fn hovered_node_attr(&self, theme: &Theme) -> CharAttribute {
+    pub(super) fn hovered_node_attr(&self, theme: &Theme) -> CharAttribute {
         theme.button.regular.text.hovered
     }
     #[inline(always)]
-    fn current_node_attr(&self, theme: &Theme) -> CharAttribute {
+    pub(super) fn current_node_attr(&self, theme: &Theme) -> CharAttribute {
         theme.button.regular.text.focused
     }
     /// Extra-selected nodes in multi-select mode (non-primary); distinct from focus/current.
     #[inline(always)]
-    fn multiselect_selected_label_attr(theme: &Theme) -> CharAttribute {
+    pub(super) fn multiselect_selected_label_attr(theme: &Theme) -> CharAttribute {
         theme.button.regular.text.pressed_or_selected
     }
 }
@@ -224,19 +228,118 @@ fn closest_points(r1: &Rect, r2: &Rect) -> (Point, Point, OrthogonalDirection, O
     }
 }
 
+// the side of `rect` where the point `p` (a point on the border of `rect`) is located
+fn side_of(rect: &Rect, p: Point) -> PortSide {
+    if p.x == rect.left() && p.x != rect.right() {
+        PortSide::West
+    } else if p.x == rect.right() && p.y != rect.top() && p.y != rect.bottom() {
+        PortSide::East
+    } else if p.y == rect.top() {
+        PortSide::North
+    } else if p.y == rect.bottom() {
+        PortSide::South
+    } else {
+        PortSide::East
+    }
+}
+
+// the point next to `p`, outside of the side of the node
+fn outside(p: Point, side: PortSide) -> Point {
+    match side {
+        PortSide::North => Point::new(p.x, p.y - 1),
+        PortSide::East => Point::new(p.x + 1, p.y),
+        PortSide::South => Point::new(p.x, p.y + 1),
+        PortSide::West => Point::new(p.x - 1, p.y),
+    }
+}
+
+// the arrow head drawn before a point placed on the specified side of the target node
+fn arrow_for(side: PortSide) -> SpecialChar {
+    match side {
+        PortSide::North => SpecialChar::TriangleDown,
+        PortSide::East => SpecialChar::TriangleLeft,
+        PortSide::South => SpecialChar::TriangleUp,
+        PortSide::West => SpecialChar::TriangleRight,
+    }
+}
+
+// the corner points of an orthogonal route (the same route that `Surface::draw_orthogonal_line` draws)
+fn orthogonal_path(p1: Point, p2: Point, mut dir: OrthogonalDirection) -> Vec<Point> {
+    if p1.x == p2.x || p1.y == p2.y {
+        return vec![p1, p2];
+    }
+    if dir == OrthogonalDirection::Auto {
+        dir = if (p2.x - p1.x).abs() > (p2.y - p1.y).abs() {
+            OrthogonalDirection::HorizontalFirst
+        } else {
+            OrthogonalDirection::VerticalFirst
+        };
+    }
+    match dir {
+        OrthogonalDirection::HorizontalFirst => vec![p1, Point::new(p2.x, p1.y), p2],
+        OrthogonalDirection::VerticalFirst => vec![p1, Point::new(p1.x, p2.y), p2],
+        OrthogonalDirection::HorizontalUntilMiddle => {
+            let x = (p1.x + p2.x) / 2;
+            vec![p1, Point::new(x, p1.y), Point::new(x, p2.y), p2]
+        }
+        OrthogonalDirection::VerticalUntilMiddle | OrthogonalDirection::Auto => {
+            let y = (p1.y + p2.y) / 2;
+            vec![p1, Point::new(p1.x, y), Point::new(p2.x, y), p2]
+        }
+    }
+}
+
+// draws a route made of horizontal and vertical segments (consecutive points must be on the same row or column)
+fn draw_orthogonal_path(surface: &mut Surface, points: &[Point], line_type: LineType, attr: CharAttribute) {
+    for w in points.windows(2) {
+        surface.draw_orthogonal_line(w[0].x, w[0].y, w[1].x, w[1].y, line_type, OrthogonalDirection::Auto, attr);
+    }
+    // the corners are drawn as a small elbow (one character before and after the corner)
+    for w in points.windows(3) {
+        let (a, b, c) = (w[0], w[1], w[2]);
+        if (a.x == b.x && b.x == c.x) || (a.y == b.y && b.y == c.y) || a == b || b == c {
+            continue;
+        }
+        let before = Point::new(b.x + (a.x - b.x).signum(), b.y + (a.y - b.y).signum());
+        let after = Point::new(b.x + (c.x - b.x).signum(), b.y + (c.y - b.y).signum());
+        let dir = if a.y == b.y {
+            OrthogonalDirection::HorizontalFirst
+        } else {
+            OrthogonalDirection::VerticalFirst
+        };
+        surface.draw_orthogonal_line(before.x, before.y, after.x, after.y, line_type, dir, attr);
+    }
+}
+
+// how an edge is drawn: the points of the route and the arrow head (position and character)
+struct EdgeRoute {
+    points: Vec<Point>,
+    orthogonal: bool,
+    arrow: Option<(Point, SpecialChar)>,
+    // a simple edge (no ports, no parallel edges) - drawn directly between the two closest points of the nodes
+    simple: Option<(OrthogonalDirection, Option<Direction>)>,
+}
+
 pub struct Graph<T>
 where
     T: GraphNode,
 {
     pub(super) nodes: Vec<Node<T>>,
     pub(super) edges: Vec<Edge>,
+    pub(super) clusters: Vec<Cluster>,
+    // for every node: the index of its cluster (or NO_CLUSTER)
+    pub(super) node_cluster: Vec<u32>,
     surface_size: Size,
-    surface: Surface,
+    pub(super) surface: Surface,
     pub(super) current_node: usize,
-    hovered_node: Option<usize>,
+    pub(super) hovered_node: Option<usize>,
     /// Right-drag new edge: source node index and line endpoint in graph coordinates.
     pending_edge_preview: Option<(usize, Point)>,
     repr_buffer: String,
+    // for every edge: the index of a self-loop or the offset of a parallel edge (see `compute_edge_offsets`)
+    edge_offsets: Vec<i32>,
+    // for every edge: the number of edges it stands for (see `compute_edge_aggregation`)
+    edge_aggregation: Vec<u32>,
     pub(super) rendering_options: RenderingOptions,
 }
 impl<T> Graph<T>
@@ -269,12 +372,16 @@ where
         let mut g = Self {
             nodes,
             edges,
+            clusters: Vec::new(),
+            node_cluster: Vec::new(),
             surface_size: Size::new(1, 1),
             surface: Surface::new(200, 200),
             current_node: 0,
             hovered_node: None,
             pending_edge_preview: None,
             repr_buffer: String::with_capacity(128),
+            edge_offsets: Vec::new(),
+            edge_aggregation: Vec::new(),
             rendering_options: RenderingOptions::new(),
         };
         // remove edges that have invalid node index value
@@ -282,6 +389,17 @@ where
         g.edges.retain(|e| (e.from_node_id < nodes_count) && (e.to_node_id < nodes_count));
         // build edges_in / edges_out for each node
         g.update_edges_in_out();
+        g.update_clusters();
+        g
+    }
+    /// Constructs a graph from pre-built nodes, edges and clusters (groups of nodes drawn as a bordered region).
+    ///
+    /// Invalid node indices are removed from the clusters, and a node that is part of several clusters only belongs
+    /// to the first one.
+    pub fn with_clusters(nodes: Vec<Node<T>>, edges: Vec<Edge>, clusters: Vec<Cluster>) -> Self {
+        let mut g = Self::new(nodes, edges);
+        g.clusters = clusters;
+        g.update_clusters();
         g
     }
     /// Builds a graph by cloning node values into default-styled nodes and creating edges from index pairs.
@@ -332,6 +450,16 @@ where
         self.nodes.get(index)
     }
 
+    /// Returns the number of clusters in the graph
+    pub fn clusters_count(&self) -> usize {
+        self.clusters.len()
+    }
+
+    /// Returns the cluster at `index`, or `None` if out of range.
+    pub fn cluster(&self, index: usize) -> Option<&Cluster> {
+        self.clusters.get(index)
+    }
+
     fn update_surface_size(&mut self, pack: bool) {
         if self.nodes.is_empty() {
             self.surface_size = Size::new(1, 1);
@@ -347,6 +475,19 @@ where
             br.y = br.y.max(n.rect.bottom());
             //log!("G","  R = {:?}",n.rect);
         }
+        self.update_cluster_rects();
+        for c in self.clusters.iter().filter(|c| !c.nodes.is_empty()) {
+            tl.x = tl.x.min(c.rect.left());
+            tl.y = tl.y.min(c.rect.top());
+            br.x = br.x.max(c.rect.right());
+            br.y = br.y.max(c.rect.bottom());
+        }
+        // self-loops and parallel edges are drawn outside the nodes
+        let margin = self.edges_margin();
+        tl.x -= margin;
+        tl.y -= margin;
+        br.x += margin;
+        br.y += margin;
         let dx = 2 - tl.x; // one character on X-axes
         let dy = 1 - tl.y; // two character on Y-axes
         if pack {
@@ -371,6 +512,7 @@ where
 
     pub(super) fn resize_graph(&mut self, pack: bool) {
         self.update_surface_size(pack);
+        self.update_cluster_rects();
         self.surface.resize(self.surface_size);
     }
 
@@ -426,7 +568,7 @@ where
             let len = self.nodes.len();
             for i in 0..self.nodes.len() {
                 let idx = (self.current_node + i) % len;
-                if !self.nodes[idx].filtered {
+                if !self.nodes[idx].filtered && self.is_node_visible(idx) {
                     self.current_node = idx;
                     break;
                     // no need to repaint here as we wil do it later
@@ -446,7 +588,7 @@ where
         let len = self.nodes.len();
         for i in 1..=len {
             let idx = (self.current_node + i) % len;
-            if !self.nodes[idx].filtered {
+            if !self.nodes[idx].filtered && self.is_node_visible(idx) {
                 self.set_current_node(idx, control);
                 break;
             }
@@ -459,7 +601,7 @@ where
         let len = self.nodes.len();
         for i in 1..=len {
             let idx = (self.current_node + len - i) % len;
-            if !self.nodes[idx].filtered {
+            if !self.nodes[idx].filtered && self.is_node_visible(idx) {
                 self.set_current_node(idx, control);
                 break;
             }
@@ -492,8 +634,10 @@ where
     }
     pub(super) fn mouse_pos_to_index(&self, x: i32, y: i32) -> Option<usize> {
         for (idx, n) in self.nodes.iter().enumerate() {
-            if n.contains(x, y) {
-                return Some(idx);
+            match self.collapsed_cluster_of(idx) {
+                None if n.contains(x, y) => return Some(idx),
+                Some(c) if self.is_node_visible(idx) && self.clusters[c].rect.contains(Point::new(x, y)) => return Some(idx),
+                _ => {}
             }
         }
         None
@@ -534,30 +678,326 @@ where
             ControlState::Focused => theme.lines.hovered,
         };
         let mouse_rect = Rect::new(end.x, end.y, end.x, end.y);
-        let (p1, p2, _, _) = closest_points(&self.nodes[from_ix].rect, &mouse_rect);
+        let (p1, p2, _, _) = closest_points(&self.node_rect(from_ix), &mouse_rect);
         let lt = self.rendering_options.edge_line_type;
         self.surface.draw_line(p1.x, p1.y, p2.x, p2.y, lt, attr);
     }
-    fn draw_edge(&mut self, index: u32, attr: CharAttribute) {
+    // for every edge: the index of the self-loop (for edges from a node to itself) or the offset of the edge
+    // within a group of parallel edges (edges with the same source and target nodes) - 0 for a single edge.
+    // An edge and its reverse are not parallel (both use the normal route). The edges that are not drawn
+    // (see `compute_edge_aggregation`) are skipped.
+    pub(super) fn compute_edge_offsets(&self, aggregation: &[u32]) -> Vec<i32> {
+        let mut counts: HashMap<(usize, usize), i32> = HashMap::new();
+        let key = |e: &Edge| (self.representative(e.from_node_id as usize), self.representative(e.to_node_id as usize));
+        for (e, _) in self.edges.iter().zip(aggregation).filter(|(_, n)| **n > 0) {
+            *counts.entry(key(e)).or_insert(0) += 1;
+        }
+        let mut used: HashMap<(usize, usize), i32> = HashMap::with_capacity(counts.len());
+        self.edges
+            .iter()
+            .zip(aggregation)
+            .map(|(e, n)| {
+                if *n == 0 {
+                    return 0;
+                }
+                let k = key(e);
+                let index = used.entry(k).or_insert(0);
+                let result = if e.from_node_id == e.to_node_id {
+                    *index
+                } else {
+                    // n parallel edges have the offsets -(n-1), -(n-3), ..., n-3, n-1
+                    2 * *index - (counts[&k] - 1)
+                };
+                *index += 1;
+                result
+            })
+            .collect()
+    }
+    // the extra space needed around the nodes for self-loops and parallel edges (0 for a graph without them)
+    pub(super) fn edges_margin(&self) -> i32 {
+        let aggregation = self.compute_edge_aggregation();
+        self.edges
+            .iter()
+            .zip(self.compute_edge_offsets(&aggregation))
+            .zip(aggregation)
+            .map(|((e, offset), n)| {
+                if n == 0 {
+                    0
+                } else if e.from_node_id == e.to_node_id {
+                    offset + 2
+                } else if offset != 0 {
+                    (offset.abs() + 1) / 2 + 1
+                } else {
+                    0
+                }
+            })
+            .max()
+            .unwrap_or(0)
+    }
+    fn self_loop_route(&self, e: &Edge, index: i32) -> EdgeRoute {
+        let r = self.node_rect(e.from_node_id as usize);
+        // the loop ends on the top side, close to the right corner (the middle of the top side is used by other edges)
+        let x = (r.right() - 2 - index).max(r.left() + 1).min(r.right());
+        let right = r.right() + 2 + index;
+        let top = r.top() - 2 - index;
+        let y = r.center_y();
+        EdgeRoute {
+            points: vec![
+                Point::new(r.right(), y),
+                Point::new(right, y),
+                Point::new(right, top),
+                Point::new(x, top),
+                Point::new(x, r.top()),
+            ],
+            orthogonal: true,
+            arrow: Some((Point::new(x, r.top() - 1), SpecialChar::TriangleDown)),
+            simple: None,
+        }
+    }
+    // parallel edges: in orthogonal mode each edge goes around the nodes (above/below or left/right),
+    // while in direct mode the edge is bent through a point placed next to the middle of the edge
+    fn parallel_edge_route(&self, e: &Edge, offset: i32) -> EdgeRoute {
+        let r1 = self.node_rect(e.from_node_id as usize);
+        let r2 = self.node_rect(e.to_node_id as usize);
+        let (p1, p2, orto_dir, entry_dir) = closest_points(&r1, &r2);
+        let horizontal = orto_dir != OrthogonalDirection::VerticalUntilMiddle;
+        if self.rendering_options.edge_routing == EdgeRouting::Direct {
+            let mut m = Point::new((p1.x + p2.x) / 2, (p1.y + p2.y) / 2);
+            if horizontal {
+                m.y += offset;
+            } else {
+                m.x += offset * 2;
+            }
+            let arrow = entry_dir.map(|d| {
+                let side = match d {
+                    Direction::Left => PortSide::West,
+                    Direction::Right => PortSide::East,
+                    Direction::Top => PortSide::North,
+                    Direction::Bottom => PortSide::South,
+                };
+                (outside(p2, side), arrow_for(side))
+            });
+            return EdgeRoute {
+                points: vec![p1, m, p2],
+                orthogonal: false,
+                arrow,
+                simple: None,
+            };
+        }
+        let lane = (offset.abs() + 1) / 2;
+        // the outer lanes are attached farther from the center of the nodes (so that the lanes do not cross)
+        let shift = lane - 1;
+        let (side, a, b, turn) = if horizontal {
+            let sign = if r1.center_x() < r2.center_x() { -1 } else { 1 };
+            let a = (r1.center_x() + sign * shift).clamp(r1.left() + 1, (r1.right() - 1).max(r1.left()));
+            let b = (r2.center_x() - sign * shift).clamp(r2.left() + 1, (r2.right() - 1).max(r2.left()));
+            if offset < 0 {
+                (PortSide::North, Point::new(a, r1.top()), Point::new(b, r2.top()), r1.top().min(r2.top()) - 1 - lane)
+            } else {
+                (PortSide::South, Point::new(a, r1.bottom()), Point::new(b, r2.bottom()), r1.bottom().max(r2.bottom()) + 1 + lane)
+            }
+        } else {
+            let sign = if r1.center_y() < r2.center_y() { -1 } else { 1 };
+            let a = (r1.center_y() + sign * shift).clamp(r1.top(), r1.bottom());
+            let b = (r2.center_y() - sign * shift).clamp(r2.top(), r2.bottom());
+            if offset < 0 {
+                (PortSide::West, Point::new(r1.left(), a), Point::new(r2.left(), b), r1.left().min(r2.left()) - 1 - lane)
+            } else {
+                (PortSide::East, Point::new(r1.right(), a), Point::new(r2.right(), b), r1.right().max(r2.right()) + 1 + lane)
+            }
+        };
+        let (t1, t2) = if horizontal {
+            (Point::new(a.x, turn), Point::new(b.x, turn))
+        } else {
+            (Point::new(turn, a.y), Point::new(turn, b.y))
+        };
+        EdgeRoute {
+            points: vec![a, t1, t2, b],
+            orthogonal: true,
+            arrow: Some((outside(b, side), arrow_for(side))),
+            simple: None,
+        }
+    }
+    // an edge with a port on (at least) one of its ends
+    fn port_route(&self, e: &Edge, from_port: Option<Port>, to_port: Option<Port>) -> EdgeRoute {
+        let r1 = self.node_rect(e.from_node_id as usize);
+        let r2 = self.node_rect(e.to_node_id as usize);
+        let p1 = from_port.map(|p| p.point(&r1));
+        let p2 = to_port.map(|p| p.point(&r2));
+        // an end without a port is attached to the side that is the closest to the other end
+        let p1 = p1.unwrap_or_else(|| {
+            let target = p2.map(|p| Rect::new(p.x, p.y, p.x, p.y)).unwrap_or(r2);
+            closest_points(&r1, &target).0
+        });
+        let p2 = p2.unwrap_or_else(|| closest_points(&Rect::new(p1.x, p1.y, p1.x, p1.y), &r2).1);
+        let side1 = from_port.map(|p| p.side()).unwrap_or_else(|| side_of(&r1, p1));
+        let side2 = to_port.map(|p| p.side()).unwrap_or_else(|| side_of(&r2, p2));
+        let s = outside(p1, side1);
+        let t = outside(p2, side2);
+        let arrow = Some((t, arrow_for(side2)));
+        if self.rendering_options.edge_routing == EdgeRouting::Direct {
+            return EdgeRoute {
+                points: vec![p1, s, t, p2],
+                orthogonal: false,
+                arrow,
+                simple: None,
+            };
+        }
+        let mut points = vec![p1];
+        let dir = match (side1.is_horizontal(), side2.is_horizontal()) {
+            (true, true) => OrthogonalDirection::HorizontalUntilMiddle,
+            (false, false) => OrthogonalDirection::VerticalUntilMiddle,
+            (true, false) => OrthogonalDirection::HorizontalFirst,
+            (false, true) => OrthogonalDirection::VerticalFirst,
+        };
+        points.extend(orthogonal_path(s, t, dir));
+        points.push(p2);
+        EdgeRoute {
+            points,
+            orthogonal: true,
+            arrow,
+            simple: None,
+        }
+    }
+    // the top-left position of a label with `width` characters placed next to the middle of the longest segment of a route
+    // (the positions that overlap a node are avoided)
+    fn label_position(&self, points: &[Point], width: i32) -> Point {
+        let mut best = (points[0], points[points.len() - 1]);
+        let mut best_len = -1;
+        for w in points.windows(2) {
+            let len = (w[1].x - w[0].x).abs() + (w[1].y - w[0].y).abs();
+            if len > best_len {
+                best = (w[0], w[1]);
+                best_len = len;
+            }
+        }
+        let (a, b) = best;
+        let m = Point::new((a.x + b.x) / 2, (a.y + b.y) / 2);
+        let centered = m.x - width / 2;
+        let candidates = if a.x == b.x {
+            [
+                Point::new(m.x + 1, m.y),
+                Point::new(m.x - width, m.y),
+                Point::new(m.x + 1, m.y - 1),
+                Point::new(m.x + 1, m.y + 1),
+                Point::new(centered, m.y),
+            ]
+        } else if a.y != b.y || best_len >= width + 2 {
+            // the label is written over the segment (if it is long enough)
+            [
+                Point::new(centered, m.y),
+                Point::new(centered, m.y - 1),
+                Point::new(centered, m.y + 1),
+                Point::new(m.x + 1, m.y),
+                Point::new(m.x - width, m.y),
+            ]
+        } else {
+            [
+                Point::new(centered, m.y - 1),
+                Point::new(centered, m.y + 1),
+                Point::new(centered, m.y),
+                Point::new(m.x + 1, m.y),
+                Point::new(m.x - width, m.y),
+            ]
+        };
+        candidates
+            .iter()
+            .copied()
+            .find(|p| {
+                (0..self.nodes.len()).filter(|&i| self.is_node_visible(i)).all(|i| {
+                    let r = self.node_rect(i);
+                    p.y < r.top() || p.y > r.bottom() || p.x + width - 1 < r.left() || p.x > r.right()
+                })
+            })
+            .unwrap_or(candidates[0])
+    }
+    fn edge_route(&self, index: u32) -> EdgeRoute {
         let e = &self.edges[index as usize];
-
+        let offset = self.edge_offsets.get(index as usize).copied().unwrap_or(0);
+        let (from, to) = (e.from_node_id as usize, e.to_node_id as usize);
+        // the summary node of a collapsed cluster has no ports
+        let from_port = e.from_port.as_deref().filter(|_| self.collapsed_cluster_of(from).is_none()).and_then(|p| self.nodes[from].port(p));
+        let to_port = e.to_port.as_deref().filter(|_| self.collapsed_cluster_of(to).is_none()).and_then(|p| self.nodes[to].port(p));
+        if e.from_node_id == e.to_node_id {
+            return self.self_loop_route(e, offset);
+        }
+        if from_port.is_some() || to_port.is_some() {
+            return self.port_route(e, from_port, to_port);
+        }
+        if offset != 0 {
+            return self.parallel_edge_route(e, offset);
+        }
         // let p1 = self.nodes[e.from_node_id as usize].rect.center();
         // let p2 = self.nodes[e.to_node_id as usize].rect.center();
-        let (p1, p2, orto_dir, entry_dir) = closest_points(&self.nodes[e.from_node_id as usize].rect, &self.nodes[e.to_node_id as usize].rect);
+        let (p1, p2, orto_dir, entry_dir) = closest_points(&self.node_rect(from), &self.node_rect(to));
+        let (points, orthogonal) = match self.rendering_options.edge_routing {
+            EdgeRouting::Direct => (vec![p1, p2], false),
+            EdgeRouting::Orthogonal => (orthogonal_path(p1, p2, orto_dir), true),
+        };
+        let arrow = match entry_dir {
+            Some(Direction::Left) => Some((Point::new(p2.x - 1, p2.y), SpecialChar::TriangleRight)),
+            Some(Direction::Right) => Some((Point::new(p2.x + 1, p2.y), SpecialChar::TriangleLeft)),
+            Some(Direction::Top) => Some((Point::new(p2.x, p2.y - 1), SpecialChar::TriangleDown)),
+            Some(Direction::Bottom) => Some((Point::new(p2.x, p2.y + 1), SpecialChar::TriangleUp)),
+            None => None,
+        };
+        EdgeRoute {
+            points,
+            orthogonal,
+            arrow,
+            simple: Some((orto_dir, entry_dir)),
+        }
+    }
+    fn draw_edge(&mut self, index: u32, attr: CharAttribute) {
+        if self.edge_aggregation.get(index as usize) == Some(&0) {
+            return;
+        }
+        let route = self.edge_route(index);
+        let e = &self.edges[index as usize];
         let line_type = e.line_type.unwrap_or(self.rendering_options.edge_line_type);
-        match self.rendering_options.edge_routing {
-            EdgeRouting::Direct => self.surface.draw_line(p1.x, p1.y, p2.x, p2.y, line_type, attr),
-            EdgeRouting::Orthogonal => self.surface.draw_orthogonal_line(p1.x, p1.y, p2.x, p2.y, line_type, orto_dir, attr),
-        }
-        if e.directed && self.rendering_options.show_arrow_heads {
-            match entry_dir {
-                Some(Direction::Left) => self.surface.write_char(p2.x - 1, p2.y, Character::with_char(SpecialChar::TriangleRight)),
-                Some(Direction::Right) => self.surface.write_char(p2.x + 1, p2.y, Character::with_char(SpecialChar::TriangleLeft)),
-                Some(Direction::Top) => self.surface.write_char(p2.x, p2.y - 1, Character::with_char(SpecialChar::TriangleDown)),
-                Some(Direction::Bottom) => self.surface.write_char(p2.x, p2.y + 1, Character::with_char(SpecialChar::TriangleUp)),
-                None => (),
+        let directed = e.directed;
+        match route.simple {
+            Some((orto_dir, _)) => {
+                let (p1, p2) = (route.points[0], route.points[route.points.len() - 1]);
+                match self.rendering_options.edge_routing {
+                    EdgeRouting::Direct => self.surface.draw_line(p1.x, p1.y, p2.x, p2.y, line_type, attr),
+                    EdgeRouting::Orthogonal => self.surface.draw_orthogonal_line(p1.x, p1.y, p2.x, p2.y, line_type, orto_dir, attr),
+                }
+            }
+            None if route.orthogonal => draw_orthogonal_path(&mut self.surface, &route.points, line_type, attr),
+            None => {
+                for w in route.points.windows(2) {
+                    self.surface.draw_line(w[0].x, w[0].y, w[1].x, w[1].y, line_type, attr);
+                }
             }
         }
+        if directed && self.rendering_options.show_arrow_heads {
+            if let Some((p, ch)) = route.arrow {
+                self.surface.write_char(p.x, p.y, Character::with_char(ch));
+            }
+        }
+    }
+    // labels are drawn after all the edges (so that other edges do not overwrite them)
+    fn draw_edge_label(&mut self, index: u32, attr: CharAttribute) {
+        // an edge that stands for several edges (towards a collapsed cluster) is labeled with the number of edges
+        let text = match self.edge_aggregation.get(index as usize).copied().unwrap_or(1) {
+            0 => return,
+            1 => {
+                let Some(label) = self.edges[index as usize].label.as_deref() else {
+                    return;
+                };
+                // only the first line of the label is drawn
+                let text = label.lines().next().unwrap_or_default();
+                if text.is_empty() {
+                    return;
+                }
+                text.to_string()
+            }
+            count => format!("×{count}"),
+        };
+        let route = self.edge_route(index);
+        let p = self.label_position(&route.points, text.chars().count() as i32);
+        self.surface.write_string(p.x, p.y, &text, attr, false);
     }
     fn draw_edges_from_node(&mut self, node_index: usize, attr: CharAttribute) {
         if node_index >= self.nodes.len() {
@@ -567,6 +1007,7 @@ where
         for i in 0..len {
             let index = self.nodes[node_index].edges_out[i];
             self.draw_edge(index, attr);
+            self.draw_edge_label(index, attr);
         }
     }
     fn draw_edges_to_node(&mut self, node_index: usize, attr: CharAttribute) {
@@ -577,6 +1018,7 @@ where
         for i in 0..len {
             let index = self.nodes[node_index].edges_in[i];
             self.draw_edge(index, attr);
+            self.draw_edge_label(index, attr);
         }
     }
     fn draw_edges_from_current(&mut self, attr: CharAttribute) {
@@ -595,16 +1037,26 @@ where
         let theme = control.theme();
         let text_attr = state.node_attr(theme);
         let edge_attr = state.edge_attr(theme);
+        self.update_cluster_rects();
+        self.edge_aggregation = self.compute_edge_aggregation();
+        self.edge_offsets = self.compute_edge_offsets(&self.edge_aggregation);
+        self.paint_cluster_frames(state, theme);
         // draw all edges
         let len = self.edges.len() as u32;
         if state == ControlState::Focused {
             for index in 0..len {
                 self.draw_edge(index, self.edges[index as usize].attribute.unwrap_or(edge_attr));
             }
+            for index in 0..len {
+                self.draw_edge_label(index, self.edges[index as usize].attribute.unwrap_or(edge_attr));
+            }
         } else {
             for index in 0..len {
                 self.draw_edge(index, edge_attr);
             }
+            for index in 0..len {
+                self.draw_edge_label(index, edge_attr);
+            }
         }
         // Edge highlight for incident edges: primary (`current_node`) only when multi-select UI is off;
         // union over every `Node::selected` when multi-select UI is on (`RenderingOptions::multiselect_ui`).
@@ -637,11 +1089,12 @@ where
                 }
             }
         }
+        self.paint_cluster_titles(state, theme);
         self.draw_pending_edge_preview(state, theme);
         let ms = self.rendering_options.multiselect_ui;
         // draw nodes
-        for node in &self.nodes {
-            self.repr_buffer.clear();
+        for index in 0..self.nodes.len() {
+            let node = &self.nodes[index];
             if state == ControlState::Focused {
                 let attr = if node.filtered {
                     ControlState::Disabled.node_attr(theme)
@@ -650,9 +1103,9 @@ where
                 } else {
                     node.text_attr.unwrap_or(text_attr)
                 };
-                node.paint(&mut self.surface, attr, &mut self.repr_buffer, ms);
+                self.paint_node_with_attr(index, attr);
             } else {
-                node.paint(&mut self.surface, text_attr, &mut self.repr_buffer, ms);
+                self.paint_node_with_attr(index, text_attr);
             };
         }
         // draw nodes related to current_node
@@ -660,18 +1113,23 @@ where
         let len = self.nodes.len();
         let hover_node_id = self.hovered_node.unwrap_or(usize::MAX);
         if (state != ControlState::Disabled) && (hover_node_id < len) {
-            let node = &self.nodes[hover_node_id];
-            let attr = state.hovered_node_attr(theme);
-            self.repr_buffer.clear();
-            node.paint(&mut self.surface, attr, &mut self.repr_buffer, ms);
+            self.paint_node_with_attr(hover_node_id, state.hovered_node_attr(theme));
         }
         if (state == ControlState::Focused) && (self.current_node < len) {
-            let node = &self.nodes[self.current_node];
-            let attr = state.current_node_attr(theme);
-            self.repr_buffer.clear();
-            node.paint(&mut self.surface, attr, &mut self.repr_buffer, ms);
+            self.paint_node_with_attr(self.current_node, state.current_node_attr(theme));
         }
     }
+    // a node of a collapsed cluster is drawn as the summary node of the cluster (only once, for the first node)
+    fn paint_node_with_attr(&mut self, index: usize, attr: CharAttribute) {
+        if let Some(c) = self.collapsed_cluster_of(index) {
+            if self.is_node_visible(index) {
+                self.paint_cluster_summary(c, attr);
+            }
+            return;
+        }
+        self.repr_buffer.clear();
+        self.nodes[index].paint(&mut self.surface, attr, &mut self.repr_buffer, self.rendering_options.multiselect_ui);
+    }
     pub(super) fn paint_node(&mut self, control: &ControlBase, index: usize) {
         let len = self.nodes.len();
         if index >= len {
@@ -707,13 +1165,7 @@ where
                 }
             }
         };
-        self.repr_buffer.clear();
-        node.paint(
-            &mut self.surface,
-            attr,
-            &mut self.repr_buffer,
-            self.rendering_options.multiselect_ui,
-        );
+        self.paint_node_with_attr(index, attr);
     }
     pub(super) fn reset_hover(&mut self, control: &ControlBase) {
         let index = self.hovered_node.unwrap_or(usize::MAX);
@@ -739,11 +1191,11 @@ where
         &self.surface
     }
 
-    /// Top-left at press for every `selected` node (multi-drag).
+    /// Top-left at press for every `selected` node (multi-drag). A selected collapsed cluster moves all of its nodes.
     pub(super) fn selected_drag_anchors(&self) -> Vec<(usize, Point)> {
         (0..self.nodes.len())
-            .filter(|&i| self.nodes[i].selected)
-            .map(|i| (i, self.nodes[i].rect.top_left()))
+            .filter(|&i| self.nodes[i].selected && self.is_node_visible(i))
+            .flat_map(|i| self.drag_anchors(i))
             .collect()
     }
 
@@ -850,7 +1302,8 @@ where
     fn move_selected_nodes_with(&mut self, dx: i32, dy: i32, control: &ControlBase) -> bool {
         let mut changed = false;
         for i in 0..self.nodes.len() {
-            if !self.nodes[i].selected {
+            // the nodes of a collapsed cluster move together with the summary node
+            if !self.nodes[self.representative(i)].selected {
                 continue;
             }
             let tl = self.nodes[i].rect.top_left();
@@ -868,7 +1321,7 @@ where
         }
         let mut resized = false;
         for i in 0..self.nodes.len() {
-            if !self.nodes[i].selected {
+            if !self.nodes[self.representative(i)].selected {
                 continue;
             }
             let node = &self.nodes[i];
@@ -925,6 +1378,12 @@ where
         if id >= self.nodes.len() {
             return;
         }
+        if self.collapsed_cluster_of(id).is_some() {
+            // a collapsed cluster is moved as a whole
+            let anchors = self.drag_anchors(id);
+            self.move_nodes_with_press_delta(&anchors, Point::ORIGIN, Point::new(dx, dy), control);
+            return;
+        }
         let tl = self.nodes[id].rect.top_left();
         self.move_node_to(id, tl.x + dx, tl.y + dy, control);
     }
@@ -947,39 +1406,40 @@ where
         if self.nodes.is_empty() {
             return None;
         }
-        let r = self.nodes[self.current_node].rect;
+        let r = self.node_rect(self.current_node);
         let c = dir.compare_point(&r);
         let mut best = None;
         let mut best_dist = u64::MAX;
-        for (index, n) in self.nodes.iter().enumerate() {
-            if index == self.current_node {
+        for index in 0..self.nodes.len() {
+            if index == self.current_node || !self.is_node_visible(index) {
                 continue;
             }
+            let nr = self.node_rect(index);
             let dp = match dir {
                 Direction::Left => {
-                    if r.right() > n.rect.right() {
-                        Some(Direction::Right.compare_point(&n.rect))
+                    if r.right() > nr.right() {
+                        Some(Direction::Right.compare_point(&nr))
                     } else {
                         None
                     }
                 }
                 Direction::Right => {
-                    if r.left() < n.rect.left() {
-                        Some(Direction::Left.compare_point(&n.rect))
+                    if r.left() < nr.left() {
+                        Some(Direction::Left.compare_point(&nr))
                     } else {
                         None
                     }
                 }
                 Direction::Top => {
-                    if r.bottom() > n.rect.bottom() {
-                        Some(Direction::Bottom.compare_point(&n.rect))
+                    if r.bottom() > nr.bottom() {
+                        Some(Direction::Bottom.compare_point(&nr))
                     } else {
                         None
                     }
                 }
                 Direction::Bottom => {
-                    if r.top() < n.rect.top() {
-                        Some(Direction::Top.compare_point(&n.rect))
+                    if r.top() < nr.top() {
+                        Some(Direction::Top.compare_point(&nr))
                     } else {
                         None
                     }
@@ -1010,13 +1470,12 @@ where
         if !self.rendering_options.multiselect_ui || self.nodes.is_empty() {
             return None;
         }
-        let fv = (0..self.nodes.len()).find(|&i| !self.nodes[i].filtered)?;
-        let all_visible_selected = (0..self.nodes.len())
-            .filter(|&i| !self.nodes[i].filtered)
-            .all(|i| self.nodes[i].selected);
-        for n in &mut self.nodes {
-            if !n.filtered {
-                n.selected = !all_visible_selected;
+        let visible = |i: usize| !self.nodes[i].filtered && self.is_node_visible(i);
+        let fv = (0..self.nodes.len()).find(|&i| visible(i))?;
+        let all_visible_selected = (0..self.nodes.len()).filter(|&i| visible(i)).all(|i| self.nodes[i].selected);
+        for i in 0..self.nodes.len() {
+            if !self.nodes[i].filtered && self.is_node_visible(i) {
+                self.nodes[i].selected = !all_visible_selected;
             }
         }
         Some(fv)
@@ -1033,13 +1492,15 @@ where
             key!("Ctrl+Up") => self.ctrl_arrow_move_to(0, -1, control),
             key!("Ctrl+Down") => self.ctrl_arrow_move_to(0, 1, control),
             key!("Ctrl+Tab") => {
-                if !self.nodes.is_empty() {
-                    self.set_current_node((self.current_node + 1) % self.nodes.len(), control);
+                let len = self.nodes.len();
+                if let Some(idx) = (1..=len).map(|i| (self.current_node + i) % len).find(|&i| self.is_node_visible(i)) {
+                    self.set_current_node(idx, control);
                 }
             }
             key!("Ctrl+Shift+Tab") => {
-                if !self.nodes.is_empty() {
-                    self.set_current_node((self.current_node + self.nodes.len() - 1) % self.nodes.len(), control);
+                let len = self.nodes.len();
+                if let Some(idx) = (1..=len).map(|i| (self.current_node + len - i) % len).find(|&i| self.is_node_visible(i)) {
+                    self.set_current_node(idx, control);
                 }
             }
             key!("Space") => {
@@ -1085,12 +1546,16 @@ where
         Self {
             nodes: Default::default(),
             edges: Default::default(),
+            clusters: Vec::new(),
+            node_cluster: Vec::new(),
             surface_size: Default::default(),
             surface: Surface::new(1, 1),
             current_node: 0,
             hovered_node: None,
             pending_edge_preview: None,
             repr_buffer: String::new(),
+            edge_offsets: Vec::new(),
+            edge_aggregation: Vec::new(),
             rendering_options: RenderingOptions::new(),
         }
     }
@@ -1159,6 +1624,15 @@ where
                 e.to_node_id = e.to_node_id.saturating_sub(1);
             }
         }
+        // the same for the nodes of the clusters
+        for c in &mut self.graph.clusters {
+            c.nodes.retain(|n| *n != idx);
+            for n in &mut c.nodes {
+                if *n > idx {
+                    *n -= 1;
+                }
+            }
+        }
         self.graph.nodes.remove(index);
         if self.current_node >= index {
             self.current_node = self.current_node.saturating_sub(1);
@@ -1222,6 +1696,45 @@ where
         self.changed_graph = true;
     }
 
+    /// Number of clusters in the graph.
+    #[inline(always)]
+    pub fn clusters_count(&self) -> usize {
+        self.graph.clusters.len()
+    }
+
+    /// Returns the cluster at `index`, or `None` if out of range.
+    #[inline(always)]
+    pub fn cluster(&self, index: usize) -> Option<&Cluster> {
+        self.graph.clusters.get(index)
+    }
+
+    /// Appends a cluster and returns its index. Nodes that already belong to another cluster are removed from
+    /// the new cluster. Marks the graph structure as changed.
+    pub fn add_cluster(&mut self, cluster: Cluster) -> usize {
+        self.graph.clusters.push(cluster);
+        self.changed_graph = true;
+        self.graph.clusters.len() - 1
+    }
+
+    /// Removes the cluster at `index` (the nodes of the cluster are not removed). No-op if out of range.
+    pub fn delete_cluster(&mut self, index: usize) {
+        if index >= self.graph.clusters.len() {
+            return;
+        }
+        self.graph.clusters.remove(index);
+        self.changed_graph = true;
+    }
+
+    /// Collapses the cluster at `index` into a single summary node (or expands it). No-op if out of range.
+    pub fn set_cluster_collapsed(&mut self, index: usize, collapsed: bool) {
+        if let Some(c) = self.graph.clusters.get_mut(index) {
+            if c.collapsed != collapsed {
+                c.collapsed = collapsed;
+                self.changed_graph = true;
+            }
+        }
+    }
+
     /// Selects the node at `index` for focus/highlighting if in range and different from the current selection.
     #[inline(always)]
     pub fn set_current_node(&mut self, index: usize) {
@@ -5,12 +5,16 @@ use super::events::*;
 use super::graph::EditableGraph;
 use super::graph::Graph;
 use super::initialization_flags::*;
+use super::overview::minimap_scale;
 use super::RenderingOptions;
 use crate::{prelude::*, ui::graphview::GraphNode};
 
 /// Squared distance (graph coordinates) before a Ctrl+press is treated as a drag instead of a click toggle.
 const MULTISELECT_CTRL_DRAG_THRESHOLD_SQ: i32 = 9;
 
+/// Largest zoom factor (the number of graph cells, on each axis, drawn in one cell of the overview).
+const MAX_ZOOM: u32 = 16;
+
 enum Drag {
     None,
     /// Right button: drag out a tentative edge from `from_node`.
@@ -28,6 +32,8 @@ enum Drag {
         node_id: usize,
         origin: Point,
     },
+    /// Left button pressed over the minimap: the view follows the mouse.
+    Minimap,
 }
 
 #[CustomControl(overwrite=OnPaint+OnKeyPressed+OnMouseEvent+OnResize+OnFocus, internal=true)]
@@ -43,6 +49,8 @@ where
     comp: ListScrollBars,
     arrange_method: ArrangeMethod,
     rendering_options: RenderingOptions,
+    zoom: u32,
+    minimap: bool,
 }
 impl<T> GraphView<T>
 where
@@ -56,6 +64,7 @@ where
     ///   - `Flags::ScrollBars`: Enables scroll bars for navigating large graphs
     ///   - `Flags::SearchBar`: Enables a search bar for finding nodes
     ///   - `Flags::MultiSelect`: Enables multi-selection UI (checkbox column and related behavior)
+    ///   - `Flags::Minimap`: Shows a minimap of the entire graph in the bottom-right corner of the control
     ///
     /// # Example
     /// ```rust, no_run
@@ -86,6 +95,8 @@ where
             graph: Graph::default(),
             arrange_method: ArrangeMethod::GridPacked,
             rendering_options: RenderingOptions::new(),
+            zoom: 1,
+            minimap: flags.contains(Flags::Minimap),
             comp: ListScrollBars::new(flags.contains(Flags::ScrollBars), flags.contains(Flags::SearchBar)),
         };
         this.sync_rendering_options_to_graph();
@@ -294,7 +305,9 @@ where
     ///
     /// This method automatically positions all nodes in the graph according to
     /// the chosen arrangement algorithm. The graph will be resized and repainted
-    /// after the arrangement is complete.
+    /// after the arrangement is complete. If the graph has clusters, the nodes of every
+    /// cluster are arranged first (with the same algorithm) and the clusters are then
+    /// arranged as single blocks, so that the nodes of a cluster stay together.
     ///
     /// # Parameters
     /// - `method`: The arrangement algorithm to use:
@@ -324,14 +337,20 @@ where
     /// graph_view.arrange_nodes(graphview::ArrangeMethod::ForceDirected);
     /// ```
     pub fn arrange_nodes(&mut self, method: ArrangeMethod) {
-        match method {
+        let layout = |graph: &mut Graph<T>| match method {
             ArrangeMethod::None => { /* do nothing */ }
-            ArrangeMethod::Grid => super::node_layout::grid::rearange(&mut self.graph, 2),
-            ArrangeMethod::GridPacked => super::node_layout::grid::rearange(&mut self.graph, 1),
-            ArrangeMethod::Circular => super::node_layout::circular::rearange(&mut self.graph),
-            ArrangeMethod::Hierarchical => super::node_layout::hierarchical::rearange(&mut self.graph, 2),
-            ArrangeMethod::HierarchicalPacked => super::node_layout::hierarchical::rearange(&mut self.graph, 1),
-            ArrangeMethod::ForceDirected => super::node_layout::force_directed::rearange(&mut self.graph),
+            ArrangeMethod::Grid => super::node_layout::grid::rearange(graph, 2),
+            ArrangeMethod::GridPacked => super::node_layout::grid::rearange(graph, 1),
+            ArrangeMethod::Circular => super::node_layout::circular::rearange(graph),
+            ArrangeMethod::Hierarchical => super::node_layout::hierarchical::rearange(graph, 2),
+            ArrangeMethod::HierarchicalPacked => super::node_layout::hierarchical::rearange(graph, 1),
+            ArrangeMethod::ForceDirected => super::node_layout::force_directed::rearange(graph),
+        };
+        // the nodes of a cluster are arranged together (as a block)
+        if method == ArrangeMethod::None || self.graph.clusters.is_empty() {
+            layout(&mut self.graph);
+        } else {
+            super::node_layout::clusters::rearange(&mut self.graph, layout);
         }
         self.arrange_method = method;
         self.graph.resize_graph(true);
@@ -408,6 +427,7 @@ where
         let current_node = editor.current_node;
         if changed_graph {
             self.graph.update_edges_in_out();
+            self.graph.update_clusters();
         }
         if changed_current_node {
             self.graph.set_current_node(current_node, &self.base);
@@ -421,14 +441,261 @@ where
         }
     }
 
+    /// Collapses a cluster into a single summary node (or expands it back).
+    ///
+    /// The nodes of a collapsed cluster are not drawn, and all the edges towards them are drawn towards the
+    /// summary node (edges between the same two nodes are drawn only once and are labeled with their count).
+    /// A cluster can also be collapsed by clicking its title, expanded by double-clicking its summary node, or
+    /// toggled with `Ctrl+Space` (for the cluster of the current node).
+    ///
+    /// # Parameters
+    /// - `index`: The index of the cluster (see [`Graph::cluster`](super::Graph::cluster))
+    /// - `collapsed`: `true` to collapse the cluster, `false` to expand it
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// type MyNode = &'static str; // or any other type that implements the GraphNode trait
+    ///
+    /// let mut graph_view: GraphView<MyNode> = GraphView::new(
+    ///     layout!("x:1,y:1,w:50,h:30"),
+    ///     graphview::Flags::ScrollBars
+    /// );
+    /// graph_view.set_cluster_collapsed(0, true);
+    /// ```
+    pub fn set_cluster_collapsed(&mut self, index: usize, collapsed: bool) {
+        let nid = self.graph.current_node_id();
+        if self.graph.set_cluster_collapsed(index, collapsed, &self.base) {
+            self.update_scroll_bars();
+            self.ensure_current_node_is_visible();
+            self.raise_current_node_changed(nid);
+        }
+    }
+
     /// Returns the number of selected nodes in the graph.
     pub fn selected_count(&self) -> usize {
         self.graph.nodes.iter().filter(|n| n.selected).count()
     }
 
+    /// Returns the current zoom factor.
+    ///
+    /// A zoom factor of `1` draws the graph at its normal (1:1) scale. A larger value `n` switches to an
+    /// overview mode where every cell of the control covers `n` x `n` cells of the graph: edges are drawn with
+    /// braille characters and nodes collapse to short labels (or to dots if there is not enough space).
+    pub fn zoom(&self) -> u32 {
+        self.zoom
+    }
+
+    /// Sets the zoom factor (between 1 and 16). The center of the view is preserved.
+    ///
+    /// # Parameters
+    /// - `level`: The zoom factor (`1` for the normal scale, larger values for an overview of the graph)
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// type MyNode = &'static str; // or any other type that implements the GraphNode trait
+    ///
+    /// let mut graph_view: GraphView<MyNode> = GraphView::new(
+    ///     layout!("x:1,y:1,w:50,h:30"),
+    ///     graphview::Flags::ScrollBars
+    /// );
+    /// // every cell of the control covers 4x4 cells of the graph
+    /// graph_view.set_zoom(4);
+    /// ```
+    pub fn set_zoom(&mut self, level: u32) {
+        let level = level.clamp(1, MAX_ZOOM);
+        let sz = self.size();
+        let z = self.zoom as i32;
+        let center = Point::new(
+            (sz.width as i32 / 2 - self.origin_point.x) * z,
+            (sz.height as i32 / 2 - self.origin_point.y) * z,
+        );
+        self.zoom = level;
+        self.update_scroll_bars();
+        self.center_view_on(center);
+    }
+
+    /// Zooms in (halves the zoom factor) - the normal scale is reached when the zoom factor is `1`.
+    pub fn zoom_in(&mut self) {
+        self.set_zoom(self.zoom / 2);
+    }
+
+    /// Zooms out (doubles the zoom factor, up to 16).
+    pub fn zoom_out(&mut self) {
+        self.set_zoom(self.zoom * 2);
+    }
+
+    /// Chooses the smallest zoom factor that makes the entire graph visible within the control.
+    pub fn fit_to_view(&mut self) {
+        let sz = self.size();
+        let gs = self.graph.size();
+        let level = gs.width.div_ceil(sz.width.max(1)).max(gs.height.div_ceil(sz.height.max(1)));
+        self.set_zoom(level);
+        self.move_scroll_to(0, 0);
+    }
+
+    /// Chooses the smallest zoom factor that makes all selected nodes visible (or the current node if
+    /// there is no selection) and centers the view on them.
+    pub fn zoom_to_selection(&mut self) {
+        let ms = self.flags.contains(Flags::MultiSelect);
+        let mut bounds: Option<Rect> = None;
+        for (index, n) in self.graph.nodes.iter().enumerate() {
+            let include = if ms && self.selected_count() > 0 {
+                n.selected
+            } else {
+                index == self.graph.current_node
+            };
+            if include {
+                bounds = Some(match bounds {
+                    Some(b) => Rect::new(
+                        b.left().min(n.rect.left()),
+                        b.top().min(n.rect.top()),
+                        b.right().max(n.rect.right()),
+                        b.bottom().max(n.rect.bottom()),
+                    ),
+                    None => n.rect,
+                });
+            }
+        }
+        let Some(b) = bounds else {
+            return;
+        };
+        let sz = self.size();
+        let level = b.width().div_ceil(sz.width.max(1)).max(b.height().div_ceil(sz.height.max(1)));
+        self.set_zoom(level);
+        self.center_view_on(b.center());
+    }
+
+    /// Shows or hides the minimap - a small view of the entire graph (drawn in the bottom-right corner of
+    /// the control) that marks the visible part of the graph. Clicking or dragging the mouse over the minimap
+    /// moves the view. The minimap is not drawn if the control is too small.
+    pub fn show_minimap(&mut self, visible: bool) {
+        self.minimap = visible;
+    }
+
+    /// Returns `true` if the minimap is enabled (see [`GraphView::show_minimap`]).
+    pub fn is_minimap_visible(&self) -> bool {
+        self.minimap
+    }
+
+    // the size of what is drawn (the graph at the current zoom factor)
+    fn content_size(&self) -> Size {
+        if self.zoom > 1 {
+            self.graph.zoomed_size(self.zoom)
+        } else {
+            self.graph.size()
+        }
+    }
+    // scrolls so that the point `p` (in graph coordinates) is in the center of the view
+    fn center_view_on(&mut self, p: Point) {
+        let sz = self.size();
+        let z = self.zoom as i32;
+        self.move_scroll_to(sz.width as i32 / 2 - p.x / z, sz.height as i32 / 2 - p.y / z);
+    }
+    // the visible part of the graph (in graph coordinates)
+    fn visible_graph_rect(&self) -> Rect {
+        let sz = self.size();
+        let z = self.zoom as i32;
+        Rect::with_point_and_size(
+            Point::new(-self.origin_point.x * z, -self.origin_point.y * z),
+            Size::new(sz.width * self.zoom, sz.height * self.zoom),
+        )
+    }
+    fn minimap_rect(&self) -> Option<Rect> {
+        if !self.minimap || self.graph.nodes.is_empty() {
+            return None;
+        }
+        let mut sz = self.size();
+        if self.has_focus() && self.flags.contains_one(Flags::ScrollBars | Flags::SearchBar) {
+            sz = Size::new(sz.width.saturating_sub(1), sz.height.saturating_sub(1));
+        }
+        let w = (sz.width / 3).clamp(12, 40);
+        let h = (sz.height / 3).clamp(5, 14);
+        if sz.width < w + 8 || sz.height < h + 3 {
+            return None;
+        }
+        Some(Rect::with_point_and_size(
+            Point::new((sz.width - w) as i32, (sz.height - h) as i32),
+            Size::new(w, h),
+        ))
+    }
+    fn process_minimap_mouse_event(&mut self, event: &MouseEvent) -> bool {
+        let Some(rect) = self.minimap_rect() else {
+            return false;
+        };
+        let (x, y) = match event {
+            MouseEvent::Pressed(d) if rect.contains(Point::new(d.x, d.y)) => (d.x, d.y),
+            MouseEvent::Drag(d) if matches!(self.drag, Drag::Minimap) => (d.x, d.y),
+            MouseEvent::Released(_) if matches!(self.drag, Drag::Minimap) => {
+                self.drag = Drag::None;
+                return true;
+            }
+            MouseEvent::DoubleClick(d) => return rect.contains(Point::new(d.x, d.y)),
+            _ => return false,
+        };
+        self.drag = Drag::Minimap;
+        let inner = Size::new(rect.width().saturating_sub(2), rect.height().saturating_sub(2));
+        let scale = minimap_scale(self.graph.size(), inner);
+        let gx = (((x - rect.left() - 1).max(0) as f32 + 0.5) * scale) as i32;
+        let gy = (((y - rect.top() - 1).max(0) as f32 + 0.5) * scale) as i32;
+        self.center_view_on(Point::new(gx, gy));
+        true
+    }
+    // mouse events when the graph is zoomed out (nodes can be selected but not moved)
+    fn process_overview_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        match event {
+            MouseEvent::Enter | MouseEvent::Leave | MouseEvent::Over(_) => {
+                self.hide_tooltip();
+                EventProcessStatus::Ignored
+            }
+            MouseEvent::Pressed(d) => {
+                let p = Point::new(d.x - self.origin_point.x, d.y - self.origin_point.y);
+                if d.button == MouseButton::Left {
+                    if let Some(id) = self.graph.overview_node_at(p, self.zoom) {
+                        let nid = self.graph.current_node_id();
+                        self.graph.set_current_node(id, &self.base);
+                        self.raise_current_node_changed(nid);
+                        self.drag = Drag::None;
+                        return EventProcessStatus::Processed;
+                    }
+                }
+                self.drag = Drag::View(Point::new(d.x, d.y));
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Drag(d) => {
+                if let Drag::View(p) = self.drag {
+                    self.move_scroll_to(self.origin_point.x + d.x - p.x, self.origin_point.y + d.y - p.y);
+                    self.drag = Drag::View(Point::new(d.x, d.y));
+                    EventProcessStatus::Processed
+                } else {
+                    EventProcessStatus::Ignored
+                }
+            }
+            MouseEvent::Released(_) => match mem::replace(&mut self.drag, Drag::None) {
+                Drag::None => EventProcessStatus::Ignored,
+                _ => EventProcessStatus::Processed,
+            },
+            MouseEvent::DoubleClick(d) => {
+                // double-clicking a node returns to the normal scale (centered on that node)
+                let p = Point::new(d.x - self.origin_point.x, d.y - self.origin_point.y);
+                if let Some(id) = self.graph.overview_node_at(p, self.zoom) {
+                    self.set_zoom(1);
+                    self.center_view_on(self.graph.node_rect(id).center());
+                    EventProcessStatus::Processed
+                } else {
+                    EventProcessStatus::Ignored
+                }
+            }
+            MouseEvent::Wheel(_) => EventProcessStatus::Ignored,
+        }
+    }
+
     fn move_scroll_to(&mut self, x: i32, y: i32) {
         let sz = self.size();
-        let surface_size = self.graph.size();
+        let surface_size = self.content_size();
         self.origin_point.x = if surface_size.width <= sz.width {
             0
         } else {
@@ -449,14 +716,16 @@ where
         self.move_scroll_to(h, v);
     }
     fn update_scroll_bars(&mut self) {
-        let paint_sz = self.graph.size();
+        let paint_sz = self.content_size();
         let sz = self.size();
         self.comp.resize(paint_sz.width as u64, paint_sz.height as u64, &self.base, sz);
         self.move_scroll_to(self.origin_point.x, self.origin_point.y);
     }
     fn ensure_node_is_visible(&mut self, node_id: usize) {
-        if let Some(node) = self.graph.nodes.get(node_id) {
-            let node_rect = node.rect;
+        if node_id < self.graph.nodes.len() {
+            let z = self.zoom as i32;
+            let r = self.graph.node_rect(node_id);
+            let node_rect = Rect::new(r.left() / z, r.top() / z, r.right() / z, r.bottom() / z);
             let sz = self.size();
             let view_rect = Rect::with_point_and_size(Point::new(-self.origin_point.x, -self.origin_point.y), sz);
 
@@ -585,7 +854,14 @@ where
         if let Some(back) = self.background {
             surface.clear(back);
         }
-        surface.draw_surface(self.origin_point.x, self.origin_point.y, self.graph.surface());
+        if self.zoom > 1 {
+            self.graph.paint_overview(surface, &self.base, self.zoom, self.origin_point, self.size());
+        } else {
+            surface.draw_surface(self.origin_point.x, self.origin_point.y, self.graph.surface());
+        }
+        if let Some(r) = self.minimap_rect() {
+            self.graph.paint_minimap(surface, &self.base, r, self.visible_graph_rect());
+        }
         // let sz = format!("Size {:?}", self.size());
         // surface.write_string(0, 0, &sz, charattr!("w,black"), false);
     }
@@ -647,6 +923,37 @@ where
                 self.move_scroll_to(i32::MIN, i32::MIN);
                 EventProcessStatus::Processed
             }
+            key!("Ctrl+PageUp") => {
+                self.zoom_in();
+                EventProcessStatus::Processed
+            }
+            key!("Ctrl+PageDown") => {
+                self.zoom_out();
+                EventProcessStatus::Processed
+            }
+            key!("Ctrl+0") => {
+                self.set_zoom(1);
+                EventProcessStatus::Processed
+            }
+            key!("Ctrl+Home") => {
+                self.fit_to_view();
+                EventProcessStatus::Processed
+            }
+            key!("Ctrl+End") => {
+                self.zoom_to_selection();
+                EventProcessStatus::Processed
+            }
+            key!("Ctrl+Space") => {
+                // collapses (or expands) the cluster of the current node
+                match self.graph.current_node_id().and_then(|id| self.graph.cluster_of(id)) {
+                    Some(c) => {
+                        let collapsed = self.graph.clusters[c].collapsed;
+                        self.set_cluster_collapsed(c, !collapsed);
+                        EventProcessStatus::Processed
+                    }
+                    None => EventProcessStatus::Ignored,
+                }
+            }
             key!("Enter") => {
                 if self.comp.is_in_edit_mode() {
                     self.goto_next_match();
@@ -710,6 +1017,12 @@ where
             self.update_scroll_pos_from_scrollbars();
             return EventProcessStatus::Processed;
         }
+        if self.process_minimap_mouse_event(event) {
+            return EventProcessStatus::Processed;
+        }
+        if self.zoom > 1 && !matches!(event, MouseEvent::Wheel(_)) {
+            return self.process_overview_mouse_event(event);
+        }
         match event {
             MouseEvent::Enter => {
                 self.graph.reset_hover(&self.base);
@@ -734,7 +1047,7 @@ where
                     return EventProcessStatus::Ignored;
                 }
                 if let Some(id) = self.graph.hovered_node_id() {
-                    let r = self.graph.nodes[id].rect + (self.origin_point.x, self.origin_point.y);
+                    let r = self.graph.node_rect(id) + (self.origin_point.x, self.origin_point.y);
                     if let Some(desc) = self.graph.node_description(id) {
                         self.base.show_tooltip_on_rect(desc, &r);
                     } else {
@@ -757,6 +1070,12 @@ where
                     }
                     return EventProcessStatus::Processed;
                 }
+                // clicking the title of a cluster collapses it
+                if let Some(c) = self.graph.cluster_title_at(data.x, data.y) {
+                    self.drag = Drag::None;
+                    self.set_cluster_collapsed(c, true);
+                    return EventProcessStatus::Processed;
+                }
                 if let Some(id) = self.graph.mouse_pos_to_index(data.x, data.y) {
                     let nid = self.graph.current_node_id();
                     let ms = self.flags.contains(Flags::MultiSelect);
@@ -772,7 +1091,7 @@ where
                         let anchors = if ms {
                             self.graph.selected_drag_anchors()
                         } else {
-                            vec![(id, self.graph.nodes[id].rect.top_left())]
+                            self.graph.drag_anchors(id)
                         };
                         self.drag = Drag::NodeMoving { origin: data, anchors };
                         self.raise_current_node_changed(nid);
@@ -799,7 +1118,7 @@ where
             MouseEvent::Released(mouse_data) => {
                 let data = Point::new(mouse_data.x - self.origin_point.x, mouse_data.y - self.origin_point.y);
                 match mem::replace(&mut self.drag, Drag::None) {
-                    Drag::None => EventProcessStatus::Ignored,
+                    Drag::None | Drag::Minimap => EventProcessStatus::Ignored,
                     Drag::EdgeConnect { from_node } => {
                         self.graph.set_edge_preview(None, &self.base);
                         self.graph.update_hover_at(data);
@@ -846,7 +1165,7 @@ where
                             let anchors = if self.graph.nodes[node_id].selected {
                                 self.graph.selected_drag_anchors()
                             } else {
-                                vec![(node_id, self.graph.nodes[node_id].rect.top_left())]
+                                self.graph.drag_anchors(node_id)
                             };
                             let resized = self.graph.move_nodes_with_press_delta(&anchors, origin, data, &self.base);
                             if resized {
@@ -866,7 +1185,13 @@ where
                 }
                 let data = Point::new(mouse_data.x - self.origin_point.x, mouse_data.y - self.origin_point.y);
                 if let Some(id) = self.graph.mouse_pos_to_index(data.x, data.y) {
-                    self.raise_action_on_node(id);
+                    // double-clicking the summary node of a collapsed cluster expands it
+                    if let Some(c) = self.graph.collapsed_cluster_of(id) {
+                        self.drag = Drag::None;
+                        self.set_cluster_collapsed(c, false);
+                    } else {
+                        self.raise_action_on_node(id);
+                    }
                     EventProcessStatus::Processed
                 } else {
                     EventProcessStatus::Ignored
@@ -881,14 +1206,14 @@ where
                         let anchors = if self.graph.nodes[*node_id].selected {
                             self.graph.selected_drag_anchors()
                         } else {
-                            vec![(*node_id, self.graph.nodes[*node_id].rect.top_left())]
+                            self.graph.drag_anchors(*node_id)
                         };
                         let o = *origin;
                         self.drag = Drag::NodeMoving { origin: o, anchors };
                     }
                 }
                 match &self.drag {
-                    Drag::None => EventProcessStatus::Ignored,
+                    Drag::None | Drag::Minimap => EventProcessStatus::Ignored,
                     Drag::EdgeConnect { from_node } => {
                         self.graph.update_hover_at(data);
                         self.graph.set_edge_preview(Some((*from_node, data)), &self.base);
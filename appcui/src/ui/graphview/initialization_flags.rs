@@ -6,6 +6,7 @@ pub enum Flags {
     ScrollBars = 1,
     SearchBar = 0x02,
     MultiSelect = 0x04,
+    Minimap = 0x08,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, EnumSelector)]
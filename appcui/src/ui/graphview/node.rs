@@ -1,4 +1,6 @@
 use super::GraphNode;
+use super::Port;
+use super::PortSide;
 use crate::graphics::*;
 
 // inactive - culoara data de mine
@@ -18,6 +20,7 @@ pub struct Node<T: GraphNode> {
     pub(super) filtered: bool,
     /// Multi-selection membership when multi-select mode is enabled on the owning `GraphView`.
     pub(super) selected: bool,
+    pub(super) ports: Vec<(String, Port)>,
 }
 impl<T> Node<T>
 where
@@ -34,6 +37,16 @@ where
         self.selected
     }
 
+    /// Returns the port with the specified name. Compass point names (`n`, `e`, `s`, `w`, `north`, ...) that are not
+    /// used as port names refer to the middle of the corresponding side.
+    pub fn port(&self, name: &str) -> Option<Port> {
+        self.ports
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, p)| *p)
+            .or_else(|| PortSide::from_name(name).map(Port::new))
+    }
+
     /// Label size derived from the current bounds (inverse of [`Node::resize`](Self::resize) padding rules).
     ///
     /// `multiselect_ui` must match how this node’s `rect` was last laid out (same flag passed to [`Node::resize`](Self::resize)).
@@ -163,6 +176,7 @@ where
                 edges_out: Vec::new(),
                 filtered: false,
                 selected: false,
+                ports: Vec::new(),
             },
             size: None,
         }
@@ -199,6 +213,17 @@ where
         self
     }
 
+    /// Adds a named port (a point on the border of the node where edges can be attached).
+    /// If a port with the same name already exists, it is replaced.
+    pub fn port(mut self, name: &str, port: Port) -> Self {
+        if let Some(p) = self.node.ports.iter_mut().find(|(n, _)| n == name) {
+            p.1 = port;
+        } else {
+            self.node.ports.push((name.to_string(), port));
+        }
+        self
+    }
+
     /// Builds the actual node. If the size was not set, the prefered size as it is returned by the GraphNode implementation will be used
     #[inline(always)]
     pub fn build(mut self) -> Node<T> {
@@ -344,4 +369,31 @@ where
     pub fn is_selected(&self) -> bool {
         self.node.selected
     }
+    /// Returns the port with the specified name (see [`Node::port`]).
+    #[inline(always)]
+    pub fn port(&self, name: &str) -> Option<Port> {
+        self.node.port(name)
+    }
+    /// Adds (or replaces) a named port. Marks the graph as changed if the port differs.
+    pub fn set_port(&mut self, name: &str, port: Port) {
+        match self.node.ports.iter_mut().find(|(n, _)| n == name) {
+            Some(p) if p.1 == port => {}
+            Some(p) => {
+                p.1 = port;
+                *self.changed = true;
+            }
+            None => {
+                self.node.ports.push((name.to_string(), port));
+                *self.changed = true;
+            }
+        }
+    }
+    /// Removes a named port. Marks the graph as changed if the port existed.
+    pub fn remove_port(&mut self, name: &str) {
+        let len = self.node.ports.len();
+        self.node.ports.retain(|(n, _)| n != name);
+        if self.node.ports.len() != len {
+            *self.changed = true;
+        }
+    }
 }
@@ -1,4 +1,5 @@
 pub(super) mod hierarchical;
 pub(super) mod grid;
 pub(super) mod circular;
-pub(super) mod force_directed;
\ No newline at end of file
+pub(super) mod force_directed;
+pub(super) mod clusters;
\ No newline at end of file
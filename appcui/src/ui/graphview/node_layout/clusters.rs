@@ -0,0 +1,94 @@
+use super::super::EdgeBuilder;
+use super::super::Graph;
+use super::super::GraphNode;
+use super::super::Node;
+use crate::graphics::*;
+use std::collections::HashSet;
+
+// the space between the frame of a cluster and its nodes (see `Graph::update_cluster_rects`)
+const FRAME_LEFT: i32 = 2;
+const FRAME_TOP: i32 = 1;
+
+// Arranges a graph with clusters in two steps:
+// 1. the nodes of every cluster are arranged (with `layout`) as a separate graph
+// 2. every cluster is replaced by a block (a node as large as the frame of the cluster) and the resulting graph
+//    (blocks and the nodes that are not part of a cluster) is arranged with the same `layout`
+// The nodes of a cluster are then moved within the block of the cluster.
+pub(in super::super) fn rearange<T: GraphNode, F: Fn(&mut Graph<T>)>(graph: &mut Graph<T>, layout: F) {
+    if graph.nodes.is_empty() {
+        return;
+    }
+    let mut slots: Vec<Option<Node<T>>> = std::mem::take(&mut graph.nodes).into_iter().map(Some).collect();
+    // the block where each node is placed (a cluster or the node itself)
+    let mut item_of = vec![usize::MAX; slots.len()];
+    let mut items: Vec<usize> = Vec::new();
+    // for every cluster: the index of its block, the top-left corner of its nodes and the size of the block
+    let mut blocks: Vec<(usize, Point, Size)> = Vec::with_capacity(graph.clusters.len());
+    for c in graph.clusters.iter().filter(|c| !c.nodes.is_empty()) {
+        let members: Vec<usize> = c.nodes.iter().map(|n| *n as usize).collect();
+        let mut sub = Graph::default();
+        sub.nodes = members.iter().map(|&n| slots[n].take().unwrap()).collect();
+        let local = |n: u32| members.iter().position(|&m| m == n as usize);
+        for e in &graph.edges {
+            if let (Some(from), Some(to)) = (local(e.from_node_id), local(e.to_node_id)) {
+                sub.edges.push(EdgeBuilder::new(from as u32, to as u32).directed(e.directed).build());
+            }
+        }
+        sub.update_edges_in_out();
+        layout(&mut sub);
+        let mut tl = sub.nodes[0].rect.top_left();
+        let mut br = sub.nodes[0].rect.bottom_right();
+        for n in &sub.nodes {
+            tl = Point::new(tl.x.min(n.rect.left()), tl.y.min(n.rect.top()));
+            br = Point::new(br.x.max(n.rect.right()), br.y.max(n.rect.bottom()));
+        }
+        let width = (br.x - tl.x + 1 + FRAME_LEFT * 2).max(c.title.chars().count() as i32 + 6);
+        let height = br.y - tl.y + 1 + FRAME_TOP * 2;
+        for (node, &index) in sub.nodes.drain(..).zip(&members) {
+            slots[index] = Some(node);
+            item_of[index] = items.len();
+        }
+        blocks.push((items.len(), tl, Size::new(width as u32, height as u32)));
+        items.push(members[0]);
+    }
+    for (index, item) in item_of.iter_mut().enumerate() {
+        if *item == usize::MAX {
+            *item = items.len();
+            items.push(index);
+        }
+    }
+    // the graph of the blocks - the first node of every cluster is temporarily resized to the size of the block
+    let mut saved = Vec::with_capacity(blocks.len());
+    for (item, _, size) in &blocks {
+        let node = slots[items[*item]].as_mut().unwrap();
+        saved.push(node.rect);
+        node.rect = Rect::with_point_and_size(Point::ORIGIN, *size);
+    }
+    let mut top = Graph::default();
+    top.nodes = items.iter().map(|&n| slots[n].take().unwrap()).collect();
+    let mut added = HashSet::new();
+    for e in &graph.edges {
+        let (from, to) = (item_of[e.from_node_id as usize], item_of[e.to_node_id as usize]);
+        if from != to && added.insert((from.min(to), from.max(to))) {
+            top.edges.push(EdgeBuilder::new(from as u32, to as u32).directed(e.directed).build());
+        }
+    }
+    top.update_edges_in_out();
+    layout(&mut top);
+    let positions: Vec<Point> = top.nodes.iter().map(|n| n.rect.top_left()).collect();
+    for (node, &index) in top.nodes.drain(..).zip(&items) {
+        slots[index] = Some(node);
+    }
+    for ((item, tl, _), rect) in blocks.iter().zip(saved) {
+        slots[items[*item]].as_mut().unwrap().rect = rect;
+        let p = positions[*item];
+        let (dx, dy) = (p.x + FRAME_LEFT - tl.x, p.y + FRAME_TOP - tl.y);
+        for (index, node) in slots.iter_mut().enumerate() {
+            if item_of[index] == *item {
+                node.as_mut().unwrap().rect += (dx, dy);
+            }
+        }
+    }
+    graph.nodes = slots.into_iter().map(|n| n.unwrap()).collect();
+    graph.update_edges_in_out();
+}
@@ -0,0 +1,218 @@
+use super::graph::ControlState;
+use super::Graph;
+use super::GraphNode;
+use crate::prelude::*;
+
+// the bit of every dot from a braille character (indexed by [row][column])
+const BRAILLE_DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+// a grid of braille characters (each character has 2x4 dots) - dots are combined (and not overwritten)
+struct BrailleCanvas {
+    width: i32,
+    height: i32,
+    cells: Vec<u8>,
+}
+
+impl BrailleCanvas {
+    fn new(size: Size) -> Self {
+        Self {
+            width: size.width as i32,
+            height: size.height as i32,
+            cells: vec![0; (size.width as usize) * (size.height as usize)],
+        }
+    }
+    fn set(&mut self, x: i32, y: i32) {
+        if x < 0 || y < 0 || x >= self.width * 2 || y >= self.height * 4 {
+            return;
+        }
+        self.cells[((y / 4) * self.width + x / 2) as usize] |= BRAILLE_DOTS[(y % 4) as usize][(x % 2) as usize];
+    }
+    fn line(&mut self, from: Point, to: Point) {
+        let (mut x, mut y) = (from.x, from.y);
+        let dx = (to.x - x).abs();
+        let dy = (to.y - y).abs();
+        let sx = if x < to.x { 1 } else { -1 };
+        let sy = if y < to.y { 1 } else { -1 };
+        let mut err = dx - dy;
+        loop {
+            self.set(x, y);
+            if x == to.x && y == to.y {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 > -dy {
+                err -= dy;
+                x += sx;
+            }
+            if e2 < dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+    fn fill(&mut self, tl: Point, br: Point) {
+        for y in tl.y.max(0)..=br.y.min(self.height * 4 - 1) {
+            for x in tl.x.max(0)..=br.x.min(self.width * 2 - 1) {
+                self.set(x, y);
+            }
+        }
+    }
+    fn paint(&self, surface: &mut Surface, x: i32, y: i32, attr: CharAttribute) {
+        for (index, bits) in self.cells.iter().enumerate() {
+            if *bits != 0 {
+                let ch = char::from_u32(0x2800 + *bits as u32).unwrap_or('\u{2800}');
+                let index = index as i32;
+                surface.write_char(x + index % self.width, y + index / self.width, Character::with_attributes(ch, attr));
+            }
+        }
+    }
+}
+
+impl<T> Graph<T>
+where
+    T: GraphNode,
+{
+    // the size of the graph when every cell of the view covers `zoom` x `zoom` cells of the graph
+    pub(super) fn zoomed_size(&self, zoom: u32) -> Size {
+        let sz = self.size();
+        Size::new(sz.width.div_ceil(zoom), sz.height.div_ceil(zoom))
+    }
+
+    // the node (if any) that is drawn at `point` (in zoomed coordinates)
+    pub(super) fn overview_node_at(&self, point: Point, zoom: u32) -> Option<usize> {
+        let z = zoom as i32;
+        (0..self.nodes.len()).find(|&i| {
+            let r = self.node_rect(i);
+            !self.nodes[i].filtered
+                && self.is_node_visible(i)
+                && point.x >= r.left() / z
+                && point.x <= r.right() / z
+                && point.y >= r.top() / z
+                && point.y <= r.bottom() / z
+        })
+    }
+
+    // draws the graph scaled down by `zoom` - edges are braille lines (between the centers of the nodes)
+    // and nodes are either short labels (if there is enough space) or dots
+    pub(super) fn paint_overview(&self, surface: &mut Surface, control: &ControlBase, zoom: u32, origin: Point, size: Size) {
+        let state = ControlState::new(control);
+        let theme = control.theme();
+        let z = zoom as i32;
+        let to_dot = |p: Point| Point::new((p.x * 2) / z + origin.x * 2, (p.y * 4) / z + origin.y * 4);
+        let mut canvas = BrailleCanvas::new(size);
+        for (e, n) in self.edges.iter().zip(self.compute_edge_aggregation()) {
+            if e.from_node_id == e.to_node_id || n == 0 {
+                continue;
+            }
+            let from = self.node_rect(e.from_node_id as usize).center();
+            let to = self.node_rect(e.to_node_id as usize).center();
+            canvas.line(to_dot(from), to_dot(to));
+        }
+        canvas.paint(surface, 0, 0, state.edge_attr(theme));
+        let ms = self.rendering_options.multiselect_ui;
+        let mut label = String::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            if !self.is_node_visible(index) {
+                continue;
+            }
+            let attr = match state {
+                ControlState::Disabled => state.node_attr(theme),
+                _ if state == ControlState::Focused && index == self.current_node => state.current_node_attr(theme),
+                _ if node.filtered => ControlState::Disabled.node_attr(theme),
+                _ if ms && node.selected => node.text_attr.unwrap_or(ControlState::multiselect_selected_label_attr(theme)),
+                _ => node.text_attr.unwrap_or(state.node_attr(theme)),
+            };
+            let r = self.node_rect(index);
+            let left = r.left() / z + origin.x;
+            let width = r.right() / z - r.left() / z + 1;
+            let y = r.center_y() / z + origin.y;
+            label.clear();
+            // a collapsed cluster is labeled with its title
+            let has_label = match self.collapsed_cluster_of(index) {
+                Some(c) => {
+                    label.push_str(&self.clusters[c].title);
+                    true
+                }
+                None => node.obj.write_label(&mut label, Size::new(u32::MAX, 1)).is_ok(),
+            };
+            if width >= 4 && has_label {
+                let text = label.lines().next().unwrap_or_default();
+                let count = text.chars().count() as i32;
+                if count > 0 {
+                    // a label that does not fit is truncated (and ends with '…')
+                    let short: String = if count > width {
+                        text.chars().take(width as usize - 1).chain(std::iter::once('…')).collect()
+                    } else {
+                        text.to_string()
+                    };
+                    let x = left + (width - count.min(width)) / 2;
+                    surface.write_string(x, y, &short, attr, false);
+                    continue;
+                }
+            }
+            surface.write_char(r.center_x() / z + origin.x, y, Character::with_attributes('●', attr));
+        }
+    }
+
+    // draws the entire graph within `rect` (a bordered inset) and marks the part of the graph that is visible (`view`)
+    pub(super) fn paint_minimap(&self, surface: &mut Surface, control: &ControlBase, rect: Rect, view: Rect) {
+        let state = ControlState::new(control);
+        let theme = control.theme();
+        let border_attr = match state {
+            ControlState::Disabled => theme.border.inactive,
+            ControlState::Normal => theme.border.normal,
+            ControlState::Focused => theme.border.focused,
+        };
+        surface.fill_rect(rect, Character::with_attributes(' ', theme.window.normal));
+        surface.draw_rect(rect, LineType::Single, border_attr);
+        let inner = Size::new(rect.width().saturating_sub(2), rect.height().saturating_sub(2));
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+        let scale = minimap_scale(self.size(), inner);
+        let to_dot = |p: Point| Point::new((p.x as f32 * 2.0 / scale) as i32, (p.y as f32 * 4.0 / scale) as i32);
+        let mut canvas = BrailleCanvas::new(inner);
+        for (e, n) in self.edges.iter().zip(self.compute_edge_aggregation()) {
+            if n == 0 {
+                continue;
+            }
+            let from = self.node_rect(e.from_node_id as usize).center();
+            let to = self.node_rect(e.to_node_id as usize).center();
+            canvas.line(to_dot(from), to_dot(to));
+        }
+        let mut nodes = BrailleCanvas::new(inner);
+        for index in (0..self.nodes.len()).filter(|&i| self.is_node_visible(i)) {
+            let r = self.node_rect(index);
+            nodes.fill(to_dot(r.top_left()), to_dot(r.bottom_right()));
+        }
+        canvas.paint(surface, rect.left() + 1, rect.top() + 1, state.edge_attr(theme));
+        nodes.paint(surface, rect.left() + 1, rect.top() + 1, state.node_attr(theme));
+        // the visible part of the graph
+        let to_cell = |x: i32, y: i32| {
+            Point::new(
+                rect.left() + 1 + ((x as f32 / scale) as i32).clamp(0, inner.width as i32 - 1),
+                rect.top() + 1 + ((y as f32 / scale) as i32).clamp(0, inner.height as i32 - 1),
+            )
+        };
+        let tl = to_cell(view.left(), view.top());
+        let br = to_cell(view.right(), view.bottom());
+        let attr = theme.lines.hovered;
+        if br.x > tl.x && br.y > tl.y {
+            surface.draw_rect(Rect::new(tl.x, tl.y, br.x, br.y), LineType::Single, attr);
+        } else {
+            surface.fill_rect(Rect::new(tl.x, tl.y, br.x, br.y), Character::with_attributes('█', attr));
+        }
+        if state == ControlState::Focused && self.current_node < self.nodes.len() {
+            let c = self.node_rect(self.current_node).center();
+            let p = to_cell(c.x, c.y);
+            surface.write_char(p.x, p.y, Character::with_attributes('●', state.current_node_attr(theme)));
+        }
+    }
+}
+
+// the number of graph cells (on each axis) that are covered by one cell of a minimap of size `inner`
+pub(super) fn minimap_scale(graph: Size, inner: Size) -> f32 {
+    let sx = graph.width as f32 / inner.width.max(1) as f32;
+    let sy = graph.height as f32 / inner.height.max(1) as f32;
+    sx.max(sy).max(0.01)
+}
@@ -0,0 +1,100 @@
+use crate::graphics::{Point, Rect};
+
+/// The side of a node where a [`Port`] is placed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PortSide {
+    /// The top side of the node
+    North,
+    /// The right side of the node
+    East,
+    /// The bottom side of the node
+    South,
+    /// The left side of the node
+    West,
+}
+
+impl PortSide {
+    /// Returns the side for a compass point name (`n`, `e`, `s`, `w`, `north`, `east`, `south` or `west` - case insensitive).
+    pub fn from_name(name: &str) -> Option<PortSide> {
+        match name.to_ascii_lowercase().as_str() {
+            "n" | "north" => Some(PortSide::North),
+            "e" | "east" => Some(PortSide::East),
+            "s" | "south" => Some(PortSide::South),
+            "w" | "west" => Some(PortSide::West),
+            _ => None,
+        }
+    }
+    #[inline(always)]
+    pub(super) fn is_horizontal(&self) -> bool {
+        matches!(self, PortSide::East | PortSide::West)
+    }
+}
+
+/// A point on the border of a node where edges are attached.
+///
+/// A port is either the middle of a side of the node ([`Port::new`]) or one of several evenly spaced
+/// slots on a side ([`Port::slot`]). Ports are added to a node with a name (via [`NodeBuilder::port`](super::NodeBuilder::port))
+/// and are referred by edges via [`EdgeBuilder::from_port`](super::EdgeBuilder::from_port) and
+/// [`EdgeBuilder::to_port`](super::EdgeBuilder::to_port).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Port {
+    side: PortSide,
+    index: u8,
+    count: u8,
+}
+
+impl Port {
+    /// Creates a port placed in the middle of the specified side.
+    pub fn new(side: PortSide) -> Self {
+        Self { side, index: 0, count: 1 }
+    }
+    /// Creates a port placed in the slot `index` out of `count` evenly spaced slots on the specified side.
+    ///
+    /// For example, `Port::slot(PortSide::South, 0, 2)` and `Port::slot(PortSide::South, 1, 2)` are the two output
+    /// slots at one quarter and three quarters of the bottom side of a node.
+    pub fn slot(side: PortSide, index: u8, count: u8) -> Self {
+        let count = count.max(1);
+        Self {
+            side,
+            index: index.min(count - 1),
+            count,
+        }
+    }
+    /// Returns the side of the node where the port is placed.
+    #[inline(always)]
+    pub fn side(&self) -> PortSide {
+        self.side
+    }
+    /// Returns the index of the slot (0 for a port placed in the middle of a side).
+    #[inline(always)]
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+    /// Returns the number of slots on the side of the port (1 for a port placed in the middle of a side).
+    #[inline(always)]
+    pub fn count(&self) -> u8 {
+        self.count
+    }
+    // the point (on the border of `rect`) where the port is located
+    pub(super) fn point(&self, rect: &Rect) -> Point {
+        let slot = |start: i32, len: i32| {
+            if self.count == 1 {
+                start + (len - 1) / 2
+            } else {
+                start + (len * (2 * self.index as i32 + 1)) / (2 * self.count as i32)
+            }
+        };
+        match self.side {
+            PortSide::North => Point::new(slot(rect.left(), rect.width() as i32), rect.top()),
+            PortSide::South => Point::new(slot(rect.left(), rect.width() as i32), rect.bottom()),
+            PortSide::West => Point::new(rect.left(), slot(rect.top(), rect.height() as i32)),
+            PortSide::East => Point::new(rect.right(), slot(rect.top(), rect.height() as i32)),
+        }
+    }
+}
+
+impl From<PortSide> for Port {
+    fn from(side: PortSide) -> Self {
+        Port::new(side)
+    }
+}
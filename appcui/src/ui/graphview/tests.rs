@@ -2096,3 +2096,559 @@ mod editable_graph_delete_tests {
         assert!(!graph.nodes[1].selected);
     }
 }
+
+mod dot_tests {
+    use super::*;
+    use crate::ui::graphview::DotError;
+
+    #[test]
+    fn from_dot_nodes_edges_and_labels() {
+        let g = graphview::Graph::<String>::from_dot(
+            r#"
+            // a small graph
+            digraph G {
+                node [shape=box];
+                a [label="Start"];
+                b; c [label="multi\nline"]
+                a -> b -> c;
+                a -> c [dir=none];
+                /* block
+                   comment */
+                "d e" -> a
+            }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(g.nodes_count(), 4);
+        assert_eq!(g.node(0).unwrap().value(), "Start");
+        assert_eq!(g.node(1).unwrap().value(), "b");
+        assert_eq!(g.node(2).unwrap().value(), "multi\nline");
+        assert_eq!(g.node(3).unwrap().value(), "d e");
+        assert!(g.nodes.iter().all(|n| n.border == Some(LineType::Single)));
+        let edges: Vec<(u32, u32, bool)> = g.edges.iter().map(|e| (e.from_node_id, e.to_node_id, e.directed)).collect();
+        assert_eq!(edges, vec![(0, 1, true), (1, 2, true), (0, 2, false), (3, 0, true)]);
+    }
+
+    #[test]
+    fn from_dot_subgraphs_and_strict_graphs() {
+        let g = graphview::Graph::<String>::from_dot("strict graph { a -- {b c}; subgraph s { node [shape=plaintext]; d } a -- b; b -- a }").unwrap();
+        assert_eq!(g.nodes_count(), 4);
+        assert_eq!(g.edges.len(), 2);
+        assert!(g.edges.iter().all(|e| !e.directed));
+        assert_eq!(g.node(0).unwrap().border, Some(LineType::SingleRound));
+        assert_eq!(g.node(3).unwrap().border, None);
+    }
+
+    #[test]
+    fn from_dot_attributes() {
+        let g = graphview::Graph::<String>::from_dot(
+            r##"digraph {
+                a [shape=box, style=bold, color=red, pos="10,4!"];
+                b [shape=doublecircle, fontcolor=white, fillcolor="#0000ff"];
+                c [shape=box, style="rounded,dashed", label="left\l"];
+                d [shape=box, penwidth=3, color=navy];
+                e [shape=box, style=dotted, label="right\r"];
+                a -> b [style=dashed, color=green];
+                b -> c [dir=back];
+                c -> d [penwidth=3.5];
+            }"##,
+        )
+        .unwrap();
+        let a = g.node(0).unwrap();
+        assert_eq!(a.border, Some(LineType::SingleThick));
+        assert_eq!(a.text_attr, Some(CharAttribute::with_color(Color::Red, Color::Transparent)));
+        assert_eq!(a.rect.top_left(), Point::new(10, 4));
+        let b = g.node(1).unwrap();
+        assert_eq!(b.border, Some(LineType::Double));
+        assert_eq!(b.text_attr, Some(CharAttribute::with_color(Color::White, Color::from_rgb(0, 0, 255))));
+        let c = g.node(2).unwrap();
+        assert_eq!(c.border, Some(LineType::AsciiRound));
+        assert_eq!(c.text_align, TextAlignment::Left);
+        assert_eq!(c.value(), "left");
+        assert_eq!(g.node(3).unwrap().border, Some(LineType::Border));
+        assert_eq!(g.node(3).unwrap().text_attr.unwrap().foreground, Color::DarkBlue);
+        assert_eq!(g.node(4).unwrap().border, Some(LineType::Braille));
+        assert_eq!(g.node(4).unwrap().text_align, TextAlignment::Right);
+        assert_eq!(g.edges[0].line_type, Some(LineType::Ascii));
+        assert_eq!(g.edges[0].attribute, Some(CharAttribute::with_fore_color(Color::Green)));
+        assert_eq!((g.edges[1].from_node_id, g.edges[1].to_node_id, g.edges[1].directed), (2, 1, true));
+        assert_eq!(g.edges[1].line_type, None);
+        assert_eq!(g.edges[2].line_type, Some(LineType::Border));
+    }
+
+    #[test]
+    fn from_dot_errors() {
+        let err = |text: &str| graphview::Graph::<String>::from_dot(text).err().unwrap();
+        assert_eq!(
+            err("digraph {\n  a -- b\n}"),
+            DotError {
+                line: 2,
+                column: 5,
+                message: "'--' can not be used in a directed graph (use '->')".to_string()
+            }
+        );
+        assert_eq!(err("graph { a -> b }").message, "'->' can not be used in an undirected graph (use '--')");
+        assert_eq!(err("tree { }").to_string(), "line 1, column 1: expected 'graph' or 'digraph', found 'tree'");
+        assert_eq!(err("graph { a [label=\"x] }").to_string(), "line 1, column 18: unterminated string");
+        assert_eq!(err("graph { a [color=orange] }").to_string(), "line 1, column 18: unknown color 'orange' for attribute 'color'");
+        assert_eq!(err("graph { a [pos=\"1;2\"] }").message, "invalid position '1;2' for attribute 'pos'");
+        assert_eq!(err("graph { a -- b").to_string(), "line 1, column 15: expected '}', found end of input");
+        assert_eq!(err("graph { a @ b }").to_string(), "line 1, column 11: unexpected character '@'");
+        assert_eq!(err("graph { a [shape=box,,] }").message, "expected an attribute name or ']', found ','");
+    }
+
+    #[test]
+    fn check_graph_from_dot_with_positions() {
+        let script = "
+            Paint.Enable(false)
+            Paint('1. Nodes placed at the pos hints (A/B on the first row, C below)')
+            CheckHash(0x7B383F7D53216B58)
+        ";
+        let mut a = App::debug(60, 15, script).build().unwrap();
+        let mut w = window!("Test,d:f");
+        let mut gv = graphview!("arrange: None, arrows: true, d:f");
+        let g = graphview::Graph::<String>::from_dot(
+            r#"digraph {
+                node [shape=box];
+                a [label="A", pos="1,1"];
+                b [label="B", pos="20,1", style=double];
+                c [label="C\nnode", pos="10,6", shape=plaintext, color=yellow];
+                a -> b; a -> c [style=dotted]; b -> c [dir=none];
+            }"#,
+        )
+        .unwrap();
+        gv.set_graph(g);
+        w.add(gv);
+        a.add_window(w);
+        a.run();
+    }
+
+    #[test]
+    fn to_dot_round_trip() {
+        let mut g = build_custom_graph_3();
+        g.nodes[0].rect = Rect::with_point_and_size(Point::new(5, 7), g.nodes[0].rect.size());
+        g.edges[1].directed = true;
+        let text = g.to_dot();
+        assert_eq!(
+            text,
+            concat!(
+                "digraph {\n",
+                "    n0 [label=\"School\", shape=box, peripheries=2, color=\"white\", fillcolor=\"red\", pos=\"5,7\", style=\"filled\"];\n",
+                "    n1 [label=\"Math\\l\", shape=box, color=\"white\", fillcolor=\"darkgreen\", pos=\"0,0\", style=\"filled\"];\n",
+                "    n2 [label=\"English\\r\", shape=box, color=\"black\", fillcolor=\"pink\", pos=\"0,0\", style=\"dashed,filled\"];\n",
+                "    n3 [label=\"Science\", shape=plaintext, pos=\"0,0\"];\n",
+                "    n4 [label=\"Literature\\n101\", shape=plaintext, color=\"black\", fillcolor=\"aqua\", pos=\"0,0\", style=\"filled\"];\n",
+                "    n0 -> n1 [dir=none, color=\"red\", style=\"dashed\"];\n",
+                "    n0 -> n2 [color=\"aqua\", style=\"dotted\"];\n",
+                "    n1 -> n3 [dir=none, color=\"green\", style=\"double\"];\n",
+                "    n2 -> n4 [dir=none, penwidth=3, color=\"black\"];\n",
+                "}\n"
+            )
+        );
+        let r = graphview::Graph::<String>::from_dot(&text).unwrap();
+        assert_eq!(r.nodes_count(), g.nodes_count());
+        for (a, b) in g.nodes.iter().zip(r.nodes.iter()) {
+            assert_eq!(a.value(), b.value());
+            assert_eq!(a.border, b.border);
+            assert_eq!(a.text_align, b.text_align);
+            assert_eq!(a.text_attr, b.text_attr);
+            assert_eq!(a.rect.top_left(), b.rect.top_left());
+        }
+        assert_eq!(r.edges.len(), g.edges.len());
+        for (a, b) in g.edges.iter().zip(r.edges.iter()) {
+            assert_eq!((a.from_node_id, a.to_node_id, a.directed), (b.from_node_id, b.to_node_id, b.directed));
+            assert_eq!(a.line_type, b.line_type);
+            assert_eq!(a.attribute.map(|x| x.foreground), b.attribute.map(|x| x.foreground));
+        }
+        let u = graphview::Graph::with_slices(&["x \"y\"", "a\\b"], &[(0, 1)], false);
+        assert_eq!(
+            u.to_dot(),
+            "graph {\n    n0 [label=\"x \\\"y\\\"\", shape=plaintext, pos=\"0,0\"];\n    n1 [label=\"a\\\\b\", shape=plaintext, pos=\"0,0\"];\n    n0 -- n1;\n}\n"
+        );
+        let r = graphview::Graph::<String>::from_dot(&u.to_dot()).unwrap();
+        assert_eq!(r.node(0).unwrap().value(), "x \"y\"");
+        assert_eq!(r.node(1).unwrap().value(), "a\\b");
+    }
+
+    #[test]
+    fn dot_edge_labels_and_ports() {
+        let g = graphview::Graph::<String>::from_dot(
+            r#"digraph { a:s -> b:n [label="yes\nmore"]; b -> a [tailport=w, headport=e]; a -> a [dir=back, tailport=e, headport=n] }"#,
+        )
+        .unwrap();
+        let e = &g.edges;
+        assert_eq!(e[0].label.as_deref(), Some("yes"));
+        assert_eq!((e[0].from_port.as_deref(), e[0].to_port.as_deref()), (Some("s"), Some("n")));
+        assert_eq!((e[1].from_port.as_deref(), e[1].to_port.as_deref()), (Some("w"), Some("e")));
+        assert_eq!(e[1].label, None);
+        assert_eq!((e[2].from_port.as_deref(), e[2].to_port.as_deref()), (Some("n"), Some("e")));
+        let text = g.to_dot();
+        assert!(text.contains("    n0 -> n1 [label=\"yes\", tailport=\"s\", headport=\"n\"];\n"));
+        let r = graphview::Graph::<String>::from_dot(&text).unwrap();
+        for (a, b) in g.edges.iter().zip(r.edges.iter()) {
+            assert_eq!((&a.label, &a.from_port, &a.to_port), (&b.label, &b.from_port, &b.to_port));
+        }
+    }
+
+    #[test]
+    fn dot_clusters() {
+        let g = graphview::Graph::<String>::from_dot(
+            r#"
+            digraph {
+                gateway -> users;
+                subgraph cluster_api {
+                    label="API";
+                    users -> orders;
+                    subgraph cluster_cache { redis }
+                }
+                subgraph cluster_db { graph [style=double, color=red]; postgres; users }
+                subgraph helpers { logs }
+                orders -> postgres;
+            }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(g.nodes_count(), 6);
+        assert_eq!(g.clusters_count(), 3);
+        // the inner cluster is parsed first (so redis belongs to it) and users is only part of the first cluster
+        let c: Vec<(&str, &[u32], LineType)> = g.clusters.iter().map(|c| (c.title(), c.nodes(), c.border())).collect();
+        assert_eq!(c[0], ("cache", &[3][..], LineType::Single));
+        assert_eq!(c[1], ("API", &[1, 2][..], LineType::Single));
+        assert_eq!(c[2], ("db", &[4][..], LineType::Double));
+        assert_eq!(g.clusters[2].attribute().map(|a| a.foreground), Some(Color::Red));
+        let text = g.to_dot();
+        assert!(text.contains("    subgraph cluster_1 {\n        graph [label=\"API\"];\n        n1;\n        n2;\n    }\n"));
+        let r = graphview::Graph::<String>::from_dot(&text).unwrap();
+        for (a, b) in g.clusters.iter().zip(r.clusters.iter()) {
+            assert_eq!((a.title(), a.nodes(), a.border(), a.attribute()), (b.title(), b.nodes(), b.border(), b.attribute()));
+        }
+    }
+}
+
+mod edge_routing_tests {
+    use super::*;
+    use crate::ui::graphview::{Port, PortSide};
+
+    fn build_state_machine() -> graphview::Graph<&'static str> {
+        let nodes = vec![
+            graphview::NodeBuilder::new("Idle").border(LineType::Single).position(Point::new(2, 3)).build(),
+            graphview::NodeBuilder::new("Running")
+                .border(LineType::Single)
+                .position(Point::new(24, 3))
+                .port("in", Port::new(PortSide::West))
+                .port("out1", Port::slot(PortSide::South, 0, 2))
+                .port("out2", Port::slot(PortSide::South, 1, 2))
+                .build(),
+            graphview::NodeBuilder::new("Done").border(LineType::Single).position(Point::new(14, 11)).build(),
+            graphview::NodeBuilder::new("Error").border(LineType::Single).position(Point::new(34, 14)).build(),
+        ];
+        let edges = vec![
+            graphview::EdgeBuilder::new(0, 1).directed(true).label("start").build(),
+            graphview::EdgeBuilder::new(1, 0).directed(true).label("stop").build(),
+            graphview::EdgeBuilder::new(0, 1).directed(true).build(),
+            graphview::EdgeBuilder::new(1, 1).directed(true).label("tick").build(),
+            graphview::EdgeBuilder::new(1, 2).directed(true).from_port("out1").to_port("n").label("ok").build(),
+            graphview::EdgeBuilder::new(1, 3).directed(true).from_port("out2").to_port("n").label("fail").build(),
+            graphview::EdgeBuilder::new(3, 0).directed(true).from_port("w").to_port("s").build(),
+        ];
+        graphview::Graph::new(nodes, edges)
+    }
+
+    #[test]
+    fn check_edge_offsets() {
+        let g = graphview::Graph::with_slices(&["A", "B", "C"], &[(0, 1), (1, 0), (0, 1), (1, 2), (2, 2), (2, 2)], true);
+        // an edge and its reverse are not parallel (only the two A -> B edges are)
+        assert_eq!(g.compute_edge_offsets(&g.compute_edge_aggregation()), vec![-1, 0, 1, 0, 0, 1]);
+        assert_eq!(g.edges_margin(), 3);
+        let g = graphview::Graph::with_slices(&["A", "B"], &[(0, 1)], true);
+        assert_eq!(g.edges_margin(), 0);
+        // a graph without self-loops and parallel edges needs no extra space
+        let g = graphview::Graph::with_slices(&["A", "B", "C"], &[(0, 1), (1, 0), (1, 2), (2, 1)], true);
+        assert_eq!(g.compute_edge_offsets(&g.compute_edge_aggregation()), vec![0, 0, 0, 0]);
+        assert_eq!(g.edges_margin(), 0);
+    }
+
+    #[test]
+    fn check_ports() {
+        let n = graphview::NodeBuilder::new("Node")
+            .border(LineType::Single)
+            .position(Point::new(10, 5))
+            .size(Size::new(8, 3))
+            .port("a", Port::slot(PortSide::North, 0, 2))
+            .port("b", Port::slot(PortSide::North, 1, 2))
+            .port("n", Port::new(PortSide::South))
+            .build();
+        // the node is 10 characters wide and 5 characters high (with border)
+        assert_eq!(n.port("a").unwrap().point(&n.rect), Point::new(12, 5));
+        assert_eq!(n.port("b").unwrap().point(&n.rect), Point::new(17, 5));
+        // "n" is redefined as a port placed on the bottom side
+        assert_eq!(n.port("n").unwrap().point(&n.rect), Point::new(14, 9));
+        assert_eq!(n.port("E").unwrap().point(&n.rect), Point::new(19, 7));
+        assert_eq!(n.port("west").unwrap(), Port::new(PortSide::West));
+        assert!(n.port("x").is_none());
+        assert_eq!(Port::slot(PortSide::East, 5, 3).index(), 2);
+    }
+
+    #[test]
+    fn check_labels_ports_and_multi_edges_orthogonal() {
+        let script = "
+            Paint.Enable(false)
+            Paint('1. Labels, ports, a self-loop, two parallel edges (one without label) and a reverse edge')
+            CheckHash(0x2ED6F2660ADACBE)
+        ";
+        let mut a = App::debug(60, 22, script).build().unwrap();
+        let mut w = window!("Test,d:f");
+        let mut gv = graphview!("routing: Orthogonal, arrange: None, d:f");
+        gv.set_graph(build_state_machine());
+        w.add(gv);
+        a.add_window(w);
+        a.run();
+    }
+
+    #[test]
+    fn check_labels_ports_and_multi_edges_direct() {
+        let script = "
+            Paint.Enable(false)
+            Paint('1. Labels, ports, a self-loop, two parallel edges (one without label) and a reverse edge')
+            CheckHash(0xDE8294F49A896C11)
+        ";
+        let mut a = App::debug(60, 22, script).build().unwrap();
+        let mut w = window!("Test,d:f");
+        let mut gv = graphview!("routing: Direct, arrange: None, d:f");
+        gv.set_graph(build_state_machine());
+        w.add(gv);
+        a.add_window(w);
+        a.run();
+    }
+}
+
+mod zoom_tests {
+    use super::*;
+
+    // `count` nodes (arranged in a grid) - each node is linked to the next one and to the one that is 5 positions away
+    fn build_large_graph(count: u32) -> graphview::Graph<String> {
+        let nodes: Vec<String> = (0..count).map(|i| format!("Node {i}")).collect();
+        let mut edges = Vec::new();
+        for i in 0..count {
+            if i + 1 < count {
+                edges.push((i, i + 1));
+            }
+            if i + 5 < count {
+                edges.push((i, i + 5));
+            }
+        }
+        graphview::Graph::with_slices_and_border(&nodes, &edges, LineType::Single, false)
+    }
+
+    #[test]
+    fn check_zoomed_size_and_overview_node_at() {
+        let mut g = graphview::Graph::with_slices(&["A", "B"], &[(0, 1)], false);
+        g.nodes[0].rect = Rect::with_point_and_size(Point::new(2, 1), Size::new(4, 3));
+        g.nodes[1].rect = Rect::with_point_and_size(Point::new(22, 9), Size::new(4, 3));
+        g.resize_graph(false);
+        let sz = g.size();
+        assert_eq!(g.zoomed_size(1), sz);
+        assert_eq!(g.zoomed_size(4), Size::new(sz.width.div_ceil(4), sz.height.div_ceil(4)));
+        assert_eq!(g.overview_node_at(Point::new(0, 0), 4), Some(0));
+        assert_eq!(g.overview_node_at(Point::new(6, 2), 4), Some(1));
+        assert_eq!(g.overview_node_at(Point::new(5, 3), 4), None);
+        assert_eq!(g.overview_node_at(Point::new(3, 1), 4), None);
+    }
+
+    #[test]
+    fn check_zoom_with_keyboard() {
+        let script = "
+            Paint.Enable(false)
+            Paint('1. Initial state (1:1)')
+            CheckHash(0x3CE453DBB257B428)
+            Key.Pressed(Ctrl+PageDown)
+            Paint('2. Zoom 2')
+            CheckHash(0x684E0FD7995859EA)
+            Key.Pressed(Ctrl+PageDown)
+            Paint('3. Zoom 4 (labels are truncated)')
+            CheckHash(0x7DE724915F4FC1B7)
+            Key.Pressed(Ctrl+PageDown)
+            Paint('4. Zoom 8 (nodes are dots)')
+            CheckHash(0x2BCD04C87E27A6EA)
+            Key.Pressed(Ctrl+PageUp)
+            Paint('5. Back to zoom 4')
+            CheckHash(0x7DE724915F4FC1B7)
+            Key.Pressed(Ctrl+0)
+            Paint('6. Back to 1:1')
+            CheckHash(0x8A8CCF0FEF290CF8)
+            Key.Pressed(Ctrl+Home)
+            Paint('7. Fit to view')
+            CheckHash(0x684E0FD7995859EA)
+            Key.Pressed(Right,2)
+            Key.Pressed(Ctrl+End)
+            Paint('8. Zoom to the current node (Node 2)')
+            CheckHash(0xAF9E57AEF79CFC32)
+        ";
+        let mut a = App::debug(60, 20, script).build().unwrap();
+        let mut w = window!("Test,d:f");
+        let mut gv = graphview!("arrange: Grid, d:f, flags:[ScrollBars]");
+        gv.set_graph(build_large_graph(30));
+        w.add(gv);
+        a.add_window(w);
+        a.run();
+    }
+
+    #[test]
+    fn check_overview_mouse() {
+        let script = "
+            Paint.Enable(false)
+            Paint('1. Initial state (zoom 2)')
+            CheckHash(0x8725DCFDCCC011F0)
+            Mouse.Click(10,7,left)
+            Paint('2. Node 14 is the current node')
+            CheckHash(0x49C02E5564530EBD)
+            Mouse.Drag(50,16,50,11)
+            Paint('3. View moved (5 lines)')
+            CheckHash(0x3DDB496B6D50317)
+            Mouse.DoubleClick(10,14,left)
+            Paint('4. Back to 1:1 (centered on Node 50)')
+            CheckHash(0x7666C54107554FD7)
+        ";
+        let mut a = App::debug(60, 20, script).build().unwrap();
+        let mut w = window!("Test,d:f");
+        let mut gv = graphview!("arrange: Grid, d:f, zoom: 2");
+        gv.set_graph(build_large_graph(60));
+        w.add(gv);
+        a.add_window(w);
+        a.run();
+    }
+
+    #[test]
+    fn check_minimap() {
+        let script = "
+            Paint.Enable(false)
+            Paint('1. Initial state (minimap in the bottom-right corner)')
+            CheckHash(0x58CC8DF9F417CFC9)
+            Mouse.Click(57,19,left)
+            Paint('2. View centered on the clicked point')
+            CheckHash(0x2152A9C8DA797142)
+            Mouse.Drag(57,19,60,21)
+            Paint('3. View moved to the bottom-right part of the graph')
+            CheckHash(0x4426B581DFA9565B)
+            Key.Pressed(Ctrl+PageDown)
+            Paint('4. Zoom 2 (with minimap)')
+            CheckHash(0x719512C499C1FB0C)
+        ";
+        let mut a = App::debug(70, 24, script).build().unwrap();
+        let mut w = window!("Test,d:f");
+        let mut gv = graphview!("arrange: Grid, d:f, flags:[ScrollBars, Minimap]");
+        gv.set_graph(build_large_graph(60));
+        w.add(gv);
+        a.add_window(w);
+        a.run();
+    }
+}
+
+mod cluster_tests {
+    use super::*;
+    use crate::ui::graphview::{ClusterBuilder, Edge, EdgeBuilder, Node, NodeBuilder};
+
+    // two namespaces ("api" and "db") with two services each, plus a gateway that is not part of any cluster
+    fn build_clustered_graph(collapsed: bool) -> graphview::Graph<&'static str> {
+        let names = ["gateway", "users", "orders", "postgres", "redis"];
+        let nodes: Vec<Node<&'static str>> = names.iter().map(|n| NodeBuilder::new(*n).border(LineType::Single).build()).collect();
+        let edges: Vec<Edge> = [(0, 1), (0, 2), (1, 3), (2, 3), (2, 4), (1, 2)]
+            .iter()
+            .map(|(a, b)| EdgeBuilder::new(*a, *b).directed(true).build())
+            .collect();
+        let clusters = vec![
+            ClusterBuilder::new("api").nodes(&[1, 2]).build(),
+            ClusterBuilder::new("db").nodes(&[3, 4]).border(LineType::Double).collapsed(collapsed).build(),
+        ];
+        graphview::Graph::with_clusters(nodes, edges, clusters)
+    }
+
+    #[test]
+    fn check_cluster_members() {
+        let nodes: Vec<Node<&'static str>> = ["a", "b", "c"].iter().map(|n| NodeBuilder::new(*n).build()).collect();
+        let clusters = vec![
+            ClusterBuilder::new("first").nodes(&[0, 1, 7]).build(),
+            ClusterBuilder::new("second").nodes(&[1, 2, 2]).collapsed(true).build(),
+        ];
+        let g = graphview::Graph::with_clusters(nodes, Vec::new(), clusters);
+        assert_eq!(g.clusters_count(), 2);
+        // invalid and duplicated nodes are removed (a node only belongs to the first cluster)
+        assert_eq!(g.cluster(0).unwrap().nodes(), &[0, 1]);
+        assert_eq!(g.cluster(1).unwrap().nodes(), &[2]);
+        assert_eq!(g.cluster(1).unwrap().title(), "second");
+        assert!(g.cluster(1).unwrap().is_collapsed());
+        assert!(g.cluster(2).is_none());
+    }
+
+    #[test]
+    fn check_collapsed_cluster_edges() {
+        let mut g = build_clustered_graph(true);
+        // edges: 0->1, 0->2, 1->3, 2->3, 2->4, 1->2 (the "db" cluster, nodes 3 and 4, is collapsed)
+        assert_eq!(g.compute_edge_aggregation(), vec![1, 1, 1, 2, 0, 1]);
+        assert!(g.is_node_visible(3));
+        assert!(!g.is_node_visible(4));
+        assert_eq!(g.representative(4), 3);
+        assert_eq!(g.node_rect(4), g.node_rect(3));
+        g.clusters[1].collapsed = false;
+        g.update_clusters();
+        assert_eq!(g.compute_edge_aggregation(), vec![1; 6]);
+        assert!(g.is_node_visible(4));
+    }
+
+    #[test]
+    fn check_layout_keeps_clusters_together() {
+        use crate::ui::graphview::node_layout::*;
+        let layouts: [fn(&mut graphview::Graph<&'static str>); 5] = [
+            |g| grid::rearange(g, 2),
+            |g| grid::rearange(g, 1),
+            |g| circular::rearange(g),
+            |g| hierarchical::rearange(g, 2),
+            |g| force_directed::rearange(g),
+        ];
+        for layout in layouts {
+            let mut g = build_clustered_graph(false);
+            clusters::rearange(&mut g, layout);
+            g.resize_graph(true);
+            let frames: Vec<Rect> = g.clusters.iter().map(|c| c.rect).collect();
+            // the frames of the clusters do not overlap and contain only the nodes of the cluster
+            assert!(!frames[0].contains_rect(frames[1]) && !frames[1].contains_rect(frames[0]));
+            for (index, n) in g.nodes.iter().enumerate() {
+                for (c, frame) in frames.iter().enumerate() {
+                    let member = g.clusters[c].nodes.contains(&(index as u32));
+                    assert_eq!(frame.contains_rect(n.rect), member);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn check_clusters_paint_and_collapse() {
+        let script = "
+            Paint.Enable(false)
+            Paint('1. Initial state (two clusters, db is collapsed)')
+            CheckHash(0xD0171397602B7F6D)
+            Key.Pressed(Ctrl+Tab)
+            Paint('2. Current node is users')
+            CheckHash(0x5F1800C015EEAB39)
+            Key.Pressed(Ctrl+Space)
+            Paint('3. api is collapsed')
+            CheckHash(0xB348038C34776072)
+            Mouse.DoubleClick(5,27,left)
+            Paint('4. db is expanded')
+            CheckHash(0xCEA3A5594D9B1C02)
+            Mouse.Click(6,25,left)
+            Paint('5. db is collapsed (click on its title)')
+            CheckHash(0x1FC7EE91E743D2B2)
+            Key.Pressed(Ctrl+Space)
+            Paint('6. api is expanded')
+            CheckHash(0xE1C21254C0B4F239)
+        ";
+        let mut a = App::debug(60, 36, script).build().unwrap();
+        let mut w = window!("Test,d:f");
+        let mut gv = graphview!("arrange: Hierarchical, routing: Orthogonal, d:f");
+        gv.set_graph(build_clustered_graph(true));
+        w.add(gv);
+        a.add_window(w);
+        a.run();
+    }
+}
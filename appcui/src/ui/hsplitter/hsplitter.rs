@@ -17,7 +17,7 @@ enum State {
     Dragging,
 }
 
-#[CustomControl(overwrite=OnPaint + OnKeyPressed + OnMouseEvent + OnResize, internal = true)]
+#[CustomControl(overwrite=OnPaint + OnKeyPressed + OnMouseEvent + OnResize + PersistState, internal = true)]
 pub struct HSplitter {
     top: Handle<SplitterPanel>,
     bottom: Handle<SplitterPanel>,
@@ -375,3 +375,21 @@ impl OnResize for HSplitter {
         }
     }
 }
+impl PersistState for HSplitter {
+    fn save_state(&self) -> Option<String> {
+        // percentages are saved with a decimal point (e.g. "0.5") and absolute positions as integers
+        match self.pos {
+            Coordinate::Absolute(value) => Some(value.to_string()),
+            Coordinate::Percentage(value) => Some(format!("{value:?}")),
+        }
+    }
+    fn restore_state(&mut self, state: &str) {
+        if state.contains('.') {
+            if let Ok(value) = state.parse::<f32>() {
+                self.set_position(value);
+            }
+        } else if let Ok(value) = state.parse::<i32>() {
+            self.set_position(value);
+        }
+    }
+}
@@ -79,15 +79,11 @@ impl OnPaint for KeySelector {
 impl OnKeyPressed for KeySelector {
     fn on_key_pressed(&mut self, key: Key, _: char) -> EventProcessStatus {
         match key.code {
-            KeyCode::Enter => {
-                if (!self.flags.contains(Flags::AcceptEnter)) || (self.flags.contains(Flags::ReadOnly)) {
-                    return EventProcessStatus::Ignored;
-                }
+            KeyCode::Enter if ((!self.flags.contains(Flags::AcceptEnter)) || (self.flags.contains(Flags::ReadOnly))) => {
+                return EventProcessStatus::Ignored;
             }
-            KeyCode::Escape => {
-                if (!self.flags.contains(Flags::AcceptEscape)) || (self.flags.contains(Flags::ReadOnly)) {
-                    return EventProcessStatus::Ignored;
-                }
+            KeyCode::Escape if ((!self.flags.contains(Flags::AcceptEscape)) || (self.flags.contains(Flags::ReadOnly))) => {
+                return EventProcessStatus::Ignored;
             }
             KeyCode::Tab
                 if ((!self.flags.contains(Flags::AcceptTab)) || (self.flags.contains(Flags::ReadOnly))) => {
@@ -423,6 +423,7 @@ impl OnKeyPressed for ListBox {
             self.search();
             return EventProcessStatus::Processed;
         }
+        let (key, _) = KeyMap::translate_key("listbox", key, character);
         match key.value() {
             key!("Up") => {
                 self.update_position(self.pos.saturating_sub(1), true);
@@ -33,6 +33,8 @@ pub use crate::ui::components::listitem::formats::VolumeFormat;
 pub use crate::ui::components::listitem::formats::WeightFormat;
 pub use crate::ui::components::listitem::ListItem;
 pub use crate::ui::components::listitem::RenderMethod;
+pub use crate::ui::components::listitem::ExportFormat;
+pub use crate::ui::components::listitem::ExportScope;
 
 pub use self::groups::Group;
 pub(super) use self::groups::GroupInformation;
@@ -32,7 +32,7 @@ impl GroupInformation {
     pub(super) fn new(name: &str) -> GroupInformation {
         GroupInformation {
             name: String::from(name),
-            name_chars_count: name.chars().count() as u16,
+            name_chars_count: crate::graphics::unicode::text_width(name) as u16,
             items_count: 0,
             items_check_count: 0,
             collapsed: false,
@@ -1,9 +1,10 @@
 use std::cmp::Ordering;
 
 use super::events::*;
-use super::{Flags, Group, GroupInformation, Item, ListItem, ViewMode};
+use super::{ExportFormat, ExportScope, Flags, Group, GroupInformation, Item, ListItem, ViewMode};
 use crate::utils;
 use appcui_proc_macro::*;
+use components::listitem::export::Exporter;
 use components::listitem::render_method::RenderData;
 use components::{Column, ColumnsHeader, ColumnsHeaderAction, ListScrollBars};
 
@@ -46,7 +47,7 @@ impl TextLine {
     }
 }
 
-#[CustomControl(overwrite=OnPaint+OnKeyPressed+OnMouseEvent+OnResize, internal=true)]
+#[CustomControl(overwrite=OnPaint+OnKeyPressed+OnMouseEvent+OnResize+PersistState, internal=true)]
 pub struct ListView<T>
 where
     T: ListItem + 'static,
@@ -617,6 +618,72 @@ where
         self.header.columns_mut().get_mut(index)
     }
 
+    /// Exports the rows of the list view (in the order they are displayed, without the items that are filtered out
+    /// or the items from collapsed groups) in a specific format. Only the visible columns (with a width larger than 0)
+    /// are exported. The values are formatted the same way they are displayed, or exported as raw values
+    /// (numbers, dates in ISO format, ...) if the `raw_values` parameter is `true`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use appcui::prelude::*;
+    ///
+    /// #[derive(ListItem)]
+    /// struct Person {
+    ///    #[Column(name="Name", width=20)]
+    ///    name: &'static str,
+    ///    #[Column(name="Age", width=5, align=Right)]
+    ///    age: u32
+    /// }
+    /// let mut lv = ListView::<Person>::new(layout!("d:f"), listview::Flags::None);
+    /// lv.add(Person { name: "John", age: 30 });
+    /// let csv = lv.export(listview::ExportFormat::Csv, listview::ExportScope::All, false);
+    /// assert_eq!(csv, "Name,Age\nJohn,30\n");
+    /// ```
+    pub fn export(&self, format: ExportFormat, scope: ExportScope, raw_values: bool) -> String {
+        match scope {
+            ExportScope::All => self.export_items(format, raw_values, |_, _| true),
+            ExportScope::Selected => self.export_items(format, raw_values, |_, item| item.is_checked()),
+            ExportScope::CurrentGroup => {
+                let gid = match self.filter.get(self.pos) {
+                    Some(Element::Item(index)) => self.data[*index as usize].group_id(),
+                    Some(Element::Group(gid)) => *gid,
+                    None => 0,
+                };
+                self.export_items(format, raw_values, |_, item| item.group_id() == gid)
+            }
+        }
+    }
+
+    fn export_items<F>(&self, format: ExportFormat, raw_values: bool, include: F) -> String
+    where
+        F: Fn(usize, &Item<T>) -> bool,
+    {
+        let columns: Vec<(usize, &Column)> = self.header.columns().iter().enumerate().filter(|(_, c)| c.width > 0).collect();
+        let names: Vec<(&str, TextAlignment)> = columns.iter().map(|(_, c)| (c.name(), c.alignment)).collect();
+        let mut exporter = Exporter::new(format, raw_values, &names);
+        for element in &self.filter {
+            if let Element::Item(index) = element {
+                let item = &self.data[*index as usize];
+                if include(*index as usize, item) {
+                    exporter.add_row(columns.iter().map(|(i, _)| item.value().render_method(*i as u16)));
+                }
+            }
+        }
+        exporter.finish()
+    }
+
+    // copies the selected items (or the current item if no item is selected) to the clipboard (as tab separated values)
+    fn copy_to_clipboard(&self) {
+        let text = if self.selected_items_count > 0 {
+            self.export(ExportFormat::Tsv, ExportScope::Selected, false)
+        } else if let Some(current) = self.current_item_index() {
+            self.export_items(ExportFormat::Tsv, false, |index, _| index == current)
+        } else {
+            return;
+        };
+        RuntimeManager::get().backend_mut().set_clipboard_text(&text);
+    }
+
     fn goto_element(&mut self, element: Element, emit_event: bool) -> bool {
         for (index, item) in self.filter.iter().enumerate() {
             if *item == element {
@@ -1014,6 +1081,11 @@ where
                 true
             }
 
+            key!("Ctrl+C") | key!("Ctrl+Insert") => {
+                self.copy_to_clipboard();
+                true
+            }
+
             // Action
             key!("Enter") => {
                 match self.filter.get(self.pos) {
@@ -1814,6 +1886,7 @@ where
     T: ListItem + 'static,
 {
     fn on_key_pressed(&mut self, key: Key, character: char) -> EventProcessStatus {
+        let (key, character) = KeyMap::translate_key("listview", key, character);
         let action = if self.view_mode == ViewMode::Details {
             self.header.process_key_pressed(key)
         } else {
@@ -1883,3 +1956,42 @@ where
         }
     }
 }
+impl<T> PersistState for ListView<T>
+where
+    T: ListItem + 'static,
+{
+    // format: <column widths separated by commas>[;<sort column>;asc|desc]
+    fn save_state(&self) -> Option<String> {
+        let columns = self.header.columns();
+        if columns.is_empty() {
+            return None;
+        }
+        let mut s = String::with_capacity(columns.len() * 4);
+        for (index, c) in columns.iter().enumerate() {
+            if index > 0 {
+                s.push(',');
+            }
+            s.push_str(&c.width().to_string());
+        }
+        if let Some(index) = self.header.sort_column() {
+            s.push_str(&format!(";{index};{}", if self.header.should_sort_ascendent() { "asc" } else { "desc" }));
+        }
+        Some(s)
+    }
+    fn restore_state(&mut self, state: &str) {
+        let mut parts = state.split(';');
+        if let Some(widths) = parts.next() {
+            for (index, width) in widths.split(',').enumerate() {
+                if let Ok(width) = width.trim().parse::<u8>() {
+                    self.header.set_column_width(index as u16, width);
+                }
+            }
+        }
+        if let (Some(index), Some(order)) = (parts.next(), parts.next()) {
+            if let Ok(index) = index.parse::<u16>() {
+                self.sort(index, order != "desc");
+            }
+        }
+        self.update_scrollbars();
+    }
+}
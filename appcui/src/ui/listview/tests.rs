@@ -6852,4 +6852,262 @@ fn check_merge_border_mode_with_details_view() {
     w.add(lv);
     a.add_window(w);
     a.run();
-}
\ No newline at end of file
+}
+struct FileEntry {
+    name: &'static str,
+    size: u64,
+    hidden: bool,
+}
+impl listview::ListItem for FileEntry {
+    fn columns_count() -> u16 {
+        3
+    }
+    fn column(index: u16) -> Column {
+        match index {
+            0 => Column::new("Name", 12, TextAlignment::Left),
+            1 => Column::new("Size", 10, TextAlignment::Right),
+            _ => Column::new("Hidden", 8, TextAlignment::Center),
+        }
+    }
+    fn render_method(&'_ self, column_index: u16) -> Option<listview::RenderMethod<'_>> {
+        match column_index {
+            0 => Some(listview::RenderMethod::Text(self.name)),
+            1 => Some(listview::RenderMethod::Size(self.size, listview::SizeFormat::Bytes)),
+            2 => Some(listview::RenderMethod::Bool(self.hidden, listview::BoolFormat::YesNo)),
+            _ => None,
+        }
+    }
+    fn compare(&self, other: &Self, column_index: u16) -> std::cmp::Ordering {
+        match column_index {
+            0 => self.name.cmp(other.name),
+            1 => self.size.cmp(&other.size),
+            _ => self.hidden.cmp(&other.hidden),
+        }
+    }
+}
+impl FileEntry {
+    fn populate(lv: &mut ListView<FileEntry>) {
+        let docs = lv.add_group("Documents");
+        let media = lv.add_group("Media");
+        lv.add_item(listview::Item::new(FileEntry { name: "notes.txt", size: 120, hidden: false }, false, None, [' ', ' '], docs));
+        lv.add_item(listview::Item::new(FileEntry { name: "a, \"b\"|c", size: 2048, hidden: true }, true, None, [' ', ' '], docs));
+        lv.add_item(listview::Item::new(FileEntry { name: "song.mp3", size: 4000000, hidden: false }, true, None, [' ', ' '], media));
+    }
+}
+
+#[test]
+fn check_export() {
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Initial state')
+        CheckHash(0xCADAE0D963BE8A45)
+    ";
+    let mut a = App::debug(60, 11, script).build().unwrap();
+    let mut w = window!("Test,d:f");
+    let mut lv = ListView::<FileEntry>::new(layout!("d:f"), listview::Flags::ShowGroups);
+    FileEntry::populate(&mut lv);
+    assert_eq!(
+        lv.export(listview::ExportFormat::Csv, listview::ExportScope::All, false),
+        "Name,Size,Hidden\nnotes.txt,120 B,No\n\"a, \"\"b\"\"|c\",\"2,048 B\",Yes\nsong.mp3,\"4,000,000 B\",No\n"
+    );
+    assert_eq!(
+        lv.export(listview::ExportFormat::Tsv, listview::ExportScope::Selected, true),
+        "Name\tSize\tHidden\na, \"b\"|c\t2048\ttrue\nsong.mp3\t4000000\tfalse\n"
+    );
+    assert_eq!(
+        lv.export(listview::ExportFormat::Json, listview::ExportScope::All, true),
+        "[\n  {\"Name\": \"notes.txt\", \"Size\": 120, \"Hidden\": false},\n  {\"Name\": \"a, \\\"b\\\"|c\", \"Size\": 2048, \"Hidden\": true},\n  {\"Name\": \"song.mp3\", \"Size\": 4000000, \"Hidden\": false}\n]\n"
+    );
+    assert_eq!(
+        lv.export(listview::ExportFormat::Markdown, listview::ExportScope::CurrentGroup, false),
+        "| Name | Size | Hidden |\n| --- | ---: | :---: |\n| notes.txt | 120 B | No |\n| a, \"b\"\\|c | 2,048 B | Yes |\n"
+    );
+    lv.clear();
+    assert_eq!(lv.export(listview::ExportFormat::Json, listview::ExportScope::All, false), "[]\n");
+    assert_eq!(lv.export(listview::ExportFormat::Csv, listview::ExportScope::CurrentGroup, false), "Name,Size,Hidden\n");
+    w.add(lv);
+    a.add_window(w);
+    a.run();
+}
+
+#[test]
+fn check_copy_to_clipboard() {
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Initial state')
+        CheckHash(0x96120E2AB932FDBA)
+        Key.Pressed(Ctrl+C)
+        CheckClipboardText('Name\\tSize\\tHidden\\na, \"b\"|c\\t2,048 B\\tYes\\nsong.mp3\\t4,000,000 B\\tNo\\n')
+        Key.Pressed(Space)
+        Key.Pressed(Down)
+        Key.Pressed(Space)
+        Key.Pressed(Down)
+        Key.Pressed(Space)
+        Key.Pressed(Ctrl+Insert)
+        CheckClipboardText('Name\\tSize\\tHidden\\nnotes.txt\\t120 B\\tNo\\n')
+    ";
+    let mut a = App::debug(60, 11, script).build().unwrap();
+    let mut w = window!("Test,d:f");
+    let mut lv = ListView::<FileEntry>::new(layout!("d:f"), listview::Flags::CheckBoxes);
+    FileEntry::populate(&mut lv);
+    w.add(lv);
+    a.add_window(w);
+    a.run();
+}
+
+#[test]
+fn check_persist_state() {
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Initial state')
+        CheckHash(0x6529B94CC5D01413)
+        Key.Pressed(F2)
+        Paint('2. Name column wider, sorted by city (descendent)')
+        CheckHash(0xED013527327823B2)
+    ";
+    #[Window(events = CommandBarEvents, commands: [Restore], internal = true)]
+    struct MyWin {
+        lv: Handle<ListView<Person>>,
+    }
+    impl MyWin {
+        fn new() -> Self {
+            let mut w = Self {
+                base: window!("Test,a:c,w:50,h:8"),
+                lv: Handle::None,
+            };
+            let mut lv = listview!("Person,d:f,columns=[{&Name,10,Left},{&Size,10,Right},{&City,10,Center}]");
+            Person::populate_for_sort(&mut lv);
+            assert_eq!(lv.save_state().as_deref(), Some("10,10,10"));
+            w.lv = w.add(lv);
+            w
+        }
+    }
+    impl CommandBarEvents for MyWin {
+        fn on_update_commandbar(&self, commandbar: &mut CommandBar) {
+            commandbar.set(key!("F2"), "Restore", mywin::Commands::Restore);
+        }
+        fn on_event(&mut self, _: mywin::Commands) {
+            let h = self.lv;
+            if let Some(lv) = self.control_mut(h) {
+                lv.restore_state("15,8,invalid;2;desc");
+                assert_eq!(lv.save_state().as_deref(), Some("15,8,10;2;desc"));
+            }
+        }
+    }
+    let mut a = App::debug(60, 11, script).command_bar().build().unwrap();
+    a.add_window(MyWin::new());
+    a.run();
+}
+
+#[test]
+fn check_locale_formats() {
+    struct Item {
+        name: &'static str,
+        value: f64,
+        size: u64,
+        ok: bool,
+        status: listview::Status,
+        duration: Duration,
+    }
+    impl listview::ListItem for Item {
+        fn render_method(&'_ self, column_index: u16) -> Option<listview::RenderMethod<'_>> {
+            match column_index {
+                0 => Some(listview::RenderMethod::Ascii(self.name)),
+                1 => Some(listview::RenderMethod::Float(self.value, listview::FloatFormat::TwoDigits)),
+                2 => Some(listview::RenderMethod::Size(self.size, listview::SizeFormat::KiloBytesWithDecimals)),
+                3 => Some(listview::RenderMethod::Bool(self.ok, listview::BoolFormat::YesNo)),
+                4 => Some(listview::RenderMethod::Status(self.status, listview::StatusFormat::Hashtag)),
+                5 => Some(listview::RenderMethod::Duration(self.duration, listview::DurationFormat::Seconds)),
+                _ => None,
+            }
+        }
+    }
+    let script = "
+        Paint.Enable(false)
+        Paint('1. German: 1.234,50 | 12.345,67 KB | Ja/Nein | Pausiert (50%) / Fertig | 90 Sek')
+        CheckHash(0xF42B438BC6DE9C75)
+        Key.TypeText('Nein')
+        Paint('2. search uses the localized text (Mike)')
+        CheckHash(0xD868EE5B40A523A2)
+    ";
+    let mut a = App::debug(90, 8, script).locale(Locale::new(Locales::German)).build().unwrap();
+    let mut w = window!("Test,d:f");
+    let mut lv = listview!(
+        "Item,d:f,flags:ScrollBars+SearchBar,columns=[{&Name,8,Left},{Value,12,Right},{Size,14,Right},{Ok,6,Left},{Status,18,Left},{Time,10,Right}]"
+    );
+    lv.add_items(vec![
+        Item {
+            name: "John",
+            value: 1234.5,
+            size: 12_345_678,
+            ok: true,
+            status: listview::Status::Paused(0.5),
+            duration: Duration::seconds(90),
+        },
+        Item {
+            name: "Mike",
+            value: -0.25,
+            size: 1_500,
+            ok: false,
+            status: listview::Status::Completed,
+            duration: Duration::seconds(5),
+        },
+    ]);
+    w.add(lv);
+    a.add_window(w);
+    a.run();
+}
+
+#[test]
+fn check_locale_units() {
+    struct Item {
+        size: u64,
+        weight: u64,
+        speed: u64,
+        temperature: f64,
+        duration: Duration,
+    }
+    impl listview::ListItem for Item {
+        fn render_method(&'_ self, column_index: u16) -> Option<listview::RenderMethod<'_>> {
+            match column_index {
+                0 => Some(listview::RenderMethod::Size(self.size, listview::SizeFormat::Auto)),
+                1 => Some(listview::RenderMethod::Weight(self.weight, listview::WeightFormat::Grams)),
+                2 => Some(listview::RenderMethod::Speed(self.speed, listview::SpeedFormat::Knots)),
+                3 => Some(listview::RenderMethod::Temperature(self.temperature, listview::TemperatureFormat::Celsius)),
+                4 => Some(listview::RenderMethod::Duration(self.duration, listview::DurationFormat::Details)),
+                _ => None,
+            }
+        }
+    }
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Romanian: 12 Mo / 1 ko | 1.500 g / 7 g | 20 noduri / 3 noduri | 21,5℃ / -2,0℃ | 1z 1h 1m 5s / 5s')
+        CheckHash(0x4061A0C841AA008)
+    ";
+    let mut locale = Locale::new(Locales::Romanian);
+    locale.load("unit.megabytes = Mo\nunit.kilobytes = ko\nunit.celsius = ℃").unwrap();
+    let mut a = App::debug(90, 8, script).locale(locale).build().unwrap();
+    let mut w = window!("Test,d:f");
+    let mut lv = listview!(
+        "Item,d:f,flags:ScrollBars,columns=[{Size,10,Right},{Weight,10,Right},{Speed,12,Right},{Temp,12,Right},{Time,16,Right}]"
+    );
+    lv.add_items(vec![
+        Item {
+            size: 12_345_678,
+            weight: 1_500,
+            speed: 20,
+            temperature: 21.5,
+            duration: Duration::seconds(90_065),
+        },
+        Item {
+            size: 1_500,
+            weight: 7,
+            speed: 3,
+            temperature: -2.0,
+            duration: Duration::seconds(5),
+        },
+    ]);
+    w.add(lv);
+    a.add_window(w);
+    a.run();
+}
@@ -144,6 +144,7 @@ impl Markdown {
         None
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn process_list_element(
         elements: &[InlineElement],
         indent: i32,
@@ -4,7 +4,7 @@ use super::{
 };
 use crate::{
     graphics::{Character, ClipArea, LineType, Rect, Size, SpecialChar, Surface, TextAlignment, TextFormatBuilder, WrapType},
-    input::{Key, KeyCode, MouseWheelDirection},
+    input::{Key, KeyCode, KeyMap, MouseWheelDirection},
     prelude::KeyModifier,
     system::{Handle, HandleSupport, RuntimeManager, Theme},
     ui::common::traits::EventProcessStatus,
@@ -614,6 +614,7 @@ impl Menu {
     }
 
     pub(crate) fn on_key_pressed(&mut self, key: Key) -> EventProcessStatus {
+        let (key, _) = KeyMap::translate_key("menu", key, '\0');
         match key.value() {
             key!("Up") | key!("Down") | key!("Home") | key!("End") | key!("PageUp") | key!("PageDown") => {
                 self.move_currentitem_to(key);
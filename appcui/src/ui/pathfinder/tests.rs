@@ -31,6 +31,7 @@ const CSV_DATA: &str = "
     f:D:\\Windows\\melody.mp3,0,2019-03-12 12:31:55,
     ";
 
+#[cfg(target_os = "windows")]
 const CSV_DATA_UNICODE: &str = "
     r,C:\\,10000,100000,SYSTEM,fixed
     r,D:\\,123,123456,USB Drive,removable
@@ -1,5 +1,6 @@
 use super::attribute_text::AttributeText;
 use super::undo::{LastAction, UndoEntry, UndoOp, MAX_UNDO_DEPTH};
+use crate::graphics::unicode;
 use crate::prelude::*;
 use crate::ui::common::{ControlEvent, ControlEventData};
 use crate::ui::textfield::selection::Selection;
@@ -140,28 +141,69 @@ impl RichTextField {
         }
     }
 
+    // lays out the characters (starting from the `start` index) in the view: a wide character that does not fit at the
+    // end of a line is moved on the next line. `f` receives the index, the position and the width of every visible character.
+    // returns the index of the first character that does not fit and the position where it would have been displayed
+    fn layout(&self, start: usize, mut f: impl FnMut(usize, i32, i32)) -> (usize, i32, i32) {
+        let sz = self.size();
+        let right = sz.width as i32 - 1;
+        let h = sz.height as i32;
+        let mut x = 1;
+        let mut y = 0;
+        let mut index = start;
+        while index < self.chars.len() {
+            let w = self.char_width(index) as i32;
+            if (x + w > right) && (x > 1) {
+                x = 1;
+                y += 1;
+            }
+            if (y >= h) || (x + w > right) {
+                break;
+            }
+            f(index, x, y);
+            x += w;
+            index += 1;
+            if x >= right {
+                x = 1;
+                y += 1;
+            }
+        }
+        (index, x, y)
+    }
+
+    #[inline(always)]
+    fn char_width(&self, index: usize) -> usize {
+        self.chars.get(index).map(|ch| unicode::char_width(ch.code).max(1) as usize).unwrap_or(1)
+    }
+
     fn update_scroll_view(&mut self, force_end_update: bool) {
         if (self.cursor.pos >= self.cursor.start) && (self.cursor.pos < self.cursor.end) {
             if force_end_update {
-                let sz = self.size();
-                let visible_glyphs = ((sz.width as usize).saturating_sub(2)) * (sz.height as usize);
-                self.cursor.end = (self.cursor.start + visible_glyphs).min(self.chars.len());
+                self.cursor.end = self.layout(self.cursor.start, |_, _, _| {}).0;
             }
             return;
         }
-        let sz = self.size();
-        let visible_glyphs = (if sz.width > 2 {
-            ((sz.width as usize) - 2) * (sz.height as usize)
-        } else {
-            0
-        })
-        .max(1); 
-
         if self.cursor.pos < self.cursor.start {
             self.cursor.start = self.cursor.pos;
-            self.cursor.end = (self.cursor.start + visible_glyphs).min(self.chars.len());
+            self.cursor.end = self.layout(self.cursor.start, |_, _, _| {}).0;
         } else {
-            self.cursor.start = self.cursor.pos.saturating_sub(visible_glyphs.saturating_sub(1));
+            let sz = self.size();
+            let cells = ((sz.width as usize).saturating_sub(2)) * (sz.height as usize);
+            let mut start = self.cursor.pos;
+            let mut used = self.char_width(start);
+            while (start > 0) && (used + self.char_width(start - 1) <= cells) {
+                used += self.char_width(start - 1);
+                start -= 1;
+            }
+            // wide characters that are moved on the next line leave some cells unused
+            while start < self.cursor.pos {
+                let (end, _, y) = self.layout(start, |_, _, _| {});
+                if (self.cursor.pos < end) || ((self.cursor.pos == self.chars.len()) && (end == self.chars.len()) && (y < sz.height as i32)) {
+                    break;
+                }
+                start += 1;
+            }
+            self.cursor.start = start;
             // Keep the glyph at/after the cursor visible when scrolling right.
             self.cursor.end = (self.cursor.pos + 1).min(self.chars.len());
         }
@@ -519,7 +561,17 @@ impl RichTextField {
         let sz = self.size();
         let w = sz.width as i32;
         let h = sz.height as i32;
-        if within_control && ((x < 1) || (x >= w - 1) || (y < 0) || (y >= h)) {
+        if (x >= 1) && (x < w - 1) && (y >= 0) && (y < h) {
+            // the first character that is displayed on the same position or after it
+            let mut result = None;
+            let (end, _, _) = self.layout(self.cursor.start, |index, cx, cy| {
+                if result.is_none() && ((cy > y) || ((cy == y) && (x < cx + self.char_width(index) as i32))) {
+                    result = Some(index);
+                }
+            });
+            return Some(result.unwrap_or(end));
+        }
+        if within_control {
             return None;
         }
         let glyphs_count = (x - 1) + y * (w - 2);
@@ -614,13 +666,8 @@ impl RichTextField {
 }
 
 impl OnResize for RichTextField {
-    fn on_resize(&mut self, _old_size: Size, new_size: Size) {
-        let visible_chars = if new_size.width > 2 {
-            ((new_size.width - 2) as usize) * (new_size.height as usize)
-        } else {
-            0
-        };
-        self.cursor.end = (self.cursor.start + visible_chars).min(self.chars.len());
+    fn on_resize(&mut self, _old_size: Size, _new_size: Size) {
+        self.cursor.end = self.layout(self.cursor.start, |_, _, _| {}).0;
         self.update_scroll_view(false);
     }
 }
@@ -637,14 +684,9 @@ impl OnPaint for RichTextField {
         let show_cursor = self.has_focus();
         let use_parser = self.parser.is_some() && show_cursor;
         let sz = self.size();
-        let w = (sz.width - 1) as i32;
-        let mut x = 1i32;
-        let mut y = 0i32;
-        let slice_end = self.cursor.end.min(self.chars.len());
-        let slice_start = self.cursor.start.min(slice_end);
-        for (i, ch) in self.chars[slice_start..slice_end].iter().enumerate() {
-            let actual_index = slice_start + i;
-            let char_to_paint = if show_cursor && self.selection.contains(actual_index) {
+        let (slice_end, x, y) = self.layout(self.cursor.start, |index, x, y| {
+            let ch = &self.chars[index];
+            let char_to_paint = if show_cursor && self.selection.contains(index) {
                 Character::with_attributes(ch.code, theme.editor.pressed_or_selected)
             } else if use_parser {
                 *ch
@@ -652,15 +694,10 @@ impl OnPaint for RichTextField {
                 Character::with_attributes(ch.code, attr)
             };
             surface.write_char(x, y, char_to_paint);
-            if show_cursor && actual_index == self.cursor.pos {
+            if show_cursor && index == self.cursor.pos {
                 surface.set_cursor(x, y);
             }
-            x += 1;
-            if x >= w {
-                x = 1;
-                y += 1;
-            }
-        }
+        });
         // Cursor can be exactly at the right edge of the current viewport (`slice_end`),
         // not only at end-of-text. In that case it must still be visible.
         if show_cursor && self.cursor.pos == slice_end {
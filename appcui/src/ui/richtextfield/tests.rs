@@ -100,29 +100,29 @@ fn check_select_left_right() {
         // pressed Right arrow to remove selection
         Key.Pressed(Right)
         Paint('cursor at end')   
-        CheckHash(0x118C7686A80A8D9E)
-        CheckCursor(24,3)
+        CheckHash(0x57CF0626AC8BD9A9)
+        CheckCursor(25,3)
         Key.Pressed(Shift+Left,4)
         Paint('last 4 chars selected:  123❤╬▶-|〓GDT| ')   
-        CheckHash(0x8AE976432AC0CA2E)
+        CheckHash(0x95C5BACFA499876A)
         CheckCursor(20,3)
         Key.Pressed(Home)
         Paint('No selection, cursor on first character')   
-        CheckHash(0x118C7686A80A8D9E)
+        CheckHash(0x57CF0626AC8BD9A9)
         CheckCursor(13,3)
         Key.Pressed(Shift+Right,5)
         Paint('First 5 characters selected')   
-        CheckHash(0xA4D17EB2D03D98B5)
+        CheckHash(0xFD6872B857F5B7A6)
         CheckCursor(18,3)
         Key.Pressed(Right)
         Key.Pressed(Shift+End)
         Paint('Last 5 characters selectedL 123❤╬▶|-〓GDT|')   
-        CheckHash(0xABDC8B8ADDB65A89)
-        CheckCursor(24,3)
+        CheckHash(0xCBEE379AC347D749)
+        CheckCursor(25,3)
         Key.Pressed(Left,3)
         Key.Pressed(Shift+Home)
         Paint('All but last 3 chars selected |123❤╬▶-〓|GDT')   
-        CheckHash(0xDCE11FF9FF553196)
+        CheckHash(0xE5A760160FAB2CF6)
         CheckCursor(13,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -270,7 +270,7 @@ fn check_home_end() {
         // pressed Right arrow to remove selection
         Key.Pressed(Right)
         Paint('Text:  ▶-〓 world|| (initial state - cursor is last)')   
-        CheckHash(0xB5F2856A17C1B50D)
+        CheckHash(0xE99C9F65970B845B)
         CheckCursor(22,3)
         Key.Pressed(Home)
         Paint('Text: |H|ello |❤|╬▶-')   
@@ -278,7 +278,7 @@ fn check_home_end() {
         CheckCursor(13,3)
         Key.Pressed(End)
         Paint('Text:  ▶-〓 world||')   
-        CheckHash(0xB5F2856A17C1B50D)
+        CheckHash(0xE99C9F65970B845B)
         CheckCursor(22,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -302,19 +302,19 @@ fn check_delete() {
         CheckCursor(17,3)
         Key.Pressed(Delete)
         Paint('Show: Hell| |❤╬▶-〓')   
-        CheckHash(0x9D7057333DEA1294)
+        CheckHash(0x8A7EFCCF1A9B2AAF)
         CheckCursor(17,3)
         Key.Pressed(Delete)
         Paint('Show: Hell|❤|╬▶-〓 ')   
-        CheckHash(0x21EE5121805EEE60)
+        CheckHash(0x15BA52AFE7FCFD33)
         CheckCursor(17,3)
         Key.Pressed(Delete)
         Paint('Show: Hell|╬|▶-〓 w')   
-        CheckHash(0xE17209AE4688CF0A)
+        CheckHash(0x2FC3E023301EFE2A)
         CheckCursor(17,3)
         Key.Pressed(Delete)
         Paint('Show: Hell|▶|-〓 wo')   
-        CheckHash(0xD496D44D8168F24E)
+        CheckHash(0x1C80B24EFD09380E)
         CheckCursor(17,3)
         Key.Pressed(Delete,3)
         Paint('Show: Hell world')   
@@ -359,15 +359,15 @@ fn check_delete_from_end() {
         Paint.Enable(false)
         Key.Pressed(Left)
         Paint('Text: ▶-〓 worl|d|')   
-        CheckHash(0xB5F2856A17C1B50D)
+        CheckHash(0xE99C9F65970B845B)
         CheckCursor(21,3)
         Key.Pressed(Delete)
         Paint('Text: ╬▶-〓 worl, cursor last')   
-        CheckHash(0x57F85A60FF685391)
+        CheckHash(0x383E998172EEB89F)
         CheckCursor(21,3)
         Key.Pressed(Delete,10)
         Paint('Text: ╬▶-〓 worl, cursor last, nothing changes')   
-        CheckHash(0x57F85A60FF685391)
+        CheckHash(0x383E998172EEB89F)
         CheckCursor(21,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -389,7 +389,7 @@ fn check_delete_after_selection() {
         CheckCursor(19,3)
         Key.Pressed(Delete)
         Paint('Text:  He|❤|╬▶-〓 wo')   
-        CheckHash(0xE80B6D9DE88C7B68)
+        CheckHash(0x7A9F9D7568B9868C)
         CheckCursor(15,3)
         Key.Pressed(Delete,10)
         Paint('Text: He|d|')   
@@ -414,35 +414,35 @@ fn check_backspace() {
         CheckCursor(16,3)
         Key.Pressed(Backspace)
         Paint('Text:  He|l|o ❤╬▶-〓')   
-        CheckHash(0xF711197C690A2243)
+        CheckHash(0x51A5C471806B4A58)
         CheckCursor(15,3)
         Key.Pressed(Backspace)
         Paint('Text:  H|l|o ❤╬▶-〓 ')   
-        CheckHash(0x218C39E1236E15A6)
+        CheckHash(0x2F146D59D27B9EF9)
         CheckCursor(14,3)
         Key.Pressed(Backspace)
         Paint('Text:  |l|o ❤╬▶-〓 w')   
-        CheckHash(0xDF2768238E2F92D5)
+        CheckHash(0xA623AE247DC1CC01)
         CheckCursor(13,3)
         Key.Pressed(Backspace,10)
         Paint('Text:  |l|o ❤╬▶-〓 w => nothing changes')   
-        CheckHash(0xDF2768238E2F92D5)
+        CheckHash(0xA623AE247DC1CC01)
         CheckCursor(13,3)
         Key.Pressed(Right,5)
         Paint('Text: lo ❤╬|▶|-〓 w')   
-        CheckHash(0xDF2768238E2F92D5)
+        CheckHash(0xA623AE247DC1CC01)
         CheckCursor(18,3)
         Key.Pressed(Backspace)
         Paint('Text: lo ❤|▶|-〓 wo')   
-        CheckHash(0x29812F27AF27CF21)
+        CheckHash(0xBAF19DAAE096B2D)
         CheckCursor(17,3)
         Key.Pressed(Backspace)
         Paint('Text: lo |▶|-〓 wor')   
-        CheckHash(0xF757EDD3740156BE)
+        CheckHash(0x6FFDE8ABD9FF76DF)
         CheckCursor(16,3)
         Key.Pressed(End)
         Paint('Text: ▶-〓 world||')   
-        CheckHash(0xB5F2856A17C1B50D)
+        CheckHash(0xE99C9F65970B845B)
         CheckCursor(22,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -459,40 +459,40 @@ fn check_backspace_from_end() {
         // pressed Right arrow to remove selection
         Key.Pressed(Right)
         Paint('1. Text: ▶-〓 world||')   
-        CheckHash(0xB5F2856A17C1B50D)
+        CheckHash(0xE99C9F65970B845B)
         CheckCursor(22,3)
         Key.Pressed(Backspace)
         Paint('2.Text: ▶-〓 worl||')   
-        CheckHash(0x57F85A60FF685391)
+        CheckHash(0x383E998172EEB89F)
         CheckCursor(21,3)
         Key.Pressed(Backspace,5)
         Paint('3.Text: ▶-〓||')   
-        CheckHash(0xDCF04CF5A595D7C3)
+        CheckHash(0x121F00676023A065)
         CheckCursor(16,3)
         Key.Pressed(Left,7)
         Paint('4.Text: o ❤╬▶-〓')   
-        CheckHash(0xEDBA26DAAFF5F06A)
+        CheckHash(0x445B1279262E0FD)
         CheckCursor(13,3)
         Key.Pressed(End)
         Paint('5.Text: llo ❤╬▶-〓||')   
-        CheckHash(0x777BAF7290F92F2A)
+        CheckHash(0xA623AE247DC1CC01)
         CheckCursor(22,3)
         Key.Pressed(Backspace,3)
         Paint('6.Text: llo ❤╬||')   
-        CheckHash(0x1CCCA3758557EAD9)
-        CheckCursor(19,3)
+        CheckHash(0x37901BEB0E40E49)
+        CheckCursor(18,3)
         Key.Pressed(Backspace,3)
         Paint('7.Text: llo||')   
-        CheckHash(0x120F4BA0A2FAD8A3)
-        CheckCursor(16,3)
-        Key.Pressed(Backspace)
-        Paint('8.Text: ll||')   
-        CheckHash(0xD37A8CFC9107AD14)
+        CheckHash(0x206DC85DA4F8978F)
         CheckCursor(15,3)
         Key.Pressed(Backspace)
-        Paint('9.Text: l||')   
+        Paint('8.Text: ll||')   
         CheckHash(0x6F226838BB58E638)
         CheckCursor(14,3)
+        Key.Pressed(Backspace)
+        Paint('9.Text: l||')   
+        CheckHash(0xA4EDA87645FBF114)
+        CheckCursor(13,3)
         Key.Pressed(Home)
         Paint('10.Text: |H|el')   
         CheckHash(0xD8844B5C8926B539)
@@ -537,11 +537,11 @@ fn check_backspace_after_selection() {
         CheckCursor(19,3)
         Key.Pressed(Backspace)
         Paint('Text:  He|❤|╬▶-〓 wo')   
-        CheckHash(0xE80B6D9DE88C7B68)
+        CheckHash(0x7A9F9D7568B9868C)
         CheckCursor(15,3)
         Key.Pressed(Backspace,2)
         Paint('Text: ❤╬▶-〓 worl')   
-        CheckHash(0xDA69921E3679D663)
+        CheckHash(0x8EE6F3D2560CA6CC)
         CheckCursor(13,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -603,7 +603,7 @@ fn check_write_unicode_text() {
         CheckCursor(18,3)
         Key.TypeText('〓rl❤')
         Paint('4.Text is: ❤〓rl❤')   
-        CheckHash(0x3CCD819EB95146CD)
+        CheckHash(0xE001B04D4387868B)
         CheckCursor(18,3)
         Key.Pressed(Home)
         Paint('5.Text is: Hello❤')   
@@ -611,7 +611,7 @@ fn check_write_unicode_text() {
         CheckCursor(13,3)
         Key.Pressed(End)
         Paint('6.Text is: ❤〓rl❤')   
-        CheckHash(0x3CCD819EB95146CD)
+        CheckHash(0xE001B04D4387868B)
         CheckCursor(18,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -686,23 +686,23 @@ fn check_write_multiline_text() {
         CheckCursor(13,5)
         Key.TypeText('〓rl❤')
         Paint('4.Text is: Hello_word❤❤〓rl❤')   
-        CheckHash(0x770216FAF138E89A)
-        CheckCursor(17,5)
+        CheckHash(0xE023E0A76D473EA9)
+        CheckCursor(18,5)
         Key.TypeText('❤')
         Paint('5.Text is: Hello_word❤❤〓rl❤❤')   
-        CheckHash(0x2B30AC2B28C6526B)
-        CheckCursor(18,5)
+        CheckHash(0x84937B4C4709AB59)
+        CheckCursor(17,5)
         Key.TypeText('❤')
         Paint('6.Text is: ello_word❤❤〓rl❤❤❤')   
-        CheckHash(0x2476BC2CC3EF5C9E)
+        CheckHash(0xEB9CE41D73FFF4FC)
         CheckCursor(18,5)
         Key.TypeText('12345')
         Paint('7.Text is: word❤❤〓rl❤❤❤12345')   
-        CheckHash(0x581C58B9161C94E2)
-        CheckCursor(18,5)
+        CheckHash(0x1658C451EC642C21)
+        CheckCursor(17,5)
         Key.Pressed(Home)
         Paint('8.Text is: Hello_word❤❤〓rl❤❤❤')   
-        CheckHash(0xE60DAD19F94F1D86)
+        CheckHash(0xD1ABE3B1E4E03350)
         CheckCursor(13,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -731,8 +731,8 @@ fn check_insert_text() {
         CheckCursor(15,3)
         Key.TypeText('〓rl❤❤❤')
         Paint('4.Text: He〓rl❤❤❤|l|l')   
-        CheckHash(0x9A47A09DA4CFEA69)
-        CheckCursor(21,3)
+        CheckHash(0x5C7D3D6B6DDBE682)
+        CheckCursor(22,3)
         Key.Pressed(End)
         Paint('4.Text: llo world||')   
         CheckHash(0x13D74C30632D286D)
@@ -760,23 +760,23 @@ fn check_insert_text_over_selection() {
         CheckCursor(19,3)
         Key.TypeText('〓❤❤❤')
         Paint('3.Text replaces (He〓❤❤❤|w|orl)')   
-        CheckHash(0x2D25F2AE8CA02B9D)
-        CheckCursor(19,3)
+        CheckHash(0x7ABF0D099CDB7E36)
+        CheckCursor(20,3)
         Key.Pressed(Right)
         Paint('4.Move cursor to right (He〓❤❤❤w|o|rl)')   
-        CheckHash(0x2D25F2AE8CA02B9D)
-        CheckCursor(20,3)
+        CheckHash(0x7ABF0D099CDB7E36)
+        CheckCursor(21,3)
         Key.Pressed(Right)
         Paint('5.Move cursor to right (He〓❤❤❤wo|r|l)')   
-        CheckHash(0x2D25F2AE8CA02B9D)
-        CheckCursor(21,3)
+        CheckHash(0x7ABF0D099CDB7E36)
+        CheckCursor(22,3)
         Key.Pressed(Right)
         Paint('6.Move cursor to right (He〓❤❤❤wor|l|)')   
-        CheckHash(0x2D25F2AE8CA02B9D)
+        CheckHash(0xA412ECB57D763AA)
         CheckCursor(22,3)
         Key.Pressed(Right)
         Paint('7.Move cursor to right (e〓❤❤❤worl|d|)')   
-        CheckHash(0x3D405AF32EA72405)
+        CheckHash(0x8813DDC098D90317)
         CheckCursor(22,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -792,72 +792,72 @@ fn check_move_to_next_word() {
         Paint.Enable(false)
         Key.Pressed(Home)
         Paint('1.Visible:  |H|ello   world,〓❤,123,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
+        CheckHash(0x96E5E8438CA81A67)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Right)
         Paint('2.Visible:  Hello   |w|orld,〓❤,123,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
+        CheckHash(0x96E5E8438CA81A67)
         CheckCursor(21,3)
         Key.Pressed(Left,2)
         Paint('3.Visible:  Hello | | world,〓❤,123,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
+        CheckHash(0x96E5E8438CA81A67)
         CheckCursor(19,3)
         Key.Pressed(Ctrl+Right)
         Paint('4.Visible:  Hello   |w|orld,〓❤,123,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
+        CheckHash(0x96E5E8438CA81A67)
         CheckCursor(21,3)
         Key.Pressed(Ctrl+Right)
         Paint('5.Visible:  Hello   world|,|〓❤,123,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
+        CheckHash(0x96E5E8438CA81A67)
         CheckCursor(26,3)
         Key.Pressed(Ctrl+Right)
         Paint('6.Visible:  Hello   world,|〓|❤,123,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
+        CheckHash(0x96E5E8438CA81A67)
         CheckCursor(27,3)
         Key.Pressed(Ctrl+Right)
         Paint('7.Visible:  Hello   world,〓❤|,|123,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
-        CheckCursor(29,3)
+        CheckHash(0x96E5E8438CA81A67)
+        CheckCursor(30,3)
         Key.Pressed(Ctrl+Right)
         Paint('8.Visible:  Hello   world,〓❤,|1|23,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
-        CheckCursor(30,3)
+        CheckHash(0x96E5E8438CA81A67)
+        CheckCursor(31,3)
         Key.Pressed(Ctrl+Right)
         Paint('9.Visible:  Hello   world,〓❤,123|,|  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
-        CheckCursor(33,3)
+        CheckHash(0x96E5E8438CA81A67)
+        CheckCursor(34,3)
         Key.Pressed(Ctrl+Right)
         Paint('10.Visible:  Hello   world,〓❤,123,  |t|est.set')   
-        CheckHash(0x2D7A431271FF0AE0)
-        CheckCursor(36,3)
+        CheckHash(0x96E5E8438CA81A67)
+        CheckCursor(37,3)
         Key.Pressed(Ctrl+Right)
         Paint('11.Visible:  Hello   world,〓❤,123,  test|.|set')   
-        CheckHash(0x2D7A431271FF0AE0)
-        CheckCursor(40,3)
+        CheckHash(0x96E5E8438CA81A67)
+        CheckCursor(41,3)
         Key.Pressed(Ctrl+Right)
         Paint('12.Visible:  Hello   world,〓❤,123,  test.|s|et')   
-        CheckHash(0x2D7A431271FF0AE0)
-        CheckCursor(41,3)
+        CheckHash(0x96E5E8438CA81A67)
+        CheckCursor(42,3)
         Key.Pressed(Ctrl+Right)
         Paint('13.Visible:  lo   world,〓❤,123,  test.set    |u|')   
-        CheckHash(0xE5B31249CF6A1A98)
+        CheckHash(0x62385338DE11E357)
         CheckCursor(45,3)
         Key.Pressed(Right,2)
         Paint('14.Visible:   world,〓❤,123,  test.set    un|i|')   
-        CheckHash(0xC78D0EC4D48FBC30)
+        CheckHash(0x7206E1306311348F)
         CheckCursor(45,3)
         Key.Pressed(Ctrl+Right)
         Paint('15.Visible:  ,〓❤,123,  test.set    uni〓code  |t|')   
-        CheckHash(0x1F540C31A3C9129C)
-        CheckCursor(45,3)
+        CheckHash(0xBC68F25EE0EB3C9B)
+        CheckCursor(44,3)
         Key.Pressed(Ctrl+Right)
         // reach the end of the text
         Paint('16.Visible:   test.set    uni〓code  twice   ')   
-        CheckHash(0x188AC9EB95C89126)
+        CheckHash(0x48B5BF4651A5EFB1)
         CheckCursor(45,3)
         Key.Pressed(Ctrl+Right)
         Paint('17.Visible:   test.set    uni〓code  twice   (nothing happens)')   
-        CheckHash(0x188AC9EB95C89126)
+        CheckHash(0x48B5BF4651A5EFB1)
         CheckCursor(45,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -873,72 +873,72 @@ fn check_move_to_previous_word() {
         Paint.Enable(false)
         Key.Pressed(End)
         Paint('1.Visible:    test.set    uni〓code  twice    ||')   
-        CheckHash(0x188AC9EB95C89126)
+        CheckHash(0x48B5BF4651A5EFB1)
         CheckCursor(45,3)
         Key.Pressed(Ctrl+Left)
         Paint('2.Visible:   test.set    uni〓code  |t|wice    ')   
-        CheckHash(0x188AC9EB95C89126)
+        CheckHash(0x48B5BF4651A5EFB1)
         CheckCursor(37,3)
         Key.Pressed(Left,1)
         Paint('3.Visible:   test.set    uni〓code  | |twice    ')   
-        CheckHash(0x188AC9EB95C89126)
+        CheckHash(0x48B5BF4651A5EFB1)
         CheckCursor(36,3)
         Key.Pressed(Ctrl+Left)
         Paint('4.Visible:   test.set    |u|ni〓code  twice   ')   
-        CheckHash(0x188AC9EB95C89126)
-        CheckCursor(27,3)
+        CheckHash(0x48B5BF4651A5EFB1)
+        CheckCursor(26,3)
         Key.Pressed(Ctrl+Left)
         Paint('5.Visible:   test.|s|et    uni〓code  twice   ')   
-        CheckHash(0x188AC9EB95C89126)
-        CheckCursor(20,3)
+        CheckHash(0x48B5BF4651A5EFB1)
+        CheckCursor(19,3)
         Key.Pressed(Ctrl+Left)
         Paint('6.Visible:   test|.|set    uni〓code  twice   ')   
-        CheckHash(0x188AC9EB95C89126)
-        CheckCursor(19,3)
+        CheckHash(0x48B5BF4651A5EFB1)
+        CheckCursor(18,3)
         Key.Pressed(Ctrl+Left)
         Paint('7.Visible:  |t|est.set    uni〓code  twice   ')   
-        CheckHash(0x188AC9EB95C89126)
-        CheckCursor(15,3)
+        CheckHash(0x48B5BF4651A5EFB1)
+        CheckCursor(14,3)
         Key.Pressed(Ctrl+Left)
         Paint('8.Visible:  |,|  test.set    uni〓code  twice   ')   
-        CheckHash(0x85C9E5D26DF4B442)
+        CheckHash(0x7564A362389F7A6D)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Left)
         Paint('9.Visible:  |1|23,  test.set    uni〓code  twicet')   
-        CheckHash(0x1F31A3D060CF832)
+        CheckHash(0xAF78B0C16F295FBC)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Left)
         Paint('10.Visible: |,|123,  test.set    uni〓code  twic')   
-        CheckHash(0xC9CD5299AA782443)
+        CheckHash(0xC02674677FC1E06F)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Left)
         Paint('11.Visible:  |〓|❤,123,  test.set    uni〓code  tw')   
-        CheckHash(0xD8427A56B47AB583)
+        CheckHash(0xEEE0398573439AD4)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Left)
         Paint('12.Visible:  |,|〓❤,123,  test.set    uni〓code  t')   
-        CheckHash(0x1F540C31A3C9129C)
+        CheckHash(0xBD480861E3E700C)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Left)
         Paint('13.Visible:  |w|orld,〓❤,123,  test.set    uni〓co')   
-        CheckHash(0x39319158125831AB)
+        CheckHash(0x5849AE4067791A1B)
         CheckCursor(13,3)
         Key.Pressed(Left,2)
         Paint('14.Visible: || world,〓❤,123,  test.set    uni〓')   
-        CheckHash(0x7B07EF88A003B02F)
+        CheckHash(0x7206E1306311348F)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Left)
         Paint('15.Visible:  |H|ello   world,〓❤,123,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
+        CheckHash(0x96E5E8438CA81A67)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Left)
         // now we reach the start of the test
         Paint('16.Visible: ||   Hello   world,〓❤,123,  test.set')   
-        CheckHash(0xC7E06F2FFB5E0060)
+        CheckHash(0x550FBBC3D5939B1B)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Left)
         Paint('17.Visible: ||   Hello   world,〓❤,123,  test.set (nothing happens)')   
-        CheckHash(0xC7E06F2FFB5E0060)
+        CheckHash(0x550FBBC3D5939B1B)
         CheckCursor(13,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -2,7 +2,7 @@ use crate::prelude::*;
 use crate::ui::tab::{Flags, Type};
 use super::events::EventData;
 
-#[CustomControl(overwrite=OnPaint+OnMouseEvent+OnKeyPressed, internal=true)]
+#[CustomControl(overwrite=OnPaint+OnMouseEvent+OnKeyPressed+PersistState, internal=true)]
 pub struct Tab {
     tab_type: Type,
     flags: Flags,
@@ -413,7 +413,8 @@ impl OnMouseEvent for Tab {
 }
 impl OnKeyPressed for Tab {
     fn on_key_pressed(&mut self, key: Key, _character: char) -> EventProcessStatus {
-        match key.value() {
+        let (translated, _) = KeyMap::translate_key("tab", key, '\0');
+        match translated.value() {
             key!("Ctrl+Tab") => {
                 let mut idx = self.base.focused_child_index;
                 idx.add(1, self.base.children.len(), Strategy::RotateFromInvalidState);
@@ -440,3 +441,13 @@ impl OnKeyPressed for Tab {
         EventProcessStatus::Ignored
     }
 }
+impl PersistState for Tab {
+    fn save_state(&self) -> Option<String> {
+        self.current_tab().map(|index| index.to_string())
+    }
+    fn restore_state(&mut self, state: &str) {
+        if let Ok(index) = state.parse::<usize>() {
+            self.set_current_tab(index);
+        }
+    }
+}
@@ -2963,8 +2963,8 @@ fn delete_selection_after_cjk_cursor_column() {
         Key.Pressed(Shift+Right, 2)
         Key.Pressed(Delete)
         Paint('After deleting xx')
-        CheckCursor(3, 1)
-        CheckHash(0x147C2B824033A0FF)
+        CheckCursor(5, 1)
+        CheckHash(0xFB9C3993582DA483)
     ";
 
     let textarea = TextArea::new("你你xx", layout!("d:f"), textarea::Flags::None);
@@ -2983,8 +2983,8 @@ fn paint_horizontal_scroll_multibyte_uses_char_columns() {
         Paint.Enable(false)
         Key.Pressed(Right, 20)
         Paint('Scrolled past window over CJK')
-        CheckCursor(18, 1)
-        CheckHash(0x9D9531C91F91044)
+        CheckCursor(17, 1)
+        CheckHash(0x5AA6521BC8727356)
     ";
 
     let text_print = "你".repeat(40);
@@ -1,5 +1,6 @@
 use super::initialization_flags::Flags;
 
+use crate::graphics::unicode;
 use crate::prelude::*;
 use std::{cmp::Ordering, fmt, mem::swap};
 
@@ -148,53 +149,90 @@ impl TextArea {
         result
     }
 
-    fn get_current_character(bytes: &[u8], position: usize) -> Option<(usize, char)> {
-        if position >= bytes.len() {
-            return None; // Out of bounds
+    // returns the end of the grapheme cluster that starts at `position` and its first character
+    fn get_current_character(text: &str, position: usize) -> Option<(usize, char)> {
+        let ch = text.get(position..)?.chars().next()?;
+        Some((unicode::next_grapheme_boundary(text, position), ch))
+    }
+    // returns the start of the grapheme cluster that ends at `position` and its first character
+    fn get_previous_character(text: &str, position: usize) -> Option<(usize, char)> {
+        if position == 0 || position > text.len() {
+            return None;
         }
-    
-        let first_byte = bytes[position];
-    
-        // Determine the number of bytes in the character
-        let char_len = match first_byte {
-            0x00..=0x7F => 1, // 1-byte (ASCII)
-            0xC2..=0xDF => 2, // 2-byte character
-            0xE0..=0xEF => 3, // 3-byte character
-            0xF0..=0xF4 => 4, // 4-byte character
-            _ => return None, // Invalid UTF-8 sequence start
-        };
-    
-        if position + char_len > bytes.len() {
-            return None; // Avoid out-of-bounds access
+        let start = unicode::previous_grapheme_boundary(text, position);
+        text[start..].chars().next().map(|c| (start, c))
+    }
+    // the number of columns (grapheme clusters) of a text
+    #[inline(always)]
+    fn column_count(text: &str) -> u32 {
+        unicode::graphemes(text).count() as u32
+    }
+    // recomputes the number of columns of a line (a character might have been combined with its neighbours)
+    fn recount_line(&mut self, line_index: usize) {
+        let start: usize = self.line_sizes[..line_index].iter().map(|v| *v as usize).sum();
+        let end = (start + self.line_sizes[line_index] as usize).min(self.text.len());
+        self.line_character_counts[line_index] = Self::column_count(&self.text[start..end]);
+    }
+    // the line (including the new line character) where the cursor is located
+    fn cursor_line_text(&self) -> &str {
+        let line_index = self.line_offset as usize + self.cursor.pos_y;
+        if line_index >= self.line_sizes.len() {
+            return "";
+        }
+        let start: usize = self.line_sizes[..line_index].iter().map(|v| *v as usize).sum();
+        let end = (start + self.line_sizes[line_index] as usize).min(self.text.len());
+        &self.text[start..end]
+    }
+    // the width (in cells) of a column (1 for the new line character and for the end of the text)
+    #[inline(always)]
+    fn column_width(grapheme: Option<&str>) -> u32 {
+        match grapheme {
+            Some(g) if !g.starts_with('\n') => unicode::grapheme_width(g),
+            _ => 1,
         }
-    
-        // Extract the UTF-8 character
-        std::str::from_utf8(&bytes[position..position + char_len])
-            .ok()
-            .and_then(|s| s.chars().next().map(|c| (position + char_len, c)))
     }
-    fn get_previous_character(bytes: &[u8], position: usize) -> Option<(usize, char)> {
-        if position == 0 {
-            return None;
+    // the cursor column is in view, but wide characters might push it outside of the visible area
+    fn fit_cursor_in_view(&mut self) {
+        let row_offset = self.row_offset as usize;
+        let cursor_column = row_offset + self.cursor.pos_x;
+        let widths: Vec<u32> = {
+            let mut graphemes = unicode::graphemes(self.cursor_line_text()).skip(row_offset);
+            (row_offset..=cursor_column).map(|_| Self::column_width(graphemes.next())).collect()
+        };
+        let mut used: u32 = widths.iter().sum();
+        let mut index = 0;
+        while (used > self.window_width) && (self.cursor.pos_x > 0) {
+            used -= widths[index];
+            index += 1;
+            self.row_offset += 1;
+            self.cursor.pos_x -= 1;
         }
-
-        let mut start = position - 1;
-
-        while start > 0 && (bytes[start] & 0xC0) == 0x80 {
-            start -= 1;
+    }
+    // the horizontal position (in cells, relative to the text area) of the cursor
+    fn cursor_cell_x(&self) -> i32 {
+        let mut graphemes = unicode::graphemes(self.cursor_line_text()).skip(self.row_offset as usize);
+        (0..self.cursor.pos_x).map(|_| Self::column_width(graphemes.next()) as i32).sum()
+    }
+    // the column (relative to the row offset) that is displayed on a specific cell of the cursor line
+    fn column_from_cell(&self, cell: usize) -> usize {
+        let mut x = 0;
+        for (column, grapheme) in unicode::graphemes(self.cursor_line_text()).skip(self.row_offset as usize).enumerate() {
+            x += Self::column_width(Some(grapheme)) as usize;
+            if x > cell {
+                return column;
+            }
         }
-
-        std::str::from_utf8(&bytes[start..position]).ok().and_then(|s| s.chars().next().map(|c| (start, c)))
+        cell
     }
     fn get_start_of_word(&mut self, absolute_position_start: usize) -> (usize, i32) {
 
         let mut current_position = absolute_position_start;
-        let bytes = self.text.as_bytes();
+        let text = self.text.as_str();
 
         let mut previous_type = CharacterType::None;
         let mut character_count = 0;
 
-        while let Some((prev_pos, prev_char)) = Self::get_previous_character(bytes, current_position) {
+        while let Some((prev_pos, prev_char)) = Self::get_previous_character(text, current_position) {
             if prev_char.is_alphanumeric() {
                 if previous_type == CharacterType::None {
                     previous_type = CharacterType::AlphaNumeric;
@@ -219,12 +257,12 @@ impl TextArea {
     fn get_end_of_word(&mut self, absolute_position_start: usize) -> (usize, i32) {
 
         let mut current_position = absolute_position_start;
-        let bytes = self.text.as_bytes();
+        let text = self.text.as_str();
 
         let mut previous_type = CharacterType::None;
         let mut character_count = 0;
 
-        while let Some((prev_pos, prev_char)) = Self::get_current_character(bytes, current_position) {
+        while let Some((prev_pos, prev_char)) = Self::get_current_character(text, current_position) {
             if prev_char.is_alphanumeric() {
                 if previous_type == CharacterType::None {
                     previous_type = CharacterType::AlphaNumeric;
@@ -492,6 +530,7 @@ impl TextArea {
             }
             self.cursor.pos_x = (new_position as usize).saturating_sub(self.row_offset as usize);
         }
+        self.fit_cursor_in_view();
         
         self.update_scrollbar_pos();
     }
@@ -499,11 +538,11 @@ impl TextArea {
     fn get_cursor_position_in_line(line_text: &str, row_position: usize, linebar_size: usize) -> usize {
         let cursor_offset = row_position - linebar_size;
 
-        let char_index = line_text.char_indices().nth(cursor_offset).map(|(i, _)| i);
+        let char_index = unicode::grapheme_indices(line_text).nth(cursor_offset).map(|(i, _)| i);
         if let Some(bytes_index) = char_index {
             bytes_index
         }
-        else if line_text.chars().count() == cursor_offset {
+        else if Self::column_count(line_text) as usize == cursor_offset {
             // If the cursor is at the end of the line, we return the length of the line
             line_text.len()
         }
@@ -602,7 +641,7 @@ impl TextArea {
     fn parse_text_in_lines(&mut self, text: &str, line_sizes: &mut Vec<u32>, line_character_counts: &mut Vec<u32>) {
         for line in text.lines() {
             line_sizes.push(line.len() as u32 + 1); // +1 for the \n we need to keep in mind
-            line_character_counts.push(line.chars().count() as u32 + 1); // +1 for the \n we need to keep in mind
+            line_character_counts.push(Self::column_count(line) + 1); // +1 for the \n we need to keep in mind
         }
 
         if text.ends_with("\n") {
@@ -756,7 +795,7 @@ impl TextArea {
 
         for line in control.text.lines() {
             control.line_sizes.push(line.len() as u32 + 1); // +1 for the \n we need to keep in mind
-            control.line_character_counts.push(line.chars().count() as u32 + 1); // +1 for the \n we need to keep in mind
+            control.line_character_counts.push(Self::column_count(line) + 1); // +1 for the \n we need to keep in mind
         }
 
         if original_ends_with_newline {
@@ -894,6 +933,7 @@ impl TextArea {
             }
 
             self.text.drain(cursor_absolute_position_new..cursor_absolute_position_initial);
+            self.recount_line(self.line_offset as usize + self.cursor.pos_y);
             
             log!("Info", "Text after deletion: {}", self.text);
             
@@ -993,7 +1033,7 @@ impl TextArea {
             self.line_sizes[position_start_y] -= (position_end_x - position_start_x) as u32;
 
             // Update the character count for the line
-            let number_chars = self.text[pos_start..pos_end].chars().count() as u32;
+            let number_chars = Self::column_count(&self.text[pos_start..pos_end]);
             self.line_character_counts[position_start_y] -= number_chars;
         }
         // The deletion request is multiline
@@ -1014,10 +1054,10 @@ impl TextArea {
             // Update the character count for the lines
 
             // Cut the first line to 0..position_start_x, converted to chars
-            self.line_character_counts[position_start_y] = self.text[start_line_position as usize..(start_line_position as usize + position_start_x)].chars().count() as u32;
+            self.line_character_counts[position_start_y] = Self::column_count(&self.text[start_line_position as usize..(start_line_position as usize + position_start_x)]);
 
             // Cut the last line to [position_end_x..]
-            self.line_character_counts[position_end_y] -= self.text[end_line_position as usize..(end_line_position as usize + position_end_x)].chars().count() as u32;
+            self.line_character_counts[position_end_y] -= Self::column_count(&self.text[end_line_position as usize..(end_line_position as usize + position_end_x)]);
 
             // Remove the lines in between
             self.line_character_counts.drain(position_start_y + 1..position_end_y);
@@ -1028,12 +1068,13 @@ impl TextArea {
         }
         // Remove the selected text
         self.text.drain(pos_start..pos_end);
+        self.recount_line(position_start_y);
         
         // Reposition the cursor to the start of the selection.
         // position_start_x is a byte offset within the line (used against line_sizes);
         // cursor.pos_x and row_offset are character columns.
         let line_start_byte = pos_start - position_start_x;
-        let position_start_char_x = self.text[line_start_byte..pos_start].chars().count();
+        let position_start_char_x = Self::column_count(&self.text[line_start_byte..pos_start]) as usize;
         
         log!("Info", "Starting position: ({}, {})", position_start_x, position_start_y);
         log!("Info", "Cursor position: ({}, {})", self.cursor.pos_x, self.cursor.pos_y);
@@ -1082,6 +1123,7 @@ impl TextArea {
     fn insert_text_internal(&mut self, text: &str) -> usize {
         let column_in_line = self.row_offset + self.cursor.pos_x as u32;
         let _text = Self::expand_tabs_to_spaces(text, column_in_line);
+        let mut inserted_columns = Self::column_count(&_text) as usize;
 
         if _text.contains('\n') {
             // We need to calculate the absolute position in the text for the cursor and the position in line
@@ -1119,15 +1161,18 @@ impl TextArea {
             self.text.insert_str(cursor_absolute_position as usize, &_text);
 
             self.line_sizes[line_index] += _text.len() as u32;
-            self.line_character_counts[line_index] += _text.chars().count() as u32;
+            let previous_count = self.line_character_counts[line_index];
+            self.recount_line(line_index);
+            // the inserted characters might be combined with the existing ones (e.g. combining marks)
+            inserted_columns = self.line_character_counts[line_index].saturating_sub(previous_count) as usize;
 
-            log!("Info", "Moving cursor by {} horizontal", _text.chars().count());
+            log!("Info", "Moving cursor by {} horizontal", inserted_columns);
         }
 
         self.update_max_line_size();
         self.update_line_number_tab_size();
         self.update_scrollbar_pos();
-        _text.chars().count()
+        inserted_columns
     }
 
     #[inline(always)]
@@ -1199,11 +1244,13 @@ impl TextArea {
     fn set_cursor_pos_from_mouse(&mut self, mouse_data: &MouseEventData) {
         self.save_mouse_data(mouse_data);
 
-        self.cursor.pos_x = self.mouse_x as usize;
+        let mut cell = self.mouse_x as usize;
         if self.flags.contains(Flags::ShowLineNumber) {
-            self.cursor.pos_x = self.cursor.pos_x.saturating_sub(self.line_number_bar_size as usize);
+            cell = cell.saturating_sub(self.line_number_bar_size as usize);
         }
         self.cursor.pos_y = self.mouse_y as usize;
+        // wide characters use two cells
+        self.cursor.pos_x = self.column_from_cell(cell);
 
         if self.line_sizes.is_empty() {
             return;
@@ -1478,35 +1525,35 @@ impl OnPaint for TextArea {
                     let current_line_view = current_line;
                 
                     let mut counter = x as usize;
-                    // row_offset is a character-column; char_indices() yields byte offsets
-                    for (char_col, (byte_idx, ch)) in current_line_view.char_indices().enumerate() {
+                    // row_offset is a column (a grapheme cluster); grapheme_indices() yields byte offsets
+                    for (char_col, (byte_idx, grapheme)) in unicode::grapheme_indices(current_line_view).enumerate() {
                         if char_col < self.row_offset as usize {
                             continue;
                         }
-                        
-                        if counter >= max_line_size + self.line_number_bar_size as usize {
-                            break;
-                        }
 
-                        if ch == '\n' {
+                        if grapheme.starts_with('\n') {
                             continue;
                         }
 
+                        let width = unicode::grapheme_width(grapheme) as usize;
+                        if counter + width > max_line_size + self.line_number_bar_size as usize {
+                            break;
+                        }
+
                         let absolute_position = initial_offset + byte_idx as u32;
 
-                        if self.selection.pos_start <= absolute_position as usize && (absolute_position as usize) < self.selection.pos_end {
-                            surface.write_char(x, y, Character::with_attributes(ch, attr_selection));
-                        }                        
-                        else if self.flags.contains(Flags::HighlightCursor) && y as usize == self.cursor.pos_y {
-                            surface.write_char(x, y, Character::with_attributes(ch, attr_line_number));
-                        }
-                        else {
-                            surface.write_char(x, y, Character::with_attributes(ch, attr_text));
-                        }
-                        
-                        x += 1;
+                        let attr = if self.selection.pos_start <= absolute_position as usize && (absolute_position as usize) < self.selection.pos_end {
+                            attr_selection
+                        } else if self.flags.contains(Flags::HighlightCursor) && y as usize == self.cursor.pos_y {
+                            attr_line_number
+                        } else {
+                            attr_text
+                        };
+                        surface.write_string(x, y, grapheme, attr, false);
+
+                        x += width as i32;
 
-                        counter += 1;
+                        counter += width;
                     }
                 }
 
@@ -1519,7 +1566,7 @@ impl OnPaint for TextArea {
             initial_offset += current_offset;
         }
 
-        surface.set_cursor(self.cursor.pos_x as i32 + self.line_number_bar_size as i32, self.cursor.pos_y as i32);
+        surface.set_cursor(self.cursor_cell_x() + self.line_number_bar_size as i32, self.cursor.pos_y as i32);
     }
 }
 
@@ -1544,6 +1591,7 @@ impl OnResize for TextArea {
   
 impl OnKeyPressed for TextArea {
     fn on_key_pressed(&mut self, key: Key, character: char) -> EventProcessStatus {
+        let (key, character) = KeyMap::translate_key("textarea", key, character);
         match key.value() {
             key!("Up") => {
                 self.move_cursor_vertical(-1);
@@ -1681,16 +1729,14 @@ impl OnKeyPressed for TextArea {
                 return EventProcessStatus::Processed;
             }
 
-            key!("Ctrl+V") | key!("Ctrl+Shift+V") => {
-                if !self.flags.contains(Flags::ReadOnly) {
-                    self.reposition_cursor();
+            key!("Ctrl+V") | key!("Ctrl+Shift+V") if !self.flags.contains(Flags::ReadOnly) => {
+                self.reposition_cursor();
 
-                    if let Some(clipboard_data) = RuntimeManager::get().backend().clipboard_text() {
-                        let inserted_count = self.insert_text_internal(&clipboard_data);
-                        self.move_cursor_horizontal(inserted_count as i32);
-                    }
-                    return EventProcessStatus::Processed;
+                if let Some(clipboard_data) = RuntimeManager::get().backend().clipboard_text() {
+                    let inserted_count = self.insert_text_internal(&clipboard_data);
+                    self.move_cursor_horizontal(inserted_count as i32);
                 }
+                return EventProcessStatus::Processed;
             }
             key!("Ctrl+A") => {
                 self.select_all();
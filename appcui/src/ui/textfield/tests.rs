@@ -66,29 +66,29 @@ fn check_select_left_right() {
         // pressed Right arrow to remove selection
         Key.Pressed(Right)
         Paint('cursor at end')   
-        CheckHash(0x118C7686A80A8D9E)
-        CheckCursor(24,3)
+        CheckHash(0x7909B9070674690E)
+        CheckCursor(26,3)
         Key.Pressed(Shift+Left,4)
         Paint('last 4 chars selected:  123❤╬▶-|〓GDT| ')   
-        CheckHash(0x8AE976432AC0CA2E)
-        CheckCursor(20,3)
+        CheckHash(0x6776DE0861CC448D)
+        CheckCursor(21,3)
         Key.Pressed(Home)
         Paint('No selection, cursor on first character')   
-        CheckHash(0x118C7686A80A8D9E)
+        CheckHash(0x7909B9070674690E)
         CheckCursor(13,3)
         Key.Pressed(Shift+Right,5)
         Paint('First 5 characters selected')   
-        CheckHash(0xA4D17EB2D03D98B5)
-        CheckCursor(18,3)
+        CheckHash(0xAE893F672515BB22)
+        CheckCursor(19,3)
         Key.Pressed(Right)
         Key.Pressed(Shift+End)
         Paint('Last 5 characters selectedL 123❤╬▶|-〓GDT|')   
-        CheckHash(0xABDC8B8ADDB65A89)
-        CheckCursor(24,3)
+        CheckHash(0x200A70EC5983912E)
+        CheckCursor(26,3)
         Key.Pressed(Left,3)
         Key.Pressed(Shift+Home)
         Paint('All but last 3 chars selected |123❤╬▶-〓|GDT')   
-        CheckHash(0xDCE11FF9FF553196)
+        CheckHash(0x1288127E32FCBF12)
         CheckCursor(13,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -236,15 +236,15 @@ fn check_home_end() {
         // pressed Right arrow to remove selection
         Key.Pressed(Right)
         Paint('Text:  ▶-〓 world|| (initial state - cursor is last)')   
-        CheckHash(0xB5F2856A17C1B50D)
+        CheckHash(0xE99C9F65970B845B)
         CheckCursor(22,3)
         Key.Pressed(Home)
         Paint('Text: |H|ello |❤|╬▶-')   
-        CheckHash(0x5B8CEEE9E9B5B8F8)
+        CheckHash(0xA2ABEBC1242491BE)
         CheckCursor(13,3)
         Key.Pressed(End)
         Paint('Text:  ▶-〓 world||')   
-        CheckHash(0xB5F2856A17C1B50D)
+        CheckHash(0xE99C9F65970B845B)
         CheckCursor(22,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -260,27 +260,27 @@ fn check_delete() {
         Paint.Enable(false)
         Key.Pressed(Home)
         Paint('Text: [Hello ❤╬▶-]')   
-        CheckHash(0x5B8CEEE9E9B5B8F8)
+        CheckHash(0xA2ABEBC1242491BE)
         CheckCursor(13,3)
         Key.Pressed(Right,4)
         Paint('Show: Hell|o| ❤╬▶-')   
-        CheckHash(0x5B8CEEE9E9B5B8F8)
+        CheckHash(0xA2ABEBC1242491BE)
         CheckCursor(17,3)
         Key.Pressed(Delete)
         Paint('Show: Hell| |❤╬▶-〓')   
-        CheckHash(0x9D7057333DEA1294)
+        CheckHash(0xC3BB416730111D90)
         CheckCursor(17,3)
         Key.Pressed(Delete)
         Paint('Show: Hell|❤|╬▶-〓 ')   
-        CheckHash(0x21EE5121805EEE60)
+        CheckHash(0x24BD8B01DE8D4A70)
         CheckCursor(17,3)
         Key.Pressed(Delete)
         Paint('Show: Hell|╬|▶-〓 w')   
-        CheckHash(0xE17209AE4688CF0A)
+        CheckHash(0x2FC3E023301EFE2A)
         CheckCursor(17,3)
         Key.Pressed(Delete)
         Paint('Show: Hell|▶|-〓 wo')   
-        CheckHash(0xD496D44D8168F24E)
+        CheckHash(0x1C80B24EFD09380E)
         CheckCursor(17,3)
         Key.Pressed(Delete,3)
         Paint('Show: Hell world')   
@@ -325,15 +325,15 @@ fn check_delete_from_end() {
         Paint.Enable(false)
         Key.Pressed(Left)
         Paint('Text: ▶-〓 worl|d|')   
-        CheckHash(0xB5F2856A17C1B50D)
+        CheckHash(0xE99C9F65970B845B)
         CheckCursor(21,3)
         Key.Pressed(Delete)
         Paint('Text: ╬▶-〓 worl, cursor last')   
-        CheckHash(0x57F85A60FF685391)
+        CheckHash(0x383E998172EEB89F)
         CheckCursor(21,3)
         Key.Pressed(Delete,10)
         Paint('Text: ╬▶-〓 worl, cursor last, nothing changes')   
-        CheckHash(0x57F85A60FF685391)
+        CheckHash(0x383E998172EEB89F)
         CheckCursor(21,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -351,11 +351,11 @@ fn check_delete_after_selection() {
         Key.Pressed(Right,2)
         Key.Pressed(Shift+Right,4)
         Paint('Text: Hello |❤|╬▶-')   
-        CheckHash(0x473ABCF8BA20B908)
+        CheckHash(0xDFCF4FCA78BCD32E)
         CheckCursor(19,3)
         Key.Pressed(Delete)
         Paint('Text:  He|❤|╬▶-〓 wo')   
-        CheckHash(0xE80B6D9DE88C7B68)
+        CheckHash(0xB3A7E8A23DC03A08)
         CheckCursor(15,3)
         Key.Pressed(Delete,10)
         Paint('Text: He|d|')   
@@ -376,39 +376,39 @@ fn check_backspace() {
         Key.Pressed(Home)
         Key.Pressed(Right,3)
         Paint('Text: Hel|l|o ❤╬▶-')   
-        CheckHash(0x5B8CEEE9E9B5B8F8)
+        CheckHash(0xA2ABEBC1242491BE)
         CheckCursor(16,3)
         Key.Pressed(Backspace)
         Paint('Text:  He|l|o ❤╬▶-〓')   
-        CheckHash(0xF711197C690A2243)
+        CheckHash(0x493C270D35AA7233)
         CheckCursor(15,3)
         Key.Pressed(Backspace)
         Paint('Text:  H|l|o ❤╬▶-〓 ')   
-        CheckHash(0x218C39E1236E15A6)
+        CheckHash(0x44A8F98F4DC8B7B2)
         CheckCursor(14,3)
         Key.Pressed(Backspace)
         Paint('Text:  |l|o ❤╬▶-〓 w')   
-        CheckHash(0xDF2768238E2F92D5)
+        CheckHash(0xAE91538D91967A62)
         CheckCursor(13,3)
         Key.Pressed(Backspace,10)
         Paint('Text:  |l|o ❤╬▶-〓 w => nothing changes')   
-        CheckHash(0xDF2768238E2F92D5)
+        CheckHash(0xAE91538D91967A62)
         CheckCursor(13,3)
         Key.Pressed(Right,5)
         Paint('Text: lo ❤╬|▶|-〓 w')   
-        CheckHash(0xDF2768238E2F92D5)
-        CheckCursor(18,3)
+        CheckHash(0xAE91538D91967A62)
+        CheckCursor(19,3)
         Key.Pressed(Backspace)
         Paint('Text: lo ❤|▶|-〓 wo')   
-        CheckHash(0x29812F27AF27CF21)
-        CheckCursor(17,3)
+        CheckHash(0xA81718057BD0CAA9)
+        CheckCursor(18,3)
         Key.Pressed(Backspace)
         Paint('Text: lo |▶|-〓 wor')   
-        CheckHash(0xF757EDD3740156BE)
+        CheckHash(0x6FFDE8ABD9FF76DF)
         CheckCursor(16,3)
         Key.Pressed(End)
         Paint('Text: ▶-〓 world||')   
-        CheckHash(0xB5F2856A17C1B50D)
+        CheckHash(0xE99C9F65970B845B)
         CheckCursor(22,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -425,40 +425,40 @@ fn check_backspace_from_end() {
         // pressed Right arrow to remove selection
         Key.Pressed(Right)
         Paint('1. Text: ▶-〓 world||')   
-        CheckHash(0xB5F2856A17C1B50D)
+        CheckHash(0xE99C9F65970B845B)
         CheckCursor(22,3)
         Key.Pressed(Backspace)
         Paint('2.Text: ▶-〓 worl||')   
-        CheckHash(0x57F85A60FF685391)
+        CheckHash(0x383E998172EEB89F)
         CheckCursor(21,3)
         Key.Pressed(Backspace,5)
         Paint('3.Text: ▶-〓||')   
-        CheckHash(0xDCF04CF5A595D7C3)
+        CheckHash(0x121F00676023A065)
         CheckCursor(16,3)
         Key.Pressed(Left,7)
         Paint('4.Text: o ❤╬▶-〓')   
-        CheckHash(0xEDBA26DAAFF5F06A)
+        CheckHash(0x1CE0547BD521B23A)
         CheckCursor(13,3)
         Key.Pressed(End)
         Paint('5.Text: llo ❤╬▶-〓||')   
-        CheckHash(0x777BAF7290F92F2A)
+        CheckHash(0x1CE0547BD521B23A)
         CheckCursor(22,3)
         Key.Pressed(Backspace,3)
         Paint('6.Text: llo ❤╬||')   
-        CheckHash(0x1CCCA3758557EAD9)
-        CheckCursor(19,3)
+        CheckHash(0x8B2EA576AD3EFAE)
+        CheckCursor(18,3)
         Key.Pressed(Backspace,3)
         Paint('7.Text: llo||')   
-        CheckHash(0x120F4BA0A2FAD8A3)
-        CheckCursor(16,3)
+        CheckHash(0x1DBDAE3597858923)
+        CheckCursor(14,3)
         Key.Pressed(Backspace)
         Paint('8.Text: ll||')   
-        CheckHash(0xD37A8CFC9107AD14)
-        CheckCursor(15,3)
+        CheckHash(0xA4EDA87645FBF114)
+        CheckCursor(13,3)
         Key.Pressed(Backspace)
         Paint('9.Text: l||')   
-        CheckHash(0x6F226838BB58E638)
-        CheckCursor(14,3)
+        CheckHash(0xA4EDA87645FBF114)
+        CheckCursor(13,3)
         Key.Pressed(Home)
         Paint('10.Text: |H|el')   
         CheckHash(0xD8844B5C8926B539)
@@ -499,15 +499,15 @@ fn check_backspace_after_selection() {
         Key.Pressed(Right,2)
         Key.Pressed(Shift+Right,4)
         Paint('Text: Hello |❤|╬▶-')   
-        CheckHash(0x473ABCF8BA20B908)
+        CheckHash(0xDFCF4FCA78BCD32E)
         CheckCursor(19,3)
         Key.Pressed(Backspace)
         Paint('Text:  He|❤|╬▶-〓 wo')   
-        CheckHash(0xE80B6D9DE88C7B68)
+        CheckHash(0xB3A7E8A23DC03A08)
         CheckCursor(15,3)
         Key.Pressed(Backspace,2)
         Paint('Text: ❤╬▶-〓 worl')   
-        CheckHash(0xDA69921E3679D663)
+        CheckHash(0x841C9003A12CCE49)
         CheckCursor(13,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -565,20 +565,20 @@ fn check_write_unicode_text() {
         CheckCursor(18,3)
         Key.TypeText('❤️')
         Paint('3.Text is: ello❤️')   
-        CheckHash(0xF1B75E37ED9C08B)
+        CheckHash(0xBD972134A6D2A4C5)
         CheckCursor(18,3)
         Key.TypeText('〓rl❤️')
         Paint('4.Text is: ❤️〓rl❤️')   
-        CheckHash(0x3CCD819EB95146CD)
-        CheckCursor(18,3)
+        CheckHash(0xE0CB5ED268735A70)
+        CheckCursor(17,3)
         Key.Pressed(Home)
         Paint('5.Text is: Hello❤️')   
-        CheckHash(0x8931746B3B63B087)
+        CheckHash(0xF2A1C652DC792B72)
         CheckCursor(13,3)
         Key.Pressed(End)
         Paint('6.Text is: ❤️〓rl❤️')   
-        CheckHash(0x3CCD819EB95146CD)
-        CheckCursor(18,3)
+        CheckHash(0xE0CB5ED268735A70)
+        CheckCursor(17,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
     let mut w = Window::new("Title", layout!("a:c,w:40,h:9"), window::Flags::None);
@@ -599,27 +599,27 @@ fn check_write_multiline_text() {
         CheckCursor(18,4)
         Key.TypeText('❤️')
         Paint('3.Text is: Hello_word❤️')   
-        CheckHash(0x300DCC6FF4C1CB86)
-        CheckCursor(13,5)
+        CheckHash(0x4FDECD28760599CD)
+        CheckCursor(15,5)
         Key.TypeText('〓rl❤️')
         Paint('4.Text is: Hello_word❤️❤️〓rl❤️')   
-        CheckHash(0x770216FAF138E89A)
+        CheckHash(0x35BFEF381D331298)
         CheckCursor(17,5)
         Key.TypeText('❤️')
         Paint('5.Text is: Hello_word❤️❤️〓rl❤️❤️')   
-        CheckHash(0x2B30AC2B28C6526B)
+        CheckHash(0x55408DDB7C16FCEE)
         CheckCursor(18,5)
         Key.TypeText('❤️')
         Paint('6.Text is: ello_word❤️❤️〓rl❤️❤️❤️')   
-        CheckHash(0x2476BC2CC3EF5C9E)
-        CheckCursor(18,5)
+        CheckHash(0xDD22107364392DEF)
+        CheckCursor(17,5)
         Key.TypeText('12345')
         Paint('7.Text is: word❤️❤️〓rl❤️❤️❤️12345')   
-        CheckHash(0x581C58B9161C94E2)
+        CheckHash(0x96BDF4530179804B)
         CheckCursor(18,5)
         Key.Pressed(Home)
         Paint('8.Text is: Hello_word❤️❤️〓rl❤️❤️❤️')   
-        CheckHash(0xE60DAD19F94F1D86)
+        CheckHash(0xDFE79BDD912C9633)
         CheckCursor(13,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -648,7 +648,7 @@ fn check_insert_text() {
         CheckCursor(15,3)
         Key.TypeText('〓rl❤️❤️❤️')
         Paint('4.Text: He〓rl❤❤❤|l|l')   
-        CheckHash(0x9A47A09DA4CFEA69)
+        CheckHash(0x1CFE1FA0B89DB4A0)
         CheckCursor(21,3)
         Key.Pressed(End)
         Paint('4.Text: llo world||')   
@@ -677,24 +677,24 @@ fn check_insert_text_over_selection() {
         CheckCursor(19,3)
         Key.TypeText('〓❤️❤️❤️')
         Paint('3.Text replaces (He〓❤❤❤|w|orl)')   
-        CheckHash(0x2D25F2AE8CA02B9D)
-        CheckCursor(19,3)
+        CheckHash(0x240A3B69D1EFDE14)
+        CheckCursor(22,3)
         Key.Pressed(Right)
         Paint('4.Move cursor to right (He〓❤❤❤w|o|rl)')   
-        CheckHash(0x2D25F2AE8CA02B9D)
-        CheckCursor(20,3)
+        CheckHash(0xA5E00F27C503522E)
+        CheckCursor(22,3)
         Key.Pressed(Right)
         Paint('5.Move cursor to right (He〓❤❤❤wo|r|l)')   
-        CheckHash(0x2D25F2AE8CA02B9D)
+        CheckHash(0xA3BF6485654E1378)
         CheckCursor(21,3)
         Key.Pressed(Right)
         Paint('6.Move cursor to right (He〓❤❤❤wor|l|)')   
-        CheckHash(0x2D25F2AE8CA02B9D)
+        CheckHash(0xA3BF6485654E1378)
         CheckCursor(22,3)
         Key.Pressed(Right)
         Paint('7.Move cursor to right (e〓❤❤❤worl|d|)')   
-        CheckHash(0x3D405AF32EA72405)
-        CheckCursor(22,3)
+        CheckHash(0xD320C7D192101C62)
+        CheckCursor(21,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
     let mut w = Window::new("Title", layout!("a:c,w:40,h:9"), window::Flags::None);
@@ -709,72 +709,72 @@ fn check_move_to_next_word() {
         Paint.Enable(false)
         Key.Pressed(Home)
         Paint('1.Visible:  |H|ello   world,〓❤,123,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
+        CheckHash(0x96E5E8438CA81A67)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Right)
         Paint('2.Visible:  Hello   |w|orld,〓❤,123,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
+        CheckHash(0x96E5E8438CA81A67)
         CheckCursor(21,3)
         Key.Pressed(Left,2)
         Paint('3.Visible:  Hello | | world,〓❤,123,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
+        CheckHash(0x96E5E8438CA81A67)
         CheckCursor(19,3)
         Key.Pressed(Ctrl+Right)
         Paint('4.Visible:  Hello   |w|orld,〓❤,123,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
+        CheckHash(0x96E5E8438CA81A67)
         CheckCursor(21,3)
         Key.Pressed(Ctrl+Right)
         Paint('5.Visible:  Hello   world|,|〓❤,123,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
+        CheckHash(0x96E5E8438CA81A67)
         CheckCursor(26,3)
         Key.Pressed(Ctrl+Right)
         Paint('6.Visible:  Hello   world,|〓|❤,123,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
+        CheckHash(0x96E5E8438CA81A67)
         CheckCursor(27,3)
         Key.Pressed(Ctrl+Right)
         Paint('7.Visible:  Hello   world,〓❤|,|123,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
-        CheckCursor(29,3)
+        CheckHash(0x96E5E8438CA81A67)
+        CheckCursor(30,3)
         Key.Pressed(Ctrl+Right)
         Paint('8.Visible:  Hello   world,〓❤,|1|23,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
-        CheckCursor(30,3)
+        CheckHash(0x96E5E8438CA81A67)
+        CheckCursor(31,3)
         Key.Pressed(Ctrl+Right)
         Paint('9.Visible:  Hello   world,〓❤,123|,|  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
-        CheckCursor(33,3)
+        CheckHash(0x96E5E8438CA81A67)
+        CheckCursor(34,3)
         Key.Pressed(Ctrl+Right)
         Paint('10.Visible:  Hello   world,〓❤,123,  |t|est.set')   
-        CheckHash(0x2D7A431271FF0AE0)
-        CheckCursor(36,3)
+        CheckHash(0x96E5E8438CA81A67)
+        CheckCursor(37,3)
         Key.Pressed(Ctrl+Right)
         Paint('11.Visible:  Hello   world,〓❤,123,  test|.|set')   
-        CheckHash(0x2D7A431271FF0AE0)
-        CheckCursor(40,3)
+        CheckHash(0x96E5E8438CA81A67)
+        CheckCursor(41,3)
         Key.Pressed(Ctrl+Right)
         Paint('12.Visible:  Hello   world,〓❤,123,  test.|s|et')   
-        CheckHash(0x2D7A431271FF0AE0)
-        CheckCursor(41,3)
+        CheckHash(0x96E5E8438CA81A67)
+        CheckCursor(42,3)
         Key.Pressed(Ctrl+Right)
         Paint('13.Visible:  lo   world,〓❤,123,  test.set    |u|')   
-        CheckHash(0xE5B31249CF6A1A98)
+        CheckHash(0x62385338DE11E357)
         CheckCursor(45,3)
         Key.Pressed(Right,2)
         Paint('14.Visible:   world,〓❤,123,  test.set    un|i|')   
-        CheckHash(0xC78D0EC4D48FBC30)
+        CheckHash(0x7206E1306311348F)
         CheckCursor(45,3)
         Key.Pressed(Ctrl+Right)
         Paint('15.Visible:  ,〓❤,123,  test.set    uni〓code  |t|')   
-        CheckHash(0x1F540C31A3C9129C)
-        CheckCursor(45,3)
+        CheckHash(0xBC68F25EE0EB3C9B)
+        CheckCursor(44,3)
         Key.Pressed(Ctrl+Right)
         // reach the end of the text
         Paint('16.Visible:   test.set    uni〓code  twice   ')   
-        CheckHash(0x188AC9EB95C89126)
+        CheckHash(0x48B5BF4651A5EFB1)
         CheckCursor(45,3)
         Key.Pressed(Ctrl+Right)
         Paint('17.Visible:   test.set    uni〓code  twice   (nothing happens)')   
-        CheckHash(0x188AC9EB95C89126)
+        CheckHash(0x48B5BF4651A5EFB1)
         CheckCursor(45,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -790,72 +790,72 @@ fn check_move_to_previous_word() {
         Paint.Enable(false)
         Key.Pressed(End)
         Paint('1.Visible:    test.set    uni〓code  twice    ||')   
-        CheckHash(0x188AC9EB95C89126)
+        CheckHash(0x48B5BF4651A5EFB1)
         CheckCursor(45,3)
         Key.Pressed(Ctrl+Left)
         Paint('2.Visible:   test.set    uni〓code  |t|wice    ')   
-        CheckHash(0x188AC9EB95C89126)
+        CheckHash(0x48B5BF4651A5EFB1)
         CheckCursor(37,3)
         Key.Pressed(Left,1)
         Paint('3.Visible:   test.set    uni〓code  | |twice    ')   
-        CheckHash(0x188AC9EB95C89126)
+        CheckHash(0x48B5BF4651A5EFB1)
         CheckCursor(36,3)
         Key.Pressed(Ctrl+Left)
         Paint('4.Visible:   test.set    |u|ni〓code  twice   ')   
-        CheckHash(0x188AC9EB95C89126)
-        CheckCursor(27,3)
+        CheckHash(0x48B5BF4651A5EFB1)
+        CheckCursor(26,3)
         Key.Pressed(Ctrl+Left)
         Paint('5.Visible:   test.|s|et    uni〓code  twice   ')   
-        CheckHash(0x188AC9EB95C89126)
-        CheckCursor(20,3)
+        CheckHash(0x48B5BF4651A5EFB1)
+        CheckCursor(19,3)
         Key.Pressed(Ctrl+Left)
         Paint('6.Visible:   test|.|set    uni〓code  twice   ')   
-        CheckHash(0x188AC9EB95C89126)
-        CheckCursor(19,3)
+        CheckHash(0x48B5BF4651A5EFB1)
+        CheckCursor(18,3)
         Key.Pressed(Ctrl+Left)
         Paint('7.Visible:  |t|est.set    uni〓code  twice   ')   
-        CheckHash(0x188AC9EB95C89126)
-        CheckCursor(15,3)
+        CheckHash(0x48B5BF4651A5EFB1)
+        CheckCursor(14,3)
         Key.Pressed(Ctrl+Left)
         Paint('8.Visible:  |,|  test.set    uni〓code  twice   ')   
-        CheckHash(0x85C9E5D26DF4B442)
+        CheckHash(0x7564A362389F7A6D)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Left)
         Paint('9.Visible:  |1|23,  test.set    uni〓code  twicet')   
-        CheckHash(0x1F31A3D060CF832)
+        CheckHash(0xAF78B0C16F295FBC)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Left)
         Paint('10.Visible: |,|123,  test.set    uni〓code  twic')   
-        CheckHash(0xC9CD5299AA782443)
+        CheckHash(0xC02674677FC1E06F)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Left)
         Paint('11.Visible:  |〓|❤,123,  test.set    uni〓code  tw')   
-        CheckHash(0xD8427A56B47AB583)
+        CheckHash(0xEEE0398573439AD4)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Left)
         Paint('12.Visible:  |,|〓❤,123,  test.set    uni〓code  t')   
-        CheckHash(0x1F540C31A3C9129C)
+        CheckHash(0xBD480861E3E700C)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Left)
         Paint('13.Visible:  |w|orld,〓❤,123,  test.set    uni〓co')   
-        CheckHash(0x39319158125831AB)
+        CheckHash(0x5849AE4067791A1B)
         CheckCursor(13,3)
         Key.Pressed(Left,2)
         Paint('14.Visible: || world,〓❤,123,  test.set    uni〓')   
-        CheckHash(0x7B07EF88A003B02F)
+        CheckHash(0x7206E1306311348F)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Left)
         Paint('15.Visible:  |H|ello   world,〓❤,123,  test.set')   
-        CheckHash(0x2D7A431271FF0AE0)
+        CheckHash(0x96E5E8438CA81A67)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Left)
         // now we reach the start of the test
         Paint('16.Visible: ||   Hello   world,〓❤,123,  test.set')   
-        CheckHash(0xC7E06F2FFB5E0060)
+        CheckHash(0x550FBBC3D5939B1B)
         CheckCursor(13,3)
         Key.Pressed(Ctrl+Left)
         Paint('17.Visible: ||   Hello   world,〓❤,123,  test.set (nothing happens)')   
-        CheckHash(0xC7E06F2FFB5E0060)
+        CheckHash(0x550FBBC3D5939B1B)
         CheckCursor(13,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -979,16 +979,16 @@ fn check_mouse_click() {
     let script = "
         Paint.Enable(false)
         Paint('1. Initial state')   
-        CheckHash(0xCA13991A964D78E7)
-        CheckCursor(25,3)
+        CheckHash(0x5B7D259A5C43F103)
+        CheckCursor(27,3)
         Mouse.Click(17,3,left)
         Paint('2. Nothing selected, cursor on |o|')   
-        CheckHash(0x9D7022C6AA5D3FFB)
+        CheckHash(0xE86C3279AF265163)
         CheckCursor(17,3)
         Mouse.Click(30,3,left)
         Paint('3. Nothing selected, cursor at the end')   
-        CheckHash(0x9D7022C6AA5D3FFB)
-        CheckCursor(25,3)
+        CheckHash(0xE86C3279AF265163)
+        CheckCursor(27,3)
         Key.TypeText('Rust is a great language')
         Paint('4. Text: a great language')   
         CheckHash(0xCA75D9C93B27BEBE)
@@ -1010,16 +1010,16 @@ fn check_mouse_click_multi_line() {
     let script = "
         Paint.Enable(false)
         Paint('1. Initial state')   
-        CheckHash(0x1D939C7615CD409F)
-        CheckCursor(17,4)
+        CheckHash(0xD92B1AA973CB46C3)
+        CheckCursor(20,4)
         Mouse.Click(17,3,left)
         Paint('2. Nothing selected, cursor on |o|')   
-        CheckHash(0xF2E5B0D71CC946A3)
+        CheckHash(0xDD48F039905A16E3)
         CheckCursor(17,3)
         Mouse.Click(20,5,left)
         Paint('3. Nothing selected, cursor at the end')   
-        CheckHash(0xF2E5B0D71CC946A3)
-        CheckCursor(17,4)
+        CheckHash(0xDD48F039905A16E3)
+        CheckCursor(20,4)
         Key.TypeText('Rust is a great language')
         Paint('4. Text: ust is a great language')   
         CheckHash(0x82AAADB5012BC38E)
@@ -1041,24 +1041,24 @@ fn check_mouse_click_outside_bounds() {
     let script = "
         Paint.Enable(false)
         Paint('1. Initial state')   
-        CheckHash(0xCA13991A964D78E7)
-        CheckCursor(25,3)
+        CheckHash(0x5B7D259A5C43F103)
+        CheckCursor(27,3)
         Mouse.Click(17,3,left)
         Paint('2. Nothing selected, cursor on |o|')   
-        CheckHash(0x9D7022C6AA5D3FFB)
+        CheckHash(0xE86C3279AF265163)
         CheckCursor(17,3)
         Mouse.Click(12,3,left)
         Paint('3. Nothing happens (click outside bounds) cursor remains the same')   
-        CheckHash(0x9D7022C6AA5D3FFB)
+        CheckHash(0xE86C3279AF265163)
         CheckCursor(17,3)
         Mouse.Click(31,3,left)
         Paint('4. Nothing happens (click outside bounds) cursor remains the same')   
-        CheckHash(0x9D7022C6AA5D3FFB)
+        CheckHash(0xE86C3279AF265163)
         CheckCursor(17,3)
         Mouse.Click(30,3,left)
         Paint('5. Now cursor at end')   
-        CheckHash(0x9D7022C6AA5D3FFB)
-        CheckCursor(25,3)
+        CheckHash(0xE86C3279AF265163)
+        CheckCursor(27,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
     let mut w = Window::new("Title", layout!("a:c,w:40,h:9"), window::Flags::None);
@@ -1073,15 +1073,15 @@ fn check_mouse_selection() {
         Paint.Enable(false)
         Key.Pressed(Right)
         Paint('1. Text: - I ❤️ Rust Language :)')   
-        CheckHash(0x480A380B10C2094C)
+        CheckHash(0x4728DC32ADBA6D33)
         CheckCursor(20,5)
         Mouse.Drag(20,3,17,5)
         Paint('2. Selected text: Rust Language')   
-        CheckHash(0xD374BBDDCBFB9EFB)
+        CheckHash(0x2A829A6063D970E4)
         CheckCursor(17,5)
         Mouse.Drag(18,3,10,2)
         Paint('3. Selected text: ello w❤️rl❤️d - I ')   
-        CheckHash(0x57E8868A7830C0DE)
+        CheckHash(0xC350023EADBA1053)
         CheckCursor(13,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -1097,11 +1097,11 @@ fn check_double_click_selection() {
         Paint.Enable(false)
         Key.Pressed(Right)
         Paint('1. Text: - I ❤️ Rust Language :)')   
-        CheckHash(0xC612D5EC66D395F)
+        CheckHash(0x77E62F1A6199AC59)
         CheckCursor(24,5)
         Mouse.DoubleClick(17,5,left)
         Paint('2. Selected text: Language')   
-        CheckHash(0xC500029C8BF4A50B)
+        CheckHash(0x966A09B8DC894B7D)
         CheckCursor(21,5)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -1118,15 +1118,15 @@ fn check_select_word_for_upper_and_lowercase() {
         Key.Pressed(Home)
         Key.Pressed(Right,8)
         Paint('1. Hello world, I ❤ Rust Language :)')   
-        CheckHash(0x933F4645D825CC91 )
+        CheckHash(0x845685372171BF66)
         CheckCursor(21,3)
         Key.Pressed(Ctrl+U)
         Paint('2. Hello world, I ❤ Rust Language :)')   
-        CheckHash(0xAED543050B90DB6)
+        CheckHash(0x872CA29A75B80C75)
         CheckCursor(24,3)
         Key.Pressed(Ctrl+Shift+U)
         Paint('3. Hello WORLD, I ❤ Rust Language :)')   
-        CheckHash(0x350A1BC25797ADD6)
+        CheckHash(0x5182ADB68959195)
         CheckCursor(24,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -1175,16 +1175,16 @@ fn check_paste() {
         Key.Pressed(Home)
         Key.Pressed(Right,6)
         Paint('1. Hello , I ❤ Rust Language :)')   
-        CheckHash(0x29421C7A89674F1F )
+        CheckHash(0xDEAA7C9AC7B93C40)
         CheckCursor(19,3)
         Clipboard.SetText('world')
         Key.Pressed('Ctrl+V')
         Paint('2. Hello world, I ❤ Rust Language :)')   
-        CheckHash(0x73D49C462B964C91)
+        CheckHash(0x7DDFB88F03293F66)
         CheckCursor(24,3)
         Key.Pressed('Shift+Insert',2)
         Paint('3. Hello worldworldworld, I ❤ Rust La')   
-        CheckHash(0xDF568934EEA67B95)
+        CheckHash(0xEBA357889543F38F)
         CheckCursor(34,3)
     ";
     let mut a = App::debug(60, 11, script).build().unwrap();
@@ -1201,19 +1201,19 @@ fn check_copy_cut() {
         Key.Pressed(Home)
         Key.Pressed(Right,8)
         Paint('1. Hello , I ❤ Rust Language :)')   
-        CheckHash(0x29421C7A89674F1F )
+        CheckHash(0xDEAA7C9AC7B93C40)
         CheckCursor(21,3)
         Key.Pressed(Shift+Right,8)
         Key.Pressed('Ctrl+C')
         Paint('2. Same text but `I ❤ Rust` is selected')   
-        CheckHash(0xF417AC2E90B028C3)
-        CheckCursor(29,3)
+        CheckHash(0xFE3135BA1A29AE5F)
+        CheckCursor(30,3)
         CheckClipboardText('I ❤️ Rust')
         Key.Pressed('Home')
         Key.Pressed(Shift+Right,5)
         Key.Pressed('Ctrl+X')
         Paint('3. , I ❤️ Rust Language :)')   
-        CheckHash(0x6A2C27777CF8CA71)
+        CheckHash(0x9B1493F144836AC6)
         CheckCursor(13,3)
         CheckClipboardText('Hello')
     ";
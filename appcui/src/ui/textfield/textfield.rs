@@ -3,6 +3,7 @@ use super::{
     CharClass, Flags, Selection,
 };
 use crate::prelude::*;
+use crate::graphics::unicode;
 use crate::utils::GlyphParser;
 
 struct Cursor {
@@ -102,31 +103,81 @@ impl TextField {
         self.move_cursor_to(self.glyphs.len(), false, true);
     }
 
+    // lays out the glyphs (starting from the `start` offset) in the view of the text field. A glyph that does not fit
+    // at the end of a line (a wide character) is moved on the next line. `f` receives the offset, the size (in bytes),
+    // the position and the width (in cells) of every glyph that fits in the view.
+    // returns the offset of the first glyph that does not fit and the position where it would have been displayed
+    fn layout(&self, start: usize, mut f: impl FnMut(usize, usize, i32, i32, i32)) -> (usize, i32, i32) {
+        let sz = self.size();
+        let right = sz.width as i32 - 1;
+        let h = sz.height as i32;
+        let mut x = 1;
+        let mut y = 0;
+        let mut pos = start;
+        while let Some((_, size)) = self.glyphs.glyph(pos) {
+            let size = size as usize;
+            let w = unicode::grapheme_width(&self.glyphs[pos..pos + size]) as i32;
+            if (x + w > right) && (x > 1) {
+                x = 1;
+                y += 1;
+            }
+            if (y >= h) || (x + w > right) {
+                break;
+            }
+            f(pos, size, x, y, w);
+            x += w;
+            pos += size;
+            if x >= right {
+                x = 1;
+                y += 1;
+            }
+        }
+        (pos, x, y)
+    }
+    // checks if the glyph from the `pos` offset (or the cursor, if `pos` is at the end of the text) is visible
+    // when the view starts from the `start` offset
+    fn is_visible_from(&self, start: usize, pos: usize) -> bool {
+        let (end, _, y) = self.layout(start, |_, _, _, _, _| {});
+        (pos < end) || ((pos == end) && (end == self.glyphs.len()) && (y < self.size().height as i32))
+    }
+    fn glyph_width(&self, pos: usize) -> usize {
+        match self.glyphs.glyph(pos) {
+            Some((_, size)) => unicode::grapheme_width(&self.glyphs[pos..pos + size as usize]) as usize,
+            None => 1, // the cursor (at the end of the text)
+        }
+    }
     fn update_scroll_view(&mut self, force_end_update: bool) {
         if (self.cursor.pos >= self.cursor.start) && (self.cursor.pos < self.cursor.end) {
             // nothing to do --> curent pos is already in the view window
             if force_end_update {
-                let sz = self.size();
-                let visible_glyphs = ((sz.width as usize) - 2) * (sz.height as usize);
-                self.cursor.end = self.glyphs.next_pos(self.cursor.start, visible_glyphs);
+                self.cursor.end = self.layout(self.cursor.start, |_, _, _, _, _| {}).0;
             }
             return;
         }
-        let sz = self.size();
-        let visible_glyphs = (if sz.width > 2 {
-            ((sz.width as usize) - 2) * (sz.height as usize)
-        } else {
-            0
-        })
-        .max(1);
-
         if self.cursor.pos < self.cursor.start {
             // scroll to the left
             self.cursor.start = self.cursor.pos;
-            self.cursor.end = self.glyphs.next_pos(self.cursor.pos, visible_glyphs);
+            self.cursor.end = self.layout(self.cursor.pos, |_, _, _, _, _| {}).0;
         } else {
-            // scroll to the right
-            self.cursor.start = self.glyphs.previous_pos(self.cursor.pos, visible_glyphs - 1);
+            // scroll to the right (the glyph from the cursor position becomes the last visible one)
+            let sz = self.size();
+            let cells = if sz.width > 2 { ((sz.width as usize) - 2) * (sz.height as usize) } else { 0 };
+            let mut start = self.cursor.pos;
+            let mut used = self.glyph_width(start);
+            while start > 0 {
+                let prev = self.glyphs.previous_pos(start, 1);
+                let w = self.glyph_width(prev);
+                if used + w > cells {
+                    break;
+                }
+                used += w;
+                start = prev;
+            }
+            // wide glyphs that are moved on the next line leave some cells unused
+            while (start < self.cursor.pos) && !self.is_visible_from(start, self.cursor.pos) {
+                start = self.glyphs.next_pos(start, 1);
+            }
+            self.cursor.start = start;
             // we add ONE to the end pot to satisfy (self.cursor.pos < self.cursor.end) condition
             self.cursor.end = self.cursor.pos;
         }
@@ -396,7 +447,17 @@ impl TextField {
         let sz = self.size();
         let w = sz.width as i32;
         let h = sz.height as i32;
-        if within_control && ((x < 1) || (x >= w - 1) || (y < 0) || (y >= h)) {
+        if (x >= 1) && (x < w - 1) && (y >= 0) && (y < h) {
+            // the first glyph that is displayed on the same position or after it
+            let mut result = None;
+            let (end, _, _) = self.layout(self.cursor.start, |pos, _, gx, gy, gw| {
+                if result.is_none() && ((gy > y) || ((gy == y) && (x < gx + gw))) {
+                    result = Some(pos);
+                }
+            });
+            return Some(result.unwrap_or(end));
+        }
+        if within_control {
             return None;
         }
         let glyphs_count = (x - 1) + y * (w - 2);
@@ -474,14 +535,9 @@ impl TextField {
     }
 }
 impl OnResize for TextField {
-    fn on_resize(&mut self, _old_size: Size, new_size: Size) {
+    fn on_resize(&mut self, _old_size: Size, _new_size: Size) {
         // we need to compute the end scroll based on the new size
-        let visible_chars = if new_size.width > 2 {
-            ((new_size.width - 2) as usize) * (new_size.height as usize)
-        } else {
-            0
-        };
-        self.cursor.end = self.glyphs.next_pos(self.cursor.start, visible_chars);
+        self.cursor.end = self.layout(self.cursor.start, |_, _, _, _, _| {}).0;
         // check if the current cursor is within the scroll view and if not update the scroll view
         self.update_scroll_view(false);
     }
@@ -498,35 +554,17 @@ impl OnPaint for TextField {
         // paint
         let show_cursor = self.has_focus();
         let sz = self.size();
-        let w = (sz.width - 1) as i32;
-        let mut count = (sz.width - 2) * sz.height;
-        let mut pos = self.cursor.start;
-        let mut x = 1;
-        let mut y = 0;
-        let mut ch = Character::with_attributes(' ', attr);
-        let mut ch_selected = Character::with_attributes(' ', theme.editor.pressed_or_selected);
-        while let Some((code, glyph_size)) = self.glyphs.glyph(pos) {
-            if (show_cursor) && self.selection.contains(pos) {
-                ch_selected.code = code;
-                surface.write_char(x, y, ch_selected);
+        let (pos, x, y) = self.layout(self.cursor.start, |pos, size, x, y, _| {
+            let glyph_attr = if show_cursor && self.selection.contains(pos) {
+                theme.editor.pressed_or_selected
             } else {
-                ch.code = code;
-                surface.write_char(x, y, ch);
-            }
+                attr
+            };
+            surface.write_string(x, y, &self.glyphs[pos..pos + size], glyph_attr, false);
             if show_cursor && (pos == self.cursor.pos) {
                 surface.set_cursor(x, y);
             }
-            x += 1;
-            if x >= w {
-                x = 1;
-                y += 1;
-            }
-            pos += glyph_size as usize;
-            count -= 1;
-            if count == 0 {
-                break;
-            }
-        }
+        });
         // if it is the last char
         if show_cursor && (pos == self.cursor.pos) {
             // if the cursor is located on the fist line outside the view --> put it on the last char but on previous line
@@ -540,6 +578,7 @@ impl OnPaint for TextField {
 }
 impl OnKeyPressed for TextField {
     fn on_key_pressed(&mut self, key: Key, character: char) -> EventProcessStatus {
+        let (key, character) = KeyMap::translate_key("textfield", key, character);
         match key.value() {
             key!("Left") | key!("Shift+Left") => {
                 self.last_action = LastAction::None;
@@ -338,11 +338,10 @@ impl OnPaint for TimePicker {
             } else {
                 attr
             };
-            if self.hour >= 12 {
-                surface.write_ascii(x, 0, b"PM", attr, false);
-            } else {
-                surface.write_ascii(x, 0, b"AM", attr, false);
-            }
+            let text = Locale::current().text(if self.hour >= 12 { "timepicker.pm" } else { "timepicker.am" });
+            // only two characters are available for the AM/PM indicator
+            let end = text.char_indices().nth(2).map(|(pos, _)| pos).unwrap_or(text.len());
+            surface.write_string(x, 0, &text[..end], attr, false);
         }
     }
 }
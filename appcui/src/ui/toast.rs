@@ -0,0 +1,26 @@
+//! Transient (non-modal) notifications.
+//!
+//! A toast is a small notification (with a title, a text and an optional list of action buttons) that is displayed
+//! in the bottom-right corner of the desktop, on top of all windows. Toasts are dismissed automatically after a timeout
+//! (unless they are sticky) or when the user clicks their close button. Every toast is also added to the notification
+//! history that can be opened via [`show_history`] or via the notification button from the app bar.
+
+pub mod events;
+mod notification_center;
+mod toast;
+mod toast_manager;
+#[cfg(test)]
+mod tests;
+
+pub use self::toast::Severity;
+pub use self::toast::Toast;
+pub(crate) use self::toast_manager::ToastAction;
+pub(crate) use self::toast_manager::ToastManager;
+
+use self::notification_center::NotificationCenter;
+use crate::ui::window::events::ModalWindowMethods;
+
+/// Opens the notification center (a modal window with the history of all the toasts that were displayed so far).
+pub fn show_history() {
+    NotificationCenter::new().show();
+}
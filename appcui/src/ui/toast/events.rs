@@ -0,0 +1,10 @@
+use super::Toast;
+use crate::{system::Handle, ui::common::traits::EventProcessStatus};
+
+pub trait ToastEvents {
+    /// Called when the user clicks on one of the action buttons of a toast. The `action_id` is the identifier
+    /// provided when the action was added to the toast (via `Toast::action(...)`). The toast is dismissed after this call.
+    fn on_action(&mut self, _toast: Handle<Toast>, _action_id: u32) -> EventProcessStatus {
+        EventProcessStatus::Ignored
+    }
+}
@@ -0,0 +1,48 @@
+use crate::prelude::*;
+use crate::system::RuntimeManager;
+
+#[ModalWindow(internal: true, events: ButtonEvents)]
+pub(super) struct NotificationCenter {
+    lb: Handle<ListBox>,
+    btn_clear: Handle<Button>,
+}
+
+impl NotificationCenter {
+    pub(super) fn new() -> Self {
+        let locale = Locale::current();
+        let mut me = Self {
+            base: ModalWindow::new(locale.text("toast.notifications"), layout!("a:c,w:70,h:16"), window::Flags::None),
+            lb: Handle::None,
+            btn_clear: Handle::None,
+        };
+        let mut lb = ListBox::new(layout!("l:1,t:1,r:1,b:3"), listbox::Flags::ScrollBars | listbox::Flags::SearchBar);
+        lb.set_empty_message(locale.text("toast.empty"));
+        // most recent notifications first
+        for entry in RuntimeManager::get().get_toasts().history().iter().rev() {
+            let text = entry.text.replace(['\n', '\r'], " ");
+            lb.add(&format!("[{}] {}: {}", entry.severity.name(), entry.title, text));
+        }
+        me.lb = me.add(lb);
+        me.btn_clear = me.add(Button::new(locale.text("toast.clear"), layout!("l:20,b:0,w:13")));
+        me.add(Button::new(locale.text("toast.close"), layout!("l:35,b:0,w:13")));
+        RuntimeManager::get().get_toasts().mark_as_read();
+        RuntimeManager::get().request_update_command_and_app_bars();
+        me
+    }
+}
+
+impl ButtonEvents for NotificationCenter {
+    fn on_pressed(&mut self, handle: Handle<Button>) -> EventProcessStatus {
+        if handle == self.btn_clear {
+            RuntimeManager::get().get_toasts().clear_history();
+            RuntimeManager::get().request_update_command_and_app_bars();
+            let h = self.lb;
+            if let Some(lb) = self.control_mut(h) {
+                lb.clear();
+            }
+        } else {
+            self.exit();
+        }
+        EventProcessStatus::Processed
+    }
+}
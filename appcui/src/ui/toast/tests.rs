@@ -0,0 +1,137 @@
+use crate::prelude::*;
+
+#[test]
+fn check_toast_show_and_dismiss() {
+    #[Window(events = ButtonEvents, internal: true)]
+    struct MyWin {
+        b_info: Handle<Button>,
+        b_error: Handle<Button>,
+        b_dismiss: Handle<Button>,
+        last: Handle<toast::Toast>,
+    }
+    impl MyWin {
+        fn new() -> Self {
+            let mut w = MyWin {
+                base: window!("Test,x:1,y:1,w:40,h:8"),
+                b_info: Handle::None,
+                b_error: Handle::None,
+                b_dismiss: Handle::None,
+                last: Handle::None,
+            };
+            w.b_info = w.add(button!("&Info,x:1,y:1,w:11"));
+            w.b_error = w.add(button!("&Error,x:13,y:1,w:11"));
+            w.b_dismiss = w.add(button!("&Dismiss,x:25,y:1,w:11"));
+            w
+        }
+    }
+    impl ButtonEvents for MyWin {
+        fn on_pressed(&mut self, handle: Handle<Button>) -> EventProcessStatus {
+            if handle == self.b_info {
+                self.last = self.show_toast(toast::Toast::info("Saved", "The file was saved successfully"));
+            } else if handle == self.b_error {
+                self.last = self.show_toast(toast::Toast::error("Sync", "The synchronization with the remote server has failed").sticky());
+            } else {
+                self.dismiss_toast(self.last);
+            }
+            EventProcessStatus::Processed
+        }
+    }
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Initial state (no toasts)')
+        CheckHash(0x6D25F07137FD8313)
+        Key.Pressed(Alt+I)
+        Paint('2. Info toast (bottom-right)')
+        CheckHash(0x5841BBDC357B3CD6)
+        Key.Pressed(Alt+E)
+        Paint('3. Error toast under the info toast')
+        CheckHash(0x4E7AA796A1EA6258)
+        Key.Pressed(Alt+D)
+        Paint('4. Error toast dismissed (only the info toast is visible)')
+        CheckHash(0x3A415BA8988F5E46)
+        Mouse.Click(77,22,left)
+        Paint('5. Info toast closed via [x] (no toasts)')
+        CheckHash(0x6D25F07137FD8313)
+    ";
+    let mut a = App::debug(80, 25, script).build().unwrap();
+    a.add_window(MyWin::new());
+    a.run();
+}
+
+#[test]
+fn check_toast_action() {
+    #[Window(events = ButtonEvents+ToastEvents, internal: true)]
+    struct MyWin {}
+    impl MyWin {
+        fn new() -> Self {
+            let mut w = MyWin {
+                base: window!("Test,x:1,y:1,w:40,h:8"),
+            };
+            w.add(button!("&Show,x:1,y:1,w:11"));
+            w
+        }
+    }
+    impl ButtonEvents for MyWin {
+        fn on_pressed(&mut self, _: Handle<Button>) -> EventProcessStatus {
+            self.show_toast(toast::Toast::warning("Update", "A new version is available").action("Install", 1).action("Later", 2));
+            EventProcessStatus::Processed
+        }
+    }
+    impl ToastEvents for MyWin {
+        fn on_action(&mut self, _: Handle<toast::Toast>, action_id: u32) -> EventProcessStatus {
+            self.set_title(&format!("Action:{}", action_id));
+            EventProcessStatus::Processed
+        }
+    }
+    let script = "
+        Paint.Enable(false)
+        Key.Pressed(Alt+S)
+        Paint('1. Toast with two actions')
+        CheckHash(0x1710D6F445B3D6FF)
+        Mouse.Click(52,23,left)
+        Paint('2. Later pressed (title is Action:2, toast dismissed)')
+        CheckHash(0x2C55BBC2A246D738)
+    ";
+    let mut a = App::debug(80, 25, script).build().unwrap();
+    a.add_window(MyWin::new());
+    a.run();
+}
+
+#[test]
+fn check_toast_notification_center() {
+    #[Window(events = ButtonEvents, internal: true)]
+    struct MyWin {}
+    impl MyWin {
+        fn new() -> Self {
+            let mut w = MyWin {
+                base: window!("Test,x:1,y:2,w:40,h:8"),
+            };
+            w.add(button!("&Show,x:1,y:1,w:11"));
+            w
+        }
+    }
+    impl ButtonEvents for MyWin {
+        fn on_pressed(&mut self, _: Handle<Button>) -> EventProcessStatus {
+            self.show_toast(toast::Toast::info("Build", "Build finished"));
+            self.show_toast(toast::Toast::error("Tests", "3 tests have failed"));
+            EventProcessStatus::Processed
+        }
+    }
+    let script = "
+        Paint.Enable(false)
+        Paint('1. No notifications button')
+        CheckHash(0x958C361A45E728F0)
+        Key.Pressed(Alt+S)
+        Paint('2. Two toasts and Notifications (2) button')
+        CheckHash(0x6A06038420691871)
+        Mouse.Click(75,0,left)
+        Paint('3. Notification center (Tests first, then Build)')
+        CheckHash(0x79823BC848DE510)
+        Key.Pressed(Escape)
+        Paint('4. Notifications button without unread count')
+        CheckHash(0x3F3CE2BE17C6FF16)
+    ";
+    let mut a = App::debug(80, 25, script).app_bar().build().unwrap();
+    a.add_window(MyWin::new());
+    a.run();
+}
@@ -0,0 +1,98 @@
+use crate::system::Locale;
+
+/// The severity of a toast. The colors used to display a toast are taken from the `info`, `warning` and `error`
+/// attributes of the window theme.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub(super) fn name(&self) -> &'static str {
+        Locale::current().text(match self {
+            Severity::Info => "toast.info",
+            Severity::Warning => "toast.warning",
+            Severity::Error => "toast.error",
+        })
+    }
+}
+
+/// A transient notification displayed in the bottom-right corner of the desktop.
+///
+/// # Example
+/// ```rust,no_run
+/// use appcui::prelude::*;
+///
+/// let t = toast::Toast::info("Saved", "The file was saved successfully")
+///     .timeout(3000)
+///     .action("Open folder", 1);
+/// ```
+pub struct Toast {
+    pub(super) severity: Severity,
+    pub(super) title: String,
+    pub(super) text: String,
+    pub(crate) timeout: Option<u32>,
+    pub(super) actions: Vec<(String, u32)>,
+}
+
+impl Toast {
+    /// The default time (in milliseconds) after which a toast is automatically dismissed.
+    pub const DEFAULT_TIMEOUT: u32 = 5000;
+
+    /// Creates a new toast with the specified severity, title and text. The toast will be dismissed automatically
+    /// after [`Toast::DEFAULT_TIMEOUT`] milliseconds.
+    pub fn new(severity: Severity, title: &str, text: &str) -> Self {
+        Self {
+            severity,
+            title: title.to_string(),
+            text: text.to_string(),
+            timeout: Some(Self::DEFAULT_TIMEOUT),
+            actions: Vec::new(),
+        }
+    }
+    /// Creates a new information toast.
+    pub fn info(title: &str, text: &str) -> Self {
+        Self::new(Severity::Info, title, text)
+    }
+    /// Creates a new warning toast.
+    pub fn warning(title: &str, text: &str) -> Self {
+        Self::new(Severity::Warning, title, text)
+    }
+    /// Creates a new error toast.
+    pub fn error(title: &str, text: &str) -> Self {
+        Self::new(Severity::Error, title, text)
+    }
+    /// Sets the time (in milliseconds) after which the toast is automatically dismissed.
+    pub fn timeout(mut self, milliseconds: u32) -> Self {
+        self.timeout = Some(milliseconds.max(1));
+        self
+    }
+    /// Makes the toast sticky (the toast will only be dismissed when the user closes it or clicks one of its actions).
+    pub fn sticky(mut self) -> Self {
+        self.timeout = None;
+        self
+    }
+    /// Adds an action button to the toast. When the user clicks the button, the `ToastEvents::on_action` event is
+    /// sent (with the specified `id`) to the control that has shown the toast.
+    pub fn action(mut self, caption: &str, id: u32) -> Self {
+        self.actions.push((caption.to_string(), id));
+        self
+    }
+    /// Returns the severity of the toast.
+    #[inline(always)]
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+    /// Returns the title of the toast.
+    #[inline(always)]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+    /// Returns the text of the toast.
+    #[inline(always)]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
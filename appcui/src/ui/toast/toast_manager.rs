@@ -0,0 +1,246 @@
+use super::{Severity, Toast};
+use crate::graphics::{Character, LineType, Rect, Surface};
+use crate::system::{Handle, Theme};
+
+const MAX_WIDTH: i32 = 40;
+const MAX_LINES: usize = 3;
+const MAX_HISTORY: usize = 100;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub(crate) enum ToastAction {
+    None,
+    Consumed,
+    Dismiss(Handle<Toast>),
+    Action {
+        receiver: Handle<()>,
+        toast: Handle<Toast>,
+        id: u32,
+    },
+}
+
+pub(crate) struct HistoryEntry {
+    pub(crate) severity: Severity,
+    pub(crate) title: String,
+    pub(crate) text: String,
+}
+
+struct ActiveToast {
+    handle: Handle<Toast>,
+    receiver: Handle<()>,
+    toast: Toast,
+    rect: Option<Rect>,
+    lines: Vec<String>,
+    // (x, width) of each action button (on the last line of the toast)
+    actions: Vec<(i32, i32)>,
+}
+
+impl ActiveToast {
+    fn close_button_x(&self, rect: &Rect) -> i32 {
+        rect.right() - 3
+    }
+}
+
+pub(crate) struct ToastManager {
+    toasts: Vec<ActiveToast>,
+    history: Vec<HistoryEntry>,
+    unread: usize,
+    last_id: u32,
+}
+
+impl ToastManager {
+    pub(crate) fn new() -> Self {
+        Self {
+            toasts: Vec::new(),
+            history: Vec::new(),
+            unread: 0,
+            last_id: 0,
+        }
+    }
+    pub(crate) fn add(&mut self, toast: Toast, receiver: Handle<()>) -> Handle<Toast> {
+        self.last_id += 1;
+        let handle = Handle::new(self.last_id);
+        if self.history.len() >= MAX_HISTORY {
+            self.history.remove(0);
+        }
+        self.history.push(HistoryEntry {
+            severity: toast.severity,
+            title: toast.title.clone(),
+            text: toast.text.clone(),
+        });
+        self.unread = (self.unread + 1).min(self.history.len());
+        self.toasts.push(ActiveToast {
+            handle,
+            receiver,
+            toast,
+            rect: None,
+            lines: Vec::new(),
+            actions: Vec::new(),
+        });
+        handle
+    }
+    pub(crate) fn remove(&mut self, handle: Handle<Toast>) -> bool {
+        let count = self.toasts.len();
+        self.toasts.retain(|t| t.handle != handle);
+        count != self.toasts.len()
+    }
+    #[inline(always)]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+    #[inline(always)]
+    pub(crate) fn has_history(&self) -> bool {
+        !self.history.is_empty()
+    }
+    #[inline(always)]
+    pub(crate) fn unread(&self) -> usize {
+        self.unread
+    }
+    #[inline(always)]
+    pub(crate) fn history(&self) -> &[HistoryEntry] {
+        &self.history
+    }
+    pub(crate) fn mark_as_read(&mut self) {
+        self.unread = 0;
+    }
+    pub(crate) fn clear_history(&mut self) {
+        self.history.clear();
+        self.unread = 0;
+    }
+
+    fn wrap(text: &str, width: usize) -> Vec<String> {
+        let mut lines = Vec::new();
+        for paragraph in text.lines() {
+            let mut line = String::new();
+            let mut len = 0;
+            for word in paragraph.split_whitespace() {
+                let word_len = word.chars().count();
+                if len > 0 && len + 1 + word_len > width {
+                    lines.push(std::mem::take(&mut line));
+                    len = 0;
+                }
+                if len > 0 {
+                    line.push(' ');
+                    len += 1;
+                }
+                // words that are larger than the width are split
+                for ch in word.chars() {
+                    if len == width {
+                        lines.push(std::mem::take(&mut line));
+                        len = 0;
+                    }
+                    line.push(ch);
+                    len += 1;
+                }
+            }
+            lines.push(line);
+        }
+        if lines.len() > MAX_LINES {
+            lines.truncate(MAX_LINES);
+            let last = &mut lines[MAX_LINES - 1];
+            while last.chars().count() + 3 > width {
+                last.pop();
+            }
+            last.push_str("...");
+        }
+        lines
+    }
+
+    pub(crate) fn arrange(&mut self, area: Rect) {
+        let width = MAX_WIDTH.min(area.width() as i32);
+        let mut bottom = area.bottom();
+        let mut fits = width >= 10;
+        for t in self.toasts.iter_mut().rev() {
+            t.rect = None;
+            t.actions.clear();
+            if !fits {
+                continue;
+            }
+            t.lines = Self::wrap(&t.toast.text, (width - 4) as usize);
+            if t.lines.is_empty() && t.toast.actions.is_empty() {
+                t.lines.push(String::new());
+            }
+            let height = 2 + t.lines.len() as i32 + if t.toast.actions.is_empty() { 0 } else { 1 };
+            let top = bottom + 1 - height;
+            if top < area.top() {
+                // older toasts will be shown once there is enough space
+                fits = false;
+                continue;
+            }
+            let rect = Rect::new(area.right() + 1 - width, top, area.right(), bottom);
+            let mut x = rect.left() + 2;
+            for (caption, _) in t.toast.actions.iter() {
+                let w = caption.chars().count() as i32 + 2;
+                if x + w > rect.right() - 1 {
+                    break;
+                }
+                t.actions.push((x, w));
+                x += w + 1;
+            }
+            t.rect = Some(rect);
+            bottom = top - 1;
+        }
+    }
+
+    pub(crate) fn paint(&self, surface: &mut Surface, theme: &Theme) {
+        for t in self.toasts.iter() {
+            let Some(rect) = t.rect else {
+                continue;
+            };
+            let attr = match t.toast.severity {
+                Severity::Info => theme.window.info,
+                Severity::Warning => theme.window.warning,
+                Severity::Error => theme.window.error,
+            };
+            surface.fill_rect(rect, Character::with_attributes(' ', attr));
+            surface.draw_rect(rect, LineType::Single, attr);
+            let title = if t.toast.title.is_empty() {
+                t.toast.severity.name()
+            } else {
+                t.toast.title.as_str()
+            };
+            let max_title = (rect.width() as usize).saturating_sub(8);
+            let title: String = title.chars().take(max_title).collect();
+            surface.write_char(rect.left() + 1, rect.top(), Character::with_attributes(' ', attr));
+            surface.write_string(rect.left() + 2, rect.top(), &title, attr, false);
+            surface.write_char(rect.left() + 2 + title.chars().count() as i32, rect.top(), Character::with_attributes(' ', attr));
+            surface.write_string(t.close_button_x(&rect), rect.top(), "[x]", attr, false);
+            for (index, line) in t.lines.iter().enumerate() {
+                surface.write_string(rect.left() + 2, rect.top() + 1 + index as i32, line, attr, false);
+            }
+            let y = rect.bottom() - 1;
+            for ((x, w), (caption, _)) in t.actions.iter().zip(t.toast.actions.iter()) {
+                surface.write_char(*x, y, Character::with_attributes('[', attr));
+                surface.write_string(*x + 1, y, caption, theme.button.regular.text.focused, false);
+                surface.write_char(*x + *w - 1, y, Character::with_attributes(']', attr));
+            }
+        }
+    }
+
+    pub(crate) fn on_mouse_pressed(&self, x: i32, y: i32) -> ToastAction {
+        for t in self.toasts.iter() {
+            let Some(rect) = t.rect else {
+                continue;
+            };
+            if !rect.contains(crate::graphics::Point::new(x, y)) {
+                continue;
+            }
+            let close_x = t.close_button_x(&rect);
+            if y == rect.top() && x >= close_x && x < close_x + 3 {
+                return ToastAction::Dismiss(t.handle);
+            }
+            if y == rect.bottom() - 1 {
+                for ((ax, w), (_, id)) in t.actions.iter().zip(t.toast.actions.iter()) {
+                    if x >= *ax && x < *ax + *w {
+                        return ToastAction::Action {
+                            receiver: t.receiver,
+                            toast: t.handle,
+                            id: *id,
+                        };
+                    }
+                }
+            }
+            return ToastAction::Consumed;
+        }
+        ToastAction::None
+    }
+}
@@ -8,6 +8,7 @@ mod initialization_flags;
 mod treeview;
 mod tree_data_manager;
 mod item;
+mod aggregate;
 #[cfg(test)]
 mod tests;
 
@@ -32,13 +33,17 @@ pub use crate::ui::components::listitem::formats::VolumeFormat;
 pub use crate::ui::components::listitem::formats::WeightFormat;
 pub use crate::ui::components::listitem::ListItem;
 pub use crate::ui::components::listitem::RenderMethod;
+pub use crate::ui::components::listitem::ExportFormat;
+pub use crate::ui::components::listitem::ExportScope;
 
 pub use self::initialization_flags::Flags;
 pub use self::item::Item;
+pub use self::aggregate::Aggregate;
 pub use self::treeview::TreeView;
 
 use self::tree_data_manager::TreeDataManager;
 use self::item::ItemVisibility;
 use self::item::FoldStatus;
+use self::aggregate::{Accumulator, AggregatedValue};
 
 
@@ -0,0 +1,108 @@
+use super::NumericFormat;
+use super::RenderMethod;
+
+/// The function used to combine the values of a column (for the rows of a collapsed item and for the footer of the tree view).
+///
+/// Only the numeric render methods (such as `Int64`, `UInt64`, `Size`, `Currency`, `Float`, `Percentage`, `Duration`, ...)
+/// can be combined. The result is displayed using the same format as the values of the column, except for `Count`
+/// that is always displayed as a number.
+#[derive(Copy, Clone)]
+pub enum Aggregate {
+    /// The sum of all values
+    Sum,
+    /// The number of values (the number of items that have a value for the column, of any type)
+    Count,
+    /// The smallest value
+    Min,
+    /// The largest value
+    Max,
+    /// The average (arithmetic mean) of all values
+    Average,
+    /// A custom function that receives all the values (as `f64`) and returns the aggregated value.
+    /// The function is only called if there is at least one value.
+    Custom(fn(&[f64]) -> f64),
+}
+
+// combines the values of a column (running totals - the values are only kept for a custom function)
+pub(super) struct Accumulator {
+    aggregate: Aggregate,
+    // the number of items with a value (of any type)
+    count: u64,
+    numeric_count: u64,
+    sum: f64,
+    min: f64,
+    max: f64,
+    values: Vec<f64>,
+    // the first numeric value (used to render the result with the same type and format)
+    first: Option<RenderMethod<'static>>,
+}
+
+impl Accumulator {
+    pub(super) fn new(aggregate: Aggregate) -> Self {
+        Self {
+            aggregate,
+            count: 0,
+            numeric_count: 0,
+            sum: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            values: Vec::new(),
+            first: None,
+        }
+    }
+    pub(super) fn add(&mut self, render_method: Option<RenderMethod<'_>>) {
+        let Some(rm) = render_method else {
+            return;
+        };
+        self.count += 1;
+        if let Some(value) = rm.numeric_value() {
+            if self.first.is_none() {
+                self.first = rm.with_numeric_value(value);
+            }
+            self.numeric_count += 1;
+            self.sum += value;
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+            if let Aggregate::Custom(_) = self.aggregate {
+                self.values.push(value);
+            }
+        }
+    }
+    fn value(&self) -> Option<f64> {
+        if let Aggregate::Count = self.aggregate {
+            return Some(self.count as f64);
+        }
+        if self.numeric_count == 0 {
+            return None;
+        }
+        match self.aggregate {
+            Aggregate::Sum => Some(self.sum),
+            Aggregate::Count => Some(self.count as f64),
+            Aggregate::Min => Some(self.min),
+            Aggregate::Max => Some(self.max),
+            Aggregate::Average => Some(self.sum / self.numeric_count as f64),
+            Aggregate::Custom(f) => Some(f(&self.values)),
+        }
+    }
+    pub(super) fn finish(self) -> Option<AggregatedValue> {
+        let value = self.value()?;
+        let template = match self.aggregate {
+            Aggregate::Count => RenderMethod::UInt64(0, NumericFormat::Normal),
+            _ => self.first?,
+        };
+        Some(AggregatedValue { value, template })
+    }
+}
+
+// the result of an aggregate function (kept by the tree view until its items change)
+pub(super) struct AggregatedValue {
+    pub(super) value: f64,
+    // a render method of the same type and format as the values of the column
+    template: RenderMethod<'static>,
+}
+
+impl AggregatedValue {
+    pub(super) fn render_method(&self) -> Option<RenderMethod<'static>> {
+        self.template.with_numeric_value(self.value)
+    }
+}
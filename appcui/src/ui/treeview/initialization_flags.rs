@@ -1,6 +1,6 @@
 use EnumBitFlags::EnumBitFlags;
 
-#[EnumBitFlags(bits = 8)]
+#[EnumBitFlags(bits = 16)]
 pub enum Flags {
     ScrollBars = 0x01,
     SearchBar = 0x02,
@@ -10,4 +10,5 @@ pub enum Flags {
     NoSelection = 0x20,
     HideHeader = 0x40,
     MergeBorders = 0x80,
+    ShowFooter = 0x100,
 }
\ No newline at end of file
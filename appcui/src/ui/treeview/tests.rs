@@ -2042,4 +2042,123 @@ fn check_merge_borders() {
     w.add(tv);
     a.add_window(w);
     a.run();
-}
\ No newline at end of file
+}
+#[test]
+fn check_aggregates_via_api() {
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Initial state ')
+        CheckHash(0x65CD06F738DA6B20) 
+    ";
+    let mut a = App::debug(60, 20, script).build().unwrap();
+    let mut w = window!("Test,d:f,flags: Sizeable");
+    let mut tv: TreeView<Course> = TreeView::new(layout!("d:f"), treeview::Flags::ShowFooter);
+    Course::populate_with_courses_batch(&mut tv);
+    let alice = tv.root_items()[1];
+    let math = tv.item(alice).unwrap().children()[0];
+    assert!(tv.aggregated_value(2, Handle::None).is_none());
+    tv.set_column_aggregate(2, treeview::Aggregate::Sum);
+    assert_eq!(tv.aggregated_value(2, Handle::None), Some(111.0));
+    assert_eq!(tv.aggregated_value(2, alice), Some(89.0));
+    tv.set_column_aggregate(2, treeview::Aggregate::Min);
+    assert_eq!(tv.aggregated_value(2, alice), Some(5.0));
+    tv.set_column_aggregate(1, treeview::Aggregate::Max);
+    assert_eq!(tv.aggregated_value(1, Handle::None), Some(22.0));
+    tv.set_column_aggregate(1, treeview::Aggregate::Average);
+    assert_eq!(tv.aggregated_value(1, math), Some(2.0));
+    tv.set_column_aggregate(0, treeview::Aggregate::Count);
+    assert_eq!(tv.aggregated_value(0, Handle::None), Some(13.0));
+    assert_eq!(tv.aggregated_value(0, alice), Some(11.0));
+    tv.set_column_aggregate(1, treeview::Aggregate::Custom(|v| v.len() as f64 * 10.0));
+    assert_eq!(tv.aggregated_value(1, math), Some(30.0));
+    assert!(matches!(tv.column_aggregate(1), Some(treeview::Aggregate::Custom(_))));
+    tv.clear_column_aggregate(1);
+    assert!(tv.column_aggregate(1).is_none());
+    assert!(tv.aggregated_value(1, math).is_none());
+    assert!(tv.column_aggregate(7).is_none());
+    // the cached values are recomputed when the items change
+    tv.set_column_aggregate(2, treeview::Aggregate::Sum);
+    assert_eq!(tv.aggregated_value(2, alice), Some(89.0));
+    let leaf = tv.item(math).unwrap().children()[0];
+    tv.item_mut(leaf).unwrap().value_mut().credits += 100;
+    assert_eq!(tv.aggregated_value(2, alice), Some(189.0));
+    assert_eq!(tv.aggregated_value(2, Handle::None), Some(211.0));
+    tv.item_mut(leaf).unwrap().value_mut().credits -= 100;
+    tv.add_to_parent(Course::new("Extra", 1, 11), alice);
+    assert_eq!(tv.aggregated_value(2, alice), Some(100.0));
+    // without children, the value of the item itself is used
+    tv.delete_item_children(alice);
+    assert_eq!(tv.aggregated_value(2, alice), Some(11.0));
+    assert_eq!(tv.aggregated_value(2, Handle::None), Some(33.0));
+    w.add(tv);
+    a.add_window(w);
+    a.run();
+}
+
+#[test]
+fn check_aggregates_footer_and_collapsed_items() {
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Footer with sum of credits and max relevance')
+        CheckHash(0xB6F28582D47FCFBA) 
+        Key.Pressed(Down)
+        Key.Pressed(Space)
+        Paint('2. Alice collapsed (aggregated values)')
+        CheckHash(0xE31C0A9736D74CF8) 
+        Key.Pressed(Space)
+        Paint('3. Alice expanded')
+        CheckHash(0xD3C474B6020E4956) 
+    ";
+    let mut a = App::debug(80, 24, script).build().unwrap();
+    let mut w = window!("Test,d:f,flags: Sizeable");
+    let mut tv: TreeView<Course> = TreeView::new(layout!("d:f"), treeview::Flags::ShowFooter | treeview::Flags::ScrollBars);
+    tv.set_column_aggregate(1, treeview::Aggregate::Max);
+    tv.set_column_aggregate(2, treeview::Aggregate::Sum);
+    Course::populate_with_courses_batch(&mut tv);
+    w.add(tv);
+    a.add_window(w);
+    a.run();
+}
+
+#[test]
+fn check_export() {
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Initial state')
+        CheckHash(0x9B1121BACD1695E3)
+        Key.Pressed(Ctrl+Insert)
+        CheckClipboardText('Name\\tRelevance\\tCredits\\nMath\\t1\\t23\\n')
+    ";
+    let mut a = App::debug(60, 20, script).build().unwrap();
+    let mut w = window!("Test,d:f,flags: Sizeable");
+    let mut tv: TreeView<Course> = TreeView::new(layout!("d:f"), treeview::Flags::ScrollBars);
+    Course::populate_with_courses_batch(&mut tv);
+    let alice = tv.root_items()[1];
+    let math = tv.item(alice).unwrap().children()[0];
+    let english = tv.item(alice).unwrap().children()[1];
+    tv.collapse_item(english, false);
+    assert_eq!(
+        tv.export(ExportFormat::Csv, ExportScope::All, true),
+        "Name,Relevance,Credits\nJohn,20,10\nAlice,21,11\nMath,1,9\nAdvance Calculus,2,8\nGeometry,1,6\nLogic,3,9\nEnglish,2,7\nAI,1,10\nNeural Networks,2,8\nDeep Learning,3,9\nBob,22,12\n"
+    );
+    tv.select_item(math, true);
+    tv.select_item(english, true);
+    assert_eq!(
+        tv.export(ExportFormat::Json, ExportScope::Selected, true),
+        "[\n  {\"Name\": \"Math\", \"Relevance\": 1, \"Credits\": 9},\n  {\"Name\": \"English\", \"Relevance\": 2, \"Credits\": 7}\n]\n"
+    );
+    tv.select_item(math, false);
+    tv.select_item(english, false);
+    // a collapsed item exports its aggregated values
+    tv.set_column_aggregate(2, treeview::Aggregate::Sum);
+    tv.move_cursor_to(math);
+    assert_eq!(
+        tv.export(ExportFormat::Markdown, ExportScope::CurrentGroup, false),
+        "| Name | Relevance | Credits |\n| --- | --- | --- |\n| Math | 1 | 9 |\n| Advance Calculus | 2 | 8 |\n| Geometry | 1 | 6 |\n| Logic | 3 | 9 |\n"
+    );
+    tv.collapse_item(math, false);
+    assert_eq!(tv.export(ExportFormat::Tsv, ExportScope::CurrentGroup, false), "Name\tRelevance\tCredits\nMath\t1\t23\n");
+    w.add(tv);
+    a.add_window(w);
+    a.run();
+}
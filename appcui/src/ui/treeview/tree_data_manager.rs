@@ -1,4 +1,5 @@
 use std::cmp::Ordering;
+use super::Accumulator;
 use super::FoldStatus;
 use super::Item;
 use super::ItemVisibility;
@@ -308,6 +309,21 @@ where
         }
     }
 
+    // adds the values of a column for all the items without children (that are not filtered out) from the subtree of an item
+    pub(super) fn aggregate(&self, handle: Handle<Item<T>>, column_index: u16, acc: &mut Accumulator) {
+        if let Some(item) = self.get(handle) {
+            if item.children.is_empty() {
+                if item.is_visible() {
+                    acc.add(item.value().render_method(column_index));
+                }
+            } else {
+                for h in item.children.iter() {
+                    self.aggregate(*h, column_index, acc);
+                }
+            }
+        }
+    }
+
     pub(super) fn set_fold_status(&mut self, parent: Handle<Item<T>>, fold_status: FoldStatus)-> bool {
         if let Some(item) = self.get_mut(parent) {
             if item.fold_status != FoldStatus::NonExpandable {
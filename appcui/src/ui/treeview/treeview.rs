@@ -1,9 +1,12 @@
 use super::events::EventData;
-use super::{Flags, FoldStatus, Item, TreeDataManager};
+use super::{Accumulator, Aggregate, AggregatedValue, ExportFormat, ExportScope, Flags, FoldStatus, Item, TreeDataManager};
+use components::listitem::export::Exporter;
 use components::listitem::render_method::RenderData;
 use appcui_proc_macro::*;
 
 use crate::prelude::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
 
 #[derive(Copy,Clone,Debug)]
 enum UpdateVisibleItemsOperation {
@@ -51,6 +54,9 @@ where
     update_item_list_enabled: bool,
     start_mouse_select: usize,
     mouse_check_mode: SelectMode,
+    aggregates: Vec<Option<Aggregate>>,
+    // the aggregated values (indexed by the index of the item handle and the column index) - cleared every time the items change
+    aggregate_cache: RefCell<HashMap<(usize, u16), Option<AggregatedValue>>>,
 }
 impl<T> TreeView<T>
 where
@@ -118,6 +124,8 @@ where
             start_mouse_select: 0,
             mouse_check_mode: SelectMode::False,
             hover_status: HoverStatus::None,
+            aggregates: Vec::new(),
+            aggregate_cache: RefCell::new(HashMap::new()),
         };
         // add columnes (if described in the type T)
         for i in 0..T::columns_count() {
@@ -264,7 +272,168 @@ where
         self.goto_handle(handle, false)
     }
 
+    /// Sets the aggregate function for a column. The aggregated value of the column is displayed instead of the value
+    /// of a collapsed item (computed over the items without children from its subtree) and in the footer row
+    /// (computed over the entire tree), if the tree view was created with the `ShowFooter` flag.
+    /// Items that are filtered out (via the search bar) are not included.
+    ///
+    /// # Example
+    /// ```rust
+    /// use appcui::prelude::*;
+    ///
+    /// #[derive(ListItem)]
+    /// struct Bill {
+    ///    #[Column(name="Name", width=20)]
+    ///    name: &'static str,
+    ///    #[Column(name="Cost", width=10, align=Right)]
+    ///    cost: u64
+    /// }
+    /// let mut tree = TreeView::<Bill>::new(layout!("d:f"), treeview::Flags::ShowFooter);
+    /// tree.set_column_aggregate(1, treeview::Aggregate::Sum);
+    /// let account = tree.add(Bill { name: "Account", cost: 0 });
+    /// tree.add_to_parent(Bill { name: "Storage", cost: 10 }, account);
+    /// tree.add_to_parent(Bill { name: "Compute", cost: 32 }, account);
+    /// assert_eq!(tree.aggregated_value(1, account), Some(42.0));
+    /// ```
+    pub fn set_column_aggregate(&mut self, column_index: u16, aggregate: Aggregate) {
+        let index = column_index as usize;
+        if index >= self.aggregates.len() {
+            self.aggregates.resize(index + 1, None);
+        }
+        self.aggregates[index] = Some(aggregate);
+        self.aggregate_cache.get_mut().clear();
+    }
+
+    /// Removes the aggregate function of a column (the column will display the values of the items, even for the collapsed items)
+    pub fn clear_column_aggregate(&mut self, column_index: u16) {
+        if let Some(a) = self.aggregates.get_mut(column_index as usize) {
+            *a = None;
+        }
+        self.aggregate_cache.get_mut().clear();
+    }
+
+    /// Returns the aggregate function of a column or None if the column has no aggregate function
+    #[inline(always)]
+    pub fn column_aggregate(&self, column_index: u16) -> Option<Aggregate> {
+        self.aggregates.get(column_index as usize).copied().flatten()
+    }
+
+    /// Returns the aggregated value of a column for the subtree of an item (or for the entire tree if the handle is `Handle::None`).
+    /// The value is computed over the items without children. If the column has no aggregate function or the column
+    /// has no numeric values, the method returns None.
+    pub fn aggregated_value(&self, column_index: u16, item_handle: Handle<Item<T>>) -> Option<f64> {
+        self.with_aggregated_value(column_index, item_handle, |v| v.value)
+    }
+
+    fn aggregated_render_method(&self, column_index: u16, item_handle: Handle<Item<T>>) -> Option<RenderMethod<'static>> {
+        self.with_aggregated_value(column_index, item_handle, |v| v.render_method()).flatten()
+    }
+
+    // the aggregated values are computed once (the cache is cleared when the items or the aggregate functions change)
+    fn with_aggregated_value<R>(&self, column_index: u16, item_handle: Handle<Item<T>>, f: impl FnOnce(&AggregatedValue) -> R) -> Option<R> {
+        let aggregate = self.column_aggregate(column_index)?;
+        if !item_handle.is_none() {
+            self.manager.get(item_handle)?;
+        }
+        let mut cache = self.aggregate_cache.borrow_mut();
+        let value = cache.entry((item_handle.index(), column_index)).or_insert_with(|| {
+            let mut acc = Accumulator::new(aggregate);
+            if item_handle.is_none() {
+                for h in self.manager.roots() {
+                    self.manager.aggregate(*h, column_index, &mut acc);
+                }
+            } else {
+                self.manager.aggregate(item_handle, column_index, &mut acc);
+            }
+            acc.finish()
+        });
+        value.as_ref().map(f)
+    }
+
+    /// Exports the rows of the tree view (in the order they are displayed, without the items that are filtered out
+    /// or the children of collapsed items) in a specific format. Only the visible columns (with a width larger than 0)
+    /// are exported. The values are formatted the same way they are displayed (including the aggregated values of
+    /// collapsed items), or exported as raw values (numbers, dates in ISO format, ...) if the `raw_values` parameter is `true`.
+    /// For the `ExportScope::CurrentGroup` scope, the current item and all of its (visible) children are exported.
+    ///
+    /// # Example
+    /// ```rust
+    /// use appcui::prelude::*;
+    ///
+    /// #[derive(ListItem)]
+    /// struct FileInfo {
+    ///    #[Column(name="Name", width=20)]
+    ///    name: &'static str,
+    ///    #[Column(name="Size", width=10, align=Right)]
+    ///    size: u64
+    /// }
+    /// let mut tree = TreeView::<FileInfo>::new(layout!("d:f"), treeview::Flags::None);
+    /// let folder = tree.add(FileInfo { name: "Folder", size: 0 });
+    /// tree.add_to_parent(FileInfo { name: "File", size: 10 }, folder);
+    /// let tsv = tree.export(treeview::ExportFormat::Tsv, treeview::ExportScope::All, true);
+    /// assert_eq!(tsv, "Name\tSize\nFolder\t0\nFile\t10\n");
+    /// ```
+    pub fn export(&self, format: ExportFormat, scope: ExportScope, raw_values: bool) -> String {
+        match scope {
+            ExportScope::All => self.export_items(format, raw_values, 0..self.item_list.len(), |_| true),
+            ExportScope::Selected => self.export_items(format, raw_values, 0..self.item_list.len(), |item| item.is_selected()),
+            ExportScope::CurrentGroup => {
+                let end = match self.current_item() {
+                    Some(current) => {
+                        let depth = current.depth;
+                        self.item_list[self.pos + 1..]
+                            .iter()
+                            .position(|h| self.manager.get(*h).map(|item| item.depth <= depth).unwrap_or(true))
+                            .map(|p| self.pos + 1 + p)
+                            .unwrap_or(self.item_list.len())
+                    }
+                    None => return self.export_items(format, raw_values, 0..0, |_| true),
+                };
+                self.export_items(format, raw_values, self.pos..end, |_| true)
+            }
+        }
+    }
+
+    fn export_items<F>(&self, format: ExportFormat, raw_values: bool, range: std::ops::Range<usize>, include: F) -> String
+    where
+        F: Fn(&Item<T>) -> bool,
+    {
+        let columns: Vec<(usize, &Column)> = self.header.columns().iter().enumerate().filter(|(_, c)| c.width > 0).collect();
+        let names: Vec<(&str, TextAlignment)> = columns.iter().map(|(_, c)| (c.name(), c.alignment)).collect();
+        let mut exporter = Exporter::new(format, raw_values, &names);
+        for h in &self.item_list[range] {
+            if let Some(item) = self.manager.get(*h) {
+                if include(item) {
+                    exporter.add_row(columns.iter().map(|(i, _)| self.cell_render_method(item, *i as u16)));
+                }
+            }
+        }
+        exporter.finish()
+    }
+
+    // copies the selected items (or the current item if no item is selected) to the clipboard (as tab separated values)
+    fn copy_to_clipboard(&self) {
+        let text = if self.manager.selected_count() > 0 {
+            self.export(ExportFormat::Tsv, ExportScope::Selected, false)
+        } else if self.pos < self.item_list.len() {
+            self.export_items(ExportFormat::Tsv, false, self.pos..self.pos + 1, |_| true)
+        } else {
+            return;
+        };
+        RuntimeManager::get().backend_mut().set_clipboard_text(&text);
+    }
+
+    // a collapsed item displays the aggregated value for the columns that have an aggregate function
+    fn cell_render_method<'a>(&self, item: &'a Item<T>, column_index: u16) -> Option<RenderMethod<'a>> {
+        if (item.fold_status == FoldStatus::Collapsed) && (!item.children.is_empty()) && self.column_aggregate(column_index).is_some() {
+            return self.aggregated_render_method(column_index, item.handle);
+        }
+        item.value().render_method(column_index)
+    }
+
     fn update_item_list(&mut self, op: UpdateVisibleItemsOperation) {
+        // items were added, deleted, modified or filtered
+        self.aggregate_cache.get_mut().clear();
         let current_handle = if self.pos < self.item_list.len() {
             self.item_list[self.pos]
         } else {
@@ -340,6 +509,7 @@ where
     /// Returns a mutable reference of the item with a specified handle or None if the handle is invalid (e.g. the handle does not exist in the tree view)
     #[inline(always)]
     pub fn item_mut(&mut self, item_handle: Handle<Item<T>>) -> Option<&mut Item<T>> {
+        self.aggregate_cache.get_mut().clear();
         self.manager.get_mut(item_handle)
     }
 
@@ -368,6 +538,7 @@ where
     pub fn current_item_mut(&mut self) -> Option<&mut Item<T>> {
         if self.pos < self.item_list.len() {
             let h = self.item_list[self.pos];
+            self.aggregate_cache.get_mut().clear();
             self.manager.get_mut(h)
         } else {
             None
@@ -396,6 +567,7 @@ where
     pub fn root_item_mut(&mut self, index: usize) -> Option<&mut Item<T>> {
         let len = self.manager.roots().len();
         if index < len {
+            self.aggregate_cache.get_mut().clear();
             self.manager.get_mut(self.manager.roots()[index])
         } else {
             None
@@ -549,16 +721,14 @@ where
         if !self.flags.contains(Flags::HideHeader) {
             sz.height = sz.height.saturating_sub(1);
         }
+        if self.flags.contains(Flags::ShowFooter) {
+            sz.height = sz.height.saturating_sub(1);
+        }
         sz
     }
     #[inline(always)]
     fn visible_items(&self) -> usize {
-        let count = self.size().height as usize;
-        if !self.flags.contains(Flags::HideHeader) {
-            count.saturating_sub(1)
-        } else {
-            count
-        }
+        self.visible_space().height as usize
     }
     #[inline(always)]
     fn item_width(&self) -> u32 {
@@ -670,7 +840,7 @@ where
                     surface.set_origin(l + extra, y);
                 }
             }
-            if let Some(render_method) = ListItem::render_method(item.value(), 0) {
+            if let Some(render_method) = self.cell_render_method(item, 0) {
                 rd.width = c.width as u16;
                 rd.alignment = c.alignment;
                 rd.attr = if attr.is_none() { item.render_attr() } else { attr };
@@ -692,7 +862,7 @@ where
                 surface.set_relative_clip(c.x.max(min_left), y, r.max(min_left), y);
             }
             surface.set_origin(c.x, y);
-            if let Some(render_method) = ListItem::render_method(item.value(), index as u16) {
+            if let Some(render_method) = self.cell_render_method(item, index as u16) {
                 rd.width = c.width as u16;
                 rd.alignment = c.alignment;
 
@@ -767,6 +937,53 @@ where
         surface.reset_clip();
         surface.reset_origin();
     }
+    fn paint_footer(&self, surface: &mut Surface, theme: &Theme) {
+        let text = match () {
+            _ if !self.is_active() => theme.header.text.inactive,
+            _ if self.has_focus() => theme.header.text.focused,
+            _ => theme.header.text.normal,
+        };
+        let sz = self.size();
+        let y = sz.height as i32 - 1;
+        let width = sz.width as i32;
+        if y < 0 {
+            return;
+        }
+        surface.fill_horizontal_line(0, y, width - 1, Character::with_attributes(' ', text));
+        let frozen_columns = self.header.frozen_columns() as usize;
+        let columns = self.header.columns();
+        let min_left = if frozen_columns == 0 {
+            0
+        } else {
+            let c = &columns[frozen_columns - 1];
+            c.x + c.width as i32 + 1
+        };
+        let mut rd = RenderData {
+            theme,
+            alignment: TextAlignment::Left,
+            width: 0,
+            attr: Some(text),
+        };
+        for (index, c) in columns.iter().enumerate() {
+            let r = c.x + c.width as i32;
+            if (r < 0) || (c.x >= width) || (c.width == 0) {
+                continue;
+            }
+            if let Some(render_method) = self.aggregated_render_method(index as u16, Handle::None) {
+                if index < frozen_columns {
+                    surface.set_relative_clip(c.x, y, r, y);
+                } else {
+                    surface.set_relative_clip(c.x.max(min_left), y, r.max(min_left), y);
+                }
+                surface.set_origin(c.x, y);
+                rd.width = c.width as u16;
+                rd.alignment = c.alignment;
+                render_method.paint(surface, &rd);
+            }
+        }
+        surface.reset_clip();
+        surface.reset_origin();
+    }
     fn autoresize_column(&mut self, column_index: u16) {
         let mut new_width = 0u32;
         let mut found = false;
@@ -1000,7 +1217,8 @@ where
     fn mouse_pos_to_index(&self, x: i32, y: i32) -> Option<usize> {
         let sz = self.size();
         let start_y = if self.flags.contains(Flags::HideHeader) { 0 } else { 1 };
-        if (y >= start_y) && (x >= 0) && (x < sz.width as i32) && (y < sz.height as i32) {
+        let end_y = if self.flags.contains(Flags::ShowFooter) { sz.height as i32 - 1 } else { sz.height as i32 };
+        if (y >= start_y) && (x >= 0) && (x < sz.width as i32) && (y < end_y) {
             let new_pos = self.top_view + (y - start_y) as usize;
             if new_pos < self.item_list.len() {
                 Some(new_pos)
@@ -1025,6 +1243,7 @@ where
         HoverStatus::None
     }
     fn process_key_pressed(&mut self, key: Key) -> bool {
+        let (key, _) = KeyMap::translate_key("treeview", key, '\0');
         // process key for items
         match key.value() {
             // movements
@@ -1098,6 +1317,10 @@ where
                 self.reverse_fold(true);
                 true
             }
+            key!("Ctrl+C") | key!("Ctrl+Insert") => {
+                self.copy_to_clipboard();
+                true
+            }
             key!("Enter") => {
                 if self.comp.is_in_edit_mode() {
                     // will be process separately
@@ -1195,6 +1418,10 @@ where
         }
         // paint items
         self.paint_items(surface, theme);
+        // paint the footer (aggregated values)
+        if self.flags.contains(Flags::ShowFooter) {
+            self.paint_footer(surface, theme);
+        }
         // paint separation lines (columns) - show wheather or not HideHeader is set or not
         self.header.paint_columns(surface, theme, &self.base, self.flags.contains(Flags::MergeBorders));
         // paint scroll bars and searh bars
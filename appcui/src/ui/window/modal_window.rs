@@ -129,11 +129,38 @@ impl<T> ModalWindow<T> {
         }
         None
     }
+
+    /// Displays the modal window without blocking (the event loop of the application keeps running).
+    /// Returns a `JoinHandle` that resolves, once the window is closed, to the result set by `exit_with`
+    /// method or to `None` if the window was closed using the `exit` method.
+    pub(crate) fn show_async<U>(object: U) -> JoinHandle<Option<T>>
+    where
+        T: 'static,
+        U: Control + WindowControl + ModalWindowMethods<T> + 'static,
+        U: DerefMut<Target = ModalWindow<T>>,
+    {
+        let (join_handle, completion) = JoinHandle::pending();
+        let handle = RuntimeManager::get().add_modal_window(object);
+        if handle.is_none() {
+            completion.complete(None);
+            return join_handle;
+        }
+        RuntimeManager::get().add_async_modal_window(
+            handle.cast(),
+            Box::new(move |rm: &mut RuntimeManager| {
+                let result = rm.get_control_mut(handle).and_then(|obj| obj.result.take());
+                completion.complete(result);
+            }),
+        );
+        join_handle
+    }
 }
 impl<T: 'static> Control for ModalWindow<T> {}
 impl<T> OnThemeChanged for ModalWindow<T> {}
+impl<T> PersistState for ModalWindow<T> {}
 impl<T> GenericMenuEvents for ModalWindow<T> {}
 impl<T> AppBarEvents for ModalWindow<T> {}
+impl<T> ToastEvents for ModalWindow<T> {}
 impl<T> DesktopEvents for ModalWindow<T> {}
 impl<T> ToolBarEvents for ModalWindow<T> {}
 impl<T> WindowEvents for ModalWindow<T> {}
@@ -2161,4 +2161,26 @@ fn check_window_type_panel_background_error() {
     w.set_hotkey(key!("Alt+2"));
     a.add_window(w);
     a.run();
-}
\ No newline at end of file
+}
+#[test]
+fn check_window_key_map() {
+    let script = "
+        Paint.Enable(false)
+        Paint('1. Initial state')
+        CheckHash(0xA861C55560189AA8)
+        Key.Pressed(Escape)
+        Paint('2. Escape no longer closes the window')
+        CheckHash(0xA861C55560189AA8)
+        Key.Pressed(Ctrl+K)
+        Paint('3. Chord pending (Ctrl+K ... in command bar)')
+        CheckHash(0x6804577E7C30E6AF)
+        Key.Pressed(W)
+        Paint('4. Window closed')
+        CheckHash(0x5B3E1BE9E5CB3C3D)
+    ";
+    let mut km = KeyMap::new();
+    km.bind("window.close", "Ctrl+K W").unwrap();
+    let mut a = App::debug(60, 12, script).command_bar().key_map(km).build().unwrap();
+    a.add_window(window!("Title,x:1,y:1,w:30,h:8"));
+    a.run();
+}
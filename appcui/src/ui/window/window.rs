@@ -38,6 +38,7 @@ pub struct Window {
     old_rect: Rect,
     hotkey_handle: Handle<super::toolbar::HotKey>,
     tag_handle: Handle<super::toolbar::Tag>,
+    id: String,
 }
 
 const MOVE_TO_LOWER_MARGIN: i32 = -100000;
@@ -254,6 +255,7 @@ impl Window {
             old_rect: Rect::new(0, 0, 0, 0),
             hotkey_handle: Handle::None,
             tag_handle: Handle::None,
+            id: String::new(),
         };
         win.set_size_bounds(12, 3, u16::MAX, u16::MAX);
         win.set_margins(1, 1, 1, 1);
@@ -523,6 +525,30 @@ impl Window {
             self.update_positions(self.size());
         }
     }
+    /// Sets the identifier of the window.
+    ///
+    /// The identifier is a stable key (it should be the same every time the application runs) that is used to match
+    /// the window with its saved state when a desktop layout is restored (see `Desktop::save_layout()` and `Desktop::restore_layout(...)`).
+    /// Windows without an identifier are not included in a saved layout. The identifier can contain any character, but it
+    /// should be unique (a layout can not be restored if more than one window has the identifier of a saved window).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut win = window!("'Logs',a:c,w:40,h:10");
+    /// win.set_id("logs");
+    /// assert_eq!(win.id(), "logs");
+    /// ```
+    pub fn set_id(&mut self, id: &str) {
+        self.id.clear();
+        self.id.push_str(id);
+    }
+    /// Returns the identifier of the window (an empty string if no identifier was set).
+    pub fn id(&self) -> &str {
+        &self.id
+    }
     /// Sets an automatically assigned hotkey for the window.
     ///
     /// This method finds the first available hotkey in the system and assigns it to this window.
@@ -644,6 +670,30 @@ impl Window {
         }
     }
 
+    // the position and size of the window when it is not maximized
+    pub(crate) fn normal_rect(&self) -> Rect {
+        if self.maximized {
+            self.old_rect
+        } else {
+            Rect::with_point_and_size(self.position(), self.size())
+        }
+    }
+    #[inline(always)]
+    pub(crate) fn is_maximized(&self) -> bool {
+        self.maximized
+    }
+    pub(crate) fn set_normal_rect(&mut self, r: Rect, maximized: bool) {
+        let r = if maximized {
+            self.old_rect = r;
+            RuntimeManager::get().get_desktop_rect()
+        } else {
+            r
+        };
+        self.set_position(r.left(), r.top());
+        self.set_size(r.width() as u16, r.height() as u16);
+        self.maximized = maximized;
+    }
+
     fn get_children_start_index(index: VectorIndex, count: usize, start_from_current: bool) -> VectorIndex {
         if (start_from_current) && (index.in_range(count)) {
             index
@@ -852,11 +902,14 @@ impl Window {
             }
         }
     }
-    fn on_mouse_release(&mut self) -> EventProcessStatus {
+    fn on_mouse_release(&mut self, x: i32, y: i32) -> EventProcessStatus {
         self.toolbar.set_current_item_pressed(false);
         self.base.set_key_input_before_children_flag(false);
 
         if self.rm_status != ResizeMoveStatus::None {
+            // in tiling mode the desktop docks the window where it was dropped
+            let point = (self.rm_status == ResizeMoveStatus::MoveByMouse).then(|| Point::new(x + self.screen_clip.left, y + self.screen_clip.top));
+            RuntimeManager::get().request_dock_window(self.handle, point);
             self.rm_status = ResizeMoveStatus::None;
         } else {
             self.on_toolbar_item_clicked(self.toolbar.get_current_item_handle());
@@ -939,21 +992,6 @@ impl Window {
         self.handle.cast()
     }
 
-    /// Returns the background task associated with a specific handle.
-    ///
-    /// # Parameters
-    ///
-    /// * `handle` - A handle to a background task
-    ///
-    /// # Returns
-    ///
-    /// * `Some(BackgroundTask<T, R>)` - The background task if the handle is valid
-    /// * `None` - If the handle is invalid
-    #[inline(always)]
-    pub fn background_task<T: Send + 'static, R: Send + 'static>(&self, handle: Handle<BackgroundTask<T, R>>) -> Option<BackgroundTask<T, R>> {
-        BackgroundTask::from_handle(handle)
-    }
-
     /// Executes a callback function over a specific control (if that control handle is valid)
     ///
     /// # Example
@@ -964,7 +1002,7 @@ impl Window {
     ///    let handle_b = w.add(button!("Button,a:C,w:10"));
     ///    Window::update_control(handle_b, |button| { button.set_caption("New text"); });
     /// ```
-    pub fn update_control<T: Control + 'static>(handle: Handle<T>, run: fn(&mut T)) {
+    pub fn update_control<T: Control + 'static, F: FnOnce(&mut T)>(handle: Handle<T>, run: F) {
         if let Some(control) = RuntimeManager::get().get_control_mut(handle) {
             run(control);
         }
@@ -1024,6 +1062,7 @@ impl OnKeyPressed for Window {
                 key!("Escape") | key!("Enter") | key!("Space") | key!("Tab") => {
                     self.rm_status = ResizeMoveStatus::None;
                     self.base.set_key_input_before_children_flag(false);
+                    RuntimeManager::get().request_dock_window(self.handle, None);
                     return EventProcessStatus::Processed;
                 }
                 key!("Up") => {
@@ -1086,6 +1125,7 @@ impl OnKeyPressed for Window {
                 _ => return EventProcessStatus::Ignored,
             }
         } else {
+            let (key, _) = KeyMap::translate_key("window", key, '\0');
             match key.value() {
                 key!("Tab") => {
                     if let Some(new_child) = Window::find_next_child_control(self.handle, true, true, true) {
@@ -1171,7 +1211,7 @@ impl OnMouseEvent for Window {
             MouseEvent::Leave => self.on_mouse_leave(),
             MouseEvent::Over(point) => self.on_mouse_over(point.x, point.y),
             MouseEvent::Pressed(event) => self.on_mouse_pressed(event.x, event.y),
-            MouseEvent::Released(_) => self.on_mouse_release(),
+            MouseEvent::Released(event) => self.on_mouse_release(event.x, event.y),
             MouseEvent::DoubleClick(_) => EventProcessStatus::Ignored,
             MouseEvent::Drag(event) => self.on_mouse_drag(event.x, event.y),
             MouseEvent::Wheel(_) => EventProcessStatus::Ignored,
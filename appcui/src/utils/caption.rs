@@ -1,3 +1,4 @@
+use crate::graphics::unicode;
 use crate::input::*;
 
 #[repr(u8)]
@@ -12,6 +13,7 @@ pub(crate) enum ExtractHotKeyMethod {
 #[derive(Clone, Default, Debug, Eq, PartialEq)]
 pub(crate) struct Caption {
     text: String,
+    // the width (in cells) of the text
     chars_count: usize,
     hotkey_pos: usize,
     hotkey: Key,
@@ -59,7 +61,7 @@ impl Caption {
             self.hotkey_pos = 0;
             self.hotkey = Key::default();
         }
-        self.chars_count = self.text.chars().count();
+        self.chars_count = unicode::text_width(&self.text) as usize;
     }
     #[inline(always)]
     pub(crate) fn text(&self) -> &str {
@@ -1,3 +1,4 @@
+use crate::system::Locale;
 use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 
 static SHORT_MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
@@ -380,15 +381,14 @@ impl FormatDuration {
         Some(unsafe { core::str::from_utf8_unchecked(&buf[index..]) })
     }
     #[inline(always)]
-    fn write_number(value: i64, suffix: u8, pos: usize, output: &mut [u8]) -> Option<usize> {
-        if output.len() <= pos {
+    // writes the number followed by the unit (the last byte is written at `pos`)
+    fn write_number(value: i64, unit: &str, pos: usize, output: &mut [u8]) -> Option<usize> {
+        if output.len() <= pos || unit.len() > pos {
             return None;
         }
-        output[pos] = suffix;
-        if pos == 0 {
-            return None;
-        }
-        let mut index = pos - 1;
+        let start = pos + 1 - unit.len();
+        output[start..=pos].copy_from_slice(unit.as_bytes());
+        let mut index = start - 1;
         let mut value = value;
         loop {
             output[index] = ((value % 10) as u8) + 48;
@@ -417,37 +417,44 @@ impl FormatDuration {
         }
         let mut index = buf.len() - 1;
         let mut seconds = d.num_seconds();
+        let locale = Locale::current();
+        let (second_unit, minute_unit, hour_unit, day_unit) = (
+            locale.text("unit.seconds"),
+            locale.text("unit.minutes"),
+            locale.text("unit.hours"),
+            locale.text("unit.days"),
+        );
 
         // just seconds
         if seconds < 60 {
-            index = FormatDuration::write_number(seconds, b's', index, buf)?;
+            index = FormatDuration::write_number(seconds, second_unit, index, buf)?;
             return Some(unsafe { core::str::from_utf8_unchecked(&buf[index..]) });
         }
         let mut minutes = seconds / 60;
         seconds %= 60;
-        index = FormatDuration::write_number(seconds, b's', index, buf)?;
+        index = FormatDuration::write_number(seconds, second_unit, index, buf)?;
         index = FormatDuration::write_space_ahead(index, buf)?;
 
         // just minutes and seconds
         if minutes < 60 {
-            index = FormatDuration::write_number(minutes, b'm', index, buf)?;
+            index = FormatDuration::write_number(minutes, minute_unit, index, buf)?;
             return Some(unsafe { core::str::from_utf8_unchecked(&buf[index..]) });
         }
         let mut hours = minutes / 60;
         minutes %= 60;
-        index = FormatDuration::write_number(minutes, b'm', index, buf)?;
+        index = FormatDuration::write_number(minutes, minute_unit, index, buf)?;
         index = FormatDuration::write_space_ahead(index, buf)?;
 
         // just hours, minutes and seconds
         if hours < 24 {
-            index = FormatDuration::write_number(hours, b'h', index, buf)?;
+            index = FormatDuration::write_number(hours, hour_unit, index, buf)?;
             return Some(unsafe { core::str::from_utf8_unchecked(&buf[index..]) });
         }
         let days = hours / 24;
         hours %= 24;
-        index = FormatDuration::write_number(hours, b'h', index, buf)?;
+        index = FormatDuration::write_number(hours, hour_unit, index, buf)?;
         index = FormatDuration::write_space_ahead(index, buf)?;
-        index = FormatDuration::write_number(days, b'd', index, buf)?;
+        index = FormatDuration::write_number(days, day_unit, index, buf)?;
 
         Some(unsafe { core::str::from_utf8_unchecked(&buf[index..]) })
     }
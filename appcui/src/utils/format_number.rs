@@ -1,7 +1,9 @@
+use crate::system::Locale;
 use core::panic;
 use std::ops::{Add, Div, DivAssign, Mul, Rem, Sub};
 
 // order
+// unit
 // suffix
 // number (from right to left + groups)
 // representation_digis
@@ -17,6 +19,10 @@ pub(crate) struct FormatNumber {
     number_of_decimals: u8,
     prefix: &'static str,
     suffix: &'static str,
+    // the id of a message from the locale (written after the suffix)
+    unit: &'static str,
+    unit_width: u8,
+    unit_right_aligned: bool,
 }
 
 impl FormatNumber {
@@ -73,6 +79,9 @@ impl FormatNumber {
             representation_digits: 0,
             prefix: "",
             suffix: "",
+            unit: "",
+            unit_width: 0,
+            unit_right_aligned: false,
         }
     }
     #[inline(always)]
@@ -118,20 +127,14 @@ impl FormatNumber {
             panic!("Invalid number of representation digits for FormatNumber (expected a number greater than 0)");
         }
         match self.base {
-            2 => {
-                if value > 128 {
-                    panic!("Invalid number of representation digits for FormatNumber (maximum number of digits is 128 for base 2)");
-                }
+            2 if value > 128 => {
+                panic!("Invalid number of representation digits for FormatNumber (maximum number of digits is 128 for base 2)");
             }
-            8 => {
-                if value > 43 {
-                    panic!("Invalid number of representation digits for FormatNumber (maximum number of digits is 43 for base 8)");
-                }
+            8 if value > 43 => {
+                panic!("Invalid number of representation digits for FormatNumber (maximum number of digits is 43 for base 8)");
             }
-            10 => {
-                if value > 39 {
-                    panic!("Invalid number of representation digits for FormatNumber (maximum number of digits is 39 for base 10)");
-                }
+            10 if value > 39 => {
+                panic!("Invalid number of representation digits for FormatNumber (maximum number of digits is 39 for base 10)");
             }
             16
                 if value > 32 => {
@@ -148,6 +151,23 @@ impl FormatNumber {
         self.suffix = suffix;
         self
     }
+    // the unit is the text of a message from the current locale (e.g. "unit.kilograms"), padded with spaces (on the right)
+    // up to `width` characters so that the numbers with different units remain aligned
+    #[inline(always)]
+    pub(crate) const fn unit(mut self, id: &'static str, width: u8) -> Self {
+        self.unit = id;
+        self.unit_width = width;
+        self.unit_right_aligned = false;
+        self
+    }
+    // same as `unit(...)`, but the padding spaces are added before the unit
+    #[inline(always)]
+    pub(crate) const fn right_aligned_unit(mut self, id: &'static str, width: u8) -> Self {
+        self.unit = id;
+        self.unit_width = width;
+        self.unit_right_aligned = true;
+        self
+    }
     #[inline(always)]
     pub(crate) const fn prefix(mut self, prefix: &'static str) -> Self {
         self.prefix = prefix;
@@ -177,6 +197,27 @@ impl FormatNumber {
         buffer[pos..offset].copy_from_slice(value.as_bytes());
         Some(pos)
     }
+    fn write_unit(&self, offset: usize, buffer: &mut [u8]) -> Option<usize> {
+        if self.unit.is_empty() {
+            return Some(offset);
+        }
+        let text = Locale::current().text(self.unit);
+        let padding = (self.unit_width as usize).saturating_sub(text.chars().count());
+        let pos = if self.unit_right_aligned { offset } else { self.write_spaces(padding, offset, buffer)? };
+        let pos = self.write_str(text, pos, buffer)?;
+        if self.unit_right_aligned {
+            self.write_spaces(padding, pos, buffer)
+        } else {
+            Some(pos)
+        }
+    }
+    fn write_spaces(&self, count: usize, offset: usize, buffer: &mut [u8]) -> Option<usize> {
+        if count > offset || offset > buffer.len() {
+            return None;
+        }
+        buffer[offset - count..offset].fill(b' ');
+        Some(offset - count)
+    }
     fn write_integer_number<T: FormatableInteger>(&self, mut value: T, offset: usize, buffer: &mut [u8]) -> Option<usize> {
         if (offset > buffer.len()) || (offset == 0) {
             return None;
@@ -326,7 +367,8 @@ impl FormatNumber {
             };
             (value.fract().abs() * factor) as u64
         };
-        let pos = self.write_str(self.suffix, len, output_buffer)?;
+        let pos = self.write_unit(len, output_buffer)?;
+        let pos = self.write_str(self.suffix, pos, output_buffer)?;
         let pos = self.write_decimals(decimans, pos, output_buffer)?;
         let pos = self.write_integer_number(int_part, pos, output_buffer)?;
         let mut pos = self.write_str(self.prefix, pos, output_buffer)?;
@@ -348,7 +390,8 @@ impl FormatNumber {
         }
         let negative = value.is_negative();
         let value = value.abs_value();
-        let pos = self.write_str(self.suffix, len, output_buffer)?;
+        let pos = self.write_unit(len, output_buffer)?;
+        let pos = self.write_str(self.suffix, pos, output_buffer)?;
         let pos = self.write_integer_number(value, pos, output_buffer)?;
         let mut pos = self.write_str(self.prefix, pos, output_buffer)?;
         if negative {
@@ -386,7 +429,8 @@ impl FormatNumber {
         };
         let negative = value.is_negative() != devider.is_negative();
         let value = int_part.abs_value();
-        let pos = self.write_str(self.suffix, len, output_buffer)?;
+        let pos = self.write_unit(len, output_buffer)?;
+        let pos = self.write_str(self.suffix, pos, output_buffer)?;
         let pos = self.write_decimals(decimans, pos, output_buffer)?;
         let pos = self.write_integer_number(value, pos, output_buffer)?;
         let mut pos = self.write_str(self.prefix, pos, output_buffer)?;
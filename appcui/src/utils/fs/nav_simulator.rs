@@ -103,6 +103,19 @@ impl crate::utils::Navigator<Entry, Root, PathBuf> for NavSimulator {
     fn current_dir(&self) -> PathBuf {
         PathBuf::from(&self.current_dir)
     }
+    fn read(&self, path: &PathBuf, max_size: usize) -> Option<Vec<u8>> {
+        // the content of a file (if any) is stored in the 5th column (with '\n' as a new line marker)
+        for line in self.data.lines() {
+            let words = line.split(',').collect::<Vec<&str>>();
+            if words.len() < 5 || !matches!(words[0].trim(), "f" | "F" | "file") || std::path::Path::new(words[1].trim()) != path {
+                continue;
+            }
+            let mut content = words[4].trim().replace("\\n", "\n").into_bytes();
+            content.truncate(max_size);
+            return Some(content);
+        }
+        None
+    }
 }
 
 impl Clone for NavSimulator {
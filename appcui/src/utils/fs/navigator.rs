@@ -3,6 +3,7 @@ use crate::utils::NavigatorEntry;
 use chrono::DateTime;
 use chrono::NaiveDateTime;
 use std::fs;
+use std::io::Read;
 use std::path::Path;
 use std::path::PathBuf;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -104,6 +105,12 @@ impl crate::utils::Navigator<Entry, Root, PathBuf> for Navigator {
     fn current_dir(&self) -> PathBuf {
         std::env::current_dir().unwrap_or_default()
     }
+    fn read(&self, path: &PathBuf, max_size: usize) -> Option<Vec<u8>> {
+        let file = fs::File::open(path).ok()?;
+        let mut content = Vec::new();
+        file.take(max_size as u64).read_to_end(&mut content).ok()?;
+        Some(content)
+    }
 }
 
 impl Navigator {
@@ -2,6 +2,7 @@ use crate::prelude::*;
 use crate::utils::NavigatorRoot;
 
 #[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
 pub(crate) enum RootType {
     Fixed,
     Removable,
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+#[cfg(target_os = "windows")]
 use super::Entry;
 use super::NavSimulator;
 use crate::utils::{fs::entry::EntryType, Navigator, NavigatorEntry, NavigatorRoot};
@@ -1,3 +1,5 @@
+use crate::graphics::unicode;
+
 pub(crate) trait GlyphParser {
     fn count_glyphs(&self) -> usize;
     fn glyph(&self, offset: usize) -> Option<(char, u32)>;
@@ -8,10 +10,6 @@ pub(crate) trait GlyphParser {
     fn index_ignoring_case(&self, to_find: &str)->Option<usize>;
 }
 
-#[inline(always)]
-fn is_variation_selector(c: char) -> bool {
-    matches!(c, '\u{FE00}'..='\u{FE0F}' | '\u{E0100}'..='\u{E01EF}')
-}
 #[inline(always)]
 fn to_ascii_lowercase(c: u8) -> u8 {
     if c.is_ascii_uppercase() {
@@ -23,43 +21,21 @@ fn to_ascii_lowercase(c: u8) -> u8 {
 
 impl GlyphParser for str {
     fn count_glyphs(&self) -> usize {
-        self.chars().filter(|&c| !is_variation_selector(c)).count()
+        unicode::graphemes(self).count()
     }
     fn glyph(&self, offset: usize) -> Option<(char, u32)> {
-        let mut chars = self[offset..].chars();
-        chars.next().map(|first_char| {
-            let mut char_size = first_char.len_utf8() as u32;
-            if char_size > 1 {
-                if let Some(next_char) = chars.next() {
-                    if is_variation_selector(next_char) {
-                        char_size += next_char.len_utf8() as u32;
-                    }
-                }
-            }
-            (first_char, char_size)
-        })
+        // a glyph is a grapheme cluster (the first character and the size in bytes of the entire cluster)
+        let first_char = self[offset..].chars().next()?;
+        Some((first_char, (unicode::next_grapheme_boundary(self, offset) - offset) as u32))
     }
 
     fn previous_glyph(&self, offset: usize) -> Option<(char, u32)> {
         if offset == 0 || offset > self.len() {
             return None;
         }
-        let slice = &self[..offset];
-        let mut char_indices = slice.char_indices().rev();
-        let mut total_size = 0;
-
-        if let Some((_, previous_char)) = char_indices.next() {
-            total_size += previous_char.len_utf8() as u32;
-            if is_variation_selector(previous_char) {
-                if let Some((_, char_before_previous_char)) = char_indices.next() {
-                    total_size += char_before_previous_char.len_utf8() as u32;
-                    return Some((char_before_previous_char, total_size));
-                }
-            }
-            Some((previous_char, total_size))
-        } else {
-            None
-        }
+        let start = unicode::previous_grapheme_boundary(self, offset);
+        let first_char = self[start..].chars().next()?;
+        Some((first_char, (offset - start) as u32))
     }
 
     fn next_pos(&self, current_pos: usize, count_glyphs: usize) -> usize {
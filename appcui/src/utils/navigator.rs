@@ -9,6 +9,7 @@ where
     fn join(&self, path: &P, entry: &E) -> Option<P>;
     fn exists(&self, path: &P) -> Option<bool>;
     fn current_dir(&self) -> P;
+    fn read(&self, path: &P, max_size: usize) -> Option<Vec<u8>>;
 }
 pub trait NavigatorEntry {
     fn name(&self) -> &str;
@@ -297,10 +297,8 @@ impl TimerEvents for FlappyGame {
 impl OnKeyPressed for FlappyGame {
     fn on_key_pressed(&mut self, key: Key, _character: char) -> EventProcessStatus {
         match self.state {
-            GameState::Menu => {
-                if key.code == KeyCode::Space {
-                    self.state = GameState::Countdown;
-                }
+            GameState::Menu if key.code == KeyCode::Space => {
+                self.state = GameState::Countdown;
             }
             GameState::Playing => match key.value() {
                 key!("space") => self.make_bird_jump(),
@@ -1,4 +1,4 @@
-use appcui::{backend, prelude::*};
+use appcui::prelude::*;
 
 #[Window(events = [HSliderEvents<i32>, HSliderEvents<f32>])]
 struct MyWin {}
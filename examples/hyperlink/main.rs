@@ -1,4 +1,4 @@
-use appcui::{backend, prelude::*};
+use appcui::prelude::*;
 
 #[Window(events = HyperLinkEvents)]
 struct MyWin {
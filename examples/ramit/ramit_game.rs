@@ -237,10 +237,8 @@ impl TimerEvents for RamItGame {
 impl OnKeyPressed for RamItGame {
     fn on_key_pressed(&mut self, key: Key, _character: char) -> EventProcessStatus {
         match self.state {
-            GameState::Menu => {
-                if key.code == KeyCode::Space {
-                    self.state = GameState::Countdown;
-                }
+            GameState::Menu if key.code == KeyCode::Space => {
+                self.state = GameState::Countdown;
             }
             GameState::Playing => match key.value() {
                 key!("up") => self.move_up(),